@@ -241,3 +241,147 @@ fn new_render_texture(
 
     texture_handle
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::App;
+    use bevy_ecs::{
+        query::{With, Without},
+        system::{Commands, Query, Res, ResMut, SystemState},
+    };
+
+    use math::Vec2;
+    use render_api::{
+        base::{CpuMaterial, CpuMesh, CpuTexture2D},
+        components::{AmbientLight, Camera, Projection, RenderLayer, Transform, Visibility},
+        resources::RenderFrame,
+        shapes::set_2d_line_transform,
+        Draw, RenderApiPlugin,
+    };
+    use render_software::{golden_image::assert_golden_image, RenderSoftwarePlugin};
+    use storage::{Handle, Storage};
+
+    use crate::app::{
+        components::DefaultDraw,
+        resources::{camera_manager::CameraManager, input::InputManager},
+    };
+
+    use super::{setup_2d_scene, setup_3d_scene};
+
+    const WIDTH: u32 = 160;
+    const HEIGHT: u32 = 96;
+
+    // the camera, light & object half of `systems::draw`, which otherwise needs an open file
+    fn draw_canvas(
+        mut render_frame: ResMut<RenderFrame>,
+        cameras_q: Query<(&Camera, &Transform, &Projection, Option<&RenderLayer>)>,
+        ambient_lights_q: Query<(&AmbientLight, Option<&RenderLayer>)>,
+        objects_q: Query<
+            (
+                &Handle<CpuMesh>,
+                &Handle<CpuMaterial>,
+                &Transform,
+                &Visibility,
+                Option<&RenderLayer>,
+            ),
+            With<DefaultDraw>,
+        >,
+    ) {
+        for (camera, transform, projection, render_layer_opt) in cameras_q.iter() {
+            if camera.is_active {
+                render_frame.draw_camera(render_layer_opt, camera, transform, projection);
+            }
+        }
+        for (light, render_layer_opt) in ambient_lights_q.iter() {
+            render_frame.draw_ambient_light(render_layer_opt, light);
+        }
+        for (mesh_handle, mat_handle, transform, visibility, render_layer_opt) in objects_q.iter() {
+            if visibility.visible {
+                render_frame.draw_mesh(render_layer_opt, mesh_handle, mat_handle, transform);
+            }
+        }
+    }
+
+    // the 2d canvas, with the select circle, triangle & line showing as they do over hovered
+    // vertices, faces & edges
+    #[test]
+    fn canvas_2d() {
+        let mut app = App::new();
+        app.add_plugins(RenderApiPlugin)
+            .add_plugins(RenderSoftwarePlugin::new(WIDTH, HEIGHT))
+            .init_resource::<CameraManager>()
+            .init_resource::<InputManager>()
+            .add_systems(Draw, draw_canvas);
+
+        let world = app.world_mut();
+        let texture_size = Vec2::new(WIDTH as f32, HEIGHT as f32);
+        let canvas_texture_handle = world
+            .resource_mut::<Storage<CpuTexture2D>>()
+            .add(CpuTexture2D::from_size(WIDTH, HEIGHT));
+
+        let mut system_state: SystemState<(
+            Commands,
+            ResMut<CameraManager>,
+            ResMut<InputManager>,
+            ResMut<Storage<CpuMesh>>,
+            ResMut<Storage<CpuMaterial>>,
+        )> = SystemState::new(world);
+        let (mut commands, mut camera_manager, mut input_manager, mut meshes, mut materials) =
+            system_state.get_mut(world);
+        setup_3d_scene(
+            &mut commands,
+            &mut camera_manager,
+            &texture_size,
+            canvas_texture_handle,
+        );
+        setup_2d_scene(
+            &mut commands,
+            &mut camera_manager,
+            &mut input_manager,
+            &mut meshes,
+            &mut materials,
+            &texture_size,
+            canvas_texture_handle,
+        );
+        system_state.apply(world);
+
+        let mut system_state: SystemState<(
+            Res<CameraManager>,
+            Res<InputManager>,
+            Query<(&mut Camera, &mut Projection, &mut Transform)>,
+            Query<(&mut Transform, &mut Visibility), Without<Camera>>,
+        )> = SystemState::new(world);
+        let (camera_manager, input_manager, mut camera_q, mut objects_q) =
+            system_state.get_mut(world);
+        camera_manager.enable_cameras(&mut camera_q, true);
+        for (entity, position) in [
+            (input_manager.select_circle_entity, Vec2::new(40.0, 48.0)),
+            (input_manager.select_triangle_entity, Vec2::new(80.0, 48.0)),
+        ] {
+            let (mut transform, mut visibility) = objects_q.get_mut(entity.unwrap()).unwrap();
+            transform.translation.x = position.x;
+            transform.translation.y = position.y;
+            visibility.visible = true;
+        }
+        {
+            let (mut transform, mut visibility) = objects_q
+                .get_mut(input_manager.select_line_entity.unwrap())
+                .unwrap();
+            set_2d_line_transform(
+                &mut transform,
+                Vec2::new(104.0, 72.0),
+                Vec2::new(144.0, 24.0),
+                0.0,
+            );
+            visibility.visible = true;
+        }
+
+        app.update();
+
+        let textures = app.world().resource::<Storage<CpuTexture2D>>();
+        assert_golden_image(
+            textures.get(&canvas_texture_handle).unwrap(),
+            "tests/golden/canvas_2d.png",
+        );
+    }
+}
@@ -16,11 +16,21 @@ impl ProtocolPlugin for AnimationComponentsPlugin {
     }
 }
 
+// Easing
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serde)]
+pub enum Easing {
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    Step,
+}
+
 // Transition
 #[derive(Clone, PartialEq, Serde)]
 pub struct Transition {
     duration_5ms: UnsignedVariableInteger<7>,
-    //pub easing: Easing,
+    easing: Easing,
 }
 
 impl Transition {
@@ -28,9 +38,18 @@ impl Transition {
         let duration_5ms = duration_ms / 5;
         Self {
             duration_5ms: duration_5ms.into(),
+            easing: Easing::Linear,
         }
     }
 
+    pub fn get_easing(&self) -> Easing {
+        self.easing
+    }
+
+    pub fn set_easing(&mut self, easing: Easing) {
+        self.easing = easing;
+    }
+
     pub fn get_duration_ms(&self) -> u16 {
         let duration_5ms: u16 = self.duration_5ms.to();
         duration_5ms * 5
@@ -48,11 +67,13 @@ pub struct AnimFrame {
     pub file_entity: EntityProperty,
     order: Property<UnsignedVariableInteger<4>>,
     pub transition: Property<Transition>,
+    // names fired when the game reaches this frame, carried through so saving keeps them
+    pub events: Property<Vec<String>>,
 }
 
 impl AnimFrame {
     pub fn new(order: u8, transition: Transition) -> Self {
-        Self::new_complete(order.into(), transition)
+        Self::new_complete(order.into(), transition, Vec::new())
     }
 
     pub fn get_order(&self) -> u8 {
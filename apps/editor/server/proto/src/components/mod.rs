@@ -15,7 +15,7 @@ pub use shape::{
 
 mod animation;
 use animation::AnimationComponentsPlugin;
-pub use animation::{AnimFrame, AnimRotation, Transition};
+pub use animation::{AnimFrame, AnimRotation, Easing, Transition};
 
 mod color;
 use color::ColorComponentsPlugin;
@@ -20,7 +20,10 @@ use editor_proto::{
 };
 
 use crate::{
-    files::{add_file_dependency, convert_from_quat, FileWriter},
+    files::{
+        add_file_dependency, convert_from_easing, convert_from_quat, convert_into_easing,
+        FileWriter,
+    },
     resources::{AnimationManager, ContentEntityData, Project},
 };
 
@@ -41,8 +44,8 @@ impl AnimWriter {
         let mut shape_map: HashMap<String, u16> = HashMap::new();
 
         let mut biggest_order_opt: Option<u8> = None;
-        //////////////////// order, frame_entity, duration_5ms
-        let mut frame_map: HashMap<u8, (Entity, Transition)> = HashMap::new();
+        //////////////////// order, frame_entity, transition, events
+        let mut frame_map: HashMap<u8, (Entity, Transition, Vec<String>)> = HashMap::new();
         let mut frame_poses_map: HashMap<Entity, HashMap<u16, SerdeQuat>> = HashMap::new();
 
         for (content_entity, content_data) in content_entities {
@@ -78,7 +81,14 @@ impl AnimWriter {
                     if frame_map.contains_key(&frame_order) {
                         panic!("anim file should not have duplicate frame orders");
                     }
-                    frame_map.insert(frame_order, (*content_entity, (*frame.transition).clone()));
+                    frame_map.insert(
+                        frame_order,
+                        (
+                            *content_entity,
+                            (*frame.transition).clone(),
+                            (*frame.events).clone(),
+                        ),
+                    );
                 }
                 ContentEntityData::Rotation => {
                     let mut system_state: SystemState<(Server, Query<&AnimRotation>)> =
@@ -132,7 +142,7 @@ impl AnimWriter {
         // Write Frames
         if let Some(biggest_order) = biggest_order_opt {
             for order in 0..=biggest_order {
-                let Some((frame_entity, transition)) = frame_map.remove(&order) else {
+                let Some((frame_entity, transition, events)) = frame_map.remove(&order) else {
                     panic!("anim file should not have any gaps in frame orders");
                 };
                 let poses = if let Some(poses) = frame_poses_map.remove(&frame_entity) {
@@ -143,6 +153,10 @@ impl AnimWriter {
 
                 info!("push frame action: {}", order);
                 let mut frame = AnimFileFrame::new(transition.get_duration_ms());
+                frame.set_easing(convert_into_easing(transition.get_easing()));
+                for event in events {
+                    frame.add_event(&event);
+                }
 
                 for (id, quat) in poses {
                     info!("push pose action: {}", id);
@@ -226,9 +240,11 @@ impl AnimReader {
             for frame in data.get_frames() {
                 info!("read frame action!");
 
-                let transition = frame.get_transition_ms();
+                let mut transition = Transition::new(frame.get_transition_ms());
+                transition.set_easing(convert_from_easing(frame.get_easing()));
 
-                let mut component = AnimFrame::new(frame_index, Transition::new(transition));
+                let mut component = AnimFrame::new(frame_index, transition);
+                *component.events = frame.get_events().clone();
                 component.file_entity.set(&server, file_entity);
                 let frame_entity = commands
                     .spawn_empty()
@@ -10,7 +10,7 @@ use logging::info;
 use naia_bevy_server::{CommandsExt, ReplicationConfig, Server};
 
 use asset_id::AssetId;
-use asset_serde::json::{AnimFileQuat, FileComponentType};
+use asset_serde::json::{AnimFileEasing, AnimFileQuat, FileComponentType};
 use math::Quat;
 
 use editor_proto::{
@@ -348,6 +348,25 @@ pub fn add_file_dependency(
 // conversion
 
 // transition
+pub fn convert_into_easing(input: editor_proto::components::Easing) -> AnimFileEasing {
+    match input {
+        editor_proto::components::Easing::Linear => AnimFileEasing::Linear,
+        editor_proto::components::Easing::EaseIn => AnimFileEasing::EaseIn,
+        editor_proto::components::Easing::EaseOut => AnimFileEasing::EaseOut,
+        editor_proto::components::Easing::EaseInOut => AnimFileEasing::EaseInOut,
+        editor_proto::components::Easing::Step => AnimFileEasing::Step,
+    }
+}
+
+pub fn convert_from_easing(input: AnimFileEasing) -> editor_proto::components::Easing {
+    match input {
+        AnimFileEasing::Linear => editor_proto::components::Easing::Linear,
+        AnimFileEasing::EaseIn => editor_proto::components::Easing::EaseIn,
+        AnimFileEasing::EaseOut => editor_proto::components::Easing::EaseOut,
+        AnimFileEasing::EaseInOut => editor_proto::components::Easing::EaseInOut,
+        AnimFileEasing::Step => editor_proto::components::Easing::Step,
+    }
+}
 
 // quat
 
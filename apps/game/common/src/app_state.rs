@@ -5,4 +5,6 @@ pub enum AppState {
     Loading,
     MainMenu,
     InGame,
+    // playing back a match recording, with no world server connection
+    Replay,
 }
@@ -1,22 +1,242 @@
+use std::{collections::HashMap, f32::consts::PI};
+
 use bevy_ecs::component::Component;
 
 use game_engine::{
-    asset::{AnimatedModelData, AssetHandle, AssetManager},
+    asset::{
+        AnimatedModelData, AnimationGraph, AnimationLayerMode, AnimationLayerPose,
+        AnimationPlayback, AssetHandle, AssetManager,
+    },
     logging::info,
     math::Vec2,
 };
 
 use game_app_network::world::{components::TileMovement, types::Direction};
 
+// A state of one of the unit's animation graphs, and how far its clip has played
+#[derive(Clone)]
+struct PlayingState {
+    state_name: String,
+    // `None` for states that play nothing
+    animation_name: Option<String>,
+    playback: AnimationPlayback,
+    animation_index_ms: f32,
+    // total clip time played since entering the state, does not wrap
+    played_ms: f32,
+}
+
+impl PlayingState {
+    fn new(graph: &AnimationGraph, state_name: &str) -> Option<Self> {
+        let state = graph.get_state(state_name)?;
+        Some(Self {
+            state_name: state.get_name().to_string(),
+            animation_name: state.get_animation().map(str::to_string),
+            playback: state.get_playback(),
+            animation_index_ms: 0.0,
+            played_ms: 0.0,
+        })
+    }
+
+    // returns the events on frames the clip reached
+    fn advance(
+        &mut self,
+        asset_manager: &AssetManager,
+        model_data: &AssetHandle<AnimatedModelData>,
+        delta_ms: f32,
+        distance: f32,
+    ) -> Vec<String> {
+        let Some(animation_name) = self.animation_name.as_ref() else {
+            return Vec::new();
+        };
+        let advance_ms = self.playback.advance_ms(delta_ms, distance);
+        if advance_ms <= 0.0 {
+            return Vec::new();
+        }
+        let previous_index_ms = self.animation_index_ms;
+        self.animation_index_ms += advance_ms;
+        self.played_ms += advance_ms;
+
+        let max_duration_ms =
+            asset_manager.get_animated_model_animation_duration_ms(model_data, animation_name);
+        if max_duration_ms <= 0.0 {
+            return Vec::new();
+        }
+        while self.animation_index_ms >= max_duration_ms {
+            self.animation_index_ms -= max_duration_ms;
+        }
+
+        asset_manager.get_animated_model_animation_events(
+            model_data,
+            animation_name,
+            previous_index_ms,
+            self.animation_index_ms,
+        )
+    }
+
+    fn progress(
+        &self,
+        asset_manager: &AssetManager,
+        model_data: &AssetHandle<AnimatedModelData>,
+    ) -> f32 {
+        let Some(animation_name) = self.animation_name.as_ref() else {
+            return 0.0;
+        };
+        let duration_ms =
+            asset_manager.get_animated_model_animation_duration_ms(model_data, animation_name);
+        if duration_ms > 0.0 {
+            self.played_ms / duration_ms
+        } else {
+            0.0
+        }
+    }
+}
+
+// A state that was left through a transition, still contributing to the pose until its
+// weight fades to zero
+#[derive(Clone)]
+struct FadingState {
+    playing: PlayingState,
+    start_weight: f32,
+    fade_ms: f32,
+    elapsed_ms: f32,
+}
+
+impl FadingState {
+    fn weight(&self) -> f32 {
+        self.start_weight * (1.0 - self.elapsed_ms / self.fade_ms).max(0.0)
+    }
+}
+
+// Playback of one graph: the unit's base graph, or one of the layers laid over it
+#[derive(Clone)]
+struct GraphRunner {
+    bone_mask: Vec<String>,
+    mode: AnimationLayerMode,
+    current: Option<PlayingState>,
+    fading: Vec<FadingState>,
+}
+
+impl GraphRunner {
+    fn new(bone_mask: &[String], mode: AnimationLayerMode) -> Self {
+        Self {
+            bone_mask: bone_mask.to_vec(),
+            mode,
+            current: None,
+            fading: Vec::new(),
+        }
+    }
+
+    // returns the events reached by the current state, fading states stay quiet
+    fn update(
+        &mut self,
+        graph: &AnimationGraph,
+        asset_manager: &AssetManager,
+        model_data: &AssetHandle<AnimatedModelData>,
+        parameters: &mut HashMap<String, f32>,
+        delta_ms: f32,
+        distance: f32,
+    ) -> Vec<String> {
+        if self.current.is_none() {
+            self.current = PlayingState::new(graph, graph.get_default_state());
+        }
+        let Some(current) = self.current.as_ref() else {
+            return Vec::new();
+        };
+        parameters.insert(
+            AnimationGraph::PROGRESS.to_string(),
+            current.progress(asset_manager, model_data),
+        );
+
+        // change state if needed
+        if let Some(transition) = graph.next_transition(&current.state_name, parameters) {
+            if let Some(next) = PlayingState::new(graph, transition.get_to()) {
+                info!(
+                    "Changing animation state: {} -> {}",
+                    current.state_name, next.state_name
+                );
+                let current_weight = self.current_weight();
+                let previous = std::mem::replace(&mut self.current, Some(next)).unwrap();
+                if transition.get_fade_ms() > 0.0
+                    && current_weight > 0.0
+                    && previous.animation_name.is_some()
+                {
+                    self.fading.push(FadingState {
+                        playing: previous,
+                        start_weight: current_weight,
+                        fade_ms: transition.get_fade_ms(),
+                        elapsed_ms: 0.0,
+                    });
+                }
+            }
+        }
+
+        // animate
+        let mut events = Vec::new();
+        if let Some(current) = self.current.as_mut() {
+            events = current.advance(asset_manager, model_data, delta_ms, distance);
+        }
+        for fading in self.fading.iter_mut() {
+            fading
+                .playing
+                .advance(asset_manager, model_data, delta_ms, distance);
+            fading.elapsed_ms += delta_ms;
+        }
+        self.fading
+            .retain(|fading| fading.elapsed_ms < fading.fade_ms);
+
+        events
+    }
+
+    fn pose(&self) -> AnimationLayerPose<'_> {
+        let mut animations = Vec::with_capacity(self.fading.len() + 1);
+        for fading in &self.fading {
+            if let Some(animation_name) = &fading.playing.animation_name {
+                animations.push((
+                    animation_name.as_str(),
+                    fading.playing.animation_index_ms,
+                    fading.weight(),
+                ));
+            }
+        }
+        if let Some(current) = &self.current {
+            if let Some(animation_name) = &current.animation_name {
+                animations.push((
+                    animation_name.as_str(),
+                    current.animation_index_ms,
+                    self.current_weight(),
+                ));
+            }
+        }
+        AnimationLayerPose {
+            animations,
+            bone_mask: &self.bone_mask,
+            mode: self.mode,
+        }
+    }
+
+    // whatever weight the fading states don't hold
+    fn current_weight(&self) -> f32 {
+        let fading_weight: f32 = self.fading.iter().map(FadingState::weight).sum();
+        (1.0 - fading_weight).max(0.0)
+    }
+}
+
 #[derive(Component, Clone)]
 pub struct AnimationState {
     pub(crate) rotation: f32,
     lookdir: Direction,
-    pub(crate) animation_name: String,
-    pub(crate) animation_index_ms: f32,
     last_pos: Vec2,
     is_moving: bool,
     move_heat: f32,
+    // facing change not yet reported to the graph
+    pending_turn: f32,
+
+    parameters: HashMap<String, f32>,
+    // `None` until the first update, when the unit's graph is known
+    base: Option<GraphRunner>,
+    layers: Vec<GraphRunner>,
+    // frame events reached since they were last taken
+    events: Vec<String>,
 }
 
 impl AnimationState {
@@ -24,11 +244,15 @@ impl AnimationState {
         Self {
             rotation: 0.0,
             lookdir: Direction::East,
-            animation_name: "idle".to_string(),
-            animation_index_ms: 0.0,
             last_pos: Vec2::ZERO,
             is_moving: false,
             move_heat: 0.0,
+            pending_turn: 0.0,
+
+            parameters: HashMap::new(),
+            base: None,
+            layers: Vec::new(),
+            events: Vec::new(),
         }
     }
 
@@ -42,13 +266,18 @@ impl AnimationState {
         delta_ms: f32,
         tile_movement: &TileMovement,
     ) {
+        let Some(graph) = asset_manager.get_animated_model_graph(model_data) else {
+            return;
+        };
+
         let last_position = self.last_pos;
         self.last_pos = position;
 
         let dx = position.x - last_position.x;
         let dy = position.y - last_position.y;
+        let distance = Vec2::new(dx, dy).length();
 
-        // change animation if needed
+        // debounce starting & stopping so a single still tick doesn't flicker the animation
         let is_moving = dx != 0.0 || dy != 0.0;
 
         if is_moving != self.is_moving {
@@ -56,14 +285,6 @@ impl AnimationState {
             if self.move_heat > 7.0 {
                 self.move_heat = 0.0;
                 self.is_moving = is_moving;
-
-                let new_animation_name = if self.is_moving { "walk" } else { "idle" };
-
-                info!(
-                    "Changing animation to: {} .. dx: {}, dy: {}",
-                    new_animation_name, dx, dy
-                );
-                self.animation_name = new_animation_name.to_string();
             }
         }
 
@@ -73,40 +294,103 @@ impl AnimationState {
             //     self.lookdir = Direction::from_coords(acceleration.x, acceleration.y);
             // }
             if tile_movement.is_moving() {
-                self.lookdir = tile_movement.as_moving().direction();
+                self.set_lookdir(tile_movement.as_moving().direction());
             }
-            self.rotation = self.lookdir.to_radians();
         }
 
-        // animate
-
-        // TODO: move this into config
-        let animation_speed_factor = match self.animation_name.as_str() {
-            "idle" => 0.075,
-            "walk" => {
-                let distance = Vec2::new(dx, dy).length();
-                0.15 * distance
-            }
-            _ => 0.0,
+        // feed the graph
+        let speed = if delta_ms > 0.0 {
+            distance * 1000.0 / delta_ms
+        } else {
+            0.0
         };
-        self.animation_index_ms += delta_ms * animation_speed_factor;
+        let turn = std::mem::take(&mut self.pending_turn);
+        self.set_parameter(AnimationGraph::SPEED, speed);
+        self.set_parameter(
+            AnimationGraph::MOVING,
+            if self.is_moving { 1.0 } else { 0.0 },
+        );
+        self.set_parameter(AnimationGraph::DIRECTION, self.rotation);
+        self.set_parameter(AnimationGraph::TURN, turn);
 
-        let max_duration_ms = asset_manager
-            .get_animated_model_animation_duration_ms(model_data, &self.animation_name);
+        if self.base.is_none() {
+            self.base = Some(GraphRunner::new(&[], AnimationLayerMode::Override));
+            self.layers = graph
+                .get_layers()
+                .iter()
+                .map(|layer| GraphRunner::new(layer.get_bone_mask(), layer.get_mode()))
+                .collect();
+        }
 
-        while self.animation_index_ms >= max_duration_ms {
-            self.animation_index_ms -= max_duration_ms;
+        // animate
+        let base = self.base.as_mut().unwrap();
+        let events = base.update(
+            graph,
+            asset_manager,
+            model_data,
+            &mut self.parameters,
+            delta_ms,
+            distance,
+        );
+        self.events.extend(events);
+        for (runner, layer) in self.layers.iter_mut().zip(graph.get_layers()) {
+            let events = runner.update(
+                layer.get_graph(),
+                asset_manager,
+                model_data,
+                &mut self.parameters,
+                delta_ms,
+                distance,
+            );
+            self.events.extend(events);
+        }
+    }
+
+    // lets gameplay drive graph parameters of its own, e.g. to trigger an emote
+    pub fn set_parameter(&mut self, name: &str, value: f32) {
+        self.parameters.insert(name.to_string(), value);
+    }
+
+    // the base pose first, then each of the graph's layers
+    pub(crate) fn blend_layers(&self) -> Vec<AnimationLayerPose<'_>> {
+        let mut layers = Vec::with_capacity(self.layers.len() + 1);
+        if let Some(base) = &self.base {
+            layers.push(base.pose());
         }
+        for layer in &self.layers {
+            layers.push(layer.pose());
+        }
+        layers
+    }
+
+    pub(crate) fn take_events(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.events)
     }
 
     pub fn recv_lookdir_update(&mut self, lookdir: &Direction) {
-        if self.animation_name == "idle" {
-            self.rotation = lookdir.to_radians();
-            self.lookdir = *lookdir;
+        if !self.is_moving {
+            self.set_lookdir(*lookdir);
         }
     }
 
     pub fn lookdir(&self) -> Direction {
         self.lookdir
     }
+
+    fn set_lookdir(&mut self, lookdir: Direction) {
+        let rotation = lookdir.to_radians();
+
+        // wrap the change into -PI..PI so turning through east reads the right way round
+        let mut turn = rotation - self.rotation;
+        while turn > PI {
+            turn -= 2.0 * PI;
+        }
+        while turn < -PI {
+            turn += 2.0 * PI;
+        }
+        self.pending_turn += turn;
+
+        self.rotation = rotation;
+        self.lookdir = lookdir;
+    }
 }
@@ -44,6 +44,10 @@ impl ConfirmedTileMovement {
         }
     }
 
+    pub fn tile_movement(&self) -> &TileMovement {
+        &self.tile_movement
+    }
+
     pub fn recv_updated_net_tile_target(
         &mut self,
         update_tick: Tick,
@@ -252,6 +256,9 @@ impl ConfirmedTileMovement {
         else {
             return;
         };
+        let Some(movement_config) = asset_manager.get_unit_movement_config(unit_handle) else {
+            return;
+        };
 
         // if update_tick is less than last_processed_server_tick, then we should simulate forward
         let mut current_tick = update_tick;
@@ -267,6 +274,7 @@ impl ConfirmedTileMovement {
                 render_position,
                 animation_state,
                 animated_model_handle,
+                movement_config,
             );
         }
     }
@@ -120,6 +120,10 @@ impl RenderPosition {
         render_position
     }
 
+    pub fn last_render_position(&self) -> Vec2 {
+        self.last_render_position
+    }
+
     pub fn current_offset_from_last_render(&mut self, client: &WorldClient) -> Vec2 {
         self.prune_queue(client);
 
@@ -0,0 +1,8 @@
+use bevy_ecs::{entity::Entity, event::Event};
+
+// Sent when a unit's animation reaches a frame tagged with a named event, e.g. "footstep"
+#[derive(Event, Clone)]
+pub struct AnimationEvent {
+    pub entity: Entity,
+    pub name: String,
+}
@@ -1,6 +1,9 @@
 mod plugin;
 pub use plugin::InWorldPlugin;
 
+mod events;
+pub use events::AnimationEvent;
+
 mod components;
 mod resources;
 mod systems;
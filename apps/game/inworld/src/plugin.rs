@@ -6,10 +6,14 @@ use bevy_ecs::{
 use bevy_state::condition::in_state;
 
 use game_app_common::AppState;
-use game_app_network::naia::ReceiveEvents;
+use game_app_network::{naia::ReceiveEvents, ConnectionManager};
 
 use crate::{
-    resources::{Global, InputManager, PredictedWorld, RollbackManager, TickTracker},
+    events::AnimationEvent,
+    resources::{
+        CameraManager, DesyncDetector, Global, InputManager, Minimap, NetworkDebugOverlay,
+        PredictedWorld, ReplayManager, RollbackManager, TickTracker,
+    },
     systems,
 };
 
@@ -23,7 +27,14 @@ impl Plugin for InWorldPlugin {
             .init_resource::<PredictedWorld>()
             .init_resource::<InputManager>()
             .init_resource::<TickTracker>()
+            .init_resource::<DesyncDetector>()
+            .init_resource::<NetworkDebugOverlay>()
             .init_resource::<RollbackManager>()
+            .init_resource::<CameraManager>()
+            .init_resource::<ReplayManager>()
+            .init_resource::<Minimap>()
+            // events
+            .add_event::<AnimationEvent>()
             // systems
             .add_systems(
                 Update,
@@ -37,7 +48,10 @@ impl Plugin for InWorldPlugin {
             .add_systems(
                 Update,
                 (
-                    systems::world_events::disconnect_events,
+                    // only once the connection manager knows whether we'll reconnect
+                    systems::world_events::disconnect_events
+                        .after(ConnectionManager::handle_world_disconnect_events)
+                        .after(ConnectionManager::handle_world_reject_events),
                     systems::world_events::message_events,
                     systems::world_events::spawn_entity_events,
                     systems::world_events::despawn_entity_events,
@@ -86,7 +100,14 @@ impl Plugin for InWorldPlugin {
             .configure_sets(Update, systems::MainLoop.after(systems::Tick))
             .add_systems(
                 Update,
-                (InputManager::recv_key_input)
+                (
+                    InputManager::recv_key_input,
+                    NetworkDebugOverlay::update,
+                    Minimap::update,
+                    systems::animation::send_animation_events,
+                    systems::particles::spawn_unit_particle_emitters,
+                    CameraManager::leave_spectating,
+                )
                     .run_if(in_state(AppState::InGame))
                     .in_set(systems::MainLoop),
             )
@@ -94,9 +115,39 @@ impl Plugin for InWorldPlugin {
             .configure_sets(Update, systems::Render.after(systems::MainLoop))
             .add_systems(
                 Update,
-                (systems::render::draw_units,)
+                (
+                    systems::render::draw_units,
+                    // after the units' render positions are updated for the frame
+                    systems::particles::update_unit_particle_emitters,
+                    CameraManager::update_camera,
+                    Minimap::draw,
+                    NetworkDebugOverlay::draw,
+                )
+                    .chain()
                     .run_if(in_state(AppState::InGame))
                     .in_set(systems::Render),
+            )
+            // Replay
+            .add_systems(
+                Update,
+                ReplayManager::load_requested_replay.run_if(in_state(AppState::MainMenu)),
+            )
+            .add_systems(
+                Update,
+                (ReplayManager::step, Minimap::update)
+                    .run_if(in_state(AppState::Replay))
+                    .in_set(systems::MainLoop),
+            )
+            .add_systems(
+                Update,
+                (
+                    ReplayManager::draw_units,
+                    CameraManager::update_camera,
+                    Minimap::draw,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Replay))
+                    .in_set(systems::Render),
             );
     }
 }
@@ -0,0 +1,190 @@
+use std::collections::HashSet;
+
+use bevy_ecs::{
+    entity::Entity,
+    prelude::{Query, Resource, With},
+    system::{Res, ResMut},
+};
+
+use game_engine::{
+    asset::{AssetHandle, UnitData},
+    input::{Input, Key},
+    logging::info,
+    math::{Vec2, Vec3},
+    render::{
+        components::{Camera, RenderLayer, RenderLayers, Transform},
+        resources::Time,
+    },
+};
+
+use game_app_network::{
+    session::{channels::ClientActionsChannel, messages::MatchLobbyLeaveSpectate, SessionClient},
+    ConnectionManager,
+};
+
+use crate::{
+    components::RenderPosition,
+    resources::{Global, ReplayManager},
+};
+
+// world units per millisecond
+const FREE_CAMERA_SPEED: f32 = 0.6;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CameraMode {
+    Free,
+    FollowPlayer,
+}
+
+// Spectators (and replays) own no avatar, so instead of the fixed overhead camera they can pan
+// freely or follow one of the players. Tab switches modes, Q / E cycle the followed player, and
+// Escape stops spectating.
+#[derive(Resource)]
+pub struct CameraManager {
+    mode: CameraMode,
+    // an entity's bits, or a replayed unit's id
+    followed_unit: Option<u64>,
+    focus: Vec2,
+    // camera translation while focused on the origin, captured from the scene's camera
+    base_translation: Option<Vec3>,
+    pressed_keys: HashSet<Key>,
+}
+
+impl Default for CameraManager {
+    fn default() -> Self {
+        Self {
+            mode: CameraMode::Free,
+            followed_unit: None,
+            focus: Vec2::ZERO,
+            base_translation: None,
+            pressed_keys: HashSet::new(),
+        }
+    }
+}
+
+impl CameraManager {
+    // used as a system
+    pub fn update_camera(
+        mut me: ResMut<Self>,
+        global: Res<Global>,
+        time: Res<Time>,
+        input: Res<Input>,
+        replay_manager: Res<ReplayManager>,
+        unit_q: Query<(Entity, &RenderPosition), With<AssetHandle<UnitData>>>,
+        mut camera_q: Query<(&mut Transform, &RenderLayer), With<Camera>>,
+    ) {
+        if global.owned_entity.is_some() {
+            // players keep the fixed overhead camera
+            return;
+        }
+
+        let scene_layer = RenderLayers::layer(0);
+        let Some((mut camera_transform, _)) = camera_q
+            .iter_mut()
+            .find(|(_, layer)| **layer == scene_layer)
+        else {
+            return;
+        };
+        let base_translation = *me
+            .base_translation
+            .get_or_insert(camera_transform.translation);
+
+        let toggle_mode = me.key_pressed(&input, Key::Tab);
+        let follow_prev = me.key_pressed(&input, Key::Q);
+        let follow_next = me.key_pressed(&input, Key::E);
+
+        if toggle_mode {
+            me.mode = match me.mode {
+                CameraMode::Free => CameraMode::FollowPlayer,
+                CameraMode::FollowPlayer => CameraMode::Free,
+            };
+            info!("camera mode: {:?}", me.mode);
+        }
+
+        match me.mode {
+            CameraMode::Free => {
+                let mut direction = Vec2::ZERO;
+                if input.is_pressed(Key::W) || input.is_pressed(Key::ArrowUp) {
+                    direction.y += 1.0;
+                }
+                if input.is_pressed(Key::S) || input.is_pressed(Key::ArrowDown) {
+                    direction.y -= 1.0;
+                }
+                if input.is_pressed(Key::A) || input.is_pressed(Key::ArrowLeft) {
+                    direction.x -= 1.0;
+                }
+                if input.is_pressed(Key::D) || input.is_pressed(Key::ArrowRight) {
+                    direction.x += 1.0;
+                }
+                me.focus +=
+                    direction.normalize_or_zero() * FREE_CAMERA_SPEED * time.get_elapsed_ms();
+            }
+            CameraMode::FollowPlayer => {
+                let mut players: Vec<(u64, Vec2)> = unit_q
+                    .iter()
+                    .map(|(entity, render_position)| {
+                        (entity.to_bits(), render_position.last_render_position())
+                    })
+                    .chain(replay_manager.unit_positions())
+                    .collect();
+                players.sort_by_key(|(unit, _)| *unit);
+
+                let current_index = me
+                    .followed_unit
+                    .and_then(|followed| players.iter().position(|(unit, _)| *unit == followed));
+                let next_index = match (current_index, players.len()) {
+                    (_, 0) => None,
+                    (None, _) => Some(0),
+                    (Some(index), count) if follow_next => Some((index + 1) % count),
+                    (Some(index), count) if follow_prev => Some((index + count - 1) % count),
+                    (Some(index), _) => Some(index),
+                };
+
+                me.followed_unit = next_index.map(|index| players[index].0);
+                if let Some(index) = next_index {
+                    me.focus = players[index].1;
+                }
+            }
+        }
+
+        camera_transform.translation = base_translation + Vec3::new(me.focus.x, me.focus.y, 0.0);
+    }
+
+    // used as a system, while in a match
+    pub fn leave_spectating(
+        mut me: ResMut<Self>,
+        global: Res<Global>,
+        input: Res<Input>,
+        mut connection_manager: ResMut<ConnectionManager>,
+        mut session_client: SessionClient,
+    ) {
+        if global.owned_entity.is_some() {
+            // players stay until the match ends
+            return;
+        }
+        if !me.key_pressed(&input, Key::Escape) {
+            return;
+        }
+
+        info!("leaving spectated match");
+
+        // the world server drops us once the session server has let the match know
+        connection_manager.leave_world();
+        let message = MatchLobbyLeaveSpectate;
+        session_client.send_message::<ClientActionsChannel, _>(&message);
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    // true only on the frame the key goes down
+    fn key_pressed(&mut self, input: &Input, key: Key) -> bool {
+        if input.is_pressed(key) {
+            self.pressed_keys.insert(key)
+        } else {
+            self.pressed_keys.remove(&key);
+            false
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use std::collections::{HashMap, VecDeque};
+
+use bevy_ecs::{entity::Entity, prelude::Resource};
+
+use game_engine::logging::{info, warn};
+
+use game_app_network::{
+    naia::{sequence_less_than, Tick},
+    world::behavior::MovementState,
+};
+
+// how many checksums to hold per unit while waiting for the other side's
+const MAX_PENDING_CHECKSUMS: usize = 8;
+// keep only the most recent reports around for inspection
+const MAX_REPORTS: usize = 32;
+
+pub struct DesyncReport {
+    pub entity: Entity,
+    pub tick: Tick,
+    pub server_checksum: u32,
+    pub client_checksum: u32,
+    pub client_state: MovementState,
+}
+
+#[derive(Default)]
+struct UnitChecksums {
+    server: VecDeque<(Tick, u32)>,
+    client: VecDeque<(Tick, MovementState)>,
+}
+
+// Compares the checksums the server sends for each unit against the client's confirmed
+// simulation of that unit at the same tick.
+#[derive(Resource, Default)]
+pub struct DesyncDetector {
+    units: HashMap<Entity, UnitChecksums>,
+    matched_count: u32,
+    desync_count: u32,
+    reports: VecDeque<DesyncReport>,
+}
+
+impl DesyncDetector {
+    pub fn recv_server_checksum(&mut self, entity: Entity, tick: Tick, checksum: u32) {
+        let unit = self.units.entry(entity).or_default();
+        push_bounded(&mut unit.server, (tick, checksum));
+        self.compare(entity);
+    }
+
+    pub fn record_client_state(&mut self, entity: Entity, tick: Tick, state: MovementState) {
+        let unit = self.units.entry(entity).or_default();
+        if let Some((_, recorded_state)) = unit
+            .client
+            .iter_mut()
+            .find(|(recorded_tick, _)| *recorded_tick == tick)
+        {
+            // a late update for an already-recorded tick replaces what was simulated for it
+            *recorded_state = state;
+        } else {
+            push_bounded(&mut unit.client, (tick, state));
+        }
+        self.compare(entity);
+    }
+
+    pub fn remove_unit(&mut self, entity: &Entity) {
+        self.units.remove(entity);
+    }
+
+    pub fn reset(&mut self) {
+        if self.matched_count > 0 || self.desync_count > 0 {
+            info!(
+                "desync detector: {:?} checksums matched, {:?} desyncs reported",
+                self.matched_count, self.desync_count
+            );
+        }
+        *self = Self::default();
+    }
+
+    pub fn reports(&self) -> impl Iterator<Item = &DesyncReport> {
+        self.reports.iter()
+    }
+
+    fn compare(&mut self, entity: Entity) {
+        let Some(unit) = self.units.get_mut(&entity) else {
+            return;
+        };
+
+        while let Some((server_tick, server_checksum)) = unit.server.front().copied() {
+            // drop client states the server will never report on
+            while unit
+                .client
+                .front()
+                .is_some_and(|(client_tick, _)| sequence_less_than(*client_tick, server_tick))
+            {
+                unit.client.pop_front();
+            }
+            let Some((client_tick, client_state)) = unit.client.front().copied() else {
+                // still waiting on the client to simulate this tick
+                return;
+            };
+            unit.server.pop_front();
+            if client_tick != server_tick {
+                // the client never simulated this tick
+                continue;
+            }
+            unit.client.pop_front();
+
+            let client_checksum = client_state.checksum();
+            if client_checksum == server_checksum {
+                self.matched_count += 1;
+                continue;
+            }
+
+            self.desync_count += 1;
+            let (tile_x, tile_y) = client_state.tile();
+            let (position_x, position_y) = client_state.position();
+            let (velocity_x, velocity_y) = client_state.velocity();
+            warn!(
+                "Desync! entity: {:?}, tick: {:?}, server checksum: {:x}, client checksum: {:x}, client tile: ({:?}, {:?}), position: ({:?}, {:?}), velocity: ({:?}, {:?})",
+                entity,
+                server_tick,
+                server_checksum,
+                client_checksum,
+                tile_x,
+                tile_y,
+                position_x,
+                position_y,
+                velocity_x,
+                velocity_y,
+            );
+
+            if self.reports.len() >= MAX_REPORTS {
+                self.reports.pop_front();
+            }
+            self.reports.push_back(DesyncReport {
+                entity,
+                tick: server_tick,
+                server_checksum,
+                client_checksum,
+                client_state,
+            });
+        }
+    }
+}
+
+fn push_bounded<T>(queue: &mut VecDeque<T>, value: T) {
+    if queue.len() >= MAX_PENDING_CHECKSUMS {
+        queue.pop_front();
+    }
+    queue.push_back(value);
+}
@@ -0,0 +1,147 @@
+use bevy_ecs::{
+    component::Component,
+    prelude::{Query, Resource, With},
+    system::{Res, ResMut},
+};
+
+use game_engine::{
+    math::{Vec2, Vec3},
+    render::{
+        base::{Color, CpuMaterial, CpuMesh, CpuTexture2D},
+        components::{
+            AmbientLight, Camera, CameraBundle, ClearOperation, RenderLayers, Transform, Viewport,
+        },
+        resources::RenderFrame,
+        shapes, Window,
+    },
+    storage::{Handle, Storage},
+};
+
+use game_app_network::world::constants::{TILE_COUNT, TILE_SIZE};
+
+// the minimap's copy of the tile world, seen only by its camera
+pub(crate) const MINIMAP_LAYER: usize = 1;
+// drawn after the world, but before the ui & debug overlay
+const OVERLAY_LAYER: usize = 3;
+
+const WORLD_SIZE: f32 = TILE_SIZE * (TILE_COUNT * 2 + 1) as f32;
+// of the window's height
+const SCREEN_FRACTION: f32 = 0.25;
+const MARGIN: f32 = 8.0;
+const MARKER_SCALE: f32 = TILE_SIZE * 0.2;
+
+#[derive(Component)]
+pub struct MinimapCamera;
+
+// A top-down view of the tile world, rendered into a texture and shown in the top-right corner.
+// The texture is kept at the size it is shown at, so it stays sharp as the window resizes.
+#[derive(Resource, Default)]
+pub struct Minimap {
+    texture: Option<Handle<CpuTexture2D>>,
+    texture_material: Option<Handle<CpuMaterial>>,
+    quad_mesh: Option<Handle<CpuMesh>>,
+    marker_mesh: Option<Handle<CpuMesh>>,
+    marker_material: Option<Handle<CpuMaterial>>,
+}
+
+impl Minimap {
+    // the texture lives across matches, so it is only created once
+    pub(crate) fn setup(
+        &mut self,
+        meshes: &mut Storage<CpuMesh>,
+        materials: &mut Storage<CpuMaterial>,
+        textures: &mut Storage<CpuTexture2D>,
+    ) -> Handle<CpuTexture2D> {
+        let texture = *self
+            .texture
+            .get_or_insert_with(|| textures.add_unique(CpuTexture2D::from_size(1, 1)));
+        self.texture_material = Some(materials.add(CpuMaterial::default().with_texture(texture)));
+        self.quad_mesh = Some(meshes.add(shapes::UnitSquare));
+        self.marker_mesh = Some(meshes.add(shapes::CenteredSquare));
+        self.marker_material = Some(materials.add(Color::LIGHT_GREEN));
+        texture
+    }
+
+    // world units per pixel of a minimap `size` pixels across
+    pub(crate) fn camera_scale(size: u32) -> Vec3 {
+        Vec3::splat(WORLD_SIZE / size as f32)
+    }
+
+    pub(crate) fn draw_marker(&self, render_frame: &mut RenderFrame, position: Vec2) {
+        let (Some(mesh), Some(material)) = (&self.marker_mesh, &self.marker_material) else {
+            return;
+        };
+        let layer = RenderLayers::layer(MINIMAP_LAYER);
+        let transform = Transform::from_xyz(position.x, position.y, 1.0).with_scale(Vec3::new(
+            MARKER_SCALE,
+            MARKER_SCALE,
+            1.0,
+        ));
+        render_frame.draw_mesh(Some(&layer), mesh, material, &transform);
+    }
+
+    fn size(window_height: u32) -> u32 {
+        ((window_height as f32 * SCREEN_FRACTION) as u32).max(1)
+    }
+
+    // used as a system
+    pub fn update(
+        me: Res<Self>,
+        window: Res<Window>,
+        mut textures: ResMut<Storage<CpuTexture2D>>,
+        mut camera_q: Query<(&mut Camera, &mut Transform), With<MinimapCamera>>,
+    ) {
+        let Some(texture) = me.texture else {
+            return;
+        };
+        let Some(window_res) = window.get() else {
+            return;
+        };
+
+        let size = Self::size(window_res.logical_size.height);
+        if textures.get(&texture).unwrap().width() != size {
+            textures.set(&texture, CpuTexture2D::from_size(size, size));
+        }
+
+        // a new match's camera starts out unsized, even if the texture already is
+        let viewport = Viewport::new_at_origin(size, size);
+        for (mut camera, mut transform) in camera_q.iter_mut() {
+            if camera.viewport != Some(viewport) {
+                camera.viewport = Some(viewport);
+                transform.scale = Self::camera_scale(size);
+            }
+        }
+    }
+
+    // used as a system
+    pub fn draw(me: Res<Self>, window: Res<Window>, mut render_frame: ResMut<RenderFrame>) {
+        let (Some(mesh), Some(material)) = (&me.quad_mesh, &me.texture_material) else {
+            return;
+        };
+        let Some(window_res) = window.get() else {
+            return;
+        };
+
+        let layer = RenderLayers::layer(OVERLAY_LAYER);
+        let mut camera_bundle = CameraBundle::new_2d(&window_res.logical_size);
+        camera_bundle.camera.clear_operation = ClearOperation {
+            red: None,
+            green: None,
+            blue: None,
+            alpha: None,
+            depth: Some(1.0),
+        };
+        render_frame.draw_camera(
+            Some(&layer),
+            &camera_bundle.camera,
+            &camera_bundle.transform,
+            &camera_bundle.projection,
+        );
+        render_frame.draw_ambient_light(Some(&layer), &AmbientLight::new(1.0, Color::WHITE));
+
+        let size = Self::size(window_res.logical_size.height) as f32;
+        let x = window_res.logical_size.width as f32 - MARGIN - size;
+        let transform = Transform::from_xyz(x, MARGIN, 1.0).with_scale(Vec3::new(size, size, 1.0));
+        render_frame.draw_mesh(Some(&layer), mesh, material, &transform);
+    }
+}
@@ -1,6 +1,12 @@
 mod global;
 pub use global::*;
 
+mod camera_manager;
+pub use camera_manager::*;
+
+mod replay_manager;
+pub use replay_manager::*;
+
 mod input_manager;
 pub use input_manager::*;
 
@@ -10,5 +16,14 @@ pub use rollback::*;
 mod tick_tracker;
 pub use tick_tracker::*;
 
+mod desync_detector;
+pub use desync_detector::*;
+
+mod minimap;
+pub use minimap::*;
+
+mod network_debug_overlay;
+pub use network_debug_overlay::*;
+
 mod predicted_world;
 pub use predicted_world::*;
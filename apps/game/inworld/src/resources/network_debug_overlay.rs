@@ -0,0 +1,188 @@
+use bevy_ecs::{
+    prelude::Resource,
+    system::{Res, ResMut},
+};
+
+use game_engine::{
+    asset::{AssetManager, AssetRender},
+    input::{Input, Key},
+    math::Vec3,
+    render::{
+        base::{Color, CpuMaterial},
+        components::{AmbientLight, CameraBundle, ClearOperation, RenderLayer, Transform},
+        resources::{RenderFrame, RenderStats, Time},
+        Window,
+    },
+    storage::{Handle, Storage},
+    ui::UiManager,
+};
+
+use game_app_network::{
+    naia::wrapping_diff,
+    world::{constants::MISPREDICTION_CORRECTION_DURATION_MS, WorldClient},
+};
+
+use crate::resources::{RollbackManager, TickTracker};
+
+// stats are re-sampled this often
+const SAMPLE_INTERVAL_MS: f32 = 1000.0;
+const LINE_HEIGHT: f32 = 18.0;
+const LINE_WIDTH: f32 = 600.0;
+const MARGIN: f32 = 8.0;
+
+// Shows connection & prediction stats over the game, toggled with the grave (`) key.
+#[derive(Resource)]
+pub struct NetworkDebugOverlay {
+    enabled: bool,
+    toggle_held: bool,
+
+    elapsed_ms: f32,
+    sampled_rollback_count: u32,
+    rollbacks_per_second: f32,
+    max_correction: f32,
+
+    text_material: Option<Handle<CpuMaterial>>,
+    lines: Vec<String>,
+}
+
+impl Default for NetworkDebugOverlay {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            toggle_held: false,
+
+            elapsed_ms: 0.0,
+            sampled_rollback_count: 0,
+            rollbacks_per_second: 0.0,
+            max_correction: 0.0,
+
+            text_material: None,
+            lines: Vec::new(),
+        }
+    }
+}
+
+impl NetworkDebugOverlay {
+    // used as a system
+    pub fn update(
+        mut me: ResMut<Self>,
+        client: WorldClient,
+        time: Res<Time>,
+        input: Res<Input>,
+        rollback_manager: Res<RollbackManager>,
+        tick_tracker: Res<TickTracker>,
+        render_stats: Res<RenderStats>,
+        mut materials: ResMut<Storage<CpuMaterial>>,
+    ) {
+        let toggle_pressed = input.is_pressed(Key::Grave);
+        if toggle_pressed && !me.toggle_held {
+            me.enabled = !me.enabled;
+        }
+        me.toggle_held = toggle_pressed;
+
+        if !me.enabled {
+            return;
+        }
+        if me.text_material.is_none() {
+            me.text_material = Some(materials.add(Color::WHITE));
+        }
+
+        me.max_correction = me
+            .max_correction
+            .max(rollback_manager.last_correction_magnitude());
+        me.elapsed_ms += time.get_elapsed_ms();
+        if me.elapsed_ms >= SAMPLE_INTERVAL_MS {
+            let rollback_count = rollback_manager.rollback_count();
+            let new_rollbacks = rollback_count.wrapping_sub(me.sampled_rollback_count);
+            me.rollbacks_per_second = new_rollbacks as f32 * 1000.0 / me.elapsed_ms;
+            me.sampled_rollback_count = rollback_count;
+            me.elapsed_ms = 0.0;
+
+            let max_correction = std::mem::take(&mut me.max_correction);
+            me.lines = vec![
+                format!(
+                    "rtt: {:.0}ms, jitter: {:.0}ms",
+                    client.rtt(),
+                    client.jitter()
+                ),
+                match (
+                    client.client_tick(),
+                    tick_tracker.last_processed_server_tick(),
+                ) {
+                    (Some(client_tick), Some(server_tick)) => format!(
+                        "tick lead: {} (client {}, server {})",
+                        wrapping_diff(server_tick, client_tick),
+                        client_tick,
+                        server_tick
+                    ),
+                    _ => "tick lead: -".to_string(),
+                },
+                format!("rollbacks: {:.1}/s", me.rollbacks_per_second),
+                format!(
+                    "max correction: {:.1} over {}ms",
+                    max_correction, MISPREDICTION_CORRECTION_DURATION_MS
+                ),
+                {
+                    let render_total = render_stats.total();
+                    format!(
+                        "meshes: {} drawn, {} culled",
+                        render_total.drawn, render_total.culled
+                    )
+                },
+            ];
+        }
+    }
+
+    // used as a system
+    pub fn draw(
+        me: Res<Self>,
+        window: Res<Window>,
+        ui_manager: Res<UiManager>,
+        asset_manager: Res<AssetManager>,
+        mut render_frame: ResMut<RenderFrame>,
+    ) {
+        if !me.enabled {
+            return;
+        }
+        let Some(text_material) = me.text_material.as_ref() else {
+            return;
+        };
+        let Some(text_icon_handle) = ui_manager.get_text_icon_handle() else {
+            return;
+        };
+        let Some(window_res) = window.get() else {
+            return;
+        };
+
+        // the ui is disabled in-world, so its layer is free for the overlay
+        let layer = RenderLayer::UI;
+        let mut camera_bundle = CameraBundle::new_2d(&window_res.logical_size);
+        camera_bundle.camera.clear_operation = ClearOperation {
+            red: None,
+            green: None,
+            blue: None,
+            alpha: None,
+            depth: Some(1.0),
+        };
+        render_frame.draw_camera(
+            Some(&layer),
+            &camera_bundle.camera,
+            &camera_bundle.transform,
+            &camera_bundle.projection,
+        );
+        render_frame.draw_ambient_light(Some(&layer), &AmbientLight::new(1.0, Color::WHITE));
+
+        for (index, line) in me.lines.iter().enumerate() {
+            let transform = Transform::from_xyz(MARGIN, MARGIN + index as f32 * LINE_HEIGHT, 1.0)
+                .with_scale(Vec3::new(LINE_WIDTH, LINE_HEIGHT, 1.0));
+            asset_manager.draw_text(
+                &mut render_frame,
+                Some(&layer),
+                text_icon_handle,
+                text_material,
+                &transform,
+                line,
+            );
+        }
+    }
+}
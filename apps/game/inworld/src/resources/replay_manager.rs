@@ -0,0 +1,447 @@
+use std::collections::{HashMap, HashSet};
+
+use bevy_ecs::{
+    entity::Entity,
+    prelude::{Commands, Query, Resource},
+    system::{Res, ResMut},
+};
+use bevy_state::state::NextState;
+
+use game_engine::{
+    asset::{AssetHandle, AssetId, AssetManager, AssetRender, UnitData},
+    filesystem::{FileSystemManager, ReadResult, TaskKey},
+    input::{Input, Key},
+    kernel::get_querystring_param,
+    logging::{info, warn},
+    math::{Quat, Vec2, Vec3},
+    render::{
+        base::{CpuMaterial, CpuMesh, CpuTexture2D},
+        components::{RenderLayer, RenderLayers, Transform},
+        resources::{RenderFrame, Time},
+    },
+    storage::Storage,
+    ui::UiManager,
+};
+
+use game_app_common::AppState;
+use game_app_network::{
+    naia::Tick,
+    world::{
+        behavior as shared_behavior,
+        components::{
+            MoveBuffer, NetworkedLookDir, NetworkedTileTarget, PhysicsController, TileMovement,
+            TileMovementType,
+        },
+        constants::TICK_INTERVAL_MS,
+        messages::PlayerCommands,
+        recording::{to_player_commands, MatchRecording, RecordedEvent, RecordedUnitId},
+        types::Direction,
+    },
+};
+
+use crate::{
+    components::AnimationState,
+    resources::{CameraManager, Minimap, MINIMAP_LAYER},
+    systems::scene_setup,
+};
+
+const TICK_MS: f32 = TICK_INTERVAL_MS as f32;
+// how far the seek keys jump, in ticks
+const SEEK_TICKS: u32 = 5000 / TICK_INTERVAL_MS as u32;
+const MIN_SPEED: f32 = 0.25;
+const MAX_SPEED: f32 = 8.0;
+
+struct ReplayUnit {
+    unit_handle: AssetHandle<UnitData>,
+    tile_movement: TileMovement,
+    move_buffer: MoveBuffer,
+    physics: PhysicsController,
+    look_dir: NetworkedLookDir,
+    animation_state: AnimationState,
+    held: bool,
+    // position before the latest tick, rendering interpolates from it
+    previous_position: Vec2,
+}
+
+impl ReplayUnit {
+    fn new(asset_id: AssetId, tile_x: i16, tile_y: i16) -> Self {
+        let net_tile_target = NetworkedTileTarget::new(tile_x, tile_y);
+        let physics = PhysicsController::new(&net_tile_target);
+        let previous_position = physics.position();
+
+        Self {
+            unit_handle: AssetHandle::<UnitData>::new(asset_id),
+            tile_movement: TileMovement::new_stopped(&net_tile_target),
+            move_buffer: MoveBuffer::new(),
+            physics,
+            look_dir: NetworkedLookDir::new(Direction::East),
+            animation_state: AnimationState::new(),
+            held: false,
+            previous_position,
+        }
+    }
+
+    fn render_position(&self, alpha: f32) -> Vec2 {
+        self.previous_position.lerp(self.physics.position(), alpha)
+    }
+}
+
+// ReplayManager
+// Plays a match recording back offline, re-simulating every unit with the same shared tick
+// behavior the world server ran. Space pauses, Comma / Period seek, Minus / Equals change the
+// playback speed and Escape returns to the main menu.
+//
+// A recording is picked with the `replay` querystring param, or the `CYBERLITH_REPLAY`
+// environment variable on native. Unit assets already in the local asset cache are drawn,
+// others are still simulated, on the default movement config.
+#[derive(Resource)]
+pub struct ReplayManager {
+    requested_path: Option<String>,
+    read_task: Option<TaskKey<ReadResult>>,
+
+    recording: Option<MatchRecording>,
+    // the next tick to simulate
+    tick: u32,
+    // index of the next recorded tick whose events have not been applied
+    event_cursor: usize,
+    units: HashMap<RecordedUnitId, ReplayUnit>,
+
+    paused: bool,
+    speed: f32,
+    // playback time not yet simulated
+    accumulator_ms: f32,
+    pressed_keys: HashSet<Key>,
+}
+
+impl Default for ReplayManager {
+    fn default() -> Self {
+        let requested_path =
+            get_querystring_param("replay").or_else(|| std::env::var("CYBERLITH_REPLAY").ok());
+
+        Self {
+            requested_path,
+            read_task: None,
+
+            recording: None,
+            tick: 0,
+            event_cursor: 0,
+            units: HashMap::new(),
+
+            paused: false,
+            speed: 1.0,
+            accumulator_ms: 0.0,
+            pressed_keys: HashSet::new(),
+        }
+    }
+}
+
+impl ReplayManager {
+    // used as a system, while in the main menu
+    pub fn load_requested_replay(
+        mut me: ResMut<Self>,
+        mut commands: Commands,
+        mut file_system_manager: ResMut<FileSystemManager>,
+        mut next_state: ResMut<NextState<AppState>>,
+        mut meshes: ResMut<Storage<CpuMesh>>,
+        mut materials: ResMut<Storage<CpuMaterial>>,
+        mut textures: ResMut<Storage<CpuTexture2D>>,
+        mut minimap: ResMut<Minimap>,
+        mut ui_manager: ResMut<UiManager>,
+        render_layer_q: Query<(Entity, &RenderLayer)>,
+    ) {
+        if let Some(path) = me.requested_path.take() {
+            info!("loading match recording: {:?}", path);
+            me.read_task = Some(file_system_manager.read(path));
+        }
+
+        let Some(read_task) = me.read_task.as_ref() else {
+            return;
+        };
+        let Some(result) = file_system_manager.get_result(read_task) else {
+            return;
+        };
+        me.read_task = None;
+
+        let bytes = match result {
+            Ok(ReadResult { bytes }) => bytes,
+            Err(e) => {
+                warn!("failed to read match recording: {:?}", e.to_string());
+                return;
+            }
+        };
+        let Ok(recording) = MatchRecording::from_bytes(&bytes) else {
+            warn!("failed to parse match recording");
+            return;
+        };
+
+        info!("starting replay of {:?} ticks", recording.tick_count());
+        me.recording = Some(recording);
+        me.restart();
+        me.paused = false;
+        me.speed = 1.0;
+
+        // swap the main menu scene for the world's
+        let render_layer_0 = RenderLayers::layer(0);
+        for (entity, layer) in render_layer_q.iter() {
+            if *layer == render_layer_0 {
+                commands.entity(entity).despawn();
+            }
+        }
+        scene_setup::scene_setup(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            &mut textures,
+            &mut minimap,
+        );
+        ui_manager.disable_ui();
+
+        next_state.set(AppState::Replay);
+    }
+
+    // used as a system, while replaying
+    pub fn step(
+        mut me: ResMut<Self>,
+        mut commands: Commands,
+        time: Res<Time>,
+        input: Res<Input>,
+        asset_manager: Res<AssetManager>,
+        mut camera_manager: ResMut<CameraManager>,
+        mut next_state: ResMut<NextState<AppState>>,
+        render_layer_q: Query<(Entity, &RenderLayer)>,
+    ) {
+        if me.key_pressed(&input, Key::Escape) {
+            info!("leaving replay");
+
+            let render_layer_0 = RenderLayers::layer(0);
+            let minimap_layer = RenderLayers::layer(MINIMAP_LAYER);
+            for (entity, layer) in render_layer_q.iter() {
+                if *layer == render_layer_0 || *layer == minimap_layer {
+                    commands.entity(entity).despawn();
+                }
+            }
+            me.recording = None;
+            me.restart();
+            camera_manager.reset();
+
+            next_state.set(AppState::MainMenu);
+            return;
+        }
+
+        if me.key_pressed(&input, Key::Space) {
+            me.paused = !me.paused;
+            info!("replay paused: {:?}", me.paused);
+        }
+        if me.key_pressed(&input, Key::Equals) {
+            me.speed = (me.speed * 2.0).min(MAX_SPEED);
+            info!("replay speed: {:?}", me.speed);
+        }
+        if me.key_pressed(&input, Key::Minus) {
+            me.speed = (me.speed * 0.5).max(MIN_SPEED);
+            info!("replay speed: {:?}", me.speed);
+        }
+        if me.key_pressed(&input, Key::Period) {
+            let target_tick = me.tick + SEEK_TICKS;
+            me.seek(&asset_manager, target_tick);
+        }
+        if me.key_pressed(&input, Key::Comma) {
+            let target_tick = me.tick.saturating_sub(SEEK_TICKS);
+            me.seek(&asset_manager, target_tick);
+        }
+
+        if me.paused {
+            return;
+        }
+        let Some(tick_count) = me
+            .recording
+            .as_ref()
+            .map(|recording| recording.tick_count())
+        else {
+            return;
+        };
+
+        me.accumulator_ms += time.get_elapsed_ms() * me.speed;
+        while me.accumulator_ms >= TICK_MS {
+            if me.tick >= tick_count {
+                info!("replay finished");
+                me.paused = true;
+                me.accumulator_ms = 0.0;
+                break;
+            }
+            me.accumulator_ms -= TICK_MS;
+            me.simulate_tick(&asset_manager);
+        }
+    }
+
+    // used as a system, while replaying
+    pub fn draw_units(
+        me: Res<Self>,
+        asset_manager: Res<AssetManager>,
+        minimap: Res<Minimap>,
+        mut render_frame: ResMut<RenderFrame>,
+    ) {
+        let layer = RenderLayers::layer(0);
+        let alpha = me.interpolation();
+        let mut transform = Transform::default();
+
+        for unit in me.units.values() {
+            let Some(animated_model_handle) =
+                asset_manager.get_unit_animated_model_handle(&unit.unit_handle)
+            else {
+                continue;
+            };
+
+            let position = unit.render_position(alpha);
+            transform.translation.x = position.x;
+            transform.translation.y = position.y;
+            transform.set_scale(Vec3::new(1.0, 1.0, 1.0));
+            transform.set_rotation(Quat::from_rotation_z(unit.animation_state.rotation));
+
+            asset_manager.draw_blended_animated_model(
+                &mut render_frame,
+                animated_model_handle,
+                &unit.animation_state.blend_layers(),
+                &transform,
+                Some(&layer),
+            );
+            minimap.draw_marker(&mut render_frame, position);
+        }
+    }
+
+    // positions of the replayed units, keyed by their recorded id
+    pub(crate) fn unit_positions(&self) -> Vec<(u64, Vec2)> {
+        let alpha = self.interpolation();
+        self.units
+            .iter()
+            .map(|(unit_id, unit)| (*unit_id as u64, unit.render_position(alpha)))
+            .collect()
+    }
+
+    fn interpolation(&self) -> f32 {
+        (self.accumulator_ms / TICK_MS).clamp(0.0, 1.0)
+    }
+
+    fn restart(&mut self) {
+        self.tick = 0;
+        self.event_cursor = 0;
+        self.units.clear();
+        self.accumulator_ms = 0.0;
+    }
+
+    // the simulation only runs forwards, so seeking back re-simulates from the start
+    fn seek(&mut self, asset_manager: &AssetManager, target_tick: u32) {
+        let Some(tick_count) = self
+            .recording
+            .as_ref()
+            .map(|recording| recording.tick_count())
+        else {
+            return;
+        };
+        let target_tick = target_tick.min(tick_count);
+        if target_tick < self.tick {
+            self.restart();
+        }
+        while self.tick < target_tick {
+            self.simulate_tick(asset_manager);
+        }
+        self.accumulator_ms = 0.0;
+        info!("replay seeked to tick {:?} / {:?}", self.tick, tick_count);
+    }
+
+    fn simulate_tick(&mut self, asset_manager: &AssetManager) {
+        let Some(recording) = self.recording.as_ref() else {
+            return;
+        };
+
+        // apply this tick's events
+        let mut commands: HashMap<RecordedUnitId, PlayerCommands> = HashMap::new();
+        while let Some(recorded_tick) = recording.ticks().get(self.event_cursor) {
+            if recorded_tick.offset != self.tick {
+                break;
+            }
+            for event in recorded_tick.events.iter() {
+                match event {
+                    RecordedEvent::Spawn(unit_id, asset_id, tile_x, tile_y, look_dir) => {
+                        let mut unit = ReplayUnit::new(*asset_id, *tile_x, *tile_y);
+                        unit.look_dir.set(*look_dir);
+                        unit.animation_state.recv_lookdir_update(look_dir);
+                        self.units.insert(*unit_id, unit);
+                    }
+                    RecordedEvent::Despawn(unit_id) => {
+                        self.units.remove(unit_id);
+                    }
+                    RecordedEvent::Hold(unit_id) => {
+                        if let Some(unit) = self.units.get_mut(unit_id) {
+                            unit.held = true;
+                        }
+                    }
+                    RecordedEvent::Resume(unit_id) => {
+                        if let Some(unit) = self.units.get_mut(unit_id) {
+                            unit.held = false;
+                        }
+                    }
+                    RecordedEvent::Command(unit_id, look_opt, move_opt) => {
+                        commands.insert(*unit_id, to_player_commands(*look_opt, *move_opt));
+                    }
+                }
+            }
+            self.event_cursor += 1;
+        }
+
+        // simulate every unit, as the world server did
+        let tick = self.tick as Tick;
+        for (unit_id, unit) in self.units.iter_mut() {
+            unit.previous_position = unit.physics.position();
+            if unit.held {
+                continue;
+            }
+
+            if let Some(movement_config) = asset_manager.get_unit_movement_config(&unit.unit_handle)
+            {
+                unit.physics
+                    .set_movement_config(movement_config.get_movement_config());
+            }
+
+            shared_behavior::process_tick(
+                TileMovementType::Server,
+                tick,
+                commands.remove(unit_id),
+                &mut unit.tile_movement,
+                &mut unit.physics,
+                &mut unit.move_buffer,
+                Some(&mut unit.look_dir),
+                None,
+            );
+
+            if let Some(animated_model_handle) =
+                asset_manager.get_unit_animated_model_handle(&unit.unit_handle)
+            {
+                unit.animation_state.update(
+                    asset_manager,
+                    animated_model_handle,
+                    unit.physics.position(),
+                    unit.physics.velocity(),
+                    unit.physics.last_acceleration(),
+                    TICK_MS,
+                    &unit.tile_movement,
+                );
+                // replay units aren't entities, nothing listens for their frame events
+                unit.animation_state.take_events();
+            }
+            unit.animation_state
+                .recv_lookdir_update(&unit.look_dir.get());
+        }
+
+        self.tick += 1;
+    }
+
+    // true only on the frame the key goes down
+    fn key_pressed(&mut self, input: &Input, key: Key) -> bool {
+        if input.is_pressed(key) {
+            self.pressed_keys.insert(key)
+        } else {
+            self.pressed_keys.remove(&key);
+            false
+        }
+    }
+}
@@ -31,15 +31,31 @@ use crate::{
 #[derive(Resource)]
 pub struct RollbackManager {
     events: Option<Tick>,
+    // totals since startup, sampled by the network debug overlay
+    rollback_count: u32,
+    last_correction_magnitude: f32,
 }
 
 impl Default for RollbackManager {
     fn default() -> Self {
-        Self { events: None }
+        Self {
+            events: None,
+            rollback_count: 0,
+            last_correction_magnitude: 0.0,
+        }
     }
 }
 
 impl RollbackManager {
+    pub fn rollback_count(&self) -> u32 {
+        self.rollback_count
+    }
+
+    // the largest misprediction corrected by the most recent rollback, in world units
+    pub fn last_correction_magnitude(&self) -> f32 {
+        self.last_correction_magnitude
+    }
+
     pub fn add_events(&mut self, events: HashMap<Entity, Tick>) {
         for (_, tick) in events {
             self.add_event(tick);
@@ -70,6 +86,7 @@ impl RollbackManager {
             let owned_entity_opt = global.owned_entity;
 
             warn!("ROLLBACK! (Tick: {:?})", server_tick);
+            me.rollback_count += 1;
 
             // info!(
             //     "Update received for Server Tick: {:?} (which is 1 less than came through in update event)",
@@ -129,7 +146,7 @@ impl RollbackManager {
             (current_tick, owned_entity_opt)
         };
 
-        main_world.resource_scope(
+        let correction_magnitude = main_world.resource_scope(
             |main_world: &mut World, mut predicted_world: Mut<PredictedWorld>| {
                 // ROLLBACK CLIENT: Replay all stored commands
 
@@ -172,9 +189,17 @@ impl RollbackManager {
 
                     //
                     for (entity, unit_handle, last_command) in unit_q.iter() {
-                        let animated_model_handle = asset_manager
-                            .get_unit_animated_model_handle(unit_handle)
-                            .unwrap();
+                        // units are only simulated once their assets have loaded, as in the tick
+                        let Some(animated_model_handle) =
+                            asset_manager.get_unit_animated_model_handle(unit_handle)
+                        else {
+                            continue;
+                        };
+                        let Some(movement_config) =
+                            asset_manager.get_unit_movement_config(unit_handle)
+                        else {
+                            continue;
+                        };
 
                         let (
                             mut predicted_tile_movement,
@@ -206,17 +231,20 @@ impl RollbackManager {
                             &mut predicted_render_position,
                             &mut predicted_animation_state,
                             &animated_model_handle,
+                            movement_config,
                         );
                     }
                 }
                 warn!("---");
 
+                let mut correction_magnitude: f32 = 0.0;
                 for (entity, _, _) in unit_q.iter() {
                     let (_, _, mut predicted_render_position, _) =
                         predicted_unit_q.get_mut(entity).unwrap();
                     // predicted_render_position.prediction_error() here prunes the queue, maybe refactor?
                     let prediction_error =
                         predicted_render_position.current_offset_from_last_render(&client);
+                    correction_magnitude = correction_magnitude.max(prediction_error.length());
                     if prediction_error.length() > 0.0 {
                         predicted_render_position.add_error_interpolation(
                             prediction_error,
@@ -224,7 +252,10 @@ impl RollbackManager {
                         );
                     }
                 }
+                correction_magnitude
             },
         );
+
+        main_world.resource_mut::<Self>().last_correction_magnitude = correction_magnitude;
     }
 }
@@ -0,0 +1,14 @@
+use bevy_ecs::{entity::Entity, event::EventWriter, prelude::Query};
+
+use crate::{components::AnimationState, events::AnimationEvent};
+
+pub fn send_animation_events(
+    mut unit_q: Query<(Entity, &mut AnimationState)>,
+    mut event_writer: EventWriter<AnimationEvent>,
+) {
+    for (entity, mut animation_state) in unit_q.iter_mut() {
+        for name in animation_state.take_events() {
+            event_writer.send(AnimationEvent { entity, name });
+        }
+    }
+}
@@ -1,3 +1,5 @@
+pub mod animation;
+pub mod particles;
 pub mod render;
 pub mod scene_setup;
 pub mod world_events;
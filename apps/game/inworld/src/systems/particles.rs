@@ -0,0 +1,60 @@
+use bevy_ecs::{
+    entity::Entity,
+    prelude::{Commands, Query},
+    query::{With, Without},
+    system::{Local, Res, ResMut},
+};
+
+use game_engine::{
+    asset::{AssetHandle, AssetManager, UnitData},
+    render::{
+        base::{Color, CpuMaterial, CpuMesh},
+        components::{ParticleEmitter, Transform},
+        shapes,
+    },
+    storage::{Handle, Storage},
+};
+
+use crate::components::RenderPosition;
+
+// gives each unit whose spec names a particle effect an emitter, once the effect has loaded
+pub fn spawn_unit_particle_emitters(
+    mut commands: Commands,
+    asset_manager: Res<AssetManager>,
+    mut meshes: ResMut<Storage<CpuMesh>>,
+    mut materials: ResMut<Storage<CpuMaterial>>,
+    mut particle_handles: Local<Option<(Handle<CpuMesh>, Handle<CpuMaterial>)>>,
+    unit_q: Query<
+        (Entity, &AssetHandle<UnitData>),
+        (With<RenderPosition>, Without<ParticleEmitter>),
+    >,
+) {
+    for (entity, unit_handle) in unit_q.iter() {
+        let Some(settings) = asset_manager.get_unit_particle_settings(unit_handle) else {
+            continue;
+        };
+        // particles are tinted by their own color, so every emitter can share these
+        let (mesh, material) = *particle_handles.get_or_insert_with(|| {
+            (
+                meshes.add(shapes::CenteredSquare),
+                materials.add(Color::WHITE),
+            )
+        });
+
+        let mut emitter = ParticleEmitter::new(settings, mesh, material).with_seed(entity.index());
+        emitter.emitting = false;
+        commands.entity(entity).insert(emitter);
+    }
+}
+
+// emitters follow their unit's rendered position, and only emit while it moves
+pub fn update_unit_particle_emitters(
+    mut unit_q: Query<(&RenderPosition, &mut Transform, &mut ParticleEmitter)>,
+) {
+    for (render_position, mut transform, mut emitter) in unit_q.iter_mut() {
+        let position = render_position.last_render_position();
+        emitter.emitting = position != transform.translation.truncate();
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+    }
+}
@@ -16,13 +16,14 @@ use game_app_network::world::WorldClient;
 
 use crate::{
     components::{AnimationState, RenderPosition},
-    resources::PredictedWorld,
+    resources::{Minimap, PredictedWorld},
 };
 
 pub fn draw_units(
     time: Res<Time>,
     client: WorldClient,
     asset_manager: Res<AssetManager>,
+    minimap: Res<Minimap>,
     mut render_frame: ResMut<RenderFrame>,
     mut predicted_world: ResMut<PredictedWorld>,
     mut unit_q: Query<(
@@ -75,14 +76,15 @@ pub fn draw_units(
             transform.set_scale(Vec3::new(1.0, 1.0, 1.0));
             transform.set_rotation(Quat::from_rotation_z(predicted_anim_state.rotation));
 
-            asset_manager.draw_animated_model(
-                &mut render_frame,
-                animated_model_handle,
-                &confirmed_anim_state.animation_name,
-                &transform,
-                confirmed_anim_state.animation_index_ms,
-                render_layer_opt,
-            );
+            render_frame.draw_pickable(entity, |render_frame| {
+                asset_manager.draw_blended_animated_model(
+                    render_frame,
+                    animated_model_handle,
+                    &confirmed_anim_state.blend_layers(),
+                    &transform,
+                    render_layer_opt,
+                );
+            });
         }
 
         // draw predicted model
@@ -94,14 +96,17 @@ pub fn draw_units(
             transform.set_scale(Vec3::new(1.0, 1.0, 1.0));
             transform.set_rotation(Quat::from_rotation_z(predicted_anim_state.rotation));
 
-            asset_manager.draw_animated_model(
-                &mut render_frame,
-                animated_model_handle,
-                &predicted_anim_state.animation_name,
-                &transform,
-                predicted_anim_state.animation_index_ms,
-                render_layer_opt,
-            );
+            render_frame.draw_pickable(entity, |render_frame| {
+                asset_manager.draw_blended_animated_model(
+                    render_frame,
+                    animated_model_handle,
+                    &predicted_anim_state.blend_layers(),
+                    &transform,
+                    render_layer_opt,
+                );
+            });
+
+            minimap.draw_marker(&mut render_frame, interp_position);
         }
 
         // if predicted_opt.is_some() {
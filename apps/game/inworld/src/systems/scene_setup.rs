@@ -3,11 +3,11 @@ use bevy_ecs::system::Commands;
 use game_engine::{
     math::Vec3,
     render::{
-        base::{Color, CpuMaterial, CpuMesh},
+        base::{Color, CpuMaterial, CpuMesh, CpuTexture2D},
         components::{
             AmbientLight, Camera, CameraBundle, ClearOperation, DirectionalLight,
             OrthographicProjection, Projection, RenderLayers, RenderObjectBundle, RenderTarget,
-            Transform,
+            Transform, Viewport,
         },
         shapes,
     },
@@ -16,14 +16,19 @@ use game_engine::{
 
 use game_app_network::world::constants::{TILE_COUNT, TILE_SIZE};
 
+use crate::resources::{Minimap, MinimapCamera, MINIMAP_LAYER};
+
 const TILE_SCALE: f32 = (TILE_SIZE * 0.5) - 5.0;
 
 pub fn scene_setup(
     commands: &mut Commands,
     meshes: &mut Storage<CpuMesh>,
     materials: &mut Storage<CpuMaterial>,
+    textures: &mut Storage<CpuTexture2D>,
+    minimap: &mut Minimap,
 ) {
     let layer = RenderLayers::layer(0);
+    let minimap_layer = RenderLayers::layer(MINIMAP_LAYER);
 
     // spawn grid of floor tiles
 
@@ -44,6 +49,16 @@ pub fn scene_setup(
                     ..Default::default()
                 })
                 .insert(layer);
+
+            commands
+                .spawn(RenderObjectBundle {
+                    mesh: meshes.add(shapes::CenteredSquare),
+                    material: materials.add(Color::GRAY),
+                    transform: Transform::from_scale(Vec3::new(TILE_SCALE, TILE_SCALE, 1.0))
+                        .with_translation(Vec3::new(x, y, 0.0)),
+                    ..Default::default()
+                })
+                .insert(minimap_layer);
         }
     }
 
@@ -56,11 +71,7 @@ pub fn scene_setup(
     let light_source = Vec3::new(-500.0, 500.0, 200.0);
     let light_target = Vec3::ZERO;
     commands
-        .spawn(DirectionalLight::new(
-            2.0,
-            Color::WHITE,
-            light_target - light_source,
-        ))
+        .spawn(DirectionalLight::new(2.0, Color::WHITE, light_target - light_source).with_shadows())
         .insert(layer);
 
     // camera
@@ -100,4 +111,29 @@ pub fn scene_setup(
             //                }),
         })
         .insert(layer);
+
+    // minimap camera, looking straight down with the same side of the world up as the camera above
+    let minimap_texture = minimap.setup(meshes, materials, textures);
+    commands
+        .spawn(AmbientLight::new(1.0, Color::WHITE))
+        .insert(minimap_layer);
+    commands
+        .spawn(CameraBundle {
+            camera: Camera {
+                viewport: Some(Viewport::new_at_origin(1, 1)),
+                clear_operation: ClearOperation::from_rgba(0.0, 0.0, 0.0, 1.0),
+                target: RenderTarget::Image(minimap_texture),
+                ..Default::default()
+            },
+            transform: Transform::from_xyz(0.0, 0.0, CAMERA_Z)
+                .with_scale(Minimap::camera_scale(1))
+                .looking_at(Vec3::ZERO, Vec3::NEG_Y),
+            projection: Projection::Orthographic(OrthographicProjection {
+                near: 0.1,
+                far: 10000.0,
+                ..Default::default()
+            }),
+        })
+        .insert(MinimapCamera)
+        .insert(minimap_layer);
 }
@@ -18,6 +18,7 @@ use game_app_network::{
     naia::sequence_greater_than,
     world::{
         // behavior as shared_behavior,
+        behavior::{is_movement_checksum_tick, MovementState},
         components::{NetworkedTileTarget, PhysicsController},
         WorldClient,
         WorldInsertComponentEvent,
@@ -28,7 +29,7 @@ use game_app_network::{
 
 use crate::{
     components::{AnimationState, ConfirmedTileMovement, RenderPosition, TickSkipper},
-    resources::{RollbackManager, TickTracker},
+    resources::{DesyncDetector, RollbackManager, TickTracker},
 };
 
 pub fn insert_net_tile_target_events(
@@ -85,6 +86,7 @@ pub fn update_net_tile_target_events(
     asset_manager: Res<AssetManager>,
     tick_tracker: Res<TickTracker>,
     mut rollback_manager: ResMut<RollbackManager>,
+    mut desync_detector: ResMut<DesyncDetector>,
     mut event_reader: EventReader<WorldUpdateComponentEvent<NetworkedTileTarget>>,
     mut updated_q: Query<(
         &NetworkedTileTarget,
@@ -146,6 +148,15 @@ pub fn update_net_tile_target_events(
             )
         }
 
+        if is_movement_checksum_tick(*update_tick) {
+            // the update overrides whatever the confirmed simulation had for this tick
+            desync_detector.record_client_state(
+                *updated_entity,
+                *update_tick,
+                MovementState::capture(tile_movement.tile_movement(), &physics),
+            );
+        }
+
         let Ok(mut tick_skipper) = tick_skipper_q.get_mut(*updated_entity) else {
             panic!(
                 "failed to get tick_skipper q for entity: {:?}",
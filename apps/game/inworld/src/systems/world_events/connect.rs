@@ -9,7 +9,7 @@ use bevy_state::state::NextState;
 use game_engine::{
     logging::info,
     render::{
-        base::{CpuMaterial, CpuMesh},
+        base::{CpuMaterial, CpuMesh, CpuTexture2D},
         components::{RenderLayer, RenderLayers},
     },
     storage::Storage,
@@ -19,13 +19,15 @@ use game_engine::{
 use game_app_common::AppState;
 use game_app_network::world::WorldConnectEvent;
 
-use crate::systems::scene_setup;
+use crate::{resources::Minimap, systems::scene_setup};
 
 pub fn connect_events(
     mut commands: Commands,
     mut next_state: ResMut<NextState<AppState>>,
     mut meshes: ResMut<Storage<CpuMesh>>,
     mut materials: ResMut<Storage<CpuMaterial>>,
+    mut textures: ResMut<Storage<CpuTexture2D>>,
+    mut minimap: ResMut<Minimap>,
     mut ui_manager: ResMut<UiManager>,
     render_layer_q: Query<(Entity, &RenderLayer)>,
 
@@ -44,7 +46,13 @@ pub fn connect_events(
         }
 
         // setup walker scene
-        scene_setup::scene_setup(&mut commands, &mut meshes, &mut materials);
+        scene_setup::scene_setup(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            &mut textures,
+            &mut minimap,
+        );
 
         // disable ui
         ui_manager.disable_ui();
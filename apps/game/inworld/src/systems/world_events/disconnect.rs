@@ -1,11 +1,64 @@
-use bevy_ecs::event::EventReader;
+use bevy_ecs::{
+    change_detection::ResMut,
+    entity::Entity,
+    event::EventReader,
+    prelude::{Commands, Query},
+    system::Res,
+};
+use bevy_state::state::NextState;
 
-use game_engine::logging::info;
+use game_engine::{
+    logging::info,
+    render::components::{RenderLayer, RenderLayers},
+};
 
-use game_app_network::world::WorldDisconnectEvent;
+use game_app_common::AppState;
+use game_app_network::{
+    world::{WorldDisconnectEvent, WorldRejectEvent},
+    ConnectionManager, WorldConnectionState,
+};
 
-pub fn disconnect_events(mut event_reader: EventReader<WorldDisconnectEvent>) {
-    for _ in event_reader.read() {
-        info!("Client disconnected from Server");
+use crate::resources::{CameraManager, DesyncDetector, Global, MINIMAP_LAYER};
+
+pub fn disconnect_events(
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<AppState>>,
+    mut global: ResMut<Global>,
+    mut camera_manager: ResMut<CameraManager>,
+    mut desync_detector: ResMut<DesyncDetector>,
+    connection_manager: Res<ConnectionManager>,
+    render_layer_q: Query<(Entity, &RenderLayer)>,
+    mut disconnect_event_reader: EventReader<WorldDisconnectEvent>,
+    mut reject_event_reader: EventReader<WorldRejectEvent>,
+) {
+    // a reconnect that is rejected also ends the match for us
+    let disconnected = disconnect_event_reader.read().count() > 0;
+    let rejected = reject_event_reader.read().count() > 0;
+    if !disconnected && !rejected {
+        return;
+    }
+
+    // while the connection manager is reconnecting, the match & our unit are still there for us
+    if connection_manager.world_connection_state != WorldConnectionState::Disconnected {
+        info!("Client disconnected from Server, waiting to reconnect");
+        return;
+    }
+
+    info!("Client left the match");
+
+    // despawning walker scene, the main menu will set up its own
+    let render_layer_0 = RenderLayers::layer(0);
+    let minimap_layer = RenderLayers::layer(MINIMAP_LAYER);
+    for (entity, layer) in render_layer_q.iter() {
+        if *layer == render_layer_0 || *layer == minimap_layer {
+            commands.entity(entity).despawn();
+        }
     }
+
+    global.owned_entity = None;
+    camera_manager.reset();
+    desync_detector.reset();
+
+    // return to the main menu
+    next_state.set(AppState::MainMenu);
 }
@@ -1,14 +1,21 @@
-use bevy_ecs::event::EventReader;
+use bevy_ecs::{event::EventReader, system::ResMut};
 
 use game_engine::logging::info;
 
 use game_app_network::world::WorldDespawnEntityEvent;
 
-pub fn despawn_entity_events(mut event_reader: EventReader<WorldDespawnEntityEvent>) {
+use crate::resources::DesyncDetector;
+
+pub fn despawn_entity_events(
+    mut desync_detector: ResMut<DesyncDetector>,
+    mut event_reader: EventReader<WorldDespawnEntityEvent>,
+) {
     for event in event_reader.read() {
         info!(
             "received Despawn Entity from World Server! (entity: {:?})",
             event.entity
         );
+
+        desync_detector.remove_unit(&event.entity);
     }
 }
@@ -3,16 +3,19 @@ use bevy_ecs::{change_detection::ResMut, event::EventReader, prelude::Commands};
 use game_engine::logging::info;
 
 use game_app_network::world::{
-    channels::EntityAssignmentChannel, messages::EntityAssignment, WorldClient, WorldMessageEvents,
+    channels::{EntityAssignmentChannel, MovementChecksumChannel},
+    messages::{EntityAssignment, MovementChecksum},
+    WorldClient, WorldMessageEvents,
 };
 
-use crate::resources::{Global, RollbackManager};
+use crate::resources::{DesyncDetector, Global, RollbackManager};
 
 pub fn message_events(
     mut commands: Commands,
     client: WorldClient,
     mut global: ResMut<Global>,
     mut rollback_manager: ResMut<RollbackManager>,
+    mut desync_detector: ResMut<DesyncDetector>,
     mut message_events: EventReader<WorldMessageEvents>,
 ) {
     for events in message_events.read() {
@@ -40,5 +43,11 @@ pub fn message_events(
                 }
             }
         }
+        for message in events.read::<MovementChecksumChannel, MovementChecksum>() {
+            let Some(entity) = message.entity.get(&client) else {
+                continue;
+            };
+            desync_detector.recv_server_checksum(entity, message.tick, message.checksum);
+        }
     }
 }
@@ -6,12 +6,14 @@ use bevy_ecs::{
     system::{Res, SystemState},
 };
 
-use game_engine::asset::{AnimatedModelData, AssetHandle, AssetManager, UnitData};
+use game_engine::asset::{
+    AnimatedModelData, AssetHandle, AssetManager, MovementConfigData, UnitData,
+};
 
 use game_app_network::{
     naia::Tick,
     world::{
-        behavior as shared_behavior,
+        behavior::{self as shared_behavior, is_movement_checksum_tick, MovementState},
         channels::PlayerCommandChannel,
         components::{NetworkedLastCommand, PhysicsController, TileMovementType},
         messages::PlayerCommands,
@@ -24,7 +26,7 @@ use crate::{
         AnimationState, ClientTileMovement, ConfirmedTileMovement, PredictedTileMovement,
         RenderPosition, TickSkipper,
     },
-    resources::{Global, InputManager, PredictedWorld, TickTracker},
+    resources::{DesyncDetector, Global, InputManager, PredictedWorld, TickTracker},
 };
 
 pub fn client_tick_events(
@@ -95,6 +97,9 @@ pub fn client_tick_events(
             else {
                 continue;
             };
+            let Some(movement_config) = asset_manager.get_unit_movement_config(unit_handle) else {
+                continue;
+            };
 
             let next_command = {
                 if let Some(owned_entity) = owned_entity_opt {
@@ -118,6 +123,7 @@ pub fn client_tick_events(
                 &mut predicted_render_position,
                 &mut predicted_animation_state,
                 animated_model_handle,
+                movement_config,
             );
         }
     }
@@ -133,7 +139,11 @@ pub fn process_tick(
     render_position: &mut RenderPosition,
     animation_state: &mut AnimationState,
     animated_model_handle: &AssetHandle<AnimatedModelData>,
+    movement_config: &MovementConfigData,
 ) {
+    // keep prediction & confirmed simulation on the unit's tuned movement values
+    physics.set_movement_config(movement_config.get_movement_config());
+
     let lookdir_opt = if let Some(player_command) = player_command.as_ref() {
         player_command.get_look()
     } else {
@@ -162,6 +172,10 @@ pub fn process_tick(
         10.0,
         inner_tile_movement,
     );
+    // predicted ticks are replayed on every rollback, only confirmed units report frame events
+    if tile_movement_type == TileMovementType::ClientPredicted {
+        animation_state.take_events();
+    }
 
     if let Some(lookdir) = lookdir_opt {
         animation_state.recv_lookdir_update(&lookdir);
@@ -171,6 +185,7 @@ pub fn process_tick(
 pub fn server_tick_events(
     asset_manager: Res<AssetManager>,
     mut tick_tracker: ResMut<TickTracker>,
+    mut desync_detector: ResMut<DesyncDetector>,
     mut tick_reader: EventReader<WorldServerTickEvent>,
     mut unit_q: Query<(
         Entity,
@@ -212,6 +227,9 @@ pub fn server_tick_events(
             else {
                 continue;
             };
+            let Some(movement_config) = asset_manager.get_unit_movement_config(unit_handle) else {
+                continue;
+            };
 
             let confirmed_tile_movement_2: &mut ConfirmedTileMovement =
                 &mut confirmed_tile_movement;
@@ -225,7 +243,19 @@ pub fn server_tick_events(
                 &mut confirmed_render_position,
                 &mut confirmed_animation_state,
                 &animated_model_handle,
+                movement_config,
             );
+
+            if is_movement_checksum_tick(server_tick) {
+                desync_detector.record_client_state(
+                    confirmed_entity,
+                    server_tick,
+                    MovementState::capture(
+                        confirmed_tile_movement.tile_movement(),
+                        &confirmed_physics,
+                    ),
+                );
+            }
         }
 
         // record
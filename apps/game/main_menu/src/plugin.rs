@@ -1,6 +1,9 @@
 use bevy_app::{App, Plugin, Startup, Update};
 use bevy_ecs::{prelude::not, schedule::IntoSystemConfigs};
-use bevy_state::condition::in_state;
+use bevy_state::{
+    condition::in_state,
+    state::{OnEnter, OnExit, OnTransition},
+};
 
 use game_engine::render::Draw;
 
@@ -9,12 +12,13 @@ use game_app_common::AppState;
 use crate::{
     resources::{
         asset_catalog::AssetCatalog, chat_message_events::ChatMessageEvents,
-        chat_message_manager::ChatMessageManager, lobby_manager::LobbyManager,
-        match_manager::MatchManager, selfhood_events::SelfhoodEvents, user_manager::UserManager,
+        chat_message_manager::ChatMessageManager, direct_message_manager::DirectMessageManager,
+        friend_manager::FriendManager, lobby_manager::LobbyManager, match_manager::MatchManager,
+        selfhood_events::SelfhoodEvents, user_manager::UserManager,
     },
     systems,
     systems::session_component_events::SessionComponentEventsPlugin,
-    ui::UiPlugin,
+    ui::{main_menu, UiPlugin},
 };
 
 pub struct MainMenuPlugin;
@@ -27,6 +31,8 @@ impl Plugin for MainMenuPlugin {
             .init_resource::<ChatMessageEvents>()
             .init_resource::<LobbyManager>()
             .init_resource::<MatchManager>()
+            .init_resource::<FriendManager>()
+            .init_resource::<DirectMessageManager>()
             .init_resource::<AssetCatalog>() // this seems to be Ui-specific
             // Ui
             .add_plugins(UiPlugin)
@@ -36,6 +42,35 @@ impl Plugin for MainMenuPlugin {
                 Update,
                 systems::cube_scene::step.run_if(not(in_state(AppState::InGame))),
             )
+            .add_systems(
+                OnTransition {
+                    exited: AppState::InGame,
+                    entered: AppState::MainMenu,
+                },
+                (systems::cube_scene::setup, main_menu::on_return_from_match),
+            )
+            .add_systems(
+                OnTransition {
+                    exited: AppState::Replay,
+                    entered: AppState::MainMenu,
+                },
+                (systems::cube_scene::setup, main_menu::on_return_from_match),
+            )
+            // portrait scene systems
+            .add_systems(Startup, systems::portrait_scene::setup)
+            .add_systems(
+                Update,
+                (systems::portrait_scene::step, main_menu::sync_user_portrait)
+                    .run_if(in_state(AppState::MainMenu)),
+            )
+            .add_systems(
+                OnEnter(AppState::MainMenu),
+                systems::portrait_scene::activate_camera,
+            )
+            .add_systems(
+                OnExit(AppState::MainMenu),
+                systems::portrait_scene::deactivate_camera,
+            )
             .add_systems(Update, systems::resize::resync_on_resize)
             // Network Systems
             .add_systems(Update, systems::asset_events::session_load_asset_events)
@@ -10,7 +10,7 @@ use game_engine::{
     asset::AssetManager,
     input::{InputEvent, Key},
     logging::{info, warn},
-    social::{LobbyId, MessageId},
+    social::{LobbyId, MessageId, Timestamp},
     ui::{
         extensions::{ListUiExt, ListUiExtItem},
         NodeActiveState, UiHandle, UiManager,
@@ -23,8 +23,24 @@ use game_app_network::session::{
     messages, SessionClient,
 };
 
+// live messages beyond this are dropped, oldest first
+const MAX_LIVE_MESSAGES: usize = 1000;
+
+// a chat message, either replicated live or loaded from the chat history
+struct ChatMessageRecord {
+    timestamp: Timestamp,
+    user_entity: Option<Entity>,
+    message: String,
+}
+
+#[derive(Default)]
+struct ChatHistoryState {
+    request_in_flight: bool,
+    no_more_history: bool,
+}
+
 use crate::{
-    resources::lobby_manager::LobbyManager,
+    resources::{direct_message_manager::DirectMessageManager, lobby_manager::LobbyManager},
     ui::{
         events::{GoToSubUiEvent, ResyncMessageListUiEvent},
         go_to_sub_ui, UiCatalog, UiKey,
@@ -33,7 +49,8 @@ use crate::{
 
 #[derive(Resource)]
 pub struct ChatMessageManager {
-    messages: HashMap<Option<LobbyId>, BTreeMap<MessageId, Entity>>,
+    messages: HashMap<Option<LobbyId>, BTreeMap<MessageId, ChatMessageRecord>>,
+    history_states: HashMap<Option<LobbyId>, ChatHistoryState>,
     last_synced_lobby_id: Option<LobbyId>,
 
     list_ui_ext: ListUiExt<MessageId>,
@@ -49,6 +66,7 @@ impl Default for ChatMessageManager {
 
         Self {
             messages,
+            history_states: HashMap::new(),
             last_synced_lobby_id: None,
             list_ui_ext: ListUiExt::new(false),
             message_item_ui: None,
@@ -61,12 +79,11 @@ impl Default for ChatMessageManager {
 impl ChatMessageManager {
     pub(crate) fn handle_resync_events(
         &mut self,
-        session_client: &mut SessionClient,
         ui_manager: &mut UiManager,
         asset_manager: &AssetManager,
         lobby_manager: &LobbyManager,
+        direct_message_manager: &DirectMessageManager,
         user_q: &Query<&User>,
-        chat_message_q: &Query<&ChatMessage>,
         resync_message_list_ui_events: &mut EventReader<ResyncMessageListUiEvent>,
     ) {
         let mut should_resync = None;
@@ -84,23 +101,21 @@ impl ChatMessageManager {
             let is_bottom_visible = self.list_ui_ext.is_bottom_visible();
 
             self.sync_with_collection(
-                session_client,
                 ui_manager,
                 asset_manager,
                 lobby_manager,
+                direct_message_manager,
                 user_q,
-                chat_message_q,
             );
 
             if is_bottom_visible && maintain_scroll {
                 self.list_ui_ext.scroll_to_bottom();
                 self.sync_with_collection(
-                    session_client,
                     ui_manager,
                     asset_manager,
                     lobby_manager,
+                    direct_message_manager,
                     user_q,
-                    chat_message_q,
                 );
             }
         }
@@ -130,6 +145,10 @@ impl ChatMessageManager {
                         info!("Scrolling Up");
                         self.list_ui_ext.scroll_up();
                         resync_message_list_ui_events.send(ResyncMessageListUiEvent::new(false));
+
+                        if self.list_ui_ext.is_top_visible() {
+                            self.request_older_messages(session_client, lobby_manager);
+                        }
                     }
                 }
                 InputEvent::KeyPressed(Key::J, _) => {
@@ -239,32 +258,95 @@ impl ChatMessageManager {
 
     pub fn recv_message(
         &mut self,
+        session_client: &SessionClient,
         lobby_id_opt: &Option<LobbyId>,
         resync_lobby_global_events: &mut EventWriter<ResyncMessageListUiEvent>,
-        message_id: MessageId,
-        message_entity: Entity,
+        message: &ChatMessage,
     ) {
         if !self.messages.contains_key(lobby_id_opt) {
             self.messages.insert(lobby_id_opt.clone(), BTreeMap::new());
         }
         let lobby_messages = self.messages.get_mut(lobby_id_opt).unwrap();
-        lobby_messages.insert(message_id, message_entity);
+        lobby_messages.insert(
+            *message.id,
+            ChatMessageRecord {
+                timestamp: *message.timestamp,
+                user_entity: message.owner_user_entity.get(session_client),
+                message: message.message.to_string(),
+            },
+        );
 
-        if lobby_messages.len() > 100 {
+        if lobby_messages.len() > MAX_LIVE_MESSAGES {
             lobby_messages.pop_first();
+
+            // the dropped messages can be loaded again from the history
+            self.history_states
+                .entry(lobby_id_opt.clone())
+                .or_default()
+                .no_more_history = false;
         }
 
         resync_lobby_global_events.send(ResyncMessageListUiEvent::new(true));
     }
 
-    pub fn sync_with_collection(
+    pub(crate) fn recv_history_message(
         &mut self,
         session_client: &SessionClient,
+        resync_message_list_ui_events: &mut EventWriter<ResyncMessageListUiEvent>,
+        entry: &messages::ChatHistoryEntry,
+    ) {
+        let history_state = self.history_states.entry(entry.lobby_id).or_default();
+        history_state.request_in_flight = false;
+        history_state.no_more_history = !entry.has_more;
+
+        let lobby_messages = self
+            .messages
+            .entry(entry.lobby_id)
+            .or_insert(BTreeMap::new());
+        lobby_messages
+            .entry(entry.message_id)
+            .or_insert(ChatMessageRecord {
+                timestamp: entry.timestamp,
+                user_entity: entry.owner_user_entity.get(session_client),
+                message: entry.message.clone(),
+            });
+
+        resync_message_list_ui_events.send(ResyncMessageListUiEvent::new(false));
+    }
+
+    // asks the session server for the page of messages before the oldest one we have
+    fn request_older_messages(
+        &mut self,
+        session_client: &mut SessionClient,
+        lobby_manager: &LobbyManager,
+    ) {
+        let lobby_id_opt = lobby_manager.get_current_lobby();
+
+        let history_state = self.history_states.entry(lobby_id_opt).or_default();
+        if history_state.request_in_flight || history_state.no_more_history {
+            // an empty page never answers, so the request stays in flight & we stop asking
+            return;
+        }
+        history_state.request_in_flight = true;
+
+        let oldest_message_id = self
+            .messages
+            .get(&lobby_id_opt)
+            .and_then(|messages| messages.keys().next().copied());
+
+        info!("requesting chat history before: {:?}", oldest_message_id);
+
+        let message = messages::ChatHistoryRequest::new(lobby_id_opt.is_some(), oldest_message_id);
+        session_client.send_message::<channels::ClientActionsChannel, _>(&message);
+    }
+
+    pub fn sync_with_collection(
+        &mut self,
         ui_manager: &mut UiManager,
         asset_manager: &AssetManager,
         lobby_manager: &LobbyManager,
+        direct_message_manager: &DirectMessageManager,
         user_q: &Query<&User>,
-        message_q: &Query<&ChatMessage>,
     ) {
         if self.message_item_ui.is_none()
             || self.day_divider_item_ui.is_none()
@@ -287,25 +369,32 @@ impl ChatMessageManager {
         }
         let messages = self.messages.get_mut(&lobby_id_opt).unwrap();
 
+        // messages from blocked users are kept, just never shown
+        let is_visible = |message: &ChatMessageRecord| {
+            message
+                .user_entity
+                .map(|user_entity| !direct_message_manager.is_blocked(&user_entity))
+                .unwrap_or(true)
+        };
+        let visible_count = messages
+            .values()
+            .filter(|message| is_visible(message))
+            .count();
+
         self.list_ui_ext.sync_with_collection(
             ui_manager,
             asset_manager,
-            messages.iter(),
-            messages.len(),
+            messages.iter().filter(|(_, message)| is_visible(message)),
+            visible_count,
             |item_ctx, message_id, prev_message_id_opt| {
-                let message_entity = *(messages.get(&message_id).unwrap());
-                let message = message_q.get(message_entity).unwrap();
-                let message_timestamp = (*message.timestamp).clone();
-                let message_user_entity = message.owner_user_entity.get(session_client).unwrap();
+                let message = messages.get(&message_id).unwrap();
+                let message_timestamp = message.timestamp;
+                let message_user_entity = message.user_entity;
 
                 let (prev_timestamp_opt, prev_message_user_entity) = match prev_message_id_opt {
                     Some(prev_message_id) => {
-                        let prev_message_entity = *(messages.get(&prev_message_id).unwrap());
-                        let prev_message = message_q.get(prev_message_entity).unwrap();
-                        let prev_timestamp = (*prev_message.timestamp).clone();
-                        let prev_message_user_entity =
-                            prev_message.owner_user_entity.get(session_client).unwrap();
-                        (Some(prev_timestamp), Some(prev_message_user_entity))
+                        let prev_message = messages.get(&prev_message_id).unwrap();
+                        (Some(prev_message.timestamp), Some(prev_message.user_entity))
                     }
                     None => (None, None),
                 };
@@ -322,7 +411,6 @@ impl ChatMessageManager {
                 // add username if necessary
                 if prev_message_user_entity.is_none() || added_divider {
                     Self::add_username_and_message_item(
-                        session_client,
                         user_q,
                         item_ctx,
                         username_and_message_ui_handle,
@@ -331,7 +419,6 @@ impl ChatMessageManager {
                 } else if prev_message_user_entity.unwrap() != message_user_entity {
                     Self::add_message_item(item_ctx, message_ui_handle, " "); // blank space
                     Self::add_username_and_message_item(
-                        session_client,
                         user_q,
                         item_ctx,
                         username_and_message_ui_handle,
@@ -348,7 +435,7 @@ impl ChatMessageManager {
     fn add_day_divider_item(
         item_ctx: &mut ListUiExtItem<MessageId>,
         ui: &UiHandle,
-        message: &ChatMessage,
+        message: &ChatMessageRecord,
     ) {
         item_ctx.add_copied_node(ui);
 
@@ -357,14 +444,13 @@ impl ChatMessageManager {
     }
 
     fn add_username_and_message_item(
-        session_client: &SessionClient,
         user_q: &Query<&User>,
         item_ctx: &mut ListUiExtItem<MessageId>,
         ui: &UiHandle,
-        message: &ChatMessage,
+        message: &ChatMessageRecord,
     ) {
-        let Some(user_info_entity) = message.owner_user_entity.get(session_client) else {
-            warn!("User info not found for Message: {:?}", *message.id);
+        let Some(user_info_entity) = message.user_entity else {
+            warn!("User info not found for Message: {:?}", message.message);
             return;
         };
 
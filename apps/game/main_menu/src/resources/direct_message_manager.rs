@@ -0,0 +1,319 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use bevy_ecs::{
+    entity::Entity,
+    event::{EventReader, EventWriter},
+    system::{Query, Resource},
+};
+
+use game_engine::{
+    asset::AssetManager,
+    input::{InputEvent, Key},
+    logging::info,
+    social::Timestamp,
+    ui::{
+        extensions::{ListUiExt, ListUiExtItem},
+        NodeActiveState, UiHandle, UiManager,
+    },
+};
+
+use game_app_network::session::{channels, components::User, messages, SessionClient};
+
+use crate::{
+    resources::user_manager::UserManager,
+    ui::{events::ResyncConversationUiEvent, UiCatalog, UiKey},
+};
+
+// messages kept per conversation, oldest dropped first
+const MAX_CONVERSATION_MESSAGES: usize = 500;
+
+struct DirectMessageRecord {
+    timestamp: Timestamp,
+    from_self: bool,
+    message: String,
+}
+
+// whispers between us & other users, keyed by the other user's entity
+#[derive(Resource)]
+pub struct DirectMessageManager {
+    conversations: HashMap<Entity, BTreeMap<u32, DirectMessageRecord>>,
+    next_message_id: u32,
+    unread_counts: HashMap<Entity, u32>,
+    blocked_users: HashSet<Entity>,
+    // the conversation being shown, if the conversation ui is open
+    current_conversation: Option<Entity>,
+    last_synced_conversation: Option<Entity>,
+
+    list_ui_ext: ListUiExt<u32>,
+}
+
+impl Default for DirectMessageManager {
+    fn default() -> Self {
+        Self {
+            conversations: HashMap::new(),
+            next_message_id: 0,
+            unread_counts: HashMap::new(),
+            blocked_users: HashSet::new(),
+            current_conversation: None,
+            last_synced_conversation: None,
+            list_ui_ext: ListUiExt::new(false),
+        }
+    }
+}
+
+impl DirectMessageManager {
+    pub(crate) fn on_load_conversation_ui(
+        &mut self,
+        ui_catalog: &mut UiCatalog,
+        ui_manager: &mut UiManager,
+        resync_conversation_ui_events: &mut EventWriter<ResyncConversationUiEvent>,
+    ) {
+        let ui_key = UiKey::Conversation;
+        let ui_handle = ui_catalog.get_ui_handle(ui_key);
+
+        ui_catalog.set_loaded(ui_key);
+
+        // setup conversation list extension
+        {
+            let container_id_str = "conversation_wall";
+
+            self.list_ui_ext
+                .set_container_ui(ui_manager, &ui_handle, container_id_str);
+            resync_conversation_ui_events.send(ResyncConversationUiEvent);
+        }
+    }
+
+    pub(crate) fn is_blocked(&self, user_entity: &Entity) -> bool {
+        self.blocked_users.contains(user_entity)
+    }
+
+    pub(crate) fn unread_count(&self, user_entity: &Entity) -> u32 {
+        self.unread_counts.get(user_entity).copied().unwrap_or(0)
+    }
+
+    pub(crate) fn current_conversation(&self) -> Option<Entity> {
+        self.current_conversation
+    }
+
+    // opening a conversation marks it as read
+    pub(crate) fn open_conversation(&mut self, user_entity: Entity) {
+        self.current_conversation = Some(user_entity);
+        self.unread_counts.remove(&user_entity);
+    }
+
+    pub(crate) fn close_conversation(&mut self) {
+        self.current_conversation = None;
+    }
+
+    // returns whether the unread counts changed
+    pub(crate) fn recv_message(
+        &mut self,
+        resync_conversation_ui_events: &mut EventWriter<ResyncConversationUiEvent>,
+        user_entity: Entity,
+        message: &messages::DirectMessageReceived,
+    ) -> bool {
+        if !message.from_self && self.is_blocked(&user_entity) {
+            return false;
+        }
+
+        let message_id = self.next_message_id;
+        self.next_message_id = self.next_message_id.wrapping_add(1);
+
+        let conversation = self.conversations.entry(user_entity).or_default();
+        conversation.insert(
+            message_id,
+            DirectMessageRecord {
+                timestamp: message.timestamp,
+                from_self: message.from_self,
+                message: message.message.clone(),
+            },
+        );
+        if conversation.len() > MAX_CONVERSATION_MESSAGES {
+            conversation.pop_first();
+        }
+
+        if self.current_conversation == Some(user_entity) {
+            resync_conversation_ui_events.send(ResyncConversationUiEvent);
+            return false;
+        }
+        if message.from_self {
+            return false;
+        }
+        *self.unread_counts.entry(user_entity).or_insert(0) += 1;
+        true
+    }
+
+    pub(crate) fn recv_user_blocked(
+        &mut self,
+        resync_conversation_ui_events: &mut EventWriter<ResyncConversationUiEvent>,
+        user_entity: Entity,
+        blocked: bool,
+    ) {
+        if blocked {
+            self.blocked_users.insert(user_entity);
+            self.unread_counts.remove(&user_entity);
+        } else {
+            self.blocked_users.remove(&user_entity);
+        }
+        resync_conversation_ui_events.send(ResyncConversationUiEvent);
+    }
+
+    pub(crate) fn handle_interaction_events(
+        &mut self,
+        ui_manager: &mut UiManager,
+        ui_catalog: &UiCatalog,
+        session_client: &mut SessionClient,
+        input_events: &mut EventReader<InputEvent>,
+        resync_conversation_ui_events: &mut EventWriter<ResyncConversationUiEvent>,
+    ) {
+        let ui_handle = ui_catalog.get_ui_handle(UiKey::Conversation);
+
+        for event in input_events.read() {
+            let typing = matches!(
+                ui_manager.get_node_active_state_from_id(&ui_handle, "message_textbox"),
+                Some(NodeActiveState::Active)
+            );
+            match event {
+                InputEvent::KeyPressed(Key::I, _) if !typing => {
+                    self.list_ui_ext.scroll_up();
+                    resync_conversation_ui_events.send(ResyncConversationUiEvent);
+                }
+                InputEvent::KeyPressed(Key::J, _) if !typing => {
+                    self.list_ui_ext.scroll_down();
+                    resync_conversation_ui_events.send(ResyncConversationUiEvent);
+                }
+                InputEvent::KeyPressed(Key::Enter, modifiers) if typing && !modifiers.shift => {
+                    self.send_message(ui_manager, &ui_handle, session_client);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn send_message(
+        &self,
+        ui_manager: &mut UiManager,
+        ui_handle: &UiHandle,
+        session_client: &mut SessionClient,
+    ) {
+        let Some(user_entity) = self.current_conversation else {
+            return;
+        };
+        let Some(textbox_text) = ui_manager.get_text(ui_handle, "message_textbox") else {
+            return;
+        };
+        ui_manager.set_text(ui_handle, "message_textbox", "");
+        if textbox_text.trim().is_empty() {
+            return;
+        }
+
+        // our own message shows up once the session server echoes it back
+        let mut message = messages::DirectMessageSend::new(&textbox_text);
+        message.user_entity.set(session_client, &user_entity);
+        session_client.send_message::<channels::ClientActionsChannel, _>(&message);
+    }
+
+    pub(crate) fn send_toggle_block(&self, session_client: &mut SessionClient) {
+        let Some(user_entity) = self.current_conversation else {
+            return;
+        };
+        let blocked = !self.is_blocked(&user_entity);
+
+        info!("setting user {:?} blocked: {:?}", user_entity, blocked);
+
+        let mut message = messages::UserBlock::new(blocked);
+        message.user_entity.set(session_client, &user_entity);
+        session_client.send_message::<channels::ClientActionsChannel, _>(&message);
+    }
+
+    pub fn sync_with_collection(
+        &mut self,
+        ui_manager: &mut UiManager,
+        ui_catalog: &UiCatalog,
+        asset_manager: &AssetManager,
+        user_manager: &UserManager,
+        user_q: &Query<&User>,
+    ) {
+        // conversations reuse the chat's message item uis
+        if !ui_catalog.get_is_loaded(UiKey::MessageListUsernameAndMessage)
+            || !ui_catalog.get_is_loaded(UiKey::MessageListMessage)
+        {
+            return;
+        }
+        let username_and_message_ui_handle =
+            ui_catalog.get_ui_handle(UiKey::MessageListUsernameAndMessage);
+        let message_ui_handle = ui_catalog.get_ui_handle(UiKey::MessageListMessage);
+
+        if self.last_synced_conversation != self.current_conversation {
+            self.last_synced_conversation = self.current_conversation;
+            self.list_ui_ext.clear(ui_manager);
+        }
+        let Some(other_user_entity) = self.current_conversation else {
+            return;
+        };
+
+        let get_username = |user_entity: Option<Entity>| {
+            user_entity
+                .and_then(|user_entity| user_q.get(user_entity).ok())
+                .map(|user| user.name.as_str().to_string())
+                .unwrap_or_else(|| "?".to_string())
+        };
+        let self_username = get_username(user_manager.get_self_user_entity());
+        let other_username = get_username(Some(other_user_entity));
+
+        let conversation = self.conversations.entry(other_user_entity).or_default();
+
+        self.list_ui_ext.sync_with_collection(
+            ui_manager,
+            asset_manager,
+            conversation.iter(),
+            conversation.len(),
+            |item_ctx, message_id, prev_message_id_opt| {
+                let message = conversation.get(&message_id).unwrap();
+                let prev_from_self = prev_message_id_opt
+                    .map(|prev_message_id| conversation.get(&prev_message_id).unwrap().from_self);
+
+                if prev_from_self == Some(message.from_self) {
+                    add_message_item(item_ctx, &message_ui_handle, message.message.as_str());
+                    return;
+                }
+                if prev_from_self.is_some() {
+                    add_message_item(item_ctx, &message_ui_handle, " "); // blank space
+                }
+                let username = if message.from_self {
+                    self_username.as_str()
+                } else {
+                    other_username.as_str()
+                };
+                add_username_and_message_item(
+                    item_ctx,
+                    &username_and_message_ui_handle,
+                    username,
+                    message,
+                );
+            },
+        );
+    }
+}
+
+fn add_username_and_message_item(
+    item_ctx: &mut ListUiExtItem<u32>,
+    ui: &UiHandle,
+    username: &str,
+    message: &DirectMessageRecord,
+) {
+    item_ctx.add_copied_node(ui);
+
+    item_ctx.set_text_by_id("user_name", username);
+
+    let message_timestamp = message.timestamp.time_string();
+    item_ctx.set_text_by_id("timestamp", message_timestamp.as_str());
+
+    item_ctx.set_text_by_id("message", message.message.as_str());
+}
+
+fn add_message_item(item_ctx: &mut ListUiExtItem<u32>, ui: &UiHandle, message_text: &str) {
+    item_ctx.add_copied_node(ui);
+
+    item_ctx.set_text_by_id("message", message_text);
+}
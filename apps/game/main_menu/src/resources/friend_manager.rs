@@ -0,0 +1,270 @@
+use std::collections::BTreeMap;
+
+use bevy_ecs::{entity::Entity, event::EventWriter, prelude::Query, system::Resource};
+
+use game_engine::{
+    asset::AssetManager,
+    social::{PresenceStatus, UserPresence},
+    ui::{
+        extensions::{ListUiExt, ListUiExtItem},
+        UiHandle, UiManager,
+    },
+};
+
+use game_app_network::session::components::{Lobby, User};
+
+use crate::{
+    resources::lobby_manager::LobbyManager,
+    ui::{
+        events::{
+            FriendListItemAcceptClickedEvent, FriendListItemDeclineClickedEvent,
+            FriendListItemJoinClickedEvent, FriendListItemRemoveClickedEvent,
+            ResyncFriendListUiEvent,
+        },
+        UiCatalog, UiKey,
+    },
+};
+
+#[derive(Clone, Copy)]
+enum FriendEntry {
+    Friend(UserPresence),
+    IncomingRequest,
+}
+
+// friends & incoming friend requests, keyed by the user entity replicated from the session server
+#[derive(Resource)]
+pub struct FriendManager {
+    entries: BTreeMap<Entity, FriendEntry>,
+    away: bool,
+    list_ui_ext: ListUiExt<Entity>,
+    item_ui: Option<UiHandle>,
+}
+
+impl Default for FriendManager {
+    fn default() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+            away: false,
+            list_ui_ext: ListUiExt::new(true),
+            item_ui: None,
+        }
+    }
+}
+
+impl FriendManager {
+    pub(crate) fn on_load_friends_ui(
+        &mut self,
+        ui_catalog: &mut UiCatalog,
+        ui_manager: &mut UiManager,
+        resync_friend_list_ui_events: &mut EventWriter<ResyncFriendListUiEvent>,
+    ) {
+        let ui_key = UiKey::Friends;
+        let ui_handle = ui_catalog.get_ui_handle(ui_key);
+
+        ui_catalog.set_loaded(ui_key);
+
+        // setup friend list extension
+        {
+            let container_id_str = "friend_list";
+
+            self.list_ui_ext
+                .set_container_ui(ui_manager, &ui_handle, container_id_str);
+            resync_friend_list_ui_events.send(ResyncFriendListUiEvent);
+        }
+    }
+
+    pub(crate) fn on_load_friend_list_item_ui(
+        &mut self,
+        ui_catalog: &mut UiCatalog,
+        resync_friend_list_ui_events: &mut EventWriter<ResyncFriendListUiEvent>,
+    ) {
+        let item_ui_key = UiKey::FriendListItem;
+        let item_ui_handle = ui_catalog.get_ui_handle(item_ui_key);
+
+        ui_catalog.set_loaded(item_ui_key);
+
+        self.item_ui = Some(item_ui_handle.clone());
+
+        resync_friend_list_ui_events.send(ResyncFriendListUiEvent);
+    }
+
+    pub(crate) fn is_friend(&self, user_entity: &Entity) -> bool {
+        matches!(self.entries.get(user_entity), Some(FriendEntry::Friend(_)))
+    }
+
+    pub(crate) fn has_incoming_request(&self, user_entity: &Entity) -> bool {
+        matches!(
+            self.entries.get(user_entity),
+            Some(FriendEntry::IncomingRequest)
+        )
+    }
+
+    pub(crate) fn get_friend_presence(&self, user_entity: &Entity) -> Option<UserPresence> {
+        match self.entries.get(user_entity) {
+            Some(FriendEntry::Friend(presence)) => Some(*presence),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn is_away(&self) -> bool {
+        self.away
+    }
+
+    // returns the new away state
+    pub(crate) fn toggle_away(
+        &mut self,
+        resync_friend_list_ui_events: &mut EventWriter<ResyncFriendListUiEvent>,
+    ) -> bool {
+        self.away = !self.away;
+        resync_friend_list_ui_events.send(ResyncFriendListUiEvent);
+        self.away
+    }
+
+    pub(crate) fn recv_friend_updated(
+        &mut self,
+        resync_friend_list_ui_events: &mut EventWriter<ResyncFriendListUiEvent>,
+        user_entity: Entity,
+        presence: UserPresence,
+    ) {
+        self.entries
+            .insert(user_entity, FriendEntry::Friend(presence));
+        resync_friend_list_ui_events.send(ResyncFriendListUiEvent);
+    }
+
+    pub(crate) fn recv_friend_removed(
+        &mut self,
+        resync_friend_list_ui_events: &mut EventWriter<ResyncFriendListUiEvent>,
+        user_entity: &Entity,
+    ) {
+        if self.is_friend(user_entity) {
+            self.entries.remove(user_entity);
+            resync_friend_list_ui_events.send(ResyncFriendListUiEvent);
+        }
+    }
+
+    pub(crate) fn recv_friend_request(
+        &mut self,
+        resync_friend_list_ui_events: &mut EventWriter<ResyncFriendListUiEvent>,
+        user_entity: Entity,
+    ) {
+        if self.is_friend(&user_entity) {
+            return;
+        }
+        self.entries
+            .insert(user_entity, FriendEntry::IncomingRequest);
+        resync_friend_list_ui_events.send(ResyncFriendListUiEvent);
+    }
+
+    pub(crate) fn remove_friend_request(
+        &mut self,
+        resync_friend_list_ui_events: &mut EventWriter<ResyncFriendListUiEvent>,
+        user_entity: &Entity,
+    ) {
+        if self.has_incoming_request(user_entity) {
+            self.entries.remove(user_entity);
+            resync_friend_list_ui_events.send(ResyncFriendListUiEvent);
+        }
+    }
+
+    pub fn sync_with_collection(
+        &mut self,
+        ui_manager: &mut UiManager,
+        asset_manager: &AssetManager,
+        lobby_manager: &LobbyManager,
+        user_q: &Query<&User>,
+        lobby_q: &Query<&Lobby>,
+    ) {
+        if self.item_ui.is_none() {
+            return;
+        }
+
+        let item_ui_handle = self.item_ui.as_ref().unwrap();
+        let current_lobby_id = lobby_manager.get_current_lobby();
+
+        self.list_ui_ext.sync_with_collection(
+            ui_manager,
+            asset_manager,
+            self.entries.iter(),
+            self.entries.len(),
+            |item_ctx, user_entity, _| {
+                let Ok(user) = user_q.get(user_entity) else {
+                    return;
+                };
+                let username = user.name.as_str();
+
+                match self.entries.get(&user_entity).unwrap() {
+                    FriendEntry::Friend(presence) => {
+                        // a friend's lobby can only be joined while we're not already in one,
+                        // and only if it's open to us
+                        let can_join = presence.status() == PresenceStatus::InLobby
+                            && current_lobby_id.is_none()
+                            && presence
+                                .lobby_id()
+                                .and_then(|lobby_id| {
+                                    let lobby_entity = lobby_manager.get_lobby_entity(&lobby_id)?;
+                                    let lobby = lobby_q.get(lobby_entity).ok()?;
+                                    let is_private =
+                                        lobby.is_invite_only() || lobby.requires_password();
+                                    Some(
+                                        !lobby.is_full()
+                                            && (!is_private
+                                                || lobby_manager.is_invited_to_lobby(&lobby_id)),
+                                    )
+                                })
+                                .unwrap_or(false);
+                        add_friend_item(item_ctx, item_ui_handle, username, presence, can_join);
+                    }
+                    FriendEntry::IncomingRequest => {
+                        add_friend_request_item(item_ctx, item_ui_handle, username);
+                    }
+                }
+            },
+        );
+    }
+}
+
+fn add_friend_item(
+    item_ctx: &mut ListUiExtItem<Entity>,
+    ui: &UiHandle,
+    username: &str,
+    presence: &UserPresence,
+    can_join: bool,
+) {
+    item_ctx.add_copied_node(ui);
+    item_ctx.set_text_by_id("username", username);
+
+    let status_text = match presence.status() {
+        PresenceStatus::Offline => "offline",
+        PresenceStatus::Online => "online",
+        PresenceStatus::InLobby => "in lobby",
+        PresenceStatus::InMatch => "in match",
+        PresenceStatus::Away => "away",
+    };
+    item_ctx.set_text_by_id("status", status_text);
+    if presence.status() == PresenceStatus::Offline {
+        item_ctx.set_style_by_id("username", "offline");
+    } else {
+        item_ctx.set_style_by_id("username", "online");
+    }
+
+    item_ctx.set_button_enabled("join_button", can_join);
+    item_ctx.set_button_enabled("remove_button", true);
+    item_ctx.set_button_enabled("accept_button", false);
+    item_ctx.set_button_enabled("decline_button", false);
+    item_ctx.register_ui_event::<FriendListItemJoinClickedEvent>("join_button");
+    item_ctx.register_ui_event::<FriendListItemRemoveClickedEvent>("remove_button");
+}
+
+fn add_friend_request_item(item_ctx: &mut ListUiExtItem<Entity>, ui: &UiHandle, username: &str) {
+    item_ctx.add_copied_node(ui);
+    item_ctx.set_text_by_id("username", username);
+    item_ctx.set_text_by_id("status", "wants to be friends");
+    item_ctx.set_style_by_id("username", "online");
+
+    item_ctx.set_button_enabled("join_button", false);
+    item_ctx.set_button_enabled("remove_button", false);
+    item_ctx.set_button_enabled("accept_button", true);
+    item_ctx.set_button_enabled("decline_button", true);
+    item_ctx.register_ui_event::<FriendListItemAcceptClickedEvent>("accept_button");
+    item_ctx.register_ui_event::<FriendListItemDeclineClickedEvent>("decline_button");
+}
@@ -1,4 +1,4 @@
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use bevy_ecs::{
     entity::Entity,
@@ -9,7 +9,7 @@ use bevy_ecs::{
 use game_engine::{
     asset::AssetManager,
     logging::info,
-    social::LobbyId,
+    social::{LobbyAccess, LobbyId, LobbySettings},
     ui::{
         extensions::{ListUiExt, ListUiExtItem},
         UiHandle, UiManager,
@@ -26,9 +26,10 @@ use crate::{
     resources::match_manager::MatchManager,
     ui::{
         events::{
-            GoToSubUiEvent, LeaveLobbyButtonClickedEvent, LobbyListItemClickedEvent,
-            ResyncLobbyListUiEvent, ResyncMainMenuUiEvent, ResyncMessageListUiEvent,
-            ResyncUserListUiEvent, StartMatchButtonClickedEvent, SubmitButtonClickedEvent,
+            GoToSubUiEvent, InviteOnlyButtonClickedEvent, LeaveLobbyButtonClickedEvent,
+            LobbyListItemClickedEvent, ResyncLobbyListUiEvent, ResyncMainMenuUiEvent,
+            ResyncMessageListUiEvent, ResyncUserListUiEvent, StartMatchButtonClickedEvent,
+            SubmitButtonClickedEvent,
         },
         go_to_sub_ui, UiCatalog, UiKey,
     },
@@ -46,6 +47,12 @@ pub struct LobbyManager {
 
     // lobby_member_entity -> user_entity
     lobby_member_to_user_map: HashMap<Entity, Entity>,
+
+    // lobbies we have been invited to
+    invited_lobbies: HashSet<LobbyId>,
+
+    // "host match" form state
+    host_invite_only: bool,
 }
 
 impl Default for LobbyManager {
@@ -57,6 +64,8 @@ impl Default for LobbyManager {
             lobby_item_ui: None,
             user_lobby_membership_map: HashMap::new(),
             lobby_member_to_user_map: HashMap::new(),
+            invited_lobbies: HashSet::new(),
+            host_invite_only: false,
         }
     }
 }
@@ -150,6 +159,20 @@ impl LobbyManager {
         }
     }
 
+    pub(crate) fn is_invited_to_lobby(&self, lobby_id: &LobbyId) -> bool {
+        self.invited_lobbies.contains(lobby_id)
+    }
+
+    pub(crate) fn recv_lobby_invite(
+        &mut self,
+        lobby_id: LobbyId,
+        resync_lobby_ui_events: &mut EventWriter<ResyncLobbyListUiEvent>,
+    ) {
+        self.invited_lobbies.insert(lobby_id);
+
+        resync_lobby_ui_events.send(ResyncLobbyListUiEvent);
+    }
+
     pub(crate) fn handle_host_match_events(
         &mut self,
         ui_manager: &mut UiManager,
@@ -157,8 +180,28 @@ impl LobbyManager {
         session_client: &mut SessionClient,
         sub_ui_event_writer: &mut EventWriter<GoToSubUiEvent>,
         submit_btn_rdr: &mut EventReader<SubmitButtonClickedEvent>,
+        invite_only_btn_rdr: &mut EventReader<InviteOnlyButtonClickedEvent>,
         should_rumble: &mut bool,
     ) {
+        // Invite Only Button Click
+        let mut invite_only_clicked = false;
+        for _ in invite_only_btn_rdr.read() {
+            invite_only_clicked = true;
+        }
+        if invite_only_clicked {
+            self.host_invite_only = !self.host_invite_only;
+
+            let ui_handle = ui_catalog.get_ui_handle(UiKey::HostMatch);
+            let button_text = if self.host_invite_only {
+                "invite only: on"
+            } else {
+                "invite only: off"
+            };
+            ui_manager.set_text(&ui_handle, "invite_only_button_text", button_text);
+
+            *should_rumble = true;
+        }
+
         // Submit Button Click
         let mut submit_clicked = false;
         for _ in submit_btn_rdr.read() {
@@ -174,13 +217,63 @@ impl LobbyManager {
                 return;
             };
 
-            // clear name textbox
+            // get max players, empty means default
+            let max_players_text = ui_manager
+                .get_text(&ui_handle, "max_players_textbox")
+                .unwrap_or_default();
+            let max_players = if max_players_text.is_empty() {
+                LobbySettings::DEFAULT_MAX_PLAYERS
+            } else {
+                match max_players_text.parse::<u8>() {
+                    Ok(max_players)
+                        if (LobbySettings::MIN_PLAYERS..=LobbySettings::MAX_PLAYERS)
+                            .contains(&max_players) =>
+                    {
+                        max_players
+                    }
+                    _ => {
+                        let error_text = format!(
+                            "max players must be between {} and {}",
+                            LobbySettings::MIN_PLAYERS,
+                            LobbySettings::MAX_PLAYERS
+                        );
+                        ui_manager.set_text(&ui_handle, "error_output_text", &error_text);
+                        return;
+                    }
+                }
+            };
+
+            // get password, empty means no password
+            let password = ui_manager
+                .get_text(&ui_handle, "password_textbox")
+                .filter(|password| !password.is_empty());
+
+            let access = if self.host_invite_only {
+                LobbyAccess::InviteOnly
+            } else if password.is_some() {
+                LobbyAccess::Password
+            } else {
+                LobbyAccess::Public
+            };
+            let settings = LobbySettings::new(max_players, access);
+            let password = match access {
+                LobbyAccess::Password => password,
+                LobbyAccess::Public | LobbyAccess::InviteOnly => None,
+            };
+
+            // clear form
             ui_manager.set_text(&ui_handle, "name_textbox", "");
+            ui_manager.set_text(&ui_handle, "max_players_textbox", "");
+            ui_manager.set_text(&ui_handle, "password_textbox", "");
+            ui_manager.set_text(&ui_handle, "error_output_text", "");
+            self.host_invite_only = false;
+            ui_manager.set_text(&ui_handle, "invite_only_button_text", "invite only: off");
 
             // info!("Creating Match Lobby: {:?}", textbox_text);
 
             // send request to session server
-            let message = messages::MatchLobbyCreate::new(&textbox_text);
+            let message =
+                messages::MatchLobbyCreate::new(&textbox_text, settings, password.as_deref());
             session_client.send_message::<channels::ClientActionsChannel, _>(&message);
 
             go_to_sub_ui(sub_ui_event_writer, UiKey::MessageList);
@@ -348,19 +441,37 @@ impl LobbyManager {
                 let lobby_entity = *(self.lobby_entities.get(&lobby_id).unwrap());
                 let lobby = lobby_q.get(lobby_entity).unwrap();
 
-                // filter out lobbies that are in progress
-                if lobby.is_in_progress() {
+                // filter out lobbies that can't be joined or watched
+                if lobby.is_abandoned() {
+                    return;
+                }
+                let is_invited = self.invited_lobbies.contains(&lobby_id);
+                if lobby.is_invite_only() && !is_invited {
                     return;
                 }
 
                 let lobby_owner_entity = lobby.owner_user_entity.get(session_client).unwrap();
                 let owner_user = user_q.get(lobby_owner_entity).unwrap();
 
+                let mut details =
+                    format!("{}/{}", lobby.member_count(), lobby.settings.max_players());
+                if lobby.is_in_progress() {
+                    details.push_str(" (spectate)");
+                } else if is_invited {
+                    details.push_str(" (invited)");
+                } else if lobby.requires_password() {
+                    details.push_str(" (password)");
+                }
+                if lobby.is_full() {
+                    details.push_str(" (full)");
+                }
+
                 Self::add_lobby_item(
                     item_ctx,
                     lobby_ui_handle,
                     lobby.name.as_str(),
                     owner_user.name.as_str(),
+                    &details,
                 );
             },
         );
@@ -371,11 +482,13 @@ impl LobbyManager {
         ui: &UiHandle,
         lobby_name: &str,
         owner_name: &str,
+        details: &str,
     ) {
         item_ctx.add_copied_node(ui);
 
         item_ctx.set_text_by_id("match_name", lobby_name);
         item_ctx.set_text_by_id("username", owner_name);
+        item_ctx.set_text_by_id("details", details);
         item_ctx.register_ui_event::<LobbyListItemClickedEvent>("lobby_button");
         // uses container_ui_handle
     }
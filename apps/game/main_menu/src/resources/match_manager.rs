@@ -1,15 +1,21 @@
 use bevy_ecs::{event::EventWriter, system::Resource};
 
-use crate::ui::events::ResyncMainMenuUiEvent;
+use game_engine::social::{MatchOutcome, MatchUserResult};
+
+use crate::ui::events::{ResyncMainMenuUiEvent, ResyncMatchResultsUiEvent};
 
 #[derive(Resource)]
 pub struct MatchManager {
     in_match: bool,
+    last_results: Option<(MatchOutcome, Option<MatchUserResult>)>,
 }
 
 impl Default for MatchManager {
     fn default() -> Self {
-        Self { in_match: false }
+        Self {
+            in_match: false,
+            last_results: None,
+        }
     }
 }
 
@@ -23,11 +29,30 @@ impl MatchManager {
         resync_main_menu_ui_events: &mut EventWriter<ResyncMainMenuUiEvent>,
     ) {
         self.in_match = true;
+        self.last_results = None;
 
         resync_main_menu_ui_events.send(ResyncMainMenuUiEvent);
     }
 
+    pub fn end_match(
+        &mut self,
+        outcome: MatchOutcome,
+        result: Option<MatchUserResult>,
+        resync_main_menu_ui_events: &mut EventWriter<ResyncMainMenuUiEvent>,
+        resync_match_results_ui_events: &mut EventWriter<ResyncMatchResultsUiEvent>,
+    ) {
+        self.in_match = false;
+        self.last_results = Some((outcome, result));
+
+        resync_main_menu_ui_events.send(ResyncMainMenuUiEvent);
+        resync_match_results_ui_events.send(ResyncMatchResultsUiEvent);
+    }
+
     pub fn leave_match(&mut self) {
         self.in_match = false;
     }
+
+    pub fn last_results(&self) -> Option<&(MatchOutcome, Option<MatchUserResult>)> {
+        self.last_results.as_ref()
+    }
 }
@@ -1,6 +1,8 @@
 pub mod asset_catalog;
 pub mod chat_message_events;
 pub mod chat_message_manager;
+pub mod direct_message_manager;
+pub mod friend_manager;
 pub mod lobby_manager;
 pub mod match_manager;
 pub mod selfhood_events;
@@ -10,11 +10,24 @@ use game_engine::{
     },
 };
 
-use game_app_network::session::components::User;
+use game_app_network::session::{
+    components::{Lobby, User},
+    SessionClient,
+};
 
 use crate::{
-    resources::lobby_manager::LobbyManager,
-    ui::{events::ResyncUserListUiEvent, UiCatalog, UiKey},
+    resources::{
+        direct_message_manager::DirectMessageManager, friend_manager::FriendManager,
+        lobby_manager::LobbyManager,
+    },
+    ui::{
+        events::{
+            ResyncUserListUiEvent, UserListItemAddFriendClickedEvent,
+            UserListItemInviteClickedEvent, UserListItemKickClickedEvent,
+            UserListItemMessageClickedEvent,
+        },
+        UiCatalog, UiKey,
+    },
 };
 
 pub type UserId = u32;
@@ -84,6 +97,10 @@ impl UserManager {
         resync_ui_events.send(ResyncUserListUiEvent);
     }
 
+    pub fn get_user_entity(&self, user_id: &UserId) -> Option<Entity> {
+        self.users.get(user_id).copied()
+    }
+
     pub fn insert_user(
         &mut self,
         resync_events: &mut EventWriter<ResyncUserListUiEvent>,
@@ -110,10 +127,14 @@ impl UserManager {
 
     pub fn sync_with_collection(
         &mut self,
+        session_client: &SessionClient,
         ui_manager: &mut UiManager,
         asset_manager: &AssetManager,
         lobby_manager: &LobbyManager,
+        friend_manager: &FriendManager,
+        direct_message_manager: &DirectMessageManager,
         user_q: &Query<&User>,
+        lobby_q: &Query<&Lobby>,
     ) {
         if self.item_ui.is_none() {
             return;
@@ -125,6 +146,18 @@ impl UserManager {
             .get_current_lobby()
             .map(|lid| lobby_manager.get_lobby_entity(&lid).unwrap());
 
+        // the lobby owner gets to kick members, and invite others to a non-public lobby
+        let (self_is_lobby_owner, lobby_is_private) = lobby_entity_opt
+            .and_then(|lobby_entity| lobby_q.get(lobby_entity).ok())
+            .map(|lobby| {
+                let owner_user_entity = lobby.owner_user_entity.get(session_client);
+                let self_is_owner =
+                    owner_user_entity.is_some() && owner_user_entity == self.self_user_entity;
+                let is_private = lobby.is_invite_only() || lobby.requires_password();
+                (self_is_owner, is_private)
+            })
+            .unwrap_or((false, false));
+
         self.list_ui_ext.sync_with_collection(
             ui_manager,
             asset_manager,
@@ -134,9 +167,13 @@ impl UserManager {
                 let user_entity = self.users.get(&user_id).unwrap();
                 let user_entity = *user_entity;
 
+                let mut is_lobby_member = false;
                 if let Some(lobby_entity) = lobby_entity_opt {
                     // we need to check if the user is in the current lobby
-                    if !lobby_manager.user_is_in_lobby(&user_entity, &lobby_entity) {
+                    is_lobby_member = lobby_manager.user_is_in_lobby(&user_entity, &lobby_entity);
+
+                    // the owner of a private lobby also sees everyone they could invite
+                    if !is_lobby_member && !(self_is_lobby_owner && lobby_is_private) {
                         return;
                     }
                 }
@@ -153,7 +190,28 @@ impl UserManager {
                             false
                         }
                     };
-                    add_user_item(item_ctx, item_ui_handle, username, is_self, is_online);
+                    let can_kick = self_is_lobby_owner && is_lobby_member && !is_self;
+                    let can_invite =
+                        self_is_lobby_owner && lobby_is_private && !is_lobby_member && is_online;
+                    let can_add_friend = !is_self
+                        && !friend_manager.is_friend(&user_entity)
+                        && !friend_manager.has_incoming_request(&user_entity);
+                    let unread_count = if is_self {
+                        None
+                    } else {
+                        Some(direct_message_manager.unread_count(&user_entity))
+                    };
+                    add_user_item(
+                        item_ctx,
+                        item_ui_handle,
+                        username,
+                        is_self,
+                        is_online,
+                        can_kick,
+                        can_invite,
+                        can_add_friend,
+                        unread_count,
+                    );
                 }
             },
         );
@@ -166,6 +224,11 @@ fn add_user_item(
     username: &str,
     is_self: bool,
     is_online: bool,
+    can_kick: bool,
+    can_invite: bool,
+    can_add_friend: bool,
+    // None for ourselves, who can't be messaged
+    unread_count: Option<u32>,
 ) {
     item_ctx.add_copied_node(ui);
     item_ctx.set_text_by_id("username", username);
@@ -178,4 +241,19 @@ fn add_user_item(
             item_ctx.set_style_by_id("username", "offline");
         }
     }
+
+    item_ctx.set_button_enabled("kick_button", can_kick);
+    item_ctx.set_button_enabled("invite_button", can_invite);
+    item_ctx.set_button_enabled("add_friend_button", can_add_friend);
+    item_ctx.register_ui_event::<UserListItemKickClickedEvent>("kick_button");
+    item_ctx.register_ui_event::<UserListItemInviteClickedEvent>("invite_button");
+    item_ctx.register_ui_event::<UserListItemAddFriendClickedEvent>("add_friend_button");
+
+    let message_text = match unread_count {
+        Some(unread_count) if unread_count > 0 => format!("message ({})", unread_count),
+        _ => "message".to_string(),
+    };
+    item_ctx.set_text_by_id("message_button_text", &message_text);
+    item_ctx.set_button_enabled("message_button", unread_count.is_some());
+    item_ctx.register_ui_event::<UserListItemMessageClickedEvent>("message_button");
 }
@@ -16,12 +16,15 @@ use crate::{
     resources::{
         asset_catalog::{on_asset_load, AssetCatalog},
         chat_message_manager::ChatMessageManager,
+        direct_message_manager::DirectMessageManager,
+        friend_manager::FriendManager,
         lobby_manager::LobbyManager,
         user_manager::UserManager,
     },
     ui::{
         events::{
-            GoToSubUiEvent, ResyncLobbyListUiEvent, ResyncMessageListUiEvent, ResyncUserListUiEvent,
+            GoToSubUiEvent, ResyncConversationUiEvent, ResyncFriendListUiEvent,
+            ResyncLobbyListUiEvent, ResyncMessageListUiEvent, ResyncUserListUiEvent,
         },
         on_ui_load, UiCatalog,
     },
@@ -36,10 +39,14 @@ pub fn session_load_asset_events(
     mut asset_catalog: ResMut<AssetCatalog>,
     mut message_manager: ResMut<ChatMessageManager>,
     mut lobby_manager: ResMut<LobbyManager>,
+    mut friend_manager: ResMut<FriendManager>,
+    mut direct_message_manager: ResMut<DirectMessageManager>,
     mut asset_loaded_event_reader: EventReader<AssetLoadedEvent>,
     mut resync_user_ui_events: EventWriter<ResyncUserListUiEvent>,
     mut resync_chat_message_ui_events: EventWriter<ResyncMessageListUiEvent>,
     mut resync_lobby_ui_events: EventWriter<ResyncLobbyListUiEvent>,
+    mut resync_friend_ui_events: EventWriter<ResyncFriendListUiEvent>,
+    mut resync_conversation_ui_events: EventWriter<ResyncConversationUiEvent>,
     mut sub_ui_event_writer: EventWriter<GoToSubUiEvent>,
 ) {
     for event in asset_loaded_event_reader.read() {
@@ -57,10 +64,14 @@ pub fn session_load_asset_events(
                     &mut user_manager,
                     &mut message_manager,
                     &mut lobby_manager,
+                    &mut friend_manager,
+                    &mut direct_message_manager,
                     &mut sub_ui_event_writer,
                     &mut resync_user_ui_events,
                     &mut resync_chat_message_ui_events,
                     &mut resync_lobby_ui_events,
+                    &mut resync_friend_ui_events,
+                    &mut resync_conversation_ui_events,
                     asset_id,
                 );
             }
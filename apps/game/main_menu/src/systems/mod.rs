@@ -1,5 +1,6 @@
 pub mod asset_events;
 pub mod cube_scene;
 pub mod initial_spinner;
+pub mod portrait_scene;
 pub(crate) mod resize;
 pub mod session_component_events;
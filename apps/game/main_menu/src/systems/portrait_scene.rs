@@ -0,0 +1,129 @@
+use bevy_ecs::{
+    component::Component,
+    query::With,
+    system::{Commands, Query, Res, ResMut},
+};
+
+use game_engine::{
+    math::Vec3,
+    render::{
+        base::{Color, CpuMaterial, CpuMesh, CpuTexture2D},
+        components::{
+            AmbientLight, Camera, CameraBundle, ClearOperation, DirectionalLight,
+            PerspectiveProjection, Projection, RenderLayers, RenderObjectBundle, RenderTarget,
+            Transform, Viewport,
+        },
+        resources::Time,
+        shapes,
+    },
+    storage::Storage,
+};
+
+// only a starting size, the ui resizes it to how large the user card shows it
+const PORTRAIT_TEXTURE_SIZE: u32 = 256;
+
+#[derive(Component)]
+pub struct PortraitMarker;
+
+#[derive(Component)]
+pub struct PortraitCamera;
+
+// a stand-in character, rendered into a texture that the main menu's user card shows
+pub fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Storage<CpuMesh>>,
+    mut materials: ResMut<Storage<CpuMaterial>>,
+    mut textures: ResMut<Storage<CpuTexture2D>>,
+) {
+    // render_layer, kept apart from the cube scene so only the character is in frame
+    let layer = RenderLayers::layer(2);
+
+    // body
+    commands
+        .spawn(RenderObjectBundle {
+            mesh: meshes.add(shapes::Cube),
+            material: materials.add(Color::BLUE),
+            transform: Transform::from_translation(Vec3::new(0.0, 0.0, 20.0))
+                .with_scale(Vec3::new(8.0, 12.0, 20.0)),
+            ..Default::default()
+        })
+        .insert(PortraitMarker)
+        .insert(layer);
+
+    // head
+    commands
+        .spawn(RenderObjectBundle {
+            mesh: meshes.add(shapes::Sphere::new(16)),
+            material: materials.add(Color::LIGHT_GRAY),
+            transform: Transform::from_translation(Vec3::new(0.0, 0.0, 50.0))
+                .with_scale(Vec3::splat(9.0)),
+            ..Default::default()
+        })
+        .insert(PortraitMarker)
+        .insert(layer);
+
+    // ambient light
+    commands
+        .spawn(AmbientLight::new(0.2, Color::WHITE))
+        .insert(layer);
+
+    // directional light
+    let light_source = Vec3::new(200.0, 100.0, 200.0);
+    let light_target = Vec3::ZERO;
+    commands
+        .spawn(DirectionalLight::new(
+            2.0,
+            Color::WHITE,
+            light_target - light_source,
+        ))
+        .insert(layer);
+
+    // camera, only active while the main menu is shown
+    let texture_handle = textures.add_unique(CpuTexture2D::from_size(
+        PORTRAIT_TEXTURE_SIZE,
+        PORTRAIT_TEXTURE_SIZE,
+    ));
+    commands
+        .spawn(CameraBundle {
+            camera: Camera {
+                viewport: Some(Viewport::new_at_origin(
+                    PORTRAIT_TEXTURE_SIZE,
+                    PORTRAIT_TEXTURE_SIZE,
+                )),
+                clear_operation: ClearOperation::from_rgba(0.0, 0.0, 0.0, 1.0),
+                target: RenderTarget::Image(texture_handle),
+                is_active: false,
+                ..Default::default()
+            },
+            transform: Transform::from_xyz(120.0, 0.0, 45.0)
+                .looking_at(Vec3::new(0.0, 0.0, 35.0), Vec3::Z),
+            projection: Projection::Perspective(PerspectiveProjection {
+                fov: std::f32::consts::PI / 4.0,
+                near: 0.1,
+                far: 1000.0,
+            }),
+        })
+        .insert(PortraitCamera)
+        .insert(layer);
+}
+
+pub fn step(time: Res<Time>, mut object_q: Query<&mut Transform, With<PortraitMarker>>) {
+    let elapsed_time = time.get_elapsed_ms();
+
+    // every part is centered on the z axis, so turning each one turns the whole character
+    for mut transform in object_q.iter_mut() {
+        transform.rotate_z(0.001 * elapsed_time);
+    }
+}
+
+pub fn activate_camera(mut camera_q: Query<&mut Camera, With<PortraitCamera>>) {
+    for mut camera in camera_q.iter_mut() {
+        camera.is_active = true;
+    }
+}
+
+pub fn deactivate_camera(mut camera_q: Query<&mut Camera, With<PortraitCamera>>) {
+    for mut camera in camera_q.iter_mut() {
+        camera.is_active = false;
+    }
+}
@@ -49,7 +49,6 @@ fn recv_inserted_chat_message_components(
         &mut chat_message_local_event_reader,
     ) {
         let message = chat_message_q.get(message_entity).unwrap();
-        let message_id = *message.id;
 
         let timestamp = *message.timestamp;
         let message = &*message.message;
@@ -76,11 +75,12 @@ fn recv_inserted_chat_message_components(
             );
         }
 
+        let message = chat_message_q.get(message_entity).unwrap();
         chat_message_manager.recv_message(
+            &session_client,
             &lobby_id_opt,
             &mut resync_message_list_ui_events,
-            message_id,
-            message_entity,
+            message,
         );
     }
 }
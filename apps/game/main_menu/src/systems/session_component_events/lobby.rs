@@ -62,6 +62,7 @@ fn recv_updated_lobby_component(
     mut match_manager: ResMut<MatchManager>,
     mut resync_main_menu_ui_events: EventWriter<ResyncMainMenuUiEvent>,
     mut resync_lobby_list_ui_events: EventWriter<ResyncLobbyListUiEvent>,
+    mut resync_user_list_ui_events: EventWriter<ResyncUserListUiEvent>,
     lobby_q: Query<&Lobby>,
     mut update_lobby_component_event_reader: EventReader<SessionUpdateComponentEvent<Lobby>>,
 ) {
@@ -81,6 +82,10 @@ fn recv_updated_lobby_component(
             continue;
         }
 
+        // ownership or settings may have changed
+        resync_main_menu_ui_events.send(ResyncMainMenuUiEvent);
+        resync_user_list_ui_events.send(ResyncUserListUiEvent);
+
         if match_manager.in_match() {
             continue;
         }
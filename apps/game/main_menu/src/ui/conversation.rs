@@ -0,0 +1,173 @@
+use bevy_ecs::{
+    change_detection::{Res, ResMut},
+    event::{EventReader, EventWriter},
+    prelude::Query,
+};
+
+use game_engine::{asset::AssetManager, input::InputEvent, ui::UiManager};
+
+use game_app_network::session::{
+    channels::PrimaryChannel, components::User, messages, SessionClient, SessionMessageEvents,
+};
+
+use crate::{
+    resources::{direct_message_manager::DirectMessageManager, user_manager::UserManager},
+    ui::{
+        events::{
+            BlockButtonClickedEvent, ResyncConversationUiEvent, ResyncMessageListUiEvent,
+            ResyncUserListUiEvent,
+        },
+        UiCatalog, UiKey,
+    },
+};
+
+pub(crate) fn on_load_conversation_ui(
+    ui_catalog: &mut UiCatalog,
+    ui_manager: &mut UiManager,
+    direct_message_manager: &mut DirectMessageManager,
+    resync_conversation_ui_events: &mut EventWriter<ResyncConversationUiEvent>,
+) {
+    let ui_handle = ui_catalog.get_ui_handle(UiKey::Conversation);
+
+    ui_manager.register_ui_event::<BlockButtonClickedEvent>(&ui_handle, "block_button");
+
+    direct_message_manager.on_load_conversation_ui(
+        ui_catalog,
+        ui_manager,
+        resync_conversation_ui_events,
+    );
+}
+
+pub(crate) fn on_enter_state(
+    resync_conversation_ui_event_writer: &mut EventWriter<ResyncConversationUiEvent>,
+) {
+    resync_conversation_ui_event_writer.send(ResyncConversationUiEvent);
+}
+
+pub(crate) fn on_leave_state(direct_message_manager: &mut DirectMessageManager) {
+    direct_message_manager.close_conversation();
+}
+
+pub(crate) fn handle_conversation_interaction_events(
+    ui_catalog: Res<UiCatalog>,
+    mut ui_manager: ResMut<UiManager>,
+    mut session_client: SessionClient,
+    mut direct_message_manager: ResMut<DirectMessageManager>,
+    mut input_events: EventReader<InputEvent>,
+    mut block_click_events: EventReader<BlockButtonClickedEvent>,
+    mut resync_conversation_ui_events: EventWriter<ResyncConversationUiEvent>,
+) {
+    // Block Button Click
+    {
+        let mut block_clicked = false;
+        for _ in block_click_events.read() {
+            block_clicked = true;
+        }
+        if block_clicked {
+            direct_message_manager.send_toggle_block(&mut session_client);
+        }
+    }
+
+    let Some(active_ui_handle) = ui_manager.active_ui() else {
+        return;
+    };
+    if ui_catalog.get_ui_key(&active_ui_handle) != UiKey::MainMenu {
+        panic!("unexpected ui");
+    }
+
+    if let Some(current_ui_handle) =
+        ui_manager.get_ui_container_contents(&active_ui_handle, "center_container")
+    {
+        if UiKey::Conversation == ui_catalog.get_ui_key(&current_ui_handle) {
+            direct_message_manager.handle_interaction_events(
+                &mut ui_manager,
+                &ui_catalog,
+                &mut session_client,
+                &mut input_events,
+                &mut resync_conversation_ui_events,
+            );
+        }
+    };
+}
+
+pub(crate) fn recv_direct_messages(
+    session_client: SessionClient,
+    mut direct_message_manager: ResMut<DirectMessageManager>,
+    mut resync_conversation_ui_events: EventWriter<ResyncConversationUiEvent>,
+    mut resync_user_list_ui_events: EventWriter<ResyncUserListUiEvent>,
+    mut resync_message_list_ui_events: EventWriter<ResyncMessageListUiEvent>,
+    mut event_reader: EventReader<SessionMessageEvents>,
+) {
+    let mut unread_changed = false;
+    let mut blocks_changed = false;
+
+    for events in event_reader.read() {
+        for message in events.read::<PrimaryChannel, messages::DirectMessageReceived>() {
+            let Some(user_entity) = message.user_entity.get(&session_client) else {
+                continue;
+            };
+            unread_changed |= direct_message_manager.recv_message(
+                &mut resync_conversation_ui_events,
+                user_entity,
+                &message,
+            );
+        }
+        for message in events.read::<PrimaryChannel, messages::UserBlocked>() {
+            let Some(user_entity) = message.user_entity.get(&session_client) else {
+                continue;
+            };
+            direct_message_manager.recv_user_blocked(
+                &mut resync_conversation_ui_events,
+                user_entity,
+                message.blocked,
+            );
+            blocks_changed = true;
+        }
+    }
+
+    // the user list shows unread counts
+    if unread_changed || blocks_changed {
+        resync_user_list_ui_events.send(ResyncUserListUiEvent);
+    }
+    // chat hides messages from blocked users
+    if blocks_changed {
+        resync_message_list_ui_events.send(ResyncMessageListUiEvent::new(true));
+    }
+}
+
+pub(crate) fn handle_resync_conversation_ui_events(
+    mut ui_manager: ResMut<UiManager>,
+    ui_catalog: Res<UiCatalog>,
+    asset_manager: Res<AssetManager>,
+    user_manager: Res<UserManager>,
+    mut direct_message_manager: ResMut<DirectMessageManager>,
+    user_q: Query<&User>,
+    mut resync_conversation_ui_events: EventReader<ResyncConversationUiEvent>,
+) {
+    let mut resync = false;
+    for _ in resync_conversation_ui_events.read() {
+        resync = true;
+    }
+    if !resync {
+        return;
+    }
+
+    if !ui_catalog.get_is_loaded(UiKey::Conversation) {
+        return;
+    }
+    let ui_handle = ui_catalog.get_ui_handle(UiKey::Conversation);
+    let is_blocked = direct_message_manager
+        .current_conversation()
+        .map(|user_entity| direct_message_manager.is_blocked(&user_entity))
+        .unwrap_or(false);
+    let block_text = if is_blocked { "unblock" } else { "block" };
+    ui_manager.set_text(&ui_handle, "block_button_text", block_text);
+
+    direct_message_manager.sync_with_collection(
+        &mut ui_manager,
+        &ui_catalog,
+        &asset_manager,
+        &user_manager,
+        &user_q,
+    );
+}
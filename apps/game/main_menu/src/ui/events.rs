@@ -1,8 +1,8 @@
-use bevy_ecs::event::Event;
+use bevy_ecs::{entity::Entity, event::Event};
 
 use game_engine::social::LobbyId;
 
-use crate::ui::UiKey;
+use crate::{resources::user_manager::UserId, ui::UiKey};
 
 #[derive(Event, Default)]
 pub struct HostMatchButtonClickedEvent;
@@ -13,6 +13,9 @@ pub struct JoinMatchButtonClickedEvent;
 #[derive(Event, Default)]
 pub struct GlobalChatButtonClickedEvent;
 
+#[derive(Event, Default)]
+pub struct FriendsButtonClickedEvent;
+
 #[derive(Event, Default)]
 pub struct DevlogButtonClickedEvent;
 
@@ -31,6 +34,15 @@ pub struct LeaveLobbyButtonClickedEvent;
 #[derive(Event, Default)]
 pub struct SubmitButtonClickedEvent;
 
+#[derive(Event, Default)]
+pub struct InviteOnlyButtonClickedEvent;
+
+#[derive(Event, Default)]
+pub struct AwayButtonClickedEvent;
+
+#[derive(Event, Default)]
+pub struct BlockButtonClickedEvent;
+
 #[derive(Event)]
 pub struct LobbyListItemClickedEvent {
     lobby_id: LobbyId,
@@ -54,6 +66,166 @@ impl From<LobbyId> for LobbyListItemClickedEvent {
     }
 }
 
+#[derive(Event)]
+pub struct UserListItemKickClickedEvent {
+    user_id: UserId,
+}
+impl Default for UserListItemKickClickedEvent {
+    fn default() -> Self {
+        panic!("UserListItemKickClickedEvent::default() should not be used");
+    }
+}
+impl UserListItemKickClickedEvent {
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+}
+impl From<UserId> for UserListItemKickClickedEvent {
+    fn from(user_id: UserId) -> Self {
+        Self { user_id }
+    }
+}
+
+#[derive(Event)]
+pub struct UserListItemInviteClickedEvent {
+    user_id: UserId,
+}
+impl Default for UserListItemInviteClickedEvent {
+    fn default() -> Self {
+        panic!("UserListItemInviteClickedEvent::default() should not be used");
+    }
+}
+impl UserListItemInviteClickedEvent {
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+}
+impl From<UserId> for UserListItemInviteClickedEvent {
+    fn from(user_id: UserId) -> Self {
+        Self { user_id }
+    }
+}
+
+#[derive(Event)]
+pub struct UserListItemAddFriendClickedEvent {
+    user_id: UserId,
+}
+impl Default for UserListItemAddFriendClickedEvent {
+    fn default() -> Self {
+        panic!("UserListItemAddFriendClickedEvent::default() should not be used");
+    }
+}
+impl UserListItemAddFriendClickedEvent {
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+}
+impl From<UserId> for UserListItemAddFriendClickedEvent {
+    fn from(user_id: UserId) -> Self {
+        Self { user_id }
+    }
+}
+
+#[derive(Event)]
+pub struct UserListItemMessageClickedEvent {
+    user_id: UserId,
+}
+impl Default for UserListItemMessageClickedEvent {
+    fn default() -> Self {
+        panic!("UserListItemMessageClickedEvent::default() should not be used");
+    }
+}
+impl UserListItemMessageClickedEvent {
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+}
+impl From<UserId> for UserListItemMessageClickedEvent {
+    fn from(user_id: UserId) -> Self {
+        Self { user_id }
+    }
+}
+
+#[derive(Event)]
+pub struct FriendListItemJoinClickedEvent {
+    user_entity: Entity,
+}
+impl Default for FriendListItemJoinClickedEvent {
+    fn default() -> Self {
+        panic!("FriendListItemJoinClickedEvent::default() should not be used");
+    }
+}
+impl FriendListItemJoinClickedEvent {
+    pub fn user_entity(&self) -> Entity {
+        self.user_entity
+    }
+}
+impl From<Entity> for FriendListItemJoinClickedEvent {
+    fn from(user_entity: Entity) -> Self {
+        Self { user_entity }
+    }
+}
+
+#[derive(Event)]
+pub struct FriendListItemRemoveClickedEvent {
+    user_entity: Entity,
+}
+impl Default for FriendListItemRemoveClickedEvent {
+    fn default() -> Self {
+        panic!("FriendListItemRemoveClickedEvent::default() should not be used");
+    }
+}
+impl FriendListItemRemoveClickedEvent {
+    pub fn user_entity(&self) -> Entity {
+        self.user_entity
+    }
+}
+impl From<Entity> for FriendListItemRemoveClickedEvent {
+    fn from(user_entity: Entity) -> Self {
+        Self { user_entity }
+    }
+}
+
+#[derive(Event)]
+pub struct FriendListItemAcceptClickedEvent {
+    user_entity: Entity,
+}
+impl Default for FriendListItemAcceptClickedEvent {
+    fn default() -> Self {
+        panic!("FriendListItemAcceptClickedEvent::default() should not be used");
+    }
+}
+impl FriendListItemAcceptClickedEvent {
+    pub fn user_entity(&self) -> Entity {
+        self.user_entity
+    }
+}
+impl From<Entity> for FriendListItemAcceptClickedEvent {
+    fn from(user_entity: Entity) -> Self {
+        Self { user_entity }
+    }
+}
+
+#[derive(Event)]
+pub struct FriendListItemDeclineClickedEvent {
+    user_entity: Entity,
+}
+impl Default for FriendListItemDeclineClickedEvent {
+    fn default() -> Self {
+        panic!("FriendListItemDeclineClickedEvent::default() should not be used");
+    }
+}
+impl FriendListItemDeclineClickedEvent {
+    pub fn user_entity(&self) -> Entity {
+        self.user_entity
+    }
+}
+impl From<Entity> for FriendListItemDeclineClickedEvent {
+    fn from(user_entity: Entity) -> Self {
+        Self { user_entity }
+    }
+}
+
 // UI events
 
 #[derive(Event, Default)]
@@ -81,3 +253,12 @@ impl ResyncMessageListUiEvent {
 
 #[derive(Event, Default)]
 pub struct ResyncLobbyListUiEvent;
+
+#[derive(Event, Default)]
+pub struct ResyncMatchResultsUiEvent;
+
+#[derive(Event, Default)]
+pub struct ResyncFriendListUiEvent;
+
+#[derive(Event, Default)]
+pub struct ResyncConversationUiEvent;
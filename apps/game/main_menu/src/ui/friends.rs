@@ -0,0 +1,212 @@
+use bevy_ecs::{
+    change_detection::{Res, ResMut},
+    event::{EventReader, EventWriter},
+    prelude::Query,
+};
+
+use game_engine::{
+    asset::AssetManager,
+    logging::info,
+    social::FriendAction,
+    ui::{UiHandle, UiManager},
+};
+
+use game_app_network::session::{
+    channels::{self, PrimaryChannel},
+    components::{Lobby, User},
+    messages, SessionClient, SessionMessageEvents,
+};
+
+use crate::{
+    resources::{friend_manager::FriendManager, lobby_manager::LobbyManager},
+    ui::{
+        events::{
+            AwayButtonClickedEvent, FriendListItemAcceptClickedEvent,
+            FriendListItemDeclineClickedEvent, FriendListItemJoinClickedEvent,
+            FriendListItemRemoveClickedEvent, GoToSubUiEvent, ResyncFriendListUiEvent,
+            ResyncUserListUiEvent,
+        },
+        go_to_sub_ui, UiCatalog, UiKey,
+    },
+};
+
+pub(crate) fn on_load_friends_ui(
+    ui_catalog: &mut UiCatalog,
+    ui_manager: &mut UiManager,
+    friend_manager: &mut FriendManager,
+    resync_friend_list_ui_events: &mut EventWriter<ResyncFriendListUiEvent>,
+) {
+    let ui_handle = ui_catalog.get_ui_handle(UiKey::Friends);
+
+    ui_manager.register_ui_event::<AwayButtonClickedEvent>(&ui_handle, "away_button");
+
+    friend_manager.on_load_friends_ui(ui_catalog, ui_manager, resync_friend_list_ui_events);
+}
+
+pub(crate) fn on_enter_state(
+    resync_friend_list_ui_event_writer: &mut EventWriter<ResyncFriendListUiEvent>,
+) {
+    resync_friend_list_ui_event_writer.send(ResyncFriendListUiEvent);
+}
+
+pub fn on_leave_state(_ui_manager: &mut UiManager, _ui_handle: &UiHandle) {
+    // TODO: implement
+}
+
+pub(crate) fn handle_friends_interaction_events(
+    mut session_client: SessionClient,
+    mut friend_manager: ResMut<FriendManager>,
+    mut sub_ui_event_writer: EventWriter<GoToSubUiEvent>,
+    mut resync_friend_list_ui_events: EventWriter<ResyncFriendListUiEvent>,
+    mut away_click_events: EventReader<AwayButtonClickedEvent>,
+    mut join_click_events: EventReader<FriendListItemJoinClickedEvent>,
+    mut remove_click_events: EventReader<FriendListItemRemoveClickedEvent>,
+    mut accept_click_events: EventReader<FriendListItemAcceptClickedEvent>,
+    mut decline_click_events: EventReader<FriendListItemDeclineClickedEvent>,
+) {
+    // Away Button Click
+    {
+        let mut away_clicked = false;
+        for _ in away_click_events.read() {
+            away_clicked = true;
+        }
+        if away_clicked {
+            let away = friend_manager.toggle_away(&mut resync_friend_list_ui_events);
+            info!("setting away: {:?}", away);
+
+            let message = messages::UserSetAway::new(away);
+            session_client.send_message::<channels::ClientActionsChannel, _>(&message);
+        }
+    }
+
+    // Join Friend's Lobby
+    for event in join_click_events.read() {
+        let Some(lobby_id) = friend_manager
+            .get_friend_presence(&event.user_entity())
+            .and_then(|presence| presence.lobby_id())
+        else {
+            continue;
+        };
+
+        info!("joining friend's lobby: {:?}", lobby_id.to_u16());
+
+        let message = messages::MatchLobbyJoin::new(lobby_id, None);
+        session_client.send_message::<channels::ClientActionsChannel, _>(&message);
+
+        go_to_sub_ui(&mut sub_ui_event_writer, UiKey::MessageList);
+
+        // prevent multiple clicks
+        break;
+    }
+
+    // Friend Actions
+    for (user_entity, action) in remove_click_events
+        .read()
+        .map(|event| (event.user_entity(), FriendAction::Remove))
+        .chain(
+            accept_click_events
+                .read()
+                .map(|event| (event.user_entity(), FriendAction::AcceptRequest)),
+        )
+        .chain(
+            decline_click_events
+                .read()
+                .map(|event| (event.user_entity(), FriendAction::DeclineRequest)),
+        )
+    {
+        info!("friend action {:?} on user: {:?}", action, user_entity);
+
+        let mut message = messages::FriendActionRequest::new(action);
+        message.user_entity.set(&session_client, &user_entity);
+        session_client.send_message::<channels::ClientActionsChannel, _>(&message);
+    }
+}
+
+pub(crate) fn recv_friend_messages(
+    session_client: SessionClient,
+    mut friend_manager: ResMut<FriendManager>,
+    mut resync_friend_list_ui_events: EventWriter<ResyncFriendListUiEvent>,
+    mut resync_user_list_ui_events: EventWriter<ResyncUserListUiEvent>,
+    mut event_reader: EventReader<SessionMessageEvents>,
+) {
+    let mut friends_changed = false;
+
+    for events in event_reader.read() {
+        for message in events.read::<PrimaryChannel, messages::FriendUpdated>() {
+            let Some(user_entity) = message.user_entity.get(&session_client) else {
+                continue;
+            };
+            friend_manager.recv_friend_updated(
+                &mut resync_friend_list_ui_events,
+                user_entity,
+                message.presence,
+            );
+            friends_changed = true;
+        }
+        for message in events.read::<PrimaryChannel, messages::FriendRemoved>() {
+            let Some(user_entity) = message.user_entity.get(&session_client) else {
+                continue;
+            };
+            friend_manager.recv_friend_removed(&mut resync_friend_list_ui_events, &user_entity);
+            friends_changed = true;
+        }
+        for message in events.read::<PrimaryChannel, messages::FriendRequestReceived>() {
+            let Some(user_entity) = message.user_entity.get(&session_client) else {
+                continue;
+            };
+            info!("received friend request from: {:?}", user_entity);
+            friend_manager.recv_friend_request(&mut resync_friend_list_ui_events, user_entity);
+            friends_changed = true;
+        }
+        for message in events.read::<PrimaryChannel, messages::FriendRequestRemoved>() {
+            let Some(user_entity) = message.user_entity.get(&session_client) else {
+                continue;
+            };
+            friend_manager.remove_friend_request(&mut resync_friend_list_ui_events, &user_entity);
+            friends_changed = true;
+        }
+    }
+
+    // the user list shows "add friend" only for non-friends
+    if friends_changed {
+        resync_user_list_ui_events.send(ResyncUserListUiEvent);
+    }
+}
+
+pub(crate) fn handle_resync_friend_list_ui_events(
+    mut ui_manager: ResMut<UiManager>,
+    ui_catalog: Res<UiCatalog>,
+    asset_manager: Res<AssetManager>,
+    lobby_manager: Res<LobbyManager>,
+    mut friend_manager: ResMut<FriendManager>,
+    user_q: Query<&User>,
+    lobby_q: Query<&Lobby>,
+    mut resync_friend_list_ui_events: EventReader<ResyncFriendListUiEvent>,
+) {
+    let mut resync = false;
+    for _ in resync_friend_list_ui_events.read() {
+        resync = true;
+    }
+    if !resync {
+        return;
+    }
+
+    if !ui_catalog.get_is_loaded(UiKey::Friends) {
+        return;
+    }
+    let ui_handle = ui_catalog.get_ui_handle(UiKey::Friends);
+    let away_text = if friend_manager.is_away() {
+        "status: away"
+    } else {
+        "status: online"
+    };
+    ui_manager.set_text(&ui_handle, "away_button_text", away_text);
+
+    friend_manager.sync_with_collection(
+        &mut ui_manager,
+        &asset_manager,
+        &lobby_manager,
+        &user_q,
+        &lobby_q,
+    );
+}
@@ -15,7 +15,7 @@ use game_app_network::session::SessionClient;
 use crate::{
     resources::lobby_manager::LobbyManager,
     ui::{
-        events::{GoToSubUiEvent, SubmitButtonClickedEvent},
+        events::{GoToSubUiEvent, InviteOnlyButtonClickedEvent, SubmitButtonClickedEvent},
         UiCatalog, UiKey,
     },
 };
@@ -26,6 +26,7 @@ pub fn on_load_host_match_ui(ui_catalog: &mut UiCatalog, ui_manager: &mut UiMana
 
     ui_catalog.set_loaded(ui_key);
     ui_manager.register_ui_event::<SubmitButtonClickedEvent>(&ui_handle, "submit_button");
+    ui_manager.register_ui_event::<InviteOnlyButtonClickedEvent>(&ui_handle, "invite_only_button");
 }
 
 pub(crate) fn handle_host_match_events(
@@ -37,6 +38,7 @@ pub(crate) fn handle_host_match_events(
     mut lobby_manager: ResMut<LobbyManager>,
     mut sub_ui_event_writer: EventWriter<GoToSubUiEvent>,
     mut submit_btn_rdr: EventReader<SubmitButtonClickedEvent>,
+    mut invite_only_btn_rdr: EventReader<InviteOnlyButtonClickedEvent>,
 ) {
     let Some(active_ui_handle) = ui_manager.active_ui() else {
         return;
@@ -58,6 +60,7 @@ pub(crate) fn handle_host_match_events(
                 &mut session_client,
                 &mut sub_ui_event_writer,
                 &mut submit_btn_rdr,
+                &mut invite_only_btn_rdr,
                 &mut should_rumble,
             );
         }
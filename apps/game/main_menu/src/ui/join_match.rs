@@ -12,9 +12,9 @@ use game_engine::{
 };
 
 use game_app_network::session::{
-    channels,
+    channels::{self, PrimaryChannel},
     components::{Lobby, User},
-    messages, SessionClient,
+    messages, SessionClient, SessionMessageEvents,
 };
 
 use crate::{
@@ -55,8 +55,10 @@ pub(crate) fn handle_join_match_input_events(
 
 pub(crate) fn handle_join_match_click_events(
     ui_catalog: Res<UiCatalog>,
-    ui_manager: Res<UiManager>,
+    mut ui_manager: ResMut<UiManager>,
+    lobby_manager: Res<LobbyManager>,
     mut session_client: SessionClient,
+    lobby_q: Query<&Lobby>,
     mut resync_lobby_list_ui_events: EventWriter<ResyncLobbyListUiEvent>,
     mut sub_ui_event_writer: EventWriter<GoToSubUiEvent>,
     mut click_events: EventReader<LobbyListItemClickedEvent>,
@@ -74,7 +76,11 @@ pub(crate) fn handle_join_match_click_events(
         let ui_key = ui_catalog.get_ui_key(&current_ui_handle);
         if ui_key == UiKey::JoinMatch {
             handle_join_match_click_events_impl(
+                &mut ui_manager,
+                &current_ui_handle,
+                &lobby_manager,
                 &mut session_client,
+                &lobby_q,
                 &mut resync_lobby_list_ui_events,
                 &mut sub_ui_event_writer,
                 &mut click_events,
@@ -136,7 +142,11 @@ fn handle_join_match_input_events_impl(
 }
 
 fn handle_join_match_click_events_impl(
+    ui_manager: &mut UiManager,
+    join_match_ui_handle: &UiHandle,
+    lobby_manager: &LobbyManager,
     session_client: &mut SessionClient,
+    lobby_q: &Query<&Lobby>,
     resync_lobby_ui_events: &mut EventWriter<ResyncLobbyListUiEvent>,
     sub_ui_event_writer: &mut EventWriter<GoToSubUiEvent>,
     click_events: &mut EventReader<LobbyListItemClickedEvent>,
@@ -148,9 +158,47 @@ fn handle_join_match_click_events_impl(
             // prevent multiple clicks
 
             let lobby_id = event.lobby_id();
+
+            let Some(lobby) = lobby_manager
+                .get_lobby_entity(&lobby_id)
+                .and_then(|lobby_entity| lobby_q.get(lobby_entity).ok())
+            else {
+                continue;
+            };
+            if lobby.is_in_progress() {
+                info!("Spectating match: {:?}", lobby_id.to_u16());
+
+                // the world connect token arrives once the world server admits us
+                let message = messages::MatchLobbySpectate::new(lobby_id);
+                session_client.send_message::<channels::ClientActionsChannel, _>(&message);
+
+                should_resync = true;
+                continue;
+            }
+            if lobby.is_full() {
+                info!("Lobby is full: {:?}", lobby_id.to_u16());
+                continue;
+            }
+
+            // an invite gets past the password
+            let password =
+                if lobby.requires_password() && !lobby_manager.is_invited_to_lobby(&lobby_id) {
+                    let password = ui_manager
+                        .get_text(join_match_ui_handle, "password_textbox")
+                        .unwrap_or_default();
+                    if password.is_empty() {
+                        info!("Lobby requires a password: {:?}", lobby_id.to_u16());
+                        continue;
+                    }
+                    ui_manager.set_text(join_match_ui_handle, "password_textbox", "");
+                    Some(password)
+                } else {
+                    None
+                };
+
             info!("Joining lobby: {:?}", lobby_id.to_u16());
 
-            let message = messages::MatchLobbyJoin::new(lobby_id);
+            let message = messages::MatchLobbyJoin::new(lobby_id, password.as_deref());
             session_client.send_message::<channels::ClientActionsChannel, _>(&message);
 
             go_to_sub_ui(sub_ui_event_writer, UiKey::MessageList);
@@ -164,6 +212,36 @@ fn handle_join_match_click_events_impl(
     }
 }
 
+pub(crate) fn recv_match_lobby_kicked_messages(
+    ui_catalog: Res<UiCatalog>,
+    mut sub_ui_event_writer: EventWriter<GoToSubUiEvent>,
+    mut event_reader: EventReader<SessionMessageEvents>,
+) {
+    for events in event_reader.read() {
+        for message in events.read::<PrimaryChannel, messages::MatchLobbyKicked>() {
+            info!("kicked from lobby: {:?}", message.lobby_id.to_u16());
+
+            if ui_catalog.get_is_loaded(UiKey::JoinMatch) {
+                go_to_sub_ui(&mut sub_ui_event_writer, UiKey::JoinMatch);
+            }
+        }
+    }
+}
+
+pub(crate) fn recv_match_lobby_invited_messages(
+    mut lobby_manager: ResMut<LobbyManager>,
+    mut resync_lobby_ui_events: EventWriter<ResyncLobbyListUiEvent>,
+    mut event_reader: EventReader<SessionMessageEvents>,
+) {
+    for events in event_reader.read() {
+        for message in events.read::<PrimaryChannel, messages::MatchLobbyInvited>() {
+            info!("invited to lobby: {:?}", message.lobby_id.to_u16());
+
+            lobby_manager.recv_lobby_invite(message.lobby_id, &mut resync_lobby_ui_events);
+        }
+    }
+}
+
 fn handle_resync_lobby_list_ui_events_impl(
     lobby_manager: &mut LobbyManager,
     ui_manager: &mut UiManager,
@@ -3,14 +3,15 @@ use std::time::Duration;
 use bevy_ecs::{
     change_detection::{Res, ResMut},
     event::{EventReader, EventWriter},
-    system::Query,
+    query::With,
+    system::{Local, Query},
 };
 use bevy_state::state::NextState;
 
 use game_engine::{
     input::{GamepadRumbleIntensity, Input, RumbleManager},
     logging::info,
-    render::components::RenderLayers,
+    render::components::{Camera, RenderLayers, RenderTarget},
     ui::UiManager,
 };
 
@@ -21,12 +22,14 @@ use crate::{
     resources::{
         lobby_manager::LobbyManager, match_manager::MatchManager, user_manager::UserManager,
     },
+    systems::portrait_scene::PortraitCamera,
     ui::{
         events::{
-            CurrentLobbyButtonClickedEvent, DevlogButtonClickedEvent, GlobalChatButtonClickedEvent,
-            GoToSubUiEvent, HostMatchButtonClickedEvent, JoinMatchButtonClickedEvent,
-            LeaveLobbyButtonClickedEvent, ResyncMainMenuUiEvent, ResyncMessageListUiEvent,
-            ResyncUserListUiEvent, SettingsButtonClickedEvent, StartMatchButtonClickedEvent,
+            CurrentLobbyButtonClickedEvent, DevlogButtonClickedEvent, FriendsButtonClickedEvent,
+            GlobalChatButtonClickedEvent, GoToSubUiEvent, HostMatchButtonClickedEvent,
+            JoinMatchButtonClickedEvent, LeaveLobbyButtonClickedEvent, ResyncMainMenuUiEvent,
+            ResyncMessageListUiEvent, ResyncUserListUiEvent, SettingsButtonClickedEvent,
+            StartMatchButtonClickedEvent,
         },
         go_to_sub_ui, UiCatalog, UiKey,
     },
@@ -64,6 +67,8 @@ pub(crate) fn on_main_menu_ui_load(
     );
     ui_manager
         .register_ui_event::<GlobalChatButtonClickedEvent>(&main_menu_ui_handle, "chat_button");
+    ui_manager
+        .register_ui_event::<FriendsButtonClickedEvent>(&main_menu_ui_handle, "friends_button");
     ui_manager.register_ui_event::<DevlogButtonClickedEvent>(&main_menu_ui_handle, "devlog_button");
     ui_manager
         .register_ui_event::<SettingsButtonClickedEvent>(&main_menu_ui_handle, "settings_button");
@@ -87,6 +92,49 @@ pub(crate) fn on_main_menu_ui_load(
     user_manager.recv_main_menu_ui(ui_manager, &main_menu_ui_handle);
 }
 
+pub(crate) fn on_return_from_match(
+    mut ui_manager: ResMut<UiManager>,
+    ui_catalog: Res<UiCatalog>,
+    match_manager: Res<MatchManager>,
+    mut sub_ui_event_writer: EventWriter<GoToSubUiEvent>,
+    mut resync_main_menu_ui_events: EventWriter<ResyncMainMenuUiEvent>,
+) {
+    let layer = RenderLayers::layer(0);
+    ui_manager.set_target_render_layer(layer);
+
+    let main_menu_ui_handle = ui_catalog.get_ui_handle(UiKey::MainMenu);
+    ui_manager.enable_ui(&main_menu_ui_handle);
+
+    if match_manager.last_results().is_some() && ui_catalog.get_is_loaded(UiKey::MatchResults) {
+        go_to_sub_ui(&mut sub_ui_event_writer, UiKey::MatchResults);
+    } else {
+        resync_main_menu_ui_events.send(ResyncMainMenuUiEvent);
+    }
+}
+
+// used as a system
+// the user card shows whatever the portrait camera renders, the ui keeps the texture sized to the card
+pub(crate) fn sync_user_portrait(
+    mut ui_manager: ResMut<UiManager>,
+    ui_catalog: Res<UiCatalog>,
+    camera_q: Query<&Camera, With<PortraitCamera>>,
+    mut is_synced: Local<bool>,
+) {
+    if *is_synced || !ui_catalog.get_is_loaded(UiKey::MainMenu) {
+        return;
+    }
+    let Ok(camera) = camera_q.get_single() else {
+        return;
+    };
+    let RenderTarget::Image(texture_handle) = &camera.target else {
+        return;
+    };
+
+    let main_menu_ui_handle = ui_catalog.get_ui_handle(UiKey::MainMenu);
+    ui_manager.set_panel_texture(&main_menu_ui_handle, "user_portrait", texture_handle);
+    *is_synced = true;
+}
+
 pub(crate) fn handle_main_menu_interaction_events(
     ui_catalog: Res<UiCatalog>,
     input: Res<Input>,
@@ -96,6 +144,7 @@ pub(crate) fn handle_main_menu_interaction_events(
     mut host_match_btn_rdr: EventReader<HostMatchButtonClickedEvent>,
     mut join_match_btn_rdr: EventReader<JoinMatchButtonClickedEvent>,
     mut global_chat_btn_rdr: EventReader<GlobalChatButtonClickedEvent>,
+    mut friends_btn_rdr: EventReader<FriendsButtonClickedEvent>,
     mut devlog_btn_rdr: EventReader<DevlogButtonClickedEvent>,
     mut settings_btn_rdr: EventReader<SettingsButtonClickedEvent>,
     mut current_lobby_btn_rdr: EventReader<CurrentLobbyButtonClickedEvent>,
@@ -154,6 +203,23 @@ pub(crate) fn handle_main_menu_interaction_events(
         }
     }
 
+    // Friends Button Click
+    {
+        let mut friends_clicked = false;
+        for _ in friends_btn_rdr.read() {
+            friends_clicked = true;
+        }
+        if friends_clicked {
+            info!("friends button clicked!");
+
+            if ui_catalog.get_is_loaded(UiKey::Friends) {
+                go_to_sub_ui(&mut sub_ui_event_writer, UiKey::Friends);
+            }
+
+            should_rumble = true;
+        }
+    }
+
     // Devlog Button Click
     {
         let mut devlog_clicked = false;
@@ -323,6 +389,7 @@ pub(crate) fn handle_resync_main_menu_ui_events(
         ui_manager.set_button_enabled(&active_ui_handle, "host_match_button", false);
         ui_manager.set_button_enabled(&active_ui_handle, "join_match_button", false);
         ui_manager.set_button_enabled(&active_ui_handle, "chat_button", false);
+        ui_manager.set_button_enabled(&active_ui_handle, "friends_button", false);
         ui_manager.set_button_enabled(&active_ui_handle, "current_lobby_button", false);
         ui_manager.set_button_enabled(&active_ui_handle, "start_button", false);
         ui_manager.set_button_enabled(&active_ui_handle, "leave_button", false);
@@ -331,6 +398,9 @@ pub(crate) fn handle_resync_main_menu_ui_events(
         return;
     }
 
+    // friends are available whether or not we're in a lobby
+    ui_manager.set_button_enabled(&active_ui_handle, "friends_button", true);
+
     let current_lobby_id = lobby_manager.get_current_lobby();
 
     if let Some(current_lobby_id) = current_lobby_id {
@@ -347,6 +417,7 @@ pub(crate) fn handle_resync_main_menu_ui_events(
 
         // make left side "lobby" button visible
         ui_manager.set_node_visible(&active_ui_handle, "current_lobby_button", true);
+        ui_manager.set_button_enabled(&active_ui_handle, "current_lobby_button", true);
         ui_manager.set_text(
             &active_ui_handle,
             "current_lobby_button_text",
@@ -370,10 +441,9 @@ pub(crate) fn handle_resync_main_menu_ui_events(
             lobby_owner_user_entity == self_user_entity
         };
 
-        if self_is_owner_of_lobby {
-            ui_manager.set_node_visible(&active_ui_handle, "start_button", true);
-            ui_manager.set_button_enabled(&active_ui_handle, "start_button", true);
-        }
+        let can_start_match = self_is_owner_of_lobby && current_lobby.can_start();
+        ui_manager.set_node_visible(&active_ui_handle, "start_button", can_start_match);
+        ui_manager.set_button_enabled(&active_ui_handle, "start_button", can_start_match);
     } else {
         // not in a lobby
 
@@ -395,6 +465,9 @@ pub(crate) fn handle_resync_main_menu_ui_events(
         let center_title_text = match current_sub_ui_key {
             UiKey::HostMatch => "Host Match",
             UiKey::JoinMatch => "Join Match",
+            UiKey::MatchResults => "Match Results",
+            UiKey::Friends => "Friends",
+            UiKey::Conversation => "Direct Messages",
             UiKey::MessageList => "Chat",
             _ => {
                 panic!("unexpected sub ui");
@@ -0,0 +1,109 @@
+use bevy_ecs::{
+    change_detection::{Res, ResMut},
+    event::{EventReader, EventWriter},
+};
+
+use game_engine::{
+    social::MatchOutcome,
+    ui::{UiHandle, UiManager},
+};
+
+use game_app_network::session::{channels::PrimaryChannel, messages, SessionMessageEvents};
+
+use crate::{
+    resources::match_manager::MatchManager,
+    ui::{
+        events::{GoToSubUiEvent, ResyncMainMenuUiEvent, ResyncMatchResultsUiEvent},
+        go_to_sub_ui, UiCatalog, UiKey,
+    },
+};
+
+pub fn on_load_match_results_ui(ui_catalog: &mut UiCatalog) {
+    let ui_key = UiKey::MatchResults;
+
+    ui_catalog.set_loaded(ui_key);
+}
+
+pub(crate) fn on_enter_state(
+    resync_match_results_ui_event_writer: &mut EventWriter<ResyncMatchResultsUiEvent>,
+) {
+    resync_match_results_ui_event_writer.send(ResyncMatchResultsUiEvent);
+}
+
+pub fn on_leave_state(_ui_manager: &mut UiManager, _ui_handle: &UiHandle) {
+    // TODO: implement
+}
+
+pub(crate) fn recv_match_ended_messages(
+    ui_catalog: Res<UiCatalog>,
+    mut match_manager: ResMut<MatchManager>,
+    mut sub_ui_event_writer: EventWriter<GoToSubUiEvent>,
+    mut resync_main_menu_ui_events: EventWriter<ResyncMainMenuUiEvent>,
+    mut resync_match_results_ui_events: EventWriter<ResyncMatchResultsUiEvent>,
+    mut event_reader: EventReader<SessionMessageEvents>,
+) {
+    for events in event_reader.read() {
+        for message in events.read::<PrimaryChannel, messages::MatchLobbyGameEnded>() {
+            match_manager.end_match(
+                message.outcome,
+                message.result,
+                &mut resync_main_menu_ui_events,
+                &mut resync_match_results_ui_events,
+            );
+
+            if ui_catalog.get_is_loaded(UiKey::MatchResults) {
+                go_to_sub_ui(&mut sub_ui_event_writer, UiKey::MatchResults);
+            }
+        }
+    }
+}
+
+pub(crate) fn handle_resync_match_results_ui_events(
+    mut ui_manager: ResMut<UiManager>,
+    ui_catalog: Res<UiCatalog>,
+    match_manager: Res<MatchManager>,
+    mut resync_match_results_ui_events: EventReader<ResyncMatchResultsUiEvent>,
+) {
+    // check if we need to resync
+    let mut resync = false;
+    for _ in resync_match_results_ui_events.read() {
+        resync = true;
+    }
+    if !resync {
+        return;
+    }
+
+    if !ui_catalog.get_is_loaded(UiKey::MatchResults) {
+        return;
+    }
+    let Some((outcome, result)) = match_manager.last_results() else {
+        return;
+    };
+    let ui_handle = ui_catalog.get_ui_handle(UiKey::MatchResults);
+
+    let outcome_text = match outcome {
+        MatchOutcome::Finished => "Match Finished",
+        MatchOutcome::Abandoned => "Match Abandoned",
+    };
+    ui_manager.set_text(&ui_handle, "outcome_text", outcome_text);
+
+    let (time_played_text, status_text) = match result {
+        Some(result) => {
+            let seconds_played = result.seconds_played();
+            let time_played_text = format!(
+                "Time Played: {}:{:02}",
+                seconds_played / 60,
+                seconds_played % 60
+            );
+            let status_text = if result.present_at_end() {
+                "Present at end of match"
+            } else {
+                "Left before end of match"
+            };
+            (time_played_text, status_text)
+        }
+        None => ("Time Played: -".to_string(), "Did not join match"),
+    };
+    ui_manager.set_text(&ui_handle, "time_played_text", &time_played_text);
+    ui_manager.set_text(&ui_handle, "status_text", status_text);
+}
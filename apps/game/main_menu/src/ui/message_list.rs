@@ -11,12 +11,14 @@ use game_engine::{
 };
 
 use game_app_network::session::{
-    components::{ChatMessage, User},
-    SessionClient,
+    channels::PrimaryChannel, components::User, messages, SessionClient, SessionMessageEvents,
 };
 
 use crate::{
-    resources::{chat_message_manager::ChatMessageManager, lobby_manager::LobbyManager},
+    resources::{
+        chat_message_manager::ChatMessageManager, direct_message_manager::DirectMessageManager,
+        lobby_manager::LobbyManager,
+    },
     ui::{events::ResyncMessageListUiEvent, UiCatalog, UiKey},
 };
 
@@ -53,26 +55,41 @@ pub(crate) fn handle_message_list_interaction_events(
 }
 
 pub(crate) fn handle_resync_message_list_ui_events(
-    mut session_client: SessionClient,
     mut ui_manager: ResMut<UiManager>,
     asset_manager: Res<AssetManager>,
     mut message_manager: ResMut<ChatMessageManager>,
     lobby_manager: Res<LobbyManager>,
+    direct_message_manager: Res<DirectMessageManager>,
     user_q: Query<&User>,
-    message_q: Query<&ChatMessage>,
     mut resync_message_list_events: EventReader<ResyncMessageListUiEvent>,
 ) {
     message_manager.handle_resync_events(
-        &mut session_client,
         &mut ui_manager,
         &asset_manager,
         &lobby_manager,
+        &direct_message_manager,
         &user_q,
-        &message_q,
         &mut resync_message_list_events,
     );
 }
 
+pub(crate) fn recv_chat_history_messages(
+    session_client: SessionClient,
+    mut message_manager: ResMut<ChatMessageManager>,
+    mut resync_message_list_events: EventWriter<ResyncMessageListUiEvent>,
+    mut event_reader: EventReader<SessionMessageEvents>,
+) {
+    for events in event_reader.read() {
+        for entry in events.read::<PrimaryChannel, messages::ChatHistoryEntry>() {
+            message_manager.recv_history_message(
+                &session_client,
+                &mut resync_message_list_events,
+                &entry,
+            );
+        }
+    }
+}
+
 pub fn on_enter_state(
     resync_message_list_ui_event_writer: &mut EventWriter<ResyncMessageListUiEvent>,
 ) {
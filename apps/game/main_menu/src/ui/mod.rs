@@ -1,8 +1,11 @@
 pub mod events;
 
+mod conversation;
+mod friends;
 mod host_match;
 mod join_match;
-mod main_menu;
+pub(crate) mod main_menu;
+mod match_results;
 mod message_list;
 mod user_list;
 
@@ -27,11 +30,12 @@ use game_app_common::AppState;
 
 use crate::{
     resources::{
-        chat_message_manager::ChatMessageManager, lobby_manager::LobbyManager,
-        user_manager::UserManager,
+        chat_message_manager::ChatMessageManager, direct_message_manager::DirectMessageManager,
+        friend_manager::FriendManager, lobby_manager::LobbyManager, user_manager::UserManager,
     },
     ui::events::{
-        GoToSubUiEvent, ResyncLobbyListUiEvent, ResyncMainMenuUiEvent, ResyncMessageListUiEvent,
+        GoToSubUiEvent, ResyncConversationUiEvent, ResyncFriendListUiEvent, ResyncLobbyListUiEvent,
+        ResyncMainMenuUiEvent, ResyncMatchResultsUiEvent, ResyncMessageListUiEvent,
         ResyncUserListUiEvent,
     },
 };
@@ -46,6 +50,13 @@ pub enum UiKey {
     JoinMatch,
     JoinMatchLobbyItem,
 
+    MatchResults,
+
+    Friends,
+    FriendListItem,
+
+    Conversation,
+
     MessageList,
     MessageListDayDivider,
     MessageListUsernameAndMessage,
@@ -72,10 +83,14 @@ pub(crate) fn on_ui_load(
     user_manager: &mut UserManager,
     chat_message_manager: &mut ChatMessageManager,
     lobby_manager: &mut LobbyManager,
+    friend_manager: &mut FriendManager,
+    direct_message_manager: &mut DirectMessageManager,
     sub_ui_event_writer: &mut EventWriter<GoToSubUiEvent>,
     resync_user_list_ui_events: &mut EventWriter<ResyncUserListUiEvent>,
     resync_message_list_ui_events: &mut EventWriter<ResyncMessageListUiEvent>,
     resync_lobby_list_ui_events: &mut EventWriter<ResyncLobbyListUiEvent>,
+    resync_friend_list_ui_events: &mut EventWriter<ResyncFriendListUiEvent>,
+    resync_conversation_ui_events: &mut EventWriter<ResyncConversationUiEvent>,
     asset_id: AssetId,
 ) {
     let ui_handle = UiHandle::new(asset_id);
@@ -106,6 +121,25 @@ pub(crate) fn on_ui_load(
             lobby_manager.on_load_lobby_item_ui(ui_catalog, resync_lobby_list_ui_events)
         }
 
+        UiKey::MatchResults => match_results::on_load_match_results_ui(ui_catalog),
+
+        UiKey::Friends => friends::on_load_friends_ui(
+            ui_catalog,
+            ui_manager,
+            friend_manager,
+            resync_friend_list_ui_events,
+        ),
+        UiKey::FriendListItem => {
+            friend_manager.on_load_friend_list_item_ui(ui_catalog, resync_friend_list_ui_events)
+        }
+
+        UiKey::Conversation => conversation::on_load_conversation_ui(
+            ui_catalog,
+            ui_manager,
+            direct_message_manager,
+            resync_conversation_ui_events,
+        ),
+
         UiKey::MessageList => chat_message_manager.on_load_container_ui(
             ui_catalog,
             ui_manager,
@@ -136,9 +170,13 @@ pub(crate) fn go_to_sub_ui(
 pub(crate) fn process_go_to_sub_ui_events(
     mut ui_manager: ResMut<UiManager>,
     ui_catalog: Res<UiCatalog>,
+    mut direct_message_manager: ResMut<DirectMessageManager>,
     mut resync_main_menu_ui_event_writer: EventWriter<ResyncMainMenuUiEvent>,
     mut resync_lobby_list_ui_event_writer: EventWriter<ResyncLobbyListUiEvent>,
     mut resync_message_list_ui_event_writer: EventWriter<ResyncMessageListUiEvent>,
+    mut resync_match_results_ui_event_writer: EventWriter<ResyncMatchResultsUiEvent>,
+    mut resync_friend_list_ui_event_writer: EventWriter<ResyncFriendListUiEvent>,
+    mut resync_conversation_ui_event_writer: EventWriter<ResyncConversationUiEvent>,
     mut sub_ui_event_reader: EventReader<GoToSubUiEvent>,
 ) {
     let mut sub_ui_key = None;
@@ -169,6 +207,11 @@ pub(crate) fn process_go_to_sub_ui_events(
             UiKey::MainMenu => panic!("invalid sub-ui"),
             UiKey::HostMatch => host_match::on_leave_state(&mut ui_manager, &current_ui_handle),
             UiKey::JoinMatch => join_match::on_leave_state(&mut ui_manager, &current_ui_handle),
+            UiKey::MatchResults => {
+                match_results::on_leave_state(&mut ui_manager, &current_ui_handle)
+            }
+            UiKey::Friends => friends::on_leave_state(&mut ui_manager, &current_ui_handle),
+            UiKey::Conversation => conversation::on_leave_state(&mut direct_message_manager),
             UiKey::MessageList => message_list::on_leave_state(&mut ui_manager, &current_ui_handle),
             _ => {
                 unimplemented!("ui not implemented");
@@ -182,6 +225,13 @@ pub(crate) fn process_go_to_sub_ui_events(
         UiKey::MainMenu => panic!("invalid sub-ui"),
         UiKey::HostMatch => host_match::on_enter_state(&mut ui_manager, &sub_ui_handle),
         UiKey::JoinMatch => join_match::on_enter_state(&mut resync_lobby_list_ui_event_writer),
+        UiKey::MatchResults => {
+            match_results::on_enter_state(&mut resync_match_results_ui_event_writer)
+        }
+        UiKey::Friends => friends::on_enter_state(&mut resync_friend_list_ui_event_writer),
+        UiKey::Conversation => {
+            conversation::on_enter_state(&mut resync_conversation_ui_event_writer)
+        }
         UiKey::MessageList => {
             message_list::on_enter_state(&mut resync_message_list_ui_event_writer)
         }
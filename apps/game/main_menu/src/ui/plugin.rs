@@ -1,15 +1,23 @@
 use bevy_app::{App, Plugin, Update};
 
 use crate::ui::{
+    conversation,
     events::{
-        CurrentLobbyButtonClickedEvent, DevlogButtonClickedEvent, GlobalChatButtonClickedEvent,
-        GoToSubUiEvent, HostMatchButtonClickedEvent, JoinMatchButtonClickedEvent,
-        LeaveLobbyButtonClickedEvent, LobbyListItemClickedEvent, ResyncLobbyListUiEvent,
-        ResyncMainMenuUiEvent, ResyncMessageListUiEvent, ResyncUserListUiEvent,
-        SettingsButtonClickedEvent, StartMatchButtonClickedEvent, SubmitButtonClickedEvent,
+        AwayButtonClickedEvent, BlockButtonClickedEvent, CurrentLobbyButtonClickedEvent,
+        DevlogButtonClickedEvent, FriendListItemAcceptClickedEvent,
+        FriendListItemDeclineClickedEvent, FriendListItemJoinClickedEvent,
+        FriendListItemRemoveClickedEvent, FriendsButtonClickedEvent, GlobalChatButtonClickedEvent,
+        GoToSubUiEvent, HostMatchButtonClickedEvent, InviteOnlyButtonClickedEvent,
+        JoinMatchButtonClickedEvent, LeaveLobbyButtonClickedEvent, LobbyListItemClickedEvent,
+        ResyncConversationUiEvent, ResyncFriendListUiEvent, ResyncLobbyListUiEvent,
+        ResyncMainMenuUiEvent, ResyncMatchResultsUiEvent, ResyncMessageListUiEvent,
+        ResyncUserListUiEvent, SettingsButtonClickedEvent, StartMatchButtonClickedEvent,
+        SubmitButtonClickedEvent, UserListItemAddFriendClickedEvent,
+        UserListItemInviteClickedEvent, UserListItemKickClickedEvent,
+        UserListItemMessageClickedEvent,
     },
-    host_match, join_match, main_menu, message_list, process_go_to_sub_ui_events, user_list,
-    UiCatalog,
+    friends, host_match, join_match, main_menu, match_results, message_list,
+    process_go_to_sub_ui_events, user_list, UiCatalog,
 };
 
 pub struct UiPlugin;
@@ -25,29 +33,56 @@ impl Plugin for UiPlugin {
             .add_systems(Update, main_menu::handle_start_match_events)
             .add_systems(Update, main_menu::handle_leave_lobby_events)
             .add_systems(Update, main_menu::handle_resync_main_menu_ui_events)
+            .add_systems(Update, user_list::handle_user_list_interaction_events)
             .add_systems(Update, user_list::handle_resync_user_list_ui_events)
             .add_systems(Update, message_list::handle_resync_message_list_ui_events)
             .add_systems(Update, message_list::handle_message_list_interaction_events)
+            .add_systems(Update, message_list::recv_chat_history_messages)
             .add_systems(Update, host_match::handle_host_match_events)
             .add_systems(Update, join_match::handle_join_match_input_events)
             .add_systems(Update, join_match::handle_join_match_click_events)
             .add_systems(Update, join_match::handle_resync_lobby_list_ui_events)
+            .add_systems(Update, join_match::recv_match_lobby_kicked_messages)
+            .add_systems(Update, join_match::recv_match_lobby_invited_messages)
+            .add_systems(Update, match_results::recv_match_ended_messages)
+            .add_systems(Update, match_results::handle_resync_match_results_ui_events)
+            .add_systems(Update, friends::handle_friends_interaction_events)
+            .add_systems(Update, friends::recv_friend_messages)
+            .add_systems(Update, friends::handle_resync_friend_list_ui_events)
+            .add_systems(Update, conversation::handle_conversation_interaction_events)
+            .add_systems(Update, conversation::recv_direct_messages)
+            .add_systems(Update, conversation::handle_resync_conversation_ui_events)
             // resync events
             .add_event::<ResyncMainMenuUiEvent>()
             .add_event::<ResyncUserListUiEvent>()
             .add_event::<ResyncMessageListUiEvent>()
             .add_event::<ResyncLobbyListUiEvent>()
+            .add_event::<ResyncMatchResultsUiEvent>()
+            .add_event::<ResyncFriendListUiEvent>()
+            .add_event::<ResyncConversationUiEvent>()
             // ui events
             .add_event::<GoToSubUiEvent>()
             .add_event::<HostMatchButtonClickedEvent>()
             .add_event::<JoinMatchButtonClickedEvent>()
             .add_event::<GlobalChatButtonClickedEvent>()
+            .add_event::<FriendsButtonClickedEvent>()
             .add_event::<DevlogButtonClickedEvent>()
             .add_event::<SettingsButtonClickedEvent>()
             .add_event::<CurrentLobbyButtonClickedEvent>()
             .add_event::<StartMatchButtonClickedEvent>()
             .add_event::<LeaveLobbyButtonClickedEvent>()
             .add_event::<SubmitButtonClickedEvent>()
-            .add_event::<LobbyListItemClickedEvent>();
+            .add_event::<LobbyListItemClickedEvent>()
+            .add_event::<InviteOnlyButtonClickedEvent>()
+            .add_event::<UserListItemKickClickedEvent>()
+            .add_event::<UserListItemInviteClickedEvent>()
+            .add_event::<UserListItemAddFriendClickedEvent>()
+            .add_event::<AwayButtonClickedEvent>()
+            .add_event::<FriendListItemJoinClickedEvent>()
+            .add_event::<FriendListItemRemoveClickedEvent>()
+            .add_event::<FriendListItemAcceptClickedEvent>()
+            .add_event::<FriendListItemDeclineClickedEvent>()
+            .add_event::<UserListItemMessageClickedEvent>()
+            .add_event::<BlockButtonClickedEvent>();
     }
 }
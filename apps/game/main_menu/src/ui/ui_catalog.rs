@@ -36,6 +36,22 @@ impl Default for UiCatalog {
             UiKey::JoinMatchLobbyItem,
             UiHandle::new(AssetId::from_str("pup52m").unwrap()),
         );
+        me.insert_ui(
+            UiKey::MatchResults,
+            UiHandle::new(AssetId::from_str("r7m2xk").unwrap()),
+        );
+        me.insert_ui(
+            UiKey::Friends,
+            UiHandle::new(AssetId::from_str("f7kw3d").unwrap()),
+        );
+        me.insert_ui(
+            UiKey::FriendListItem,
+            UiHandle::new(AssetId::from_str("h2nv8q").unwrap()),
+        );
+        me.insert_ui(
+            UiKey::Conversation,
+            UiHandle::new(AssetId::from_str("p4dm7c").unwrap()),
+        );
         me.insert_ui(
             UiKey::MessageList,
             UiHandle::new(AssetId::from_str("ngffab").unwrap()),
@@ -1,24 +1,111 @@
 use bevy_ecs::{
     change_detection::{Res, ResMut},
-    event::EventReader,
+    event::{EventReader, EventWriter},
     prelude::Query,
 };
 
-use game_engine::{asset::AssetManager, ui::UiManager};
+use game_engine::{asset::AssetManager, logging::info, social::FriendAction, ui::UiManager};
 
-use game_app_network::session::components::User;
+use game_app_network::session::{
+    channels,
+    components::{Lobby, User},
+    messages, SessionClient,
+};
 
 use crate::{
-    resources::{lobby_manager::LobbyManager, user_manager::UserManager},
-    ui::events::ResyncUserListUiEvent,
+    resources::{
+        direct_message_manager::DirectMessageManager, friend_manager::FriendManager,
+        lobby_manager::LobbyManager, user_manager::UserManager,
+    },
+    ui::{
+        events::{
+            GoToSubUiEvent, ResyncConversationUiEvent, ResyncUserListUiEvent,
+            UserListItemAddFriendClickedEvent, UserListItemInviteClickedEvent,
+            UserListItemKickClickedEvent, UserListItemMessageClickedEvent,
+        },
+        go_to_sub_ui, UiCatalog, UiKey,
+    },
 };
 
+pub(crate) fn handle_user_list_interaction_events(
+    mut session_client: SessionClient,
+    user_manager: Res<UserManager>,
+    ui_catalog: Res<UiCatalog>,
+    mut direct_message_manager: ResMut<DirectMessageManager>,
+    mut sub_ui_event_writer: EventWriter<GoToSubUiEvent>,
+    mut resync_user_list_ui_events: EventWriter<ResyncUserListUiEvent>,
+    mut resync_conversation_ui_events: EventWriter<ResyncConversationUiEvent>,
+    mut kick_click_events: EventReader<UserListItemKickClickedEvent>,
+    mut invite_click_events: EventReader<UserListItemInviteClickedEvent>,
+    mut add_friend_click_events: EventReader<UserListItemAddFriendClickedEvent>,
+    mut message_click_events: EventReader<UserListItemMessageClickedEvent>,
+) {
+    for event in kick_click_events.read() {
+        let Some(user_entity) = user_manager.get_user_entity(&event.user_id()) else {
+            continue;
+        };
+
+        info!("kicking user: {:?}", user_entity);
+
+        let mut message = messages::MatchLobbyKick::new();
+        message.user_entity.set(&session_client, &user_entity);
+        session_client.send_message::<channels::ClientActionsChannel, _>(&message);
+    }
+    for event in invite_click_events.read() {
+        let Some(user_entity) = user_manager.get_user_entity(&event.user_id()) else {
+            continue;
+        };
+
+        info!("inviting user: {:?}", user_entity);
+
+        let mut message = messages::MatchLobbyInvite::new();
+        message.user_entity.set(&session_client, &user_entity);
+        session_client.send_message::<channels::ClientActionsChannel, _>(&message);
+    }
+    for event in add_friend_click_events.read() {
+        let Some(user_entity) = user_manager.get_user_entity(&event.user_id()) else {
+            continue;
+        };
+
+        info!("sending friend request to user: {:?}", user_entity);
+
+        let mut message = messages::FriendActionRequest::new(FriendAction::SendRequest);
+        message.user_entity.set(&session_client, &user_entity);
+        session_client.send_message::<channels::ClientActionsChannel, _>(&message);
+    }
+    for event in message_click_events.read() {
+        let Some(user_entity) = user_manager.get_user_entity(&event.user_id()) else {
+            continue;
+        };
+        if !ui_catalog.get_is_loaded(UiKey::Conversation) {
+            continue;
+        }
+
+        info!("opening conversation with user: {:?}", user_entity);
+
+        direct_message_manager.open_conversation(user_entity);
+        go_to_sub_ui(&mut sub_ui_event_writer, UiKey::Conversation);
+
+        // the conversation ui stays up when switching between users
+        resync_conversation_ui_events.send(ResyncConversationUiEvent);
+        // the unread count was cleared
+        resync_user_list_ui_events.send(ResyncUserListUiEvent);
+
+        // prevent multiple clicks
+        break;
+    }
+}
+
 pub(crate) fn handle_resync_user_list_ui_events(
+    session_client: SessionClient,
     lobby_manager: Res<LobbyManager>,
+    friend_manager: Res<FriendManager>,
+    direct_message_manager: Res<DirectMessageManager>,
     mut user_manager: ResMut<UserManager>,
     mut ui_manager: ResMut<UiManager>,
     asset_manager: Res<AssetManager>,
     user_q: Query<&User>,
+    lobby_q: Query<&Lobby>,
     mut resync_user_list_ui_events: EventReader<ResyncUserListUiEvent>,
 ) {
     let mut resync = false;
@@ -26,6 +113,15 @@ pub(crate) fn handle_resync_user_list_ui_events(
         resync = true;
     }
     if resync {
-        user_manager.sync_with_collection(&mut ui_manager, &asset_manager, &lobby_manager, &user_q);
+        user_manager.sync_with_collection(
+            &session_client,
+            &mut ui_manager,
+            &asset_manager,
+            &lobby_manager,
+            &friend_manager,
+            &direct_message_manager,
+            &user_q,
+            &lobby_q,
+        );
     }
 }
@@ -17,7 +17,7 @@ use ui_runner::UiManager;
 
 use session_server_naia_proto::{
     channels::{AssetRequestsChannel, PrimaryChannel},
-    messages::{LoadAssetRequest, LoadAssetWithData, WorldConnectToken},
+    messages::{LoadAssetRequest, LoadAssetWithData, MatchLobbyGameEnded, WorldConnectToken},
 };
 use world_server_naia_proto::messages::Auth as WorldAuth;
 
@@ -28,9 +28,13 @@ use crate::{
         SessionConnectEvent, SessionDisconnectEvent, SessionMessageEvents, SessionRejectEvent,
         SessionRequestEvents,
     },
-    world::{WorldConnectEvent, WorldDisconnectEvent},
+    world::{WorldConnectEvent, WorldDisconnectEvent, WorldRejectEvent},
 };
 
+pub(crate) const DEFAULT_CONNECT_INTERVAL: Duration = Duration::from_millis(5000);
+// retries back off from the connect interval, doubling each time up to this
+const MAX_RECONNECT_INTERVAL: Duration = Duration::from_secs(30);
+
 type SessionClient<'a> = Client<'a, Session>;
 type WorldClient<'a> = Client<'a, World>;
 
@@ -40,26 +44,65 @@ pub enum ConnectionState {
     SendingSessionConnect,
     WaitingForSessionConnect,
     ConnectedToSession,
-    ConnectedToWorld,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum WorldConnectionState {
+    Disconnected,
+    Connecting,
+    Connected,
+    WaitingToReconnect,
 }
 
 #[derive(Resource)]
 pub struct ConnectionManager {
     pub connection_state: ConnectionState,
+    pub world_connection_state: WorldConnectionState,
+    connect_interval: Duration,
     send_timer: Timer,
+    session_retries: u32,
+    world_timer: Timer,
+    world_retries: u32,
+    // kept for the rest of the match, the world server takes it again after a dropped connection
+    world_login_token: Option<String>,
 }
 
 impl Default for ConnectionManager {
     fn default() -> Self {
+        Self::new(DEFAULT_CONNECT_INTERVAL)
+    }
+}
+
+impl ConnectionManager {
+    pub(crate) fn new(connect_interval: Duration) -> Self {
         Self {
             connection_state: ConnectionState::Disconnected,
-            // TODO: split this out into config var?
-            send_timer: Timer::new(Duration::from_millis(5000)),
+            world_connection_state: WorldConnectionState::Disconnected,
+            connect_interval,
+            send_timer: Timer::new(connect_interval),
+            session_retries: 0,
+            world_timer: Timer::new(connect_interval),
+            world_retries: 0,
+            world_login_token: None,
         }
     }
-}
 
-impl ConnectionManager {
+    fn retry_interval(&self, retries: u32) -> Duration {
+        let multiplier = 2u32.saturating_pow(retries.saturating_sub(1));
+        self.connect_interval
+            .saturating_mul(multiplier)
+            .min(MAX_RECONNECT_INTERVAL)
+    }
+
+    fn retry_session_connect(&mut self) {
+        self.session_retries += 1;
+        let retry_interval = self.retry_interval(self.session_retries);
+        info!("retrying session server connection in {:?}", retry_interval);
+
+        self.connection_state = ConnectionState::SendingSessionConnect;
+        self.send_timer = Timer::new(retry_interval);
+    }
+
     // used as a system
     pub fn handle_session_connect_events(
         client: SessionClient,
@@ -75,12 +118,8 @@ impl ConnectionManager {
                 server_address
             );
 
-            let ConnectionState::WaitingForSessionConnect = &connection_manager.connection_state
-            else {
-                panic!("Shouldn't happen");
-            };
-
             connection_manager.connection_state = ConnectionState::ConnectedToSession;
+            connection_manager.session_retries = 0;
         }
     }
 
@@ -92,7 +131,8 @@ impl ConnectionManager {
         for _ in session_disconnect_event_reader.read() {
             warn!("Client disconnected from session server");
 
-            connection_manager.connection_state = ConnectionState::SendingSessionConnect;
+            // the session server holds on to us for a while, including any lobby we were in
+            connection_manager.retry_session_connect();
         }
     }
 
@@ -104,7 +144,7 @@ impl ConnectionManager {
         for _ in session_reject_event_reader.read() {
             warn!("Client rejected from connecting to the session server");
 
-            connection_manager.connection_state = ConnectionState::SendingSessionConnect;
+            connection_manager.retry_session_connect();
         }
     }
 
@@ -123,11 +163,8 @@ impl ConnectionManager {
                 server_address
             );
 
-            let ConnectionState::ConnectedToSession = &connection_manager.connection_state else {
-                panic!("Shouldn't happen");
-            };
-
-            connection_manager.connection_state = ConnectionState::ConnectedToWorld;
+            connection_manager.world_connection_state = WorldConnectionState::Connected;
+            connection_manager.world_retries = 0;
         }
     }
 
@@ -139,19 +176,41 @@ impl ConnectionManager {
         for _ in event_reader.read() {
             info!("Client disconnected from world server",);
 
-            let ConnectionState::ConnectedToWorld = &connection_manager.connection_state else {
-                panic!(
-                    "Shouldn't happen .. state is: {:?}",
-                    &connection_manager.connection_state
-                );
-            };
+            if connection_manager.world_login_token.is_none() {
+                // the match is over
+                connection_manager.world_connection_state = WorldConnectionState::Disconnected;
+                continue;
+            }
 
-            connection_manager.connection_state = ConnectionState::ConnectedToSession;
+            // the world server keeps our unit around for a while, so try to get back to it
+            connection_manager.world_retries += 1;
+            let retry_interval =
+                connection_manager.retry_interval(connection_manager.world_retries);
+            info!("retrying world server connection in {:?}", retry_interval);
+
+            connection_manager.world_connection_state = WorldConnectionState::WaitingToReconnect;
+            connection_manager.world_timer = Timer::new(retry_interval);
+        }
+    }
+
+    // used as a system
+    pub fn handle_world_reject_events(
+        mut event_reader: EventReader<WorldRejectEvent>,
+        mut connection_manager: ResMut<Self>,
+    ) {
+        for _ in event_reader.read() {
+            warn!("Client rejected from connecting to the world server");
+
+            // either the match ended, or we were away for too long
+            connection_manager.world_login_token = None;
+            connection_manager.world_connection_state = WorldConnectionState::Disconnected;
+            connection_manager.world_retries = 0;
         }
     }
 
     // used as a system
     pub fn handle_session_message_events(
+        mut connection_manager: ResMut<Self>,
         mut world_client: WorldClient,
         http_client: Res<HttpClient>,
         mut asset_cache: ResMut<AssetCache>,
@@ -166,7 +225,15 @@ impl ConnectionManager {
             for token in events.read::<PrimaryChannel, WorldConnectToken>() {
                 info!("received World Connect Token from Session Server!");
 
-                Self::send_world_connect(&mut world_client, &http_client, &token);
+                connection_manager.recv_world_connect_token(
+                    &mut world_client,
+                    &http_client,
+                    &token.login_token,
+                );
+            }
+            for _ in events.read::<PrimaryChannel, MatchLobbyGameEnded>() {
+                // the world server is about to drop us, and that's no reason to reconnect
+                connection_manager.world_login_token = None;
             }
             for asset_message in events.read::<AssetRequestsChannel, LoadAssetWithData>() {
                 info!(
@@ -218,15 +285,56 @@ impl ConnectionManager {
         }
     }
 
+    // the session server sends the token again after a reconnect, while we may still be in the match
+    fn recv_world_connect_token(
+        &mut self,
+        world_client: &mut WorldClient,
+        http_client: &HttpClient,
+        login_token: &str,
+    ) {
+        self.world_login_token = Some(login_token.to_string());
+
+        match &self.world_connection_state {
+            WorldConnectionState::Disconnected | WorldConnectionState::WaitingToReconnect => {
+                self.world_connection_state = WorldConnectionState::Connecting;
+                Self::send_world_connect(world_client, http_client, login_token);
+            }
+            WorldConnectionState::Connecting | WorldConnectionState::Connected => {}
+        }
+    }
+
+    // leaving the match on purpose, so the world server dropping us is no reason to reconnect
+    pub fn leave_world(&mut self) {
+        self.world_login_token = None;
+    }
+
     // used as a system
     pub fn handle_connection(
         mut connection_manager: ResMut<Self>,
         http_client: Res<HttpClient>,
         mut session_client: SessionClient,
+        mut world_client: WorldClient,
     ) {
+        connection_manager.handle_world_reconnect(&http_client, &mut world_client);
         connection_manager.handle_connection_impl(&http_client, &mut session_client);
     }
 
+    fn handle_world_reconnect(&mut self, http_client: &HttpClient, world_client: &mut WorldClient) {
+        let WorldConnectionState::WaitingToReconnect = &self.world_connection_state else {
+            return;
+        };
+        if !self.world_timer.ringing() {
+            return;
+        }
+        let Some(login_token) = self.world_login_token.clone() else {
+            self.world_connection_state = WorldConnectionState::Disconnected;
+            return;
+        };
+
+        self.world_connection_state = WorldConnectionState::Connecting;
+        Self::send_world_connect(world_client, http_client, &login_token);
+    }
+
     fn handle_connection_impl(
         &mut self,
         http_client: &HttpClient,
@@ -250,9 +358,6 @@ impl ConnectionManager {
             }
             ConnectionState::WaitingForSessionConnect => {}
             ConnectionState::ConnectedToSession => {}
-            ConnectionState::ConnectedToWorld => {
-                // info!("world : connected!");
-            }
         }
     }
 
@@ -273,12 +378,12 @@ impl ConnectionManager {
             fn send_world_connect(
                 world_client: &mut WorldClient,
                 _http_client: &HttpClient,
-                token: &WorldConnectToken
+                login_token: &str
             ) {
                 let url = format!("{}://{}:{}", config::PUBLIC_PROTOCOL, config::PUBLIC_IP_ADDR, config::WORLD_SERVER_SIGNAL_PORT);
 
                 info!("connecting to world server: {}", &url);
-                world_client.auth(WorldAuth::new(&token.login_token));
+                world_client.auth(WorldAuth::new(login_token));
                 let socket = WebrtcSocket::new(&url, world_client.socket_config());
                 world_client.connect(socket);
             }
@@ -302,7 +407,7 @@ impl ConnectionManager {
             fn send_world_connect(
                 world_client: &mut WorldClient,
                 http_client: &HttpClient,
-                token: &WorldConnectToken
+                login_token: &str
             ) {
                 let world_server_public_webrtc_url = config::TargetEnv::gateway_url();
                 info!(
@@ -310,7 +415,7 @@ impl ConnectionManager {
                     &world_server_public_webrtc_url
                 );
 
-                world_client.auth(WorldAuth::new(&token.login_token));
+                world_client.auth(WorldAuth::new(login_token));
                 if let Some(cookies) = http_client.cookie_header_value() {
                     let mut headers = Vec::new();
                     headers.push(("Cookie".to_string(), cookies));
@@ -42,7 +42,7 @@ pub mod world {
     };
 
     pub use world_server_naia_proto::{
-        behavior, channels, components, constants, messages, resources, types,
+        behavior, channels, components, constants, messages, recording, resources, types,
     };
 }
 
@@ -75,4 +75,5 @@ pub mod session {
     pub use session_server_naia_proto::{channels, components, messages};
 }
 
+pub use connection_manager::{ConnectionManager, WorldConnectionState};
 pub use plugin::NetworkedEnginePlugin;
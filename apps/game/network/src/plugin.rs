@@ -1,33 +1,105 @@
+use std::time::Duration;
+
 use bevy_app::{App, Plugin, Startup, Update};
 
 use naia_bevy_client::{ClientConfig as NaiaClientConfig, Plugin as NaiaClientPlugin};
 
-use session_server_naia_proto::protocol as session_server_naia_protocol;
-use world_server_naia_proto::protocol as world_server_naia_protocol;
+use config::LinkConditions;
+use kernel::get_querystring_param;
+use logging::{info, warn};
+use session_server_naia_proto::conditioned_protocol as session_server_naia_protocol;
+use world_server_naia_proto::{
+    conditioned_protocol as world_server_naia_protocol, LinkConditionerConfig,
+};
 
 use super::{
     asset_cache_checker::AssetCacheChecker,
     asset_ref_processor::AssetRefProcessor,
     client_markers::{Session, World},
-    connection_manager::ConnectionManager,
+    connection_manager::{ConnectionManager, DEFAULT_CONNECT_INTERVAL},
     session_events::SessionEventsPlugin,
     world_events::WorldEventsPlugin,
 };
 
-pub struct NetworkedEnginePlugin;
+pub struct NetworkedEnginePlugin {
+    connect_interval: Duration,
+    bandwidth_measure_duration: Option<Duration>,
+    link_conditions: Option<LinkConditions>,
+}
+
+impl Default for NetworkedEnginePlugin {
+    fn default() -> Self {
+        Self {
+            connect_interval: DEFAULT_CONNECT_INTERVAL,
+            bandwidth_measure_duration: None,
+            link_conditions: None,
+        }
+    }
+}
+
+impl NetworkedEnginePlugin {
+    // how long to wait between each step of connecting to the session server
+    pub fn with_connect_interval(mut self, connect_interval: Duration) -> Self {
+        self.connect_interval = connect_interval;
+        self
+    }
+
+    // enables naia's bandwidth monitor on both clients, averaged over the given window
+    pub fn with_bandwidth_measurement(mut self, measure_duration: Duration) -> Self {
+        self.bandwidth_measure_duration = Some(measure_duration);
+        self
+    }
+
+    // simulates latency, jitter & loss on packets received from both servers
+    pub fn with_link_conditions(mut self, link_conditions: LinkConditions) -> Self {
+        info!("simulating link conditions: {}", link_conditions);
+        self.link_conditions = Some(link_conditions);
+        self
+    }
+
+    // picks up link conditions from the `link` querystring param, or the
+    // `CYBERLITH_LINK_CONDITIONER` environment variable on native
+    pub fn with_requested_link_conditions(self) -> Self {
+        let Some(requested) = get_querystring_param("link")
+            .or_else(|| std::env::var("CYBERLITH_LINK_CONDITIONER").ok())
+        else {
+            return self;
+        };
+        match LinkConditions::parse(&requested) {
+            Ok(Some(link_conditions)) => self.with_link_conditions(link_conditions),
+            Ok(None) => self,
+            Err(err) => {
+                warn!("ignoring requested link conditions: {}", err);
+                self
+            }
+        }
+    }
+
+    fn naia_link_conditioner(&self) -> Option<LinkConditionerConfig> {
+        self.link_conditions.map(|conditions| {
+            LinkConditionerConfig::new(conditions.latency_ms, conditions.jitter_ms, conditions.loss)
+        })
+    }
+
+    fn naia_client_config(&self) -> NaiaClientConfig {
+        let mut config = NaiaClientConfig::default();
+        config.connection.bandwidth_measure_duration = self.bandwidth_measure_duration;
+        config
+    }
+}
 
 impl Plugin for NetworkedEnginePlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(NaiaClientPlugin::<Session>::new(
-            NaiaClientConfig::default(),
-            session_server_naia_protocol(),
+            self.naia_client_config(),
+            session_server_naia_protocol(self.naia_link_conditioner()),
         ))
         .add_plugins(NaiaClientPlugin::<World>::new(
-            NaiaClientConfig::default(),
-            world_server_naia_protocol(),
+            self.naia_client_config(),
+            world_server_naia_protocol(self.naia_link_conditioner()),
         ))
         // connection manager stuff, maybe refactor out into a plugin?
-        .init_resource::<ConnectionManager>()
+        .insert_resource(ConnectionManager::new(self.connect_interval))
         .add_systems(Update, ConnectionManager::handle_connection)
         // asset ref processing stuff
         .init_resource::<AssetRefProcessor>()
@@ -47,7 +47,7 @@ impl Plugin for GameApp {
                 ..Default::default()
             })
             .add_plugins(CommonPlugin)
-            .add_plugins(NetworkedEnginePlugin)
+            .add_plugins(NetworkedEnginePlugin::default().with_requested_link_conditions())
             .add_plugins(InWorldPlugin)
             // handle resizes
             .add_systems(Update, resize::handle_viewport_resize)
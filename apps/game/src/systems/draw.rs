@@ -1,11 +1,14 @@
-use bevy_ecs::system::{Query, ResMut};
+use bevy_ecs::{
+    entity::Entity,
+    system::{Query, ResMut},
+};
 
 use game_engine::{
     render::{
         base::{CpuMaterial, CpuMesh},
         components::{
-            AmbientLight, Camera, DirectionalLight, PointLight, Projection, RenderLayer, Transform,
-            Visibility,
+            AmbientLight, Camera, DirectionalLight, ParticleEmitter, PointLight, PostProcess,
+            Projection, RenderLayer, Transform, Visibility,
         },
         resources::RenderFrame,
     },
@@ -15,26 +18,38 @@ use game_engine::{
 pub fn draw(
     mut render_frame: ResMut<RenderFrame>,
     // Cameras
-    cameras_q: Query<(&Camera, &Transform, &Projection, Option<&RenderLayer>)>,
+    cameras_q: Query<(
+        &Camera,
+        &Transform,
+        &Projection,
+        Option<&RenderLayer>,
+        Option<&PostProcess>,
+    )>,
     // Meshes
     cpu_meshes_q: Query<(
+        Entity,
         &Handle<CpuMesh>,
         &Handle<CpuMaterial>,
         &Transform,
         &Visibility,
         Option<&RenderLayer>,
     )>,
+    // Particles
+    particle_emitters_q: Query<(&ParticleEmitter, &Visibility, Option<&RenderLayer>)>,
     // Lights
     ambient_lights_q: Query<(&AmbientLight, Option<&RenderLayer>)>,
     point_lights_q: Query<(&PointLight, Option<&RenderLayer>)>,
     directional_lights_q: Query<(&DirectionalLight, Option<&RenderLayer>)>,
 ) {
     // Aggregate Cameras
-    for (camera, transform, projection, render_layer_opt) in cameras_q.iter() {
+    for (camera, transform, projection, render_layer_opt, post_process_opt) in cameras_q.iter() {
         if !camera.is_active {
             continue;
         }
         render_frame.draw_camera(render_layer_opt, camera, transform, projection);
+        if let Some(post_process) = post_process_opt {
+            render_frame.draw_post_process(render_layer_opt, post_process);
+        }
     }
 
     // Aggregate Point Lights
@@ -53,10 +68,22 @@ pub fn draw(
     }
 
     // Aggregate Cpu Meshes
-    for (mesh_handle, mat_handle, transform, visibility, render_layer_opt) in cpu_meshes_q.iter() {
+    for (entity, mesh_handle, mat_handle, transform, visibility, render_layer_opt) in
+        cpu_meshes_q.iter()
+    {
+        if !visibility.visible {
+            continue;
+        }
+        render_frame.draw_pickable(entity, |render_frame| {
+            render_frame.draw_mesh(render_layer_opt, mesh_handle, mat_handle, transform);
+        });
+    }
+
+    // Aggregate Particles
+    for (emitter, visibility, render_layer_opt) in particle_emitters_q.iter() {
         if !visibility.visible {
             continue;
         }
-        render_frame.draw_mesh(render_layer_opt, mesh_handle, mat_handle, transform);
+        render_frame.draw_particles(render_layer_opt, emitter);
     }
 }
@@ -0,0 +1,129 @@
+use std::time::Duration;
+
+use clap::{value_parser, Arg, ArgMatches, Command};
+
+pub(crate) struct BotArgs {
+    pub(crate) bot_count: usize,
+    pub(crate) first_bot: usize,
+    pub(crate) username_prefix: String,
+    pub(crate) password: String,
+    pub(crate) bots_per_lobby: usize,
+    pub(crate) moves_per_second: f32,
+    pub(crate) chats_per_minute: f32,
+    pub(crate) run_duration: Duration,
+    pub(crate) report_interval: Duration,
+}
+
+pub(crate) fn cli() -> Command {
+    Command::new("load_bot")
+        .about("runs headless bots against the session & world servers, reporting per-bot metrics")
+        .arg(
+            Arg::new("bots")
+                .short('n')
+                .long("bots")
+                .help("number of bots to run in this process")
+                .default_value("10")
+                .value_parser(value_parser!(usize)),
+        )
+        .arg(
+            Arg::new("first")
+                .long("first")
+                .help("index of the first bot, so several processes can run disjoint bots")
+                .default_value("0")
+                .value_parser(value_parser!(usize)),
+        )
+        .arg(
+            Arg::new("prefix")
+                .short('u')
+                .long("prefix")
+                .help("bots log in as <prefix><index>, these accounts must already exist")
+                .default_value("loadbot"),
+        )
+        .arg(
+            Arg::new("password")
+                .short('p')
+                .long("password")
+                .help("password shared by every bot account")
+                .required(true),
+        )
+        .arg(
+            Arg::new("lobby_size")
+                .short('l')
+                .long("lobby_size")
+                .help("bots per lobby, the first of each group creates it & starts the match")
+                .default_value("4")
+                .value_parser(value_parser!(usize)),
+        )
+        .arg(
+            Arg::new("moves")
+                .short('m')
+                .long("moves")
+                .help("how many times per second each bot picks a new random move")
+                .default_value("1.0")
+                .value_parser(value_parser!(f32)),
+        )
+        .arg(
+            Arg::new("chats")
+                .short('c')
+                .long("chats")
+                .help("how many chat messages per minute each bot sends")
+                .default_value("2.0")
+                .value_parser(value_parser!(f32)),
+        )
+        .arg(
+            Arg::new("duration")
+                .short('d')
+                .long("duration")
+                .help("seconds to run for before reporting & exiting")
+                .default_value("300")
+                .value_parser(value_parser!(u64)),
+        )
+        .arg(
+            Arg::new("report_interval")
+                .short('r')
+                .long("report_interval")
+                .help("seconds between metrics reports")
+                .default_value("10")
+                .value_parser(value_parser!(u64)),
+        )
+}
+
+impl BotArgs {
+    pub(crate) fn from_matches(matches: &ArgMatches) -> Self {
+        let bots_per_lobby = *matches.get_one::<usize>("lobby_size").unwrap();
+        if bots_per_lobby == 0 {
+            panic!("lobby_size must be at least 1");
+        }
+
+        Self {
+            bot_count: *matches.get_one::<usize>("bots").unwrap(),
+            first_bot: *matches.get_one::<usize>("first").unwrap(),
+            username_prefix: matches.get_one::<String>("prefix").unwrap().clone(),
+            password: matches.get_one::<String>("password").unwrap().clone(),
+            bots_per_lobby,
+            moves_per_second: *matches.get_one::<f32>("moves").unwrap(),
+            chats_per_minute: *matches.get_one::<f32>("chats").unwrap(),
+            run_duration: Duration::from_secs(*matches.get_one::<u64>("duration").unwrap()),
+            report_interval: Duration::from_secs(
+                *matches.get_one::<u64>("report_interval").unwrap(),
+            ),
+        }
+    }
+
+    pub(crate) fn username(&self, bot_index: usize) -> String {
+        format!("{}{}", self.username_prefix, bot_index)
+    }
+
+    // bots are grouped into lobbies by index, so separate processes still fill lobbies evenly
+    pub(crate) fn lobby_name(&self, bot_index: usize) -> String {
+        format!(
+            "{} {}",
+            self.username_prefix,
+            bot_index / self.bots_per_lobby
+        )
+    }
+
+    pub(crate) fn is_lobby_owner(&self, bot_index: usize) -> bool {
+        bot_index % self.bots_per_lobby == 0
+    }
+}
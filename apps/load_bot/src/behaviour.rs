@@ -0,0 +1,411 @@
+use std::time::{Duration, Instant};
+
+use bevy_ecs::{
+    entity::Entity,
+    event::EventReader,
+    system::{Query, Res, ResMut, Resource},
+};
+
+use game_app_network::{
+    naia::wrapping_diff,
+    session::{
+        channels::{ClientActionsChannel, PrimaryChannel},
+        components::Lobby,
+        messages::{
+            GlobalChatSendMessage, MatchLobbyCreate, MatchLobbyGameStart, MatchLobbyJoin,
+            MatchLobbySendMessage, WorldConnectToken,
+        },
+        SessionClient, SessionConnectEvent, SessionDisconnectEvent, SessionMessageEvents,
+    },
+    world::{
+        channels::{EntityAssignmentChannel, PlayerCommandChannel},
+        components::{NetworkedLastCommand, NetworkedMoveBuffer, NetworkedTileTarget},
+        messages::{EntityAssignment, PlayerCommands},
+        types::Direction,
+        WorldClient, WorldClientTickEvent, WorldConnectEvent, WorldDisconnectEvent,
+        WorldInsertComponentEvent, WorldMessageEvents, WorldUpdateComponentEvent,
+    },
+};
+use logging::{info, warn};
+use social_server_types::{LobbyId, LobbySettings};
+
+use crate::{args::BotArgs, metrics::BotMetrics};
+
+// how long a lobby owner waits for the rest of its group before starting the match anyway
+const LOBBY_FILL_TIMEOUT: Duration = Duration::from_secs(30);
+const CHAT_MESSAGE_LEN: usize = 24;
+
+#[derive(Clone, Copy)]
+enum LobbyStatus {
+    NotConnected,
+    Searching,
+    Creating,
+    Joined(LobbyId),
+    Started,
+}
+
+#[derive(Resource)]
+pub(crate) struct BotBehaviour {
+    username: String,
+    lobby_name: String,
+    is_lobby_owner: bool,
+    lobby_size: usize,
+    logged_in_at: Instant,
+
+    lobby_status: LobbyStatus,
+    lobby_fill_deadline: Option<Instant>,
+    session_connected: bool,
+
+    world_token_received_at: Option<Instant>,
+    world_connected: bool,
+    owned_entity: Option<Entity>,
+
+    commands: PlayerCommands,
+    move_interval: Option<Duration>,
+    next_move_at: Instant,
+    chat_interval: Option<Duration>,
+    next_chat_at: Instant,
+}
+
+impl BotBehaviour {
+    pub(crate) fn new(args: &BotArgs, bot_index: usize) -> Self {
+        let move_interval = rate_to_interval(args.moves_per_second);
+        let chat_interval = rate_to_interval(args.chats_per_minute / 60.0);
+        let now = Instant::now();
+
+        Self {
+            username: args.username(bot_index),
+            lobby_name: args.lobby_name(bot_index),
+            is_lobby_owner: args.is_lobby_owner(bot_index),
+            lobby_size: args.bots_per_lobby,
+            logged_in_at: now,
+
+            lobby_status: LobbyStatus::NotConnected,
+            lobby_fill_deadline: None,
+            session_connected: false,
+
+            world_token_received_at: None,
+            world_connected: false,
+            owned_entity: None,
+
+            commands: PlayerCommands::new(),
+            move_interval,
+            next_move_at: now,
+            // spread the bots' chat out, rather than have them all speak on the same frame
+            chat_interval,
+            next_chat_at: now + jittered(chat_interval.unwrap_or_default()),
+        }
+    }
+
+    pub(crate) fn set_logged_in(&mut self) {
+        self.logged_in_at = Instant::now();
+    }
+
+    // half the time the bot stands still, otherwise it walks & looks in a random direction
+    fn randomize_commands(&mut self) {
+        let mut commands = PlayerCommands::new();
+        if random::gen_bool() {
+            let direction = Direction::random();
+            commands.set_move(direction);
+            commands.set_look(direction);
+        }
+        self.commands = commands;
+    }
+}
+
+fn rate_to_interval(per_second: f32) -> Option<Duration> {
+    if per_second <= 0.0 {
+        return None;
+    }
+    Some(Duration::from_secs_f32(1.0 / per_second))
+}
+
+fn jittered(interval: Duration) -> Duration {
+    interval.mul_f32(random::gen_range_f32(0.0, 1.0))
+}
+
+// session
+
+pub(crate) fn session_connect_events(
+    mut client: SessionClient,
+    mut behaviour: ResMut<BotBehaviour>,
+    mut metrics: ResMut<BotMetrics>,
+    mut connect_reader: EventReader<SessionConnectEvent>,
+    mut disconnect_reader: EventReader<SessionDisconnectEvent>,
+) {
+    for _ in connect_reader.read() {
+        behaviour.session_connected = true;
+
+        // a resumed session keeps the lobby the bot was already in
+        if !matches!(behaviour.lobby_status, LobbyStatus::NotConnected) {
+            info!("{} reconnected to session server", behaviour.username);
+            continue;
+        }
+
+        let latency = behaviour.logged_in_at.elapsed();
+        info!(
+            "{} connected to session server after {:?}",
+            behaviour.username, latency
+        );
+        metrics.session_connect_latency = Some(latency);
+
+        if behaviour.is_lobby_owner {
+            let message =
+                MatchLobbyCreate::new(&behaviour.lobby_name, LobbySettings::default(), None);
+            client.send_message::<ClientActionsChannel, _>(&message);
+            behaviour.lobby_status = LobbyStatus::Creating;
+        } else {
+            behaviour.lobby_status = LobbyStatus::Searching;
+        }
+    }
+    for _ in disconnect_reader.read() {
+        warn!("{} disconnected from session server", behaviour.username);
+        behaviour.session_connected = false;
+    }
+}
+
+pub(crate) fn lobby_update(
+    mut client: SessionClient,
+    mut behaviour: ResMut<BotBehaviour>,
+    lobby_q: Query<&Lobby>,
+) {
+    match behaviour.lobby_status {
+        LobbyStatus::NotConnected | LobbyStatus::Started => {}
+        LobbyStatus::Searching => {
+            let Some(lobby) = lobby_q.iter().find(|lobby| {
+                *lobby.name == behaviour.lobby_name
+                    && lobby.is_waiting_to_start()
+                    && !lobby.is_full()
+            }) else {
+                return;
+            };
+            let lobby_id = *lobby.id;
+            client.send_message::<ClientActionsChannel, _>(&MatchLobbyJoin::new(lobby_id, None));
+            behaviour.lobby_status = LobbyStatus::Joined(lobby_id);
+        }
+        LobbyStatus::Creating => {
+            let Some(lobby) = lobby_q
+                .iter()
+                .find(|lobby| *lobby.name == behaviour.lobby_name && lobby.is_waiting_to_start())
+            else {
+                return;
+            };
+            behaviour.lobby_status = LobbyStatus::Joined(*lobby.id);
+            behaviour.lobby_fill_deadline = Some(Instant::now() + LOBBY_FILL_TIMEOUT);
+        }
+        LobbyStatus::Joined(lobby_id) => {
+            let Some(lobby) = lobby_q.iter().find(|lobby| *lobby.id == lobby_id) else {
+                return;
+            };
+            if lobby.is_in_progress() {
+                behaviour.lobby_status = LobbyStatus::Started;
+                return;
+            }
+            let Some(deadline) = behaviour.lobby_fill_deadline else {
+                return;
+            };
+            let lobby_filled = lobby.member_count() as usize >= behaviour.lobby_size;
+            if lobby.can_start() && (lobby_filled || Instant::now() >= deadline) {
+                info!(
+                    "{} starting match in lobby `{}` with {} members",
+                    behaviour.username,
+                    behaviour.lobby_name,
+                    lobby.member_count()
+                );
+                client.send_message::<ClientActionsChannel, _>(&MatchLobbyGameStart);
+                behaviour.lobby_status = LobbyStatus::Started;
+            }
+        }
+    }
+}
+
+// chats in the bot's lobby once it has one, and in global chat before that
+pub(crate) fn chat_update(
+    mut client: SessionClient,
+    mut behaviour: ResMut<BotBehaviour>,
+    mut metrics: ResMut<BotMetrics>,
+) {
+    if !behaviour.session_connected {
+        return;
+    }
+    let Some(chat_interval) = behaviour.chat_interval else {
+        return;
+    };
+    let now = Instant::now();
+    if now < behaviour.next_chat_at {
+        return;
+    }
+    behaviour.next_chat_at = now + chat_interval;
+
+    let text = random::generate_random_string(CHAT_MESSAGE_LEN);
+    match behaviour.lobby_status {
+        LobbyStatus::Joined(_) | LobbyStatus::Started => {
+            client.send_message::<ClientActionsChannel, _>(&MatchLobbySendMessage::new(&text));
+        }
+        _ => {
+            client.send_message::<ClientActionsChannel, _>(&GlobalChatSendMessage::new(&text));
+        }
+    }
+    metrics.chats_sent += 1;
+}
+
+pub(crate) fn session_message_events(
+    mut behaviour: ResMut<BotBehaviour>,
+    mut event_reader: EventReader<SessionMessageEvents>,
+) {
+    for events in event_reader.read() {
+        // the connection manager connects to the world, we just start the clock
+        for _ in events.read::<PrimaryChannel, WorldConnectToken>() {
+            behaviour.world_token_received_at = Some(Instant::now());
+        }
+    }
+}
+
+// world
+
+pub(crate) fn world_connect_events(
+    mut behaviour: ResMut<BotBehaviour>,
+    mut metrics: ResMut<BotMetrics>,
+    mut connect_reader: EventReader<WorldConnectEvent>,
+    mut disconnect_reader: EventReader<WorldDisconnectEvent>,
+) {
+    for _ in connect_reader.read() {
+        if let Some(token_received_at) = behaviour.world_token_received_at {
+            let latency = token_received_at.elapsed();
+            info!(
+                "{} connected to world server after {:?}",
+                behaviour.username, latency
+            );
+            metrics.world_connect_latency = Some(latency);
+        }
+        behaviour.world_connected = true;
+    }
+    for _ in disconnect_reader.read() {
+        info!("{} disconnected from world server", behaviour.username);
+        behaviour.world_connected = false;
+        behaviour.owned_entity = None;
+    }
+}
+
+pub(crate) fn world_message_events(
+    client: WorldClient,
+    mut behaviour: ResMut<BotBehaviour>,
+    mut event_reader: EventReader<WorldMessageEvents>,
+) {
+    for events in event_reader.read() {
+        for message in events.read::<EntityAssignmentChannel, EntityAssignment>() {
+            let entity = message.entity.get(&client);
+            if message.assign {
+                behaviour.owned_entity = entity;
+            } else if behaviour.owned_entity == entity {
+                behaviour.owned_entity = None;
+            }
+        }
+    }
+}
+
+pub(crate) fn world_tick_events(
+    mut client: WorldClient,
+    mut behaviour: ResMut<BotBehaviour>,
+    mut metrics: ResMut<BotMetrics>,
+    mut event_reader: EventReader<WorldClientTickEvent>,
+) {
+    for event in event_reader.read() {
+        let client_tick = event.tick;
+        if let Some(server_tick) = client.server_tick() {
+            metrics
+                .tick_drift
+                .record(wrapping_diff(server_tick, client_tick));
+        }
+
+        if behaviour.owned_entity.is_none() {
+            continue;
+        }
+        if let Some(move_interval) = behaviour.move_interval {
+            let now = Instant::now();
+            if now >= behaviour.next_move_at {
+                behaviour.next_move_at = now + move_interval;
+                behaviour.randomize_commands();
+            }
+        }
+
+        // like a held key, the current commands are sent every tick
+        client.send_tick_buffer_message::<PlayerCommandChannel, PlayerCommands>(
+            &client_tick,
+            &behaviour.commands,
+        );
+        metrics.commands_sent += 1;
+    }
+}
+
+// the game client rolls back at most once a frame, whenever one of these arrives for the unit it
+// predicts, i.e. the one assigned to this bot. other units' updates don't count
+pub(crate) fn rollback_events(
+    behaviour: Res<BotBehaviour>,
+    mut metrics: ResMut<BotMetrics>,
+    mut insert_tile_target_reader: EventReader<WorldInsertComponentEvent<NetworkedTileTarget>>,
+    mut update_tile_target_reader: EventReader<WorldUpdateComponentEvent<NetworkedTileTarget>>,
+    mut insert_last_command_reader: EventReader<WorldInsertComponentEvent<NetworkedLastCommand>>,
+    mut update_last_command_reader: EventReader<WorldUpdateComponentEvent<NetworkedLastCommand>>,
+    mut insert_move_buffer_reader: EventReader<WorldInsertComponentEvent<NetworkedMoveBuffer>>,
+    mut update_move_buffer_reader: EventReader<WorldUpdateComponentEvent<NetworkedMoveBuffer>>,
+) {
+    // every reader is drained, even while nothing is owned, so old events aren't counted later
+    let owned_entity = behaviour.owned_entity;
+    let is_owned = |entity: Entity| Some(entity) == owned_entity;
+
+    let mut rollback = false;
+    rollback |= insert_tile_target_reader
+        .read()
+        .filter(|event| is_owned(event.entity))
+        .count()
+        > 0;
+    rollback |= update_tile_target_reader
+        .read()
+        .filter(|event| is_owned(event.entity))
+        .count()
+        > 0;
+    rollback |= insert_last_command_reader
+        .read()
+        .filter(|event| is_owned(event.entity))
+        .count()
+        > 0;
+    rollback |= update_last_command_reader
+        .read()
+        .filter(|event| is_owned(event.entity))
+        .count()
+        > 0;
+    rollback |= insert_move_buffer_reader
+        .read()
+        .filter(|event| is_owned(event.entity))
+        .count()
+        > 0;
+    rollback |= update_move_buffer_reader
+        .read()
+        .filter(|event| is_owned(event.entity))
+        .count()
+        > 0;
+    if rollback {
+        metrics.rollbacks += 1;
+    }
+}
+
+pub(crate) fn measure_bandwidth(
+    mut session_client: SessionClient,
+    mut world_client: WorldClient,
+    behaviour: Res<BotBehaviour>,
+    mut metrics: ResMut<BotMetrics>,
+) {
+    let mut incoming_kbps = 0.0;
+    let mut outgoing_kbps = 0.0;
+    if behaviour.session_connected {
+        incoming_kbps += session_client.incoming_bandwidth();
+        outgoing_kbps += session_client.outgoing_bandwidth();
+    }
+    if behaviour.world_connected {
+        incoming_kbps += world_client.incoming_bandwidth();
+        outgoing_kbps += world_client.outgoing_bandwidth();
+    }
+    metrics.incoming_kbps = incoming_kbps;
+    metrics.outgoing_kbps = outgoing_kbps;
+}
@@ -0,0 +1,137 @@
+use std::{
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+
+use bevy_app::{App, Startup, Update};
+
+use asset_cache::{AssetCache, AssetLoadedEvent};
+use asset_loader::{AssetManager, AssetMetadataStore};
+use filesystem::FileSystemPlugin;
+use game_app_network::NetworkedEnginePlugin;
+use kernel::{http::CookieStore, KernelPlugin};
+use ui_runner::UiManager;
+
+use crate::{
+    args::BotArgs,
+    behaviour::{self, BotBehaviour},
+    login::{build_login_app, LoginResult, LoginState},
+    metrics::BotMetrics,
+};
+
+// each bot keeps its cookies & asset cache apart from the others, under here
+const BOT_DATA_DIR: &str = "bot_data";
+// bots log in before they start the game app, so there's no reason to wait between steps
+const CONNECT_INTERVAL: Duration = Duration::from_millis(100);
+const BANDWIDTH_MEASURE_DURATION: Duration = Duration::from_secs(5);
+
+enum BotPhase {
+    LoggingIn(App),
+    InGame(App),
+    Failed(String),
+}
+
+pub(crate) struct Bot {
+    username: String,
+    data_dir: String,
+    cookie_store: Arc<RwLock<CookieStore>>,
+    behaviour_opt: Option<BotBehaviour>,
+    phase: BotPhase,
+}
+
+impl Bot {
+    pub(crate) fn new(args: &BotArgs, bot_index: usize) -> Self {
+        let username = args.username(bot_index);
+        let data_dir = format!("{}/{}", BOT_DATA_DIR, username);
+        let cookie_store = Arc::new(RwLock::new(CookieStore::in_dir(&format!(
+            "{}/cookies",
+            data_dir
+        ))));
+        let login_app = build_login_app(cookie_store.clone(), &username, &args.password);
+
+        Self {
+            username,
+            data_dir,
+            cookie_store,
+            behaviour_opt: Some(BotBehaviour::new(args, bot_index)),
+            phase: BotPhase::LoggingIn(login_app),
+        }
+    }
+
+    pub(crate) fn username(&self) -> &str {
+        &self.username
+    }
+
+    pub(crate) fn update(&mut self) {
+        match &mut self.phase {
+            BotPhase::LoggingIn(app) => {
+                app.update();
+                let result = app.world_mut().resource_mut::<LoginState>().take_result();
+                match result {
+                    Some(LoginResult::LoggedIn(latency)) => {
+                        self.phase = BotPhase::InGame(self.build_game_app(latency));
+                    }
+                    Some(LoginResult::Failed(err)) => {
+                        self.phase = BotPhase::Failed(err);
+                    }
+                    None => {}
+                }
+            }
+            BotPhase::InGame(app) => app.update(),
+            BotPhase::Failed(_) => {}
+        }
+    }
+
+    // None while logging in, Err if the bot has given up
+    pub(crate) fn metrics(&self) -> Option<Result<&BotMetrics, &str>> {
+        match &self.phase {
+            BotPhase::LoggingIn(_) => None,
+            BotPhase::InGame(app) => Some(Ok(app.world().resource::<BotMetrics>())),
+            BotPhase::Failed(err) => Some(Err(err)),
+        }
+    }
+
+    // the game app's networking, without its renderer, input or ui
+    fn build_game_app(&mut self, login_latency: Duration) -> App {
+        let assets_dir = format!("{}/assets", self.data_dir);
+
+        let mut behaviour = self.behaviour_opt.take().unwrap();
+        behaviour.set_logged_in();
+        let metrics = BotMetrics {
+            login_latency: Some(login_latency),
+            ..Default::default()
+        };
+
+        let mut app = App::default();
+        app.add_plugins(KernelPlugin::new(Some(self.cookie_store.clone())))
+            .add_plugins(FileSystemPlugin)
+            // assets the session server sends are cached as they would be by the game,
+            // which needs these even though nothing is ever drawn
+            .init_resource::<AssetManager>()
+            .init_resource::<UiManager>()
+            .insert_resource(AssetMetadataStore::new(&assets_dir))
+            .add_systems(Startup, AssetMetadataStore::startup)
+            .add_systems(Update, AssetMetadataStore::handle_metadata_tasks)
+            .insert_resource(AssetCache::new(&assets_dir))
+            .add_event::<AssetLoadedEvent>()
+            .add_systems(Update, AssetCache::handle_save_asset_tasks)
+            .add_plugins(
+                NetworkedEnginePlugin::default()
+                    .with_connect_interval(CONNECT_INTERVAL)
+                    .with_bandwidth_measurement(BANDWIDTH_MEASURE_DURATION),
+            )
+            // bot
+            .insert_resource(behaviour)
+            .insert_resource(metrics)
+            .add_systems(Update, behaviour::session_connect_events)
+            .add_systems(Update, behaviour::lobby_update)
+            .add_systems(Update, behaviour::chat_update)
+            .add_systems(Update, behaviour::session_message_events)
+            .add_systems(Update, behaviour::world_connect_events)
+            .add_systems(Update, behaviour::world_message_events)
+            .add_systems(Update, behaviour::world_tick_events)
+            .add_systems(Update, behaviour::rollback_events)
+            .add_systems(Update, behaviour::measure_bandwidth);
+        app
+    }
+}
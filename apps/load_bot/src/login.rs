@@ -0,0 +1,94 @@
+use std::{
+    sync::{Arc, RwLock},
+    time::{Duration, Instant},
+};
+
+use bevy_app::{App, Startup, Update};
+use bevy_ecs::system::{ResMut, Resource};
+
+use config::{GATEWAY_PORT, PUBLIC_IP_ADDR};
+use gateway_http_proto::{UserLoginRequest, UserLoginResponse};
+use kernel::{
+    http::{CookieStore, HttpClient, ResponseKey},
+    KernelPlugin,
+};
+use logging::{info, warn};
+
+pub(crate) enum LoginResult {
+    // how long the gateway took to answer
+    LoggedIn(Duration),
+    Failed(String),
+}
+
+// the launcher's part of the flow: logs in through the gateway, leaving the auth cookies in the
+// bot's cookie store for the game app to connect with
+#[derive(Resource)]
+pub(crate) struct LoginState {
+    username: String,
+    password: String,
+    sent_at: Option<Instant>,
+    response_key_opt: Option<ResponseKey<UserLoginResponse>>,
+    result: Option<LoginResult>,
+}
+
+impl LoginState {
+    pub(crate) fn take_result(&mut self) -> Option<LoginResult> {
+        self.result.take()
+    }
+}
+
+pub(crate) fn build_login_app(
+    cookie_store: Arc<RwLock<CookieStore>>,
+    username: &str,
+    password: &str,
+) -> App {
+    let mut app = App::default();
+    app.add_plugins(KernelPlugin::new(Some(cookie_store)))
+        .insert_resource(LoginState {
+            username: username.to_string(),
+            password: password.to_string(),
+            sent_at: None,
+            response_key_opt: None,
+            result: None,
+        })
+        .add_systems(Startup, send_login_request)
+        .add_systems(Update, recv_login_response);
+    app
+}
+
+fn send_login_request(mut http_client: ResMut<HttpClient>, mut state: ResMut<LoginState>) {
+    let request = UserLoginRequest::new(&state.username, &state.password);
+    let key = http_client.send(PUBLIC_IP_ADDR, GATEWAY_PORT, request);
+    state.response_key_opt = Some(key);
+    state.sent_at = Some(Instant::now());
+
+    info!("{} sending login request...", state.username);
+}
+
+fn recv_login_response(mut http_client: ResMut<HttpClient>, mut state: ResMut<LoginState>) {
+    let Some(key) = &state.response_key_opt else {
+        return;
+    };
+    let Some(result) = http_client.recv(key) else {
+        return;
+    };
+    state.response_key_opt = None;
+
+    let result = match result {
+        Ok(response) => {
+            if response.is_simultaneous_login_detected() {
+                LoginResult::Failed("already logged in elsewhere".to_string())
+            } else {
+                LoginResult::LoggedIn(state.sent_at.unwrap().elapsed())
+            }
+        }
+        Err(err) => LoginResult::Failed(format!("{:?}", err)),
+    };
+    match &result {
+        LoginResult::LoggedIn(latency) => {
+            info!("{} logged in after {:?}", state.username, latency)
+        }
+        LoginResult::Failed(err) => warn!("{} failed to log in: {}", state.username, err),
+    }
+    state.result = Some(result);
+}
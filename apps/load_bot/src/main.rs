@@ -0,0 +1,83 @@
+mod args;
+mod behaviour;
+mod bot;
+mod login;
+mod metrics;
+
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+use logging::{info, warn};
+
+use crate::{args::BotArgs, bot::Bot, metrics::MetricsSummary};
+
+const FRAME_DURATION: Duration = Duration::from_millis(16);
+
+fn main() {
+    logging::initialize();
+
+    let args = BotArgs::from_matches(&args::cli().get_matches());
+
+    info!(
+        "starting {} bots ({}{}..{}{}), {} per lobby, for {:?}",
+        args.bot_count,
+        args.username_prefix,
+        args.first_bot,
+        args.username_prefix,
+        args.first_bot + args.bot_count,
+        args.bots_per_lobby,
+        args.run_duration,
+    );
+
+    let mut bots: Vec<Bot> = (args.first_bot..args.first_bot + args.bot_count)
+        .map(|bot_index| Bot::new(&args, bot_index))
+        .collect();
+
+    // every bot runs on this thread, taking turns each frame
+    let started = Instant::now();
+    let mut next_report = started + args.report_interval;
+    while started.elapsed() < args.run_duration {
+        let frame_started = Instant::now();
+
+        for bot in &mut bots {
+            bot.update();
+        }
+
+        if Instant::now() >= next_report {
+            next_report += args.report_interval;
+            report(&bots, false);
+        }
+
+        let frame_elapsed = frame_started.elapsed();
+        if frame_elapsed < FRAME_DURATION {
+            thread::sleep(FRAME_DURATION - frame_elapsed);
+        }
+    }
+
+    report(&bots, true);
+}
+
+// per-bot lines only in the final report, otherwise large runs would drown the log
+fn report(bots: &[Bot], is_final: bool) {
+    let mut summary = MetricsSummary::default();
+    for bot in bots {
+        match bot.metrics() {
+            None => summary.add_logging_in(),
+            Some(Ok(metrics)) => {
+                if is_final {
+                    info!("{}: {}", bot.username(), metrics);
+                }
+                summary.add(metrics);
+            }
+            Some(Err(err)) => {
+                if is_final {
+                    warn!("{}: failed: {}", bot.username(), err);
+                }
+                summary.add_failed();
+            }
+        }
+    }
+    info!("{}", summary);
+}
@@ -0,0 +1,161 @@
+use std::{
+    fmt::{Display, Formatter},
+    time::Duration,
+};
+
+use bevy_ecs::system::Resource;
+
+#[derive(Resource, Default, Clone)]
+pub(crate) struct BotMetrics {
+    pub(crate) login_latency: Option<Duration>,
+    // from logging in to the session server accepting the connection
+    pub(crate) session_connect_latency: Option<Duration>,
+    // from receiving the world connect token to the world server accepting the connection
+    pub(crate) world_connect_latency: Option<Duration>,
+    pub(crate) tick_drift: TickDrift,
+    // frames in which the game client would have rolled back & replayed its prediction
+    pub(crate) rollbacks: u32,
+    pub(crate) incoming_kbps: f32,
+    pub(crate) outgoing_kbps: f32,
+    pub(crate) commands_sent: u32,
+    pub(crate) chats_sent: u32,
+}
+
+// how many ticks the client runs ahead of the last tick it received from the server.
+// the client aims to stay just far enough ahead for its commands to arrive in time,
+// so a wide spread here means the connection is struggling to keep up
+#[derive(Default, Clone)]
+pub(crate) struct TickDrift {
+    samples: u32,
+    sum: i64,
+    min: i16,
+    max: i16,
+}
+
+impl TickDrift {
+    pub(crate) fn record(&mut self, drift: i16) {
+        if self.samples == 0 {
+            self.min = drift;
+            self.max = drift;
+        } else {
+            self.min = self.min.min(drift);
+            self.max = self.max.max(drift);
+        }
+        self.samples += 1;
+        self.sum += drift as i64;
+    }
+
+    fn average(&self) -> Option<f32> {
+        if self.samples == 0 {
+            return None;
+        }
+        Some(self.sum as f32 / self.samples as f32)
+    }
+}
+
+impl Display for BotMetrics {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "login {} | session {} | world {} | ",
+            fmt_latency(self.login_latency),
+            fmt_latency(self.session_connect_latency),
+            fmt_latency(self.world_connect_latency),
+        )?;
+        match self.tick_drift.average() {
+            Some(average) => write!(
+                f,
+                "tick drift {:.1} ({}..{}) | ",
+                average, self.tick_drift.min, self.tick_drift.max
+            )?,
+            None => write!(f, "tick drift - | ")?,
+        }
+        write!(
+            f,
+            "rollbacks {} | in {:.1} kbps | out {:.1} kbps | commands {} | chats {}",
+            self.rollbacks,
+            self.incoming_kbps,
+            self.outgoing_kbps,
+            self.commands_sent,
+            self.chats_sent
+        )
+    }
+}
+
+fn fmt_latency(latency: Option<Duration>) -> String {
+    match latency {
+        Some(latency) => format!("{}ms", latency.as_millis()),
+        None => "-".to_string(),
+    }
+}
+
+// totals across every bot in the process, so runs of different sizes can be compared
+#[derive(Default)]
+pub(crate) struct MetricsSummary {
+    bots: u32,
+    logging_in: u32,
+    in_world: u32,
+    failed: u32,
+    session_connect_latencies: Vec<Duration>,
+    world_connect_latencies: Vec<Duration>,
+    rollbacks: u32,
+    incoming_kbps: f32,
+    outgoing_kbps: f32,
+}
+
+impl MetricsSummary {
+    pub(crate) fn add_logging_in(&mut self) {
+        self.bots += 1;
+        self.logging_in += 1;
+    }
+
+    pub(crate) fn add_failed(&mut self) {
+        self.bots += 1;
+        self.failed += 1;
+    }
+
+    pub(crate) fn add(&mut self, metrics: &BotMetrics) {
+        self.bots += 1;
+        if let Some(latency) = metrics.session_connect_latency {
+            self.session_connect_latencies.push(latency);
+        }
+        if let Some(latency) = metrics.world_connect_latency {
+            self.in_world += 1;
+            self.world_connect_latencies.push(latency);
+        }
+        self.rollbacks += metrics.rollbacks;
+        self.incoming_kbps += metrics.incoming_kbps;
+        self.outgoing_kbps += metrics.outgoing_kbps;
+    }
+}
+
+impl Display for MetricsSummary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} bots ({} logging in, {} in session, {} in world, {} failed) | session p50 {} p99 {} | world p50 {} p99 {} | rollbacks {} | in {:.1} kbps | out {:.1} kbps",
+            self.bots,
+            self.logging_in,
+            self.session_connect_latencies.len(),
+            self.in_world,
+            self.failed,
+            fmt_latency(percentile(&self.session_connect_latencies, 50)),
+            fmt_latency(percentile(&self.session_connect_latencies, 99)),
+            fmt_latency(percentile(&self.world_connect_latencies, 50)),
+            fmt_latency(percentile(&self.world_connect_latencies, 99)),
+            self.rollbacks,
+            self.incoming_kbps,
+            self.outgoing_kbps,
+        )
+    }
+}
+
+fn percentile(latencies: &[Duration], percent: usize) -> Option<Duration> {
+    if latencies.is_empty() {
+        return None;
+    }
+    let mut sorted = latencies.to_vec();
+    sorted.sort();
+    let index = ((sorted.len() - 1) * percent) / 100;
+    Some(sorted[index])
+}
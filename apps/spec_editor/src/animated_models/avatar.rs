@@ -1,5 +1,5 @@
 use asset_id::{AssetId, ETag};
-use spec::AnimatedModel;
+use spec::{AnimatedModel, AnimationGraph};
 
 use crate::catalog::AssetCatalog;
 
@@ -21,6 +21,7 @@ pub fn define() -> (String, AssetId, ETag, AnimatedModel) {
     let mut animated_model = AnimatedModel::new(model_asset_id);
     animated_model.add_animation("idle", idle_animation_asset_id);
     animated_model.add_animation("walk", walk_animation_asset_id);
+    animated_model.set_graph(AnimationGraph::idle_walk());
 
     (
         self_name.to_string(),
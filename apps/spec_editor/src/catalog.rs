@@ -22,4 +22,8 @@ impl AssetCatalog {
     pub(crate) fn avatar_movement_config() -> AssetId {
         AssetId::from_str("wyte5b").unwrap()
     }
+
+    pub(crate) fn avatar_dust_particle_effect() -> AssetId {
+        AssetId::from_str("p7d4kq").unwrap()
+    }
 }
@@ -3,11 +3,13 @@ mod writers;
 
 mod animated_models;
 mod movement_configs;
+mod particle_effects;
 mod units;
 
 use writers::{
     animated_model::write_to_file as write_animated_model,
-    movement_config::write_to_file as write_movement_config, unit::write_to_file as write_unit,
+    movement_config::write_to_file as write_movement_config,
+    particle_effect::write_to_file as write_particle_effect, unit::write_to_file as write_unit,
 };
 
 fn main() {
@@ -17,6 +19,9 @@ fn main() {
     // avatar.movement_config
     write_movement_config(movement_configs::avatar::define());
 
+    // avatar_dust.particle_effect
+    write_particle_effect(particle_effects::avatar_dust::define());
+
     // avatar.unit
     write_unit(units::avatar::define());
 }
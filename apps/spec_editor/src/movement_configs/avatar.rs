@@ -12,7 +12,14 @@ pub fn define() -> (String, AssetId, ETag, MovementConfig) {
     let self_asset_id = AssetId::from_str(&self_asset_id_str).unwrap();
 
     // Create spec !
-    let mut movement_config = MovementConfig::new(2.0);
+    let movement_config = MovementConfig::new(
+        12.0, // max velocity
+        3.0,  // min velocity
+        1.0,  // acceleration
+        1.0,  // friction
+        5.0,  // arrival distance
+        4.0,  // steering deadzone
+    );
 
     (
         self_name.to_string(),
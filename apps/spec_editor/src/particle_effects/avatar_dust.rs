@@ -0,0 +1,36 @@
+use asset_id::{AssetId, ETag};
+use spec::ParticleEffect;
+
+#[allow(unused)]
+pub fn define() -> (String, AssetId, ETag, ParticleEffect) {
+    // config
+    let self_name = "avatar_dust";
+    let self_asset_id_str = "p7d4kq"; // AssetId::gen_random().as_string(); // keep this around to generate new AssetIds if needed!
+    let self_etag = ETag::gen_random();
+
+    // asset ids ..
+    let self_asset_id = AssetId::from_str(&self_asset_id_str).unwrap();
+
+    // Create spec !
+    let particle_effect = ParticleEffect::new(
+        12.0,  // spawn rate
+        600.0, // lifetime ms
+        32,    // max particles
+    )
+    .with_velocity(
+        (0.0, 0.0, 1.0), // direction
+        35.0,            // spread degrees
+        4.0,             // min speed
+        8.0,             // max speed
+    )
+    .with_acceleration((0.0, 0.0, -9.8))
+    .with_color((181, 160, 128), (120, 104, 84))
+    .with_size(1.5, 0.2);
+
+    (
+        self_name.to_string(),
+        self_asset_id,
+        self_etag,
+        particle_effect,
+    )
+}
@@ -15,9 +15,11 @@ pub fn define() -> (String, AssetId, ETag, Unit) {
 
     let animated_model_asset_id = AssetCatalog::avatar_animated_model();
     let movement_config_asset_id = AssetCatalog::avatar_movement_config();
+    let particle_effect_asset_id = AssetCatalog::avatar_dust_particle_effect();
 
     // Create spec !
-    let mut unit = Unit::new(animated_model_asset_id, movement_config_asset_id);
+    let mut unit = Unit::new(animated_model_asset_id, movement_config_asset_id)
+        .with_particle_effect(particle_effect_asset_id);
 
     (self_name.to_string(), self_asset_id, self_etag, unit)
 }
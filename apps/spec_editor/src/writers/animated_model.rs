@@ -8,6 +8,10 @@ use spec::AnimatedModel;
 pub(crate) fn write_to_file(definition: (String, AssetId, ETag, AnimatedModel)) -> AnimatedModel {
     let (name, spec_asset_id, spec_etag, spec) = definition;
 
+    if let Err(error) = spec.validate() {
+        panic!("invalid animated model `{}`: {}", name, error);
+    }
+
     let spec_asset_id_str = spec_asset_id.to_string();
 
     // spec -> JSON bytes
@@ -1,3 +1,4 @@
 pub mod animated_model;
 pub mod movement_config;
+pub mod particle_effect;
 pub mod unit;
@@ -0,0 +1,68 @@
+use asset_id::{AssetId, AssetType, ETag};
+use asset_serde::{
+    bits::{AssetMetadataSerde, ParticleEffectBits},
+    json::{Asset, AssetData, AssetMeta, ParticleEffectJson},
+};
+use spec::ParticleEffect;
+
+pub(crate) fn write_to_file(definition: (String, AssetId, ETag, ParticleEffect)) -> ParticleEffect {
+    let (name, spec_asset_id, spec_etag, spec) = definition;
+
+    let spec_asset_id_str = spec_asset_id.to_string();
+
+    // spec -> JSON bytes
+    let spec_bytes = {
+        let spec_json = ParticleEffectJson::from(&spec);
+        let new_meta = AssetMeta::new(&spec_asset_id, ParticleEffectJson::CURRENT_SCHEMA_VERSION);
+        let asset = Asset::new(new_meta, AssetData::ParticleEffect(spec_json));
+        let spec_bytes = serde_json::to_vec_pretty(&asset).unwrap();
+        // info!("json byte count: {:?}", spec_bytes.len());
+        spec_bytes
+    };
+
+    // write JSON bytes to file
+    std::fs::write(format!("output/{}.particle_effect.json", name), &spec_bytes).unwrap();
+
+    // JSON bytes -> spec
+    let spec: ParticleEffect = {
+        let asset: Asset = serde_json::from_slice(&spec_bytes).unwrap();
+        let (_, data) = asset.deconstruct();
+        let AssetData::ParticleEffect(spec_json) = data else {
+            panic!("expected ParticleEffect");
+        };
+        spec_json.into()
+    };
+
+    // spec -> bit-packed bytes
+    let spec_bytes: Vec<u8> = {
+        let spec_bits: ParticleEffectBits = (&spec).into();
+        spec_bits.into()
+    };
+    // info!("bits byte count: {:?}", spec_bytes.len());
+
+    // write bit-packed data to file
+    std::fs::write(format!("output/{}", spec_asset_id_str), &spec_bytes).unwrap();
+
+    // write metadata to file
+    {
+        let metadata = AssetMetadataSerde::new(spec_etag, AssetType::ParticleEffect);
+        let metadata_bytes = metadata.to_bytes();
+        std::fs::write(
+            format!("output/{}.meta", spec_asset_id_str),
+            &metadata_bytes,
+        )
+        .unwrap();
+    }
+
+    // bit-packed bytes -> spec
+    let particle_effect: ParticleEffect = {
+        let spec_bits = ParticleEffectBits::from_bytes(&spec_bytes).unwrap();
+        ParticleEffectBits::into(spec_bits)
+    };
+
+    // delete bit-packed files
+    std::fs::remove_file(format!("output/{}", spec_asset_id_str)).unwrap();
+    std::fs::remove_file(format!("output/{}.meta", spec_asset_id_str)).unwrap();
+
+    particle_effect
+}
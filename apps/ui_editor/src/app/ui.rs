@@ -71,6 +71,10 @@ pub fn setup(
     let main_menu_ui_handle = global.load_ui(&mut ui_manager, game::main_menu::ui_define()); // game main menu
 
     // global.load_ui(&mut ui_manager, game::host_match::ui_define()); // game host match
+    // global.load_ui(&mut ui_manager, game::match_results::ui_define()); // game match results
+    // global.load_ui(&mut ui_manager, game::friends::ui_define()); // game friends
+    // global.load_ui(&mut ui_manager, game::friend_list_item::ui_define()); // game friend list item
+    // global.load_ui(&mut ui_manager, game::conversation::ui_define()); // game conversation
 
     ui_manager.set_target_render_layer(RenderLayers::layer(0));
     ui_manager.enable_ui(&main_menu_ui_handle);
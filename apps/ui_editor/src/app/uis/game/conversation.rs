@@ -0,0 +1,87 @@
+use game_engine::{
+    asset::{AssetId, ETag},
+    render::base::Color,
+};
+use ui_builder::{Alignment, UiConfig, UiConfigBuild};
+
+#[allow(unused)]
+pub fn ui_define() -> (String, AssetId, ETag, UiConfig) {
+    // config
+    let ui_name = "conversation";
+    let ui_asset_id_str = "p4dm7c"; //AssetId::gen_random().as_string(); // keep this around to generate new AssetIds if needed!
+    let ui_etag = ETag::gen_random();
+
+    // asset ids ..
+    let ui_asset_id = AssetId::from_str(&ui_asset_id_str).unwrap();
+
+    // Create UI !
+    let mut ui_config = UiConfig::new();
+
+    // styles
+    let window_style = ui_config.create_panel_style(|s| {
+        s.set_background_alpha(0.)
+            .set_vertical()
+            .set_children_valign(Alignment::Start);
+    });
+    let header_style = ui_config.create_panel_style(|s| {
+        s.set_background_alpha(0.)
+            .set_width_pc(100.0)
+            .set_horizontal()
+            .set_children_halign(Alignment::Start);
+    });
+    let block_button_style = ui_config.create_button_style(|s| {
+        s.set_background_color(Color::DARK_GRAY)
+            .set_hover_color(Color::RED)
+            .set_down_color(Color::BLUE)
+            .set_self_halign(Alignment::Start)
+            .set_margin_left_vp(2.0)
+            .set_margin_top_vp(1.0);
+    });
+    let block_button_text_style = ui_config.create_text_style(|s| {
+        s.set_background_alpha(0.)
+            .set_size_vp(3.)
+            .set_margin_left_vp(1.0)
+            .set_margin_right_vp(1.0)
+            .set_text_color(Color::WHITE);
+    });
+    let conversation_wall_style = ui_config.create_panel_style(|s| {
+        s.set_background_alpha(0.)
+            .set_width_pc(100.0)
+            .set_height_pc(88.0)
+            .set_vertical()
+            .set_children_valign(Alignment::End);
+    });
+    let base_textbox_style = ui_config.create_textbox_style(|s| {
+        s.set_background_color(Color::GRAY)
+            .set_hover_color(Color::RED)
+            .set_active_color(Color::BLUE)
+            .set_selection_color(Color::DARK_BLUE)
+            .set_width_pc(100.)
+            .set_height_vp(4.0);
+    });
+
+    // nodes
+    ui_config.root_mut().set_style(window_style).contents(|c| {
+        // block toggle for the other user
+        c.add_panel().set_style(header_style).contents(|c| {
+            c.add_button("block_button")
+                .set_style(block_button_style)
+                .contents(|c| {
+                    c.add_text_with_id("block", "block_button_text")
+                        .set_style(block_button_text_style);
+                });
+        });
+
+        // messages with the other user
+        c.add_panel_with_id("conversation_wall")
+            .set_style(conversation_wall_style);
+
+        // message input
+        // text-edit
+        c.add_textbox("message_textbox")
+            .set_as_first_input()
+            .set_style(base_textbox_style);
+    });
+
+    (ui_name.to_string(), ui_asset_id, ui_etag, ui_config)
+}
@@ -0,0 +1,109 @@
+use game_engine::{
+    asset::{AssetId, ETag},
+    render::base::Color,
+};
+use ui_builder::{Alignment, UiConfig, UiConfigBuild};
+
+#[allow(unused)]
+pub fn ui_define() -> (String, AssetId, ETag, UiConfig) {
+    // config
+    let ui_name = "friend_list_item";
+    let ui_asset_id_str = "h2nv8q"; // AssetId::gen_random().as_string(); // keep this around to generate new AssetIds if needed!
+    let ui_etag = ETag::gen_random();
+
+    // asset ids ..
+    let ui_asset_id = AssetId::from_str(&ui_asset_id_str).unwrap();
+
+    // Create UI !
+    let mut ui_config = UiConfig::new();
+
+    // styles
+    let container_style = ui_config.create_panel_style(|s| {
+        s.set_background_alpha(0.)
+            .set_height_vp(4.0)
+            .set_width_pc(100.0)
+            .set_horizontal()
+            .set_self_halign(Alignment::Start)
+            .set_margin_left_vp(2.0)
+            .set_margin_top_vp(1.0);
+    });
+    let username_style_online = ui_config.create_text_style(|s| {
+        s.set_id("online")
+            .set_background_alpha(0.)
+            .set_size_pc(100.0)
+            .set_text_color(Color::WHITE);
+    });
+    let username_style_offline = ui_config.create_text_style(|s| {
+        s.set_id("offline")
+            .set_background_alpha(0.)
+            .set_size_pc(100.0)
+            .set_text_color(Color::LIGHT_GRAY);
+    });
+    let status_style = ui_config.create_text_style(|s| {
+        s.set_background_alpha(0.)
+            .set_size_pc(80.0)
+            .set_margin_left_vp(2.0)
+            .set_self_valign(Alignment::Center)
+            .set_text_color(Color::LIGHT_GRAY);
+    });
+
+    let action_button_style = ui_config.create_button_style(|s| {
+        s.set_background_color(Color::DARK_GRAY)
+            .set_hover_color(Color::RED)
+            .set_down_color(Color::BLUE)
+            .set_height_pc(80.0)
+            .set_margin_left_vp(1.0)
+            .set_self_valign(Alignment::Center);
+    });
+    let action_button_text_style = ui_config.create_text_style(|s| {
+        s.set_background_alpha(0.)
+            .set_size_pc(100.0)
+            .set_margin_left_pc(4.0)
+            .set_margin_right_pc(4.0)
+            .set_text_color(Color::WHITE);
+    });
+
+    // nodes
+    ui_config
+        .root_mut()
+        .set_style(container_style)
+        .contents(|c| {
+            // username
+            c.add_text_with_id("?", "username")
+                .set_style(username_style_offline);
+
+            // presence, or "wants to be friends" for a request
+            c.add_text_with_id("offline", "status")
+                .set_style(status_style);
+
+            // join button (friend is in a lobby)
+            c.add_button("join_button")
+                .set_style(action_button_style)
+                .contents(|c| {
+                    c.add_text("join").set_style(action_button_text_style);
+                });
+
+            // remove button (friends only)
+            c.add_button("remove_button")
+                .set_style(action_button_style)
+                .contents(|c| {
+                    c.add_text("remove").set_style(action_button_text_style);
+                });
+
+            // accept button (requests only)
+            c.add_button("accept_button")
+                .set_style(action_button_style)
+                .contents(|c| {
+                    c.add_text("accept").set_style(action_button_text_style);
+                });
+
+            // decline button (requests only)
+            c.add_button("decline_button")
+                .set_style(action_button_style)
+                .contents(|c| {
+                    c.add_text("decline").set_style(action_button_text_style);
+                });
+        });
+
+    (ui_name.to_string(), ui_asset_id, ui_etag, ui_config)
+}
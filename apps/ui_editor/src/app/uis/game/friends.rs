@@ -0,0 +1,75 @@
+use game_engine::{
+    asset::{AssetId, ETag},
+    render::base::Color,
+};
+
+use ui_builder::{Alignment, UiConfig, UiConfigBuild};
+
+#[allow(unused)]
+pub fn ui_define() -> (String, AssetId, ETag, UiConfig) {
+    // config
+    let ui_name = "friends";
+    let ui_asset_id_str = "f7kw3d"; //AssetId::gen_random().as_string(); // keep this around to generate new AssetIds if needed!
+    let ui_etag = ETag::gen_random();
+
+    // asset ids ..
+    let ui_asset_id = AssetId::from_str(&ui_asset_id_str).unwrap();
+
+    // Create UI !
+    let mut ui_config = UiConfig::new();
+
+    // styles
+    let window_style = ui_config.create_panel_style(|s| {
+        s.set_background_alpha(0.)
+            .set_vertical()
+            .set_children_valign(Alignment::Start);
+    });
+    let friend_list_style = ui_config.create_panel_style(|s| {
+        s.set_background_alpha(0.)
+            .set_width_pc(100.0)
+            .set_height_pc(100.0)
+            .set_vertical()
+            .set_children_valign(Alignment::Start);
+    });
+
+    let away_container_style = ui_config.create_panel_style(|s| {
+        s.set_background_alpha(0.)
+            .set_width_pc(100.0)
+            .set_horizontal()
+            .set_children_halign(Alignment::Start);
+    });
+    let away_button_style = ui_config.create_button_style(|s| {
+        s.set_background_color(Color::DARK_GRAY)
+            .set_hover_color(Color::RED)
+            .set_down_color(Color::BLUE)
+            .set_self_halign(Alignment::Start)
+            .set_margin_left_vp(2.0)
+            .set_margin_top_vp(1.0);
+    });
+    let away_button_text_style = ui_config.create_text_style(|s| {
+        s.set_background_alpha(0.)
+            .set_size_vp(3.)
+            .set_margin_left_vp(1.0)
+            .set_margin_right_vp(1.0)
+            .set_text_color(Color::WHITE);
+    });
+
+    // nodes
+    ui_config.root_mut().set_style(window_style).contents(|c| {
+        // away toggle, friends see "away" instead of "online"
+        c.add_panel().set_style(away_container_style).contents(|c| {
+            c.add_button("away_button")
+                .set_style(away_button_style)
+                .contents(|c| {
+                    c.add_text_with_id("status: online", "away_button_text")
+                        .set_style(away_button_text_style);
+                });
+        });
+
+        // friends & incoming friend requests
+        c.add_panel_with_id("friend_list")
+            .set_style(friend_list_style);
+    });
+
+    (ui_name.to_string(), ui_asset_id, ui_etag, ui_config)
+}
@@ -110,7 +110,51 @@ pub fn ui_define() -> (String, AssetId, ETag, UiConfig) {
             .set_as_first_input()
             .set_style(base_textbox_style)
             .navigation(|n| {
-                n.down_goes_to("submit_button").tab_goes_to("submit_button");
+                n.down_goes_to("max_players_textbox")
+                    .tab_goes_to("max_players_textbox");
+            });
+
+        // max players input
+        // text
+        c.add_text("max players:").set_style(base_label_style);
+        // text-edit
+        c.add_textbox("max_players_textbox")
+            .set_style(base_textbox_style)
+            .navigation(|n| {
+                n.up_goes_to("name_textbox")
+                    .down_goes_to("password_textbox")
+                    .tab_goes_to("password_textbox");
+            });
+
+        // password input
+        // text
+        c.add_text("password (optional):")
+            .set_style(base_label_style);
+        // text-edit
+        c.add_textbox("password_textbox")
+            .set_as_password()
+            .set_style(base_textbox_style)
+            .navigation(|n| {
+                n.up_goes_to("max_players_textbox")
+                    .down_goes_to("invite_only_button")
+                    .tab_goes_to("invite_only_button");
+            });
+
+        c.add_panel()
+            .set_style(button_container_style)
+            .contents(|c| {
+                // invite only toggle button
+                c.add_button("invite_only_button")
+                    .set_style(submit_button_style)
+                    .contents(|c| {
+                        c.add_text_with_id("invite only: off", "invite_only_button_text")
+                            .set_style(base_button_text_style);
+                    })
+                    .navigation(|n| {
+                        n.up_goes_to("password_textbox")
+                            .down_goes_to("submit_button")
+                            .tab_goes_to("submit_button");
+                    });
             });
 
         c.add_panel()
@@ -123,7 +167,8 @@ pub fn ui_define() -> (String, AssetId, ETag, UiConfig) {
                         c.add_text("submit").set_style(base_button_text_style);
                     })
                     .navigation(|n| {
-                        n.up_goes_to("name_textbox").tab_goes_to("name_textbox");
+                        n.up_goes_to("invite_only_button")
+                            .tab_goes_to("name_textbox");
                     });
 
                 // spinner
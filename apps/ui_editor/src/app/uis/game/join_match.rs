@@ -1,4 +1,7 @@
-use game_engine::asset::{AssetId, ETag};
+use game_engine::{
+    asset::{AssetId, ETag},
+    render::base::Color,
+};
 
 use ui_builder::{Alignment, UiConfig, UiConfigBuild};
 
@@ -31,8 +34,42 @@ pub fn ui_define() -> (String, AssetId, ETag, UiConfig) {
             .set_children_valign(Alignment::Start);
     });
 
+    let password_container_style = ui_config.create_panel_style(|s| {
+        s.set_background_alpha(0.)
+            .set_width_pc(100.0)
+            .set_horizontal()
+            .set_children_halign(Alignment::Start);
+    });
+    let password_label_style = ui_config.create_text_style(|s| {
+        s.set_background_alpha(0.)
+            .set_size_vp(3.)
+            .set_self_halign(Alignment::Start)
+            .set_margin_left_vp(2.0)
+            .set_text_color(Color::WHITE);
+    });
+    let password_textbox_style = ui_config.create_textbox_style(|s| {
+        s.set_background_color(Color::GRAY)
+            .set_hover_color(Color::RED)
+            .set_active_color(Color::BLUE)
+            .set_selection_color(Color::DARK_BLUE)
+            .set_size_pc(40., 5.)
+            .set_self_halign(Alignment::Start)
+            .set_margin_left_vp(2.0);
+    });
+
     // nodes
     ui_config.root_mut().set_style(window_style).contents(|c| {
+        // password input, used when joining a password-protected lobby
+        c.add_panel()
+            .set_style(password_container_style)
+            .contents(|c| {
+                c.add_text("lobby password:")
+                    .set_style(password_label_style);
+                c.add_textbox("password_textbox")
+                    .set_as_password()
+                    .set_style(password_textbox_style);
+            });
+
         // match lobby list
         c.add_panel_with_id("lobby_list")
             .set_style(match_lobby_list_style);
@@ -2,7 +2,7 @@ use game_engine::{
     asset::{AssetId, ETag},
     render::base::Color,
 };
-use ui_builder::{Alignment, UiConfig, UiConfigBuild};
+use ui_builder::{Alignment, ImageFit, UiConfig, UiConfigBuild};
 
 #[allow(unused)]
 pub fn ui_define() -> (String, AssetId, ETag, UiConfig) {
@@ -68,10 +68,18 @@ pub fn ui_define() -> (String, AssetId, ETag, UiConfig) {
     let center_container_style = ui_config.create_ui_container_style(|s| {
         s.set_width_pc(100.0).set_height_vp(95.0);
     });
+    let right_user_card_style = ui_config.create_panel_style(|s| {
+        s.set_background_color(Color::BLACK)
+            .set_background_fit(ImageFit::Contain)
+            .set_width_pc(100.0)
+            .set_height_vp(15.0)
+            .set_margin_top_vp(0.2);
+    });
     let right_user_list_style = ui_config.create_panel_style(|s| {
         s.set_background_color(Color::DARK_GRAY)
             .set_width_pc(100.0)
-            .set_height_vp(75.0)
+            .set_height_vp(60.0)
+            .set_margin_top_vp(0.2)
             .set_children_valign(Alignment::Start);
     });
 
@@ -139,6 +147,13 @@ pub fn ui_define() -> (String, AssetId, ETag, UiConfig) {
                     c.add_text("chat").set_style(base_button_text_style);
                 });
 
+            // friends
+            c.add_button("friends_button")
+                .set_style(side_button_style)
+                .contents(|c| {
+                    c.add_text("friends").set_style(base_button_text_style);
+                });
+
             // devlog
             c.add_button("devlog_button")
                 .set_enabled(false)
@@ -194,6 +209,10 @@ pub fn ui_define() -> (String, AssetId, ETag, UiConfig) {
                 //     });
             });
 
+            // user card, shows the portrait camera's render target
+            c.add_panel_with_id("user_portrait")
+                .set_style(right_user_card_style);
+
             // user list
             c.add_panel_with_id("user_list")
                 .set_style(right_user_list_style);
@@ -50,6 +50,13 @@ pub fn ui_define() -> (String, AssetId, ETag, UiConfig) {
             .set_self_halign(Alignment::Start);
     });
 
+    let details_style = ui_config.create_text_style(|s| {
+        s.set_background_alpha(0.)
+            .set_size_pc(100.0)
+            .set_text_color(Color::WHITE)
+            .set_self_halign(Alignment::Start);
+    });
+
     // nodes
     ui_config
         .root_mut()
@@ -64,6 +71,9 @@ pub fn ui_define() -> (String, AssetId, ETag, UiConfig) {
                     // username
                     c.add_text_with_id("coolname", "username")
                         .set_style(username_style);
+                    // player count & access
+                    c.add_text_with_id("1/8", "details")
+                        .set_style(details_style);
                 });
         });
 
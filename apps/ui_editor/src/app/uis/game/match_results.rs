@@ -0,0 +1,79 @@
+use game_engine::{
+    asset::{AssetId, ETag},
+    render::base::Color,
+};
+use ui_builder::{Alignment, UiConfig, UiConfigBuild};
+
+#[allow(unused)]
+pub fn ui_define() -> (String, AssetId, ETag, UiConfig) {
+    // config
+    let ui_name = "match_results";
+    let ui_asset_id_str = "r7m2xk"; //AssetId::gen_random().as_string(); // keep this around to generate new AssetIds if needed!
+    let ui_etag = ETag::gen_random();
+
+    // asset ids ..
+    let ui_asset_id = AssetId::from_str(&ui_asset_id_str).unwrap();
+
+    // Create UI !
+    let mut ui_config = UiConfig::new();
+
+    // styles
+    let window_style = ui_config.create_panel_style(|s| {
+        s.set_background_alpha(0.)
+            .set_vertical()
+            .set_children_valign(Alignment::Start);
+    });
+    let heading_container_style = ui_config.create_panel_style(|s| {
+        s.set_background_alpha(0.)
+            .set_width_pc(100.0)
+            .set_height_pc(10.0)
+            .set_horizontal()
+            .set_children_halign(Alignment::Start);
+    });
+    let heading_text_style = ui_config.create_text_style(|s| {
+        s.set_background_alpha(0.)
+            .set_size_vp(4.0)
+            .set_margin_left_vp(2.0)
+            .set_text_color(Color::WHITE);
+    });
+    let outcome_text_style = ui_config.create_text_style(|s| {
+        s.set_background_alpha(0.)
+            .set_size_vp(5.)
+            .set_self_halign(Alignment::Start)
+            .set_margin_left_vp(4.0)
+            .set_margin_top_vp(2.0)
+            .set_text_color(Color::WHITE);
+    });
+    let detail_text_style = ui_config.create_text_style(|s| {
+        s.set_background_alpha(0.)
+            .set_size_vp(3.)
+            .set_self_halign(Alignment::Start)
+            .set_margin_left_vp(4.0)
+            .set_margin_top_vp(1.0)
+            .set_text_color(Color::LIGHT_GRAY);
+    });
+
+    // nodes
+    ui_config.root_mut().set_style(window_style).contents(|c| {
+        // heading container
+        c.add_panel()
+            .set_style(heading_container_style)
+            .contents(|c| {
+                c.add_text("match results").set_style(heading_text_style);
+            });
+
+        // outcome
+        c.add_text_with_id("match finished", "outcome_text")
+            .set_style(outcome_text_style);
+
+        // time played
+        c.add_text_with_id("time played: 0:00", "time_played_text")
+            .set_style(detail_text_style);
+
+        // present at end or left early
+        c.add_text_with_id("you were there at the end", "status_text")
+            .set_style(detail_text_style);
+    });
+
+    (ui_name.to_string(), ui_asset_id, ui_etag, ui_config)
+}
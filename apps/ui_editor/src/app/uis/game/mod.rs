@@ -1,3 +1,6 @@
+pub mod conversation;
+pub mod friend_list_item;
+pub mod friends;
 pub mod global_chat;
 pub mod global_chat_day_divider;
 pub mod global_chat_message;
@@ -6,4 +9,5 @@ pub mod host_match;
 pub mod join_match;
 pub mod main_menu;
 pub mod match_lobby_list_item;
+pub mod match_results;
 pub mod user_list_item;
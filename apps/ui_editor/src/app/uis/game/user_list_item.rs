@@ -44,6 +44,22 @@ pub fn ui_define() -> (String, AssetId, ETag, UiConfig) {
             .set_text_color(Color::LIGHT_GRAY);
     });
 
+    let action_button_style = ui_config.create_button_style(|s| {
+        s.set_background_color(Color::DARK_GRAY)
+            .set_hover_color(Color::RED)
+            .set_down_color(Color::BLUE)
+            .set_height_pc(80.0)
+            .set_margin_left_pc(2.0)
+            .set_self_valign(Alignment::Center);
+    });
+    let action_button_text_style = ui_config.create_text_style(|s| {
+        s.set_background_alpha(0.)
+            .set_size_pc(100.0)
+            .set_margin_left_pc(4.0)
+            .set_margin_right_pc(4.0)
+            .set_text_color(Color::WHITE);
+    });
+
     // nodes
     ui_config
         .root_mut()
@@ -52,6 +68,35 @@ pub fn ui_define() -> (String, AssetId, ETag, UiConfig) {
             // username
             c.add_text_with_id("?", "username")
                 .set_style(username_style_offline);
+
+            // kick button (lobby owner only)
+            c.add_button("kick_button")
+                .set_style(action_button_style)
+                .contents(|c| {
+                    c.add_text("kick").set_style(action_button_text_style);
+                });
+
+            // invite button (lobby owner only)
+            c.add_button("invite_button")
+                .set_style(action_button_style)
+                .contents(|c| {
+                    c.add_text("invite").set_style(action_button_text_style);
+                });
+
+            // add friend button (users who aren't friends yet)
+            c.add_button("add_friend_button")
+                .set_style(action_button_style)
+                .contents(|c| {
+                    c.add_text("add friend").set_style(action_button_text_style);
+                });
+
+            // message button, shows the unread count for this user
+            c.add_button("message_button")
+                .set_style(action_button_style)
+                .contents(|c| {
+                    c.add_text_with_id("message", "message_button_text")
+                        .set_style(action_button_text_style);
+                });
         });
 
     (ui_name.to_string(), ui_asset_id, ui_etag, ui_config)
@@ -2,16 +2,18 @@ use std::collections::HashMap;
 
 use asset_serde::{
     bits::{
-        AnimAction, AnimatedModelBits, ComponentFileType, IconAction, IconFrameAction, MeshAction,
-        ModelAction, MovementConfigBits, PaletteAction, SceneAction, SerdeQuat, SerdeRotation,
-        SkelAction, SkinAction, Transition, UnitBits,
+        AnimAction, AnimatedModelBits, ComponentFileType, Easing, IconAction, IconFrameAction,
+        MeshAction, ModelAction, MovementConfigBits, PaletteAction, ParticleEffectBits,
+        SceneAction, SerdeQuat, SerdeRotation, SkelAction, SkinAction, Transition, UnitBits,
     },
     json::{
-        AnimatedModelJson, AnimationJson, FileComponentType, IconJson, MeshJson, ModelJson,
-        MovementConfigJson, PaletteJson, SceneJson, SkeletonJson, SkinJson, UiConfigJson, UnitJson,
+        AnimFileEasing, AnimatedModelJson, AnimationJson, FileComponentType, IconJson, MeshJson,
+        ModelJson, MovementConfigJson, PaletteJson, ParticleEffectJson, SceneJson, SkeletonJson,
+        SkinJson, TextureJson, UiConfigJson, UnitJson,
     },
 };
-use spec::{AnimatedModel, MovementConfig, Unit};
+use render_api::base::CpuTexture2D;
+use spec::{AnimatedModel, MovementConfig, ParticleEffect, Unit};
 
 pub(crate) fn palette(data: PaletteJson) -> Vec<u8> {
     let mut actions = Vec::new();
@@ -99,7 +101,18 @@ pub(crate) fn animation(data: AnimationJson) -> Vec<u8> {
             );
         }
         let transition_ms = frame.get_transition_ms();
-        actions.push(AnimAction::Frame(rotations, Transition::new(transition_ms)));
+        let easing = match frame.get_easing() {
+            AnimFileEasing::Linear => Easing::Linear,
+            AnimFileEasing::EaseIn => Easing::EaseIn,
+            AnimFileEasing::EaseOut => Easing::EaseOut,
+            AnimFileEasing::EaseInOut => Easing::EaseInOut,
+            AnimFileEasing::Step => Easing::Step,
+        };
+        actions.push(AnimAction::Frame(
+            rotations,
+            Transition::new(transition_ms, easing),
+            frame.get_events().clone(),
+        ));
     }
 
     AnimAction::write(actions).to_vec()
@@ -213,12 +226,18 @@ pub(crate) fn ui(data: UiConfigJson) -> Vec<u8> {
 
 pub(crate) fn animated_model(data: AnimatedModelJson) -> Vec<u8> {
     let base: AnimatedModel = data.into();
+    if let Err(error) = base.validate() {
+        panic!("invalid animated model: {}", error);
+    }
     let bits: AnimatedModelBits = (&base).into();
     let bytes: Vec<u8> = bits.into();
     bytes
 }
 
-pub(crate) fn movement_config(data: MovementConfigJson) -> Vec<u8> {
+pub(crate) fn movement_config(data: MovementConfigJson, schema_version: u32) -> Vec<u8> {
+    if let Err(error) = MovementConfigJson::check_schema_version(schema_version) {
+        panic!("invalid movement config: {}", error);
+    }
     let base: MovementConfig = data.into();
     let bits: MovementConfigBits = (&base).into();
     let bytes = bits.into();
@@ -231,3 +250,19 @@ pub(crate) fn unit(data: UnitJson) -> Vec<u8> {
     let bytes = bits.into();
     bytes
 }
+
+pub(crate) fn particle_effect(data: ParticleEffectJson) -> Vec<u8> {
+    let base: ParticleEffect = data.into();
+    let bits: ParticleEffectBits = (&base).into();
+    let bytes = bits.into();
+    bytes
+}
+
+// textures are shipped as the png itself, decoded here only to catch broken files before the client
+pub(crate) fn texture(data: TextureJson) -> Vec<u8> {
+    let png_bytes = data.get_png_bytes().expect("cannot process texture");
+    if let Err(err) = CpuTexture2D::from_png(&png_bytes) {
+        panic!("cannot process texture, invalid png: {}", err);
+    }
+    png_bytes
+}
@@ -4,7 +4,7 @@ use std::{fs, path::Path};
 
 use asset_id::{AssetId, AssetType, ETag};
 use asset_serde::{
-    bits::AssetMetadataSerde,
+    bits::{AnimAction, AssetMetadataSerde, MovementConfigBits, UI_FORMAT_VERSION},
     json::{Asset, AssetData, AssetMeta, ProcessedAssetMeta},
 };
 use git::{
@@ -353,6 +353,7 @@ fn write_all_new_files(repo: &Repository, unprocessed_files: &Vec<UnprocessedFil
             );
         }
         let dependencies = get_dependencies(&unprocessed_asset_data);
+        let format_version = get_format_version(&unprocessed_asset_data);
 
         // convert asset data to bits
         let processed_asset_bytes = match unprocessed_asset_data {
@@ -366,8 +367,12 @@ fn write_all_new_files(repo: &Repository, unprocessed_files: &Vec<UnprocessedFil
             AssetData::Icon(data) => convert_to_bits::icon(data),
             AssetData::Ui(data) => convert_to_bits::ui(data),
             AssetData::AnimatedModel(data) => convert_to_bits::animated_model(data),
-            AssetData::MovementConfig(data) => convert_to_bits::movement_config(data),
+            AssetData::MovementConfig(data) => {
+                convert_to_bits::movement_config(data, unprocessed_asset_meta.schema_version())
+            }
             AssetData::Unit(data) => convert_to_bits::unit(data),
+            AssetData::ParticleEffect(data) => convert_to_bits::particle_effect(data),
+            AssetData::Texture(data) => convert_to_bits::texture(data),
         };
 
         // write new data file
@@ -383,7 +388,8 @@ fn write_all_new_files(repo: &Repository, unprocessed_files: &Vec<UnprocessedFil
         // process Asset Meta
         let meta_file_path = format!("{}.meta", file_path);
         let meta_full_path = format!("{}.meta", full_path);
-        let processed_meta = process_new_meta_file(&unprocessed_asset_meta, dependencies, hash);
+        let processed_meta =
+            process_new_meta_file(&unprocessed_asset_meta, dependencies, format_version, hash);
         let meta_bytes = processed_meta.write();
 
         // write new meta file
@@ -420,18 +426,32 @@ fn get_dependencies(data: &AssetData) -> Vec<AssetId> {
         AssetData::AnimatedModel(data) => data.dependencies(),
         AssetData::MovementConfig(data) => data.dependencies(),
         AssetData::Unit(data) => data.dependencies(),
+        AssetData::ParticleEffect(data) => data.dependencies(),
+        AssetData::Texture(data) => data.dependencies(),
+    }
+}
+
+// the layout version of each type's processed bits, 0 for those which aren't versioned
+fn get_format_version(data: &AssetData) -> u8 {
+    match data {
+        AssetData::Animation(_) => AnimAction::FORMAT_VERSION,
+        AssetData::MovementConfig(_) => MovementConfigBits::FORMAT_VERSION,
+        AssetData::Ui(_) => UI_FORMAT_VERSION,
+        _ => 0,
     }
 }
 
 fn process_new_meta_file(
     unprocessed_meta: &AssetMeta,
     dependencies: Vec<AssetId>,
+    format_version: u8,
     hash: AssetHash,
 ) -> ProcessedAssetMeta {
     ProcessedAssetMeta::new(
         unprocessed_meta.asset_id(),
         ETag::gen_random(),
         unprocessed_meta.schema_version(),
+        format_version,
         dependencies,
         hash.to_vec(),
     )
@@ -470,11 +490,14 @@ fn prune_unchanged_files(
             .find(|meta| meta.asset_id() == unprocessed_asset.meta().asset_id());
 
         if let Some(meta) = prev_meta {
-            if unprocessed_hash == meta.hash() {
+            if unprocessed_hash != meta.hash() {
+                info!("file changed: {}", prev_path);
+            } else if meta.format_version() != get_format_version(unprocessed_asset.data()) {
+                // the json is the same, but the bits it was processed into are of an old layout
+                info!("file format changed: {}", prev_path);
+            } else {
                 info!("file unchanged: {}", prev_path);
                 continue;
-            } else {
-                info!("file changed: {}", prev_path);
             }
         } else {
             info!("file new: {}", prev_path);
@@ -34,6 +34,13 @@ pub const CONTENT_SERVER_FILES_PATH: &str = "./files";
 #[allow(dead_code)]
 pub const ASSET_SERVER_FILES_PATH: &str = "./assets";
 
+#[allow(dead_code)]
+pub const WORLD_SERVER_RECORDINGS_PATH: &str = "./recordings";
+
+// "off" leaves each protocol's own link conditioning in place, see `LinkConditions`
+#[allow(dead_code)]
+pub const LINK_CONDITIONER: &str = "off";
+
 #[allow(dead_code)]
 pub const REDIRECTOR_PORT: u16 = 14195;
 
@@ -74,19 +81,23 @@ pub const AUTH_SERVER_PORT: u16 = 14206;
 pub const SOCIAL_SERVER_PORT: u16 = 14207;
 
 #[allow(dead_code)]
-pub const REGION_SERVER_SECRET: &str = "ArQZmRSf4xvbLVusVjrqGhIaZOExAeIq";
+pub const REGION_SERVER_SECRET: Option<&str> = Some("ArQZmRSf4xvbLVusVjrqGhIaZOExAeIq");
+
+#[allow(dead_code)]
+pub const SESSION_SERVER_GLOBAL_SECRET: Option<&str> = Some("zUe6K0RKY03JJMPo3u5SaByfiut0alOW");
 
 #[allow(dead_code)]
-pub const SESSION_SERVER_GLOBAL_SECRET: &str = "zUe6K0RKY03JJMPo3u5SaByfiut0alOW";
+pub const WORLD_SERVER_GLOBAL_SECRET: Option<&str> = Some("VKHusVjrGh035aSlQ7236bvVxlQ70alOW");
 
 #[allow(dead_code)]
-pub const WORLD_SERVER_GLOBAL_SECRET: &str = "VKHusVjrGh035aSlQ7236bvVxlQ70alOW";
+pub const ASSET_SERVER_GLOBAL_SECRET: Option<&str> = Some("QvsVjrGh035V70aVKHuaSbxlllQ7236OW");
 
 #[allow(dead_code)]
-pub const ASSET_SERVER_GLOBAL_SECRET: &str = "QvsVjrGh035V70aVKHuaSbxlllQ7236OW";
+pub const SOCIAL_SERVER_GLOBAL_SECRET: Option<&str> = Some("sVjrSbaVKHuaSbxlGh03QvsVjrSbxl");
 
+// held by admin tooling, rather than by any server
 #[allow(dead_code)]
-pub const SOCIAL_SERVER_GLOBAL_SECRET: &str = "sVjrSbaVKHuaSbxlGh03QvsVjrSbxl";
+pub const SOCIAL_SERVER_ADMIN_SECRET: Option<&str> = Some("Xq7bWfLkR2tNmZp9CdVhYs4GjE6aUo");
 
 // cpu priorities
 pub use crate::from::cpu_priority::{
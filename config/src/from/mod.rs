@@ -1,4 +1,5 @@
-mod cpu_priority;
+pub(crate) mod cpu_priority;
+pub(crate) mod tuning;
 
 cfg_if! {
     if #[cfg(feature = "local")] {
@@ -34,6 +34,13 @@ pub const CONTENT_SERVER_FILES_PATH: &str = "/usr/local/bin/files";
 #[allow(dead_code)]
 pub const ASSET_SERVER_FILES_PATH: &str = "/usr/local/bin/assets";
 
+#[allow(dead_code)]
+pub const WORLD_SERVER_RECORDINGS_PATH: &str = "/usr/local/bin/recordings";
+
+// "off" leaves each protocol's own link conditioning in place, see `LinkConditions`
+#[allow(dead_code)]
+pub const LINK_CONDITIONER: &str = "off";
+
 #[allow(dead_code)]
 pub const REDIRECTOR_PORT: u16 = 80;
 
@@ -73,20 +80,27 @@ pub const AUTH_SERVER_PORT: u16 = 14206;
 #[allow(dead_code)]
 pub const SOCIAL_SERVER_PORT: u16 = 14207;
 
+// secrets are never compiled in for prod, each must be given at runtime (see config.example.toml)
+// or the servers refuse to start
+
+#[allow(dead_code)]
+pub const REGION_SERVER_SECRET: Option<&str> = None;
+
 #[allow(dead_code)]
-pub const REGION_SERVER_SECRET: &str = "ArQZmRSf4xvbLVusVjrqGhIaZOExAeIq";
+pub const SESSION_SERVER_GLOBAL_SECRET: Option<&str> = None;
 
 #[allow(dead_code)]
-pub const SESSION_SERVER_GLOBAL_SECRET: &str = "zUe6K0RKY03JJMPo3u5SaByfiut0alOW";
+pub const WORLD_SERVER_GLOBAL_SECRET: Option<&str> = None;
 
 #[allow(dead_code)]
-pub const WORLD_SERVER_GLOBAL_SECRET: &str = "VKHusVjrGh035aSlQ7236bvVxlQ70alOW";
+pub const ASSET_SERVER_GLOBAL_SECRET: Option<&str> = None;
 
 #[allow(dead_code)]
-pub const ASSET_SERVER_GLOBAL_SECRET: &str = "QvsVjrGh035V70aVKHuaSbxlllQ7236OW";
+pub const SOCIAL_SERVER_GLOBAL_SECRET: Option<&str> = None;
 
+// held by admin tooling, rather than by any server
 #[allow(dead_code)]
-pub const SOCIAL_SERVER_GLOBAL_SECRET: &str = "sVjrSbaVKHuaSbxlGh03QvsVjrSbxl";
+pub const SOCIAL_SERVER_ADMIN_SECRET: Option<&str> = None;
 
 // cpu priorities
 pub use crate::from::cpu_priority::{
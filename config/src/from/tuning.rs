@@ -0,0 +1,25 @@
+// gameplay & moderation settings, the same for every environment
+
+// how long a disconnected user's session & world state is kept for them to reconnect to
+#[allow(dead_code)]
+pub const RECONNECT_GRACE_PERIOD_SECS: u32 = 30;
+
+// how long a match runs before it is finished
+#[allow(dead_code)]
+pub const MATCH_DURATION_SECS: u32 = 60 * 10;
+
+// radius, in tiles, at which a unit comes into a user's view
+#[allow(dead_code)]
+pub const INTEREST_ENTER_RADIUS: u32 = 8;
+
+// extra tiles a unit may drift past the enter radius before leaving the view
+#[allow(dead_code)]
+pub const INTEREST_EXIT_HYSTERESIS: u32 = 2;
+
+// width, in tiles, of the chunks whose units are always in view of each other
+#[allow(dead_code)]
+pub const INTEREST_CHUNK_SIZE: u32 = 8;
+
+// words masked out of chat messages, matched case-insensitively
+#[allow(dead_code)]
+pub const CHAT_WORD_FILTER: &[&str] = &[];
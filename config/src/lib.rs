@@ -6,6 +6,16 @@ mod from;
 mod to;
 pub use to::*;
 
+mod link_conditions;
+pub use link_conditions::LinkConditions;
+
+cfg_if! {
+    if #[cfg(feature = "runtime")] {
+        mod runtime;
+        pub use runtime::{init_runtime_config, RequiredConfig, RuntimeConfigError};
+    }
+}
+
 cfg_if! {
     if #[cfg(any(feature = "client", feature = "gateway", feature = "auth", feature = "world"))] {
         mod target_env;
@@ -0,0 +1,163 @@
+use std::fmt::{Display, Formatter};
+
+// Simulated network conditions, applied by naia to every packet a transport receives.
+// Written either as a preset name (`good`, `average`, `poor`) or as
+// `latency_ms[,jitter_ms[,loss]]`, e.g. `120,20,0.05` for 120ms +/- 20ms with 5% loss.
+// `off` means no conditioning beyond what the protocol itself sets up.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct LinkConditions {
+    pub latency_ms: u32,
+    pub jitter_ms: u32,
+    // fraction of packets dropped, between 0.0 and 1.0
+    pub loss: f32,
+}
+
+impl LinkConditions {
+    pub const GOOD: Self = Self::new(40, 6, 0.002);
+    pub const AVERAGE: Self = Self::new(100, 10, 0.02);
+    pub const POOR: Self = Self::new(200, 20, 0.1);
+
+    pub const fn new(latency_ms: u32, jitter_ms: u32, loss: f32) -> Self {
+        Self {
+            latency_ms,
+            jitter_ms,
+            loss,
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Option<Self>, String> {
+        let value = value.trim();
+        match value.to_lowercase().as_str() {
+            "" | "off" | "none" => return Ok(None),
+            "good" => return Ok(Some(Self::GOOD)),
+            "average" => return Ok(Some(Self::AVERAGE)),
+            "poor" => return Ok(Some(Self::POOR)),
+            _ => {}
+        }
+
+        let parts: Vec<&str> = value.split(',').map(str::trim).collect();
+        if parts.len() > 3 {
+            return Err(format!(
+                "expected `latency_ms[,jitter_ms[,loss]]` or a preset, got `{}`",
+                value
+            ));
+        }
+        let latency_ms = parse_part::<u32>(parts[0], "latency_ms")?;
+        let jitter_ms = match parts.get(1) {
+            Some(part) => parse_part::<u32>(part, "jitter_ms")?,
+            None => 0,
+        };
+        let loss = match parts.get(2) {
+            Some(part) => parse_part::<f32>(part, "loss")?,
+            None => 0.0,
+        };
+        if !(0.0..=1.0).contains(&loss) {
+            return Err(format!("loss must be between 0.0 and 1.0, got {}", loss));
+        }
+        if jitter_ms > latency_ms {
+            return Err(format!(
+                "jitter_ms ({}) must not be larger than latency_ms ({})",
+                jitter_ms, latency_ms
+            ));
+        }
+
+        Ok(Some(Self::new(latency_ms, jitter_ms, loss)))
+    }
+}
+
+impl Display for LinkConditions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}ms +/- {}ms, {}% loss",
+            self.latency_ms,
+            self.jitter_ms,
+            self.loss * 100.0
+        )
+    }
+}
+
+fn parse_part<T: std::str::FromStr>(part: &str, name: &str) -> Result<T, String> {
+    part.parse::<T>()
+        .map_err(|_| format!("invalid {} in link conditions: `{}`", name, part))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinkConditions;
+
+    #[test]
+    fn presets() {
+        assert_eq!(
+            LinkConditions::parse("good"),
+            Ok(Some(LinkConditions::GOOD))
+        );
+        assert_eq!(
+            LinkConditions::parse(" Average "),
+            Ok(Some(LinkConditions::AVERAGE))
+        );
+        assert_eq!(
+            LinkConditions::parse("POOR"),
+            Ok(Some(LinkConditions::POOR))
+        );
+        for off in ["", "off", "none", "  OFF "] {
+            assert_eq!(LinkConditions::parse(off), Ok(None), "`{}`", off);
+        }
+    }
+
+    #[test]
+    fn latency_jitter_and_loss() {
+        assert_eq!(
+            LinkConditions::parse("120"),
+            Ok(Some(LinkConditions::new(120, 0, 0.0)))
+        );
+        assert_eq!(
+            LinkConditions::parse("120,20"),
+            Ok(Some(LinkConditions::new(120, 20, 0.0)))
+        );
+        assert_eq!(
+            LinkConditions::parse("120, 20, 0.05"),
+            Ok(Some(LinkConditions::new(120, 20, 0.05)))
+        );
+        // jitter may go as high as the latency, and loss covers the whole range
+        assert_eq!(
+            LinkConditions::parse("50,50,1"),
+            Ok(Some(LinkConditions::new(50, 50, 1.0)))
+        );
+        assert_eq!(
+            LinkConditions::parse("0,0,0"),
+            Ok(Some(LinkConditions::new(0, 0, 0.0)))
+        );
+    }
+
+    #[test]
+    fn loss_is_shown_as_a_percentage() {
+        let conditions = LinkConditions::new(120, 20, 0.05);
+        assert_eq!(conditions.to_string(), "120ms +/- 20ms, 5% loss");
+    }
+
+    #[test]
+    fn malformed_input_is_rejected() {
+        for value in [
+            "fast",
+            "120ms",
+            "-5",
+            "120,abc",
+            "120,20,lots",
+            "120,20,0.05,1",
+            ",20",
+            "120,,0.1",
+            // loss is a fraction, not a percentage
+            "120,20,5",
+            "120,20,-0.1",
+            // jitter larger than the latency
+            "20,40",
+        ] {
+            assert!(
+                LinkConditions::parse(value).is_err(),
+                "`{}` should be rejected",
+                value
+            );
+        }
+    }
+}
@@ -0,0 +1,643 @@
+use std::{
+    collections::HashMap,
+    env,
+    fmt::{Display, Formatter},
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::OnceLock,
+};
+
+use serde::Deserialize;
+
+use crate::{
+    from::{self, cpu_priority, tuning},
+    LinkConditions,
+};
+
+// points at the config file, otherwise `config.toml` in the working directory is used if present
+const CONFIG_PATH_ENV_VAR: &str = "CYBERLITH_CONFIG";
+const DEFAULT_CONFIG_PATH: &str = "config.toml";
+// every value can be overridden by an env var of its upper-cased name with this prefix,
+// and every secret can also be read from the file named by the same var suffixed with `_FILE`
+const ENV_VAR_PREFIX: &str = "CYBERLITH_";
+
+const MIN_SECRET_LEN: usize = 16;
+
+static RUNTIME_CONFIG: OnceLock<RuntimeConfig> = OnceLock::new();
+
+#[derive(Debug)]
+pub enum RuntimeConfigError {
+    Read(PathBuf, String),
+    Parse(PathBuf, String),
+    EnvVar(String, String),
+    Invalid(String),
+}
+
+impl Display for RuntimeConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Read(path, err) => write!(f, "failed to read {:?}: {}", path, err),
+            Self::Parse(path, err) => write!(f, "failed to parse {:?}: {}", path, err),
+            Self::EnvVar(name, err) => write!(f, "invalid env var {}: {}", name, err),
+            Self::Invalid(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+// the settings one server can't run without, by name. a server is only handed its own secrets,
+// so only these are required to be set
+pub struct RequiredConfig {
+    pub secrets: &'static [&'static str],
+    // the ports it listens on, which can't be shared with one another
+    pub listen_ports: &'static [&'static str],
+}
+
+// loads & validates the runtime config, panicking with the reason if it is invalid.
+// servers call this first thing, so a bad config fails at startup rather than on first use.
+// a process running several servers passes each of their requirements
+pub fn init_runtime_config(required: &[&RequiredConfig]) {
+    RUNTIME_CONFIG.get_or_init(|| load_or_panic(required));
+}
+
+// if the servers haven't initialized the config yet, nothing is required up front and a missing
+// secret panics when it is read
+fn get() -> &'static RuntimeConfig {
+    RUNTIME_CONFIG.get_or_init(|| load_or_panic(&[]))
+}
+
+fn load_or_panic(required: &[&RequiredConfig]) -> RuntimeConfig {
+    match RuntimeConfig::load(required) {
+        Ok(config) => config,
+        Err(err) => panic!("invalid runtime config: {}", err),
+    }
+}
+
+macro_rules! runtime_config {
+    (
+        strings { $($s_name:ident = $s_default:expr,)* }
+        secrets { $($k_name:ident = $k_default:expr,)* }
+        ports { $($p_name:ident = $p_default:expr,)* }
+        priorities { $($c_name:ident = $c_default:expr,)* }
+        numbers { $($n_name:ident = $n_default:expr,)* }
+        lists { $($l_name:ident = $l_default:expr,)* }
+    ) => {
+        struct RuntimeConfig {
+            $($s_name: String,)*
+            // None until given, when there is no compiled-in default
+            $($k_name: Option<String>,)*
+            $($p_name: u16,)*
+            $($c_name: usize,)*
+            $($n_name: u32,)*
+            $($l_name: Vec<String>,)*
+        }
+
+        impl Default for RuntimeConfig {
+            fn default() -> Self {
+                Self {
+                    $($s_name: $s_default.to_string(),)*
+                    $($k_name: $k_default.map(str::to_string),)*
+                    $($p_name: $p_default,)*
+                    $($c_name: $c_default,)*
+                    $($n_name: $n_default,)*
+                    $($l_name: $l_default.iter().map(|item| item.to_string()).collect(),)*
+                }
+            }
+        }
+
+        #[derive(Deserialize, Default)]
+        #[serde(default, deny_unknown_fields)]
+        struct ConfigFile {
+            $($s_name: Option<String>,)*
+            $($k_name: Option<String>,)*
+            $($p_name: Option<u16>,)*
+            $($c_name: Option<usize>,)*
+            $($n_name: Option<u32>,)*
+            $($l_name: Option<Vec<String>>,)*
+            secret_files: SecretFiles,
+        }
+
+        // paths to files holding each secret, so they needn't live in the config file itself
+        #[derive(Deserialize, Default)]
+        #[serde(default, deny_unknown_fields)]
+        struct SecretFiles {
+            $($k_name: Option<PathBuf>,)*
+        }
+
+        impl RuntimeConfig {
+            // precedence: compiled-in default < config file < secret file < env var < env secret file
+            fn apply_file(&mut self, file: ConfigFile) -> Result<(), RuntimeConfigError> {
+                $(if let Some(value) = file.$s_name { self.$s_name = value; })*
+                $(if let Some(value) = file.$k_name { self.$k_name = Some(value); })*
+                $(if let Some(value) = file.$p_name { self.$p_name = value; })*
+                $(if let Some(value) = file.$c_name { self.$c_name = value; })*
+                $(if let Some(value) = file.$n_name { self.$n_name = value; })*
+                $(if let Some(value) = file.$l_name { self.$l_name = value; })*
+                $(if let Some(path) = file.secret_files.$k_name { self.$k_name = Some(read_secret_file(&path)?); })*
+                Ok(())
+            }
+
+            fn apply_env(&mut self) -> Result<(), RuntimeConfigError> {
+                $(if let Some(value) = env_var(stringify!($s_name))? { self.$s_name = value; })*
+                $(if let Some(value) = env_var(stringify!($k_name))? { self.$k_name = Some(value); })*
+                $(if let Some(value) = env_var(stringify!($p_name))? { self.$p_name = value; })*
+                $(if let Some(value) = env_var(stringify!($c_name))? { self.$c_name = value; })*
+                $(if let Some(value) = env_var(stringify!($n_name))? { self.$n_name = value; })*
+                $(if let Some(value) = env_var::<String>(stringify!($l_name))? { self.$l_name = parse_env_list(&value); })*
+                $(if let Some(path) = env_var::<PathBuf>(&format!("{}_file", stringify!($k_name)))? {
+                    self.$k_name = Some(read_secret_file(&path)?);
+                })*
+                Ok(())
+            }
+
+            fn secret(&self, name: &str) -> Result<Option<&str>, RuntimeConfigError> {
+                match name {
+                    $(stringify!($k_name) => Ok(self.$k_name.as_deref()),)*
+                    _ => Err(RuntimeConfigError::Invalid(format!("{} is not a secret", name))),
+                }
+            }
+
+            fn port(&self, name: &str) -> Result<u16, RuntimeConfigError> {
+                match name {
+                    $(stringify!($p_name) => Ok(self.$p_name),)*
+                    _ => Err(RuntimeConfigError::Invalid(format!("{} is not a port", name))),
+                }
+            }
+
+            fn validate(&self, required: &[&RequiredConfig]) -> Result<(), RuntimeConfigError> {
+                $(validate_not_empty(stringify!($s_name), &self.$s_name)?;)*
+
+                // secrets that are given must be usable, even when this server doesn't need them
+                $(
+                    if let Some(value) = self.$k_name.as_deref() {
+                        validate_secret(stringify!($k_name), value)?;
+                    }
+                )*
+                for name in required.iter().flat_map(|required| required.secrets.iter()) {
+                    if self.secret(name)?.is_none() {
+                        return Err(RuntimeConfigError::Invalid(format!(
+                            "{} is not set, give it in a secret file or the {}{}_FILE env var",
+                            name,
+                            ENV_VAR_PREFIX,
+                            name.to_uppercase()
+                        )));
+                    }
+                }
+
+                $(
+                    if self.$p_name == 0 {
+                        return Err(RuntimeConfigError::Invalid(format!("{} must not be 0", stringify!($p_name))));
+                    }
+                )*
+                let mut ports: HashMap<u16, &str> = HashMap::new();
+                for name in required.iter().flat_map(|required| required.listen_ports.iter()) {
+                    let port = self.port(name)?;
+                    if let Some(other) = ports.insert(port, name) {
+                        if other != *name {
+                            return Err(RuntimeConfigError::Invalid(format!(
+                                "{} and {} both use port {}", other, name, port
+                            )));
+                        }
+                    }
+                }
+
+                $(
+                    if self.$c_name == 0 {
+                        return Err(RuntimeConfigError::Invalid(format!("{} must be at least 1", stringify!($c_name))));
+                    }
+                    if self.$c_name > self.total_cpu_priority {
+                        return Err(RuntimeConfigError::Invalid(format!(
+                            "{} is larger than total_cpu_priority", stringify!($c_name)
+                        )));
+                    }
+                )*
+
+                Ok(())
+            }
+        }
+
+        $(
+            #[allow(dead_code)]
+            pub fn $s_name() -> &'static str {
+                get().$s_name.as_str()
+            }
+        )*
+        $(
+            #[allow(dead_code)]
+            pub fn $k_name() -> &'static str {
+                get()
+                    .$k_name
+                    .as_deref()
+                    .unwrap_or_else(|| panic!("{} is not set", stringify!($k_name)))
+            }
+        )*
+        $(
+            #[allow(dead_code)]
+            pub fn $p_name() -> u16 {
+                get().$p_name
+            }
+        )*
+        $(
+            #[allow(dead_code)]
+            pub fn $c_name() -> usize {
+                get().$c_name
+            }
+        )*
+        $(
+            #[allow(dead_code)]
+            pub fn $n_name() -> u32 {
+                get().$n_name
+            }
+        )*
+        $(
+            #[allow(dead_code)]
+            pub fn $l_name() -> &'static [String] {
+                get().$l_name.as_slice()
+            }
+        )*
+    };
+}
+
+runtime_config! {
+    strings {
+        self_binding_addr = from::SELF_BINDING_ADDR,
+        public_protocol = from::PUBLIC_PROTOCOL,
+        public_ip_addr = from::PUBLIC_IP_ADDR,
+        content_server_recv_addr = from::CONTENT_SERVER_RECV_ADDR,
+        region_server_recv_addr = from::REGION_SERVER_RECV_ADDR,
+        session_server_recv_addr = from::SESSION_SERVER_RECV_ADDR,
+        world_server_recv_addr = from::WORLD_SERVER_RECV_ADDR,
+        asset_server_recv_addr = from::ASSET_SERVER_RECV_ADDR,
+        social_server_recv_addr = from::SOCIAL_SERVER_RECV_ADDR,
+        auth_server_recv_addr = from::AUTH_SERVER_RECV_ADDR,
+        content_server_files_path = from::CONTENT_SERVER_FILES_PATH,
+        asset_server_files_path = from::ASSET_SERVER_FILES_PATH,
+        world_server_recordings_path = from::WORLD_SERVER_RECORDINGS_PATH,
+        link_conditioner = from::LINK_CONDITIONER,
+    }
+    secrets {
+        region_server_secret = from::REGION_SERVER_SECRET,
+        session_server_global_secret = from::SESSION_SERVER_GLOBAL_SECRET,
+        world_server_global_secret = from::WORLD_SERVER_GLOBAL_SECRET,
+        asset_server_global_secret = from::ASSET_SERVER_GLOBAL_SECRET,
+        social_server_global_secret = from::SOCIAL_SERVER_GLOBAL_SECRET,
+        social_server_admin_secret = from::SOCIAL_SERVER_ADMIN_SECRET,
+    }
+    ports {
+        redirector_port = from::REDIRECTOR_PORT,
+        gateway_port = from::GATEWAY_PORT,
+        content_server_port = from::CONTENT_SERVER_PORT,
+        region_server_port = from::REGION_SERVER_PORT,
+        session_server_http_port = from::SESSION_SERVER_HTTP_PORT,
+        session_server_signal_port = from::SESSION_SERVER_SIGNAL_PORT,
+        session_server_webrtc_port = from::SESSION_SERVER_WEBRTC_PORT,
+        world_server_http_port = from::WORLD_SERVER_HTTP_PORT,
+        world_server_signal_port = from::WORLD_SERVER_SIGNAL_PORT,
+        world_server_webrtc_port = from::WORLD_SERVER_WEBRTC_PORT,
+        asset_server_port = from::ASSET_SERVER_PORT,
+        auth_server_port = from::AUTH_SERVER_PORT,
+        social_server_port = from::SOCIAL_SERVER_PORT,
+    }
+    priorities {
+        region_server_cpu_priority = cpu_priority::REGION_SERVER_CPU_PRIORITY,
+        auth_server_cpu_priority = cpu_priority::AUTH_SERVER_CPU_PRIORITY,
+        content_server_cpu_priority = cpu_priority::CONTENT_SERVER_CPU_PRIORITY,
+        gateway_server_cpu_priority = cpu_priority::GATEWAY_SERVER_CPU_PRIORITY,
+        redirector_server_cpu_priority = cpu_priority::REDIRECTOR_SERVER_CPU_PRIORITY,
+        asset_server_cpu_priority = cpu_priority::ASSET_SERVER_CPU_PRIORITY,
+        social_server_cpu_priority = cpu_priority::SOCIAL_SERVER_CPU_PRIORITY,
+        session_server_cpu_priority = cpu_priority::SESSION_SERVER_CPU_PRIORITY,
+        world_server_cpu_priority = cpu_priority::WORLD_SERVER_CPU_PRIORITY,
+        total_cpu_priority = from::TOTAL_CPU_PRIORITY,
+    }
+    numbers {
+        reconnect_grace_period_secs = tuning::RECONNECT_GRACE_PERIOD_SECS,
+        match_duration_secs = tuning::MATCH_DURATION_SECS,
+        interest_enter_radius = tuning::INTEREST_ENTER_RADIUS,
+        interest_exit_hysteresis = tuning::INTEREST_EXIT_HYSTERESIS,
+        interest_chunk_size = tuning::INTEREST_CHUNK_SIZE,
+    }
+    lists {
+        chat_word_filter = tuning::CHAT_WORD_FILTER,
+    }
+}
+
+impl RuntimeConfig {
+    fn load(required_config: &[&RequiredConfig]) -> Result<Self, RuntimeConfigError> {
+        let mut config = Self::default();
+
+        let (path, required) = match env::var(CONFIG_PATH_ENV_VAR) {
+            Ok(path) => (PathBuf::from(path), true),
+            Err(_) => (PathBuf::from(DEFAULT_CONFIG_PATH), false),
+        };
+        if required || path.exists() {
+            let contents = fs::read_to_string(&path)
+                .map_err(|err| RuntimeConfigError::Read(path.clone(), err.to_string()))?;
+            let file: ConfigFile = toml::from_str(&contents)
+                .map_err(|err| RuntimeConfigError::Parse(path.clone(), err.to_string()))?;
+            config.apply_file(file)?;
+        }
+
+        config.apply_env()?;
+        config.validate(required_config)?;
+        LinkConditions::parse(&config.link_conditioner).map_err(RuntimeConfigError::Invalid)?;
+        config.validate_interest()?;
+
+        Ok(config)
+    }
+
+    // the world server measures these in i16 tiles
+    fn validate_interest(&self) -> Result<(), RuntimeConfigError> {
+        for (name, value) in [
+            ("interest_enter_radius", self.interest_enter_radius),
+            ("interest_exit_hysteresis", self.interest_exit_hysteresis),
+            ("interest_chunk_size", self.interest_chunk_size),
+        ] {
+            if value > i16::MAX as u32 {
+                return Err(RuntimeConfigError::Invalid(format!(
+                    "{} must be at most {}",
+                    name,
+                    i16::MAX
+                )));
+            }
+        }
+        if self.interest_enter_radius + self.interest_exit_hysteresis > i16::MAX as u32 {
+            return Err(RuntimeConfigError::Invalid(format!(
+                "interest_enter_radius + interest_exit_hysteresis must be at most {}",
+                i16::MAX
+            )));
+        }
+        if self.interest_chunk_size == 0 {
+            return Err(RuntimeConfigError::Invalid(
+                "interest_chunk_size must be at least 1".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+// simulated latency, jitter & loss for this server's naia transport, for local testing
+#[allow(dead_code)]
+pub fn link_conditions() -> Option<LinkConditions> {
+    LinkConditions::parse(link_conditioner()).expect("link_conditioner is validated on load")
+}
+
+fn env_var<T: FromStr>(name: &str) -> Result<Option<T>, RuntimeConfigError>
+where
+    T::Err: Display,
+{
+    let env_var_name = format!("{}{}", ENV_VAR_PREFIX, name.to_uppercase());
+    let Ok(value) = env::var(&env_var_name) else {
+        return Ok(None);
+    };
+    value
+        .parse::<T>()
+        .map(Some)
+        .map_err(|err| RuntimeConfigError::EnvVar(env_var_name, err.to_string()))
+}
+
+// lists given in env vars are comma separated, e.g. CYBERLITH_CHAT_WORD_FILTER=foo,bar
+fn parse_env_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+// trailing newlines are trimmed, as most tools write secrets with one
+fn read_secret_file(path: &Path) -> Result<String, RuntimeConfigError> {
+    fs::read_to_string(path)
+        .map(|secret| secret.trim_end().to_string())
+        .map_err(|err| RuntimeConfigError::Read(path.to_path_buf(), err.to_string()))
+}
+
+fn validate_not_empty(name: &str, value: &str) -> Result<(), RuntimeConfigError> {
+    if value.trim().is_empty() {
+        return Err(RuntimeConfigError::Invalid(format!(
+            "{} must not be empty",
+            name
+        )));
+    }
+    Ok(())
+}
+
+fn validate_secret(name: &str, value: &str) -> Result<(), RuntimeConfigError> {
+    if value.len() < MIN_SECRET_LEN {
+        return Err(RuntimeConfigError::Invalid(format!(
+            "{} must be at least {} characters",
+            name, MIN_SECRET_LEN
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::PathBuf};
+
+    use super::{parse_env_list, ConfigFile, RequiredConfig, RuntimeConfig, MIN_SECRET_LEN};
+
+    fn secret(name: &str) -> String {
+        format!("{}_{}", name, "x".repeat(MIN_SECRET_LEN))
+    }
+
+    fn write_secret_file(name: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("config_test_{}_{}", std::process::id(), name));
+        // secret files usually end in a newline, which isn't part of the secret
+        fs::write(&path, format!("{}\n", secret(name))).unwrap();
+        path
+    }
+
+    fn config_file(toml: &str) -> ConfigFile {
+        toml::from_str(toml).unwrap()
+    }
+
+    #[test]
+    fn later_sources_take_precedence() {
+        let default_port = RuntimeConfig::default().social_server_port;
+        let file_secret_path = write_secret_file("secret_file");
+        let env_secret_path = write_secret_file("env_file");
+
+        let mut config = RuntimeConfig::default();
+        config
+            .apply_file(config_file(&format!(
+                "social_server_port = {}\n\
+                 region_server_secret = \"{}\"\n\
+                 session_server_global_secret = \"{}\"\n\
+                 world_server_global_secret = \"{}\"\n\
+                 asset_server_global_secret = \"{}\"\n\
+                 [secret_files]\n\
+                 session_server_global_secret = {:?}\n\
+                 world_server_global_secret = {:?}\n\
+                 asset_server_global_secret = {:?}\n",
+                default_port + 1,
+                secret("file"),
+                secret("file"),
+                secret("file"),
+                secret("file"),
+                file_secret_path,
+                file_secret_path,
+                file_secret_path,
+            )))
+            .unwrap();
+
+        // only this test touches the env
+        env::set_var("CYBERLITH_WORLD_SERVER_GLOBAL_SECRET", secret("env"));
+        env::set_var("CYBERLITH_ASSET_SERVER_GLOBAL_SECRET", secret("env"));
+        env::set_var(
+            "CYBERLITH_ASSET_SERVER_GLOBAL_SECRET_FILE",
+            &env_secret_path,
+        );
+        let result = config.apply_env();
+        env::remove_var("CYBERLITH_WORLD_SERVER_GLOBAL_SECRET");
+        env::remove_var("CYBERLITH_ASSET_SERVER_GLOBAL_SECRET");
+        env::remove_var("CYBERLITH_ASSET_SERVER_GLOBAL_SECRET_FILE");
+        let _ = fs::remove_file(&file_secret_path);
+        let _ = fs::remove_file(&env_secret_path);
+        result.unwrap();
+
+        // default < file
+        assert_eq!(config.social_server_port, default_port + 1);
+        assert_eq!(config.region_server_secret, Some(secret("file")));
+        // file < secret file
+        assert_eq!(
+            config.session_server_global_secret,
+            Some(secret("secret_file"))
+        );
+        // secret file < env
+        assert_eq!(config.world_server_global_secret, Some(secret("env")));
+        // env < env file
+        assert_eq!(config.asset_server_global_secret, Some(secret("env_file")));
+    }
+
+    #[test]
+    fn reads_lists() {
+        let mut config = RuntimeConfig::default();
+        config
+            .apply_file(config_file("chat_word_filter = [\"foo\", \"bar\"]"))
+            .unwrap();
+        assert_eq!(config.chat_word_filter, vec!["foo", "bar"]);
+
+        assert_eq!(parse_env_list(" foo, bar ,,"), vec!["foo", "bar"]);
+    }
+
+    #[test]
+    fn unknown_keys_are_rejected() {
+        assert!(toml::from_str::<ConfigFile>("not_a_setting = 1").is_err());
+        assert!(
+            toml::from_str::<ConfigFile>("[secret_files]\nsocial_server_port = \"/a\"").is_err()
+        );
+    }
+
+    fn valid_config() -> RuntimeConfig {
+        RuntimeConfig {
+            region_server_secret: Some(secret("region")),
+            session_server_global_secret: Some(secret("session")),
+            world_server_global_secret: Some(secret("world")),
+            asset_server_global_secret: Some(secret("asset")),
+            social_server_global_secret: Some(secret("social")),
+            social_server_admin_secret: Some(secret("admin")),
+            ..Default::default()
+        }
+    }
+
+    const SOCIAL: RequiredConfig = RequiredConfig {
+        secrets: &[
+            "region_server_secret",
+            "social_server_global_secret",
+            "social_server_admin_secret",
+        ],
+        listen_ports: &["social_server_port"],
+    };
+    const REGION: RequiredConfig = RequiredConfig {
+        secrets: &["region_server_secret"],
+        listen_ports: &["region_server_port"],
+    };
+
+    #[test]
+    fn validates_secrets() {
+        assert!(valid_config().validate(&[&SOCIAL]).is_ok());
+
+        let mut config = valid_config();
+        config.social_server_global_secret = None;
+        assert!(config.validate(&[&SOCIAL]).is_err());
+
+        let mut config = valid_config();
+        config.social_server_global_secret = Some("short".to_string());
+        assert!(config.validate(&[&SOCIAL]).is_err());
+        // given secrets are checked even if unused
+        assert!(config.validate(&[&REGION]).is_err());
+    }
+
+    #[test]
+    fn only_required_secrets_must_be_set() {
+        // `local` builds have compiled-in secrets, so every other one is cleared
+        let config = RuntimeConfig {
+            region_server_secret: Some(secret("region")),
+            session_server_global_secret: None,
+            world_server_global_secret: None,
+            asset_server_global_secret: None,
+            social_server_global_secret: None,
+            social_server_admin_secret: None,
+            ..Default::default()
+        };
+        assert!(config.validate(&[&REGION]).is_ok());
+        assert!(config.validate(&[&REGION, &SOCIAL]).is_err());
+        assert!(config.validate(&[]).is_ok());
+    }
+
+    #[test]
+    fn unknown_requirements_are_rejected() {
+        let typo = RequiredConfig {
+            secrets: &["region_secret"],
+            listen_ports: &[],
+        };
+        assert!(valid_config().validate(&[&typo]).is_err());
+
+        let typo = RequiredConfig {
+            secrets: &[],
+            listen_ports: &["region_port"],
+        };
+        assert!(valid_config().validate(&[&typo]).is_err());
+    }
+
+    #[test]
+    fn validates_strings_ports_and_priorities() {
+        let mut config = valid_config();
+        config.public_ip_addr = " ".to_string();
+        assert!(config.validate(&[&SOCIAL]).is_err());
+
+        let mut config = valid_config();
+        config.social_server_port = 0;
+        assert!(config.validate(&[&SOCIAL]).is_err());
+
+        // servers on other hosts may share a port, servers in one process may not
+        let mut config = valid_config();
+        config.social_server_port = config.region_server_port;
+        assert!(config.validate(&[&SOCIAL]).is_ok());
+        assert!(config.validate(&[&SOCIAL, &REGION]).is_err());
+
+        let mut config = valid_config();
+        config.social_server_cpu_priority = 0;
+        assert!(config.validate(&[&SOCIAL]).is_err());
+
+        let mut config = valid_config();
+        config.social_server_cpu_priority = config.total_cpu_priority + 1;
+        assert!(config.validate(&[&SOCIAL]).is_err());
+    }
+
+    #[test]
+    fn validates_interest() {
+        assert!(valid_config().validate_interest().is_ok());
+
+        let mut config = valid_config();
+        config.interest_chunk_size = 0;
+        assert!(config.validate_interest().is_err());
+
+        let mut config = valid_config();
+        config.interest_enter_radius = i16::MAX as u32;
+        assert!(config.validate_interest().is_err());
+    }
+}
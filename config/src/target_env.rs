@@ -1,5 +1,3 @@
-use crate::{GATEWAY_PORT, PUBLIC_IP_ADDR, PUBLIC_PROTOCOL};
-
 pub enum TargetEnv {
     #[allow(dead_code)]
     Local,
@@ -33,6 +31,16 @@ impl TargetEnv {
     }
 
     pub fn gateway_url() -> String {
-        format!("{}://{}:{}", PUBLIC_PROTOCOL, PUBLIC_IP_ADDR, GATEWAY_PORT)
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "runtime")] {
+                use crate::runtime::{gateway_port, public_ip_addr, public_protocol};
+
+                format!("{}://{}:{}", public_protocol(), public_ip_addr(), gateway_port())
+            } else {
+                use crate::from::{GATEWAY_PORT, PUBLIC_IP_ADDR, PUBLIC_PROTOCOL};
+
+                format!("{}://{}:{}", PUBLIC_PROTOCOL, PUBLIC_IP_ADDR, GATEWAY_PORT)
+            }
+        }
     }
 }
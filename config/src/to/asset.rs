@@ -1,16 +1,21 @@
-use crate::from;
+use crate::runtime;
 
-pub use from::PUBLIC_IP_ADDR;
-pub use from::SELF_BINDING_ADDR;
+pub use runtime::public_ip_addr;
+pub use runtime::self_binding_addr;
 
-pub use from::REGION_SERVER_PORT;
-pub use from::REGION_SERVER_RECV_ADDR;
-pub use from::REGION_SERVER_SECRET;
+pub use runtime::region_server_port;
+pub use runtime::region_server_recv_addr;
+pub use runtime::region_server_secret;
 
-pub use from::ASSET_SERVER_FILES_PATH;
-pub use from::ASSET_SERVER_GLOBAL_SECRET;
-pub use from::ASSET_SERVER_PORT;
-pub use from::ASSET_SERVER_RECV_ADDR;
+pub use runtime::asset_server_files_path;
+pub use runtime::asset_server_global_secret;
+pub use runtime::asset_server_port;
+pub use runtime::asset_server_recv_addr;
 
-pub use from::ASSET_SERVER_CPU_PRIORITY;
-pub use from::TOTAL_CPU_PRIORITY;
+pub use runtime::asset_server_cpu_priority;
+pub use runtime::total_cpu_priority;
+
+pub const ASSET_SERVER_CONFIG: runtime::RequiredConfig = runtime::RequiredConfig {
+    secrets: &["region_server_secret", "asset_server_global_secret"],
+    listen_ports: &["asset_server_port"],
+};
@@ -1,11 +1,16 @@
-use crate::from;
+use crate::runtime;
 
-pub use from::PUBLIC_IP_ADDR;
-pub use from::PUBLIC_PROTOCOL;
-pub use from::SELF_BINDING_ADDR;
+pub use runtime::public_ip_addr;
+pub use runtime::public_protocol;
+pub use runtime::self_binding_addr;
 
-pub use from::AUTH_SERVER_PORT;
-pub use from::GATEWAY_PORT;
+pub use runtime::auth_server_port;
+pub use runtime::gateway_port;
 
-pub use from::AUTH_SERVER_CPU_PRIORITY;
-pub use from::TOTAL_CPU_PRIORITY;
+pub use runtime::auth_server_cpu_priority;
+pub use runtime::total_cpu_priority;
+
+pub const AUTH_SERVER_CONFIG: runtime::RequiredConfig = runtime::RequiredConfig {
+    secrets: &[],
+    listen_ports: &["auth_server_port"],
+};
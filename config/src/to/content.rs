@@ -1,9 +1,14 @@
-use crate::from;
+use crate::runtime;
 
-pub use from::SELF_BINDING_ADDR;
+pub use runtime::self_binding_addr;
 
-pub use from::CONTENT_SERVER_FILES_PATH;
-pub use from::CONTENT_SERVER_PORT;
+pub use runtime::content_server_files_path;
+pub use runtime::content_server_port;
 
-pub use from::CONTENT_SERVER_CPU_PRIORITY;
-pub use from::TOTAL_CPU_PRIORITY;
+pub use runtime::content_server_cpu_priority;
+pub use runtime::total_cpu_priority;
+
+pub const CONTENT_SERVER_CONFIG: runtime::RequiredConfig = runtime::RequiredConfig {
+    secrets: &[],
+    listen_ports: &["content_server_port"],
+};
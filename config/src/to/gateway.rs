@@ -1,27 +1,32 @@
-use crate::from;
+use crate::runtime;
 
-pub use from::GATEWAY_PORT;
-pub use from::PUBLIC_IP_ADDR;
-pub use from::PUBLIC_PROTOCOL;
-pub use from::SELF_BINDING_ADDR;
+pub use runtime::gateway_port;
+pub use runtime::public_ip_addr;
+pub use runtime::public_protocol;
+pub use runtime::self_binding_addr;
 
-pub use from::REGION_SERVER_PORT;
-pub use from::REGION_SERVER_RECV_ADDR;
+pub use runtime::region_server_port;
+pub use runtime::region_server_recv_addr;
 
-pub use from::AUTH_SERVER_PORT;
-pub use from::AUTH_SERVER_RECV_ADDR;
+pub use runtime::auth_server_port;
+pub use runtime::auth_server_recv_addr;
 
-pub use from::CONTENT_SERVER_PORT;
-pub use from::CONTENT_SERVER_RECV_ADDR;
+pub use runtime::content_server_port;
+pub use runtime::content_server_recv_addr;
 
-pub use from::SESSION_SERVER_RECV_ADDR;
-pub use from::SESSION_SERVER_SIGNAL_PORT;
+pub use runtime::session_server_recv_addr;
+pub use runtime::session_server_signal_port;
 
-pub use from::WORLD_SERVER_RECV_ADDR;
-pub use from::WORLD_SERVER_SIGNAL_PORT;
+pub use runtime::world_server_recv_addr;
+pub use runtime::world_server_signal_port;
 
-pub use from::SOCIAL_SERVER_PORT;
-pub use from::SOCIAL_SERVER_RECV_ADDR;
+pub use runtime::social_server_port;
+pub use runtime::social_server_recv_addr;
 
-pub use from::GATEWAY_SERVER_CPU_PRIORITY;
-pub use from::TOTAL_CPU_PRIORITY;
+pub use runtime::gateway_server_cpu_priority;
+pub use runtime::total_cpu_priority;
+
+pub const GATEWAY_CONFIG: runtime::RequiredConfig = runtime::RequiredConfig {
+    secrets: &[],
+    listen_ports: &["gateway_port"],
+};
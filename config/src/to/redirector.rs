@@ -1,10 +1,15 @@
-use crate::from;
+use crate::runtime;
 
-pub use from::GATEWAY_PORT;
-pub use from::PUBLIC_IP_ADDR;
-pub use from::PUBLIC_PROTOCOL;
-pub use from::REDIRECTOR_PORT;
-pub use from::SELF_BINDING_ADDR;
+pub use runtime::gateway_port;
+pub use runtime::public_ip_addr;
+pub use runtime::public_protocol;
+pub use runtime::redirector_port;
+pub use runtime::self_binding_addr;
 
-pub use from::REDIRECTOR_SERVER_CPU_PRIORITY;
-pub use from::TOTAL_CPU_PRIORITY;
+pub use runtime::redirector_server_cpu_priority;
+pub use runtime::total_cpu_priority;
+
+pub const REDIRECTOR_CONFIG: runtime::RequiredConfig = runtime::RequiredConfig {
+    secrets: &[],
+    listen_ports: &["redirector_port"],
+};
@@ -1,14 +1,25 @@
-use crate::from;
+use crate::runtime;
 
-pub use from::SELF_BINDING_ADDR;
+pub use runtime::self_binding_addr;
 
-pub use from::REGION_SERVER_PORT;
+pub use runtime::region_server_port;
 
-pub use from::ASSET_SERVER_GLOBAL_SECRET;
-pub use from::REGION_SERVER_SECRET;
-pub use from::SESSION_SERVER_GLOBAL_SECRET;
-pub use from::SOCIAL_SERVER_GLOBAL_SECRET;
-pub use from::WORLD_SERVER_GLOBAL_SECRET;
+pub use runtime::asset_server_global_secret;
+pub use runtime::region_server_secret;
+pub use runtime::session_server_global_secret;
+pub use runtime::social_server_global_secret;
+pub use runtime::world_server_global_secret;
 
-pub use from::REGION_SERVER_CPU_PRIORITY;
-pub use from::TOTAL_CPU_PRIORITY;
+pub use runtime::region_server_cpu_priority;
+pub use runtime::total_cpu_priority;
+
+pub const REGION_SERVER_CONFIG: runtime::RequiredConfig = runtime::RequiredConfig {
+    secrets: &[
+        "region_server_secret",
+        "session_server_global_secret",
+        "world_server_global_secret",
+        "asset_server_global_secret",
+        "social_server_global_secret",
+    ],
+    listen_ports: &["region_server_port"],
+};
@@ -1,24 +1,41 @@
-use crate::from;
+use crate::runtime;
 
-pub use from::GATEWAY_PORT;
-pub use from::PUBLIC_IP_ADDR;
-pub use from::PUBLIC_PROTOCOL;
-pub use from::SELF_BINDING_ADDR;
+pub use runtime::gateway_port;
+pub use runtime::public_ip_addr;
+pub use runtime::public_protocol;
+pub use runtime::self_binding_addr;
 
-pub use from::REGION_SERVER_PORT;
-pub use from::REGION_SERVER_RECV_ADDR;
-pub use from::REGION_SERVER_SECRET;
+pub use runtime::link_conditions;
 
-pub use from::AUTH_SERVER_PORT;
-pub use from::AUTH_SERVER_RECV_ADDR;
+pub use runtime::region_server_port;
+pub use runtime::region_server_recv_addr;
+pub use runtime::region_server_secret;
 
-pub use from::SOCIAL_SERVER_GLOBAL_SECRET;
+pub use runtime::auth_server_port;
+pub use runtime::auth_server_recv_addr;
 
-pub use from::SESSION_SERVER_GLOBAL_SECRET;
-pub use from::SESSION_SERVER_HTTP_PORT;
-pub use from::SESSION_SERVER_RECV_ADDR;
-pub use from::SESSION_SERVER_SIGNAL_PORT;
-pub use from::SESSION_SERVER_WEBRTC_PORT;
+pub use runtime::social_server_global_secret;
 
-pub use from::SESSION_SERVER_CPU_PRIORITY;
-pub use from::TOTAL_CPU_PRIORITY;
+pub use runtime::session_server_global_secret;
+pub use runtime::session_server_http_port;
+pub use runtime::session_server_recv_addr;
+pub use runtime::session_server_signal_port;
+pub use runtime::session_server_webrtc_port;
+
+pub use runtime::session_server_cpu_priority;
+pub use runtime::total_cpu_priority;
+
+pub use runtime::reconnect_grace_period_secs;
+
+pub const SESSION_SERVER_CONFIG: runtime::RequiredConfig = runtime::RequiredConfig {
+    secrets: &[
+        "region_server_secret",
+        "session_server_global_secret",
+        "social_server_global_secret",
+    ],
+    listen_ports: &[
+        "session_server_http_port",
+        "session_server_signal_port",
+        "session_server_webrtc_port",
+    ],
+};
@@ -1,15 +1,27 @@
-use crate::from;
+use crate::runtime;
 
-pub use from::PUBLIC_IP_ADDR;
-pub use from::SELF_BINDING_ADDR;
+pub use runtime::public_ip_addr;
+pub use runtime::self_binding_addr;
 
-pub use from::REGION_SERVER_PORT;
-pub use from::REGION_SERVER_RECV_ADDR;
-pub use from::REGION_SERVER_SECRET;
+pub use runtime::region_server_port;
+pub use runtime::region_server_recv_addr;
+pub use runtime::region_server_secret;
 
-pub use from::SOCIAL_SERVER_GLOBAL_SECRET;
-pub use from::SOCIAL_SERVER_PORT;
-pub use from::SOCIAL_SERVER_RECV_ADDR;
+pub use runtime::social_server_admin_secret;
+pub use runtime::social_server_global_secret;
+pub use runtime::social_server_port;
+pub use runtime::social_server_recv_addr;
 
-pub use from::SOCIAL_SERVER_CPU_PRIORITY;
-pub use from::TOTAL_CPU_PRIORITY;
+pub use runtime::social_server_cpu_priority;
+pub use runtime::total_cpu_priority;
+
+pub use runtime::chat_word_filter;
+
+pub const SOCIAL_SERVER_CONFIG: runtime::RequiredConfig = runtime::RequiredConfig {
+    secrets: &[
+        "region_server_secret",
+        "social_server_global_secret",
+        "social_server_admin_secret",
+    ],
+    listen_ports: &["social_server_port"],
+};
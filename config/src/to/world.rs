@@ -1,26 +1,45 @@
-use crate::from;
+use crate::runtime;
 
-pub use from::GATEWAY_PORT;
-pub use from::PUBLIC_IP_ADDR;
-pub use from::PUBLIC_PROTOCOL;
-pub use from::SELF_BINDING_ADDR;
+pub use runtime::gateway_port;
+pub use runtime::public_ip_addr;
+pub use runtime::public_protocol;
+pub use runtime::self_binding_addr;
 
-pub use from::REGION_SERVER_PORT;
-pub use from::REGION_SERVER_RECV_ADDR;
-pub use from::REGION_SERVER_SECRET;
+pub use runtime::link_conditions;
 
-pub use from::WORLD_SERVER_GLOBAL_SECRET;
-pub use from::WORLD_SERVER_HTTP_PORT;
-pub use from::WORLD_SERVER_RECV_ADDR;
-pub use from::WORLD_SERVER_SIGNAL_PORT;
-pub use from::WORLD_SERVER_WEBRTC_PORT;
+pub use runtime::region_server_port;
+pub use runtime::region_server_recv_addr;
+pub use runtime::region_server_secret;
 
-pub use from::TOTAL_CPU_PRIORITY;
-pub use from::WORLD_SERVER_CPU_PRIORITY;
+pub use runtime::world_server_global_secret;
+pub use runtime::world_server_http_port;
+pub use runtime::world_server_recordings_path;
+pub use runtime::world_server_recv_addr;
+pub use runtime::world_server_signal_port;
+pub use runtime::world_server_webrtc_port;
+
+pub use runtime::total_cpu_priority;
+pub use runtime::world_server_cpu_priority;
 
 cfg_if! {
     if #[cfg(feature = "odst")] {
-        pub use from::SESSION_SERVER_HTTP_PORT;
-        pub use from::SESSION_SERVER_RECV_ADDR;
+        pub use runtime::session_server_http_port;
+        pub use runtime::session_server_recv_addr;
     }
 }
+
+pub use runtime::match_duration_secs;
+pub use runtime::reconnect_grace_period_secs;
+
+pub use runtime::interest_chunk_size;
+pub use runtime::interest_enter_radius;
+pub use runtime::interest_exit_hysteresis;
+
+pub const WORLD_SERVER_CONFIG: runtime::RequiredConfig = runtime::RequiredConfig {
+    secrets: &["region_server_secret", "world_server_global_secret"],
+    listen_ports: &[
+        "world_server_http_port",
+        "world_server_signal_port",
+        "world_server_webrtc_port",
+    ],
+};
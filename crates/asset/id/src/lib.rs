@@ -19,6 +19,8 @@ pub enum AssetType {
     AnimatedModel,
     MovementConfig,
     Unit,
+    Texture,
+    ParticleEffect,
 }
 
 impl AssetType {
@@ -36,6 +38,8 @@ impl AssetType {
             "animated_model" => Some(Self::AnimatedModel),
             "movement_config" => Some(Self::MovementConfig),
             "unit" => Some(Self::Unit),
+            "texture" => Some(Self::Texture),
+            "particle_effect" => Some(Self::ParticleEffect),
             _ => None,
         }
     }
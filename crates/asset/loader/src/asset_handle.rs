@@ -6,7 +6,8 @@ use asset_id::{AssetId, AssetType};
 
 use crate::{
     AnimatedModelData, AnimationData, IconData, MeshData, ModelData, MovementConfigData,
-    PaletteData, SceneData, SkeletonData, SkinData, UiDependencies, UnitData,
+    PaletteData, ParticleEffectData, SceneData, SkeletonData, SkinData, TextureData,
+    UiDependencies, UnitData,
 };
 
 #[derive(Clone, Copy, Eq, PartialEq, Hash)]
@@ -23,6 +24,8 @@ pub enum TypedAssetId {
     AnimatedModel(AssetId),
     MovementConfig(AssetId),
     Unit(AssetId),
+    Texture(AssetId),
+    ParticleEffect(AssetId),
 }
 
 impl TypedAssetId {
@@ -40,6 +43,8 @@ impl TypedAssetId {
             AssetType::AnimatedModel => Self::AnimatedModel(asset_id),
             AssetType::MovementConfig => Self::MovementConfig(asset_id),
             AssetType::Unit => Self::Unit(asset_id),
+            AssetType::Texture => Self::Texture(asset_id),
+            AssetType::ParticleEffect => Self::ParticleEffect(asset_id),
         }
     }
 
@@ -57,6 +62,8 @@ impl TypedAssetId {
             Self::AnimatedModel(id) => *id,
             Self::MovementConfig(id) => *id,
             Self::Unit(id) => *id,
+            Self::Texture(id) => *id,
+            Self::ParticleEffect(id) => *id,
         }
     }
 
@@ -74,6 +81,8 @@ impl TypedAssetId {
             Self::AnimatedModel(_) => AssetType::AnimatedModel,
             Self::MovementConfig(_) => AssetType::MovementConfig,
             Self::Unit(_) => AssetType::Unit,
+            Self::Texture(_) => AssetType::Texture,
+            Self::ParticleEffect(_) => AssetType::ParticleEffect,
         }
     }
 }
@@ -231,6 +240,24 @@ impl From<TypedAssetId> for AssetHandle<UnitData> {
     }
 }
 
+impl From<TypedAssetId> for AssetHandle<TextureData> {
+    fn from(typed_asset_id: TypedAssetId) -> Self {
+        let TypedAssetId::Texture(asset_id) = typed_asset_id else {
+            panic!("expected texture id");
+        };
+        Self::new(asset_id)
+    }
+}
+
+impl From<TypedAssetId> for AssetHandle<ParticleEffectData> {
+    fn from(typed_asset_id: TypedAssetId) -> Self {
+        let TypedAssetId::ParticleEffect(asset_id) = typed_asset_id else {
+            panic!("expected particle effect id");
+        };
+        Self::new(asset_id)
+    }
+}
+
 // AssetHandle -> TypedAssetId
 
 impl From<AssetHandle<SkeletonData>> for TypedAssetId {
@@ -304,3 +331,15 @@ impl From<AssetHandle<UnitData>> for TypedAssetId {
         Self::new(handle.asset_id, AssetType::Unit)
     }
 }
+
+impl From<AssetHandle<TextureData>> for TypedAssetId {
+    fn from(handle: AssetHandle<TextureData>) -> Self {
+        Self::new(handle.asset_id, AssetType::Texture)
+    }
+}
+
+impl From<AssetHandle<ParticleEffectData>> for TypedAssetId {
+    fn from(handle: AssetHandle<ParticleEffectData>) -> Self {
+        Self::new(handle.asset_id, AssetType::ParticleEffect)
+    }
+}
@@ -5,13 +5,15 @@ use bevy_ecs::system::{ResMut, Resource};
 use asset_id::{AssetId, AssetType};
 use render_api::{
     base::CpuSkin,
-    base::{CpuMaterial, CpuMesh},
+    base::{CpuMaterial, CpuMesh, CpuTexture2D},
+    components::ParticleSettings,
 };
-use storage::Storage;
+use spec::AnimationGraph;
+use storage::{Handle, Storage};
 
 use crate::{
     processed_asset_store::ProcessedAssetStore, AnimatedModelData, AnimationData, AssetHandle,
-    IconData, MovementConfigData, UnitData,
+    IconData, MovementConfigData, ParticleEffectData, TextureData, UnitData,
 };
 
 #[derive(Resource)]
@@ -38,15 +40,17 @@ impl AssetManager {
     }
 
     // used as a system
-    pub(crate) fn sync(
+    pub fn sync(
         mut asset_manager: ResMut<Self>,
         mut meshes: ResMut<Storage<CpuMesh>>,
         mut materials: ResMut<Storage<CpuMaterial>>,
         mut skins: ResMut<Storage<CpuSkin>>,
+        mut textures: ResMut<Storage<CpuTexture2D>>,
     ) {
         asset_manager.store.sync_meshes(&mut meshes);
         asset_manager.store.sync_icons(&mut meshes);
         asset_manager.store.sync_palettes(&mut materials);
+        asset_manager.store.sync_textures(&mut textures);
 
         asset_manager
             .store
@@ -95,6 +99,26 @@ impl AssetManager {
         self.store.get_icon_frame_height(handle, index)
     }
 
+    // Texture
+
+    pub fn get_texture_handle(
+        &self,
+        handle: &AssetHandle<TextureData>,
+    ) -> Option<&Handle<CpuTexture2D>> {
+        let data = self.store.textures.get(handle)?;
+        data.get_cpu_texture_handle()
+    }
+
+    // Particle Effect
+
+    pub fn get_particle_settings(
+        &self,
+        handle: &AssetHandle<ParticleEffectData>,
+    ) -> Option<ParticleSettings> {
+        let data = self.store.particle_effects.get(handle)?;
+        Some(data.get_particle_settings())
+    }
+
     // Animation
 
     pub fn get_animation_duration_ms(&self, handle: &AssetHandle<AnimationData>) -> f32 {
@@ -119,6 +143,38 @@ impl AssetManager {
         self.get_animation_duration_ms(animation_handle)
     }
 
+    // names of the events on frames the clip reached after `from_ms`, up to and including `to_ms`
+    pub fn get_animated_model_animation_events(
+        &self,
+        handle: &AssetHandle<AnimatedModelData>,
+        animation_name: &str,
+        from_ms: f32,
+        to_ms: f32,
+    ) -> Vec<String> {
+        let Some(model) = self.store.animated_models.get(handle) else {
+            return Vec::new();
+        };
+        let Some(animation_handle) = model.get_animation_handle(animation_name) else {
+            return Vec::new();
+        };
+        let Some(animation) = self.store.animations.get(animation_handle) else {
+            return Vec::new();
+        };
+        animation
+            .get_events_between(from_ms, to_ms)
+            .into_iter()
+            .map(str::to_string)
+            .collect()
+    }
+
+    pub fn get_animated_model_graph(
+        &self,
+        handle: &AssetHandle<AnimatedModelData>,
+    ) -> Option<&AnimationGraph> {
+        let model = self.store.animated_models.get(handle)?;
+        Some(model.get_animation_graph())
+    }
+
     // Unit
 
     pub fn get_unit_animated_model_handle(
@@ -137,4 +193,13 @@ impl AssetManager {
         let movement_config_handle = unit.get_movement_config_file_handle()?;
         self.store.movement_configs.get(movement_config_handle)
     }
+
+    pub fn get_unit_particle_settings(
+        &self,
+        handle: &AssetHandle<UnitData>,
+    ) -> Option<ParticleSettings> {
+        let unit = self.store.units.get(handle)?;
+        let particle_effect_handle = unit.get_particle_effect_file_handle()?;
+        self.get_particle_settings(particle_effect_handle)
+    }
 }
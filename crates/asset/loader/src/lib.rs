@@ -21,3 +21,4 @@ pub use text_measurer::*;
 pub use types::*;
 
 pub use asset_serde::bits::AssetMetadataSerde;
+pub use spec::{AnimationGraph, AnimationLayerMode, AnimationPlayback};
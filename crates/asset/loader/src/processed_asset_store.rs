@@ -2,13 +2,13 @@ use std::collections::HashMap;
 
 use asset_id::{AssetId, AssetType};
 use logging::warn;
-use render_api::base::{CpuMaterial, CpuMesh, CpuSkin};
+use render_api::base::{CpuMaterial, CpuMesh, CpuSkin, CpuTexture2D};
 use storage::Storage;
 
 use crate::{
     asset_storage::AssetStorage, AnimatedModelData, AnimationData, AssetHandle, IconData, MeshData,
-    ModelData, MovementConfigData, PaletteData, SceneData, SkeletonData, SkinData, TypedAssetId,
-    UnitData,
+    ModelData, MovementConfigData, PaletteData, ParticleEffectData, SceneData, SkeletonData,
+    SkinData, TextureData, TypedAssetId, UnitData,
 };
 
 pub struct ProcessedAssetStore {
@@ -23,11 +23,14 @@ pub struct ProcessedAssetStore {
     pub animated_models: AssetStorage<AnimatedModelData>,
     pub movement_configs: AssetStorage<MovementConfigData>,
     pub units: AssetStorage<UnitData>,
+    pub textures: AssetStorage<TextureData>,
+    pub particle_effects: AssetStorage<ParticleEffectData>,
 
     // mesh file name, skin handle
     queued_meshes: Vec<AssetHandle<MeshData>>,
     queued_palettes: Vec<AssetHandle<PaletteData>>,
     queued_icons: Vec<AssetHandle<IconData>>,
+    queued_textures: Vec<AssetHandle<TextureData>>,
 
     icons_waiting_on_palettes: HashMap<AssetHandle<PaletteData>, Vec<AssetHandle<IconData>>>,
     skins_waiting_on_palettes: HashMap<AssetHandle<PaletteData>, Vec<AssetHandle<SkinData>>>,
@@ -50,10 +53,13 @@ impl Default for ProcessedAssetStore {
             animated_models: AssetStorage::default(),
             movement_configs: AssetStorage::default(),
             units: AssetStorage::default(),
+            textures: AssetStorage::default(),
+            particle_effects: AssetStorage::default(),
 
             queued_meshes: Vec::new(),
             queued_palettes: Vec::new(),
             queued_icons: Vec::new(),
+            queued_textures: Vec::new(),
             icons_waiting_on_palettes: HashMap::new(),
             skins_waiting_on_palettes: HashMap::new(),
             skins_waiting_on_meshes: HashMap::new(),
@@ -215,6 +221,24 @@ impl ProcessedAssetStore {
                     unit_data.load_dependencies(handle, &mut dependencies);
                 }
             }
+            AssetType::Texture => {
+                let handle = AssetHandle::<TextureData>::new(*asset_id);
+                if !self.textures.has(&handle) {
+                    let bytes = asset_data_store.get(asset_id).unwrap();
+                    let texture_data = TextureData::from_bytes(bytes);
+                    self.textures.insert(handle, texture_data);
+                    self.queued_textures.push(handle);
+                }
+            }
+            AssetType::ParticleEffect => {
+                let handle = AssetHandle::<ParticleEffectData>::new(*asset_id);
+                if !self.particle_effects.has(&handle) {
+                    let bytes = asset_data_store.get(asset_id).unwrap();
+                    let bytes: &[u8] = bytes;
+                    let particle_effect_data = ParticleEffectData::from(bytes);
+                    self.particle_effects.insert(handle, particle_effect_data);
+                }
+            }
         };
 
         if !dependencies.is_empty() {
@@ -236,7 +260,9 @@ impl ProcessedAssetStore {
             TypedAssetId::Mesh(_)
             | TypedAssetId::Skeleton(_)
             | TypedAssetId::Palette(_)
-            | TypedAssetId::MovementConfig(_) => {
+            | TypedAssetId::MovementConfig(_)
+            | TypedAssetId::Texture(_)
+            | TypedAssetId::ParticleEffect(_) => {
                 panic!("unexpected dependency for this type of asset")
             }
             TypedAssetId::Ui(_) => {
@@ -356,6 +382,17 @@ impl ProcessedAssetStore {
         }
     }
 
+    pub(crate) fn sync_textures(&mut self, textures: &mut Storage<CpuTexture2D>) {
+        if self.queued_textures.is_empty() {
+            return;
+        }
+
+        for texture_handle in std::mem::take(&mut self.queued_textures) {
+            let texture_data = self.textures.get_mut(&texture_handle).unwrap();
+            texture_data.load_cpu_texture(textures);
+        }
+    }
+
     pub(crate) fn sync_palettes(&mut self, materials: &mut Storage<CpuMaterial>) {
         if self.queued_palettes.is_empty() {
             return;
@@ -1,7 +1,9 @@
 use asset_id::AssetId;
 use asset_serde::bits::AnimatedModelBits;
+use spec::AnimationGraph;
 use std::collections::HashMap;
 // use logging::info;
+use logging::warn;
 
 use crate::{
     asset_dependency::AssetDependency, AnimationData, AssetHandle, ModelData, TypedAssetId,
@@ -11,6 +13,7 @@ pub struct AnimatedModelData {
     model_file: AssetDependency<ModelData>,
     animation_name_to_id: HashMap<String, AssetId>,
     animation_files: HashMap<AssetId, AssetDependency<AnimationData>>,
+    graph: AnimationGraph,
 }
 
 impl Default for AnimatedModelData {
@@ -34,12 +37,27 @@ impl From<&[u8]> for AnimatedModelData {
             animation_files.insert(*asset_id, AssetDependency::AssetId(*asset_id));
         }
 
+        // models exported before graphs existed only have the idle & walk clips
+        let mut graph = match base.get_graph() {
+            Some(graph) => graph.clone().into(),
+            None => AnimationGraph::idle_walk(),
+        };
+        // a broken graph would leave the model stuck in a state with no clip, or in none at all
+        if let Err(error) = graph.validate(&animation_name_to_id) {
+            warn!(
+                "invalid animation graph, using idle & walk instead: {}",
+                error
+            );
+            graph = AnimationGraph::idle_walk();
+        }
+
         // info!("--- done reading animated model ---");
 
         Self {
             model_file: AssetDependency::AssetId(model_asset_id),
             animation_name_to_id,
             animation_files,
+            graph,
         }
     }
 }
@@ -62,6 +80,10 @@ impl AnimatedModelData {
         Some(handle)
     }
 
+    pub fn get_animation_graph(&self) -> &AnimationGraph {
+        &self.graph
+    }
+
     pub(crate) fn load_dependencies(
         &self,
         handle: AssetHandle<Self>,
@@ -1,13 +1,34 @@
 use std::collections::HashMap;
 
+use asset_serde::bits::Easing;
 use math::Quat;
 use render_api::components::Transform;
+use spec::AnimationLayerMode;
 
 use crate::{
     asset_dependency::{AssetComponentHandle, AssetDependency},
     AssetHandle, ModelData, SkeletonData, TypedAssetId,
 };
 
+// One layer of an animated model's pose as it's drawn, laid over the layers before it
+pub struct AnimationLayerPose<'a> {
+    // (animation name, frame time ms, weight) for each clip blended on this layer
+    pub animations: Vec<(&'a str, f32, f32)>,
+    // bones the layer drives, every bone when empty
+    pub bone_mask: &'a [String],
+    pub mode: AnimationLayerMode,
+}
+
+impl<'a> AnimationLayerPose<'a> {
+    pub fn base(animations: Vec<(&'a str, f32, f32)>) -> Self {
+        Self {
+            animations,
+            bone_mask: &[],
+            mode: AnimationLayerMode::Override,
+        }
+    }
+}
+
 pub struct AnimationData {
     skeleton_file: AssetDependency<SkeletonData>,
     frames: Vec<Frame>,
@@ -22,15 +43,20 @@ impl Default for AnimationData {
 
 struct Frame {
     duration_ms: f32,
+    // how the pose moves towards the next frame's
+    easing: Easing,
     // bone index, rotation
     rotations: HashMap<String, Quat>,
+    events: Vec<String>,
 }
 
 impl Frame {
-    pub fn new(duration_ms: f32) -> Self {
+    pub fn new(duration_ms: f32, easing: Easing, events: Vec<String>) -> Self {
         Self {
             duration_ms,
+            easing,
             rotations: HashMap::new(),
+            events,
         }
     }
 
@@ -85,20 +111,127 @@ impl AnimationData {
         model_data: &ModelData,
         frame_elapsed_ms: f32,
     ) -> Option<Vec<(AssetComponentHandle, Transform)>> {
-        let model_components = model_data.get_components_copied();
-
-        let (frame_index, next_frame_index, interpolation) = self.get_frame_stats(frame_elapsed_ms);
-        //info!("frame_index: {}, next_frame_index: {}, interpolation: {}", frame_index, next_frame_index, interpolation);
+        Self::get_blended_animated_components(
+            skeleton_data,
+            model_data,
+            &[(self, frame_elapsed_ms, 1.0)],
+        )
+    }
 
-        let interpolated_skeleton = self.get_interpolated_skeleton(
+    // Poses the model with a weighted blend of clips that share its skeleton. Each entry is
+    // (animation, frame_elapsed_ms, weight), weights are relative and need not sum to 1.0
+    pub fn get_blended_animated_components(
+        skeleton_data: &SkeletonData,
+        model_data: &ModelData,
+        animations: &[(&AnimationData, f32, f32)],
+    ) -> Option<Vec<(AssetComponentHandle, Transform)>> {
+        let (rotations, _) = Self::get_blended_rotations(animations)?;
+        Some(Self::get_posed_components(
             skeleton_data,
-            frame_index,
-            next_frame_index,
-            interpolation,
-        );
+            model_data,
+            rotations,
+        ))
+    }
+
+    // Per-bone rotations of a weighted blend of clips, along with the blend's total weight.
+    // `None` when nothing has any weight
+    pub fn get_blended_rotations(
+        animations: &[(&AnimationData, f32, f32)],
+    ) -> Option<(HashMap<String, Quat>, f32)> {
+        let mut blended_rotations: HashMap<String, Quat> = HashMap::new();
+        let mut total_weight = 0.0;
+
+        for (animation, frame_elapsed_ms, weight) in animations {
+            if *weight <= 0.0 {
+                continue;
+            }
+            let (frame_index, next_frame_index, interpolation) =
+                animation.get_frame_stats(*frame_elapsed_ms);
+            let rotations =
+                animation.get_interpolated_rotations(frame_index, next_frame_index, interpolation);
+
+            // blending one clip at a time with its share of the running total weight keeps
+            // every clip's contribution proportional to its weight
+            total_weight += weight;
+            let blend = weight / total_weight;
+            for (bone_name, rotation) in rotations.iter() {
+                let blended_rotation = blended_rotations
+                    .entry(bone_name.clone())
+                    .or_insert(Quat::IDENTITY);
+                *blended_rotation = blended_rotation.slerp(*rotation, blend);
+            }
+            // a bone this clip doesn't animate rests at identity
+            for (bone_name, blended_rotation) in blended_rotations.iter_mut() {
+                if !rotations.contains_key(bone_name) {
+                    *blended_rotation = blended_rotation.slerp(Quat::IDENTITY, blend);
+                }
+            }
+        }
+
+        if total_weight <= 0.0 {
+            return None;
+        }
+
+        Some((blended_rotations, total_weight))
+    }
+
+    // Lays a layer's rotations over `base` on the bones in `bone_mask`, or on every bone when
+    // the mask is empty. `weight` fades the layer in & out
+    pub fn apply_layer(
+        base: &mut HashMap<String, Quat>,
+        layer: &HashMap<String, Quat>,
+        weight: f32,
+        bone_mask: &[String],
+        mode: AnimationLayerMode,
+    ) {
+        let weight = weight.clamp(0.0, 1.0);
+        if weight <= 0.0 {
+            return;
+        }
+        let in_mask = |bone_name: &String| bone_mask.is_empty() || bone_mask.contains(bone_name);
+
+        match mode {
+            AnimationLayerMode::Override => {
+                for (bone_name, base_rotation) in base.iter_mut() {
+                    if !in_mask(bone_name) {
+                        continue;
+                    }
+                    let layer_rotation = layer.get(bone_name).copied().unwrap_or(Quat::IDENTITY);
+                    *base_rotation = base_rotation.slerp(layer_rotation, weight);
+                }
+                for (bone_name, layer_rotation) in layer.iter() {
+                    if !in_mask(bone_name) || base.contains_key(bone_name) {
+                        continue;
+                    }
+                    base.insert(
+                        bone_name.clone(),
+                        Quat::IDENTITY.slerp(*layer_rotation, weight),
+                    );
+                }
+            }
+            AnimationLayerMode::Additive => {
+                // the layer's rotations are offsets from the rest pose, applied on top
+                for (bone_name, layer_rotation) in layer.iter() {
+                    if !in_mask(bone_name) {
+                        continue;
+                    }
+                    let offset = Quat::IDENTITY.slerp(*layer_rotation, weight);
+                    let base_rotation = base.entry(bone_name.clone()).or_insert(Quat::IDENTITY);
+                    *base_rotation = (*base_rotation * offset).normalize();
+                }
+            }
+        }
+    }
+
+    pub fn get_posed_components(
+        skeleton_data: &SkeletonData,
+        model_data: &ModelData,
+        rotations: HashMap<String, Quat>,
+    ) -> Vec<(AssetComponentHandle, Transform)> {
+        let interpolated_skeleton = skeleton_data.get_interpolated_skeleton(rotations);
 
         let mut output = Vec::new();
-        for (component_handle, bone_name, child_transform) in model_components {
+        for (component_handle, bone_name, child_transform) in model_data.get_components_copied() {
             let parent_transform = interpolated_skeleton
                 .get(&bone_name)
                 .expect("bone name not found in skeleton");
@@ -106,7 +239,26 @@ impl AnimationData {
             output.push((component_handle, final_transform));
         }
 
-        Some(output)
+        output
+    }
+
+    // Events on the frames that playback reached after `from_ms`, up to & including `to_ms`.
+    // A `to_ms` smaller than `from_ms` means playback looped past the end of the clip
+    pub fn get_events_between(&self, from_ms: f32, to_ms: f32) -> Vec<&str> {
+        let mut output = Vec::new();
+        let mut frame_start_ms = 0.0;
+        for frame in &self.frames {
+            let reached = if from_ms <= to_ms {
+                from_ms < frame_start_ms && frame_start_ms <= to_ms
+            } else {
+                from_ms < frame_start_ms || frame_start_ms <= to_ms
+            };
+            if reached {
+                output.extend(frame.events.iter().map(String::as_str));
+            }
+            frame_start_ms += frame.duration_ms;
+        }
+        output
     }
 
     // returns (frame_index, next_frame_index, interpolation)
@@ -129,18 +281,22 @@ impl AnimationData {
                 if next_frame_index >= self.frames.len() {
                     next_frame_index = 0;
                 }
-                return (frame_index, next_frame_index, remaining_ms / frame_duration);
+                let easing = self.frames[frame_index].easing;
+                return (
+                    frame_index,
+                    next_frame_index,
+                    easing.apply(remaining_ms / frame_duration),
+                );
             }
         }
     }
 
-    fn get_interpolated_skeleton(
+    fn get_interpolated_rotations(
         &self,
-        skeleton_data: &SkeletonData,
         frame_index: usize,
         next_frame_index: usize,
         interpolation: f32,
-    ) -> HashMap<String, Transform> {
+    ) -> HashMap<String, Quat> {
         let current_frame = &self.frames[frame_index];
         let next_frame = &self.frames[next_frame_index];
 
@@ -176,7 +332,7 @@ impl AnimationData {
             }
         }
 
-        skeleton_data.get_interpolated_skeleton(interpolated_rotations)
+        interpolated_rotations
     }
 
     pub fn from_bytes(bytes: &[u8]) -> Self {
@@ -196,14 +352,14 @@ impl AnimationData {
                     //info!("ShapeIndex {}: {}", names.len(), name);
                     name_map.insert(name_map.len() as u16, name);
                 }
-                asset_serde::bits::AnimAction::Frame(rotation_map, transition_time) => {
+                asset_serde::bits::AnimAction::Frame(rotation_map, transition, events) => {
                     // info!(
                     //     "Frame {}: {:?}ms",
                     //     frames.len(),
-                    //     transition_time.get_duration_ms()
+                    //     transition.get_duration_ms()
                     // );
-                    let transition_time = transition_time.get_duration_ms() as f32;
-                    let mut frame = Frame::new(transition_time);
+                    let transition_time = transition.get_duration_ms() as f32;
+                    let mut frame = Frame::new(transition_time, transition.get_easing(), events);
                     total_animation_time_ms += transition_time;
                     for (name_index, rotation) in rotation_map {
                         let name = name_map.get(&name_index).unwrap().clone();
@@ -228,3 +384,83 @@ impl AnimationData {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use asset_id::AssetId;
+
+    use super::*;
+
+    // a single held frame, rotating each bone about z by the given angle
+    fn animation(bone_angles: &[(&str, f32)]) -> AnimationData {
+        let mut frame = Frame::new(100.0, Easing::Linear, Vec::new());
+        for (bone_name, angle) in bone_angles {
+            frame.add_rotation(bone_name.to_string(), Quat::from_rotation_z(*angle));
+        }
+        AnimationData {
+            skeleton_file: AssetDependency::AssetId(AssetId::from_str("8273wa").unwrap()),
+            frames: vec![frame],
+            total_duration: 100.0,
+        }
+    }
+
+    fn assert_angle(rotations: &HashMap<String, Quat>, bone_name: &str, angle: f32) {
+        let rotation = rotations[bone_name];
+        let expected = Quat::from_rotation_z(angle);
+        assert!(
+            rotation.angle_between(expected) < 0.0001,
+            "{} is {:?}, expected {:?}",
+            bone_name,
+            rotation,
+            expected
+        );
+    }
+
+    #[test]
+    fn single_clip_is_unchanged_by_its_weight() {
+        let walk = animation(&[("arm", FRAC_PI_2)]);
+        let (rotations, total_weight) =
+            AnimationData::get_blended_rotations(&[(&walk, 0.0, 2.0)]).unwrap();
+        assert_eq!(total_weight, 2.0);
+        assert_angle(&rotations, "arm", FRAC_PI_2);
+    }
+
+    #[test]
+    fn clips_contribute_in_proportion_to_their_weight() {
+        let idle = animation(&[("arm", 0.0)]);
+        let walk = animation(&[("arm", FRAC_PI_2)]);
+        let (rotations, total_weight) =
+            AnimationData::get_blended_rotations(&[(&idle, 0.0, 1.0), (&walk, 0.0, 3.0)]).unwrap();
+        assert_eq!(total_weight, 4.0);
+        assert_angle(&rotations, "arm", FRAC_PI_2 * 0.75);
+
+        // the order clips are given in doesn't matter
+        let (rotations, _) =
+            AnimationData::get_blended_rotations(&[(&walk, 0.0, 3.0), (&idle, 0.0, 1.0)]).unwrap();
+        assert_angle(&rotations, "arm", FRAC_PI_2 * 0.75);
+    }
+
+    #[test]
+    fn bones_a_clip_doesnt_animate_blend_towards_rest() {
+        let wave = animation(&[("arm", FRAC_PI_2)]);
+        let kick = animation(&[("leg", FRAC_PI_2)]);
+        let (rotations, _) =
+            AnimationData::get_blended_rotations(&[(&wave, 0.0, 1.0), (&kick, 0.0, 1.0)]).unwrap();
+        assert_angle(&rotations, "arm", FRAC_PI_2 * 0.5);
+        assert_angle(&rotations, "leg", FRAC_PI_2 * 0.5);
+    }
+
+    #[test]
+    fn weightless_clips_are_skipped() {
+        let idle = animation(&[("arm", 0.0)]);
+        let walk = animation(&[("arm", FRAC_PI_2)]);
+        assert!(AnimationData::get_blended_rotations(&[(&walk, 0.0, 0.0)]).is_none());
+
+        let (rotations, total_weight) =
+            AnimationData::get_blended_rotations(&[(&idle, 0.0, 0.0), (&walk, 0.0, 1.0)]).unwrap();
+        assert_eq!(total_weight, 1.0);
+        assert_angle(&rotations, "arm", FRAC_PI_2);
+    }
+}
@@ -5,9 +5,11 @@ mod mesh;
 mod model;
 mod movement_config;
 mod palette;
+mod particle_effect;
 mod scene;
 mod skeleton;
 mod skin;
+mod texture;
 mod ui;
 mod unit;
 
@@ -18,8 +20,10 @@ pub use mesh::*;
 pub use model::*;
 pub use movement_config::*;
 pub use palette::*;
+pub use particle_effect::*;
 pub use scene::*;
 pub use skeleton::*;
 pub use skin::*;
+pub use texture::*;
 pub use ui::*;
 pub use unit::*;
@@ -1,7 +1,8 @@
 use asset_serde::bits::MovementConfigBits;
+use spec::MovementConfig;
 
 pub struct MovementConfigData {
-    max_velocity: f32,
+    movement_config: MovementConfig,
 }
 
 impl Default for MovementConfigData {
@@ -19,13 +20,17 @@ impl From<&[u8]> for MovementConfigData {
         // info!("--- done reading movement config ---");
 
         Self {
-            max_velocity: base.get_max_velocity(),
+            movement_config: base.into(),
         }
     }
 }
 
 impl MovementConfigData {
+    pub fn get_movement_config(&self) -> &MovementConfig {
+        &self.movement_config
+    }
+
     pub fn get_max_velocity(&self) -> f32 {
-        self.max_velocity
+        self.movement_config.max_velocity()
     }
 }
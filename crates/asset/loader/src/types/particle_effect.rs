@@ -0,0 +1,51 @@
+use asset_serde::bits::ParticleEffectBits;
+use math::Vec3;
+use render_api::{base::Color, components::ParticleSettings};
+use spec::ParticleEffect;
+
+pub struct ParticleEffectData {
+    particle_effect: ParticleEffect,
+}
+
+impl Default for ParticleEffectData {
+    fn default() -> Self {
+        panic!("");
+    }
+}
+
+impl From<&[u8]> for ParticleEffectData {
+    fn from(bytes: &[u8]) -> Self {
+        let base = ParticleEffectBits::from_bytes(bytes).expect("unable to parse file");
+
+        Self {
+            particle_effect: base.into(),
+        }
+    }
+}
+
+impl ParticleEffectData {
+    pub fn get_particle_effect(&self) -> &ParticleEffect {
+        &self.particle_effect
+    }
+
+    pub fn get_particle_settings(&self) -> ParticleSettings {
+        let effect = &self.particle_effect;
+        let vec3 = |(x, y, z): (f32, f32, f32)| Vec3::new(x, y, z);
+        let color = |(r, g, b): (u8, u8, u8)| Color::new(r, g, b);
+
+        ParticleSettings {
+            spawn_rate: effect.spawn_rate(),
+            lifetime_ms: effect.lifetime_ms(),
+            max_particles: effect.max_particles() as usize,
+            direction: vec3(effect.direction()),
+            spread: effect.spread_degrees().to_radians(),
+            min_speed: effect.min_speed(),
+            max_speed: effect.max_speed(),
+            acceleration: vec3(effect.acceleration()),
+            start_color: color(effect.start_color()),
+            end_color: color(effect.end_color()),
+            start_size: effect.start_size(),
+            end_size: effect.end_size(),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use render_api::base::CpuTexture2D;
+use storage::{Handle, Storage};
+
+use crate::asset_dependency::AssetDependency;
+
+pub struct TextureData {
+    path: AssetDependency<CpuTexture2D>,
+}
+
+impl Default for TextureData {
+    fn default() -> Self {
+        panic!("");
+    }
+}
+
+impl TextureData {
+    pub fn get_cpu_texture_handle(&self) -> Option<&Handle<CpuTexture2D>> {
+        if let AssetDependency::<CpuTexture2D>::Handle(handle) = &self.path {
+            Some(handle)
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn load_cpu_texture(&mut self, textures: &mut Storage<CpuTexture2D>) {
+        let AssetDependency::<CpuTexture2D>::Bytes(bytes) = &self.path else {
+            panic!("expected bytes right after load");
+        };
+
+        let cpu_texture = CpuTexture2D::from_png(bytes).expect("unable to parse texture file");
+
+        // texture data can't be hashed, so every file gets its own texture
+        let cpu_texture_handle = textures.add_unique(cpu_texture);
+
+        self.path.load_handle(cpu_texture_handle);
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let boxed_bytes = bytes.to_vec().into_boxed_slice();
+
+        Self {
+            path: AssetDependency::Bytes(boxed_bytes),
+        }
+    }
+}
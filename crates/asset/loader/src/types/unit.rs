@@ -2,12 +2,13 @@ use asset_serde::bits::UnitBits;
 
 use crate::{
     asset_dependency::AssetDependency, AnimatedModelData, AssetHandle, MovementConfigData,
-    TypedAssetId,
+    ParticleEffectData, TypedAssetId,
 };
 
 pub struct UnitData {
     animated_model_file: AssetDependency<AnimatedModelData>,
     movement_config_file: AssetDependency<MovementConfigData>,
+    particle_effect_file_opt: Option<AssetDependency<ParticleEffectData>>,
 }
 
 impl Default for UnitData {
@@ -24,12 +25,14 @@ impl From<&[u8]> for UnitData {
 
         let animated_model_asset_id = base.get_animated_model_asset_id();
         let movement_config_asset_id = base.get_movement_config_asset_id();
+        let particle_effect_asset_id_opt = base.get_particle_effect_asset_id();
 
         // info!("--- done reading unit ---");
 
         Self {
             animated_model_file: AssetDependency::AssetId(animated_model_asset_id),
             movement_config_file: AssetDependency::AssetId(movement_config_asset_id),
+            particle_effect_file_opt: particle_effect_asset_id_opt.map(AssetDependency::AssetId),
         }
     }
 }
@@ -54,6 +57,16 @@ impl UnitData {
         }
     }
 
+    pub fn get_particle_effect_file_handle(&self) -> Option<&AssetHandle<ParticleEffectData>> {
+        if let Some(AssetDependency::<ParticleEffectData>::AssetHandle(handle)) =
+            &self.particle_effect_file_opt
+        {
+            Some(handle)
+        } else {
+            None
+        }
+    }
+
     pub(crate) fn load_dependencies(
         &self,
         handle: AssetHandle<Self>,
@@ -73,6 +86,14 @@ impl UnitData {
             handle.into(),
             TypedAssetId::MovementConfig(asset_id.clone()),
         ));
+
+        if let Some(particle_effect_file) = &self.particle_effect_file_opt {
+            let AssetDependency::<ParticleEffectData>::AssetId(asset_id) = particle_effect_file
+            else {
+                panic!("expected path right after load");
+            };
+            dependencies.push((handle.into(), TypedAssetId::ParticleEffect(*asset_id)));
+        }
     }
 
     pub(crate) fn finish_dependency(&mut self, dependency_typed_id: TypedAssetId) {
@@ -85,6 +106,13 @@ impl UnitData {
                 let handle = AssetHandle::<MovementConfigData>::new(asset_id);
                 self.movement_config_file.load_asset_handle(handle);
             }
+            TypedAssetId::ParticleEffect(asset_id) => {
+                let handle = AssetHandle::<ParticleEffectData>::new(asset_id);
+                let Some(particle_effect_file) = &mut self.particle_effect_file_opt else {
+                    panic!("unit has no particle effect");
+                };
+                particle_effect_file.load_asset_handle(handle);
+            }
             _ => {
                 panic!("unexpected type of handle");
             }
@@ -1,8 +1,9 @@
 use logging::warn;
 
 use asset_loader::{
-    AnimatedModelData, AssetComponentHandle, AssetHandle, AssetManager, IconData, MeshData,
-    ModelData, ProcessedAssetStore, SceneData, SkinData, UiTextMeasurer,
+    AnimatedModelData, AnimationData, AnimationLayerPose, AssetComponentHandle, AssetHandle,
+    AssetManager, IconData, MeshData, ModelData, ProcessedAssetStore, SceneData, SkinData,
+    UiTextMeasurer,
 };
 use render_api::{
     base::CpuMaterial,
@@ -77,6 +78,15 @@ pub trait AssetRender {
         frame_time_ms: f32,
         render_layer_opt: Option<&RenderLayer>,
     );
+    // the first layer is the base pose, each one after is laid over those before it
+    fn draw_blended_animated_model(
+        &self,
+        render_frame: &mut RenderFrame,
+        animated_model_handle: &AssetHandle<AnimatedModelData>,
+        layers: &[AnimationLayerPose],
+        parent_transform: &Transform,
+        render_layer_opt: Option<&RenderLayer>,
+    );
 }
 
 impl AssetRender for AssetManager {
@@ -213,14 +223,35 @@ impl AssetRender for AssetManager {
         frame_time_ms: f32,
         render_layer_opt: Option<&RenderLayer>,
     ) {
-        AssetRenderer::draw_animated_model(
+        AssetRenderer::draw_blended_animated_model(
             render_frame,
             render_layer_opt,
             self.get_store(),
             animated_model_handle,
-            animation_name,
+            &[AnimationLayerPose::base(vec![(
+                animation_name,
+                frame_time_ms,
+                1.0,
+            )])],
+            parent_transform,
+        );
+    }
+
+    fn draw_blended_animated_model(
+        &self,
+        render_frame: &mut RenderFrame,
+        animated_model_handle: &AssetHandle<AnimatedModelData>,
+        layers: &[AnimationLayerPose],
+        parent_transform: &Transform,
+        render_layer_opt: Option<&RenderLayer>,
+    ) {
+        AssetRenderer::draw_blended_animated_model(
+            render_frame,
+            render_layer_opt,
+            self.get_store(),
+            animated_model_handle,
+            layers,
             parent_transform,
-            frame_time_ms,
         );
     }
 }
@@ -466,14 +497,13 @@ impl AssetRenderer {
         }
     }
 
-    pub(crate) fn draw_animated_model(
+    pub(crate) fn draw_blended_animated_model(
         render_frame: &mut RenderFrame,
         render_layer_opt: Option<&RenderLayer>,
         asset_store: &ProcessedAssetStore,
         animated_model_handle: &AssetHandle<AnimatedModelData>,
-        animation_name: &str,
+        layers: &[AnimationLayerPose],
         parent_transform: &Transform,
-        frame_time_ms: f32,
     ) {
         let Some(animated_model_data) = asset_store.animated_models.get(animated_model_handle)
         else {
@@ -486,33 +516,61 @@ impl AssetRenderer {
         let Some(model_handle) = animated_model_data.get_model_file_handle() else {
             return;
         };
-        let Some(animation_handle) = animated_model_data.get_animation_handle(animation_name)
-        else {
-            return;
-        };
         let Some(model_data) = asset_store.models.get(model_handle) else {
             warn!("model data not loaded 1: {:?}", model_handle.asset_id());
             return;
         };
-        let Some(animation_data) = asset_store.animations.get(animation_handle) else {
-            warn!(
-                "animation data not loaded 1: {:?}",
-                animation_handle.asset_id()
-            );
-            return;
-        };
-        let skeleton_handle = {
-            let skeleton_handle_1 = model_data.get_skeleton_handle();
-            let skeleton_handle_2 = animation_data.get_skeleton_handle();
-            if skeleton_handle_1 != skeleton_handle_2 {
-                panic!(
-                    "skeleton mismatch: {:?} != {:?}",
-                    skeleton_handle_1.asset_id(),
-                    skeleton_handle_2.asset_id()
-                );
+        let skeleton_handle = model_data.get_skeleton_handle();
+
+        let mut rotations_opt = None;
+        for layer in layers {
+            let mut blended_animations = Vec::with_capacity(layer.animations.len());
+            for (animation_name, frame_time_ms, weight) in &layer.animations {
+                let Some(animation_handle) =
+                    animated_model_data.get_animation_handle(animation_name)
+                else {
+                    return;
+                };
+                let Some(animation_data) = asset_store.animations.get(animation_handle) else {
+                    warn!(
+                        "animation data not loaded 1: {:?}",
+                        animation_handle.asset_id()
+                    );
+                    return;
+                };
+                let animation_skeleton_handle = animation_data.get_skeleton_handle();
+                if skeleton_handle != animation_skeleton_handle {
+                    panic!(
+                        "skeleton mismatch: {:?} != {:?}",
+                        skeleton_handle.asset_id(),
+                        animation_skeleton_handle.asset_id()
+                    );
+                }
+                blended_animations.push((animation_data, *frame_time_ms, *weight));
+            }
+
+            let Some((layer_rotations, layer_weight)) =
+                AnimationData::get_blended_rotations(&blended_animations)
+            else {
+                // nothing playing on this layer
+                continue;
+            };
+            match rotations_opt.as_mut() {
+                None => rotations_opt = Some(layer_rotations),
+                Some(rotations) => AnimationData::apply_layer(
+                    rotations,
+                    &layer_rotations,
+                    layer_weight,
+                    layer.bone_mask,
+                    layer.mode,
+                ),
             }
-            skeleton_handle_1
+        }
+        let Some(rotations) = rotations_opt else {
+            // nothing to blend
+            return;
         };
+
         let Some(skeleton_data) = asset_store.skeletons.get(&skeleton_handle) else {
             warn!(
                 "skeleton data not loaded 1: {:?}",
@@ -520,12 +578,8 @@ impl AssetRenderer {
             );
             return;
         };
-        let Some(model_components) =
-            animation_data.get_animated_components(skeleton_data, model_data, frame_time_ms)
-        else {
-            // not yet loaded all
-            return;
-        };
+        let model_components =
+            AnimationData::get_posed_components(skeleton_data, model_data, rotations);
         for (skin_or_scene_handle, mut component_transform) in model_components {
             component_transform = component_transform.multiply(parent_transform);
 
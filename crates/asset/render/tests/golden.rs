@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+
+use bevy_app::{App, Update};
+use bevy_ecs::world::Mut;
+
+use asset_id::{AssetId, AssetType};
+use asset_loader::{AssetHandle, AssetManager, IconData};
+use asset_render::AssetRender;
+use math::Vec3;
+use render_api::{
+    base::{Color, CpuMaterial},
+    components::{AmbientLight, CameraBundle, ClearOperation, Transform, Viewport},
+    resources::RenderFrame,
+    RenderApiPlugin,
+};
+use render_software::{golden_image::assert_golden_image, HeadlessScreen, RenderSoftwarePlugin};
+use storage::Storage;
+
+const SIZE: u32 = 128;
+
+// the launcher's embedded assets, so these tests don't need an asset repo checked out
+const EMBEDDED_DIR: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../../apps/launcher/src/embedded"
+);
+const PALETTE_ID: &str = "8273wa";
+const FONT_ICON_ID: &str = "34mvvk";
+const EYE_ICON_ID: &str = "qbgz5j";
+
+fn headless_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(RenderApiPlugin)
+        .add_plugins(RenderSoftwarePlugin::new(SIZE, SIZE))
+        .init_resource::<AssetManager>()
+        .add_systems(Update, AssetManager::sync);
+
+    let assets = [
+        (PALETTE_ID, AssetType::Palette),
+        (FONT_ICON_ID, AssetType::Icon),
+        (EYE_ICON_ID, AssetType::Icon),
+    ];
+    let mut asset_data_store = HashMap::new();
+    for (asset_id_str, _) in assets.iter() {
+        let path = format!("{}/{}", EMBEDDED_DIR, asset_id_str);
+        let bytes = std::fs::read(&path).unwrap_or_else(|error| panic!("{}: {}", path, error));
+        asset_data_store.insert(AssetId::from_str(asset_id_str).unwrap(), bytes);
+    }
+    {
+        let mut asset_manager = app.world_mut().resource_mut::<AssetManager>();
+        for (asset_id_str, asset_type) in assets.iter() {
+            let asset_id = AssetId::from_str(asset_id_str).unwrap();
+            asset_manager.load(&asset_data_store, &asset_id, asset_type);
+        }
+    }
+
+    // moves the loaded assets into render storage, icon skins wait a frame for their palette's
+    // materials to be flushed
+    for _ in 0..3 {
+        app.update();
+    }
+
+    app
+}
+
+// a screen camera, lit the way the ui is
+fn draw_camera(app: &mut App) {
+    let mut camera_bundle = CameraBundle::new_2d(&Viewport::new_at_origin(SIZE, SIZE));
+    camera_bundle.camera.clear_operation = ClearOperation::from_rgba(0.0, 0.0, 0.0, 1.0);
+    let mut render_frame = app.world_mut().resource_mut::<RenderFrame>();
+    render_frame.draw_camera(
+        None,
+        &camera_bundle.camera,
+        &camera_bundle.transform,
+        &camera_bundle.projection,
+    );
+    render_frame.draw_ambient_light(None, &AmbientLight::new(1.0, Color::WHITE));
+}
+
+fn icon_handle(asset_id_str: &str) -> AssetHandle<IconData> {
+    AssetHandle::new(AssetId::from_str(asset_id_str).unwrap())
+}
+
+fn render(app: &mut App, draw: impl FnOnce(&AssetManager, &mut RenderFrame)) {
+    draw_camera(app);
+    app.world_mut()
+        .resource_scope(|world, asset_manager: Mut<AssetManager>| {
+            draw(&asset_manager, &mut world.resource_mut::<RenderFrame>());
+        });
+    app.update();
+}
+
+#[test]
+fn text() {
+    let mut app = headless_app();
+    let material = app
+        .world_mut()
+        .resource_mut::<Storage<CpuMaterial>>()
+        .add(Color::WHITE);
+
+    render(&mut app, |asset_manager, render_frame| {
+        let font = icon_handle(FONT_ICON_ID);
+        let mut transform = Transform::from_xyz(8.0, 16.0, 0.0);
+        transform.scale.x = 112.0;
+        transform.scale.y = 24.0;
+        asset_manager.draw_text(render_frame, None, &font, &material, &transform, "Hello");
+
+        let mut transform = Transform::from_xyz(8.0, 72.0, 0.0);
+        transform.scale.x = 112.0;
+        transform.scale.y = 16.0;
+        asset_manager.draw_text(
+            render_frame,
+            None,
+            &font,
+            &material,
+            &transform,
+            "0123456789",
+        );
+    });
+
+    let screen = app.world().resource::<HeadlessScreen>();
+    assert_golden_image(&screen.capture(), "tests/golden/text.png");
+}
+
+#[test]
+fn icon() {
+    let mut app = headless_app();
+
+    render(&mut app, |asset_manager, render_frame| {
+        let eye = icon_handle(EYE_ICON_ID);
+        for (subimage_index, x) in [(0, SIZE as f32 * 0.25), (1, SIZE as f32 * 0.75)] {
+            let transform =
+                Transform::from_xyz(x, SIZE as f32 * 0.5, 0.0).with_scale(Vec3::new(0.5, 0.5, 1.0));
+            asset_manager.draw_icon(render_frame, &eye, subimage_index, &transform, None);
+        }
+    });
+
+    let screen = app.world().resource::<HeadlessScreen>();
+    assert_golden_image(&screen.capture(), "tests/golden/icon.png");
+}
@@ -19,18 +19,50 @@ use naia_serde::{SerdeInternal as Serde, UnsignedVariableInteger};
 
 use crate::bits::common::SerdeQuat;
 
+// Easing
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serde)]
+pub enum Easing {
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    // hold the frame's pose until the next one
+    Step,
+}
+
+impl Easing {
+    // maps linear progress through a transition (0.0 to 1.0) onto the eased progress
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t,
+            Self::EaseOut => t * (2.0 - t),
+            Self::EaseInOut => t * t * (3.0 - 2.0 * t),
+            Self::Step => {
+                if t < 1.0 {
+                    0.0
+                } else {
+                    1.0
+                }
+            }
+        }
+    }
+}
+
 // Transition
 #[derive(Clone, PartialEq, Serde)]
 pub struct Transition {
     duration_5ms: UnsignedVariableInteger<7>,
-    //pub easing: Easing,
+    easing: Easing,
 }
 
 impl Transition {
-    pub fn new(duration_ms: u16) -> Self {
+    pub fn new(duration_ms: u16, easing: Easing) -> Self {
         let duration_5ms = duration_ms / 5;
         Self {
             duration_5ms: duration_5ms.into(),
+            easing,
         }
     }
 
@@ -38,6 +70,10 @@ impl Transition {
         let duration_5ms: u16 = self.duration_5ms.to();
         duration_5ms * 5
     }
+
+    pub fn get_easing(&self) -> Easing {
+        self.easing
+    }
 }
 
 // Actions
@@ -46,8 +82,14 @@ pub enum AnimAction {
     SkelFile(AssetId),
     // shape name -> shape_index
     ShapeIndex(String),
-    // shape_index -> rotation
-    Frame(HashMap<u16, SerdeQuat>, Transition),
+    // shape_index -> rotation, transition to the next frame, events fired on reaching the frame
+    Frame(HashMap<u16, SerdeQuat>, Transition, Vec<String>),
+}
+
+impl AnimAction {
+    // written first, so animations processed before frames carried an easing & events fail to
+    // read rather than read as garbage. bump it whenever the layout changes
+    pub const FORMAT_VERSION: u8 = 1;
 }
 
 #[derive(Serde, Clone, PartialEq)]
@@ -57,3 +99,71 @@ pub enum AnimActionType {
     Frame,
     None,
 }
+
+#[cfg(all(test, feature = "read_bits", feature = "write_bits"))]
+mod tests {
+    use std::collections::HashMap;
+
+    use asset_id::AssetId;
+
+    use crate::bits::common::SerdeQuat;
+
+    use super::{AnimAction, Easing, Transition};
+
+    fn actions() -> Vec<AnimAction> {
+        let mut poses = HashMap::new();
+        poses.insert(0, SerdeQuat::from_xyzw(0.0, 0.0, 0.0, 1.0));
+        poses.insert(3, SerdeQuat::from_xyzw(0.5, -0.5, 0.5, 0.5));
+        vec![
+            AnimAction::SkelFile(AssetId::from_str("8273wa").unwrap()),
+            AnimAction::ShapeIndex("left_leg".to_string()),
+            AnimAction::Frame(
+                poses,
+                Transition::new(250, Easing::EaseInOut),
+                vec!["footstep".to_string()],
+            ),
+            AnimAction::Frame(
+                HashMap::new(),
+                Transition::new(100, Easing::Step),
+                Vec::new(),
+            ),
+        ]
+    }
+
+    #[test]
+    fn roundtrip() {
+        let bytes = AnimAction::write(actions());
+        let read = AnimAction::read(&bytes).unwrap();
+
+        assert_eq!(read.len(), 4);
+        let AnimAction::SkelFile(asset_id) = &read[0] else {
+            panic!("expected the skeleton file first");
+        };
+        assert_eq!(*asset_id, AssetId::from_str("8273wa").unwrap());
+        let AnimAction::ShapeIndex(name) = &read[1] else {
+            panic!("expected a shape index second");
+        };
+        assert_eq!(name, "left_leg");
+        let AnimAction::Frame(poses, transition, events) = &read[2] else {
+            panic!("expected a frame third");
+        };
+        assert_eq!(poses.len(), 2);
+        assert!(poses.get(&3).unwrap() == &SerdeQuat::from_xyzw(0.5, -0.5, 0.5, 0.5));
+        assert!(*transition == Transition::new(250, Easing::EaseInOut));
+        assert_eq!(events, &vec!["footstep".to_string()]);
+        let AnimAction::Frame(poses, transition, events) = &read[3] else {
+            panic!("expected a frame fourth");
+        };
+        assert!(poses.is_empty());
+        assert_eq!(transition.get_easing(), Easing::Step);
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn other_versions_are_rejected() {
+        let mut bytes = AnimAction::write(actions());
+        bytes[0] = AnimAction::FORMAT_VERSION + 1;
+
+        assert!(AnimAction::read(&bytes).is_err());
+    }
+}
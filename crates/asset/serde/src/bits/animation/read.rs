@@ -14,6 +14,11 @@ impl AnimAction {
         let bit_reader = &mut bit_reader;
         let mut actions = Vec::new();
 
+        let version = u8::de(bit_reader)?;
+        if version != Self::FORMAT_VERSION {
+            return Err(SerdeErr);
+        }
+
         loop {
             let action_type = AnimActionType::de(bit_reader)?;
 
@@ -39,7 +44,8 @@ impl AnimAction {
                         let pose_quat = SerdeQuat::de(bit_reader)?;
                         poses.insert(shape_index, pose_quat);
                     }
-                    actions.push(Self::Frame(poses, transition));
+                    let events = Vec::<String>::de(bit_reader)?;
+                    actions.push(Self::Frame(poses, transition, events));
                 }
                 AnimActionType::None => {
                     break;
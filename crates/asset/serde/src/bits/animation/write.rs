@@ -6,6 +6,8 @@ impl AnimAction {
     pub fn write(actions: Vec<Self>) -> Box<[u8]> {
         let mut bit_writer = FileBitWriter::new();
 
+        Self::FORMAT_VERSION.ser(&mut bit_writer);
+
         for action in actions {
             match action {
                 Self::SkelFile(asset_id) => {
@@ -16,7 +18,7 @@ impl AnimAction {
                     AnimActionType::ShapeIndex.ser(&mut bit_writer);
                     name.ser(&mut bit_writer);
                 }
-                Self::Frame(poses, transition) => {
+                Self::Frame(poses, transition, events) => {
                     AnimActionType::Frame.ser(&mut bit_writer);
                     transition.ser(&mut bit_writer);
                     for (shape_index, pose_quat) in poses {
@@ -28,6 +30,8 @@ impl AnimAction {
                     }
                     // continue bit
                     false.ser(&mut bit_writer);
+
+                    events.ser(&mut bit_writer);
                 }
             }
         }
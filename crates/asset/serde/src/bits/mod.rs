@@ -12,7 +12,7 @@ pub use metadata::AssetMetadataSerde;
 //
 
 mod animation;
-pub use animation::{AnimAction, Transition};
+pub use animation::{AnimAction, Easing, Transition};
 
 mod icon;
 pub use icon::{IconAction, IconFrameAction};
@@ -70,10 +70,27 @@ impl AnimFilePose {
     }
 }
 
+#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum AnimFileEasing {
+    // also for files written before easing existed, which have none
+    #[default]
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    Step,
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct AnimFileFrame {
     poses: Vec<AnimFilePose>,
     transition_ms: u16,
+    #[serde(default)]
+    easing: AnimFileEasing,
+    // fired when playback reaches this frame, e.g. "footstep"
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    events: Vec<String>,
 }
 
 impl AnimFileFrame {
@@ -81,6 +98,8 @@ impl AnimFileFrame {
         Self {
             poses: Vec::new(),
             transition_ms,
+            easing: AnimFileEasing::Linear,
+            events: Vec::new(),
         }
     }
 
@@ -105,6 +124,22 @@ impl AnimFileFrame {
     pub fn get_transition_ms(&self) -> u16 {
         self.transition_ms
     }
+
+    pub fn get_easing(&self) -> AnimFileEasing {
+        self.easing
+    }
+
+    pub fn set_easing(&mut self, easing: AnimFileEasing) {
+        self.easing = easing;
+    }
+
+    pub fn get_events(&self) -> &Vec<String> {
+        &self.events
+    }
+
+    pub fn add_event(&mut self, name: &str) {
+        self.events.push(name.to_string());
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -15,11 +15,12 @@ cfg_if! {
 
 use asset_id::AssetId;
 use serde::{Deserialize, Serialize};
-use spec_serde::json::{AnimatedModelJson, MovementConfigJson, UnitJson};
+use spec_serde::json::{AnimatedModelJson, MovementConfigJson, ParticleEffectJson, UnitJson};
 
 use crate::json::{
     animation::AnimationJson, icon::IconJson, mesh::MeshJson, model::ModelJson,
-    palette::PaletteJson, scene::SceneJson, skeleton::SkeletonJson, skin::SkinJson, UiConfigJson,
+    palette::PaletteJson, scene::SceneJson, skeleton::SkeletonJson, skin::SkinJson,
+    texture::TextureJson, UiConfigJson,
 };
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -81,6 +82,8 @@ pub enum AssetData {
     AnimatedModel(AnimatedModelJson),
     MovementConfig(MovementConfigJson),
     Unit(UnitJson),
+    ParticleEffect(ParticleEffectJson),
+    Texture(TextureJson),
 }
 
 impl AssetData {
@@ -98,6 +101,8 @@ impl AssetData {
             Self::AnimatedModel(_) => "animated_model",
             Self::MovementConfig(_) => "movement_config",
             Self::Unit(_) => "unit",
+            Self::ParticleEffect(_) => "particle_effect",
+            Self::Texture(_) => "texture",
         }
         .to_string()
     }
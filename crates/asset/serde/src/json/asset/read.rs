@@ -24,6 +24,9 @@ pub struct ProcessedAssetMeta {
     asset_id: String,
     etag: String,
     schema_version: u32,
+    // the layout of the processed bits, missing from metas written before it was versioned
+    #[serde(default)]
+    format_version: u8,
     dependencies: Vec<String>,
     hash: Vec<u8>,
 }
@@ -33,6 +36,7 @@ impl ProcessedAssetMeta {
         asset_id: AssetId,
         etag: ETag,
         schema_version: u32,
+        format_version: u8,
         dependencies: Vec<AssetId>,
         hash: Vec<u8>,
     ) -> Self {
@@ -41,6 +45,7 @@ impl ProcessedAssetMeta {
             asset_id: asset_id.as_string(),
             etag: etag.as_string(),
             schema_version,
+            format_version,
             dependencies,
             hash,
         }
@@ -50,6 +55,10 @@ impl ProcessedAssetMeta {
         AssetId::from_str(&self.asset_id).unwrap()
     }
 
+    pub fn format_version(&self) -> u8 {
+        self.format_version
+    }
+
     pub fn hash(&self) -> &[u8] {
         &self.hash
     }
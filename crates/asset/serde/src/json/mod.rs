@@ -20,7 +20,7 @@ pub const MAX_SCALE: f32 = 100.0;
 //
 
 mod animation;
-pub use animation::{AnimFileFrame, AnimFileQuat, AnimationJson};
+pub use animation::{AnimFileEasing, AnimFileFrame, AnimFileQuat, AnimationJson};
 
 mod icon;
 pub use icon::{IconFileFrame, IconJson};
@@ -42,3 +42,6 @@ pub use skeleton::SkeletonJson;
 
 mod skin;
 pub use skin::SkinJson;
+
+mod texture;
+pub use texture::TextureJson;
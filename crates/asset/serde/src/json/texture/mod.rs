@@ -0,0 +1,47 @@
+use cfg_if::cfg_if;
+
+use asset_id::AssetId;
+use base64::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::error::AssetIoError;
+
+cfg_if! {
+    if #[cfg(feature = "read_json")] {
+        mod read;
+    } else {}
+}
+
+cfg_if! {
+    if #[cfg(feature = "write_json")] {
+        mod write;
+    } else {}
+}
+
+// Texture
+
+// The png file, base64 encoded. It is processed as-is, the loader decodes it.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct TextureJson {
+    png: String,
+}
+
+impl TextureJson {
+    pub const CURRENT_SCHEMA_VERSION: u32 = 0;
+
+    pub fn new(png_bytes: &[u8]) -> Self {
+        Self {
+            png: BASE64_STANDARD.encode(png_bytes),
+        }
+    }
+
+    pub fn dependencies(&self) -> Vec<AssetId> {
+        Vec::new()
+    }
+
+    pub fn get_png_bytes(&self) -> Result<Vec<u8>, AssetIoError> {
+        BASE64_STANDARD
+            .decode(&self.png)
+            .map_err(|err| AssetIoError::Message(format!("invalid texture png: {}", err)))
+    }
+}
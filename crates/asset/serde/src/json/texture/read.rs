@@ -0,0 +1,14 @@
+use crate::{
+    error::AssetIoError,
+    json::{Asset, AssetData, AssetMeta, TextureJson},
+};
+
+impl TextureJson {
+    pub fn read(bytes: &[u8]) -> Result<(AssetMeta, Self), AssetIoError> {
+        let (meta, data) = Asset::read(bytes)?.deconstruct();
+        let AssetData::Texture(data) = data else {
+            return Err(AssetIoError::Message("Invalid Asset Type".to_string()));
+        };
+        Ok((meta, data))
+    }
+}
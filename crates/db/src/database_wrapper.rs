@@ -7,17 +7,31 @@ use crate::{
 
 pub struct DatabaseWrapper {
     tables: HashMap<TypeId, Box<dyn Table>>,
+    in_memory: bool,
 }
 
 impl DatabaseWrapper {
     pub fn init() -> Self {
         Self {
             tables: HashMap::new(),
+            in_memory: false,
+        }
+    }
+
+    // tables start empty and are never pulled from or pushed to their repos
+    pub fn init_in_memory() -> Self {
+        Self {
+            tables: HashMap::new(),
+            in_memory: true,
         }
     }
 
     pub fn table_open<K: DbTableKey>(&mut self) {
-        let table_impl = TableImpl::<K>::init();
+        let table_impl = if self.in_memory {
+            TableImpl::<K>::init_in_memory()
+        } else {
+            TableImpl::<K>::init()
+        };
         self.tables.insert(TypeId::of::<K>(), Box::new(table_impl));
     }
 
@@ -22,7 +22,8 @@ pub trait Table: Send + Sync {
 // TableImpl
 pub struct TableImpl<K: DbTableKey> {
     root_path: String,
-    repo: Arc<Mutex<Repository>>,
+    // None when the table lives only in memory, and is never synced to a repo
+    repo: Option<Arc<Mutex<Repository>>>,
 
     next_id: u64,
     next_key_has_changed: bool,
@@ -72,13 +73,23 @@ impl<K: DbTableKey> TableImpl<K> {
 
         Self {
             root_path,
-            repo: Arc::new(Mutex::new(git_repo)),
+            repo: Some(Arc::new(Mutex::new(git_repo))),
             next_id,
             next_key_has_changed: false,
             store,
         }
     }
 
+    pub fn init_in_memory() -> Self {
+        Self {
+            root_path: K::repo_name().to_string(),
+            repo: None,
+            next_id: 0,
+            next_key_has_changed: false,
+            store: HashMap::new(),
+        }
+    }
+
     pub fn insert(&mut self, mut value: K::Value) -> Result<K::Key, DbError> {
         // get next key
         let key = self.get_next_key();
@@ -91,8 +102,8 @@ impl<K: DbTableKey> TableImpl<K> {
         self.store.insert(key, value.clone());
 
         // upload to database
-        {
-            let repo = self.repo.lock().unwrap();
+        if let Some(repo) = &self.repo {
+            let repo = repo.lock().unwrap();
             create_new_file::<K>(&self.root_path, &repo, value);
         }
 
@@ -118,9 +129,9 @@ impl<K: DbTableKey> TableImpl<K> {
         }
 
         // upload to database
-        {
+        if let Some(repo) = &self.repo {
             let item_ref = self.store.get(key).unwrap();
-            let repo = self.repo.lock().unwrap();
+            let repo = repo.lock().unwrap();
             update_file::<K>(&self.root_path, &repo, item_ref);
         }
     }
@@ -145,7 +156,10 @@ impl<K: DbTableKey> TableImpl<K> {
         }
         self.next_key_has_changed = false;
 
-        let repo = self.repo.lock().unwrap();
+        let Some(repo) = &self.repo else {
+            return;
+        };
+        let repo = repo.lock().unwrap();
         update_nextid(&self.root_path, &repo, self.next_id);
     }
 }
@@ -8,6 +8,9 @@ mod smtp_alias {
 
 pub use smtp_alias::{SmtpError, SmtpResponse};
 
+mod mailer;
+pub use mailer::{Mailer, Outbox, SentEmail};
+
 pub fn send(
     sender_email: &str,
     recipient_email: &str,
@@ -0,0 +1,77 @@
+use std::sync::{Arc, Mutex};
+
+use crate::{send, SmtpError};
+
+// where outgoing emails go: out over SMTP, or into an in-memory outbox for tests
+#[derive(Clone)]
+pub enum Mailer {
+    Smtp,
+    InMemory(Outbox),
+}
+
+impl Mailer {
+    pub fn send(
+        &self,
+        sender_email: &str,
+        recipient_email: &str,
+        subject: &str,
+        text_content: &str,
+        html_content: &str,
+    ) -> Result<(), SmtpError> {
+        match self {
+            Self::Smtp => send(
+                sender_email,
+                recipient_email,
+                subject,
+                text_content,
+                html_content,
+            )
+            .map(|_response| ()),
+            Self::InMemory(outbox) => {
+                outbox.push(SentEmail {
+                    sender_email: sender_email.to_string(),
+                    recipient_email: recipient_email.to_string(),
+                    subject: subject.to_string(),
+                    text_content: text_content.to_string(),
+                    html_content: html_content.to_string(),
+                });
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SentEmail {
+    pub sender_email: String,
+    pub recipient_email: String,
+    pub subject: String,
+    pub text_content: String,
+    pub html_content: String,
+}
+
+#[derive(Clone, Default)]
+pub struct Outbox {
+    sent: Arc<Mutex<Vec<SentEmail>>>,
+}
+
+impl Outbox {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn push(&self, email: SentEmail) {
+        self.sent.lock().unwrap().push(email);
+    }
+
+    // every email sent so far to the recipient, oldest first
+    pub fn sent_to(&self, recipient_email: &str) -> Vec<SentEmail> {
+        self.sent
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|email| email.recipient_email == recipient_email)
+            .cloned()
+            .collect()
+    }
+}
@@ -4,6 +4,7 @@ extern crate cfg_if;
 mod plugin;
 pub use plugin::EnginePlugin;
 
+mod picker;
 mod renderer;
 
 pub mod kernel {
@@ -13,8 +14,9 @@ pub mod asset {
     pub use asset_cache::AssetLoadedEvent;
     pub use asset_id::{AssetId, AssetType, ETag};
     pub use asset_loader::{
-        embedded_asset_event, AnimatedModelData, AnimationData, AssetHandle, AssetManager,
-        AssetMetadataSerde, EmbeddedAssetEvent, IconData, MeshData, ModelData, PaletteData,
+        embedded_asset_event, AnimatedModelData, AnimationData, AnimationGraph, AnimationLayerMode,
+        AnimationLayerPose, AnimationPlayback, AssetHandle, AssetManager, AssetMetadataSerde,
+        EmbeddedAssetEvent, IconData, MeshData, ModelData, MovementConfigData, PaletteData,
         SceneData, SkeletonData, SkinData, UnitData,
     };
     pub use asset_render::AssetRender;
@@ -31,12 +33,23 @@ pub mod math {
 pub mod render {
     pub use render_api::*;
 }
+pub mod picking {
+    pub use crate::picker::Picker;
+    pub use render_api::{base::Ray, resources::PickHit};
+}
+#[cfg(feature = "software_renderer")]
+pub mod headless {
+    pub use render_software::{golden_image, Framebuffer, HeadlessScreen};
+}
 pub mod config {
     pub use config::*;
 }
 pub mod storage {
     pub use storage::*;
 }
+pub mod filesystem {
+    pub use filesystem::{FileSystemManager, ReadResult, TaskError, TaskKey};
+}
 pub mod ui {
     pub use ui_runner::{state::NodeActiveState, UiHandle, UiManager};
 
@@ -0,0 +1,70 @@
+use bevy_ecs::{
+    entity::Entity,
+    system::{Query, Res, SystemParam},
+};
+
+use input::Input;
+use math::Vec2;
+use render_api::{
+    base::{CpuMesh, Ray},
+    components::{Camera, Projection, RenderLayer, Transform},
+    resources::{PickHit, RenderFrame},
+};
+use storage::Storage;
+
+///
+/// Finds which entity is under the mouse, or any other point on screen.
+/// Only meshes drawn inside [RenderFrame::draw_pickable] on the camera's [RenderLayer] can be hit.
+///
+/// Reads every camera's [Transform], so systems using it alongside a `Query<&mut Transform>` need `Without<Camera>` on that query.
+///
+#[derive(SystemParam)]
+pub struct Picker<'w, 's> {
+    input: Res<'w, Input>,
+    render_frame: Res<'w, RenderFrame>,
+    meshes: Res<'w, Storage<CpuMesh>>,
+    cameras_q: Query<
+        'w,
+        's,
+        (
+            &'static Camera,
+            &'static Transform,
+            &'static Projection,
+            Option<&'static RenderLayer>,
+        ),
+    >,
+}
+
+impl Picker<'_, '_> {
+    pub fn pick_under_mouse(&self, camera_entity: Entity) -> Option<PickHit> {
+        self.pick(camera_entity, *self.input.mouse_position())
+    }
+
+    pub fn pick(&self, camera_entity: Entity, screen_position: Vec2) -> Option<PickHit> {
+        let ray = self.ray(camera_entity, screen_position)?;
+        let (_, _, _, render_layer_opt) = self.cameras_q.get(camera_entity).ok()?;
+        self.render_frame.pick(render_layer_opt, &ray, &self.meshes)
+    }
+
+    ///
+    /// The ray from the camera through `screen_position`, which is in the same pixels as [Input::mouse_position].
+    /// None if the camera is inactive or the position is outside its viewport.
+    ///
+    pub fn ray(&self, camera_entity: Entity, screen_position: Vec2) -> Option<Ray> {
+        let Ok((camera, transform, projection, _)) = self.cameras_q.get(camera_entity) else {
+            return None;
+        };
+        if !camera.is_active {
+            return None;
+        }
+        let viewport = camera.viewport_or_default();
+        let position = screen_position - viewport.position_vec2();
+        let size = viewport.size_vec2();
+        if position.x < 0.0 || position.y < 0.0 || position.x >= size.x || position.y >= size.y {
+            return None;
+        }
+        Some(Ray::from_screen_position(
+            camera, transform, projection, position,
+        ))
+    }
+}
@@ -1,14 +1,18 @@
 // Renderer
 cfg_if! {
-    if #[cfg(all(feature = "gl_renderer", feature = "wgpu_renderer"))]
+    if #[cfg(any(
+        all(feature = "gl_renderer", feature = "wgpu_renderer"),
+        all(feature = "gl_renderer", feature = "software_renderer"),
+        all(feature = "wgpu_renderer", feature = "software_renderer"),
+    ))]
     {
-        // Use both renderer...
-        compile_error!("Requires either 'gl_renderer' or 'wgpu_renderer' feature, you must pick one.");
+        // Use more than one renderer...
+        compile_error!("Requires one of 'gl_renderer', 'wgpu_renderer' or 'software_renderer' features, you must pick one.");
     }
-    else if #[cfg(all(not(feature = "gl_renderer"), not(feature = "wgpu_renderer")))]
+    else if #[cfg(all(not(feature = "gl_renderer"), not(feature = "wgpu_renderer"), not(feature = "software_renderer")))]
     {
-        // Use no protocols...
-        compile_error!("Requires either 'gl_renderer' or 'wgpu_renderer' feature, you must pick one.");
+        // Use no renderer...
+        compile_error!("Requires one of 'gl_renderer', 'wgpu_renderer' or 'software_renderer' features, you must pick one.");
     }
 }
 
@@ -25,3 +29,10 @@ cfg_if! {
         pub use wgpu_renderer::RendererPlugin;
     }
 }
+
+cfg_if! {
+    if #[cfg(feature = "software_renderer")] {
+        mod software_renderer;
+        pub use software_renderer::RendererPlugin;
+    }
+}
@@ -0,0 +1,11 @@
+use bevy_app::{App, Plugin};
+
+use render_software::RenderSoftwarePlugin;
+
+pub struct RendererPlugin;
+
+impl Plugin for RendererPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(RenderSoftwarePlugin::default());
+    }
+}
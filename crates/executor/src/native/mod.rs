@@ -11,5 +11,6 @@ pub mod smol {
     pub use async_io::{block_on, Async, Timer};
     pub use async_lock as lock;
     pub use async_net as net;
+    pub use blocking::unblock;
     pub use futures_lite::{future, io, stream};
 }
@@ -4,7 +4,7 @@ use executor::smol::{
     io::{AsyncReadExt, AsyncWriteExt},
     net::TcpStream,
 };
-use url::Url;
+use url::{Position, Url};
 
 use http_common::{Request, RequestOptions, Response, ResponseError};
 
@@ -34,7 +34,12 @@ async fn fetch_async_inner(request: Request) -> Result<Response, ResponseError>
 
     let mut tcp_stream = connect(&url).await?;
 
-    let mut request_string = format!("{} {} HTTP/1.1\r\n", request.method.as_str(), url.path(),);
+    // path & query string, ie. everything after the host
+    let mut request_string = format!(
+        "{} {} HTTP/1.1\r\n",
+        request.method.as_str(),
+        &url[Position::BeforePath..]
+    );
 
     // Add the Host header
     if let Some(host) = url.host_str() {
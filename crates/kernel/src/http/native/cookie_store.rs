@@ -22,6 +22,11 @@ impl CookieStore {
         Self::new_impl("cookies")
     }
 
+    // for running several logged-in clients in one process, each persisting to its own directory
+    pub fn in_dir(dir: &str) -> Self {
+        Self::new_impl(dir)
+    }
+
     #[allow(dead_code)]
     pub(crate) fn test() -> Self {
         Self::new_impl("cookies_test")
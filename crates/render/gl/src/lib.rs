@@ -84,6 +84,7 @@ pub enum CompressedPixelUnpackData<'a> {
 }
 
 // these fields are ordered according to spec: https://docs.gl/gl4/glMultiDrawArraysIndirect
+#[derive(Clone, Copy)]
 pub struct DrawArraysIndirectCommand {
     // vertex count
     count: u32,
@@ -1,25 +1,42 @@
 use std::hash::{Hash, Hasher};
 
-use storage::StorageHash;
+use storage::{Handle, StorageHash};
 
-use crate::base::Color;
+use crate::base::{Color, CpuTexture2D};
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct CpuMaterial {
     pub diffuse: Color,
     pub emissive: f32,
     // see https://learnopengl.com/Lighting/Basic-Lighting ... this value is inversed. larger values = smaller shine
     pub shine_size: f32,
     pub shine_amount: f32,
+    // multiplied with `diffuse`, sampled with the mesh's uvs
+    pub texture: Option<Handle<CpuTexture2D>>,
 }
 
 impl StorageHash<CpuMaterial> for CpuMaterial {}
 
+impl std::fmt::Debug for CpuMaterial {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut d = f.debug_struct("CpuMaterial");
+        d.field("diffuse", &self.diffuse);
+        d.field("emissive", &self.emissive);
+        d.field("shine_size", &self.shine_size);
+        d.field("shine_amount", &self.shine_amount);
+        if let Some(texture) = &self.texture {
+            d.field("texture", &texture.id);
+        }
+        d.finish()
+    }
+}
+
 impl Hash for CpuMaterial {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.diffuse.hash(state);
         self.emissive.to_bits().hash(state);
         self.shine_size.to_bits().hash(state);
+        self.texture.hash(state);
     }
 }
 
@@ -30,6 +47,7 @@ impl Default for CpuMaterial {
             emissive: 0.0,
             shine_size: 32.0,
             shine_amount: 0.5,
+            texture: None,
         }
     }
 }
@@ -41,8 +59,14 @@ impl CpuMaterial {
             emissive,
             shine_size,
             shine_amount,
+            texture: None,
         }
     }
+
+    pub fn with_texture(mut self, texture: Handle<CpuTexture2D>) -> Self {
+        self.texture = Some(texture);
+        self
+    }
 }
 
 // impl From<Color> for CpuMaterial {
@@ -1,4 +1,4 @@
-use math::Vec3;
+use math::{Vec2, Vec3};
 
 use crate::base::AxisAlignedBoundingBox;
 
@@ -12,6 +12,10 @@ pub struct CpuMesh {
     vertices: Vec<Vec3>,
     /// Face indices
     face_indices: Option<Vec<u16>>,
+    /// Texture coordinates, one per vertex.
+    uvs: Option<Vec<Vec2>>,
+    /// Vertex normals, one per vertex. Meshes without them are lit per face.
+    normals: Option<Vec<Vec3>>,
 }
 
 impl Default for CpuMesh {
@@ -19,6 +23,8 @@ impl Default for CpuMesh {
         Self {
             vertices: Vec::new(),
             face_indices: None,
+            uvs: None,
+            normals: None,
         }
     }
 }
@@ -30,6 +36,12 @@ impl std::fmt::Debug for CpuMesh {
         if let Some(face_indices) = &self.face_indices {
             d.field("face_indices", &face_indices.len());
         }
+        if let Some(uvs) = &self.uvs {
+            d.field("uvs", &uvs.len());
+        }
+        if let Some(normals) = &self.normals {
+            d.field("normals", &normals.len());
+        }
         d.finish()
     }
 }
@@ -39,6 +51,8 @@ impl CpuMesh {
         Self {
             vertices,
             face_indices: None,
+            uvs: None,
+            normals: None,
         }
     }
 
@@ -54,6 +68,38 @@ impl CpuMesh {
         self.face_indices = Some(face_indices);
     }
 
+    pub fn add_uvs(&mut self, uvs: Vec<Vec2>) {
+        assert_eq!(uvs.len(), self.vertices.len(), "expected one uv per vertex");
+        self.uvs = Some(uvs);
+    }
+
+    pub fn add_normals(&mut self, normals: Vec<Vec3>) {
+        assert_eq!(
+            normals.len(),
+            self.vertices.len(),
+            "expected one normal per vertex"
+        );
+        self.normals = Some(normals);
+    }
+
+    pub fn with_uvs(mut self, uvs: Vec<Vec2>) -> Self {
+        self.add_uvs(uvs);
+        self
+    }
+
+    pub fn with_normals(mut self, normals: Vec<Vec3>) -> Self {
+        self.add_normals(normals);
+        self
+    }
+
+    pub fn has_uvs(&self) -> bool {
+        self.uvs.is_some()
+    }
+
+    pub fn has_normals(&self) -> bool {
+        self.normals.is_some()
+    }
+
     pub fn is_skinnable(&self) -> bool {
         self.face_indices.is_some()
     }
@@ -74,6 +120,24 @@ impl CpuMesh {
         }
     }
 
+    /// The texture coordinates of each vertex, `(0, 0)` for all of them if the mesh has none.
+    pub fn uvs(&self) -> Vec<Vec2> {
+        if let Some(uvs) = &self.uvs {
+            uvs.clone()
+        } else {
+            vec![Vec2::ZERO; self.vertices.len()]
+        }
+    }
+
+    /// The normal of each vertex, or each face's normal for its vertices if the mesh has none.
+    pub fn normals(&self) -> Vec<Vec3> {
+        if let Some(normals) = &self.normals {
+            normals.clone()
+        } else {
+            self.compute_normals()
+        }
+    }
+
     /// Returns the number of vertices in this mesh.
     pub fn vertex_count(&self) -> usize {
         self.vertices.len()
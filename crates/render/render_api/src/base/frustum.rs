@@ -0,0 +1,153 @@
+use math::{Mat4, Vec3, Vec4};
+
+use crate::{
+    base::AxisAlignedBoundingBox,
+    components::{Camera, CameraProjection, Projection, Transform},
+};
+
+///
+/// The volume a camera can see, used to skip meshes that can't end up on screen.
+///
+#[derive(Debug, Copy, Clone)]
+pub struct Frustum {
+    // left, right, bottom, top, near, far. A point is inside when `plane.dot(point.extend(1.0)) >= 0`
+    planes: [Vec4; 6],
+    camera_position: Vec3,
+    // world units spanned by the viewport's height at distance 1 (perspective), or overall (orthographic)
+    view_height: f32,
+    is_perspective: bool,
+}
+
+impl Frustum {
+    pub fn new(camera: &Camera, transform: &Transform, projection: &Projection) -> Self {
+        let viewport = camera.viewport_or_default();
+        let view_projection = projection.projection_matrix(&viewport) * transform.view_matrix();
+        let (view_height, is_perspective) = match projection {
+            Projection::Perspective(perspective) => ((perspective.fov * 0.5).tan() * 2.0, true),
+            Projection::Orthographic(_) => (viewport.height as f32, false),
+        };
+
+        let mut frustum = Self::from_view_projection(&view_projection);
+        frustum.camera_position = transform.translation;
+        frustum.view_height = view_height;
+        frustum.is_perspective = is_perspective;
+        frustum
+    }
+
+    ///
+    /// Extracts the frustum planes from a view-projection matrix with a 0..1 depth range.
+    ///
+    pub fn from_view_projection(view_projection: &Mat4) -> Self {
+        let row_0 = view_projection.row(0);
+        let row_1 = view_projection.row(1);
+        let row_2 = view_projection.row(2);
+        let row_3 = view_projection.row(3);
+
+        let mut planes = [
+            row_3 + row_0,
+            row_3 - row_0,
+            row_3 + row_1,
+            row_3 - row_1,
+            row_2,
+            row_3 - row_2,
+        ];
+        for plane in planes.iter_mut() {
+            let length = plane.truncate().length();
+            if length > 0.0 {
+                *plane /= length;
+            }
+        }
+
+        Self {
+            planes,
+            camera_position: Vec3::ZERO,
+            view_height: 1.0,
+            is_perspective: false,
+        }
+    }
+
+    ///
+    /// Returns false only if the bounding box lies entirely outside one of the frustum's planes.
+    ///
+    pub fn intersects_aabb(&self, aabb: &AxisAlignedBoundingBox) -> bool {
+        if aabb.is_empty() {
+            return false;
+        }
+        if aabb.is_infinite() {
+            return true;
+        }
+        let min = aabb.min();
+        let max = aabb.max();
+        for plane in &self.planes {
+            // the corner furthest along the plane's normal
+            let corner = Vec3::new(
+                if plane.x >= 0.0 { max.x } else { min.x },
+                if plane.y >= 0.0 { max.y } else { min.y },
+                if plane.z >= 0.0 { max.z } else { min.z },
+            );
+            if plane.dot(corner.extend(1.0)) < 0.0 {
+                return false;
+            }
+        }
+        true
+    }
+
+    ///
+    /// The bounding box's height on screen as a fraction of the viewport's height. Used to select LODs.
+    ///
+    pub fn screen_size(&self, aabb: &AxisAlignedBoundingBox) -> f32 {
+        let diameter = aabb.size().length();
+        let view_height = if self.is_perspective {
+            let distance = aabb.center().distance(self.camera_position);
+            distance * self.view_height
+        } else {
+            self.view_height
+        };
+        if view_height <= 0.0 {
+            return f32::INFINITY;
+        }
+        diameter / view_height
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use math::{Mat4, Vec3};
+
+    use crate::base::{AxisAlignedBoundingBox, Frustum};
+
+    fn unit_box_at(center: Vec3) -> AxisAlignedBoundingBox {
+        AxisAlignedBoundingBox::new_with_positions(&[
+            center - Vec3::splat(0.5),
+            center + Vec3::splat(0.5),
+        ])
+    }
+
+    fn looking_down_z() -> Frustum {
+        let projection = Mat4::perspective_lh(std::f32::consts::FRAC_PI_2, 1.0, 0.1, 100.0);
+        let view = Mat4::look_at_lh(Vec3::ZERO, Vec3::Z, Vec3::Y);
+        Frustum::from_view_projection(&(projection * view))
+    }
+
+    #[test]
+    fn keeps_boxes_in_view() {
+        let frustum = looking_down_z();
+
+        assert!(frustum.intersects_aabb(&unit_box_at(Vec3::new(0.0, 0.0, 10.0))));
+        // straddling the edge of the view
+        assert!(frustum.intersects_aabb(&unit_box_at(Vec3::new(10.4, 0.0, 10.0))));
+    }
+
+    #[test]
+    fn culls_boxes_out_of_view() {
+        let frustum = looking_down_z();
+
+        // behind the camera
+        assert!(!frustum.intersects_aabb(&unit_box_at(Vec3::new(0.0, 0.0, -10.0))));
+        // off to the side
+        assert!(!frustum.intersects_aabb(&unit_box_at(Vec3::new(20.0, 0.0, 10.0))));
+        // past the far plane
+        assert!(!frustum.intersects_aabb(&unit_box_at(Vec3::new(0.0, 0.0, 200.0))));
+        assert!(!frustum.intersects_aabb(&AxisAlignedBoundingBox::EMPTY));
+    }
+}
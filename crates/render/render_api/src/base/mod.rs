@@ -5,6 +5,8 @@ mod cpu_material;
 mod cpu_mesh;
 mod cpu_skin;
 mod error;
+mod frustum;
+mod ray;
 mod texture;
 
 pub use aabb::*;
@@ -14,4 +16,6 @@ pub use cpu_material::*;
 pub use cpu_mesh::*;
 pub use cpu_skin::*;
 pub use error::*;
+pub use frustum::*;
+pub use ray::*;
 pub use texture::*;
@@ -0,0 +1,171 @@
+use math::{Vec2, Vec3, Vec4};
+
+use crate::{
+    base::AxisAlignedBoundingBox,
+    components::{Camera, CameraProjection, Projection, Transform},
+};
+
+///
+/// A half-line through the world, used to find what lies under a point on screen.
+///
+#[derive(Debug, Copy, Clone)]
+pub struct Ray {
+    origin: Vec3,
+    // always normalized, so distances along the ray are in world units
+    direction: Vec3,
+}
+
+impl Ray {
+    pub fn new(origin: Vec3, direction: Vec3) -> Self {
+        Self {
+            origin,
+            direction: direction.normalize_or_zero(),
+        }
+    }
+
+    ///
+    /// The ray from the camera through `position`, given in pixels from the top-left of the camera's viewport.
+    /// It starts on the near plane, so nothing between the camera and the near plane is hit.
+    ///
+    pub fn from_screen_position(
+        camera: &Camera,
+        transform: &Transform,
+        projection: &Projection,
+        position: Vec2,
+    ) -> Self {
+        let viewport = camera.viewport_or_default();
+        let view_projection = projection.projection_matrix(&viewport) * transform.view_matrix();
+        let inverse = view_projection.inverse();
+
+        let ndc_x = (2.0 * position.x) / viewport.width as f32 - 1.0;
+        let ndc_y = 1.0 - (2.0 * position.y) / viewport.height as f32;
+
+        // depth runs 0..1 between the near & far planes
+        let near = inverse * Vec4::new(ndc_x, ndc_y, 0.0, 1.0);
+        let far = inverse * Vec4::new(ndc_x, ndc_y, 1.0, 1.0);
+        let near = near.truncate() / near.w;
+        let far = far.truncate() / far.w;
+
+        Self::new(near, far - near)
+    }
+
+    pub fn origin(&self) -> Vec3 {
+        self.origin
+    }
+
+    pub fn direction(&self) -> Vec3 {
+        self.direction
+    }
+
+    ///
+    /// The point `distance` world units along the ray.
+    ///
+    pub fn at(&self, distance: f32) -> Vec3 {
+        self.origin + self.direction * distance
+    }
+
+    ///
+    /// The distance along the ray at which it enters the bounding box, 0 if it starts inside.
+    ///
+    pub fn intersect_aabb(&self, aabb: &AxisAlignedBoundingBox) -> Option<f32> {
+        if aabb.is_empty() {
+            return None;
+        }
+        let inverse_direction = self.direction.recip();
+        let t_0 = (aabb.min() - self.origin) * inverse_direction;
+        let t_1 = (aabb.max() - self.origin) * inverse_direction;
+        let t_enter = t_0.min(t_1).max_element();
+        let t_exit = t_0.max(t_1).min_element();
+
+        if t_enter > t_exit || t_exit < 0.0 {
+            return None;
+        }
+        Some(t_enter.max(0.0))
+    }
+
+    ///
+    /// The distance along the ray at which it hits the triangle, from either side.
+    ///
+    pub fn intersect_triangle(&self, vertices: [Vec3; 3]) -> Option<f32> {
+        // Möller–Trumbore
+        let edge_1 = vertices[1] - vertices[0];
+        let edge_2 = vertices[2] - vertices[0];
+        let p = self.direction.cross(edge_2);
+        let determinant = edge_1.dot(p);
+        if determinant.abs() < f32::EPSILON {
+            // parallel to the triangle's plane
+            return None;
+        }
+        let inverse_determinant = 1.0 / determinant;
+
+        let to_origin = self.origin - vertices[0];
+        let u = to_origin.dot(p) * inverse_determinant;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+        let q = to_origin.cross(edge_1);
+        let v = self.direction.dot(q) * inverse_determinant;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+
+        let distance = edge_2.dot(q) * inverse_determinant;
+        if distance < 0.0 {
+            return None;
+        }
+        Some(distance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use math::{Vec2, Vec3};
+
+    use crate::{
+        base::{AxisAlignedBoundingBox, Ray},
+        components::{Camera, Projection, Transform, Viewport},
+    };
+
+    #[test]
+    fn screen_center_looks_forward() {
+        let camera = Camera {
+            viewport: Some(Viewport::new_at_origin(200, 100)),
+            ..Default::default()
+        };
+        let transform = Transform::from_xyz(0.0, 0.0, -10.0).looking_at(Vec3::ZERO, Vec3::Y);
+        let ray = Ray::from_screen_position(
+            &camera,
+            &transform,
+            &Projection::default(),
+            Vec2::new(100.0, 50.0),
+        );
+
+        assert!(ray.direction().abs_diff_eq(Vec3::Z, 0.0001));
+        assert!(ray.origin().x.abs() < 0.0001 && ray.origin().y.abs() < 0.0001);
+    }
+
+    #[test]
+    fn hits_boxes_and_triangles_in_front() {
+        let ray = Ray::new(Vec3::new(0.0, 0.0, -5.0), Vec3::Z);
+
+        let aabb = AxisAlignedBoundingBox::new_with_positions(&[Vec3::splat(-1.0), Vec3::ONE]);
+        assert_eq!(ray.intersect_aabb(&aabb), Some(4.0));
+        let behind = AxisAlignedBoundingBox::new_with_positions(&[
+            Vec3::new(-1.0, -1.0, -9.0),
+            Vec3::new(1.0, 1.0, -7.0),
+        ]);
+        assert_eq!(ray.intersect_aabb(&behind), None);
+
+        let triangle = [
+            Vec3::new(-1.0, -1.0, 2.0),
+            Vec3::new(1.0, -1.0, 2.0),
+            Vec3::new(0.0, 1.0, 2.0),
+        ];
+        assert_eq!(ray.intersect_triangle(triangle), Some(7.0));
+        // wound the other way, still hit
+        let flipped = [triangle[0], triangle[2], triangle[1]];
+        assert_eq!(ray.intersect_triangle(flipped), Some(7.0));
+        let beside = triangle.map(|vertex| vertex + Vec3::X * 3.0);
+        assert_eq!(ray.intersect_triangle(beside), None);
+    }
+}
@@ -30,10 +30,48 @@ impl CpuTexture2D {
         output
     }
 
+    /// Decodes a PNG into an [RgbaU8](CpuTextureData::RgbaU8) texture, top row first.
+    pub fn from_png(bytes: &[u8]) -> Result<Self, png::DecodingError> {
+        let mut decoder = png::Decoder::new(bytes);
+        decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+        let mut reader = decoder.read_info()?;
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buffer)?;
+        let bytes = &buffer[..info.buffer_size()];
+
+        let pixels = match info.color_type {
+            png::ColorType::Rgba => bytes
+                .chunks_exact(4)
+                .map(|p| [p[0], p[1], p[2], p[3]])
+                .collect(),
+            png::ColorType::Rgb => bytes
+                .chunks_exact(3)
+                .map(|p| [p[0], p[1], p[2], 255])
+                .collect(),
+            png::ColorType::GrayscaleAlpha => bytes
+                .chunks_exact(2)
+                .map(|p| [p[0], p[0], p[0], p[1]])
+                .collect(),
+            png::ColorType::Grayscale => bytes.iter().map(|v| [*v, *v, *v, 255]).collect(),
+            // expanded into rgb by the decoder
+            png::ColorType::Indexed => unreachable!(),
+        };
+
+        let mut texture = Self::from_size(info.width, info.height);
+        texture.set_data(CpuTextureData::RgbaU8(pixels));
+        Ok(texture)
+    }
+
     pub fn initial_data(&self) -> Option<&CpuTextureData> {
         self.initial_data.as_ref()
     }
 
+    // replaces the texture's pixels, e.g. with a frame read back from a renderer
+    pub fn set_data(&mut self, data: CpuTextureData) {
+        self.data_type = data.data_type();
+        self.initial_data = Some(data);
+    }
+
     pub fn data_type(&self) -> &CpuTextureDataType {
         &self.data_type
     }
@@ -58,3 +96,30 @@ impl Default for CpuTexture2D {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::base::{CpuTexture2D, CpuTextureData};
+
+    #[test]
+    fn from_png_expands_to_rgba() {
+        let mut bytes = Vec::new();
+        {
+            let mut encoder = png::Encoder::new(&mut bytes, 2, 1);
+            encoder.set_color(png::ColorType::Rgb);
+            encoder.set_depth(png::BitDepth::Eight);
+            let mut writer = encoder.write_header().unwrap();
+            writer.write_image_data(&[1, 2, 3, 4, 5, 6]).unwrap();
+        }
+
+        let texture = CpuTexture2D::from_png(&bytes).unwrap();
+        assert_eq!((texture.width(), texture.height()), (2, 1));
+        assert_eq!(
+            texture.initial_data(),
+            Some(&CpuTextureData::RgbaU8(vec![
+                [1, 2, 3, 255],
+                [4, 5, 6, 255]
+            ]))
+        );
+    }
+}
@@ -37,6 +37,17 @@ pub enum CpuTextureData {
     RgbaU8(Vec<[u8; 4]>),
 }
 
+impl CpuTextureData {
+    pub fn data_type(&self) -> CpuTextureDataType {
+        match self {
+            Self::RU8(_) => CpuTextureDataType::RU8,
+            Self::RgU8(_) => CpuTextureDataType::RgU8,
+            Self::RgbU8(_) => CpuTextureDataType::RgbU8,
+            Self::RgbaU8(_) => CpuTextureDataType::RgbaU8,
+        }
+    }
+}
+
 impl Hash for CpuTextureData {
     fn hash<H: Hasher>(&self, _state: &mut H) {
         match self {
@@ -12,7 +12,7 @@ use crate::base::Color;
 
 ///
 /// A light which shines in the given direction.
-/// The light will cast shadows if you [generate a shadow map](DirectionalLight::generate_shadow_map).
+/// The light will cast shadows over what the camera sees if [cast_shadows](DirectionalLight::cast_shadows) is set.
 ///
 #[derive(Debug, Clone, Copy, Component)]
 pub struct DirectionalLight {
@@ -22,6 +22,8 @@ pub struct DirectionalLight {
     pub color: Color,
     /// The direction the light shines.
     pub direction: Vec3,
+    /// Whether meshes block this light from the meshes behind them.
+    pub cast_shadows: bool,
 }
 
 impl Hash for DirectionalLight {
@@ -31,6 +33,7 @@ impl Hash for DirectionalLight {
         self.direction.x.to_bits().hash(state);
         self.direction.y.to_bits().hash(state);
         self.direction.z.to_bits().hash(state);
+        self.cast_shadows.hash(state);
     }
 }
 
@@ -43,13 +46,20 @@ impl DirectionalLight {
             intensity,
             color,
             direction,
+            cast_shadows: false,
         }
     }
 
+    pub fn with_shadows(mut self) -> Self {
+        self.cast_shadows = true;
+        self
+    }
+
     pub fn mirror(&mut self, other: &DirectionalLight) {
         self.intensity = other.intensity;
         self.color = other.color;
         self.direction = other.direction;
+        self.cast_shadows = other.cast_shadows;
     }
 }
 
@@ -59,6 +69,7 @@ impl Default for DirectionalLight {
             intensity: 1.0,
             color: Color::WHITE,
             direction: Vec3::new(0.0, -1.0, 0.0),
+            cast_shadows: false,
         }
     }
 }
@@ -18,6 +18,8 @@ pub struct PointLight {
     pub color: Color,
     /// The [Attenuation] of the light.
     pub attenuation: Attenuation,
+    /// Whether meshes block this light from the meshes behind them, in every direction.
+    pub cast_shadows: bool,
 }
 
 impl PointLight {
@@ -33,8 +35,33 @@ impl PointLight {
             intensity,
             color,
             attenuation,
+            cast_shadows: false,
         }
     }
+
+    pub fn with_shadows(mut self) -> Self {
+        self.cast_shadows = true;
+        self
+    }
+
+    /// The distance past which the light is attenuated to under 1% of its intensity.
+    pub fn range(&self) -> f32 {
+        const CUTOFF: f32 = 100.0;
+        let Attenuation {
+            constant,
+            linear,
+            quadratic,
+        } = self.attenuation;
+        let range = if quadratic > 0.0 {
+            (-linear + (linear * linear - 4.0 * quadratic * (constant - CUTOFF)).sqrt())
+                / (2.0 * quadratic)
+        } else if linear > 0.0 {
+            (CUTOFF - constant) / linear
+        } else {
+            f32::INFINITY
+        };
+        range.max(0.0)
+    }
 }
 
 impl Default for PointLight {
@@ -44,6 +71,7 @@ impl Default for PointLight {
             color: Color::WHITE,
             intensity: 1.0,
             attenuation: Attenuation::default(),
+            cast_shadows: false,
         }
     }
 }
@@ -5,3 +5,13 @@ pub enum TypedLight {
     Directional(DirectionalLight),
     Point(PointLight),
 }
+
+impl TypedLight {
+    pub fn casts_shadows(&self) -> bool {
+        match self {
+            Self::Ambient(_) => false,
+            Self::Directional(light) => light.cast_shadows,
+            Self::Point(light) => light.cast_shadows,
+        }
+    }
+}
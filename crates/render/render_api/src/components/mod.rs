@@ -2,6 +2,8 @@ pub use camera::*;
 pub use clear_operation::*;
 pub use light::*;
 pub use object::*;
+pub use particle_emitter::*;
+pub use post_process::*;
 pub use projection::*;
 pub use render_layer::*;
 pub use render_target::*;
@@ -13,6 +15,8 @@ mod camera;
 mod clear_operation;
 mod light;
 mod object;
+mod particle_emitter;
+mod post_process;
 mod projection;
 mod render_layer;
 mod render_target;
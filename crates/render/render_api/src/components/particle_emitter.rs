@@ -0,0 +1,309 @@
+use bevy_ecs::{
+    component::Component,
+    system::{Query, Res},
+};
+
+use math::{lerp, Quat, Vec3};
+use storage::Handle;
+
+use crate::{
+    base::{Color, CpuMaterial, CpuMesh},
+    components::Transform,
+    resources::Time,
+};
+
+///
+/// How a [ParticleEmitter] spawns its particles & how they change over their lifetime.
+///
+#[derive(Clone, Copy, Debug)]
+pub struct ParticleSettings {
+    /// Particles spawned per second.
+    pub spawn_rate: f32,
+    /// How long each particle lives, in milliseconds.
+    pub lifetime_ms: f32,
+    /// Once this many are alive, no more spawn until some die.
+    pub max_particles: usize,
+    /// The center of the cone particles are launched along, relative to the emitter's rotation.
+    pub direction: Vec3,
+    /// The angle between the cone's center and its edge, in radians. 0 launches every particle along `direction`.
+    pub spread: f32,
+    /// Launch speeds are picked between these, in units per second.
+    pub min_speed: f32,
+    pub max_speed: f32,
+    /// Added to each particle's velocity every second, ie. gravity.
+    pub acceleration: Vec3,
+    pub start_color: Color,
+    pub end_color: Color,
+    /// The scale of the particle mesh when it spawns & when it dies.
+    pub start_size: f32,
+    pub end_size: f32,
+}
+
+impl Default for ParticleSettings {
+    fn default() -> Self {
+        Self {
+            spawn_rate: 10.0,
+            lifetime_ms: 1000.0,
+            max_particles: 100,
+            direction: Vec3::Y,
+            spread: 0.5,
+            min_speed: 1.0,
+            max_speed: 2.0,
+            acceleration: Vec3::ZERO,
+            start_color: Color::WHITE,
+            end_color: Color::WHITE,
+            start_size: 1.0,
+            end_size: 0.0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Particle {
+    /// In world space, particles don't follow the emitter once spawned.
+    pub position: Vec3,
+    pub velocity: Vec3,
+    pub age_ms: f32,
+}
+
+impl Particle {
+    fn progress(&self, settings: &ParticleSettings) -> f32 {
+        if settings.lifetime_ms <= 0.0 {
+            return 1.0;
+        }
+        (self.age_ms / settings.lifetime_ms).clamp(0.0, 1.0)
+    }
+
+    pub fn color(&self, settings: &ParticleSettings) -> Color {
+        let start = settings.start_color.to_vec3();
+        let end = settings.end_color.to_vec3();
+        let color = start.lerp(end, self.progress(settings));
+        Color::from_rgb_f32(color.x, color.y, color.z)
+    }
+
+    pub fn size(&self, settings: &ParticleSettings) -> f32 {
+        lerp(
+            settings.start_size,
+            settings.end_size,
+            self.progress(settings),
+        )
+    }
+}
+
+///
+/// Spawns particles at its entity's [Transform], which are drawn as copies of `mesh`
+/// turned to face the camera. `mesh` is usually a [CenteredSquare](crate::shapes::CenteredSquare).
+///
+#[derive(Component, Clone)]
+pub struct ParticleEmitter {
+    pub settings: ParticleSettings,
+    pub mesh: Handle<CpuMesh>,
+    /// Tinted by each particle's color.
+    pub material: Handle<CpuMaterial>,
+    /// When false no new particles spawn, the ones alive still finish their lifetime.
+    pub emitting: bool,
+    particles: Vec<Particle>,
+    // fractional particles carried between frames, so low rates still spawn
+    spawn_accumulator: f32,
+    random_state: u32,
+}
+
+impl ParticleEmitter {
+    pub fn new(
+        settings: ParticleSettings,
+        mesh: Handle<CpuMesh>,
+        material: Handle<CpuMaterial>,
+    ) -> Self {
+        Self {
+            settings,
+            mesh,
+            material,
+            emitting: true,
+            particles: Vec::new(),
+            spawn_accumulator: 0.0,
+            random_state: 0x9E37_79B9,
+        }
+    }
+
+    /// Emitters with the same seed spawn the same pattern, give nearby ones different seeds.
+    pub fn with_seed(mut self, seed: u32) -> Self {
+        // xorshift never leaves 0
+        self.random_state = seed.max(1);
+        self
+    }
+
+    pub fn particles(&self) -> &[Particle] {
+        &self.particles
+    }
+
+    /// True once emitting has stopped and every particle has died.
+    pub fn is_finished(&self) -> bool {
+        !self.emitting && self.particles.is_empty()
+    }
+
+    pub fn update(&mut self, elapsed_ms: f32, transform: &Transform) {
+        let elapsed_secs = elapsed_ms / 1000.0;
+
+        let lifetime_ms = self.settings.lifetime_ms;
+        self.particles.retain_mut(|particle| {
+            particle.age_ms += elapsed_ms;
+            particle.age_ms < lifetime_ms
+        });
+        for particle in &mut self.particles {
+            particle.velocity += self.settings.acceleration * elapsed_secs;
+            particle.position += particle.velocity * elapsed_secs;
+        }
+
+        if !self.emitting {
+            self.spawn_accumulator = 0.0;
+            return;
+        }
+        self.spawn_accumulator += self.settings.spawn_rate * elapsed_secs;
+        while self.spawn_accumulator >= 1.0 {
+            self.spawn_accumulator -= 1.0;
+            if self.particles.len() >= self.settings.max_particles {
+                // don't save up a burst for when there's room again
+                self.spawn_accumulator = 0.0;
+                break;
+            }
+            self.spawn(transform);
+        }
+    }
+
+    fn spawn(&mut self, transform: &Transform) {
+        let direction = transform.rotation * self.random_direction();
+        let speed = lerp(
+            self.settings.min_speed,
+            self.settings.max_speed,
+            self.random(),
+        );
+        self.particles.push(Particle {
+            position: transform.translation,
+            velocity: direction * speed,
+            age_ms: 0.0,
+        });
+    }
+
+    // uniform over the cap of the unit sphere within `spread` of `direction`
+    fn random_direction(&mut self) -> Vec3 {
+        let cos_spread = self.settings.spread.cos();
+        let cos_angle = lerp(1.0, cos_spread, self.random());
+        let sin_angle = (1.0 - cos_angle * cos_angle).max(0.0).sqrt();
+        let around = self.random() * std::f32::consts::TAU;
+        let local = Vec3::new(
+            sin_angle * around.cos(),
+            sin_angle * around.sin(),
+            cos_angle,
+        );
+
+        let mut direction = self.settings.direction.normalize_or_zero();
+        if direction == Vec3::ZERO {
+            direction = Vec3::Y;
+        }
+        Quat::from_rotation_arc(Vec3::Z, direction) * local
+    }
+
+    // 0..1, xorshift is plenty for visuals and keeps this crate free of a rand dependency
+    fn random(&mut self) -> f32 {
+        let mut x = self.random_state;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.random_state = x;
+        (x >> 8) as f32 / (1u32 << 24) as f32
+    }
+}
+
+pub fn update_particle_emitters(
+    time: Res<Time>,
+    mut emitters_q: Query<(&mut ParticleEmitter, &Transform)>,
+) {
+    let elapsed_ms = time.get_elapsed_ms();
+    for (mut emitter, transform) in emitters_q.iter_mut() {
+        emitter.update(elapsed_ms, transform);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use math::Vec3;
+    use storage::Storage;
+
+    use crate::{
+        base::{Color, CpuMaterial, CpuMesh},
+        components::{ParticleEmitter, ParticleSettings, Transform},
+        shapes::CenteredSquare,
+    };
+
+    fn emitter(settings: ParticleSettings) -> ParticleEmitter {
+        let mut meshes = Storage::<CpuMesh>::default();
+        let mut materials = Storage::<CpuMaterial>::default();
+        let mesh = meshes.add(CenteredSquare::new());
+        let material = materials.add(Color::WHITE);
+        ParticleEmitter::new(settings, mesh, material)
+    }
+
+    #[test]
+    fn spawns_at_rate_and_dies_after_lifetime() {
+        let mut emitter = emitter(ParticleSettings {
+            spawn_rate: 6.0,
+            lifetime_ms: 500.0,
+            ..Default::default()
+        });
+        let transform = Transform::from_xyz(1.0, 2.0, 3.0);
+
+        // 1.5 particles worth, the half carries over
+        emitter.update(250.0, &transform);
+        assert_eq!(emitter.particles().len(), 1);
+        emitter.update(250.0, &transform);
+        assert_eq!(emitter.particles().len(), 3);
+        assert_eq!(emitter.particles()[2].position, transform.translation);
+
+        emitter.emitting = false;
+        emitter.update(300.0, &transform);
+        // the first was spawned 550ms ago
+        assert_eq!(emitter.particles().len(), 2);
+        emitter.update(200.0, &transform);
+        assert!(emitter.is_finished());
+    }
+
+    #[test]
+    fn launches_within_the_cone() {
+        let spread = 0.3;
+        let mut emitter = emitter(ParticleSettings {
+            spawn_rate: 1000.0,
+            max_particles: 1000,
+            direction: Vec3::X,
+            spread,
+            acceleration: Vec3::ZERO,
+            ..Default::default()
+        });
+
+        emitter.update(100.0, &Transform::default());
+        assert_eq!(emitter.particles().len(), 100);
+        for particle in emitter.particles() {
+            let angle = particle.velocity.angle_between(Vec3::X);
+            assert!(angle <= spread + 0.001, "angle {} outside spread", angle);
+        }
+    }
+
+    #[test]
+    fn interpolates_over_lifetime() {
+        let settings = ParticleSettings {
+            lifetime_ms: 1000.0,
+            start_color: Color::BLACK,
+            end_color: Color::WHITE,
+            start_size: 2.0,
+            end_size: 0.0,
+            ..Default::default()
+        };
+        let mut emitter = emitter(settings);
+        emitter.update(100.0, &Transform::default());
+        emitter.emitting = false;
+        emitter.update(500.0, &Transform::default());
+
+        let particle = emitter.particles()[0];
+        assert_eq!(particle.size(&settings), 1.0);
+        assert_eq!(particle.color(&settings).r, 127);
+    }
+}
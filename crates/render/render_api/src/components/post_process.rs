@@ -0,0 +1,159 @@
+use std::default::Default;
+
+use bevy_ecs::component::Component;
+
+use crate::base::Color;
+
+///
+/// The image effects applied to what a [Camera](crate::components::Camera) renders, in order.
+/// Add it next to the camera, each effect reads the output of the one before it.
+///
+#[derive(Component, Clone, Debug, Default)]
+pub struct PostProcess {
+    pub effects: Vec<PostEffect>,
+}
+
+impl PostProcess {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, effect: impl Into<PostEffect>) -> Self {
+        self.push(effect);
+        self
+    }
+
+    pub fn push(&mut self, effect: impl Into<PostEffect>) {
+        self.effects.push(effect.into());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum PostEffect {
+    /// Smooths jagged edges, usually last in the chain.
+    Fxaa,
+    Bloom(BloomSettings),
+    Outline(OutlineSettings),
+    Vignette(VignetteSettings),
+    ColorGrading(ColorGradingSettings),
+}
+
+///
+/// Blurs the emissive part of materials (see [CpuMaterial::emissive](crate::base::CpuMaterial::emissive))
+/// and adds it on top of the image, so glowing meshes bleed light into their surroundings.
+///
+#[derive(Clone, Copy, Debug)]
+pub struct BloomSettings {
+    /// How much of the blurred glow is added to the image.
+    pub intensity: f32,
+    /// How far the glow spreads, in pixels.
+    pub radius: f32,
+}
+
+impl Default for BloomSettings {
+    fn default() -> Self {
+        Self {
+            intensity: 1.0,
+            radius: 8.0,
+        }
+    }
+}
+
+impl From<BloomSettings> for PostEffect {
+    fn from(settings: BloomSettings) -> Self {
+        PostEffect::Bloom(settings)
+    }
+}
+
+///
+/// Draws lines where the depth changes sharply, ie. around the silhouettes of meshes.
+///
+#[derive(Clone, Copy, Debug)]
+pub struct OutlineSettings {
+    pub color: Color,
+    /// The width of the lines, in pixels.
+    pub thickness: f32,
+    /// The depth difference, relative to the distance from the camera, which counts as an edge.
+    pub depth_threshold: f32,
+}
+
+impl Default for OutlineSettings {
+    fn default() -> Self {
+        Self {
+            color: Color::BLACK,
+            thickness: 1.0,
+            depth_threshold: 0.05,
+        }
+    }
+}
+
+impl From<OutlineSettings> for PostEffect {
+    fn from(settings: OutlineSettings) -> Self {
+        PostEffect::Outline(settings)
+    }
+}
+
+///
+/// Darkens the image towards its corners.
+///
+#[derive(Clone, Copy, Debug)]
+pub struct VignetteSettings {
+    /// How dark the corners get, 0 leaves the image as-is and 1 is black.
+    pub intensity: f32,
+    /// The distance from the center where darkening starts, 1 is the middle of the top edge.
+    pub radius: f32,
+    /// The distance over which it fades in.
+    pub softness: f32,
+}
+
+impl Default for VignetteSettings {
+    fn default() -> Self {
+        Self {
+            intensity: 0.5,
+            radius: 0.75,
+            softness: 0.5,
+        }
+    }
+}
+
+impl From<VignetteSettings> for PostEffect {
+    fn from(settings: VignetteSettings) -> Self {
+        PostEffect::Vignette(settings)
+    }
+}
+
+///
+/// Adjusts the tone of the final image. The defaults leave it unchanged.
+///
+#[derive(Clone, Copy, Debug)]
+pub struct ColorGradingSettings {
+    /// Brightness in stops, 1 doubles it & -1 halves it.
+    pub exposure: f32,
+    /// 1 is unchanged, higher pushes colors away from mid gray.
+    pub contrast: f32,
+    /// 1 is unchanged, 0 is grayscale.
+    pub saturation: f32,
+    /// Multiplied into the image.
+    pub tint: Color,
+}
+
+impl Default for ColorGradingSettings {
+    fn default() -> Self {
+        Self {
+            exposure: 0.0,
+            contrast: 1.0,
+            saturation: 1.0,
+            tint: Color::WHITE,
+        }
+    }
+}
+
+impl From<ColorGradingSettings> for PostEffect {
+    fn from(settings: ColorGradingSettings) -> Self {
+        PostEffect::ColorGrading(settings)
+    }
+}
@@ -1,4 +1,4 @@
-use bevy_app::{App, Last, MainScheduleOrder, Plugin};
+use bevy_app::{App, Last, MainScheduleOrder, Plugin, Update};
 use bevy_ecs::schedule::{ExecutorKind, Schedule};
 
 use storage::Storage;
@@ -6,7 +6,8 @@ use storage::Storage;
 use crate::{
     base::{CpuMaterial, CpuMesh, CpuSkin, CpuTexture2D},
     base_set::{Draw, RenderSync},
-    resources::{RenderFrame, Time},
+    components::update_particle_emitters,
+    resources::{MeshLods, RenderFrame, RenderStats, Time},
     Render,
 };
 
@@ -21,7 +22,11 @@ impl Plugin for RenderApiPlugin {
             .init_resource::<Storage<CpuTexture2D>>()
             .init_resource::<Storage<CpuSkin>>()
             .init_resource::<RenderFrame>()
-            .init_resource::<Time>();
+            .init_resource::<MeshLods>()
+            .init_resource::<RenderStats>()
+            .init_resource::<Time>()
+            // Systems
+            .add_systems(Update, update_particle_emitters);
 
         // Schedules
         app.init_schedule(RenderSync);
@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+
+use bevy_ecs::system::Resource;
+
+use storage::Handle;
+
+use crate::base::CpuMesh;
+
+// Lower-detail stand-ins for meshes, swapped in by the renderer as instances shrink on screen.
+// Meshes without an entry are always drawn as-is.
+#[derive(Resource, Default)]
+pub struct MeshLods {
+    // (max screen size, mesh) ordered largest first, sizes as a fraction of the viewport height
+    levels: HashMap<Handle<CpuMesh>, Vec<(f32, Handle<CpuMesh>)>>,
+}
+
+impl MeshLods {
+    // each level's mesh replaces `mesh_handle` once the instance is smaller on screen than its size
+    pub fn insert(
+        &mut self,
+        mesh_handle: Handle<CpuMesh>,
+        mut levels: Vec<(f32, Handle<CpuMesh>)>,
+    ) {
+        levels.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+        self.levels.insert(mesh_handle, levels);
+    }
+
+    pub fn remove(&mut self, mesh_handle: &Handle<CpuMesh>) {
+        self.levels.remove(mesh_handle);
+    }
+
+    pub fn select(&self, mesh_handle: &Handle<CpuMesh>, screen_size: f32) -> Handle<CpuMesh> {
+        let mut output = *mesh_handle;
+        let Some(levels) = self.levels.get(mesh_handle) else {
+            return output;
+        };
+        for (max_screen_size, level_handle) in levels {
+            if screen_size >= *max_screen_size {
+                break;
+            }
+            output = *level_handle;
+        }
+        output
+    }
+}
@@ -1,11 +1,17 @@
+mod mesh_lods;
+mod picking;
 mod render_frame;
 mod render_pass;
+mod render_stats;
 mod time;
 mod window;
 mod window_settings;
 
+pub use mesh_lods::*;
+pub use picking::*;
 pub use render_frame::*;
 pub use render_pass::*;
+pub use render_stats::*;
 pub use time::*;
 pub use window::*;
 pub use window_settings::*;
@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+
+use bevy_ecs::entity::Entity;
+
+use math::{Mat4, Vec3};
+use storage::{Handle, Storage};
+
+use crate::base::{AxisAlignedBoundingBox, CpuMesh, Ray};
+
+// a mesh drawn on behalf of an entity, see `RenderFrame::draw_pickable`
+#[derive(Clone, Copy)]
+pub(crate) struct Pickable {
+    pub(crate) entity: Entity,
+    pub(crate) render_layer: usize,
+    pub(crate) mesh_handle: Handle<CpuMesh>,
+    pub(crate) transform_matrix: Mat4,
+}
+
+///
+/// What a [Ray] hit first.
+///
+#[derive(Debug, Clone, Copy)]
+pub struct PickHit {
+    pub entity: Entity,
+    /// Where the ray hit, in world space.
+    pub point: Vec3,
+    /// The index of the hit triangle within its [CpuMesh], as counted by [CpuMesh::for_each_triangle].
+    pub face_index: usize,
+    /// From the ray's origin to `point`, in world units.
+    pub distance: f32,
+}
+
+pub(crate) fn pick(
+    pickables: &[Pickable],
+    render_layer: usize,
+    ray: &Ray,
+    meshes: &Storage<CpuMesh>,
+) -> Option<PickHit> {
+    // the same mesh is usually drawn many times, only work its bounds out once
+    let mut mesh_aabbs: HashMap<Handle<CpuMesh>, AxisAlignedBoundingBox> = HashMap::new();
+
+    let mut candidates = Vec::new();
+    for pickable in pickables {
+        if pickable.render_layer != render_layer {
+            continue;
+        }
+        let Some(mesh) = meshes.get(&pickable.mesh_handle) else {
+            continue;
+        };
+        let mut aabb = *mesh_aabbs
+            .entry(pickable.mesh_handle)
+            .or_insert_with(|| mesh.compute_aabb());
+        aabb.transform(&pickable.transform_matrix);
+        if let Some(distance) = ray.intersect_aabb(&aabb) {
+            candidates.push((distance, pickable));
+        }
+    }
+    candidates.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+    let mut closest_hit: Option<PickHit> = None;
+    for (aabb_distance, pickable) in candidates {
+        if let Some(hit) = &closest_hit {
+            if hit.distance <= aabb_distance {
+                // nothing left can be in front of it
+                break;
+            }
+        }
+        let mesh = meshes.get(&pickable.mesh_handle).unwrap();
+        let vertices = mesh.vertices();
+        let matrix = pickable.transform_matrix;
+        mesh.for_each_triangle(|i0, i1, i2| {
+            let triangle = [
+                matrix.transform_point3(vertices[i0]),
+                matrix.transform_point3(vertices[i1]),
+                matrix.transform_point3(vertices[i2]),
+            ];
+            let Some(distance) = ray.intersect_triangle(triangle) else {
+                return;
+            };
+            if closest_hit.is_some_and(|hit| hit.distance <= distance) {
+                return;
+            }
+            closest_hit = Some(PickHit {
+                entity: pickable.entity,
+                point: ray.at(distance),
+                face_index: i0 / 3,
+                distance,
+            });
+        });
+    }
+
+    closest_hit
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::entity::Entity;
+
+    use math::Vec3;
+    use storage::Storage;
+
+    use crate::{
+        base::{Color, CpuMaterial, CpuMesh, Ray},
+        components::{RenderLayers, Transform},
+        resources::RenderFrame,
+        shapes::Cube,
+    };
+
+    #[test]
+    fn picks_the_nearest_entity_drawn_on_the_layer() {
+        let mut meshes = Storage::<CpuMesh>::default();
+        let mut materials = Storage::<CpuMaterial>::default();
+        let cube = meshes.add(Cube);
+        let material = materials.add(Color::WHITE);
+
+        let near = Entity::from_raw(1);
+        let far = Entity::from_raw(2);
+        let other_layer = Entity::from_raw(3);
+        let layer_1 = RenderLayers::layer(1);
+
+        let mut render_frame = RenderFrame::default();
+        for (entity, z, render_layer_opt) in [
+            (far, 10.0, None),
+            (near, 5.0, None),
+            (other_layer, 2.0, Some(&layer_1)),
+        ] {
+            render_frame.draw_pickable(entity, |render_frame| {
+                let transform = Transform::from_xyz(0.0, 0.0, z);
+                render_frame.draw_mesh(render_layer_opt, &cube, &material, &transform);
+            });
+        }
+        // not pickable, drawn outside of `draw_pickable`
+        render_frame.draw_mesh(None, &cube, &material, &Transform::from_xyz(0.0, 0.0, 0.0));
+
+        let ray = Ray::new(Vec3::new(0.0, 0.0, -5.0), Vec3::Z);
+        // only what has been rendered can be picked
+        assert!(render_frame.pick(None, &ray, &meshes).is_none());
+        render_frame.take_render_passes();
+
+        let hit = render_frame.pick(None, &ray, &meshes).unwrap();
+        assert_eq!(hit.entity, near);
+        assert_eq!(hit.distance, 9.0);
+        assert_eq!(hit.point, Vec3::new(0.0, 0.0, 4.0));
+        assert!(hit.face_index < meshes.get(&cube).unwrap().triangle_count());
+
+        let hit = render_frame.pick(Some(&layer_1), &ray, &meshes).unwrap();
+        assert_eq!(hit.entity, other_layer);
+    }
+}
@@ -1,29 +1,40 @@
 use std::default::Default;
 
-use bevy_ecs::system::Resource;
+use bevy_ecs::{entity::Entity, system::Resource};
 
-use math::{lerp, Vec2};
-use storage::Handle;
+use math::{lerp, Mat4, Vec2};
+use storage::{Handle, Storage};
 
 use crate::{
-    base::{CpuMaterial, CpuMesh, CpuSkin},
+    base::{Color, CpuMaterial, CpuMesh, CpuSkin, Ray},
     components::{
-        AmbientLight, Camera, DirectionalLight, PointLight, Projection, RenderLayer, RenderLayers,
-        Transform, TypedLight, Viewport,
+        AmbientLight, Camera, DirectionalLight, ParticleEmitter, PointLight, PostProcess,
+        Projection, RenderLayer, RenderLayers, Transform, TypedLight, Viewport,
+    },
+    resources::{
+        picking::{pick, Pickable},
+        render_pass::{Billboard, RenderPass},
+        PickHit,
     },
-    resources::render_pass::RenderPass,
     shapes::set_2d_line_transform,
 };
 
 #[derive(Resource)]
 pub struct RenderFrame {
     render_passes: Vec<Option<RenderPass>>,
+    pick_entity_opt: Option<Entity>,
+    pickables: Vec<Pickable>,
+    // what was on screen last, picks are tested against these
+    rendered_pickables: Vec<Pickable>,
 }
 
 impl Default for RenderFrame {
     fn default() -> Self {
         Self {
             render_passes: Self::new_render_passes(),
+            pick_entity_opt: None,
+            pickables: Vec::new(),
+            rendered_pickables: Vec::new(),
         }
     }
 }
@@ -41,6 +52,7 @@ impl RenderFrame {
         let mut output_frame = Self::new_render_passes();
 
         std::mem::swap(&mut self.render_passes, &mut output_frame);
+        self.rendered_pickables = std::mem::take(&mut self.pickables);
 
         output_frame
     }
@@ -82,6 +94,15 @@ impl RenderFrame {
         contents.camera_projection_opt = Some(*projection);
     }
 
+    pub fn draw_post_process(
+        &mut self,
+        render_layer_opt: Option<&RenderLayer>,
+        post_process: &PostProcess,
+    ) {
+        let contents = self.get_render_pass_mut(render_layer_opt);
+        contents.post_effects = post_process.effects.clone();
+    }
+
     pub fn draw_point_light(&mut self, render_layer_opt: Option<&RenderLayer>, light: &PointLight) {
         let contents = self.get_render_pass_mut(render_layer_opt);
         contents.lights.push(TypedLight::Point(*light));
@@ -154,11 +175,13 @@ impl RenderFrame {
         mat_handle: &Handle<CpuMaterial>,
         transform: &Transform,
     ) {
+        let transform_matrix = transform.compute_matrix();
+        self.add_pickable(render_layer_opt, mesh_handle, transform_matrix);
         let contents = self.get_render_pass_mut(render_layer_opt);
         contents.add_mesh(
             mesh_handle,
             MaterialOrSkinHandle::Material(mat_handle.clone()),
-            transform.compute_matrix(),
+            transform_matrix,
         );
     }
 
@@ -169,19 +192,79 @@ impl RenderFrame {
         skin_handle: &Handle<CpuSkin>,
         transform: &Transform,
     ) {
+        let transform_matrix = transform.compute_matrix();
+        self.add_pickable(render_layer_opt, mesh_handle, transform_matrix);
         let contents = self.get_render_pass_mut(render_layer_opt);
         contents.add_mesh(
             mesh_handle,
             MaterialOrSkinHandle::Skin(skin_handle.clone()),
-            transform.compute_matrix(),
+            transform_matrix,
         );
     }
+
+    pub fn draw_particles(
+        &mut self,
+        render_layer_opt: Option<&RenderLayer>,
+        emitter: &ParticleEmitter,
+    ) {
+        let contents = self.get_render_pass_mut(render_layer_opt);
+        for particle in emitter.particles() {
+            contents.add_billboard(Billboard {
+                mesh_handle: emitter.mesh,
+                mat_handle: emitter.material,
+                position: particle.position,
+                size: particle.size(&emitter.settings),
+                color: particle.color(&emitter.settings),
+            });
+        }
+    }
+
+    ///
+    /// Meshes drawn inside `draw` can be found by [RenderFrame::pick], which reports them as `entity`.
+    ///
+    pub fn draw_pickable(&mut self, entity: Entity, draw: impl FnOnce(&mut Self)) {
+        let previous_entity_opt = self.pick_entity_opt.replace(entity);
+        draw(self);
+        self.pick_entity_opt = previous_entity_opt;
+    }
+
+    ///
+    /// The first mesh the ray hits, out of those drawn pickable on the layer last frame.
+    ///
+    pub fn pick(
+        &self,
+        render_layer_opt: Option<&RenderLayer>,
+        ray: &Ray,
+        meshes: &Storage<CpuMesh>,
+    ) -> Option<PickHit> {
+        let render_layer = convert_wrapper(render_layer_opt.copied());
+        pick(&self.rendered_pickables, render_layer, ray, meshes)
+    }
+
+    fn add_pickable(
+        &mut self,
+        render_layer_opt: Option<&RenderLayer>,
+        mesh_handle: &Handle<CpuMesh>,
+        transform_matrix: Mat4,
+    ) {
+        let Some(entity) = self.pick_entity_opt else {
+            return;
+        };
+        self.pickables.push(Pickable {
+            entity,
+            render_layer: convert_wrapper(render_layer_opt.copied()),
+            mesh_handle: *mesh_handle,
+            transform_matrix,
+        });
+    }
 }
 
 #[derive(Clone, Copy)]
 pub enum MaterialOrSkinHandle {
     Material(Handle<CpuMaterial>),
     Skin(Handle<CpuSkin>),
+    // the material's color multiplied by another, so instances can differ without a material each
+    TintedMaterial(Handle<CpuMaterial>, Color),
 }
 
 fn convert_wrapper(w: Option<RenderLayer>) -> usize {
@@ -1,12 +1,12 @@
 use std::collections::HashMap;
 
-use math::Mat4;
+use math::{Mat4, Vec3};
 use storage::Handle;
 
 use crate::{
-    base::CpuMesh,
-    components::{Camera, Projection, Transform, TypedLight},
-    resources::MaterialOrSkinHandle,
+    base::{AxisAlignedBoundingBox, Color, CpuMaterial, CpuMesh, Frustum},
+    components::{Camera, PostEffect, Projection, Transform, TypedLight},
+    resources::{MaterialOrSkinHandle, MeshLods, RenderPassStats},
 };
 
 pub struct RenderPass {
@@ -14,7 +14,30 @@ pub struct RenderPass {
     pub camera_transform_opt: Option<Transform>,
     pub camera_projection_opt: Option<Projection>,
     pub lights: Vec<TypedLight>,
+    pub post_effects: Vec<PostEffect>,
+    // every instance, culling only picks which of these are drawn
     pub meshes: HashMap<Handle<CpuMesh>, Vec<(MaterialOrSkinHandle, Mat4)>>,
+    pub billboards: Vec<Billboard>,
+    // filled by `cull`: the instances in view, by the mesh they're drawn with (their own, or a
+    // lower-detail one), as their mesh & index in `meshes`
+    visible: Option<VisibleInstances>,
+    // filled by `cull`: how many of each mesh's instances were added before the billboards, which
+    // don't cast shadows
+    shadow_caster_counts: Option<HashMap<Handle<CpuMesh>, usize>>,
+}
+
+pub type MeshInstances<'a> = HashMap<Handle<CpuMesh>, Vec<&'a (MaterialOrSkinHandle, Mat4)>>;
+
+type VisibleInstances = HashMap<Handle<CpuMesh>, Vec<(Handle<CpuMesh>, usize)>>;
+
+// a mesh which is turned to face the camera, once the pass knows where the camera is
+#[derive(Clone, Copy)]
+pub struct Billboard {
+    pub mesh_handle: Handle<CpuMesh>,
+    pub mat_handle: Handle<CpuMaterial>,
+    pub position: Vec3,
+    pub size: f32,
+    pub color: Color,
 }
 
 impl Default for RenderPass {
@@ -24,7 +47,11 @@ impl Default for RenderPass {
             camera_transform_opt: None,
             camera_projection_opt: None,
             lights: Vec::new(),
+            post_effects: Vec::new(),
             meshes: HashMap::new(),
+            billboards: Vec::new(),
+            visible: None,
+            shadow_caster_counts: None,
         }
     }
 }
@@ -42,4 +69,125 @@ impl RenderPass {
         let map = self.meshes.get_mut(mesh_handle).unwrap();
         map.push((mat_handle, transform_matrix));
     }
+
+    pub fn add_billboard(&mut self, billboard: Billboard) {
+        self.billboards.push(billboard);
+    }
+
+    // picks the instances inside the camera's frustum, and the LOD mesh each is drawn with.
+    // `mesh_aabb` returns a mesh's model-space bounds, meshes it has none for are always drawn
+    pub fn cull(
+        &mut self,
+        mesh_aabb: impl Fn(&Handle<CpuMesh>) -> Option<AxisAlignedBoundingBox>,
+        mesh_lods: &MeshLods,
+    ) -> RenderPassStats {
+        // counted before billboards join the meshes
+        self.shadow_caster_counts = Some(
+            self.meshes
+                .iter()
+                .map(|(mesh_handle, instances)| (*mesh_handle, instances.len()))
+                .collect(),
+        );
+        self.face_billboards_to_camera();
+
+        let mut stats = RenderPassStats::default();
+        let (Some(camera), Some(camera_transform), Some(camera_projection)) = (
+            self.camera_opt.as_ref(),
+            self.camera_transform_opt.as_ref(),
+            self.camera_projection_opt.as_ref(),
+        ) else {
+            stats.drawn = self.meshes.values().map(Vec::len).sum();
+            return stats;
+        };
+        let frustum = Frustum::new(camera, camera_transform, camera_projection);
+
+        let mut visible = VisibleInstances::new();
+        for (mesh_handle, instances) in self.meshes.iter() {
+            let Some(base_aabb) = mesh_aabb(mesh_handle).filter(|aabb| !aabb.is_empty()) else {
+                stats.drawn += instances.len();
+                visible
+                    .entry(*mesh_handle)
+                    .or_default()
+                    .extend((0..instances.len()).map(|index| (*mesh_handle, index)));
+                continue;
+            };
+            for (index, (_, transform_matrix)) in instances.iter().enumerate() {
+                let mut instance_aabb = base_aabb;
+                instance_aabb.transform(transform_matrix);
+                if !frustum.intersects_aabb(&instance_aabb) {
+                    stats.culled += 1;
+                    continue;
+                }
+                stats.drawn += 1;
+                let mut lod_handle =
+                    mesh_lods.select(mesh_handle, frustum.screen_size(&instance_aabb));
+                if lod_handle != *mesh_handle && mesh_aabb(&lod_handle).is_none() {
+                    // the lower-detail mesh isn't loaded yet
+                    lod_handle = *mesh_handle;
+                }
+                visible
+                    .entry(lod_handle)
+                    .or_default()
+                    .push((*mesh_handle, index));
+            }
+        }
+        self.visible = Some(visible);
+
+        stats
+    }
+
+    // the instances to draw, by the mesh to draw them with. every instance if the pass wasn't culled
+    pub fn drawn_instances(&self) -> MeshInstances<'_> {
+        let Some(visible) = &self.visible else {
+            return self
+                .meshes
+                .iter()
+                .map(|(mesh_handle, instances)| (*mesh_handle, instances.iter().collect()))
+                .collect();
+        };
+        visible
+            .iter()
+            .map(|(lod_handle, indices)| {
+                let instances = indices
+                    .iter()
+                    .map(|(mesh_handle, index)| &self.meshes[mesh_handle][*index])
+                    .collect();
+                (*lod_handle, instances)
+            })
+            .collect()
+    }
+
+    // every instance but the billboards, in view or not, as shadows fall into view from outside it
+    pub fn shadow_casters(&self) -> MeshInstances<'_> {
+        self.meshes
+            .iter()
+            .filter_map(|(mesh_handle, instances)| {
+                let count = match &self.shadow_caster_counts {
+                    Some(counts) => counts.get(mesh_handle).copied().unwrap_or(0),
+                    None => instances.len(),
+                };
+                (count > 0).then(|| (*mesh_handle, instances[..count].iter().collect()))
+            })
+            .collect()
+    }
+
+    // billboards become instances of their mesh, tinted by their color
+    fn face_billboards_to_camera(&mut self) {
+        let billboards = std::mem::take(&mut self.billboards);
+        let Some(camera_transform) = self.camera_transform_opt else {
+            return;
+        };
+        for billboard in billboards {
+            let transform = Transform {
+                translation: billboard.position,
+                rotation: camera_transform.rotation,
+                scale: Vec3::splat(billboard.size),
+            };
+            self.add_mesh(
+                &billboard.mesh_handle,
+                MaterialOrSkinHandle::TintedMaterial(billboard.mat_handle, billboard.color),
+                transform.compute_matrix(),
+            );
+        }
+    }
 }
@@ -0,0 +1,43 @@
+use bevy_ecs::system::Resource;
+
+#[derive(Clone, Copy, Default, Debug)]
+pub struct RenderPassStats {
+    pub culled: usize,
+    pub drawn: usize,
+}
+
+impl RenderPassStats {
+    pub fn submitted(&self) -> usize {
+        self.culled + self.drawn
+    }
+}
+
+// Mesh instance counts from the last rendered frame, for debug overlays
+#[derive(Resource, Default)]
+pub struct RenderStats {
+    // (render layer index, stats) for every pass that was rendered
+    passes: Vec<(usize, RenderPassStats)>,
+}
+
+impl RenderStats {
+    pub fn clear(&mut self) {
+        self.passes.clear();
+    }
+
+    pub fn record(&mut self, render_layer_index: usize, stats: RenderPassStats) {
+        self.passes.push((render_layer_index, stats));
+    }
+
+    pub fn passes(&self) -> &Vec<(usize, RenderPassStats)> {
+        &self.passes
+    }
+
+    pub fn total(&self) -> RenderPassStats {
+        let mut output = RenderPassStats::default();
+        for (_, stats) in &self.passes {
+            output.culled += stats.culled;
+            output.drawn += stats.drawn;
+        }
+        output
+    }
+}
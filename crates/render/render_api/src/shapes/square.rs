@@ -1,4 +1,4 @@
-use math::Vec3;
+use math::{Vec2, Vec3};
 use storage::StorageHash;
 
 use crate::base::CpuMesh;
@@ -29,7 +29,13 @@ impl From<CenteredSquare> for CpuMesh {
 
         let indices = vec![0, 2, 1, 2, 0, 3];
 
-        CpuMesh::from_indices(&positions, &indices)
+        // the whole texture across the square
+        let uvs = indices
+            .iter()
+            .map(|index| (positions[*index].truncate() + 1.0) * 0.5)
+            .collect();
+
+        CpuMesh::from_indices(&positions, &indices).with_uvs(uvs)
     }
 }
 
@@ -56,7 +62,13 @@ impl From<UnitSquare> for CpuMesh {
 
         let indices = vec![0, 2, 1, 2, 0, 3];
 
-        CpuMesh::from_indices(&positions, &indices)
+        // v runs up from the image's bottom row, while 2D cameras put y = 0 at the top
+        let uvs = indices
+            .iter()
+            .map(|index| Vec2::new(positions[*index].x, 1.0 - positions[*index].y))
+            .collect();
+
+        CpuMesh::from_indices(&positions, &indices).with_uvs(uvs)
     }
 }
 
@@ -18,6 +18,8 @@ use crate::{
 #[derive(Clone)]
 pub struct ColorTarget<'a> {
     target: &'a GpuTexture2D,
+    // bound to the second attachment, for shaders with a second output like the emissive color
+    secondary: Option<&'a GpuTexture2D>,
 }
 
 impl<'a> RenderTargetExt for ColorTarget<'a> {
@@ -46,7 +48,20 @@ impl<'a> RenderTargetExt for ColorTarget<'a> {
 
 impl<'a> ColorTarget<'a> {
     pub(in crate::core) fn new_texture2d(texture: &'a GpuTexture2D) -> Self {
-        ColorTarget { target: texture }
+        ColorTarget {
+            target: texture,
+            secondary: None,
+        }
+    }
+
+    ///
+    /// Also writes a shader's second output (`layout (location = 1)`) into the given texture,
+    /// which must be the same size as this target's.
+    /// Clearing clears both textures.
+    ///
+    pub fn with_secondary(mut self, texture: &'a GpuTexture2D) -> Self {
+        self.secondary = Some(texture);
+        self
     }
 
     ///
@@ -92,8 +107,14 @@ impl<'a> ColorTarget<'a> {
     pub(super) fn bind(&self) {
         let context = Context::get();
         unsafe {
-            context.draw_buffers(&[gl::COLOR_ATTACHMENT0]);
             self.target.bind_as_color_target(0, 0);
+            match self.secondary {
+                Some(secondary) => {
+                    context.draw_buffers(&[gl::COLOR_ATTACHMENT0, gl::COLOR_ATTACHMENT1]);
+                    secondary.bind_as_color_target(1, 0);
+                }
+                None => context.draw_buffers(&[gl::COLOR_ATTACHMENT0]),
+            }
         }
     }
 }
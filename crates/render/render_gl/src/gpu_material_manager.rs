@@ -2,19 +2,31 @@ use std::{collections::HashMap, default::Default};
 
 use bevy_ecs::system::Resource;
 
-use render_api::{base::CpuMaterial, components::Transform};
-use storage::Handle;
+use logging::warn;
+use render_api::{
+    base::{CpuMaterial, CpuTexture2D},
+    components::Transform,
+};
+use storage::{Handle, SideStorage};
 
 use crate::{
     core::{GpuTexture2D, Program},
-    renderer::{FragmentShader, Light},
+    renderer::{shadows_shader_source, FragmentShader, Light},
 };
 
+// the number of sampler uniforms the mesh shader has for material textures,
+// must match `physical_material.frag`
+const MAX_MATERIAL_TEXTURES: usize = 8;
+
 #[derive(Resource)]
 pub struct GpuMaterialManager {
     assets: HashMap<Handle<CpuMaterial>, GpuMaterial>,
     gpu_materials: Option<GpuTexture2D>,
     cpu_materials: Vec<[f32; 4]>,
+    // the textures used by materials, by sampler slot
+    texture_slots: Vec<Handle<CpuTexture2D>>,
+    // bound in place of textures which aren't on the gpu yet
+    blank_texture: Option<GpuTexture2D>,
 }
 
 impl Default for GpuMaterialManager {
@@ -23,6 +35,8 @@ impl Default for GpuMaterialManager {
             assets: HashMap::new(),
             gpu_materials: None,
             cpu_materials: Vec::new(),
+            texture_slots: Vec::new(),
+            blank_texture: None,
         }
     }
 }
@@ -39,13 +53,42 @@ impl GpuMaterialManager {
             cpu_material.diffuse.b as f32 / 255.0,
             cpu_material.emissive,
         ];
-        let second = [cpu_material.shine_size, cpu_material.shine_amount, 0.0, 0.0];
+        let texture_slot = match &cpu_material.texture {
+            Some(texture_handle) => self.texture_slot(texture_handle),
+            None => None,
+        };
+        let texture_slot = texture_slot.map(|slot| slot as f32).unwrap_or(-1.0);
+        let second = [
+            cpu_material.shine_size,
+            cpu_material.shine_amount,
+            texture_slot,
+            0.0,
+        ];
         self.cpu_materials.push(first);
         self.cpu_materials.push(second);
 
         self.gpu_sync();
     }
 
+    fn texture_slot(&mut self, texture_handle: &Handle<CpuTexture2D>) -> Option<usize> {
+        if let Some(slot) = self
+            .texture_slots
+            .iter()
+            .position(|handle| handle == texture_handle)
+        {
+            return Some(slot);
+        }
+        if self.texture_slots.len() >= MAX_MATERIAL_TEXTURES {
+            warn!(
+                "more than {} material textures, drawing material untextured",
+                MAX_MATERIAL_TEXTURES
+            );
+            return None;
+        }
+        self.texture_slots.push(*texture_handle);
+        Some(self.texture_slots.len() - 1)
+    }
+
     fn gpu_sync(&mut self) {
         self.gpu_materials = Some(GpuTexture2D::new_empty::<[f32; 4]>(
             self.cpu_materials.len() as u32,
@@ -53,6 +96,12 @@ impl GpuMaterialManager {
         ));
         let gpu_materials = self.gpu_materials.as_mut().unwrap();
         gpu_materials.fill_pure(&self.cpu_materials);
+
+        if self.blank_texture.is_none() {
+            let mut blank_texture = GpuTexture2D::new_empty::<[u8; 4]>(1, 1);
+            blank_texture.fill_pure(&[[255u8; 4]]);
+            self.blank_texture = Some(blank_texture);
+        }
     }
 
     pub fn get(&self, handle: &Handle<CpuMaterial>) -> Option<&GpuMaterial> {
@@ -64,8 +113,9 @@ impl GpuMaterialManager {
         self.assets.remove(_handle)
     }
 
-    pub fn fragment_shader(&self) -> FragmentShader {
-        let output = include_str!("shaders/physical_material.frag").to_string();
+    pub fn fragment_shader(&self, lights: &[&dyn Light]) -> FragmentShader {
+        let mut output = shadows_shader_source(lights);
+        output.push_str(include_str!("shaders/physical_material.frag"));
         FragmentShader { source: output }
     }
 
@@ -78,6 +128,7 @@ impl GpuMaterialManager {
         program: &Program,
         camera_transform: &Transform,
         lights: &[&dyn Light],
+        textures: &SideStorage<CpuTexture2D, GpuTexture2D>,
     ) {
         if !lights.is_empty() {
             for (i, light) in lights.iter().enumerate() {
@@ -89,6 +140,14 @@ impl GpuMaterialManager {
         //program.use_uniform("material_texture_width", self.cpu_materials.len() as f32);
 
         program.use_texture("material_texture", self.gpu_materials.as_ref().unwrap());
+
+        // slots no material uses are never sampled, so can stay unbound
+        for (slot, texture_handle) in self.texture_slots.iter().enumerate() {
+            let texture = textures
+                .get(texture_handle)
+                .unwrap_or_else(|| self.blank_texture.as_ref().unwrap());
+            program.use_texture(&format!("material_texture_{}", slot), texture);
+        }
     }
 }
 
@@ -3,10 +3,10 @@ use std::{collections::HashMap, default::Default};
 use bevy_ecs::system::Resource;
 
 use gl::DrawArraysIndirectCommand;
-use math::Vec3;
+use math::{Vec2, Vec3};
 use render_api::{
     base::{AxisAlignedBoundingBox, CpuMesh},
-    components::Camera,
+    components::Viewport,
 };
 use storage::Handle;
 
@@ -17,9 +17,11 @@ pub struct GpuMeshManager {
     assets: HashMap<Handle<CpuMesh>, GpuMesh>,
     gpu_positions: Option<VertexBuffer>,
     gpu_normals: Option<VertexBuffer>,
+    gpu_uvs: Option<VertexBuffer>,
     gpu_face_indices: Option<VertexBuffer>,
     cpu_positions: Vec<Vec3>,
     cpu_normals: Vec<Vec3>,
+    cpu_uvs: Vec<Vec2>,
     cpu_face_indices: Vec<u16>,
 }
 
@@ -29,9 +31,11 @@ impl Default for GpuMeshManager {
             assets: HashMap::new(),
             gpu_positions: None,
             gpu_normals: None,
+            gpu_uvs: None,
             gpu_face_indices: None,
             cpu_positions: Vec::new(),
             cpu_normals: Vec::new(),
+            cpu_uvs: Vec::new(),
             cpu_face_indices: Vec::new(),
         }
     }
@@ -40,18 +44,20 @@ impl Default for GpuMeshManager {
 impl GpuMeshManager {
     pub fn insert(&mut self, handle: Handle<CpuMesh>, cpu_mesh: &CpuMesh) {
         let new_cpu_vertices = cpu_mesh.vertices();
-        let new_cpu_normals = cpu_mesh.compute_normals();
+        let new_cpu_normals = cpu_mesh.normals();
+        let new_cpu_uvs = cpu_mesh.uvs();
         let new_cpu_face_indices = cpu_mesh.face_indices();
         let new_aabb = cpu_mesh.compute_aabb();
 
         let first = self.cpu_positions.len();
         let count = new_cpu_vertices.len();
 
-        let gpu_mesh = GpuMesh::new(first, count, new_aabb);
+        let gpu_mesh = GpuMesh::new(first, count, new_aabb, cpu_mesh.has_normals());
         self.assets.insert(handle, gpu_mesh);
 
         self.cpu_positions.extend(new_cpu_vertices);
         self.cpu_normals.extend(new_cpu_normals);
+        self.cpu_uvs.extend(new_cpu_uvs);
         self.cpu_face_indices.extend(new_cpu_face_indices);
 
         self.gpu_sync();
@@ -61,6 +67,7 @@ impl GpuMeshManager {
         if self.gpu_positions.is_none() {
             self.gpu_positions = Some(VertexBuffer::new());
             self.gpu_normals = Some(VertexBuffer::new());
+            self.gpu_uvs = Some(VertexBuffer::new());
             self.gpu_face_indices = Some(VertexBuffer::new());
         }
         let gpu_positions = self.gpu_positions.as_mut().unwrap();
@@ -69,6 +76,9 @@ impl GpuMeshManager {
         let gpu_normals = self.gpu_normals.as_mut().unwrap();
         gpu_normals.fill(&self.cpu_normals);
 
+        let gpu_uvs = self.gpu_uvs.as_mut().unwrap();
+        gpu_uvs.fill(&self.cpu_uvs);
+
         let gpu_face_indices = self.gpu_face_indices.as_mut().unwrap();
         gpu_face_indices.fill(&self.cpu_face_indices);
     }
@@ -89,27 +99,25 @@ impl GpuMeshManager {
     pub fn use_attributes(&self, program: &Program) {
         let gpu_positions = self.gpu_positions.as_ref().unwrap();
         let gpu_normals = self.gpu_normals.as_ref().unwrap();
+        let gpu_uvs = self.gpu_uvs.as_ref().unwrap();
         let gpu_face_indices = self.gpu_face_indices.as_ref().unwrap();
 
         program.use_vertex_attribute("vertex_world_position", gpu_positions);
         program.use_vertex_attribute_if_required("vertex_world_normal", gpu_normals);
-        program.use_vertex_attribute("vertex_face_index", gpu_face_indices);
+        program.use_vertex_attribute_if_required("vertex_uv", gpu_uvs);
+        program.use_vertex_attribute_if_required("vertex_face_index", gpu_face_indices);
     }
 
     pub fn render(
         &self,
         program: &Program,
         render_states: RenderStates,
-        camera: &Camera,
+        viewport: Viewport,
         draw_commands: Vec<DrawArraysIndirectCommand>,
     ) {
         self.use_attributes(program);
 
-        program.multi_draw_arrays_indirect(
-            render_states,
-            camera.viewport_or_default(),
-            draw_commands,
-        );
+        program.multi_draw_arrays_indirect(render_states, viewport, draw_commands);
     }
 }
 
@@ -117,11 +125,27 @@ pub struct GpuMesh {
     first: usize,
     count: usize,
     aabb: AxisAlignedBoundingBox,
+    // lit per vertex & interpolated, rather than per face
+    smooth_shading: bool,
 }
 
 impl GpuMesh {
-    pub fn new(first: usize, count: usize, aabb: AxisAlignedBoundingBox) -> Self {
-        Self { first, count, aabb }
+    pub fn new(
+        first: usize,
+        count: usize,
+        aabb: AxisAlignedBoundingBox,
+        smooth_shading: bool,
+    ) -> Self {
+        Self {
+            first,
+            count,
+            aabb,
+            smooth_shading,
+        }
+    }
+
+    pub fn smooth_shading(&self) -> bool {
+        self.smooth_shading
     }
 
     pub fn aabb(&self) -> AxisAlignedBoundingBox {
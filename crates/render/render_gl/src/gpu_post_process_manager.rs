@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+
+use bevy_ecs::system::Resource;
+
+use render_api::{
+    base::CpuTexture2D,
+    components::{PostEffect, Viewport},
+    resources::RenderPass,
+};
+use storage::SideStorage;
+
+use crate::{
+    core::{ClearState, GpuDepthTexture2D, GpuTexture2D, RenderTarget},
+    renderer::{
+        effect::{BloomEffect, ColorGradingEffect, FxaaEffect, OutlineEffect, VignetteEffect},
+        RenderTargetExt,
+    },
+    GpuMaterialManager, GpuMeshManager, GpuShadowManager, GpuSkinManager,
+};
+
+struct PostProcessTargets {
+    scene_color: GpuTexture2D,
+    scene_emissive: GpuTexture2D,
+    scene_depth: GpuDepthTexture2D,
+    // effects read from one & write to the other, swapping each step
+    effect_colors: [GpuTexture2D; 2],
+    bloom_colors: [GpuTexture2D; 2],
+}
+
+impl PostProcessTargets {
+    fn new(width: u32, height: u32) -> Self {
+        let color = || GpuTexture2D::new_empty::<[u8; 4]>(width, height);
+        Self {
+            scene_color: color(),
+            scene_emissive: color(),
+            scene_depth: GpuDepthTexture2D::new::<f32>(width, height),
+            effect_colors: [color(), color()],
+            bloom_colors: [color(), color()],
+        }
+    }
+
+    fn size(&self) -> (u32, u32) {
+        (self.scene_color.width(), self.scene_color.height())
+    }
+}
+
+// Renders passes which have post effects into intermediate textures, then runs the effects in
+// order with the last one drawing into the pass's real target.
+// The textures are kept between frames & only remade when the camera's viewport changes size.
+// Each render layer gets its own, so layers with differently sized viewports don't remake them
+// back & forth every frame.
+#[derive(Resource, Default)]
+pub struct GpuPostProcessManager {
+    targets: HashMap<usize, PostProcessTargets>,
+}
+
+impl GpuPostProcessManager {
+    #[allow(clippy::too_many_arguments)]
+    pub fn render(
+        &mut self,
+        render_layer_index: usize,
+        render_target: &RenderTarget,
+        gpu_mesh_manager: &GpuMeshManager,
+        gpu_material_manager: &GpuMaterialManager,
+        gpu_skin_manager: &GpuSkinManager,
+        gpu_shadow_manager: &mut GpuShadowManager,
+        textures: &SideStorage<CpuTexture2D, GpuTexture2D>,
+        mut render_pass: RenderPass,
+    ) {
+        let post_effects = std::mem::take(&mut render_pass.post_effects);
+        let camera = render_pass.camera_opt.as_mut().unwrap();
+        let viewport = camera.viewport_or_default();
+        let clear_state: ClearState = (&camera.clear_operation).into();
+        // the scene fills the intermediate textures, only the last effect draws at the camera's viewport
+        let scene_viewport = Viewport::new_at_origin(viewport.width, viewport.height);
+        camera.viewport = Some(scene_viewport);
+        let projection = render_pass.camera_projection_opt.unwrap();
+
+        let targets = self.targets(render_layer_index, viewport.width, viewport.height);
+
+        let scene_target = RenderTarget::new(
+            targets
+                .scene_color
+                .as_color_target()
+                .with_secondary(&targets.scene_emissive),
+            targets.scene_depth.as_depth_target(),
+        );
+        scene_target.clear(clear_state);
+        targets
+            .scene_emissive
+            .as_color_target()
+            .clear(ClearState::color(0.0, 0.0, 0.0, 1.0));
+        scene_target.render(
+            gpu_mesh_manager,
+            gpu_material_manager,
+            gpu_skin_manager,
+            gpu_shadow_manager,
+            textures,
+            render_pass,
+        );
+
+        let mut input = &targets.scene_color;
+        for (index, post_effect) in post_effects.iter().enumerate() {
+            // bloom blurs in its own targets, before the output is bound
+            let bloom_opt = match post_effect {
+                PostEffect::Bloom(settings) => {
+                    let effect = BloomEffect::new(*settings);
+                    let bloom_texture = effect.blur(&targets.scene_emissive, &targets.bloom_colors);
+                    Some((effect, bloom_texture))
+                }
+                _ => None,
+            };
+
+            let apply = |viewport: Viewport| match post_effect {
+                PostEffect::Fxaa => FxaaEffect::default().apply(input, viewport),
+                PostEffect::Bloom(_) => {
+                    let (effect, bloom_texture) = bloom_opt.as_ref().unwrap();
+                    effect.apply(input, bloom_texture, viewport);
+                }
+                PostEffect::Outline(settings) => OutlineEffect::new(*settings).apply(
+                    input,
+                    &targets.scene_depth,
+                    &projection,
+                    viewport,
+                ),
+                PostEffect::Vignette(settings) => {
+                    VignetteEffect::new(*settings).apply(input, viewport)
+                }
+                PostEffect::ColorGrading(settings) => {
+                    ColorGradingEffect::new(*settings).apply(input, viewport)
+                }
+            };
+
+            if index + 1 == post_effects.len() {
+                render_target.write(|| apply(viewport));
+            } else {
+                let output = &targets.effect_colors[index % 2];
+                output.as_color_target().write(|| apply(scene_viewport));
+                input = output;
+            }
+        }
+    }
+
+    fn targets(
+        &mut self,
+        render_layer_index: usize,
+        width: u32,
+        height: u32,
+    ) -> &PostProcessTargets {
+        let targets = self
+            .targets
+            .entry(render_layer_index)
+            .or_insert_with(|| PostProcessTargets::new(width, height));
+        if targets.size() != (width, height) {
+            *targets = PostProcessTargets::new(width, height);
+        }
+        targets
+    }
+}
@@ -0,0 +1,167 @@
+use bevy_ecs::system::Resource;
+
+use gl::DrawArraysIndirectCommand;
+use math::Mat4;
+use render_api::components::{Camera, Projection, Transform, TypedLight, Viewport};
+
+use crate::{
+    core::{ClearState, Context, Cull, GpuDepthTexture2D, GpuTexture2D, RenderStates},
+    renderer::{directional_shadow_matrix, point_shadow_matrices, RenderTargetExt},
+    GpuMeshManager,
+};
+
+const DIRECTIONAL_SHADOW_MAP_SIZE: u32 = 2048;
+const POINT_SHADOW_FACE_SIZE: u32 = 512;
+
+pub struct ShadowMap {
+    texture: GpuDepthTexture2D,
+    // one for a directional light, one per cube face for a point light
+    matrices: Vec<Mat4>,
+}
+
+impl ShadowMap {
+    fn new(width: u32, height: u32) -> Self {
+        Self {
+            texture: GpuDepthTexture2D::new::<f32>(width, height),
+            matrices: Vec::new(),
+        }
+    }
+
+    pub fn texture(&self) -> &GpuDepthTexture2D {
+        &self.texture
+    }
+
+    pub fn matrices(&self) -> &Vec<Mat4> {
+        &self.matrices
+    }
+
+    fn size(&self) -> (u32, u32) {
+        (self.texture.width(), self.texture.height())
+    }
+}
+
+// what a render pass's shadow maps are drawn from
+pub struct ShadowPass<'a> {
+    pub lights: &'a [TypedLight],
+    pub camera: &'a Camera,
+    pub camera_transform: &'a Transform,
+    pub camera_projection: &'a Projection,
+    pub commands: &'a [DrawArraysIndirectCommand],
+    pub instance_texture: &'a GpuTexture2D,
+}
+
+// Shadow maps for the shadow casting lights of the render pass being drawn.
+// Textures are pooled & reused across passes and frames, only growing when a pass needs more.
+#[derive(Resource, Default)]
+pub struct GpuShadowManager {
+    shadow_maps: Vec<ShadowMap>,
+    // the pooled map of each light in the current pass, by the light's index
+    light_shadow_maps: Vec<Option<usize>>,
+}
+
+impl GpuShadowManager {
+    pub fn get(&self, light_index: usize) -> Option<&ShadowMap> {
+        let map_index = (*self.light_shadow_maps.get(light_index)?)?;
+        self.shadow_maps.get(map_index)
+    }
+
+    pub fn render_shadow_maps(&mut self, gpu_mesh_manager: &GpuMeshManager, pass: ShadowPass) {
+        self.light_shadow_maps.clear();
+        let mut used = vec![false; self.shadow_maps.len()];
+
+        for light in pass.lights {
+            let (size, matrices) = match light {
+                TypedLight::Directional(light) if light.cast_shadows => (
+                    (DIRECTIONAL_SHADOW_MAP_SIZE, DIRECTIONAL_SHADOW_MAP_SIZE),
+                    vec![directional_shadow_matrix(
+                        light.direction,
+                        pass.camera,
+                        pass.camera_transform,
+                        pass.camera_projection,
+                        DIRECTIONAL_SHADOW_MAP_SIZE,
+                    )],
+                ),
+                TypedLight::Point(light) if light.cast_shadows => (
+                    (POINT_SHADOW_FACE_SIZE * 3, POINT_SHADOW_FACE_SIZE * 2),
+                    point_shadow_matrices(light.position, light.range()).to_vec(),
+                ),
+                _ => {
+                    self.light_shadow_maps.push(None);
+                    continue;
+                }
+            };
+
+            let free_index = (0..self.shadow_maps.len())
+                .find(|index| !used[*index] && self.shadow_maps[*index].size() == size);
+            let map_index = match free_index {
+                Some(index) => index,
+                None => {
+                    self.shadow_maps.push(ShadowMap::new(size.0, size.1));
+                    used.push(false);
+                    self.shadow_maps.len() - 1
+                }
+            };
+            used[map_index] = true;
+            self.light_shadow_maps.push(Some(map_index));
+
+            let shadow_map = &mut self.shadow_maps[map_index];
+            shadow_map.matrices = matrices;
+            render_depth(
+                shadow_map,
+                gpu_mesh_manager,
+                pass.commands,
+                pass.instance_texture,
+            );
+        }
+    }
+}
+
+fn render_depth(
+    shadow_map: &ShadowMap,
+    gpu_mesh_manager: &GpuMeshManager,
+    commands: &[DrawArraysIndirectCommand],
+    instance_texture: &GpuTexture2D,
+) {
+    // matrices are laid out left to right, then bottom to top, 3 across at most
+    let face_count = shadow_map.matrices.len() as u32;
+    let columns = face_count.min(3);
+    let rows = face_count.div_ceil(columns);
+    let face_width = shadow_map.texture.width() / columns;
+    let face_height = shadow_map.texture.height() / rows;
+
+    // only back faces are drawn, so surfaces don't shadow themselves
+    let render_states = RenderStates {
+        cull: Cull::Front,
+        ..Default::default()
+    };
+
+    let depth_target = shadow_map.texture.as_depth_target();
+    depth_target.clear(ClearState::depth(1.0));
+    depth_target.write(|| {
+        Context::get()
+            .program(
+                include_str!("shaders/shadow_depth.vert").to_string(),
+                include_str!("shaders/shadow_depth.frag").to_string(),
+                |program| {
+                    program.use_texture("instance_texture", instance_texture);
+                    for (face_index, matrix) in shadow_map.matrices.iter().enumerate() {
+                        let face_index = face_index as u32;
+                        let viewport = Viewport {
+                            x: ((face_index % columns) * face_width) as i32,
+                            y: ((face_index / columns) * face_height) as i32,
+                            width: face_width,
+                            height: face_height,
+                        };
+                        program.use_uniform("view_projection", *matrix);
+                        gpu_mesh_manager.render(
+                            program,
+                            render_states,
+                            viewport,
+                            commands.to_vec(),
+                        );
+                    }
+                },
+            )
+            .expect("Failed compiling shader");
+    });
+}
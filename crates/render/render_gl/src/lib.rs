@@ -5,6 +5,8 @@ pub(crate) mod window;
 mod exit_system;
 mod gpu_material_manager;
 mod gpu_mesh_manager;
+mod gpu_post_process_manager;
+mod gpu_shadow_manager;
 mod gpu_skin_manager;
 mod input;
 mod plugin;
@@ -14,6 +16,8 @@ mod sync;
 
 pub(crate) use gpu_material_manager::*;
 pub(crate) use gpu_mesh_manager::*;
+pub(crate) use gpu_post_process_manager::*;
+pub(crate) use gpu_shadow_manager::*;
 pub(crate) use gpu_skin_manager::*;
 
 pub use core::{apply_effect, Context, GpuTexture2D};
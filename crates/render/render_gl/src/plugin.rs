@@ -8,7 +8,10 @@ use clipboard::ClipboardPlugin;
 use kernel::KernelPlugin;
 use render_api::{Render, Window};
 
-use crate::{exit_system, input, render::render, runner::runner_func, sync::SyncPlugin, window};
+use crate::{
+    exit_system, input, render::render, runner::runner_func, sync::SyncPlugin, window,
+    GpuPostProcessManager, GpuShadowManager,
+};
 
 pub struct RenderGlPlugin;
 
@@ -29,6 +32,8 @@ impl Plugin for RenderGlPlugin {
             .set_runner(runner_func)
             // Resources
             .insert_resource(Window::default())
+            .init_resource::<GpuShadowManager>()
+            .init_resource::<GpuPostProcessManager>()
             // Systems
             .add_systems(PreStartup, window::sync)
             .add_systems(First, window::sync)
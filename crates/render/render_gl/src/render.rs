@@ -1,7 +1,9 @@
 use bevy_ecs::system::{NonSendMut, Res, ResMut};
 
 use render_api::{
-    base::CpuTexture2D, components::RenderTarget as CameraRenderTarget, resources::RenderFrame,
+    base::CpuTexture2D,
+    components::RenderTarget as CameraRenderTarget,
+    resources::{MeshLods, RenderFrame, RenderStats},
 };
 use storage::SideStorage;
 
@@ -9,7 +11,8 @@ use crate::{
     core::{GpuDepthTexture2D, GpuTexture2D, RenderTarget},
     renderer::RenderTargetExt,
     window::FrameInput,
-    GpuMaterialManager, GpuMeshManager, GpuSkinManager,
+    GpuMaterialManager, GpuMesh, GpuMeshManager, GpuPostProcessManager, GpuShadowManager,
+    GpuSkinManager,
 };
 
 pub fn render(
@@ -19,17 +22,22 @@ pub fn render(
     gpu_mesh_manager: Res<GpuMeshManager>,
     gpu_material_manager: Res<GpuMaterialManager>,
     gpu_skin_manager: Res<GpuSkinManager>,
+    mut gpu_shadow_manager: ResMut<GpuShadowManager>,
+    mut gpu_post_process_manager: ResMut<GpuPostProcessManager>,
+    mesh_lods: Res<MeshLods>,
+    mut render_stats: ResMut<RenderStats>,
     textures: ResMut<SideStorage<CpuTexture2D, GpuTexture2D>>,
     depth_textures: ResMut<SideStorage<CpuTexture2D, GpuDepthTexture2D>>,
 ) {
     let render_passes = render_frame.take_render_passes();
+    render_stats.clear();
 
     // Draw
-    for render_pass_opt in render_passes {
+    for (render_layer_index, render_pass_opt) in render_passes.into_iter().enumerate() {
         if render_pass_opt.is_none() {
             continue;
         }
-        let render_pass = render_pass_opt.unwrap();
+        let mut render_pass = render_pass_opt.unwrap();
 
         // let Some(render_layer) =  render_pass.render_layer else {
         //     panic!("RenderPass has no RenderLayer!")
@@ -40,7 +48,7 @@ pub fn render(
         //     info!("Rendering RenderLayer {:?}", render_layer);
         // }
 
-        let Some(camera) = render_pass.camera_opt.as_ref() else {
+        let Some(camera) = render_pass.camera_opt else {
             continue;
         };
 
@@ -56,14 +64,35 @@ pub fn render(
             }
         };
 
+        let stats = render_pass.cull(
+            |mesh_handle| gpu_mesh_manager.get(mesh_handle).map(GpuMesh::aabb),
+            &mesh_lods,
+        );
+        render_stats.record(render_layer_index, stats);
+
         // Clear the color and depth of the screen render target using the camera's clear color
         render_target.clear((&camera.clear_operation).into());
 
-        render_target.render(
-            &gpu_mesh_manager,
-            &gpu_material_manager,
-            &gpu_skin_manager,
-            render_pass,
-        );
+        if render_pass.post_effects.is_empty() {
+            render_target.render(
+                &gpu_mesh_manager,
+                &gpu_material_manager,
+                &gpu_skin_manager,
+                &mut gpu_shadow_manager,
+                &textures,
+                render_pass,
+            );
+        } else {
+            gpu_post_process_manager.render(
+                render_layer_index,
+                &render_target,
+                &gpu_mesh_manager,
+                &gpu_material_manager,
+                &gpu_skin_manager,
+                &mut gpu_shadow_manager,
+                &textures,
+                render_pass,
+            );
+        }
     }
 }
@@ -0,0 +1,88 @@
+use math::Vec2;
+use render_api::components::{BloomSettings, Viewport};
+
+use crate::{
+    core::{apply_effect, GpuTexture2D},
+    renderer::{effect::effect_render_states, RenderTargetExt},
+};
+
+///
+/// Blurs an emissive texture and adds it on top of a color texture, so glowing surfaces bleed
+/// light into their surroundings.
+///
+#[derive(Clone, Debug)]
+pub struct BloomEffect {
+    settings: BloomSettings,
+}
+
+impl BloomEffect {
+    pub fn new(settings: BloomSettings) -> Self {
+        Self { settings }
+    }
+
+    ///
+    /// Blurs the emissive texture horizontally into the first blur texture, then vertically
+    /// into the second, which is returned. All three must be the same size.
+    /// Binds its own targets, so must be called before writing to the target [BloomEffect::apply] draws into.
+    ///
+    pub fn blur<'a>(
+        &self,
+        emissive_texture: &GpuTexture2D,
+        blur_textures: &'a [GpuTexture2D; 2],
+    ) -> &'a GpuTexture2D {
+        let (w, h) = emissive_texture.resolution();
+        let texel = Vec2::new(1.0 / w as f32, 1.0 / h as f32);
+        self.blur_pass(emissive_texture, &blur_textures[0], Vec2::new(texel.x, 0.0));
+        self.blur_pass(
+            &blur_textures[0],
+            &blur_textures[1],
+            Vec2::new(0.0, texel.y),
+        );
+        &blur_textures[1]
+    }
+
+    fn blur_pass(&self, input: &GpuTexture2D, output: &GpuTexture2D, direction: Vec2) {
+        output.as_color_target().write(|| {
+            apply_effect(
+                &format!(
+                    "{}\n{}",
+                    input.fragment_shader_source(),
+                    include_str!("../../shaders/bloom_blur_effect.frag")
+                ),
+                effect_render_states(),
+                Viewport::new_at_origin(output.width(), output.height()),
+                |program| {
+                    input.use_uniforms(program);
+                    program.use_uniform("direction", direction);
+                    program.use_uniform("radius", self.settings.radius);
+                },
+            )
+        });
+    }
+
+    ///
+    /// Adds the blurred texture returned by [BloomEffect::blur] to the color texture.
+    /// Must be called in the callback given as input to a [RenderTarget], [ColorTarget] or [DepthTarget] write method.
+    ///
+    pub fn apply(
+        &self,
+        color_texture: &GpuTexture2D,
+        bloom_texture: &GpuTexture2D,
+        viewport: Viewport,
+    ) {
+        apply_effect(
+            &format!(
+                "{}\n{}",
+                color_texture.fragment_shader_source(),
+                include_str!("../../shaders/bloom_composite_effect.frag")
+            ),
+            effect_render_states(),
+            viewport,
+            |program| {
+                color_texture.use_uniforms(program);
+                program.use_texture("bloomMap", bloom_texture);
+                program.use_uniform("intensity", self.settings.intensity);
+            },
+        )
+    }
+}
@@ -0,0 +1,42 @@
+use render_api::components::{ColorGradingSettings, Viewport};
+
+use crate::{
+    core::{apply_effect, GpuTexture2D},
+    renderer::effect::effect_render_states,
+};
+
+///
+/// Adjusts the exposure, contrast, saturation & tint of the given color texture.
+///
+#[derive(Clone, Debug)]
+pub struct ColorGradingEffect {
+    settings: ColorGradingSettings,
+}
+
+impl ColorGradingEffect {
+    pub fn new(settings: ColorGradingSettings) -> Self {
+        Self { settings }
+    }
+
+    ///
+    /// Must be called in the callback given as input to a [RenderTarget], [ColorTarget] or [DepthTarget] write method.
+    ///
+    pub fn apply(&self, color_texture: &GpuTexture2D, viewport: Viewport) {
+        apply_effect(
+            &format!(
+                "{}\n{}",
+                color_texture.fragment_shader_source(),
+                include_str!("../../shaders/color_grading_effect.frag")
+            ),
+            effect_render_states(),
+            viewport,
+            |program| {
+                color_texture.use_uniforms(program);
+                program.use_uniform("exposure", self.settings.exposure);
+                program.use_uniform("contrast", self.settings.contrast);
+                program.use_uniform("saturation", self.settings.saturation);
+                program.use_uniform("tint", self.settings.tint.to_vec3());
+            },
+        )
+    }
+}
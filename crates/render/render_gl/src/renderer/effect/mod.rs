@@ -2,5 +2,26 @@
 //! A collection of image based effects, ie. effects applied to each pixel of a rendered image.
 //!
 
+mod bloom;
+mod color_grading;
 mod fxaa;
+mod outline;
+mod vignette;
+
+pub use bloom::BloomEffect;
+pub use color_grading::ColorGradingEffect;
 pub use fxaa::FxaaEffect;
+pub use outline::OutlineEffect;
+pub use vignette::VignetteEffect;
+
+use crate::core::{Cull, DepthTest, RenderStates, WriteMask};
+
+// effects overwrite the color of every pixel they cover, whatever the depth
+fn effect_render_states() -> RenderStates {
+    RenderStates {
+        write_mask: WriteMask::COLOR,
+        depth_test: DepthTest::Always,
+        cull: Cull::Back,
+        ..Default::default()
+    }
+}
@@ -0,0 +1,61 @@
+use math::Vec2;
+use render_api::components::{CameraProjection, OutlineSettings, Projection, Viewport};
+
+use crate::{
+    core::{apply_effect, GpuDepthTexture2D, GpuTexture2D},
+    renderer::effect::effect_render_states,
+};
+
+///
+/// Draws lines over the given color texture where the depth texture has edges,
+/// which outlines the silhouettes of meshes.
+///
+#[derive(Clone, Debug)]
+pub struct OutlineEffect {
+    settings: OutlineSettings,
+}
+
+impl OutlineEffect {
+    pub fn new(settings: OutlineSettings) -> Self {
+        Self { settings }
+    }
+
+    ///
+    /// The depth texture must have been rendered with the given projection.
+    /// Must be called in the callback given as input to a [RenderTarget], [ColorTarget] or [DepthTarget] write method.
+    ///
+    pub fn apply(
+        &self,
+        color_texture: &GpuTexture2D,
+        depth_texture: &GpuDepthTexture2D,
+        projection: &Projection,
+        viewport: Viewport,
+    ) {
+        apply_effect(
+            &format!(
+                "{}\n{}\n{}",
+                color_texture.fragment_shader_source(),
+                depth_texture.fragment_shader_source(),
+                include_str!("../../shaders/outline_effect.frag")
+            ),
+            effect_render_states(),
+            viewport,
+            |program| {
+                color_texture.use_uniforms(program);
+                depth_texture.use_uniforms(program);
+                let (w, h) = color_texture.resolution();
+                program.use_uniform("resolution", Vec2::new(w as f32, h as f32));
+                program.use_uniform("outline_color", self.settings.color.to_vec3());
+                program.use_uniform("thickness", self.settings.thickness);
+                program.use_uniform("depth_threshold", self.settings.depth_threshold);
+                program.use_uniform("near", projection.near());
+                program.use_uniform("far", projection.far());
+                let is_perspective = match projection {
+                    Projection::Perspective(_) => 1.0,
+                    Projection::Orthographic(_) => 0.0,
+                };
+                program.use_uniform("is_perspective", is_perspective);
+            },
+        )
+    }
+}
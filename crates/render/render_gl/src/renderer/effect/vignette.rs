@@ -0,0 +1,44 @@
+use math::Vec2;
+use render_api::components::{Viewport, VignetteSettings};
+
+use crate::{
+    core::{apply_effect, GpuTexture2D},
+    renderer::effect::effect_render_states,
+};
+
+///
+/// Darkens the given color texture towards its corners.
+///
+#[derive(Clone, Debug)]
+pub struct VignetteEffect {
+    settings: VignetteSettings,
+}
+
+impl VignetteEffect {
+    pub fn new(settings: VignetteSettings) -> Self {
+        Self { settings }
+    }
+
+    ///
+    /// Must be called in the callback given as input to a [RenderTarget], [ColorTarget] or [DepthTarget] write method.
+    ///
+    pub fn apply(&self, color_texture: &GpuTexture2D, viewport: Viewport) {
+        apply_effect(
+            &format!(
+                "{}\n{}",
+                color_texture.fragment_shader_source(),
+                include_str!("../../shaders/vignette_effect.frag")
+            ),
+            effect_render_states(),
+            viewport,
+            |program| {
+                color_texture.use_uniforms(program);
+                let (w, h) = color_texture.resolution();
+                program.use_uniform("resolution", Vec2::new(w as f32, h as f32));
+                program.use_uniform("intensity", self.settings.intensity);
+                program.use_uniform("radius", self.settings.radius);
+                program.use_uniform("softness", self.settings.softness.max(0.0001));
+            },
+        )
+    }
+}
@@ -2,6 +2,7 @@ use render_api::components::DirectionalLight;
 
 use crate::core::Program;
 use crate::renderer::Light;
+use crate::ShadowMap;
 
 impl Light for DirectionalLight {
     fn shader_source(&self, i: u32) -> String {
@@ -28,4 +29,30 @@ impl Light for DirectionalLight {
         light_dir *= -1.0;
         program.use_uniform(&format!("light_dir_{}", i), light_dir);
     }
+
+    fn casts_shadows(&self) -> bool {
+        self.cast_shadows
+    }
+
+    fn shadow_shader_source(&self, i: u32) -> String {
+        format!(
+            "
+                uniform mat4 shadow_matrix_{};
+                uniform sampler2D shadow_map_{};
+
+                float shadow_visibility_{}(vec3 position)
+                {{
+                    vec4 clip_position = shadow_matrix_{} * vec4(position, 1.0);
+                    vec3 ndc = clip_position.xyz / clip_position.w;
+                    return sample_shadow(shadow_map_{}, ndc.xy * 0.5 + 0.5, ndc.z * 0.5 + 0.5, vec2(0.0), vec2(1.0));
+                }}
+
+            ", i, i, i, i, i,
+        )
+    }
+
+    fn use_shadow_uniforms(&self, program: &Program, i: u32, shadow_map: &ShadowMap) {
+        program.use_uniform(&format!("shadow_matrix_{}", i), shadow_map.matrices()[0]);
+        program.use_depth_texture(&format!("shadow_map_{}", i), shadow_map.texture());
+    }
 }
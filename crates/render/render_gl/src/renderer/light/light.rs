@@ -1,10 +1,19 @@
 use render_api::components::TypedLight;
 
-use crate::core::Program;
+use crate::{core::Program, ShadowMap};
 
 pub trait Light {
     fn shader_source(&self, i: u32) -> String;
     fn use_uniforms(&self, program: &Program, i: u32);
+
+    fn casts_shadows(&self) -> bool {
+        false
+    }
+    // fragment shader source defining `float shadow_visibility_{i}(vec3 position)`
+    fn shadow_shader_source(&self, _i: u32) -> String {
+        String::new()
+    }
+    fn use_shadow_uniforms(&self, _program: &Program, _i: u32, _shadow_map: &ShadowMap) {}
 }
 
 impl Light for TypedLight {
@@ -23,4 +32,28 @@ impl Light for TypedLight {
             TypedLight::Point(light) => light.use_uniforms(program, i),
         }
     }
+
+    fn casts_shadows(&self) -> bool {
+        match self {
+            TypedLight::Ambient(light) => light.casts_shadows(),
+            TypedLight::Directional(light) => light.casts_shadows(),
+            TypedLight::Point(light) => light.casts_shadows(),
+        }
+    }
+
+    fn shadow_shader_source(&self, i: u32) -> String {
+        match self {
+            TypedLight::Ambient(light) => light.shadow_shader_source(i),
+            TypedLight::Directional(light) => light.shadow_shader_source(i),
+            TypedLight::Point(light) => light.shadow_shader_source(i),
+        }
+    }
+
+    fn use_shadow_uniforms(&self, program: &Program, i: u32, shadow_map: &ShadowMap) {
+        match self {
+            TypedLight::Ambient(light) => light.use_shadow_uniforms(program, i, shadow_map),
+            TypedLight::Directional(light) => light.use_shadow_uniforms(program, i, shadow_map),
+            TypedLight::Point(light) => light.use_shadow_uniforms(program, i, shadow_map),
+        }
+    }
 }
@@ -2,7 +2,7 @@ use math::*;
 
 use render_api::components::PointLight;
 
-use crate::{core::Program, renderer::Light};
+use crate::{core::Program, renderer::Light, ShadowMap};
 
 impl Light for PointLight {
     fn shader_source(&self, i: u32) -> String {
@@ -40,4 +40,46 @@ impl Light for PointLight {
         );
         program.use_uniform(&format!("light_position_{}", i), self.position);
     }
+
+    fn casts_shadows(&self) -> bool {
+        self.cast_shadows
+    }
+
+    // the six cube faces are laid out 3 across & 2 down, in the order +X, -X, +Y, -Y, +Z, -Z
+    fn shadow_shader_source(&self, i: u32) -> String {
+        format!(
+            "
+                uniform mat4 shadow_matrices_{}[6];
+                uniform vec3 shadow_light_position_{};
+                uniform sampler2D shadow_map_{};
+
+                float shadow_visibility_{}(vec3 position)
+                {{
+                    vec3 direction = position - shadow_light_position_{};
+                    vec3 distance = abs(direction);
+                    int face;
+                    if (distance.x >= distance.y && distance.x >= distance.z) {{
+                        face = direction.x > 0.0 ? 0 : 1;
+                    }} else if (distance.y >= distance.z) {{
+                        face = direction.y > 0.0 ? 2 : 3;
+                    }} else {{
+                        face = direction.z > 0.0 ? 4 : 5;
+                    }}
+
+                    vec4 clip_position = shadow_matrices_{}[face] * vec4(position, 1.0);
+                    vec3 ndc = clip_position.xyz / clip_position.w;
+                    vec2 tile_size = vec2(1.0 / 3.0, 0.5);
+                    vec2 tile_min = vec2(float(face % 3), float(face / 3)) * tile_size;
+                    return sample_shadow(shadow_map_{}, ndc.xy * 0.5 + 0.5, ndc.z * 0.5 + 0.5, tile_min, tile_size);
+                }}
+
+            ", i, i, i, i, i, i, i,
+        )
+    }
+
+    fn use_shadow_uniforms(&self, program: &Program, i: u32, shadow_map: &ShadowMap) {
+        program.use_uniform_array(&format!("shadow_matrices_{}", i), shadow_map.matrices());
+        program.use_uniform(&format!("shadow_light_position_{}", i), self.position);
+        program.use_depth_texture(&format!("shadow_map_{}", i), shadow_map.texture());
+    }
 }
@@ -1,5 +1,15 @@
+use math::{Mat4, Vec3, Vec4};
+
+use render_api::components::{Camera, CameraProjection, Projection, Transform};
+
 use crate::renderer::Light;
 
+// world units past the camera's view that can still cast a shadow into it
+const SHADOW_CASTER_MARGIN: f32 = 500.0;
+// shadows are only fitted this far out from the camera
+const MAX_SHADOW_DISTANCE: f32 = 1500.0;
+const POINT_SHADOW_NEAR: f32 = 1.0;
+
 pub fn lights_shader_source(lights: &[&dyn Light]) -> String {
     let mut shader_source = String::new();
     shader_source.push_str(include_str!("../../shaders/shared.vert"));
@@ -7,7 +17,18 @@ pub fn lights_shader_source(lights: &[&dyn Light]) -> String {
     let mut dir_fun = String::new();
     for (i, light) in lights.iter().enumerate() {
         shader_source.push_str(&light.shader_source(i as u32));
-        dir_fun.push_str(&format!("color += calculate_single_light_{}(position, normal, view_direction, material_color, material_shine);\n", i))
+        if light.casts_shadows() {
+            // left to the fragment shader, which knows whether the fragment is in shadow
+            shader_source.push_str(&format!("flat out vec3 shadowed_color_{};\n", i));
+            shader_source.push_str(&format!("out vec3 smooth_shadowed_color_{};\n", i));
+            dir_fun.push_str(&format!("shadowed_color_{} = calculate_single_light_{}(position, normal, view_direction, material_color, material_shine);\n", i, i));
+            dir_fun.push_str(&format!(
+                "smooth_shadowed_color_{} = shadowed_color_{};\n",
+                i, i
+            ))
+        } else {
+            dir_fun.push_str(&format!("color += calculate_single_light_{}(position, normal, view_direction, material_color, material_shine);\n", i))
+        }
     }
     shader_source.push_str(&format!(
         "
@@ -27,26 +48,121 @@ pub fn lights_shader_source(lights: &[&dyn Light]) -> String {
     shader_source
 }
 
-// pub(crate) fn shadow_matrix(
-//     camera: &Camera,
-//     projection: &Projection,
-//     transform: &Transform,
-// ) -> Mat4 {
-//     let bias_matrix = Mat4::from_cols(
-//         Vec4::new(0.5, 0.0, 0.0, 0.0),
-//         Vec4::new(0.0, 0.5, 0.0, 0.0),
-//         Vec4::new(0.0, 0.0, 0.5, 0.0),
-//         Vec4::new(0.5, 0.5, 0.5, 1.0),
-//     );
-//     bias_matrix
-//         * projection.projection_matrix(&camera.viewport_or_default())
-//         * transform.compute_matrix()
-// }
-
-// pub(crate) fn compute_up_direction(direction: Vec3) -> Vec3 {
-//     if Vec3::X.dot(direction).abs() > 0.9 {
-//         (Vec3::Y.cross(direction)).normalize()
-//     } else {
-//         (Vec3::X.cross(direction)).normalize()
-//     }
-// }
+pub fn shadows_shader_source(lights: &[&dyn Light]) -> String {
+    let mut shader_source = String::new();
+    shader_source.push_str(include_str!("../../shaders/shared.vert"));
+    shader_source.push_str(include_str!("../../shaders/shading.frag"));
+    shader_source.push_str(include_str!("../../shaders/shadow.frag"));
+    let mut shadow_fun = String::new();
+    for (i, light) in lights.iter().enumerate() {
+        if !light.casts_shadows() {
+            continue;
+        }
+        shader_source.push_str(&format!("flat in vec3 shadowed_color_{};\n", i));
+        shader_source.push_str(&format!("in vec3 smooth_shadowed_color_{};\n", i));
+        shader_source.push_str(&light.shadow_shader_source(i as u32));
+        shadow_fun.push_str(&format!(
+            "color += shaded(shadowed_color_{}, smooth_shadowed_color_{}) * shadow_visibility_{}(position);\n",
+            i, i, i
+        ));
+    }
+    shader_source.push_str(&format!(
+        "
+            vec3 calculate_shadowed_light(vec3 position)
+            {{
+                vec3 color = vec3(0.0, 0.0, 0.0);
+                {}
+                return color;
+            }}
+            ",
+        &shadow_fun
+    ));
+    shader_source
+}
+
+// A light-space view projection for a directional light, fitted around what the camera can see
+pub(crate) fn directional_shadow_matrix(
+    direction: Vec3,
+    camera: &Camera,
+    camera_transform: &Transform,
+    camera_projection: &Projection,
+    shadow_map_size: u32,
+) -> Mat4 {
+    let view_projection = camera_projection.projection_matrix(&camera.viewport_or_default())
+        * camera_transform.view_matrix();
+    let inverse_view_projection = view_projection.inverse();
+    let unproject = |x: f32, y: f32, z: f32| {
+        let position = inverse_view_projection * Vec4::new(x, y, z, 1.0);
+        position.truncate() / position.w
+    };
+
+    // the camera frustum's corners, with the far plane pulled in to the max shadow distance
+    let near = camera_projection.near();
+    let far = camera_projection.far();
+    let far_t = if far > near {
+        ((MAX_SHADOW_DISTANCE - near) / (far - near)).clamp(0.0, 1.0)
+    } else {
+        1.0
+    };
+    let mut corners = Vec::with_capacity(8);
+    for (x, y) in [(-1.0, -1.0), (1.0, -1.0), (-1.0, 1.0), (1.0, 1.0)] {
+        let near_corner = unproject(x, y, 0.0);
+        let far_corner = unproject(x, y, 1.0);
+        corners.push(near_corner);
+        corners.push(near_corner.lerp(far_corner, far_t));
+    }
+    let center = corners.iter().copied().sum::<Vec3>() / corners.len() as f32;
+
+    let direction = direction.normalize();
+    let light_view = Mat4::look_at_lh(center, center + direction, compute_up_direction(direction));
+    let mut min = Vec3::splat(f32::INFINITY);
+    let mut max = Vec3::splat(f32::NEG_INFINITY);
+    for corner in &corners {
+        let light_corner = light_view.transform_point3(*corner);
+        min = min.min(light_corner);
+        max = max.max(light_corner);
+    }
+
+    // snap the bounds to whole texels so shadow edges don't crawl as the camera moves
+    let texel_size =
+        ((max.x - min.x).max(max.y - min.y) / shadow_map_size as f32).max(f32::EPSILON);
+    min.x = (min.x / texel_size).floor() * texel_size;
+    min.y = (min.y / texel_size).floor() * texel_size;
+    max.x = (max.x / texel_size).ceil() * texel_size;
+    max.y = (max.y / texel_size).ceil() * texel_size;
+
+    let light_projection = Mat4::orthographic_lh(
+        min.x,
+        max.x,
+        min.y,
+        max.y,
+        min.z - SHADOW_CASTER_MARGIN,
+        max.z,
+    );
+    light_projection * light_view
+}
+
+// One view projection per cube face, in the order +X, -X, +Y, -Y, +Z, -Z
+pub(crate) fn point_shadow_matrices(position: Vec3, range: f32) -> [Mat4; 6] {
+    let far = range.clamp(POINT_SHADOW_NEAR + 1.0, MAX_SHADOW_DISTANCE);
+    let projection = Mat4::perspective_lh(std::f32::consts::FRAC_PI_2, 1.0, POINT_SHADOW_NEAR, far);
+    let face = |direction: Vec3, up: Vec3| {
+        projection * Mat4::look_at_lh(position, position + direction, up)
+    };
+    [
+        face(Vec3::X, Vec3::Y),
+        face(Vec3::NEG_X, Vec3::Y),
+        face(Vec3::Y, Vec3::Z),
+        face(Vec3::NEG_Y, Vec3::Z),
+        face(Vec3::Z, Vec3::Y),
+        face(Vec3::NEG_Z, Vec3::Y),
+    ]
+}
+
+pub(crate) fn compute_up_direction(direction: Vec3) -> Vec3 {
+    if Vec3::X.dot(direction).abs() > 0.9 {
+        (Vec3::Y.cross(direction)).normalize()
+    } else {
+        (Vec3::X.cross(direction)).normalize()
+    }
+}
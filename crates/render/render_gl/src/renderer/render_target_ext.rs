@@ -1,18 +1,16 @@
-use std::collections::HashMap;
-
 use gl::DrawArraysIndirectCommand;
 use math::Mat4;
 use render_api::{
-    base::CpuMesh,
-    components::{Camera, CameraProjection, Projection, Transform},
-    resources::{MaterialOrSkinHandle, RenderPass},
+    base::{Color, CpuTexture2D},
+    components::{Camera, CameraProjection, Projection, Transform, TypedLight},
+    resources::{MaterialOrSkinHandle, MeshInstances, RenderPass},
 };
-use storage::Handle;
+use storage::SideStorage;
 
 use crate::{
     core::{Context, Cull, GpuTexture2D, RenderStates},
     renderer::{lights_shader_source, Light},
-    GpuMaterialManager, GpuMeshManager, GpuSkinManager,
+    GpuMaterialManager, GpuMeshManager, GpuShadowManager, GpuSkinManager, ShadowPass,
 };
 
 pub trait RenderTargetExt {
@@ -25,18 +23,57 @@ pub trait RenderTargetExt {
         gpu_mesh_manager: &GpuMeshManager,
         gpu_material_manager: &GpuMaterialManager,
         gpu_skin_manager: &GpuSkinManager,
+        gpu_shadow_manager: &mut GpuShadowManager,
+        textures: &SideStorage<CpuTexture2D, GpuTexture2D>,
         render_pass: RenderPass,
     ) -> &Self {
-        let RenderPass {
-            camera_opt,
-            camera_transform_opt,
-            camera_projection_opt,
-            lights,
-            meshes,
-        } = render_pass;
-        let camera = camera_opt.unwrap();
-        let camera_transform = camera_transform_opt.unwrap();
-        let camera_projection = camera_projection_opt.unwrap();
+        let camera = render_pass.camera_opt.unwrap();
+        let camera_transform = render_pass.camera_transform_opt.unwrap();
+        let camera_projection = render_pass.camera_projection_opt.unwrap();
+        let lights = &render_pass.lights;
+
+        if !gpu_skin_manager.is_ready()
+            || !gpu_material_manager.is_ready()
+            || !gpu_mesh_manager.is_ready()
+        {
+            return self;
+        }
+        let (commands, instance_texture) = meshes_to_commands(
+            render_pass.drawn_instances(),
+            gpu_mesh_manager,
+            gpu_material_manager,
+            gpu_skin_manager,
+        );
+
+        // casters outside the camera's view still shadow what is in it, so they aren't culled
+        let shadow_casters = lights.iter().any(TypedLight::casts_shadows).then(|| {
+            meshes_to_commands(
+                render_pass.shadow_casters(),
+                gpu_mesh_manager,
+                gpu_material_manager,
+                gpu_skin_manager,
+            )
+        });
+        let (shadow_commands, shadow_instance_texture) = match &shadow_casters {
+            Some((shadow_commands, shadow_instance_texture)) => {
+                (shadow_commands, shadow_instance_texture)
+            }
+            // no light casts shadows, so no shadow map is drawn from these
+            None => (&commands, &instance_texture),
+        };
+
+        // shadow maps are drawn into their own targets, so before this one is bound
+        gpu_shadow_manager.render_shadow_maps(
+            gpu_mesh_manager,
+            ShadowPass {
+                lights,
+                camera: &camera,
+                camera_transform: &camera_transform,
+                camera_projection: &camera_projection,
+                commands: shadow_commands,
+                instance_texture: shadow_instance_texture,
+            },
+        );
 
         let light_refs: Vec<&dyn Light> = lights.iter().map(|item| item as &dyn Light).collect();
 
@@ -45,11 +82,14 @@ pub trait RenderTargetExt {
                 gpu_mesh_manager,
                 gpu_material_manager,
                 gpu_skin_manager,
+                gpu_shadow_manager,
+                textures,
                 &camera,
                 &camera_transform,
                 &camera_projection,
                 &light_refs,
-                meshes,
+                commands,
+                &instance_texture,
             );
         });
         self
@@ -60,35 +100,30 @@ fn render<'a>(
     gpu_mesh_manager: &'a GpuMeshManager,
     gpu_material_manager: &'a GpuMaterialManager,
     gpu_skin_manager: &'a GpuSkinManager,
+    gpu_shadow_manager: &'a GpuShadowManager,
+    textures: &SideStorage<CpuTexture2D, GpuTexture2D>,
     camera: &Camera,
     camera_transform: &Transform,
     camera_projection: &Projection,
     lights: &[&dyn Light],
-    meshes: HashMap<Handle<CpuMesh>, Vec<(MaterialOrSkinHandle, Mat4)>>,
+    commands: Vec<DrawArraysIndirectCommand>,
+    instance_texture: &GpuTexture2D,
 ) {
-    if !gpu_skin_manager.is_ready()
-        || !gpu_material_manager.is_ready()
-        || !gpu_mesh_manager.is_ready()
-    {
-        return;
-    }
-    let (commands, instance_texture) = meshes_to_commands(
-        meshes,
-        gpu_mesh_manager,
-        gpu_material_manager,
-        gpu_skin_manager,
-    );
-
     let render_states = RenderStates {
         cull: Cull::Back,
         ..Default::default()
     };
-    let fragment_shader = gpu_material_manager.fragment_shader();
+    let fragment_shader = gpu_material_manager.fragment_shader(lights);
     let vertex_shader_source = vertex_shader_source(lights);
     Context::get()
         .program(vertex_shader_source, fragment_shader.source, |program| {
-            gpu_material_manager.use_uniforms(program, camera_transform, lights);
+            gpu_material_manager.use_uniforms(program, camera_transform, lights, textures);
             gpu_skin_manager.use_uniforms(program);
+            for (i, light) in lights.iter().enumerate() {
+                if let Some(shadow_map) = gpu_shadow_manager.get(i) {
+                    light.use_shadow_uniforms(program, i as u32, shadow_map);
+                }
+            }
 
             program.use_uniform(
                 "view_projection",
@@ -96,15 +131,20 @@ fn render<'a>(
                     * camera_transform.view_matrix(),
             );
 
-            program.use_texture("instance_texture", &instance_texture);
+            program.use_texture("instance_texture", instance_texture);
 
-            gpu_mesh_manager.render(program, render_states, camera, commands);
+            gpu_mesh_manager.render(
+                program,
+                render_states,
+                camera.viewport_or_default(),
+                commands,
+            );
         })
         .expect("Failed compiling shader");
 }
 
 fn meshes_to_commands(
-    mut mesh_handle_transform_map: HashMap<Handle<CpuMesh>, Vec<(MaterialOrSkinHandle, Mat4)>>,
+    mut mesh_handle_transform_map: MeshInstances<'_>,
     gpu_mesh_manager: &GpuMeshManager,
     gpu_mat_manager: &GpuMaterialManager,
     gpu_skin_manager: &GpuSkinManager,
@@ -143,7 +183,12 @@ fn meshes_to_commands(
             0,
         ));
 
-        let instance_row = get_instance_row(gpu_mat_manager, gpu_skin_manager, instances);
+        let instance_row = get_instance_row(
+            gpu_mat_manager,
+            gpu_skin_manager,
+            instances,
+            gpu_mesh.smooth_shading(),
+        );
         instances_rows.push(instance_row);
     }
 
@@ -172,9 +217,11 @@ fn meshes_to_commands(
 fn get_instance_row(
     gpu_mat_manager: &GpuMaterialManager,
     gpu_skin_manager: &GpuSkinManager,
-    instances: Vec<(MaterialOrSkinHandle, Mat4)>,
+    instances: Vec<&(MaterialOrSkinHandle, Mat4)>,
+    smooth_shading: bool,
 ) -> Vec<[f32; 4]> {
     let mut instance_row = Vec::new();
+    let smooth_shading = if smooth_shading { 1.0 } else { 0.0 };
 
     let indices = {
         // No need to order, just return the indices as is.
@@ -183,7 +230,7 @@ fn get_instance_row(
 
     // Next, we can compute the instance buffers with that ordering.
     {
-        for (mat_handle, transform) in indices.iter().map(|i| instances[*i]) {
+        for (mat_handle, transform) in indices.iter().map(|i| *instances[*i]) {
             instance_row.push(transform.row(0).to_array());
             instance_row.push(transform.row(1).to_array());
             instance_row.push(transform.row(2).to_array());
@@ -192,12 +239,22 @@ fn get_instance_row(
                 MaterialOrSkinHandle::Material(mat_handle) => {
                     let has_skin = -100.0;
                     let mat_index = gpu_mat_manager.get(&mat_handle).unwrap();
-                    instance_row.push([has_skin, mat_index.index() as f32, 0.0, 0.0]);
+                    instance_row.push([has_skin, mat_index.index() as f32, smooth_shading, 0.0]);
                 }
                 MaterialOrSkinHandle::Skin(skin_handle) => {
                     let has_skin = 100.0;
                     let skin_index = gpu_skin_manager.get(&skin_handle).unwrap();
-                    instance_row.push([has_skin, skin_index.index() as f32, 0.0, 0.0]);
+                    instance_row.push([has_skin, skin_index.index() as f32, smooth_shading, 0.0]);
+                }
+                MaterialOrSkinHandle::TintedMaterial(mat_handle, tint) => {
+                    let has_skin = -100.0;
+                    let mat_index = gpu_mat_manager.get(&mat_handle).unwrap();
+                    instance_row.push([
+                        has_skin,
+                        mat_index.index() as f32,
+                        smooth_shading,
+                        pack_tint(tint),
+                    ]);
                 }
             }
         }
@@ -206,6 +263,12 @@ fn get_instance_row(
     instance_row
 }
 
+// 0 is untinted, otherwise 1 + the 24 bit rgb, which a f32 holds exactly
+fn pack_tint(tint: Color) -> f32 {
+    let rgb = ((tint.r as u32) << 16) | ((tint.g as u32) << 8) | (tint.b as u32);
+    (rgb + 1) as f32
+}
+
 fn vertex_shader_source(lights: &[&dyn Light]) -> String {
     let mut output = lights_shader_source(lights);
     output.push_str(include_str!("../shaders/mesh.vert"));
@@ -0,0 +1,22 @@
+uniform vec2 direction;
+uniform float radius;
+
+in vec2 uvs;
+
+layout (location = 0) out vec4 color;
+
+// one axis of a separable gaussian blur, `direction` is a texel step along that axis
+void main()
+{
+    float sigma = max(radius, 1.0) * 0.5;
+    float step_size = max(radius, 1.0) / 8.0;
+    vec3 total = vec3(0.0);
+    float total_weight = 0.0;
+    for (int i = -8; i <= 8; i++) {
+        float offset = float(i) * step_size;
+        float weight = exp(-(offset * offset) / (2.0 * sigma * sigma));
+        total += sample_color(uvs + direction * offset).rgb * weight;
+        total_weight += weight;
+    }
+    color = vec4(total / total_weight, 1.0);
+}
@@ -0,0 +1,14 @@
+uniform sampler2D bloomMap;
+uniform float intensity;
+
+in vec2 uvs;
+
+layout (location = 0) out vec4 color;
+
+void main()
+{
+    vec3 scene = sample_color(uvs).rgb;
+    vec3 bloom = texture(bloomMap, uvs).rgb * intensity;
+    // screen blend, so bright areas don't clip to white
+    color = vec4(vec3(1.0) - (vec3(1.0) - scene) * (vec3(1.0) - bloom), 1.0);
+}
@@ -0,0 +1,18 @@
+uniform float exposure;
+uniform float contrast;
+uniform float saturation;
+uniform vec3 tint;
+
+in vec2 uvs;
+
+layout (location = 0) out vec4 color;
+
+void main()
+{
+    vec3 graded = sample_color(uvs).rgb * exp2(exposure);
+    graded = (graded - 0.5) * contrast + 0.5;
+    float luminance = dot(graded, vec3(0.2126, 0.7152, 0.0722));
+    graded = mix(vec3(luminance), graded, saturation);
+    graded *= tint;
+    color = vec4(clamp(graded, 0.0, 1.0), 1.0);
+}
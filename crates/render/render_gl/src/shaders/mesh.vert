@@ -8,9 +8,17 @@ uniform sampler2D skin_texture;
 
 in vec3 vertex_world_position;
 in vec3 vertex_world_normal;
+in vec2 vertex_uv;
 in uint vertex_face_index;
 
 flat out vec3 color;
+out vec3 smooth_color;
+flat out float smooth_shading;
+flat out vec3 emissive;
+out vec3 frag_world_position;
+out vec2 frag_uv;
+flat out int texture_slot;
+flat out vec3 texture_tint;
 
 vec4 get_transform(int row_index) {
     highp int x_coord_i = (gl_InstanceID * 4) + row_index;
@@ -76,6 +84,7 @@ void main()
     world_position /= world_position.w;
     vec3 transformed_vertex_world_position = world_position.xyz;
     gl_Position = view_projection * world_position;
+    frag_world_position = transformed_vertex_world_position;
 
     // normal
     // TODO: send this via a uniform instead of calculating here?
@@ -84,6 +93,7 @@ void main()
 
     // material
     bool has_skin = instance_data.x > 0.0;
+    smooth_shading = instance_data.z;
     int material_index;
 
     if (has_skin) {
@@ -96,20 +106,31 @@ void main()
     vec4 material_data1 = get_material_data(material_index, 0);
     vec4 material_data2 = get_material_data(material_index, 1);
     vec3 material_color = vec3(material_data1.x, material_data1.y, material_data1.z);
+    if (instance_data.w > 0.0) {
+        int tint = int(instance_data.w) - 1;
+        material_color *= vec3((tint >> 16) & 255, (tint >> 8) & 255, tint & 255) / 255.0;
+    }
     float material_emissive = material_data1.w;
     float material_shine_size = max(1.0, material_data2.x);
     float material_shine_amount = material_data2.y;
     vec2 material_shine = vec2(material_shine_size, material_shine_amount);
 
-    // color
-    color = (material_emissive * material_color);
-    color = color + calculate_total_light(
+    // texture
+    texture_slot = int(material_data2.z);
+    texture_tint = material_color;
+    frag_uv = vertex_uv;
+
+    // textured materials are lit as white, the fragment shader multiplies in the texel
+    vec3 surface_color = texture_slot >= 0 ? vec3(1.0) : material_color;
+
+    // color, tone mapped in the fragment shader once shadowed lights are added
+    emissive = (material_emissive * surface_color);
+    color = emissive + calculate_total_light(
         camera_position,
         transformed_vertex_world_position,
         transformed_vertex_world_normal,
-        material_color,
+        surface_color,
         material_shine
     );
-    color = reinhard_tone_mapping(color);
-    color = srgb_from_rgb(color);
-}
\ No newline at end of file
+    smooth_color = color;
+}
@@ -0,0 +1,38 @@
+uniform vec2 resolution;
+uniform vec3 outline_color;
+uniform float thickness;
+uniform float depth_threshold;
+uniform float near;
+uniform float far;
+uniform float is_perspective;
+
+in vec2 uvs;
+
+layout (location = 0) out vec4 color;
+
+// distance from the camera, the projections map it to 0..1 in NDC which GL stores as 0.5..1
+float linear_depth(vec2 uv)
+{
+    float ndc = sample_depth(uv) * 2.0 - 1.0;
+    if (is_perspective < 0.5) {
+        return near + ndc * (far - near);
+    }
+    return (near * far) / (far - ndc * (far - near));
+}
+
+void main()
+{
+    vec2 texel = thickness / resolution;
+    float center = linear_depth(uvs);
+    float left = linear_depth(uvs - vec2(texel.x, 0.0));
+    float right = linear_depth(uvs + vec2(texel.x, 0.0));
+    float down = linear_depth(uvs - vec2(0.0, texel.y));
+    float up = linear_depth(uvs + vec2(0.0, texel.y));
+
+    // the largest jump to a neighbour, relative to how far away this pixel is
+    float difference = max(max(abs(left - center), abs(right - center)), max(abs(down - center), abs(up - center)));
+    float edge = step(depth_threshold, difference / max(center, 0.0001));
+
+    vec3 scene = sample_color(uvs).rgb;
+    color = vec4(mix(scene, outline_color, edge), 1.0);
+}
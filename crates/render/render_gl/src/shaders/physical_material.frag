@@ -1,9 +1,63 @@
-
 flat in vec3 color;
+in vec3 smooth_color;
+flat in vec3 emissive;
+in vec3 frag_world_position;
+in vec2 frag_uv;
+flat in int texture_slot;
+flat in vec3 texture_tint;
+
+uniform sampler2D material_texture_0;
+uniform sampler2D material_texture_1;
+uniform sampler2D material_texture_2;
+uniform sampler2D material_texture_3;
+uniform sampler2D material_texture_4;
+uniform sampler2D material_texture_5;
+uniform sampler2D material_texture_6;
+uniform sampler2D material_texture_7;
 
 layout (location = 0) out vec3 final_color;
+// only kept when post-processing binds a second target, read by bloom
+layout (location = 1) out vec3 emissive_color;
+
+// sampler arrays can't be indexed by a per-triangle value, so each slot gets a branch
+vec3 sample_material_texture(int slot, vec2 uv)
+{
+    if (slot == 0) {
+        return texture(material_texture_0, uv).rgb;
+    }
+    if (slot == 1) {
+        return texture(material_texture_1, uv).rgb;
+    }
+    if (slot == 2) {
+        return texture(material_texture_2, uv).rgb;
+    }
+    if (slot == 3) {
+        return texture(material_texture_3, uv).rgb;
+    }
+    if (slot == 4) {
+        return texture(material_texture_4, uv).rgb;
+    }
+    if (slot == 5) {
+        return texture(material_texture_5, uv).rgb;
+    }
+    if (slot == 6) {
+        return texture(material_texture_6, uv).rgb;
+    }
+    if (slot == 7) {
+        return texture(material_texture_7, uv).rgb;
+    }
+    return vec3(1.0);
+}
 
 void main()
 {
-    final_color = color;
-}
\ No newline at end of file
+    vec3 surface_color = vec3(1.0);
+    if (texture_slot >= 0) {
+        surface_color = texture_tint * sample_material_texture(texture_slot, frag_uv);
+    }
+
+    vec3 total_color = shaded(color, smooth_color) + calculate_shadowed_light(frag_world_position);
+    total_color = reinhard_tone_mapping(total_color * surface_color);
+    final_color = srgb_from_rgb(total_color);
+    emissive_color = srgb_from_rgb(reinhard_tone_mapping(emissive * surface_color));
+}
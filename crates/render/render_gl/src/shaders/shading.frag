@@ -0,0 +1,7 @@
+flat in float smooth_shading;
+
+// meshes with vertex normals interpolate their lighting, the rest are lit per face
+vec3 shaded(vec3 flat_color, vec3 smooth_color)
+{
+    return smooth_shading > 0.5 ? smooth_color : flat_color;
+}
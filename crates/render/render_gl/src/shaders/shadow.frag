@@ -0,0 +1,27 @@
+
+#define SHADOW_BIAS 0.002
+
+// 1.0 where `position` is lit, 0.0 where it's in shadow, softened over the neighbouring texels.
+// `tile_min` & `tile_size` pick the region of `shadow_map` that `uv` is relative to
+float sample_shadow(sampler2D shadow_map, vec2 uv, float depth, vec2 tile_min, vec2 tile_size)
+{
+    if (uv.x < 0.0 || uv.x > 1.0 || uv.y < 0.0 || uv.y > 1.0 || depth > 1.0) {
+        return 1.0;
+    }
+
+    vec2 texel_size = 1.0 / vec2(textureSize(shadow_map, 0));
+    vec2 tile_max = tile_min + tile_size - texel_size;
+    vec2 center = tile_min + uv * tile_size;
+
+    float shadowed = 0.0;
+    for (int x = -1; x <= 1; x++) {
+        for (int y = -1; y <= 1; y++) {
+            vec2 sample_uv = clamp(center + vec2(float(x), float(y)) * texel_size, tile_min, tile_max);
+            float closest_depth = texture(shadow_map, sample_uv).x;
+            if (depth - SHADOW_BIAS > closest_depth) {
+                shadowed += 1.0;
+            }
+        }
+    }
+    return 1.0 - (shadowed / 9.0);
+}
@@ -0,0 +1,28 @@
+
+uniform mat4 view_projection;
+
+uniform sampler2D instance_texture;
+
+in vec3 vertex_world_position;
+
+vec4 get_transform(int row_index) {
+    highp int x_coord_i = (gl_InstanceID * 4) + row_index;
+    highp int y_coord_i = gl_DrawID;
+
+    return texelFetch(instance_texture, ivec2(x_coord_i, y_coord_i), 0);
+}
+
+void main()
+{
+    vec4 transform_row1 = get_transform(0);
+    vec4 transform_row2 = get_transform(1);
+    vec4 transform_row3 = get_transform(2);
+
+    mat4 transform;
+    transform[0] = vec4(transform_row1.x, transform_row2.x, transform_row3.x, 0.0);
+    transform[1] = vec4(transform_row1.y, transform_row2.y, transform_row3.y, 0.0);
+    transform[2] = vec4(transform_row1.z, transform_row2.z, transform_row3.z, 0.0);
+    transform[3] = vec4(transform_row1.w, transform_row2.w, transform_row3.w, 1.0);
+
+    gl_Position = view_projection * transform * vec4(vertex_world_position, 1.0);
+}
@@ -0,0 +1,20 @@
+uniform vec2 resolution;
+uniform float intensity;
+uniform float radius;
+uniform float softness;
+
+in vec2 uvs;
+
+layout (location = 0) out vec4 color;
+
+void main()
+{
+    // distance from the center, stretched so the vignette stays round on wide images
+    vec2 offset = (uvs - 0.5) * 2.0;
+    offset.x *= resolution.x / resolution.y;
+    float distance_from_center = length(offset);
+    float darkness = smoothstep(radius, radius + softness, distance_from_center) * intensity;
+
+    vec3 scene = sample_color(uvs).rgb;
+    color = vec4(scene * (1.0 - darkness), 1.0);
+}
@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+
+use bevy_ecs::system::Resource;
+
+use render_api::{
+    base::{CpuTexture2D, CpuTextureData},
+    components::ClearOperation,
+};
+use storage::Handle;
+
+///
+/// Color & depth buffers for one render target. Rows are stored bottom to top, as in OpenGL,
+/// so window coordinates index straight into them.
+///
+pub struct Framebuffer {
+    width: u32,
+    height: u32,
+    color: Vec<[u8; 4]>,
+    depth: Vec<f32>,
+}
+
+impl Framebuffer {
+    pub fn new(width: u32, height: u32) -> Self {
+        let pixel_count = (width * height) as usize;
+        Self {
+            width,
+            height,
+            color: vec![[0, 0, 0, 255]; pixel_count],
+            depth: vec![1.0; pixel_count],
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    ///
+    /// The color at `x` from the left and `y` from the top, like a pixel in an image.
+    ///
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        self.color[self.index(x, self.height - 1 - y)]
+    }
+
+    ///
+    /// The color buffer as RGBA texture data, top row first.
+    ///
+    pub fn to_texture_data(&self) -> CpuTextureData {
+        let mut pixels = Vec::with_capacity(self.color.len());
+        for row in self.color.chunks(self.width as usize).rev() {
+            pixels.extend_from_slice(row);
+        }
+        CpuTextureData::RgbaU8(pixels)
+    }
+
+    pub fn to_texture(&self) -> CpuTexture2D {
+        let mut texture = CpuTexture2D::from_size(self.width, self.height);
+        texture.set_data(self.to_texture_data());
+        texture
+    }
+
+    pub(crate) fn resize(&mut self, width: u32, height: u32) {
+        if self.width != width || self.height != height {
+            *self = Self::new(width, height);
+        }
+    }
+
+    // like `glClear`, channels the operation leaves as `None` are untouched
+    pub(crate) fn clear(&mut self, clear_operation: &ClearOperation) {
+        let channels = [
+            clear_operation.red,
+            clear_operation.green,
+            clear_operation.blue,
+            clear_operation.alpha,
+        ];
+        for pixel in self.color.iter_mut() {
+            for (channel, value_opt) in channels.iter().enumerate() {
+                if let Some(value) = value_opt {
+                    pixel[channel] = unorm_from_f32(*value);
+                }
+            }
+        }
+        if let Some(depth) = clear_operation.depth {
+            self.depth.fill(depth.clamp(0.0, 1.0));
+        }
+    }
+
+    // writes the color if `depth` is nearer than what's already there, window coordinates
+    pub(crate) fn write(&mut self, x: u32, y: u32, depth: f32, rgb: [u8; 3]) {
+        let index = self.index(x, y);
+        if depth >= self.depth[index] {
+            return;
+        }
+        self.depth[index] = depth;
+        let pixel = &mut self.color[index];
+        pixel[0] = rgb[0];
+        pixel[1] = rgb[1];
+        pixel[2] = rgb[2];
+    }
+
+    fn index(&self, x: u32, y: u32) -> usize {
+        (y * self.width + x) as usize
+    }
+}
+
+pub(crate) fn unorm_from_f32(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+///
+/// What would have been presented to the window, had there been one.
+///
+#[derive(Resource)]
+pub struct HeadlessScreen {
+    framebuffer: Framebuffer,
+}
+
+impl HeadlessScreen {
+    pub(crate) fn new(width: u32, height: u32) -> Self {
+        Self {
+            framebuffer: Framebuffer::new(width, height),
+        }
+    }
+
+    pub fn framebuffer(&self) -> &Framebuffer {
+        &self.framebuffer
+    }
+
+    pub(crate) fn framebuffer_mut(&mut self) -> &mut Framebuffer {
+        &mut self.framebuffer
+    }
+
+    ///
+    /// Copies the last rendered frame into a texture, e.g. to compare against a golden image.
+    ///
+    pub fn capture(&self) -> CpuTexture2D {
+        self.framebuffer.to_texture()
+    }
+}
+
+// Depth buffers (and a color copy) of the textures cameras render into, kept across frames so
+// passes which don't clear draw over what's already there
+#[derive(Resource, Default)]
+pub(crate) struct ImageTargets {
+    framebuffers: HashMap<Handle<CpuTexture2D>, Framebuffer>,
+}
+
+impl ImageTargets {
+    pub(crate) fn get_or_insert(
+        &mut self,
+        handle: &Handle<CpuTexture2D>,
+        width: u32,
+        height: u32,
+    ) -> &mut Framebuffer {
+        let framebuffer = self
+            .framebuffers
+            .entry(*handle)
+            .or_insert_with(|| Framebuffer::new(width, height));
+        framebuffer.resize(width, height);
+        framebuffer
+    }
+}
@@ -0,0 +1,122 @@
+//!
+//! Compares rendered frames against reference PNGs checked into the repo.
+//!
+//! A missing golden image is written from the frame instead, so new tests can be blessed by
+//! running them once and committing the result. Set `UPDATE_GOLDEN_IMAGES=1` to re-bless
+//! after an intended change. On CI (`CI` set) a missing golden image fails the test.
+//!
+
+use std::{fs::File, io::BufWriter, path::Path};
+
+use render_api::base::{CpuTexture2D, CpuTextureData};
+
+// per-channel difference allowed before a pixel counts as changed, absorbs float differences
+// between platforms
+pub const DEFAULT_CHANNEL_TOLERANCE: u8 = 2;
+
+pub fn assert_golden_image(texture: &CpuTexture2D, golden_path: impl AsRef<Path>) {
+    assert_golden_image_with_tolerance(texture, golden_path, DEFAULT_CHANNEL_TOLERANCE, 0);
+}
+
+pub fn assert_golden_image_with_tolerance(
+    texture: &CpuTexture2D,
+    golden_path: impl AsRef<Path>,
+    channel_tolerance: u8,
+    max_changed_pixels: usize,
+) {
+    let golden_path = golden_path.as_ref();
+    let pixels = rgba_pixels(texture);
+
+    let update = std::env::var("UPDATE_GOLDEN_IMAGES").is_ok();
+    if update || !golden_path.exists() {
+        if !update && std::env::var("CI").is_ok() {
+            panic!("missing golden image: {:?}", golden_path);
+        }
+        write_png(golden_path, texture.width(), texture.height(), &pixels)
+            .unwrap_or_else(|error| panic!("writing {:?}: {}", golden_path, error));
+        return;
+    }
+
+    let golden = read_png(golden_path)
+        .unwrap_or_else(|error| panic!("reading {:?}: {}", golden_path, error));
+    let (width, height) = (golden.width(), golden.height());
+    let golden_pixels = rgba_pixels(&golden);
+    assert_eq!(
+        (width, height),
+        (texture.width(), texture.height()),
+        "frame size differs from golden image {:?}",
+        golden_path
+    );
+
+    let changed_pixels = count_changed_pixels(&pixels, &golden_pixels, channel_tolerance);
+    if changed_pixels > max_changed_pixels {
+        // keep the frame next to the golden image, for diffing by eye
+        let actual_path = golden_path.with_extension("actual.png");
+        let _ = write_png(&actual_path, width, height, &pixels);
+        panic!(
+            "{} pixels differ from golden image {:?}, frame written to {:?}",
+            changed_pixels, golden_path, actual_path
+        );
+    }
+}
+
+pub fn count_changed_pixels(a: &[[u8; 4]], b: &[[u8; 4]], channel_tolerance: u8) -> usize {
+    a.iter()
+        .zip(b.iter())
+        .filter(|(a, b)| {
+            a.iter()
+                .zip(b.iter())
+                .any(|(a, b)| a.abs_diff(*b) > channel_tolerance)
+        })
+        .count()
+}
+
+fn rgba_pixels(texture: &CpuTexture2D) -> Vec<[u8; 4]> {
+    match texture.initial_data() {
+        Some(CpuTextureData::RgbaU8(pixels)) => pixels.clone(),
+        Some(CpuTextureData::RgbU8(pixels)) => {
+            pixels.iter().map(|[r, g, b]| [*r, *g, *b, 255]).collect()
+        }
+        Some(CpuTextureData::RgU8(pixels)) => {
+            pixels.iter().map(|[r, g]| [*r, *g, 0, 255]).collect()
+        }
+        Some(CpuTextureData::RU8(pixels)) => pixels.iter().map(|r| [*r, 0, 0, 255]).collect(),
+        None => panic!("texture has no pixels to compare"),
+    }
+}
+
+fn write_png(
+    path: &Path,
+    width: u32,
+    height: u32,
+    pixels: &[[u8; 4]],
+) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let file = File::create(path)?;
+    let mut encoder = png::Encoder::new(BufWriter::new(file), width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&pixels.concat())?;
+    Ok(())
+}
+
+fn read_png(path: &Path) -> Result<CpuTexture2D, Box<dyn std::error::Error>> {
+    Ok(CpuTexture2D::from_png(&std::fs::read(path)?)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::count_changed_pixels;
+
+    #[test]
+    fn ignores_differences_within_tolerance() {
+        let a = [[10, 20, 30, 255], [0, 0, 0, 255]];
+        let b = [[12, 18, 30, 255], [0, 0, 0, 255]];
+
+        assert_eq!(count_changed_pixels(&a, &b, 2), 0);
+        assert_eq!(count_changed_pixels(&a, &b, 1), 1);
+    }
+}
@@ -0,0 +1,14 @@
+mod framebuffer;
+mod mesh_manager;
+mod plugin;
+mod rasterizer;
+mod render;
+mod shading;
+mod sync;
+
+pub mod golden_image;
+
+pub(crate) use mesh_manager::*;
+
+pub use framebuffer::{Framebuffer, HeadlessScreen};
+pub use plugin::RenderSoftwarePlugin;
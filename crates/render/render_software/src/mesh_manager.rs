@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+
+use bevy_ecs::system::Resource;
+
+use math::Vec3;
+use render_api::base::{AxisAlignedBoundingBox, CpuMesh};
+use storage::Handle;
+
+pub struct SoftwareMesh {
+    vertices: Vec<Vec3>,
+    normals: Vec<Vec3>,
+    face_indices: Vec<u16>,
+    aabb: AxisAlignedBoundingBox,
+}
+
+impl SoftwareMesh {
+    fn new(cpu_mesh: &CpuMesh) -> Self {
+        Self {
+            vertices: cpu_mesh.vertices(),
+            normals: cpu_mesh.normals(),
+            face_indices: cpu_mesh.face_indices(),
+            aabb: cpu_mesh.compute_aabb(),
+        }
+    }
+
+    pub fn triangle_count(&self) -> usize {
+        self.vertices.len() / 3
+    }
+
+    pub fn vertex(&self, index: usize) -> Vec3 {
+        self.vertices[index]
+    }
+
+    pub fn normal(&self, index: usize) -> Vec3 {
+        self.normals[index]
+    }
+
+    pub fn face_index(&self, index: usize) -> usize {
+        self.face_indices[index] as usize
+    }
+
+    pub fn aabb(&self) -> AxisAlignedBoundingBox {
+        self.aabb
+    }
+}
+
+// Meshes with the per-vertex data the rasterizer needs worked out once, rather than every frame
+#[derive(Resource, Default)]
+pub struct SoftwareMeshManager {
+    meshes: HashMap<Handle<CpuMesh>, SoftwareMesh>,
+}
+
+impl SoftwareMeshManager {
+    pub fn insert(&mut self, handle: Handle<CpuMesh>, cpu_mesh: &CpuMesh) {
+        self.meshes.insert(handle, SoftwareMesh::new(cpu_mesh));
+    }
+
+    pub fn remove(&mut self, handle: &Handle<CpuMesh>) {
+        self.meshes.remove(handle);
+    }
+
+    pub fn get(&self, handle: &Handle<CpuMesh>) -> Option<&SoftwareMesh> {
+        self.meshes.get(handle)
+    }
+}
@@ -0,0 +1,50 @@
+use bevy_app::{App, Plugin};
+
+use render_api::{components::Viewport, Render, Window, WindowResolution};
+
+use crate::{
+    framebuffer::{HeadlessScreen, ImageTargets},
+    render::render,
+    sync::SyncPlugin,
+};
+
+// Renders on the CPU, with no window or GPU. Frames drawn to the screen end up in `HeadlessScreen`,
+// frames drawn to images are written back into their `CpuTexture2D`.
+pub struct RenderSoftwarePlugin {
+    width: u32,
+    height: u32,
+}
+
+impl RenderSoftwarePlugin {
+    pub fn new(width: u32, height: u32) -> Self {
+        Self { width, height }
+    }
+}
+
+impl Default for RenderSoftwarePlugin {
+    fn default() -> Self {
+        let viewport = Viewport::default();
+        Self::new(viewport.width, viewport.height)
+    }
+}
+
+impl Plugin for RenderSoftwarePlugin {
+    fn build(&self, app: &mut App) {
+        let mut window = Window::default();
+        window.set(WindowResolution {
+            physical_size: Viewport::new_at_origin(self.width, self.height),
+            logical_size: Viewport::new_at_origin(self.width, self.height),
+            device_pixel_ratio: 1.0,
+        });
+
+        app
+            // Plugins
+            .add_plugins(SyncPlugin)
+            // Resources
+            .insert_resource(window)
+            .insert_resource(HeadlessScreen::new(self.width, self.height))
+            .init_resource::<ImageTargets>()
+            // Systems
+            .add_systems(Render, render);
+    }
+}
@@ -0,0 +1,141 @@
+use math::{Vec3, Vec4};
+use render_api::components::Viewport;
+
+use crate::Framebuffer;
+
+///
+/// Draws a triangle given in clip space, the way OpenGL would with back faces culled
+/// (counter-clockwise is front facing) and a `Less` depth test.
+/// `shade` is only called if some of the triangle is front facing & on screen.
+///
+pub(crate) fn draw_triangle(
+    framebuffer: &mut Framebuffer,
+    viewport: &Viewport,
+    clip_positions: [Vec4; 3],
+    shade: impl FnOnce() -> [u8; 3],
+) {
+    let polygon = clip_to_depth_range(&clip_positions);
+    if polygon.len() < 3 {
+        return;
+    }
+    let window_positions: Vec<Vec3> = polygon
+        .iter()
+        .map(|clip_position| window_from_clip(viewport, *clip_position))
+        .collect();
+    if window_positions
+        .iter()
+        .any(|position| !position.is_finite())
+    {
+        // a vertex sits right on the camera
+        return;
+    }
+
+    let mut shade = Some(shade);
+    let mut rgb_opt = None;
+    for index in 1..window_positions.len() - 1 {
+        let triangle = [
+            window_positions[0],
+            window_positions[index],
+            window_positions[index + 1],
+        ];
+        if edge(triangle[0], triangle[1], triangle[2]) <= 0.0 {
+            // back facing, or seen edge-on
+            continue;
+        }
+        let rgb = *rgb_opt.get_or_insert_with(|| (shade.take().unwrap())());
+        fill_triangle(framebuffer, viewport, triangle, rgb);
+    }
+}
+
+// Sutherland-Hodgman against the near & far planes, `-w <= z <= w`. The sides are left to
+// the viewport's scissoring in `fill_triangle`.
+fn clip_to_depth_range(clip_positions: &[Vec4; 3]) -> Vec<Vec4> {
+    let mut polygon = clip_positions.to_vec();
+    let planes: [fn(&Vec4) -> f32; 2] = [|v| v.z + v.w, |v| v.w - v.z];
+    for distance in planes {
+        if polygon.is_empty() {
+            break;
+        }
+        let mut clipped = Vec::with_capacity(polygon.len() + 1);
+        for index in 0..polygon.len() {
+            let current = polygon[index];
+            let next = polygon[(index + 1) % polygon.len()];
+            let current_distance = distance(&current);
+            let next_distance = distance(&next);
+            if current_distance >= 0.0 {
+                clipped.push(current);
+            }
+            if (current_distance >= 0.0) != (next_distance >= 0.0) {
+                let t = current_distance / (current_distance - next_distance);
+                clipped.push(current.lerp(next, t));
+            }
+        }
+        polygon = clipped;
+    }
+    polygon
+}
+
+// x & y in pixels from the bottom left of the target, z in the 0..1 depth range
+fn window_from_clip(viewport: &Viewport, clip_position: Vec4) -> Vec3 {
+    let ndc = clip_position.truncate() / clip_position.w;
+    Vec3::new(
+        viewport.x as f32 + (ndc.x + 1.0) * 0.5 * viewport.width as f32,
+        viewport.y as f32 + (ndc.y + 1.0) * 0.5 * viewport.height as f32,
+        ndc.z * 0.5 + 0.5,
+    )
+}
+
+// twice the signed area of `a`, `b`, `c`, positive when counter-clockwise
+fn edge(a: Vec3, b: Vec3, c: Vec3) -> f32 {
+    (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+}
+
+fn fill_triangle(
+    framebuffer: &mut Framebuffer,
+    viewport: &Viewport,
+    triangle: [Vec3; 3],
+    rgb: [u8; 3],
+) {
+    let [a, b, c] = triangle;
+    let area = edge(a, b, c);
+
+    // pixels inside both the triangle's bounds and the viewport
+    let min_x =
+        a.x.min(b.x)
+            .min(c.x)
+            .floor()
+            .max(viewport.x as f32)
+            .max(0.0) as i64;
+    let min_y =
+        a.y.min(b.y)
+            .min(c.y)
+            .floor()
+            .max(viewport.y as f32)
+            .max(0.0) as i64;
+    let max_x =
+        a.x.max(b.x)
+            .max(c.x)
+            .ceil()
+            .min((viewport.x + viewport.width as i32) as f32)
+            .min(framebuffer.width() as f32) as i64;
+    let max_y =
+        a.y.max(b.y)
+            .max(c.y)
+            .ceil()
+            .min((viewport.y + viewport.height as i32) as f32)
+            .min(framebuffer.height() as f32) as i64;
+
+    for y in min_y..max_y {
+        for x in min_x..max_x {
+            let center = Vec3::new(x as f32 + 0.5, y as f32 + 0.5, 0.0);
+            let weight_a = edge(b, c, center);
+            let weight_b = edge(c, a, center);
+            let weight_c = edge(a, b, center);
+            if weight_a < 0.0 || weight_b < 0.0 || weight_c < 0.0 {
+                continue;
+            }
+            let depth = (weight_a * a.z + weight_b * b.z + weight_c * c.z) / area;
+            framebuffer.write(x as u32, y as u32, depth.clamp(0.0, 1.0), rgb);
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use bevy_ecs::system::{Res, ResMut};
+
+use logging::warn;
+use math::{Mat3, Mat4, Vec3};
+use render_api::{
+    base::{Color, CpuMaterial, CpuSkin, CpuTexture2D},
+    components::{CameraProjection, RenderTarget, TypedLight, Viewport},
+    resources::{MaterialOrSkinHandle, MeshLods, RenderFrame, RenderPass, RenderStats},
+    Window,
+};
+use storage::Storage;
+
+use crate::{
+    framebuffer::ImageTargets, rasterizer::draw_triangle, shading::shade_vertex, Framebuffer,
+    HeadlessScreen, SoftwareMesh, SoftwareMeshManager,
+};
+
+#[allow(clippy::too_many_arguments)]
+pub fn render(
+    mut render_frame: ResMut<RenderFrame>,
+    window: Res<Window>,
+    // Resources
+    mesh_manager: Res<SoftwareMeshManager>,
+    materials: Res<Storage<CpuMaterial>>,
+    skins: Res<Storage<CpuSkin>>,
+    mut textures: ResMut<Storage<CpuTexture2D>>,
+    mesh_lods: Res<MeshLods>,
+    mut render_stats: ResMut<RenderStats>,
+    mut screen: ResMut<HeadlessScreen>,
+    mut image_targets: ResMut<ImageTargets>,
+) {
+    let render_passes = render_frame.take_render_passes();
+    render_stats.clear();
+
+    if let Some(resolution) = window.get() {
+        let size = resolution.physical_size;
+        screen.framebuffer_mut().resize(size.width, size.height);
+    }
+
+    // Draw
+    for (render_layer_index, render_pass_opt) in render_passes.into_iter().enumerate() {
+        let Some(mut render_pass) = render_pass_opt else {
+            continue;
+        };
+        let Some(camera) = render_pass.camera_opt else {
+            continue;
+        };
+
+        let stats = render_pass.cull(
+            |mesh_handle| mesh_manager.get(mesh_handle).map(SoftwareMesh::aabb),
+            &mesh_lods,
+        );
+        render_stats.record(render_layer_index, stats);
+
+        match &camera.target {
+            RenderTarget::Screen => {
+                let framebuffer = screen.framebuffer_mut();
+                framebuffer.clear(&camera.clear_operation);
+                draw_pass(framebuffer, &mesh_manager, &materials, &skins, render_pass);
+            }
+            RenderTarget::Image(texture_handle) => {
+                // Render to Image
+                let Some(texture) = textures.get(texture_handle) else {
+                    warn!("render target texture not found, skipping render pass");
+                    continue;
+                };
+                let framebuffer =
+                    image_targets.get_or_insert(texture_handle, texture.width(), texture.height());
+                framebuffer.clear(&camera.clear_operation);
+                draw_pass(framebuffer, &mesh_manager, &materials, &skins, render_pass);
+
+                let texture = textures.get_mut(texture_handle).unwrap();
+                texture.set_data(framebuffer.to_texture_data());
+            }
+        }
+    }
+}
+
+fn draw_pass(
+    framebuffer: &mut Framebuffer,
+    mesh_manager: &SoftwareMeshManager,
+    materials: &Storage<CpuMaterial>,
+    skins: &Storage<CpuSkin>,
+    render_pass: RenderPass,
+) {
+    // post-processing & shadows are only implemented by the GL renderer
+    let camera = render_pass.camera_opt.unwrap();
+    let camera_transform = render_pass.camera_transform_opt.unwrap();
+    let camera_projection = render_pass.camera_projection_opt.unwrap();
+    let lights = &render_pass.lights;
+    let meshes = render_pass.drawn_instances();
+
+    let viewport = camera.viewport_or_default();
+    let view_projection =
+        camera_projection.projection_matrix(&viewport) * camera_transform.view_matrix();
+
+    // same order as the GL renderer, so meshes at equal depth overlap the same way
+    let mut mesh_handles = meshes.keys().copied().collect::<Vec<_>>();
+    mesh_handles.sort();
+
+    for mesh_handle in mesh_handles {
+        let Some(mesh) = mesh_manager.get(&mesh_handle) else {
+            continue;
+        };
+        for (mat_handle, transform) in meshes.get(&mesh_handle).unwrap() {
+            draw_instance(
+                framebuffer,
+                &viewport,
+                &view_projection,
+                camera_transform.translation,
+                lights,
+                mesh,
+                materials,
+                skins,
+                mat_handle,
+                transform,
+            );
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn draw_instance(
+    framebuffer: &mut Framebuffer,
+    viewport: &Viewport,
+    view_projection: &Mat4,
+    camera_position: Vec3,
+    lights: &[TypedLight],
+    mesh: &SoftwareMesh,
+    materials: &Storage<CpuMaterial>,
+    skins: &Storage<CpuSkin>,
+    mat_handle: &MaterialOrSkinHandle,
+    transform: &Mat4,
+) {
+    let normal_matrix = Mat3::from_mat4(*transform).inverse().transpose();
+
+    for triangle_index in 0..mesh.triangle_count() {
+        let first_vertex = triangle_index * 3;
+        let world_positions =
+            [0, 1, 2].map(|offset| transform.project_point3(mesh.vertex(first_vertex + offset)));
+        let clip_positions =
+            world_positions.map(|position| *view_projection * position.extend(1.0));
+
+        // the triangle is flat shaded with its last vertex's color, as OpenGL does
+        let last_vertex = first_vertex + 2;
+        let (material_handle, tint_opt) = match mat_handle {
+            MaterialOrSkinHandle::Material(material_handle) => (*material_handle, None),
+            MaterialOrSkinHandle::Skin(skin_handle) => {
+                let face_index = mesh.face_index(last_vertex);
+                let Some(material_handle) = skins
+                    .get(skin_handle)
+                    .and_then(|skin| skin.face_to_material_list().get(face_index))
+                else {
+                    continue;
+                };
+                (*material_handle, None)
+            }
+            MaterialOrSkinHandle::TintedMaterial(material_handle, tint) => {
+                (*material_handle, Some(*tint))
+            }
+        };
+        let Some(material) = materials.get(&material_handle) else {
+            continue;
+        };
+        let tinted_material;
+        let material = match tint_opt {
+            Some(tint) => {
+                let mut material = material.clone();
+                let color = material.diffuse.to_vec3() * tint.to_vec3();
+                material.diffuse = Color::from_rgb_f32(color.x, color.y, color.z);
+                tinted_material = material;
+                &tinted_material
+            }
+            None => material,
+        };
+
+        draw_triangle(framebuffer, viewport, clip_positions, || {
+            let normal = (normal_matrix * mesh.normal(last_vertex)).normalize_or_zero();
+            shade_vertex(
+                lights,
+                camera_position,
+                world_positions[2],
+                normal,
+                material,
+            )
+        });
+    }
+}
@@ -0,0 +1,113 @@
+use math::Vec3;
+use render_api::{base::CpuMaterial, components::TypedLight};
+
+use crate::framebuffer::unorm_from_f32;
+
+// A port of the GL renderer's mesh.vert & shared.vert, so both renderers light a vertex the same.
+// Shadows aren't rendered, lights that cast them light everything in reach.
+pub(crate) fn shade_vertex(
+    lights: &[TypedLight],
+    camera_position: Vec3,
+    position: Vec3,
+    normal: Vec3,
+    material: &CpuMaterial,
+) -> [u8; 3] {
+    let material_color = material.diffuse.to_vec3();
+    let material_shine = (material.shine_size.max(1.0), material.shine_amount);
+
+    // convert from right-handed y-up to left-handed z-up
+    let view_direction = swizzle((camera_position - position).normalize_or_zero());
+
+    let mut color = material.emissive * material_color;
+    for light in lights {
+        color += match light {
+            TypedLight::Ambient(light) => {
+                light.color.color.to_vec3() * light.color.intensity * material_color
+            }
+            TypedLight::Directional(light) => {
+                let light_color = light.color.to_vec3() * light.intensity;
+                let light_direction = swizzle(-light.direction.normalize_or_zero());
+                calculate_light(
+                    light_color,
+                    light_direction,
+                    view_direction,
+                    normal,
+                    material_color,
+                    material_shine,
+                )
+            }
+            TypedLight::Point(light) => {
+                let offset = light.position - position;
+                let distance = offset.length();
+                // a vertex at the light's position gets no direction to it, rather than NaN
+                let light_direction = swizzle(offset.normalize_or_zero());
+                let attenuation = light.attenuation.constant
+                    + light.attenuation.linear * distance
+                    + light.attenuation.quadratic * distance * distance;
+                let light_color = light.color.to_vec3() * light.intensity / attenuation.max(1.0);
+                calculate_light(
+                    light_color,
+                    light_direction,
+                    view_direction,
+                    normal,
+                    material_color,
+                    material_shine,
+                )
+            }
+        };
+    }
+
+    let color = srgb_from_rgb(reinhard_tone_mapping(color));
+    [
+        unorm_from_f32(color.x),
+        unorm_from_f32(color.y),
+        unorm_from_f32(color.z),
+    ]
+}
+
+fn swizzle(direction: Vec3) -> Vec3 {
+    Vec3::new(direction.x, -direction.z, -direction.y)
+}
+
+fn calculate_light(
+    light_color: Vec3,
+    light_direction: Vec3,
+    view_direction: Vec3,
+    normal: Vec3,
+    material_color: Vec3,
+    (shine_size, shine_amount): (f32, f32),
+) -> Vec3 {
+    // diffuse
+    let diffuse_strength = normal.dot(light_direction).max(0.0);
+    let diffuse_color = diffuse_strength * light_color * material_color;
+
+    // specular
+    let reflect_direction = reflect(-light_direction, normal);
+    let specular_strength = view_direction
+        .dot(reflect_direction)
+        .max(0.0)
+        .powf(shine_size);
+    let specular_color = shine_amount * specular_strength * light_color;
+
+    diffuse_color + specular_color
+}
+
+// GLSL's `reflect`
+fn reflect(incident: Vec3, normal: Vec3) -> Vec3 {
+    incident - 2.0 * normal.dot(incident) * normal
+}
+
+fn reinhard_tone_mapping(color: Vec3) -> Vec3 {
+    color / (color + Vec3::ONE)
+}
+
+fn srgb_from_rgb(rgb: Vec3) -> Vec3 {
+    let channel = |value: f32| {
+        if value < 0.0031308 {
+            value * 12.92
+        } else {
+            1.055 * value.powf(1.0 / 2.4) - 0.055
+        }
+    };
+    Vec3::new(channel(rgb.x), channel(rgb.y), channel(rgb.z))
+}
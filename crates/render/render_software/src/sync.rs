@@ -0,0 +1,67 @@
+use bevy_app::{App, Plugin};
+use bevy_ecs::{change_detection::DetectChanges, system::ResMut};
+
+use render_api::{
+    base::{CpuMaterial, CpuMesh, CpuSkin, CpuTexture2D},
+    RenderSync,
+};
+use storage::Storage;
+
+use crate::SoftwareMeshManager;
+
+// Materials, skins & textures are read straight out of their `Storage` while rendering,
+// only meshes need preparing ahead of time. The others are still flushed, as assets wait on
+// `Storage::added_was_flushed` before using them (e.g. skins on their palette's materials)
+pub struct SyncPlugin;
+
+impl Plugin for SyncPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            // Resources
+            .init_resource::<SoftwareMeshManager>()
+            // Systems
+            .add_systems(RenderSync, sync_mesh_assets)
+            .add_systems(RenderSync, flush_assets::<CpuMaterial>)
+            .add_systems(RenderSync, flush_assets::<CpuSkin>)
+            .add_systems(RenderSync, flush_assets::<CpuTexture2D>);
+    }
+}
+
+fn sync_mesh_assets(
+    mut cpu_assets: ResMut<Storage<CpuMesh>>,
+    mut mesh_manager: ResMut<SoftwareMeshManager>,
+) {
+    if !cpu_assets.is_changed() {
+        return;
+    }
+
+    // Handle Added Meshes
+    let added_handles = cpu_assets.flush_added();
+    for added_handle in added_handles {
+        let cpu_data = cpu_assets.get(&added_handle).unwrap();
+        mesh_manager.insert(added_handle, cpu_data);
+    }
+
+    // Handle Changed Meshes
+    let changed_handles = cpu_assets.flush_changed();
+    for changed_handle in changed_handles {
+        let cpu_data = cpu_assets.get(&changed_handle).unwrap();
+        mesh_manager.insert(changed_handle, cpu_data);
+    }
+
+    // Handle Removed Meshes
+    let removed_handles = cpu_assets.flush_removed();
+    for removed_handle in removed_handles {
+        mesh_manager.remove(&removed_handle);
+    }
+}
+
+fn flush_assets<T: Send + Sync + 'static>(mut cpu_assets: ResMut<Storage<T>>) {
+    if !cpu_assets.is_changed() {
+        return;
+    }
+
+    cpu_assets.flush_added();
+    cpu_assets.flush_changed();
+    cpu_assets.flush_removed();
+}
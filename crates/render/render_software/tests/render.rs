@@ -0,0 +1,141 @@
+use bevy_app::App;
+
+use math::Vec3;
+use render_api::{
+    base::{Color, CpuMaterial, CpuMesh, CpuTexture2D, CpuTextureData},
+    components::{Camera, CameraBundle, ClearOperation, RenderTarget, Transform, Viewport},
+    resources::RenderFrame,
+    shapes::UnitSquare,
+    RenderApiPlugin,
+};
+use render_software::{HeadlessScreen, RenderSoftwarePlugin};
+use storage::{Handle, Storage};
+
+const SIZE: u32 = 64;
+const CLEAR_COLOR: [u8; 4] = [0, 0, 255, 255];
+
+fn headless_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(RenderApiPlugin)
+        .add_plugins(RenderSoftwarePlugin::new(SIZE, SIZE));
+    app
+}
+
+fn add_square(app: &mut App) -> Handle<CpuMesh> {
+    app.world_mut()
+        .resource_mut::<Storage<CpuMesh>>()
+        .add(UnitSquare)
+}
+
+fn add_material(app: &mut App, color: Color) -> Handle<CpuMaterial> {
+    app.world_mut()
+        .resource_mut::<Storage<CpuMaterial>>()
+        .add(CpuMaterial::new(color, 1.0, 32.0, 0.0))
+}
+
+fn draw_camera(app: &mut App, target: RenderTarget, size: u32) {
+    let mut camera_bundle = CameraBundle::new_2d(&Viewport::new_at_origin(size, size));
+    camera_bundle.camera = Camera {
+        clear_operation: ClearOperation::from_rgba(0.0, 0.0, 1.0, 1.0),
+        target,
+        ..camera_bundle.camera
+    };
+    app.world_mut().resource_mut::<RenderFrame>().draw_camera(
+        None,
+        &camera_bundle.camera,
+        &camera_bundle.transform,
+        &camera_bundle.projection,
+    );
+}
+
+// a square covering the middle half of the target
+fn draw_square(
+    app: &mut App,
+    mesh: &Handle<CpuMesh>,
+    material: &Handle<CpuMaterial>,
+    size: u32,
+    z: f32,
+) {
+    let quarter = size as f32 * 0.25;
+    let transform = Transform::from_xyz(quarter, quarter, z).with_scale(Vec3::new(
+        quarter * 2.0,
+        quarter * 2.0,
+        1.0,
+    ));
+    app.world_mut()
+        .resource_mut::<RenderFrame>()
+        .draw_mesh(None, mesh, material, &transform);
+}
+
+#[test]
+fn renders_meshes_to_the_screen() {
+    let mut app = headless_app();
+    let mesh = add_square(&mut app);
+    let material = add_material(&mut app, Color::WHITE);
+
+    draw_camera(&mut app, RenderTarget::Screen, SIZE);
+    draw_square(&mut app, &mesh, &material, SIZE, 0.0);
+    app.update();
+
+    let screen = app.world().resource::<HeadlessScreen>();
+    let framebuffer = screen.framebuffer();
+    assert_eq!(framebuffer.pixel(1, 1), CLEAR_COLOR);
+    assert_eq!(framebuffer.pixel(SIZE - 2, SIZE - 2), CLEAR_COLOR);
+
+    let center = framebuffer.pixel(SIZE / 2, SIZE / 2);
+    assert_ne!(center, CLEAR_COLOR);
+    assert_eq!(center[0], center[1]);
+    assert_eq!(center[1], center[2]);
+}
+
+#[test]
+fn nearer_meshes_hide_farther_ones() {
+    let mut app = headless_app();
+    let mesh = add_square(&mut app);
+    let near_material = add_material(&mut app, Color::GREEN);
+    let far_material = add_material(&mut app, Color::RED);
+
+    // the nearer square is drawn first, so only the depth test keeps it on top
+    draw_camera(&mut app, RenderTarget::Screen, SIZE);
+    draw_square(&mut app, &mesh, &near_material, SIZE, 10.0);
+    draw_square(&mut app, &mesh, &far_material, SIZE, 0.0);
+    app.update();
+
+    let screen = app.world().resource::<HeadlessScreen>();
+    let center = screen.framebuffer().pixel(SIZE / 2, SIZE / 2);
+    assert!(center[1] > 0);
+    assert_eq!(center[0], 0);
+}
+
+#[test]
+fn renders_to_images() {
+    let image_size = 32;
+    let mut app = headless_app();
+    let mesh = add_square(&mut app);
+    let material = add_material(&mut app, Color::WHITE);
+    let texture = app
+        .world_mut()
+        .resource_mut::<Storage<CpuTexture2D>>()
+        .add(CpuTexture2D::from_size(image_size, image_size));
+
+    draw_camera(&mut app, RenderTarget::Image(texture), image_size);
+    draw_square(&mut app, &mesh, &material, image_size, 0.0);
+    app.update();
+
+    let textures = app.world().resource::<Storage<CpuTexture2D>>();
+    let Some(CpuTextureData::RgbaU8(pixels)) = textures.get(&texture).unwrap().initial_data()
+    else {
+        panic!("render target has no RGBA pixels");
+    };
+    assert_eq!(pixels.len(), (image_size * image_size) as usize);
+    assert_eq!(pixels[0], CLEAR_COLOR);
+    let center = (image_size / 2 * image_size + image_size / 2) as usize;
+    assert_ne!(pixels[center], CLEAR_COLOR);
+
+    // nothing was drawn to the screen
+    let screen = app.world().resource::<HeadlessScreen>();
+    assert_eq!(
+        screen.framebuffer().pixel(SIZE / 2, SIZE / 2),
+        [0, 0, 0, 255]
+    );
+}
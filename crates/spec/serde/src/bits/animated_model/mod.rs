@@ -12,14 +12,19 @@ cfg_if! {
     } else {}
 }
 
-use naia_serde::SerdeInternal as Serde;
+use naia_serde::{SerdeInternal as Serde, SignedVariableInteger, UnsignedVariableInteger};
 
 use asset_id::AssetId;
 
+// graph rates & condition values are serialized as thousandths
+pub type AnimationValueSerdeInt = SignedVariableInteger<10>;
+pub type FadeSerdeInt = UnsignedVariableInteger<7>;
+
 #[derive(Serde, Clone, PartialEq)]
 pub struct AnimatedModelBits {
     model_asset_id: AssetId,
     animations: Vec<(String, AssetId)>,
+    graph: Option<AnimationGraphBits>,
 }
 
 impl AnimatedModelBits {
@@ -27,6 +32,7 @@ impl AnimatedModelBits {
         Self {
             model_asset_id,
             animations: Vec::new(),
+            graph: None,
         }
     }
 
@@ -41,4 +47,192 @@ impl AnimatedModelBits {
     pub fn get_animations(&self) -> &Vec<(String, AssetId)> {
         &self.animations
     }
+
+    pub fn get_graph(&self) -> Option<&AnimationGraphBits> {
+        self.graph.as_ref()
+    }
+
+    pub fn set_graph(&mut self, graph: AnimationGraphBits) {
+        self.graph = Some(graph);
+    }
+}
+
+#[derive(Serde, Clone, PartialEq)]
+pub struct AnimationGraphBits {
+    default_state: String,
+    states: Vec<AnimationGraphStateBits>,
+    transitions: Vec<AnimationTransitionBits>,
+    layers: Vec<AnimationGraphLayerBits>,
+}
+
+impl AnimationGraphBits {
+    pub fn new(default_state: &str) -> Self {
+        Self {
+            default_state: default_state.to_string(),
+            states: Vec::new(),
+            transitions: Vec::new(),
+            layers: Vec::new(),
+        }
+    }
+
+    pub fn get_default_state(&self) -> &str {
+        &self.default_state
+    }
+
+    pub fn get_states(&self) -> &Vec<AnimationGraphStateBits> {
+        &self.states
+    }
+
+    pub fn add_state(
+        &mut self,
+        name: &str,
+        animation: Option<&str>,
+        playback_rate: f32,
+        per_distance: bool,
+    ) {
+        self.states.push(AnimationGraphStateBits {
+            name: name.to_string(),
+            animation: animation.map(str::to_string),
+            playback_rate: to_serde_value(playback_rate),
+            per_distance,
+        });
+    }
+
+    pub fn get_transitions(&self) -> &Vec<AnimationTransitionBits> {
+        &self.transitions
+    }
+
+    pub fn add_transition(&mut self, transition: AnimationTransitionBits) {
+        self.transitions.push(transition);
+    }
+
+    pub fn get_layers(&self) -> &Vec<AnimationGraphLayerBits> {
+        &self.layers
+    }
+
+    pub fn add_layer(&mut self, layer: AnimationGraphLayerBits) {
+        self.layers.push(layer);
+    }
+}
+
+#[derive(Serde, Clone, PartialEq)]
+pub struct AnimationGraphLayerBits {
+    name: String,
+    bone_mask: Vec<String>,
+    additive: bool,
+    graph: AnimationGraphBits,
+}
+
+impl AnimationGraphLayerBits {
+    pub fn new(
+        name: &str,
+        bone_mask: Vec<String>,
+        additive: bool,
+        graph: AnimationGraphBits,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            bone_mask,
+            additive,
+            graph,
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_bone_mask(&self) -> &Vec<String> {
+        &self.bone_mask
+    }
+
+    pub fn is_additive(&self) -> bool {
+        self.additive
+    }
+
+    pub fn get_graph(&self) -> &AnimationGraphBits {
+        &self.graph
+    }
+}
+
+#[derive(Serde, Clone, PartialEq)]
+pub struct AnimationGraphStateBits {
+    name: String,
+    animation: Option<String>,
+    playback_rate: AnimationValueSerdeInt,
+    per_distance: bool,
+}
+
+impl AnimationGraphStateBits {
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_animation(&self) -> Option<&str> {
+        self.animation.as_deref()
+    }
+
+    pub fn get_playback_rate(&self) -> f32 {
+        from_serde_value(&self.playback_rate)
+    }
+
+    pub fn is_per_distance(&self) -> bool {
+        self.per_distance
+    }
+}
+
+#[derive(Serde, Clone, PartialEq)]
+pub struct AnimationTransitionBits {
+    from: Option<String>,
+    to: String,
+    // (parameter, is greater-than, value)
+    conditions: Vec<(String, bool, AnimationValueSerdeInt)>,
+    fade_ms: FadeSerdeInt,
+}
+
+impl AnimationTransitionBits {
+    pub fn new(from: Option<&str>, to: &str, fade_ms: f32) -> Self {
+        Self {
+            from: from.map(str::to_string),
+            to: to.to_string(),
+            conditions: Vec::new(),
+            fade_ms: FadeSerdeInt::new(fade_ms.round() as u32),
+        }
+    }
+
+    pub fn get_from(&self) -> Option<&str> {
+        self.from.as_deref()
+    }
+
+    pub fn get_to(&self) -> &str {
+        &self.to
+    }
+
+    pub fn add_condition(&mut self, parameter: &str, greater_than: bool, value: f32) {
+        self.conditions
+            .push((parameter.to_string(), greater_than, to_serde_value(value)));
+    }
+
+    pub fn get_conditions(&self) -> Vec<(&str, bool, f32)> {
+        self.conditions
+            .iter()
+            .map(|(parameter, greater_than, value)| {
+                (parameter.as_str(), *greater_than, from_serde_value(value))
+            })
+            .collect()
+    }
+
+    pub fn get_fade_ms(&self) -> f32 {
+        let fade_ms: u32 = self.fade_ms.to();
+        fade_ms as f32
+    }
+}
+
+fn to_serde_value(value: f32) -> AnimationValueSerdeInt {
+    AnimationValueSerdeInt::new((value * 1000.0).round() as i64)
+}
+
+fn from_serde_value(value: &AnimationValueSerdeInt) -> f32 {
+    let value: i64 = value.to();
+    (value as f32) / 1000.0
 }
@@ -1,8 +1,11 @@
 use naia_serde::{BitReader, SerdeErr, SerdeInternal as Serde};
 
-use spec::AnimatedModel;
+use spec::{
+    AnimatedModel, AnimationComparison, AnimationGraph, AnimationGraphLayer, AnimationGraphState,
+    AnimationLayerMode, AnimationPlayback, AnimationTransition,
+};
 
-use crate::bits::AnimatedModelBits;
+use crate::bits::{AnimatedModelBits, AnimationGraphBits};
 
 impl AnimatedModelBits {
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, SerdeErr> {
@@ -13,14 +16,72 @@ impl AnimatedModelBits {
     }
 }
 
-impl Into<AnimatedModel> for AnimatedModelBits {
-    fn into(self) -> AnimatedModel {
-        let mut animated_model = AnimatedModel::new(self.get_model_asset_id());
+impl From<AnimatedModelBits> for AnimatedModel {
+    fn from(value: AnimatedModelBits) -> Self {
+        let mut animated_model = AnimatedModel::new(value.get_model_asset_id());
 
-        for (name, asset_id) in self.get_animations() {
+        for (name, asset_id) in value.get_animations() {
             animated_model.add_animation(name, *asset_id);
         }
+        if let Some(graph) = value.get_graph() {
+            animated_model.set_graph(graph.clone().into());
+        }
 
         animated_model
     }
 }
+
+impl From<AnimationGraphBits> for AnimationGraph {
+    fn from(value: AnimationGraphBits) -> Self {
+        let mut graph = AnimationGraph::new(value.get_default_state());
+
+        for state in value.get_states() {
+            let playback = if state.is_per_distance() {
+                AnimationPlayback::Distance(state.get_playback_rate())
+            } else {
+                AnimationPlayback::Time(state.get_playback_rate())
+            };
+            let state = match state.get_animation() {
+                Some(animation) => AnimationGraphState::new(state.get_name(), animation, playback),
+                None => AnimationGraphState::empty(state.get_name()),
+            };
+            graph.add_state(state);
+        }
+
+        for transition_bits in value.get_transitions() {
+            let mut transition = AnimationTransition::new(
+                transition_bits.get_from(),
+                transition_bits.get_to(),
+                transition_bits.get_fade_ms(),
+            );
+            for (parameter, greater_than, value) in transition_bits.get_conditions() {
+                let comparison = if greater_than {
+                    AnimationComparison::GreaterThan
+                } else {
+                    AnimationComparison::LessThan
+                };
+                transition.add_condition(parameter, comparison, value);
+            }
+            graph.add_transition(transition);
+        }
+
+        for layer_bits in value.get_layers() {
+            let mode = if layer_bits.is_additive() {
+                AnimationLayerMode::Additive
+            } else {
+                AnimationLayerMode::Override
+            };
+            let mut layer = AnimationGraphLayer::new(
+                layer_bits.get_name(),
+                mode,
+                layer_bits.get_graph().clone().into(),
+            );
+            for bone_name in layer_bits.get_bone_mask() {
+                layer.add_bone(bone_name);
+            }
+            graph.add_layer(layer);
+        }
+
+        graph
+    }
+}
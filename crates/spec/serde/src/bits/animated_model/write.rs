@@ -1,8 +1,12 @@
 use naia_serde::{FileBitWriter, SerdeInternal as Serde};
 
-use spec::AnimatedModel;
+use spec::{
+    AnimatedModel, AnimationComparison, AnimationGraph, AnimationLayerMode, AnimationPlayback,
+};
 
-use crate::bits::AnimatedModelBits;
+use crate::bits::{
+    AnimatedModelBits, AnimationGraphBits, AnimationGraphLayerBits, AnimationTransitionBits,
+};
 
 impl From<&AnimatedModel> for AnimatedModelBits {
     fn from(value: &AnimatedModel) -> Self {
@@ -11,16 +15,66 @@ impl From<&AnimatedModel> for AnimatedModelBits {
         for (name, asset_id) in value.get_animations() {
             me.add_animation(name, *asset_id);
         }
+        if let Some(graph) = value.get_graph() {
+            me.set_graph(graph.into());
+        }
+
+        me
+    }
+}
+
+impl From<&AnimationGraph> for AnimationGraphBits {
+    fn from(value: &AnimationGraph) -> Self {
+        let mut me = Self::new(value.get_default_state());
+
+        for state in value.get_states() {
+            let (playback_rate, per_distance) = match state.get_playback() {
+                AnimationPlayback::Time(rate) => (rate, false),
+                AnimationPlayback::Distance(rate) => (rate, true),
+            };
+            me.add_state(
+                state.get_name(),
+                state.get_animation(),
+                playback_rate,
+                per_distance,
+            );
+        }
+
+        for transition in value.get_transitions() {
+            let mut transition_bits = AnimationTransitionBits::new(
+                transition.get_from(),
+                transition.get_to(),
+                transition.get_fade_ms(),
+            );
+            for condition in transition.get_conditions() {
+                let greater_than = condition.get_comparison() == AnimationComparison::GreaterThan;
+                transition_bits.add_condition(
+                    condition.get_parameter(),
+                    greater_than,
+                    condition.get_value(),
+                );
+            }
+            me.add_transition(transition_bits);
+        }
+
+        for layer in value.get_layers() {
+            me.add_layer(AnimationGraphLayerBits::new(
+                layer.get_name(),
+                layer.get_bone_mask().clone(),
+                layer.get_mode() == AnimationLayerMode::Additive,
+                layer.get_graph().into(),
+            ));
+        }
 
         me
     }
 }
 
-impl Into<Vec<u8>> for AnimatedModelBits {
-    fn into(self) -> Vec<u8> {
+impl From<AnimatedModelBits> for Vec<u8> {
+    fn from(value: AnimatedModelBits) -> Self {
         let mut bit_writer = FileBitWriter::new();
 
-        self.ser(&mut bit_writer);
+        value.ser(&mut bit_writer);
 
         bit_writer.to_vec()
     }
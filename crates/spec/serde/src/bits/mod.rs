@@ -1,8 +1,14 @@
 mod animated_model;
-pub use animated_model::AnimatedModelBits;
+pub use animated_model::{
+    AnimatedModelBits, AnimationGraphBits, AnimationGraphLayerBits, AnimationGraphStateBits,
+    AnimationTransitionBits,
+};
 
 mod movement_config;
 pub use movement_config::MovementConfigBits;
 
+mod particle_effect;
+pub use particle_effect::ParticleEffectBits;
+
 mod unit;
 pub use unit::UnitBits;
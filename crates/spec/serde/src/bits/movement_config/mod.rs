@@ -12,20 +12,92 @@ cfg_if! {
     } else {}
 }
 
+// all movement values are serialized as hundredths
 pub type VelocitySerdeInt = UnsignedVariableInteger<4>;
+pub type MovementValueSerdeInt = UnsignedVariableInteger<4>;
 
 use naia_serde::UnsignedVariableInteger;
 
 pub struct MovementConfigBits {
     max_velocity: f32,
+    min_velocity: f32,
+    acceleration: f32,
+    friction: f32,
+    arrival_distance: f32,
+    steering_deadzone: f32,
 }
 
 impl MovementConfigBits {
-    pub fn new(max_velocity: f32) -> Self {
-        Self { max_velocity }
+    // written first, so that files of another layout fail to read rather than read as garbage.
+    // bump it whenever the layout changes, and re-process the movement config assets
+    pub const FORMAT_VERSION: u8 = 1;
+
+    pub fn new(
+        max_velocity: f32,
+        min_velocity: f32,
+        acceleration: f32,
+        friction: f32,
+        arrival_distance: f32,
+        steering_deadzone: f32,
+    ) -> Self {
+        Self {
+            max_velocity,
+            min_velocity,
+            acceleration,
+            friction,
+            arrival_distance,
+            steering_deadzone,
+        }
     }
 
     pub fn get_max_velocity(&self) -> f32 {
         self.max_velocity
     }
+
+    pub fn get_min_velocity(&self) -> f32 {
+        self.min_velocity
+    }
+
+    pub fn get_acceleration(&self) -> f32 {
+        self.acceleration
+    }
+
+    pub fn get_friction(&self) -> f32 {
+        self.friction
+    }
+
+    pub fn get_arrival_distance(&self) -> f32 {
+        self.arrival_distance
+    }
+
+    pub fn get_steering_deadzone(&self) -> f32 {
+        self.steering_deadzone
+    }
+}
+
+#[cfg(all(test, feature = "read_bits", feature = "write_bits"))]
+mod tests {
+    use spec::MovementConfig;
+
+    use super::MovementConfigBits;
+
+    #[test]
+    fn roundtrip() {
+        let config = MovementConfig::new(12.0, 3.0, 1.5, 0.75, 5.0, 4.25);
+
+        let bytes: Vec<u8> = MovementConfigBits::from(&config).into();
+        let read: MovementConfig = MovementConfigBits::from_bytes(&bytes).unwrap().into();
+
+        assert_eq!(read, config);
+    }
+
+    #[test]
+    fn other_versions_are_rejected() {
+        let config = MovementConfig::new(12.0, 3.0, 1.5, 0.75, 5.0, 4.25);
+
+        let mut bytes: Vec<u8> = MovementConfigBits::from(&config).into();
+        bytes[0] = MovementConfigBits::FORMAT_VERSION + 1;
+
+        assert!(MovementConfigBits::from_bytes(&bytes).is_err());
+    }
 }
@@ -2,7 +2,10 @@ use naia_serde::{BitReader, SerdeErr, SerdeInternal as Serde};
 
 use spec::MovementConfig;
 
-use crate::bits::{movement_config::VelocitySerdeInt, MovementConfigBits};
+use crate::bits::{
+    movement_config::{MovementValueSerdeInt, VelocitySerdeInt},
+    MovementConfigBits,
+};
 
 /////
 
@@ -11,15 +14,47 @@ impl MovementConfigBits {
         let mut bit_reader = BitReader::new(bytes);
         let bit_reader = &mut bit_reader;
 
+        let version = u8::de(bit_reader)?;
+        if version != Self::FORMAT_VERSION {
+            return Err(SerdeErr);
+        }
+
         let max_velocity: u32 = VelocitySerdeInt::de(bit_reader)?.to();
         let max_velocity = (max_velocity as f32) / 100.0;
 
-        Ok(Self::new(max_velocity))
+        let min_velocity: u32 = VelocitySerdeInt::de(bit_reader)?.to();
+        let min_velocity = (min_velocity as f32) / 100.0;
+
+        let acceleration = read_value(bit_reader)?;
+        let friction = read_value(bit_reader)?;
+        let arrival_distance = read_value(bit_reader)?;
+        let steering_deadzone = read_value(bit_reader)?;
+
+        Ok(Self::new(
+            max_velocity,
+            min_velocity,
+            acceleration,
+            friction,
+            arrival_distance,
+            steering_deadzone,
+        ))
     }
 }
 
+fn read_value(bit_reader: &mut BitReader) -> Result<f32, SerdeErr> {
+    let value: u32 = MovementValueSerdeInt::de(bit_reader)?.to();
+    Ok((value as f32) / 100.0)
+}
+
 impl Into<MovementConfig> for MovementConfigBits {
     fn into(self) -> MovementConfig {
-        MovementConfig::new(self.get_max_velocity())
+        MovementConfig::new(
+            self.get_max_velocity(),
+            self.get_min_velocity(),
+            self.get_acceleration(),
+            self.get_friction(),
+            self.get_arrival_distance(),
+            self.get_steering_deadzone(),
+        )
     }
 }
@@ -1,12 +1,22 @@
-use naia_serde::{FileBitWriter, SerdeInternal as Serde};
+use naia_serde::{BitWrite, FileBitWriter, SerdeInternal as Serde};
 
 use spec::MovementConfig;
 
-use crate::bits::{movement_config::VelocitySerdeInt, MovementConfigBits};
+use crate::bits::{
+    movement_config::{MovementValueSerdeInt, VelocitySerdeInt},
+    MovementConfigBits,
+};
 
 impl From<&MovementConfig> for MovementConfigBits {
     fn from(value: &MovementConfig) -> Self {
-        Self::new(value.max_velocity())
+        Self::new(
+            value.max_velocity(),
+            value.min_velocity(),
+            value.acceleration(),
+            value.friction(),
+            value.arrival_distance(),
+            value.steering_deadzone(),
+        )
     }
 }
 
@@ -14,10 +24,27 @@ impl Into<Vec<u8>> for MovementConfigBits {
     fn into(self) -> Vec<u8> {
         let mut bit_writer = FileBitWriter::new();
 
+        Self::FORMAT_VERSION.ser(&mut bit_writer);
+
         let max_velocity = (self.get_max_velocity() * 100.0) as u32;
         let max_velocity = VelocitySerdeInt::new(max_velocity);
         max_velocity.ser(&mut bit_writer);
 
+        let min_velocity = (self.get_min_velocity() * 100.0) as u32;
+        let min_velocity = VelocitySerdeInt::new(min_velocity);
+        min_velocity.ser(&mut bit_writer);
+
+        write_value(&mut bit_writer, self.get_acceleration());
+        write_value(&mut bit_writer, self.get_friction());
+        write_value(&mut bit_writer, self.get_arrival_distance());
+        write_value(&mut bit_writer, self.get_steering_deadzone());
+
         bit_writer.to_vec()
     }
 }
+
+fn write_value(bit_writer: &mut dyn BitWrite, value: f32) {
+    let value = (value * 100.0) as u32;
+    let value = MovementValueSerdeInt::new(value);
+    value.ser(bit_writer);
+}
@@ -0,0 +1,35 @@
+use cfg_if::cfg_if;
+
+cfg_if! {
+    if #[cfg(feature = "read_bits")] {
+        mod read;
+    } else {}
+}
+
+cfg_if! {
+    if #[cfg(feature = "write_bits")] {
+        mod write;
+    } else {}
+}
+
+use naia_serde::{SerdeInternal as Serde, SignedVariableInteger, UnsignedVariableInteger};
+
+// rates, angles, speeds & sizes are serialized as hundredths, signed for directions & accelerations
+pub type ParticleValueSerdeInt = SignedVariableInteger<7>;
+pub type LifetimeSerdeInt = UnsignedVariableInteger<7>;
+
+#[derive(Serde, Clone, PartialEq)]
+pub struct ParticleEffectBits {
+    spawn_rate: ParticleValueSerdeInt,
+    lifetime_ms: LifetimeSerdeInt,
+    max_particles: u16,
+    direction: [ParticleValueSerdeInt; 3],
+    spread_degrees: ParticleValueSerdeInt,
+    min_speed: ParticleValueSerdeInt,
+    max_speed: ParticleValueSerdeInt,
+    acceleration: [ParticleValueSerdeInt; 3],
+    start_color: [u8; 3],
+    end_color: [u8; 3],
+    start_size: ParticleValueSerdeInt,
+    end_size: ParticleValueSerdeInt,
+}
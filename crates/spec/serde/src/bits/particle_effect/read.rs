@@ -0,0 +1,52 @@
+use naia_serde::{BitReader, SerdeErr, SerdeInternal as Serde};
+
+use spec::ParticleEffect;
+
+use crate::bits::{particle_effect::ParticleValueSerdeInt, ParticleEffectBits};
+
+impl ParticleEffectBits {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SerdeErr> {
+        let mut bit_reader = BitReader::new(bytes);
+        let bit_reader = &mut bit_reader;
+
+        Self::de(bit_reader)
+    }
+}
+
+impl From<ParticleEffectBits> for ParticleEffect {
+    fn from(value: ParticleEffectBits) -> Self {
+        let lifetime_ms: u32 = value.lifetime_ms.to();
+
+        ParticleEffect::new(
+            read_value(&value.spawn_rate),
+            lifetime_ms as f32,
+            value.max_particles,
+        )
+        .with_velocity(
+            read_vector(&value.direction),
+            read_value(&value.spread_degrees),
+            read_value(&value.min_speed),
+            read_value(&value.max_speed),
+        )
+        .with_acceleration(read_vector(&value.acceleration))
+        .with_color(read_color(value.start_color), read_color(value.end_color))
+        .with_size(read_value(&value.start_size), read_value(&value.end_size))
+    }
+}
+
+fn read_value(value: &ParticleValueSerdeInt) -> f32 {
+    let value: i64 = value.to();
+    (value as f32) / 100.0
+}
+
+fn read_vector(vector: &[ParticleValueSerdeInt; 3]) -> (f32, f32, f32) {
+    (
+        read_value(&vector[0]),
+        read_value(&vector[1]),
+        read_value(&vector[2]),
+    )
+}
+
+fn read_color(color: [u8; 3]) -> (u8, u8, u8) {
+    (color[0], color[1], color[2])
+}
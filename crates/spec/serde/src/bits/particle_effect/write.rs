@@ -0,0 +1,53 @@
+use naia_serde::{FileBitWriter, SerdeInternal as Serde};
+
+use spec::ParticleEffect;
+
+use crate::bits::{
+    particle_effect::{LifetimeSerdeInt, ParticleValueSerdeInt},
+    ParticleEffectBits,
+};
+
+impl From<&ParticleEffect> for ParticleEffectBits {
+    fn from(value: &ParticleEffect) -> Self {
+        Self {
+            spawn_rate: write_value(value.spawn_rate()),
+            lifetime_ms: LifetimeSerdeInt::new(value.lifetime_ms().round() as u32),
+            max_particles: value.max_particles(),
+            direction: write_vector(value.direction()),
+            spread_degrees: write_value(value.spread_degrees()),
+            min_speed: write_value(value.min_speed()),
+            max_speed: write_value(value.max_speed()),
+            acceleration: write_vector(value.acceleration()),
+            start_color: write_color(value.start_color()),
+            end_color: write_color(value.end_color()),
+            start_size: write_value(value.start_size()),
+            end_size: write_value(value.end_size()),
+        }
+    }
+}
+
+impl Into<Vec<u8>> for ParticleEffectBits {
+    fn into(self) -> Vec<u8> {
+        let mut bit_writer = FileBitWriter::new();
+
+        self.ser(&mut bit_writer);
+
+        bit_writer.to_vec()
+    }
+}
+
+fn write_value(value: f32) -> ParticleValueSerdeInt {
+    ParticleValueSerdeInt::new((value * 100.0).round() as i64)
+}
+
+fn write_vector(vector: (f32, f32, f32)) -> [ParticleValueSerdeInt; 3] {
+    [
+        write_value(vector.0),
+        write_value(vector.1),
+        write_value(vector.2),
+    ]
+}
+
+fn write_color(color: (u8, u8, u8)) -> [u8; 3] {
+    [color.0, color.1, color.2]
+}
@@ -20,13 +20,19 @@ use asset_id::AssetId;
 pub struct UnitBits {
     animated_model_asset_id: AssetId,
     movement_config_asset_id: AssetId,
+    particle_effect_asset_id: Option<AssetId>,
 }
 
 impl UnitBits {
-    pub fn new(animated_model_asset_id: AssetId, movement_config_asset_id: AssetId) -> Self {
+    pub fn new(
+        animated_model_asset_id: AssetId,
+        movement_config_asset_id: AssetId,
+        particle_effect_asset_id: Option<AssetId>,
+    ) -> Self {
         Self {
             animated_model_asset_id,
             movement_config_asset_id,
+            particle_effect_asset_id,
         }
     }
 
@@ -37,4 +43,8 @@ impl UnitBits {
     pub fn get_movement_config_asset_id(&self) -> AssetId {
         self.movement_config_asset_id
     }
+
+    pub fn get_particle_effect_asset_id(&self) -> Option<AssetId> {
+        self.particle_effect_asset_id
+    }
 }
@@ -13,11 +13,15 @@ impl UnitBits {
     }
 }
 
-impl Into<Unit> for UnitBits {
-    fn into(self) -> Unit {
-        Unit::new(
-            self.get_animated_model_asset_id(),
-            self.get_movement_config_asset_id(),
-        )
+impl From<UnitBits> for Unit {
+    fn from(value: UnitBits) -> Self {
+        let unit = Unit::new(
+            value.get_animated_model_asset_id(),
+            value.get_movement_config_asset_id(),
+        );
+        match value.get_particle_effect_asset_id() {
+            Some(asset_id) => unit.with_particle_effect(asset_id),
+            None => unit,
+        }
     }
 }
@@ -9,6 +9,7 @@ impl From<&Unit> for UnitBits {
         Self::new(
             value.get_animated_model_asset_id(),
             value.get_movement_config_asset_id(),
+            value.get_particle_effect_asset_id(),
         )
     }
 }
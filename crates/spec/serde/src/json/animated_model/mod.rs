@@ -24,15 +24,19 @@ pub struct AnimatedModelJson {
     model_asset_id: String,
     // animation name -> animation asset id
     animations: HashMap<String, String>,
+    // models without a graph play the built-in idle / walk pair
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    graph: Option<AnimationGraphJson>,
 }
 
 impl AnimatedModelJson {
-    pub const CURRENT_SCHEMA_VERSION: u32 = 0;
+    pub const CURRENT_SCHEMA_VERSION: u32 = 1;
 
     pub fn new() -> Self {
         Self {
             model_asset_id: String::new(),
             animations: HashMap::new(),
+            graph: None,
         }
     }
 
@@ -64,4 +68,222 @@ impl AnimatedModelJson {
         self.animations
             .insert(name.to_string(), asset_id.as_string());
     }
+
+    pub fn get_graph(&self) -> Option<&AnimationGraphJson> {
+        self.graph.as_ref()
+    }
+
+    pub fn set_graph(&mut self, graph: AnimationGraphJson) {
+        self.graph = Some(graph);
+    }
+}
+
+// AnimationGraph
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct AnimationGraphJson {
+    default_state: String,
+    states: Vec<AnimationGraphStateJson>,
+    transitions: Vec<AnimationTransitionJson>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    layers: Vec<AnimationGraphLayerJson>,
+}
+
+impl AnimationGraphJson {
+    pub fn new(default_state: &str) -> Self {
+        Self {
+            default_state: default_state.to_string(),
+            states: Vec::new(),
+            transitions: Vec::new(),
+            layers: Vec::new(),
+        }
+    }
+
+    pub fn get_default_state(&self) -> &str {
+        &self.default_state
+    }
+
+    pub fn get_states(&self) -> &Vec<AnimationGraphStateJson> {
+        &self.states
+    }
+
+    pub fn add_state(&mut self, state: AnimationGraphStateJson) {
+        self.states.push(state);
+    }
+
+    pub fn get_transitions(&self) -> &Vec<AnimationTransitionJson> {
+        &self.transitions
+    }
+
+    pub fn add_transition(&mut self, transition: AnimationTransitionJson) {
+        self.transitions.push(transition);
+    }
+
+    pub fn get_layers(&self) -> &Vec<AnimationGraphLayerJson> {
+        &self.layers
+    }
+
+    pub fn add_layer(&mut self, layer: AnimationGraphLayerJson) {
+        self.layers.push(layer);
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct AnimationGraphLayerJson {
+    name: String,
+    // bones the layer drives, every bone when empty
+    #[serde(default)]
+    bone_mask: Vec<String>,
+    // otherwise the layer overrides the pose beneath
+    #[serde(default)]
+    additive: bool,
+    graph: AnimationGraphJson,
+}
+
+impl AnimationGraphLayerJson {
+    pub fn new(
+        name: &str,
+        bone_mask: Vec<String>,
+        additive: bool,
+        graph: AnimationGraphJson,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            bone_mask,
+            additive,
+            graph,
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_bone_mask(&self) -> &Vec<String> {
+        &self.bone_mask
+    }
+
+    pub fn is_additive(&self) -> bool {
+        self.additive
+    }
+
+    pub fn get_graph(&self) -> &AnimationGraphJson {
+        &self.graph
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct AnimationGraphStateJson {
+    name: String,
+    // missing means the state plays nothing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    animation: Option<String>,
+    // clip ms per elapsed ms, or per world unit moved when `per_distance` is set
+    playback_rate: f32,
+    #[serde(default)]
+    per_distance: bool,
+}
+
+impl AnimationGraphStateJson {
+    pub fn new(
+        name: &str,
+        animation: Option<&str>,
+        playback_rate: f32,
+        per_distance: bool,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            animation: animation.map(str::to_string),
+            playback_rate,
+            per_distance,
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_animation(&self) -> Option<&str> {
+        self.animation.as_deref()
+    }
+
+    pub fn get_playback_rate(&self) -> f32 {
+        self.playback_rate
+    }
+
+    pub fn is_per_distance(&self) -> bool {
+        self.per_distance
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct AnimationTransitionJson {
+    // missing means "from any state"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    from: Option<String>,
+    to: String,
+    #[serde(default)]
+    conditions: Vec<AnimationConditionJson>,
+    fade_ms: f32,
+}
+
+impl AnimationTransitionJson {
+    pub fn new(from: Option<&str>, to: &str, fade_ms: f32) -> Self {
+        Self {
+            from: from.map(str::to_string),
+            to: to.to_string(),
+            conditions: Vec::new(),
+            fade_ms,
+        }
+    }
+
+    pub fn get_from(&self) -> Option<&str> {
+        self.from.as_deref()
+    }
+
+    pub fn get_to(&self) -> &str {
+        &self.to
+    }
+
+    pub fn get_conditions(&self) -> &Vec<AnimationConditionJson> {
+        &self.conditions
+    }
+
+    pub fn add_condition(&mut self, condition: AnimationConditionJson) {
+        self.conditions.push(condition);
+    }
+
+    pub fn get_fade_ms(&self) -> f32 {
+        self.fade_ms
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct AnimationConditionJson {
+    parameter: String,
+    // either ">" or "<"
+    comparison: String,
+    value: f32,
+}
+
+impl AnimationConditionJson {
+    pub fn new(parameter: &str, comparison: &str, value: f32) -> Self {
+        Self {
+            parameter: parameter.to_string(),
+            comparison: comparison.to_string(),
+            value,
+        }
+    }
+
+    pub fn get_parameter(&self) -> &str {
+        &self.parameter
+    }
+
+    pub fn get_comparison(&self) -> &str {
+        &self.comparison
+    }
+
+    pub fn get_value(&self) -> f32 {
+        self.value
+    }
 }
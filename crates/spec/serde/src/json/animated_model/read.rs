@@ -1,14 +1,76 @@
 use asset_id::AssetId;
-use spec::AnimatedModel;
+use spec::{
+    AnimatedModel, AnimationComparison, AnimationGraph, AnimationGraphLayer, AnimationGraphState,
+    AnimationLayerMode, AnimationPlayback, AnimationTransition,
+};
 
-use crate::json::AnimatedModelJson;
+use crate::json::{AnimatedModelJson, AnimationGraphJson};
 
-impl Into<AnimatedModel> for AnimatedModelJson {
-    fn into(self) -> AnimatedModel {
-        let mut me = AnimatedModel::new(self.get_model_asset_id());
-        for (name, asset_id) in self.get_animations() {
+impl From<AnimatedModelJson> for AnimatedModel {
+    fn from(value: AnimatedModelJson) -> Self {
+        let mut me = AnimatedModel::new(value.get_model_asset_id());
+        for (name, asset_id) in value.get_animations() {
             me.add_animation(name, AssetId::from_str(asset_id).unwrap());
         }
+        if let Some(graph) = value.get_graph() {
+            me.set_graph(graph.clone().into());
+        }
+
+        me
+    }
+}
+
+impl From<AnimationGraphJson> for AnimationGraph {
+    fn from(value: AnimationGraphJson) -> Self {
+        let mut me = AnimationGraph::new(value.get_default_state());
+        for state in value.get_states() {
+            let playback = if state.is_per_distance() {
+                AnimationPlayback::Distance(state.get_playback_rate())
+            } else {
+                AnimationPlayback::Time(state.get_playback_rate())
+            };
+            let state = match state.get_animation() {
+                Some(animation) => AnimationGraphState::new(state.get_name(), animation, playback),
+                None => AnimationGraphState::empty(state.get_name()),
+            };
+            me.add_state(state);
+        }
+        for transition_json in value.get_transitions() {
+            let mut transition = AnimationTransition::new(
+                transition_json.get_from(),
+                transition_json.get_to(),
+                transition_json.get_fade_ms(),
+            );
+            for condition in transition_json.get_conditions() {
+                let comparison = match condition.get_comparison() {
+                    ">" => AnimationComparison::GreaterThan,
+                    "<" => AnimationComparison::LessThan,
+                    other => panic!("unknown animation condition comparison: {}", other),
+                };
+                transition.add_condition(
+                    condition.get_parameter(),
+                    comparison,
+                    condition.get_value(),
+                );
+            }
+            me.add_transition(transition);
+        }
+        for layer_json in value.get_layers() {
+            let mode = if layer_json.is_additive() {
+                AnimationLayerMode::Additive
+            } else {
+                AnimationLayerMode::Override
+            };
+            let mut layer = AnimationGraphLayer::new(
+                layer_json.get_name(),
+                mode,
+                layer_json.get_graph().clone().into(),
+            );
+            for bone_name in layer_json.get_bone_mask() {
+                layer.add_bone(bone_name);
+            }
+            me.add_layer(layer);
+        }
 
         me
     }
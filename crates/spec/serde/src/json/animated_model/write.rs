@@ -1,6 +1,11 @@
-use spec::AnimatedModel;
+use spec::{
+    AnimatedModel, AnimationComparison, AnimationGraph, AnimationLayerMode, AnimationPlayback,
+};
 
-use crate::json::AnimatedModelJson;
+use crate::json::{
+    AnimatedModelJson, AnimationConditionJson, AnimationGraphJson, AnimationGraphLayerJson,
+    AnimationGraphStateJson, AnimationTransitionJson,
+};
 
 impl From<&AnimatedModel> for AnimatedModelJson {
     fn from(value: &AnimatedModel) -> Self {
@@ -9,6 +14,56 @@ impl From<&AnimatedModel> for AnimatedModelJson {
         for (name, asset_id) in value.get_animations() {
             me.add_animation(name, asset_id);
         }
+        if let Some(graph) = value.get_graph() {
+            me.set_graph(graph.into());
+        }
+
+        me
+    }
+}
+
+impl From<&AnimationGraph> for AnimationGraphJson {
+    fn from(value: &AnimationGraph) -> Self {
+        let mut me = Self::new(value.get_default_state());
+        for state in value.get_states() {
+            let (playback_rate, per_distance) = match state.get_playback() {
+                AnimationPlayback::Time(rate) => (rate, false),
+                AnimationPlayback::Distance(rate) => (rate, true),
+            };
+            me.add_state(AnimationGraphStateJson::new(
+                state.get_name(),
+                state.get_animation(),
+                playback_rate,
+                per_distance,
+            ));
+        }
+        for transition in value.get_transitions() {
+            let mut transition_json = AnimationTransitionJson::new(
+                transition.get_from(),
+                transition.get_to(),
+                transition.get_fade_ms(),
+            );
+            for condition in transition.get_conditions() {
+                let comparison = match condition.get_comparison() {
+                    AnimationComparison::GreaterThan => ">",
+                    AnimationComparison::LessThan => "<",
+                };
+                transition_json.add_condition(AnimationConditionJson::new(
+                    condition.get_parameter(),
+                    comparison,
+                    condition.get_value(),
+                ));
+            }
+            me.add_transition(transition_json);
+        }
+        for layer in value.get_layers() {
+            me.add_layer(AnimationGraphLayerJson::new(
+                layer.get_name(),
+                layer.get_bone_mask().clone(),
+                layer.get_mode() == AnimationLayerMode::Additive,
+                layer.get_graph().into(),
+            ));
+        }
 
         me
     }
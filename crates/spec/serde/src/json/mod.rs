@@ -1,8 +1,14 @@
 mod animated_model;
-pub use animated_model::AnimatedModelJson;
+pub use animated_model::{
+    AnimatedModelJson, AnimationConditionJson, AnimationGraphJson, AnimationGraphLayerJson,
+    AnimationGraphStateJson, AnimationTransitionJson,
+};
 
 mod movement_config;
 pub use movement_config::MovementConfigJson;
 
+mod particle_effect;
+pub use particle_effect::ParticleEffectJson;
+
 mod unit;
 pub use unit::UnitJson;
@@ -2,6 +2,7 @@ use cfg_if::cfg_if;
 use serde::{Deserialize, Serialize};
 
 use asset_id::AssetId;
+use spec::MovementConfig;
 
 cfg_if! {
     if #[cfg(feature = "read_json")] {
@@ -17,16 +18,66 @@ cfg_if! {
 
 // MovementConfig
 
+// schema 0 files only hold a max velocity, the rest default to `MovementConfig`'s defaults
 #[derive(Serialize, Deserialize, Clone)]
 pub struct MovementConfigJson {
     max_velocity: f32, // in meters per second
+    #[serde(default = "default_min_velocity")]
+    min_velocity: f32, // in meters per second
+    #[serde(default = "default_acceleration")]
+    acceleration: f32,
+    #[serde(default = "default_friction")]
+    friction: f32,
+    #[serde(default = "default_arrival_distance")]
+    arrival_distance: f32,
+    #[serde(default = "default_steering_deadzone")]
+    steering_deadzone: f32,
+}
+
+fn default_min_velocity() -> f32 {
+    MovementConfig::DEFAULT_MIN_VELOCITY
+}
+
+fn default_acceleration() -> f32 {
+    MovementConfig::DEFAULT_ACCELERATION
+}
+
+fn default_friction() -> f32 {
+    MovementConfig::DEFAULT_FRICTION
+}
+
+fn default_arrival_distance() -> f32 {
+    MovementConfig::DEFAULT_ARRIVAL_DISTANCE
+}
+
+fn default_steering_deadzone() -> f32 {
+    MovementConfig::DEFAULT_STEERING_DEADZONE
 }
 
 impl MovementConfigJson {
-    pub const CURRENT_SCHEMA_VERSION: u32 = 0;
+    pub const CURRENT_SCHEMA_VERSION: u32 = 1;
+
+    // older schemas are read with the defaults above, newer ones are from a newer editor
+    pub fn check_schema_version(schema_version: u32) -> Result<(), String> {
+        if schema_version > Self::CURRENT_SCHEMA_VERSION {
+            return Err(format!(
+                "movement config schema version {} is newer than the supported {}",
+                schema_version,
+                Self::CURRENT_SCHEMA_VERSION
+            ));
+        }
+        Ok(())
+    }
 
     pub fn new() -> Self {
-        Self { max_velocity: 0.0 }
+        Self {
+            max_velocity: 0.0,
+            min_velocity: 0.0,
+            acceleration: 0.0,
+            friction: 0.0,
+            arrival_distance: 0.0,
+            steering_deadzone: 0.0,
+        }
     }
 
     pub fn dependencies(&self) -> Vec<AssetId> {
@@ -40,4 +91,83 @@ impl MovementConfigJson {
     pub fn set_max_velocity(&mut self, val: f32) {
         self.max_velocity = val;
     }
+
+    pub fn get_min_velocity(&self) -> f32 {
+        self.min_velocity
+    }
+
+    pub fn set_min_velocity(&mut self, val: f32) {
+        self.min_velocity = val;
+    }
+
+    pub fn get_acceleration(&self) -> f32 {
+        self.acceleration
+    }
+
+    pub fn set_acceleration(&mut self, val: f32) {
+        self.acceleration = val;
+    }
+
+    pub fn get_friction(&self) -> f32 {
+        self.friction
+    }
+
+    pub fn set_friction(&mut self, val: f32) {
+        self.friction = val;
+    }
+
+    pub fn get_arrival_distance(&self) -> f32 {
+        self.arrival_distance
+    }
+
+    pub fn set_arrival_distance(&mut self, val: f32) {
+        self.arrival_distance = val;
+    }
+
+    pub fn get_steering_deadzone(&self) -> f32 {
+        self.steering_deadzone
+    }
+
+    pub fn set_steering_deadzone(&mut self, val: f32) {
+        self.steering_deadzone = val;
+    }
+}
+
+#[cfg(all(test, feature = "read_json", feature = "write_json"))]
+mod tests {
+    use spec::MovementConfig;
+
+    use super::MovementConfigJson;
+
+    #[test]
+    fn roundtrip() {
+        let config = MovementConfig::new(12.0, 3.0, 1.5, 0.75, 5.0, 4.25);
+
+        let json = serde_json::to_string(&MovementConfigJson::from(&config)).unwrap();
+        let read: MovementConfigJson = serde_json::from_str(&json).unwrap();
+        let read: MovementConfig = read.into();
+
+        assert_eq!(read, config);
+    }
+
+    #[test]
+    fn schema_0_files_read_with_defaults() {
+        let read: MovementConfigJson = serde_json::from_str(r#"{"max_velocity":8.0}"#).unwrap();
+        let read: MovementConfig = read.into();
+
+        assert_eq!(read, MovementConfig::new(8.0, 3.0, 1.0, 1.0, 5.0, 4.0));
+    }
+
+    #[test]
+    fn newer_schemas_are_rejected() {
+        assert!(MovementConfigJson::check_schema_version(0).is_ok());
+        assert!(MovementConfigJson::check_schema_version(
+            MovementConfigJson::CURRENT_SCHEMA_VERSION
+        )
+        .is_ok());
+        assert!(MovementConfigJson::check_schema_version(
+            MovementConfigJson::CURRENT_SCHEMA_VERSION + 1
+        )
+        .is_err());
+    }
 }
@@ -4,6 +4,13 @@ use crate::json::MovementConfigJson;
 
 impl Into<MovementConfig> for MovementConfigJson {
     fn into(self) -> MovementConfig {
-        MovementConfig::new(self.max_velocity)
+        MovementConfig::new(
+            self.max_velocity,
+            self.min_velocity,
+            self.acceleration,
+            self.friction,
+            self.arrival_distance,
+            self.steering_deadzone,
+        )
     }
 }
@@ -7,6 +7,11 @@ impl From<&MovementConfig> for MovementConfigJson {
         let mut me = Self::new();
 
         me.set_max_velocity(value.max_velocity());
+        me.set_min_velocity(value.min_velocity());
+        me.set_acceleration(value.acceleration());
+        me.set_friction(value.friction());
+        me.set_arrival_distance(value.arrival_distance());
+        me.set_steering_deadzone(value.steering_deadzone());
 
         me
     }
@@ -0,0 +1,98 @@
+use cfg_if::cfg_if;
+use serde::{Deserialize, Serialize};
+
+use asset_id::AssetId;
+
+cfg_if! {
+    if #[cfg(feature = "read_json")] {
+        mod read;
+    } else {}
+}
+
+cfg_if! {
+    if #[cfg(feature = "write_json")] {
+        mod write;
+    } else {}
+}
+
+// ParticleEffect
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ParticleEffectJson {
+    spawn_rate: f32, // particles per second
+    lifetime_ms: f32,
+    max_particles: u16,
+    direction: [f32; 3],
+    spread_degrees: f32,
+    min_speed: f32, // in meters per second
+    max_speed: f32, // in meters per second
+    acceleration: [f32; 3],
+    start_color: [u8; 3],
+    end_color: [u8; 3],
+    start_size: f32,
+    end_size: f32,
+}
+
+impl Default for ParticleEffectJson {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParticleEffectJson {
+    pub const CURRENT_SCHEMA_VERSION: u32 = 0;
+
+    pub fn new() -> Self {
+        Self {
+            spawn_rate: 0.0,
+            lifetime_ms: 0.0,
+            max_particles: 0,
+            direction: [0.0, 1.0, 0.0],
+            spread_degrees: 0.0,
+            min_speed: 0.0,
+            max_speed: 0.0,
+            acceleration: [0.0; 3],
+            start_color: [255; 3],
+            end_color: [255; 3],
+            start_size: 1.0,
+            end_size: 1.0,
+        }
+    }
+
+    pub fn dependencies(&self) -> Vec<AssetId> {
+        Vec::new()
+    }
+
+    pub fn set_spawn(&mut self, spawn_rate: f32, lifetime_ms: f32, max_particles: u16) {
+        self.spawn_rate = spawn_rate;
+        self.lifetime_ms = lifetime_ms;
+        self.max_particles = max_particles;
+    }
+
+    pub fn set_velocity(
+        &mut self,
+        direction: [f32; 3],
+        spread_degrees: f32,
+        min_speed: f32,
+        max_speed: f32,
+    ) {
+        self.direction = direction;
+        self.spread_degrees = spread_degrees;
+        self.min_speed = min_speed;
+        self.max_speed = max_speed;
+    }
+
+    pub fn set_acceleration(&mut self, acceleration: [f32; 3]) {
+        self.acceleration = acceleration;
+    }
+
+    pub fn set_color(&mut self, start_color: [u8; 3], end_color: [u8; 3]) {
+        self.start_color = start_color;
+        self.end_color = end_color;
+    }
+
+    pub fn set_size(&mut self, start_size: f32, end_size: f32) {
+        self.start_size = start_size;
+        self.end_size = end_size;
+    }
+}
@@ -0,0 +1,23 @@
+use spec::ParticleEffect;
+
+use crate::json::ParticleEffectJson;
+
+impl From<ParticleEffectJson> for ParticleEffect {
+    fn from(value: ParticleEffectJson) -> Self {
+        let [dx, dy, dz] = value.direction;
+        let [ax, ay, az] = value.acceleration;
+        let [sr, sg, sb] = value.start_color;
+        let [er, eg, eb] = value.end_color;
+
+        ParticleEffect::new(value.spawn_rate, value.lifetime_ms, value.max_particles)
+            .with_velocity(
+                (dx, dy, dz),
+                value.spread_degrees,
+                value.min_speed,
+                value.max_speed,
+            )
+            .with_acceleration((ax, ay, az))
+            .with_color((sr, sg, sb), (er, eg, eb))
+            .with_size(value.start_size, value.end_size)
+    }
+}
@@ -0,0 +1,31 @@
+use spec::ParticleEffect;
+
+use crate::json::ParticleEffectJson;
+
+impl From<&ParticleEffect> for ParticleEffectJson {
+    fn from(value: &ParticleEffect) -> Self {
+        let mut me = Self::new();
+
+        let (dx, dy, dz) = value.direction();
+        let (ax, ay, az) = value.acceleration();
+        let (sr, sg, sb) = value.start_color();
+        let (er, eg, eb) = value.end_color();
+
+        me.set_spawn(
+            value.spawn_rate(),
+            value.lifetime_ms(),
+            value.max_particles(),
+        );
+        me.set_velocity(
+            [dx, dy, dz],
+            value.spread_degrees(),
+            value.min_speed(),
+            value.max_speed(),
+        );
+        me.set_acceleration([ax, ay, az]);
+        me.set_color([sr, sg, sb], [er, eg, eb]);
+        me.set_size(value.start_size(), value.end_size());
+
+        me
+    }
+}
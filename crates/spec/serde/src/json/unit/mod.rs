@@ -21,6 +21,8 @@ cfg_if! {
 pub struct UnitJson {
     animated_model_asset_id: String,
     movement_config_asset_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    particle_effect_asset_id: Option<String>,
 }
 
 impl UnitJson {
@@ -30,6 +32,7 @@ impl UnitJson {
         Self {
             animated_model_asset_id: String::new(),
             movement_config_asset_id: String::new(),
+            particle_effect_asset_id: None,
         }
     }
 
@@ -38,6 +41,9 @@ impl UnitJson {
 
         output.push(self.get_animated_model_asset_id());
         output.push(self.get_movement_config_asset_id());
+        if let Some(asset_id) = self.get_particle_effect_asset_id() {
+            output.push(asset_id);
+        }
 
         output
     }
@@ -57,4 +63,14 @@ impl UnitJson {
     pub fn set_movement_config_asset_id(&mut self, asset_id: &AssetId) {
         self.movement_config_asset_id = asset_id.as_string();
     }
+
+    pub fn get_particle_effect_asset_id(&self) -> Option<AssetId> {
+        self.particle_effect_asset_id
+            .as_ref()
+            .map(|asset_id| AssetId::from_str(asset_id).unwrap())
+    }
+
+    pub fn set_particle_effect_asset_id(&mut self, asset_id: &AssetId) {
+        self.particle_effect_asset_id = Some(asset_id.as_string());
+    }
 }
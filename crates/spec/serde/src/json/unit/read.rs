@@ -4,9 +4,13 @@ use crate::json::UnitJson;
 
 impl Into<Unit> for UnitJson {
     fn into(self) -> Unit {
-        Unit::new(
+        let unit = Unit::new(
             self.get_animated_model_asset_id(),
             self.get_movement_config_asset_id(),
-        )
+        );
+        match self.get_particle_effect_asset_id() {
+            Some(asset_id) => unit.with_particle_effect(asset_id),
+            None => unit,
+        }
     }
 }
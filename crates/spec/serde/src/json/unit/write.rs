@@ -7,6 +7,9 @@ impl From<&Unit> for UnitJson {
         let mut me = Self::new();
         me.set_animated_model_asset_id(&value.get_animated_model_asset_id());
         me.set_movement_config_asset_id(&value.get_movement_config_asset_id());
+        if let Some(asset_id) = value.get_particle_effect_asset_id() {
+            me.set_particle_effect_asset_id(&asset_id);
+        }
         me
     }
 }
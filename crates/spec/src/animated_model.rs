@@ -2,9 +2,12 @@ use std::collections::HashMap;
 
 use asset_id::AssetId;
 
+use crate::AnimationGraph;
+
 pub struct AnimatedModel {
     model_asset_id: AssetId,
     animations: HashMap<String, AssetId>,
+    graph: Option<AnimationGraph>,
 }
 
 impl AnimatedModel {
@@ -12,6 +15,7 @@ impl AnimatedModel {
         Self {
             model_asset_id,
             animations: HashMap::new(),
+            graph: None,
         }
     }
 
@@ -26,4 +30,19 @@ impl AnimatedModel {
     pub fn add_animation(&mut self, name: &str, asset_id: AssetId) {
         self.animations.insert(name.to_string(), asset_id);
     }
+
+    pub fn get_graph(&self) -> Option<&AnimationGraph> {
+        self.graph.as_ref()
+    }
+
+    pub fn set_graph(&mut self, graph: AnimationGraph) {
+        self.graph = Some(graph);
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        match &self.graph {
+            Some(graph) => graph.validate(&self.animations),
+            None => Ok(()),
+        }
+    }
 }
@@ -0,0 +1,436 @@
+use std::collections::HashMap;
+
+use asset_id::AssetId;
+
+// A state machine over an animated model's clips. The client feeds it parameters every update
+// and cross-fades between states whenever a transition's conditions are all met.
+#[derive(Clone, PartialEq, Debug)]
+pub struct AnimationGraph {
+    default_state: String,
+    states: Vec<AnimationGraphState>,
+    // checked in order, the first transition that applies wins
+    transitions: Vec<AnimationTransition>,
+    // state machines of their own, laid over this one's pose in order
+    layers: Vec<AnimationGraphLayer>,
+}
+
+impl AnimationGraph {
+    // distance moved over the last update, in world units per second
+    pub const SPEED: &'static str = "speed";
+    // 1.0 once the unit has been moving for a few updates, 0.0 once it has settled
+    pub const MOVING: &'static str = "moving";
+    // facing, in radians
+    pub const DIRECTION: &'static str = "direction";
+    // signed change in facing over the last update, in radians
+    pub const TURN: &'static str = "turn";
+    // how many times the current state's clip has played through, e.g. 1.0 once it has finished
+    pub const PROGRESS: &'static str = "progress";
+
+    pub fn new(default_state: &str) -> Self {
+        Self {
+            default_state: default_state.to_string(),
+            states: Vec::new(),
+            transitions: Vec::new(),
+            layers: Vec::new(),
+        }
+    }
+
+    // the graph every animated model used before graphs were data-driven
+    pub fn idle_walk() -> Self {
+        let mut graph = Self::new("idle");
+        graph.add_state(AnimationGraphState::new(
+            "idle",
+            "idle",
+            AnimationPlayback::Time(0.075),
+        ));
+        graph.add_state(AnimationGraphState::new(
+            "walk",
+            "walk",
+            AnimationPlayback::Distance(1.5),
+        ));
+
+        let mut start_walking = AnimationTransition::new(Some("idle"), "walk", 150.0);
+        start_walking.add_condition(Self::MOVING, AnimationComparison::GreaterThan, 0.5);
+        graph.add_transition(start_walking);
+
+        let mut stop_walking = AnimationTransition::new(Some("walk"), "idle", 150.0);
+        stop_walking.add_condition(Self::MOVING, AnimationComparison::LessThan, 0.5);
+        graph.add_transition(stop_walking);
+
+        graph
+    }
+
+    pub fn get_default_state(&self) -> &str {
+        &self.default_state
+    }
+
+    pub fn get_states(&self) -> &Vec<AnimationGraphState> {
+        &self.states
+    }
+
+    pub fn get_state(&self, name: &str) -> Option<&AnimationGraphState> {
+        self.states.iter().find(|state| state.name == name)
+    }
+
+    pub fn add_state(&mut self, state: AnimationGraphState) {
+        self.states.push(state);
+    }
+
+    pub fn get_transitions(&self) -> &Vec<AnimationTransition> {
+        &self.transitions
+    }
+
+    pub fn add_transition(&mut self, transition: AnimationTransition) {
+        self.transitions.push(transition);
+    }
+
+    pub fn get_layers(&self) -> &Vec<AnimationGraphLayer> {
+        &self.layers
+    }
+
+    pub fn add_layer(&mut self, layer: AnimationGraphLayer) {
+        self.layers.push(layer);
+    }
+
+    // the first transition out of `state_name` whose conditions all hold
+    pub fn next_transition(
+        &self,
+        state_name: &str,
+        parameters: &HashMap<String, f32>,
+    ) -> Option<&AnimationTransition> {
+        self.transitions.iter().find(|transition| {
+            transition.applies_from(state_name) && transition.conditions_met(parameters)
+        })
+    }
+
+    pub fn validate(&self, animations: &HashMap<String, AssetId>) -> Result<(), String> {
+        if self.get_state(&self.default_state).is_none() {
+            return Err(format!(
+                "default state `{}` is not a state of the graph",
+                self.default_state
+            ));
+        }
+        for state in &self.states {
+            if let Some(animation) = &state.animation {
+                if !animations.contains_key(animation) {
+                    return Err(format!(
+                        "state `{}` plays unknown animation `{}`",
+                        state.name, animation
+                    ));
+                }
+            }
+        }
+        for transition in &self.transitions {
+            if let Some(from) = &transition.from {
+                if self.get_state(from).is_none() {
+                    return Err(format!("transition from unknown state `{}`", from));
+                }
+            }
+            if self.get_state(&transition.to).is_none() {
+                return Err(format!("transition to unknown state `{}`", transition.to));
+            }
+            if transition.fade_ms < 0.0 {
+                return Err(format!(
+                    "transition to `{}` has a negative fade",
+                    transition.to
+                ));
+            }
+        }
+        for layer in &self.layers {
+            if !layer.graph.layers.is_empty() {
+                return Err(format!("layer `{}` has layers of its own", layer.name));
+            }
+            layer
+                .graph
+                .validate(animations)
+                .map_err(|error| format!("layer `{}`: {}", layer.name, error))?;
+        }
+        Ok(())
+    }
+}
+
+// A state machine laid over part (or all) of the pose beneath it, e.g. an upper-body emote
+// playing while the base graph keeps walking
+#[derive(Clone, PartialEq, Debug)]
+pub struct AnimationGraphLayer {
+    name: String,
+    // bones the layer drives, every bone when empty
+    bone_mask: Vec<String>,
+    mode: AnimationLayerMode,
+    graph: AnimationGraph,
+}
+
+impl AnimationGraphLayer {
+    pub fn new(name: &str, mode: AnimationLayerMode, graph: AnimationGraph) -> Self {
+        Self {
+            name: name.to_string(),
+            bone_mask: Vec::new(),
+            mode,
+            graph,
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_bone_mask(&self) -> &Vec<String> {
+        &self.bone_mask
+    }
+
+    pub fn add_bone(&mut self, bone_name: &str) {
+        self.bone_mask.push(bone_name.to_string());
+    }
+
+    pub fn get_mode(&self) -> AnimationLayerMode {
+        self.mode
+    }
+
+    pub fn get_graph(&self) -> &AnimationGraph {
+        &self.graph
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AnimationLayerMode {
+    // replaces the pose beneath on the masked bones
+    Override,
+    // rotates the pose beneath by the layer's offsets from rest
+    Additive,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct AnimationGraphState {
+    name: String,
+    // `None` plays nothing, so a layer in this state leaves the pose beneath untouched
+    animation: Option<String>,
+    playback: AnimationPlayback,
+}
+
+impl AnimationGraphState {
+    pub fn new(name: &str, animation: &str, playback: AnimationPlayback) -> Self {
+        Self {
+            name: name.to_string(),
+            animation: Some(animation.to_string()),
+            playback,
+        }
+    }
+
+    pub fn empty(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            animation: None,
+            playback: AnimationPlayback::Time(1.0),
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_animation(&self) -> Option<&str> {
+        self.animation.as_deref()
+    }
+
+    pub fn get_playback(&self) -> AnimationPlayback {
+        self.playback
+    }
+}
+
+// How fast a state's clip advances
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum AnimationPlayback {
+    // clip milliseconds per elapsed millisecond
+    Time(f32),
+    // clip milliseconds per world unit moved, so feet keep pace with the ground
+    Distance(f32),
+}
+
+impl AnimationPlayback {
+    pub fn advance_ms(&self, delta_ms: f32, distance: f32) -> f32 {
+        match self {
+            Self::Time(rate) => delta_ms * rate,
+            Self::Distance(rate) => distance * rate,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct AnimationTransition {
+    // `None` means the transition applies from any other state
+    from: Option<String>,
+    to: String,
+    conditions: Vec<AnimationCondition>,
+    fade_ms: f32,
+}
+
+impl AnimationTransition {
+    pub fn new(from: Option<&str>, to: &str, fade_ms: f32) -> Self {
+        Self {
+            from: from.map(str::to_string),
+            to: to.to_string(),
+            conditions: Vec::new(),
+            fade_ms,
+        }
+    }
+
+    pub fn get_from(&self) -> Option<&str> {
+        self.from.as_deref()
+    }
+
+    pub fn get_to(&self) -> &str {
+        &self.to
+    }
+
+    pub fn get_conditions(&self) -> &Vec<AnimationCondition> {
+        &self.conditions
+    }
+
+    pub fn add_condition(&mut self, parameter: &str, comparison: AnimationComparison, value: f32) {
+        self.conditions
+            .push(AnimationCondition::new(parameter, comparison, value));
+    }
+
+    pub fn get_fade_ms(&self) -> f32 {
+        self.fade_ms
+    }
+
+    fn applies_from(&self, state_name: &str) -> bool {
+        match &self.from {
+            Some(from) => from == state_name,
+            None => self.to != state_name,
+        }
+    }
+
+    // parameters that were never set read as 0.0
+    fn conditions_met(&self, parameters: &HashMap<String, f32>) -> bool {
+        self.conditions.iter().all(|condition| {
+            let value = parameters.get(&condition.parameter).copied().unwrap_or(0.0);
+            condition.is_met(value)
+        })
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct AnimationCondition {
+    parameter: String,
+    comparison: AnimationComparison,
+    value: f32,
+}
+
+impl AnimationCondition {
+    pub fn new(parameter: &str, comparison: AnimationComparison, value: f32) -> Self {
+        Self {
+            parameter: parameter.to_string(),
+            comparison,
+            value,
+        }
+    }
+
+    pub fn get_parameter(&self) -> &str {
+        &self.parameter
+    }
+
+    pub fn get_comparison(&self) -> AnimationComparison {
+        self.comparison
+    }
+
+    pub fn get_value(&self) -> f32 {
+        self.value
+    }
+
+    pub fn is_met(&self, parameter_value: f32) -> bool {
+        match self.comparison {
+            AnimationComparison::GreaterThan => parameter_value > self.value,
+            AnimationComparison::LessThan => parameter_value < self.value,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AnimationComparison {
+    GreaterThan,
+    LessThan,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn parameters(values: &[(&str, f32)]) -> HashMap<String, f32> {
+        values
+            .iter()
+            .map(|(name, value)| (name.to_string(), *value))
+            .collect()
+    }
+
+    fn next_state<'a>(
+        graph: &'a AnimationGraph,
+        state_name: &str,
+        values: &[(&str, f32)],
+    ) -> Option<&'a str> {
+        graph
+            .next_transition(state_name, &parameters(values))
+            .map(AnimationTransition::get_to)
+    }
+
+    #[test]
+    fn idle_walk_follows_moving() {
+        let graph = AnimationGraph::idle_walk();
+        let moving = AnimationGraph::MOVING;
+
+        assert_eq!(next_state(&graph, "idle", &[(moving, 1.0)]), Some("walk"));
+        assert_eq!(next_state(&graph, "idle", &[(moving, 0.0)]), None);
+        // unset parameters read as 0.0
+        assert_eq!(next_state(&graph, "idle", &[]), None);
+        assert_eq!(next_state(&graph, "walk", &[]), Some("idle"));
+        assert_eq!(next_state(&graph, "walk", &[(moving, 1.0)]), None);
+    }
+
+    #[test]
+    fn first_applicable_transition_wins() {
+        let mut graph = AnimationGraph::idle_walk();
+        let mut start_running = AnimationTransition::new(Some("idle"), "run", 100.0);
+        start_running.add_condition(AnimationGraph::SPEED, AnimationComparison::GreaterThan, 3.0);
+        graph.add_transition(start_running);
+
+        // walk is listed first, and its condition holds too
+        let running = [(AnimationGraph::MOVING, 1.0), (AnimationGraph::SPEED, 4.0)];
+        assert_eq!(next_state(&graph, "idle", &running), Some("walk"));
+        assert_eq!(
+            next_state(&graph, "idle", &[(AnimationGraph::SPEED, 4.0)]),
+            Some("run")
+        );
+    }
+
+    #[test]
+    fn transitions_from_any_state_skip_their_target() {
+        let mut graph = AnimationGraph::new("idle");
+        let mut wave = AnimationTransition::new(None, "wave", 50.0);
+        wave.add_condition("emote", AnimationComparison::GreaterThan, 0.5);
+        graph.add_transition(wave);
+
+        let emoting = [("emote", 1.0)];
+        assert_eq!(next_state(&graph, "idle", &emoting), Some("wave"));
+        assert_eq!(next_state(&graph, "walk", &emoting), Some("wave"));
+        assert_eq!(next_state(&graph, "wave", &emoting), None);
+    }
+
+    #[test]
+    fn validate_catches_unknown_states_and_clips() {
+        let mut animations = HashMap::new();
+        animations.insert("idle".to_string(), AssetId::from_str("8273wa").unwrap());
+        animations.insert("walk".to_string(), AssetId::from_str("34mvvk").unwrap());
+        assert!(AnimationGraph::idle_walk().validate(&animations).is_ok());
+
+        animations.remove("walk");
+        assert!(AnimationGraph::idle_walk().validate(&animations).is_err());
+
+        animations.insert("walk".to_string(), AssetId::from_str("34mvvk").unwrap());
+        let mut graph = AnimationGraph::idle_walk();
+        graph.add_transition(AnimationTransition::new(Some("idle"), "run", 100.0));
+        assert!(graph.validate(&animations).is_err());
+    }
+}
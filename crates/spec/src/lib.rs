@@ -1,8 +1,14 @@
 mod animated_model;
 pub use animated_model::*;
 
+mod animation_graph;
+pub use animation_graph::*;
+
 mod movement_config;
 pub use movement_config::*;
 
+mod particle_effect;
+pub use particle_effect::*;
+
 mod unit;
 pub use unit::*;
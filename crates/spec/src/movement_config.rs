@@ -1,13 +1,60 @@
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub struct MovementConfig {
     max_velocity: f32,
+    min_velocity: f32,
+    acceleration: f32,
+    friction: f32,
+    arrival_distance: f32,
+    steering_deadzone: f32,
 }
 
 impl MovementConfig {
-    pub fn new(max_velocity: f32) -> Self {
-        Self { max_velocity }
+    // the values movement used before it was configurable per unit
+    pub const DEFAULT_MIN_VELOCITY: f32 = 3.0;
+    pub const DEFAULT_ACCELERATION: f32 = 1.0;
+    pub const DEFAULT_FRICTION: f32 = 1.0;
+    pub const DEFAULT_ARRIVAL_DISTANCE: f32 = 5.0;
+    pub const DEFAULT_STEERING_DEADZONE: f32 = 4.0;
+
+    pub fn new(
+        max_velocity: f32,
+        min_velocity: f32,
+        acceleration: f32,
+        friction: f32,
+        arrival_distance: f32,
+        steering_deadzone: f32,
+    ) -> Self {
+        Self {
+            max_velocity,
+            min_velocity,
+            acceleration,
+            friction,
+            arrival_distance,
+            steering_deadzone,
+        }
     }
 
     pub fn max_velocity(&self) -> f32 {
         self.max_velocity
     }
+
+    pub fn min_velocity(&self) -> f32 {
+        self.min_velocity
+    }
+
+    pub fn acceleration(&self) -> f32 {
+        self.acceleration
+    }
+
+    pub fn friction(&self) -> f32 {
+        self.friction
+    }
+
+    pub fn arrival_distance(&self) -> f32 {
+        self.arrival_distance
+    }
+
+    pub fn steering_deadzone(&self) -> f32 {
+        self.steering_deadzone
+    }
 }
@@ -0,0 +1,113 @@
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ParticleEffect {
+    spawn_rate: f32, // per second
+    lifetime_ms: f32,
+    max_particles: u16,
+    direction: (f32, f32, f32),
+    spread_degrees: f32,
+    min_speed: f32,
+    max_speed: f32,
+    acceleration: (f32, f32, f32),
+    start_color: (u8, u8, u8),
+    end_color: (u8, u8, u8),
+    start_size: f32,
+    end_size: f32,
+}
+
+impl ParticleEffect {
+    pub fn new(spawn_rate: f32, lifetime_ms: f32, max_particles: u16) -> Self {
+        Self {
+            spawn_rate,
+            lifetime_ms,
+            max_particles,
+            direction: (0.0, 1.0, 0.0),
+            spread_degrees: 0.0,
+            min_speed: 0.0,
+            max_speed: 0.0,
+            acceleration: (0.0, 0.0, 0.0),
+            start_color: (255, 255, 255),
+            end_color: (255, 255, 255),
+            start_size: 1.0,
+            end_size: 1.0,
+        }
+    }
+
+    pub fn with_velocity(
+        mut self,
+        direction: (f32, f32, f32),
+        spread_degrees: f32,
+        min_speed: f32,
+        max_speed: f32,
+    ) -> Self {
+        self.direction = direction;
+        self.spread_degrees = spread_degrees;
+        self.min_speed = min_speed;
+        self.max_speed = max_speed;
+        self
+    }
+
+    pub fn with_acceleration(mut self, acceleration: (f32, f32, f32)) -> Self {
+        self.acceleration = acceleration;
+        self
+    }
+
+    pub fn with_color(mut self, start_color: (u8, u8, u8), end_color: (u8, u8, u8)) -> Self {
+        self.start_color = start_color;
+        self.end_color = end_color;
+        self
+    }
+
+    pub fn with_size(mut self, start_size: f32, end_size: f32) -> Self {
+        self.start_size = start_size;
+        self.end_size = end_size;
+        self
+    }
+
+    pub fn spawn_rate(&self) -> f32 {
+        self.spawn_rate
+    }
+
+    pub fn lifetime_ms(&self) -> f32 {
+        self.lifetime_ms
+    }
+
+    pub fn max_particles(&self) -> u16 {
+        self.max_particles
+    }
+
+    pub fn direction(&self) -> (f32, f32, f32) {
+        self.direction
+    }
+
+    pub fn spread_degrees(&self) -> f32 {
+        self.spread_degrees
+    }
+
+    pub fn min_speed(&self) -> f32 {
+        self.min_speed
+    }
+
+    pub fn max_speed(&self) -> f32 {
+        self.max_speed
+    }
+
+    pub fn acceleration(&self) -> (f32, f32, f32) {
+        self.acceleration
+    }
+
+    pub fn start_color(&self) -> (u8, u8, u8) {
+        self.start_color
+    }
+
+    pub fn end_color(&self) -> (u8, u8, u8) {
+        self.end_color
+    }
+
+    pub fn start_size(&self) -> f32 {
+        self.start_size
+    }
+
+    pub fn end_size(&self) -> f32 {
+        self.end_size
+    }
+}
@@ -3,6 +3,7 @@ use asset_id::AssetId;
 pub struct Unit {
     animated_model_asset_id: AssetId,
     movement_config_asset_id: AssetId,
+    particle_effect_asset_id: Option<AssetId>,
 }
 
 impl Unit {
@@ -10,9 +11,15 @@ impl Unit {
         Self {
             animated_model_asset_id,
             movement_config_asset_id,
+            particle_effect_asset_id: None,
         }
     }
 
+    pub fn with_particle_effect(mut self, particle_effect_asset_id: AssetId) -> Self {
+        self.particle_effect_asset_id = Some(particle_effect_asset_id);
+        self
+    }
+
     pub fn get_animated_model_asset_id(&self) -> AssetId {
         self.animated_model_asset_id
     }
@@ -20,4 +27,8 @@ impl Unit {
     pub fn get_movement_config_asset_id(&self) -> AssetId {
         self.movement_config_asset_id
     }
+
+    pub fn get_particle_effect_asset_id(&self) -> Option<AssetId> {
+        self.particle_effect_asset_id
+    }
 }
@@ -20,7 +20,7 @@ pub use ui_config::UiConfig;
 // just for ui_io
 pub use button::{Button, ButtonStyle, Navigation};
 pub use node::UiNode;
-pub use panel::{Panel, PanelStyle};
+pub use panel::{ImageFit, Panel, PanelStyle};
 pub use spinner::{Spinner, SpinnerStyle};
 pub use style::{BaseNodeStyle, NodeStyle, StyleId, WidgetStyle};
 pub use text::{Text, TextStyle};
@@ -22,12 +22,61 @@ impl Panel {
     }
 }
 
+// how a texture set on a panel (see `UiManager::set_panel_texture`) is fit into the panel's bounds
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ImageFit {
+    // fills the panel, ignoring the texture's aspect ratio
+    Stretch,
+    // the whole texture is shown, centered, leaving the panel's background on two sides
+    Contain,
+    // fills the panel, cropping whatever of the texture overflows it
+    Cover,
+}
+
+impl ImageFit {
+    // given the panel's size & the texture's aspect ratio (width / height),
+    // returns the size of the quad to draw, centered in the panel, and the size of the
+    // centered region of the texture to show on it, in uv units
+    pub fn fit(
+        &self,
+        panel_width: f32,
+        panel_height: f32,
+        aspect_ratio: f32,
+    ) -> ((f32, f32), (f32, f32)) {
+        let panel_aspect_ratio = panel_width / panel_height;
+        match self {
+            Self::Stretch => ((panel_width, panel_height), (1.0, 1.0)),
+            Self::Contain => {
+                if aspect_ratio > panel_aspect_ratio {
+                    ((panel_width, panel_width / aspect_ratio), (1.0, 1.0))
+                } else {
+                    ((panel_height * aspect_ratio, panel_height), (1.0, 1.0))
+                }
+            }
+            Self::Cover => {
+                if aspect_ratio > panel_aspect_ratio {
+                    (
+                        (panel_width, panel_height),
+                        (panel_aspect_ratio / aspect_ratio, 1.0),
+                    )
+                } else {
+                    (
+                        (panel_width, panel_height),
+                        (1.0, aspect_ratio / panel_aspect_ratio),
+                    )
+                }
+            }
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct PanelStyle {
     pub is_viewport: bool,
 
     pub background_color: Option<Color>,
     pub background_alpha: Option<f32>, // keep it private, need to validate
+    pub background_fit: Option<ImageFit>,
 
     pub layout_type: Option<LayoutType>,
 
@@ -51,6 +100,7 @@ impl PanelStyle {
 
         self.background_color = other.background_color.or(self.background_color);
         self.background_alpha = other.background_alpha.or(self.background_alpha);
+        self.background_fit = other.background_fit.or(self.background_fit);
         self.layout_type = other.layout_type.or(self.layout_type);
         self.padding_left = other.padding_left.or(self.padding_left);
         self.padding_right = other.padding_right.or(self.padding_right);
@@ -70,6 +120,7 @@ impl PanelStyle {
 
             background_color: None,
             background_alpha: None,
+            background_fit: None,
 
             layout_type: None,
 
@@ -19,5 +19,5 @@ pub use ui_container::*;
 mod ui_config;
 pub use ui_config::*;
 
-pub use ui_builder_config::UiConfig;
+pub use ui_builder_config::{ImageFit, UiConfig};
 pub use ui_layout::Alignment;
@@ -1,7 +1,7 @@
 use render_api::base::Color;
 use ui_builder_config::{
-    Button, NodeId, NodeStyle, Panel, PanelStyle, Spinner, StyleId, Text, Textbox, UiConfig,
-    UiContainer, UiNode, Widget, WidgetStyle,
+    Button, ImageFit, NodeId, NodeStyle, Panel, PanelStyle, Spinner, StyleId, Text, Textbox,
+    UiConfig, UiContainer, UiNode, Widget, WidgetStyle,
 };
 use ui_layout::{Alignment, LayoutType, MarginUnits, PositionType, SizeUnits, Solid};
 
@@ -217,6 +217,11 @@ impl<'a> PanelStyleMut<'a> {
         self
     }
 
+    pub fn set_background_fit(&mut self, fit: ImageFit) -> &mut Self {
+        self.get_panel_style_mut().background_fit = Some(fit);
+        self
+    }
+
     pub fn set_horizontal(&mut self) -> &mut Self {
         self.get_panel_style_mut().layout_type = Some(LayoutType::Row);
         self
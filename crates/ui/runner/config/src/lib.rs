@@ -8,7 +8,7 @@ mod styles;
 
 // Re-export
 pub use ui_builder_config::{
-    BaseNodeStyle, Button, EmailValidation, PasswordValidation, StyleId, Text, Textbox,
+    BaseNodeStyle, Button, EmailValidation, ImageFit, PasswordValidation, StyleId, Text, Textbox,
     UiContainer, UiNode, UsernameValidation, ValidationType, Widget, WidgetKind,
 };
 pub use ui_layout::{Alignment, LayoutCache, NodeId, NodeStore, TextMeasurer, UiVisibilityStore};
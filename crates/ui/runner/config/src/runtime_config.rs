@@ -6,8 +6,8 @@ use std::{
 use asset_id::AssetId;
 use render_api::base::Color;
 use ui_builder_config::{
-    BaseNodeStyle, Button, ButtonStyle, Navigation, Panel, PanelStyle, SpinnerStyle, StyleId,
-    TextStyle, Textbox, TextboxStyle, UiConfig, UiNode, WidgetKind, WidgetStyle,
+    BaseNodeStyle, Button, ButtonStyle, ImageFit, Navigation, Panel, PanelStyle, SpinnerStyle,
+    StyleId, TextStyle, Textbox, TextboxStyle, UiConfig, UiNode, WidgetKind, WidgetStyle,
 };
 use ui_layout::{
     Alignment, LayoutType, MarginUnits, NodeId, NodeStore, PositionType, SizeUnits, Solid,
@@ -347,6 +347,13 @@ impl UiRuntimeConfig {
         }
     }
 
+    // how a texture set on the panel is fit into it, stretched if unset
+    pub fn node_background_fit(&self, id: &NodeId) -> ImageFit {
+        self.panel_style(id)
+            .and_then(|panel_style| panel_style.background_fit)
+            .unwrap_or(ImageFit::Stretch)
+    }
+
     // navigation
     pub fn nav_get_up_id(&self, id: &NodeId) -> Option<NodeId> {
         let nav = self.get_node_nav(id)?;
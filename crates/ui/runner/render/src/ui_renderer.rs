@@ -319,6 +319,39 @@ fn draw_ui_panel(
             return;
         };
     }
+
+    // draw texture
+    let Some(panel_state) = ui_state.panel_ref(id) else {
+        return;
+    };
+    let (Some(mat_handle), Some(aspect_ratio)) = (
+        panel_state.background_material(),
+        panel_state.background_aspect_ratio(),
+    ) else {
+        return;
+    };
+    let ((quad_width, quad_height), uv_size) =
+        ui_config
+            .node_background_fit(id)
+            .fit(transform.scale.x, transform.scale.y, aspect_ratio);
+    let mesh_handle = match panel_state.background_mesh() {
+        Some(mesh_handle) if uv_size != (1.0, 1.0) => mesh_handle,
+        _ => *ui_manager.get_box_mesh_handle().unwrap(),
+    };
+
+    // centered in the panel, in front of its background
+    let mut texture_transform = *transform;
+    texture_transform.translation.x += (transform.scale.x - quad_width) * 0.5;
+    texture_transform.translation.y += (transform.scale.y - quad_height) * 0.5;
+    texture_transform.translation.z += UiRuntimeConfig::Z_STEP_RENDER * 0.5;
+    texture_transform.scale.x = quad_width;
+    texture_transform.scale.y = quad_height;
+    render_frame.draw_mesh(
+        Some(&RenderLayer::UI),
+        &mesh_handle,
+        &mat_handle,
+        &texture_transform,
+    );
 }
 
 fn draw_ui_text(
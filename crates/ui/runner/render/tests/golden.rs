@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+
+use bevy_app::{App, Update};
+use bevy_ecs::world::Mut;
+
+use asset_id::{AssetId, AssetType};
+use asset_loader::AssetManager;
+use input::{Input, InputEvent};
+use render_api::{
+    base::CpuTexture2D,
+    components::{Camera, Viewport},
+    RenderApiPlugin,
+};
+use render_software::{golden_image::assert_golden_image, HeadlessScreen, RenderSoftwarePlugin};
+use ui_render::UiRenderPlugin;
+use ui_runner::{UiHandle, UiManager, UiPlugin};
+
+const WIDTH: u32 = 400;
+const HEIGHT: u32 = 300;
+
+// the launcher's uis, along with the palette & icons they're drawn with
+const EMBEDDED_DIR: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../../../apps/launcher/src/embedded"
+);
+const PALETTE_ID: &str = "8273wa";
+const FONT_ICON_ID: &str = "34mvvk";
+const EYE_ICON_ID: &str = "qbgz5j";
+const START_UI_ID: &str = "tpp7za";
+const LOGIN_UI_ID: &str = "3f5gej";
+
+fn asset_id(asset_id_str: &str) -> AssetId {
+    AssetId::from_str(asset_id_str).unwrap()
+}
+
+fn embedded_assets() -> HashMap<AssetId, Vec<u8>> {
+    let mut asset_data_store = HashMap::new();
+    for entry in std::fs::read_dir(EMBEDDED_DIR).unwrap() {
+        let path = entry.unwrap().path();
+        if path.extension().is_some() {
+            // .meta files
+            continue;
+        }
+        let asset_id_str = path.file_name().unwrap().to_str().unwrap();
+        asset_data_store.insert(asset_id(asset_id_str), std::fs::read(&path).unwrap());
+    }
+    asset_data_store
+}
+
+fn render_ui(ui_id_str: &str) -> CpuTexture2D {
+    let mut app = App::new();
+    app.add_plugins(RenderApiPlugin)
+        .add_plugins(RenderSoftwarePlugin::new(WIDTH, HEIGHT))
+        .insert_resource(Input::new())
+        .add_event::<InputEvent>()
+        .init_resource::<AssetManager>()
+        .add_systems(Update, AssetManager::sync)
+        .add_plugins(UiPlugin)
+        .add_plugins(UiRenderPlugin);
+
+    // the ui lays itself out in the viewport of the camera on its target render layer
+    app.world_mut().spawn(Camera {
+        viewport: Some(Viewport::new_at_origin(WIDTH, HEIGHT)),
+        ..Default::default()
+    });
+
+    let asset_data_store = embedded_assets();
+    app.world_mut()
+        .resource_scope(|world, mut asset_manager: Mut<AssetManager>| {
+            asset_manager.load(
+                &asset_data_store,
+                &asset_id(PALETTE_ID),
+                &AssetType::Palette,
+            );
+            asset_manager.load(&asset_data_store, &asset_id(FONT_ICON_ID), &AssetType::Icon);
+            asset_manager.load(&asset_data_store, &asset_id(EYE_ICON_ID), &AssetType::Icon);
+
+            let mut ui_manager = world.resource_mut::<UiManager>();
+            ui_manager.load(&mut asset_manager, &asset_data_store, &asset_id(ui_id_str));
+            ui_manager.set_text_icon_handle(asset_id(FONT_ICON_ID));
+            ui_manager.set_eye_icon_handle(asset_id(EYE_ICON_ID));
+            ui_manager.enable_ui(&UiHandle::new(asset_id(ui_id_str)));
+        });
+
+    // assets are synced, then icon skins wait a frame for their palette's materials
+    for _ in 0..4 {
+        app.update();
+    }
+
+    app.world().resource::<HeadlessScreen>().capture()
+}
+
+#[test]
+fn start() {
+    assert_golden_image(&render_ui(START_UI_ID), "tests/golden/start.png");
+}
+
+#[test]
+fn login() {
+    assert_golden_image(&render_ui(LOGIN_UI_ID), "tests/golden/login.png");
+}
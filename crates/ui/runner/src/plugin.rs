@@ -1,4 +1,5 @@
 use bevy_app::{App, Plugin, PostUpdate, PreUpdate, Startup, Update};
+use bevy_ecs::schedule::IntoSystemConfigs;
 
 use clipboard::ClipboardPlugin;
 
@@ -22,6 +23,10 @@ impl Plugin for UiPlugin {
             .add_systems(Update, systems::ui_update)
             .add_systems(PostUpdate, UiManager::process_cursor_change)
             .add_systems(Update, UiManager::sync_assets)
+            .add_systems(
+                Update,
+                UiManager::sync_panel_textures.after(systems::ui_update),
+            )
             .add_systems(Update, UiManager::process_ui_global_events)
             .add_systems(Update, UiManager::process_ui_node_events)
             .add_systems(Update, UiManager::update_blinkiness);
@@ -6,10 +6,10 @@ use logging::warn;
 // use logging::info;
 use math::Vec3;
 use render_api::{
-    base::CpuMaterial,
+    base::{CpuMaterial, CpuMesh, CpuTexture2D},
     components::{CameraBundle, ClearOperation, Projection, Transform, Viewport},
 };
-use storage::Storage;
+use storage::{Handle, Storage};
 use ui_runner_config::{BaseNodeStyle, NodeId, SerdeErr, StyleId, UiRuntimeConfig};
 use ui_state::UiState;
 
@@ -225,6 +225,16 @@ impl UiRuntime {
             .load_cpu_data(&ui_handle.asset_id(), &self.config, materials);
     }
 
+    pub(crate) fn sync_panel_textures(
+        &mut self,
+        textures: &Storage<CpuTexture2D>,
+        materials: &mut Storage<CpuMaterial>,
+        meshes: &mut Storage<CpuMesh>,
+    ) -> Vec<(Handle<CpuTexture2D>, u32, u32)> {
+        self.state
+            .sync_panel_textures(&self.config, textures, materials, meshes)
+    }
+
     pub(crate) fn recalculate_layout(
         &mut self,
         text_measurer: &UiTextMeasurer,
@@ -392,6 +402,34 @@ impl UiRuntime {
         self.state.clear_ui_container(&node_id);
     }
 
+    pub fn set_panel_texture(&mut self, id_str: &str, texture_handle: &Handle<CpuTexture2D>) {
+        // get node_id from id_str
+        let Some(node_id) = self.get_node_id_by_id_str(id_str) else {
+            warn!(
+                "set_panel_texture: node_id not found for id_str: {}",
+                id_str
+            );
+            return;
+        };
+
+        // set texture
+        self.state.set_panel_texture(&node_id, texture_handle);
+    }
+
+    pub fn clear_panel_texture(&mut self, id_str: &str) {
+        // get node_id from id_str
+        let Some(node_id) = self.get_node_id_by_id_str(id_str) else {
+            warn!(
+                "clear_panel_texture: node_id not found for id_str: {}",
+                id_str
+            );
+            return;
+        };
+
+        // clear texture
+        self.state.clear_panel_texture(&node_id);
+    }
+
     pub fn ui_state_ref(&self) -> &UiState {
         &self.state
     }
@@ -4,7 +4,7 @@ use bevy_ecs::{
     change_detection::{Mut, Res, ResMut},
     event::Event,
     prelude::World,
-    system::Resource,
+    system::{Query, Resource},
 };
 
 use asset_id::AssetId;
@@ -14,8 +14,8 @@ use input::{CursorIcon, Input};
 use logging::{info, warn};
 use math::Vec2;
 use render_api::{
-    base::{CpuMaterial, CpuMesh},
-    components::{Camera, RenderLayer},
+    base::{CpuMaterial, CpuMesh, CpuTexture2D},
+    components::{Camera, RenderLayer, RenderTarget, Viewport},
     resources::Time,
     shapes::UnitSquare,
 };
@@ -160,6 +160,51 @@ impl UiManager {
         ui_manager.sync_uis(&mut materials);
     }
 
+    // used as a system
+    // panel textures which cameras render into are resized to how large they are shown,
+    // along with those cameras' viewports
+    pub(crate) fn sync_panel_textures(
+        mut ui_manager: ResMut<Self>,
+        mut textures: ResMut<Storage<CpuTexture2D>>,
+        mut materials: ResMut<Storage<CpuMaterial>>,
+        mut meshes: ResMut<Storage<CpuMesh>>,
+        mut cameras_q: Query<&mut Camera>,
+    ) {
+        let mut texture_sizes = Vec::new();
+        for (_, ui_runtime) in ui_manager.ui_runtimes.iter_mut() {
+            texture_sizes.append(&mut ui_runtime.sync_panel_textures(
+                &textures,
+                &mut materials,
+                &mut meshes,
+            ));
+        }
+
+        for (texture_handle, width, height) in texture_sizes {
+            let viewport = Viewport::new_at_origin(width, height);
+            let mut is_render_target = false;
+            for mut camera in cameras_q.iter_mut() {
+                let RenderTarget::Image(target_handle) = &camera.target else {
+                    continue;
+                };
+                if *target_handle != texture_handle {
+                    continue;
+                }
+                is_render_target = true;
+                if camera.viewport != Some(viewport) {
+                    camera.viewport = Some(viewport);
+                }
+            }
+            if !is_render_target {
+                continue;
+            }
+
+            let texture = textures.get(&texture_handle).unwrap();
+            if texture.width() != width || texture.height() != height {
+                textures.set(&texture_handle, CpuTexture2D::from_size(width, height));
+            }
+        }
+    }
+
     // used as a system
     pub fn prepare_cursor_change(mut ui_manager: ResMut<Self>) {
         ui_manager.set_cursor_icon_change(None);
@@ -690,6 +735,27 @@ impl UiManager {
         }
     }
 
+    pub fn set_panel_texture(
+        &mut self,
+        ui_handle: &UiHandle,
+        id_str: &str,
+        texture_handle: &Handle<CpuTexture2D>,
+    ) {
+        if let Some(ui_runtime) = self.ui_runtimes.get_mut(ui_handle) {
+            ui_runtime.set_panel_texture(id_str, texture_handle);
+        } else {
+            warn!("ui data not loaded 9: {:?}", ui_handle.asset_id());
+        }
+    }
+
+    pub fn clear_panel_texture(&mut self, ui_handle: &UiHandle, id_str: &str) {
+        if let Some(ui_runtime) = self.ui_runtimes.get_mut(ui_handle) {
+            ui_runtime.clear_panel_texture(id_str);
+        } else {
+            warn!("ui data not loaded 10: {:?}", ui_handle.asset_id());
+        }
+    }
+
     pub fn get_box_mesh_handle(&self) -> Option<&Handle<CpuMesh>> {
         self.globals.get_box_mesh_handle()
     }
@@ -18,5 +18,6 @@ pub use ui_state::UiState;
 // just for ui_io
 pub use button::NodeActiveState;
 pub use node_state::UiNodeState;
+pub use panel::PanelState;
 pub use textbox::TextboxState;
 pub use ui_container::UiContainerState;
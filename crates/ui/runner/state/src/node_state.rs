@@ -1,7 +1,10 @@
 use ui_runner_config::UiNode;
 
 use crate::button::ButtonState;
-use crate::{text::TextState, textbox::TextboxState, widget::WidgetState, UiContainerState};
+use crate::{
+    panel::PanelState, text::TextState, textbox::TextboxState, widget::WidgetState,
+    UiContainerState,
+};
 
 #[derive(Clone)]
 pub struct UiNodeState {
@@ -19,6 +22,20 @@ impl UiNodeState {
         Self::new(widget_state)
     }
 
+    pub fn widget_panel_ref(&self) -> Option<&PanelState> {
+        match &self.widget {
+            WidgetState::Panel(panel) => Some(panel),
+            _ => None,
+        }
+    }
+
+    pub fn widget_panel_mut(&mut self) -> Option<&mut PanelState> {
+        match &mut self.widget {
+            WidgetState::Panel(panel) => Some(panel),
+            _ => None,
+        }
+    }
+
     pub fn widget_button_ref(&self) -> Option<&ButtonState> {
         match &self.widget {
             WidgetState::Button(button) => Some(button),
@@ -1,6 +1,99 @@
-use render_api::base::CpuMaterial;
+use math::{Vec2, Vec3};
+use render_api::base::{CpuMaterial, CpuMesh, CpuTexture2D};
 use storage::Handle;
 
+#[derive(Clone)]
+pub struct PanelState {
+    background_texture_opt: Option<Handle<CpuTexture2D>>,
+    // width / height of the texture when it was set, kept as render targets get resized
+    background_aspect_ratio_opt: Option<f32>,
+    background_material_opt: Option<Handle<CpuMaterial>>,
+    // only set while the panel shows part of its texture, with the size of that part in uv units
+    background_mesh_opt: Option<(Handle<CpuMesh>, (f32, f32))>,
+}
+
+impl Default for PanelState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PanelState {
+    pub fn new() -> Self {
+        Self {
+            background_texture_opt: None,
+            background_aspect_ratio_opt: None,
+            background_material_opt: None,
+            background_mesh_opt: None,
+        }
+    }
+
+    pub fn background_texture(&self) -> Option<Handle<CpuTexture2D>> {
+        self.background_texture_opt
+    }
+
+    pub fn background_aspect_ratio(&self) -> Option<f32> {
+        self.background_aspect_ratio_opt
+    }
+
+    pub fn background_material(&self) -> Option<Handle<CpuMaterial>> {
+        self.background_material_opt
+    }
+
+    pub fn background_mesh(&self) -> Option<Handle<CpuMesh>> {
+        self.background_mesh_opt.map(|(handle, _)| handle)
+    }
+
+    pub(crate) fn set_background_texture(&mut self, texture_handle: Handle<CpuTexture2D>) {
+        *self = Self::new();
+        self.background_texture_opt = Some(texture_handle);
+    }
+
+    pub(crate) fn clear_background_texture(&mut self) {
+        *self = Self::new();
+    }
+
+    pub(crate) fn set_background_aspect_ratio(&mut self, aspect_ratio: f32) {
+        self.background_aspect_ratio_opt = Some(aspect_ratio);
+    }
+
+    pub(crate) fn set_background_material(&mut self, handle: Handle<CpuMaterial>) {
+        self.background_material_opt = Some(handle);
+    }
+
+    pub(crate) fn background_mesh_uv_size(&self) -> Option<(f32, f32)> {
+        self.background_mesh_opt.map(|(_, uv_size)| uv_size)
+    }
+
+    pub(crate) fn set_background_mesh(&mut self, handle: Handle<CpuMesh>, uv_size: (f32, f32)) {
+        self.background_mesh_opt = Some((handle, uv_size));
+    }
+}
+
+// the same quad as `UnitSquare`, but only showing the centered `uv_size` part of the texture
+pub(crate) fn cropped_unit_square(uv_size: (f32, f32)) -> CpuMesh {
+    let positions = vec![
+        Vec3::new(0.0, 0.0, 0.0),
+        Vec3::new(1.0, 0.0, 0.0),
+        Vec3::new(1.0, 1.0, 0.0),
+        Vec3::new(0.0, 1.0, 0.0),
+    ];
+
+    let indices = vec![0, 2, 1, 2, 0, 3];
+
+    let uv_size = Vec2::new(uv_size.0, uv_size.1);
+    let uv_min = (Vec2::ONE - uv_size) * 0.5;
+    let uvs = indices
+        .iter()
+        .map(|index| {
+            let position = positions[*index];
+            uv_min + Vec2::new(position.x, 1.0 - position.y) * uv_size
+        })
+        .collect();
+
+    CpuMesh::from_indices(&positions, &indices).with_uvs(uvs)
+}
+
 #[derive(Clone)]
 pub struct PanelStyleState {
     background_color_handle: Option<Handle<CpuMaterial>>,
@@ -1,18 +1,25 @@
 use asset_id::AssetId;
 use logging::warn;
 use render_api::{
-    base::{Color, CpuMaterial},
+    base::{Color, CpuMaterial, CpuMesh, CpuTexture2D},
     components::Viewport,
 };
-use storage::Storage;
+use storage::{Handle, Storage};
 use ui_runner_config::{
     LayoutCache, NodeId, TextMeasurer, UiNode, UiRuntimeConfig, UiVisibilityStore, WidgetKind,
 };
 
 use crate::{
-    button::ButtonStyleState, panel::PanelStyleState, spinner::SpinnerStyleState,
-    state_store::UiStateStore, style_state::StyleState, text::TextStyleState,
-    textbox::TextboxState, textbox::TextboxStyleState, widget::WidgetState, UiNodeState,
+    button::ButtonStyleState,
+    panel::{cropped_unit_square, PanelState, PanelStyleState},
+    spinner::SpinnerStyleState,
+    state_store::UiStateStore,
+    style_state::StyleState,
+    text::TextStyleState,
+    textbox::TextboxState,
+    textbox::TextboxStyleState,
+    widget::WidgetState,
+    UiNodeState,
 };
 
 pub struct UiState {
@@ -205,6 +212,101 @@ impl UiState {
         ui_container.ui_handle_opt = None;
     }
 
+    pub fn panel_ref(&self, node_id: &NodeId) -> Option<&PanelState> {
+        self.store.get_node(node_id)?.widget_panel_ref()
+    }
+
+    pub fn set_panel_texture(&mut self, node_id: &NodeId, texture_handle: &Handle<CpuTexture2D>) {
+        let Some(node) = self.store.get_node_mut(node_id) else {
+            logging::warn!(
+                "set_panel_texture: node not found for node_id: {:?}",
+                node_id
+            );
+            return;
+        };
+        let Some(panel) = node.widget_panel_mut() else {
+            return;
+        };
+        panel.set_background_texture(*texture_handle);
+    }
+
+    pub fn clear_panel_texture(&mut self, node_id: &NodeId) {
+        let Some(node) = self.store.get_node_mut(node_id) else {
+            logging::warn!(
+                "clear_panel_texture: node not found for node_id: {:?}",
+                node_id
+            );
+            return;
+        };
+        let Some(panel) = node.widget_panel_mut() else {
+            return;
+        };
+        panel.clear_background_texture();
+    }
+
+    // creates what each textured panel needs to be drawn with its current layout,
+    // returning the size in pixels that each texture is shown at
+    pub fn sync_panel_textures(
+        &mut self,
+        ui_config: &UiRuntimeConfig,
+        textures: &Storage<CpuTexture2D>,
+        materials: &mut Storage<CpuMaterial>,
+        meshes: &mut Storage<CpuMesh>,
+    ) -> Vec<(Handle<CpuTexture2D>, u32, u32)> {
+        let mut output = Vec::new();
+
+        for (id, node) in self.store.nodes.iter_mut() {
+            let Some(panel) = node.widget_panel_mut() else {
+                continue;
+            };
+            let Some(texture_handle) = panel.background_texture() else {
+                continue;
+            };
+            let Some(texture) = textures.get(&texture_handle) else {
+                continue;
+            };
+
+            if panel.background_aspect_ratio().is_none() {
+                let aspect_ratio = texture.width() as f32 / texture.height().max(1) as f32;
+                panel.set_background_aspect_ratio(aspect_ratio);
+            }
+            if panel.background_material().is_none() {
+                let material = CpuMaterial::default().with_texture(texture_handle);
+                panel.set_background_material(materials.add(material));
+            }
+
+            let Some((width, height, _, _, _)) = self.cache.bounds(id) else {
+                continue;
+            };
+            if width < 1.0 || height < 1.0 {
+                continue;
+            }
+            let aspect_ratio = panel.background_aspect_ratio().unwrap();
+            let ((quad_width, quad_height), uv_size) =
+                ui_config
+                    .node_background_fit(id)
+                    .fit(width, height, aspect_ratio);
+
+            if uv_size != (1.0, 1.0) && panel.background_mesh_uv_size() != Some(uv_size) {
+                let mesh = cropped_unit_square(uv_size);
+                let mesh_handle = match panel.background_mesh() {
+                    Some(mesh_handle) => {
+                        meshes.set(&mesh_handle, mesh);
+                        mesh_handle
+                    }
+                    None => meshes.add_unique(mesh),
+                };
+                panel.set_background_mesh(mesh_handle, uv_size);
+            }
+
+            let texture_width = (quad_width / uv_size.0).round().max(1.0) as u32;
+            let texture_height = (quad_height / uv_size.1).round().max(1.0) as u32;
+            output.push((texture_handle, texture_width, texture_height));
+        }
+
+        output
+    }
+
     // styles
 
     fn node_style_state(&self, config: &UiRuntimeConfig, id: &NodeId) -> Option<&StyleState> {
@@ -1,9 +1,13 @@
 use ui_runner_config::Widget;
 
-use crate::{button::ButtonState, text::TextState, textbox::TextboxState, UiContainerState};
+use crate::{
+    button::ButtonState, panel::PanelState, text::TextState, textbox::TextboxState,
+    UiContainerState,
+};
 
 #[derive(Clone)]
 pub enum WidgetState {
+    Panel(PanelState),
     Button(ButtonState),
     Text(TextState),
     Textbox(TextboxState),
@@ -15,6 +19,7 @@ pub enum WidgetState {
 impl WidgetState {
     pub(crate) fn from_widget(widget: &Widget) -> Self {
         match widget {
+            Widget::Panel(_) => Self::Panel(PanelState::new()),
             Widget::Button(button) => Self::Button(ButtonState::new(button)),
             Widget::Textbox(textbox) => Self::Textbox(TextboxState::new(textbox)),
             Widget::Text(text) => Self::Text(TextState::new(text)),
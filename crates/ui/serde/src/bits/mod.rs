@@ -24,6 +24,10 @@ cfg_if! {
 
 use naia_serde::{SerdeInternal as Serde, UnsignedInteger, UnsignedVariableInteger};
 
+// the first byte of every ui file. ui files of any other version fail to read, so bump this
+// whenever the layout below changes, and re-export the ui assets
+pub const UI_FORMAT_VERSION: u8 = 1;
+
 use ui_builder_config::{NodeId, UiConfig, WidgetKind};
 
 // Actions
@@ -81,6 +85,7 @@ pub(crate) struct PanelStyleBits {
 
     background_color: Option<ColorBits>,
     background_alpha: Option<UnsignedInteger<4>>,
+    background_fit: Option<ImageFitBits>,
 
     layout_type: Option<LayoutTypeBits>,
 
@@ -176,6 +181,13 @@ pub(crate) enum LayoutTypeBits {
     Column,
 }
 
+#[derive(Serde, Clone, PartialEq)]
+pub(crate) enum ImageFitBits {
+    Stretch,
+    Contain,
+    Cover,
+}
+
 #[derive(Serde, Clone, PartialEq)]
 pub(crate) struct ColorBits {
     r: u8,
@@ -261,3 +273,45 @@ pub(crate) struct SpinnerBits {}
 
 #[derive(Serde, Clone, PartialEq, Debug)]
 pub(crate) struct UiContainerBits {}
+
+#[cfg(all(test, feature = "read_bits", feature = "write_bits"))]
+mod tests {
+    use ui_builder_config::{ImageFit, NodeStyle, PanelStyle, UiConfig, WidgetStyle};
+
+    use super::{read_ui_bits, write_ui_bits, UI_FORMAT_VERSION};
+
+    fn ui_config() -> UiConfig {
+        let mut panel_style = PanelStyle::empty();
+        panel_style.background_fit = Some(ImageFit::Cover);
+
+        let mut ui_config = UiConfig::new();
+        let style_id =
+            ui_config.insert_style(NodeStyle::empty(None, WidgetStyle::Panel(panel_style)));
+        ui_config
+            .node_mut(&UiConfig::ROOT_NODE_ID)
+            .unwrap()
+            .set_style_id(style_id);
+        ui_config
+    }
+
+    #[test]
+    fn roundtrip() {
+        let bytes = write_ui_bits(&ui_config());
+        let read = read_ui_bits(&bytes).unwrap();
+
+        let WidgetStyle::Panel(panel_style) = read.styles_iter().next().unwrap().base.widget_style
+        else {
+            panic!("expected a panel style");
+        };
+        assert_eq!(panel_style.background_fit, Some(ImageFit::Cover));
+        assert_eq!(write_ui_bits(&read), bytes);
+    }
+
+    #[test]
+    fn other_versions_are_rejected() {
+        let mut bytes = write_ui_bits(&ui_config());
+        bytes[0] = UI_FORMAT_VERSION + 1;
+
+        assert!(read_ui_bits(&bytes).is_err());
+    }
+}
@@ -6,17 +6,17 @@ use naia_serde::{
 
 use render_api::base::Color;
 use ui_builder_config::{
-    BaseNodeStyle, Button, ButtonStyle, NodeId, NodeStyle, Panel, PanelStyle, Spinner,
+    BaseNodeStyle, Button, ButtonStyle, ImageFit, NodeId, NodeStyle, Panel, PanelStyle, Spinner,
     SpinnerStyle, StyleId, Text, TextStyle, Textbox, TextboxStyle, UiConfig, UiContainer,
     ValidationType, Widget, WidgetKind, WidgetStyle,
 };
 use ui_layout::{Alignment, LayoutType, MarginUnits, PositionType, SizeUnits, Solid};
 
 use crate::bits::{
-    AlignmentBits, ButtonBits, ButtonStyleBits, ColorBits, LayoutTypeBits, MarginUnitsBits,
-    PanelBits, PanelStyleBits, PositionTypeBits, SizeUnitsBits, SolidBits, SpinnerStyleBits,
-    TextStyleBits, TextboxBits, TextboxStyleBits, UiAction, UiActionType, UiNodeBits, UiStyleBits,
-    ValidationBits, WidgetBits, WidgetStyleBits,
+    AlignmentBits, ButtonBits, ButtonStyleBits, ColorBits, ImageFitBits, LayoutTypeBits,
+    MarginUnitsBits, PanelBits, PanelStyleBits, PositionTypeBits, SizeUnitsBits, SolidBits,
+    SpinnerStyleBits, TextStyleBits, TextboxBits, TextboxStyleBits, UiAction, UiActionType,
+    UiNodeBits, UiStyleBits, ValidationBits, WidgetBits, WidgetStyleBits, UI_FORMAT_VERSION,
 };
 
 pub fn read_bits(data: &[u8]) -> Result<UiConfig, SerdeErr> {
@@ -82,6 +82,12 @@ fn convert_actions_to_ui_config(actions: Vec<UiAction>) -> UiConfig {
 fn bytes_to_actions(data: &[u8]) -> Result<Vec<UiAction>, SerdeErr> {
     let mut bit_reader = BitReader::new(data);
     let bit_reader = &mut bit_reader;
+
+    let version = u8::de(bit_reader)?;
+    if version != UI_FORMAT_VERSION {
+        return Err(SerdeErr);
+    }
+
     let mut actions = Vec::new();
 
     loop {
@@ -465,47 +471,47 @@ fn set_textbox_navigation(
     }
 }
 
-impl Into<PositionType> for PositionTypeBits {
-    fn into(self) -> PositionType {
-        match self {
-            Self::Absolute => PositionType::Absolute,
-            Self::Relative => PositionType::Relative,
+impl From<PositionTypeBits> for PositionType {
+    fn from(value: PositionTypeBits) -> Self {
+        match value {
+            PositionTypeBits::Absolute => PositionType::Absolute,
+            PositionTypeBits::Relative => PositionType::Relative,
         }
     }
 }
 
-impl Into<SizeUnits> for SizeUnitsBits {
-    fn into(self) -> SizeUnits {
-        match self {
-            Self::Pixels(val) => {
+impl From<SizeUnitsBits> for SizeUnits {
+    fn from(value: SizeUnitsBits) -> Self {
+        match value {
+            SizeUnitsBits::Pixels(val) => {
                 let val: u64 = val.to();
                 let val: f32 = val as f32;
                 SizeUnits::Pixels(val)
             }
-            Self::Percent(val) => {
+            SizeUnitsBits::Percent(val) => {
                 let val: u64 = val.to();
                 let val: f32 = val as f32;
                 SizeUnits::Percentage(val)
             }
-            Self::Viewport(val) => {
+            SizeUnitsBits::Viewport(val) => {
                 let val: u64 = val.to();
                 let val: f32 = (val as f32) / 10.0;
                 SizeUnits::Viewport(val)
             }
-            Self::Auto => SizeUnits::Auto,
+            SizeUnitsBits::Auto => SizeUnits::Auto,
         }
     }
 }
 
-impl Into<MarginUnits> for MarginUnitsBits {
-    fn into(self) -> MarginUnits {
-        match self {
-            Self::Percent(val) => {
+impl From<MarginUnitsBits> for MarginUnits {
+    fn from(value: MarginUnitsBits) -> Self {
+        match value {
+            MarginUnitsBits::Percent(val) => {
                 let val: u64 = val.to();
                 let val: f32 = val as f32;
                 MarginUnits::Percentage(val)
             }
-            Self::Viewport(val) => {
+            MarginUnitsBits::Viewport(val) => {
                 let val: u64 = val.to();
                 let val: f32 = (val as f32) / 10.0;
                 MarginUnits::Viewport(val)
@@ -514,80 +520,90 @@ impl Into<MarginUnits> for MarginUnitsBits {
     }
 }
 
-impl Into<Solid> for SolidBits {
-    fn into(self) -> Solid {
-        match self {
-            Self::Fit => Solid::Fit,
-            Self::Fill => Solid::Fill,
+impl From<SolidBits> for Solid {
+    fn from(value: SolidBits) -> Self {
+        match value {
+            SolidBits::Fit => Solid::Fit,
+            SolidBits::Fill => Solid::Fill,
+        }
+    }
+}
+
+impl From<AlignmentBits> for Alignment {
+    fn from(value: AlignmentBits) -> Self {
+        match value {
+            AlignmentBits::Start => Alignment::Start,
+            AlignmentBits::Center => Alignment::Center,
+            AlignmentBits::End => Alignment::End,
         }
     }
 }
 
-impl Into<Alignment> for AlignmentBits {
-    fn into(self) -> Alignment {
-        match self {
-            Self::Start => Alignment::Start,
-            Self::Center => Alignment::Center,
-            Self::End => Alignment::End,
+impl From<LayoutTypeBits> for LayoutType {
+    fn from(value: LayoutTypeBits) -> Self {
+        match value {
+            LayoutTypeBits::Row => LayoutType::Row,
+            LayoutTypeBits::Column => LayoutType::Column,
         }
     }
 }
 
-impl Into<LayoutType> for LayoutTypeBits {
-    fn into(self) -> LayoutType {
-        match self {
-            Self::Row => LayoutType::Row,
-            Self::Column => LayoutType::Column,
+impl From<ImageFitBits> for ImageFit {
+    fn from(value: ImageFitBits) -> Self {
+        match value {
+            ImageFitBits::Stretch => ImageFit::Stretch,
+            ImageFitBits::Contain => ImageFit::Contain,
+            ImageFitBits::Cover => ImageFit::Cover,
         }
     }
 }
 
-impl Into<ValidationType> for ValidationBits {
-    fn into(self) -> ValidationType {
-        match self {
-            Self::Alphanumeric => ValidationType::Username,
-            Self::Password => ValidationType::Password,
-            Self::Email => ValidationType::Email,
+impl From<ValidationBits> for ValidationType {
+    fn from(value: ValidationBits) -> Self {
+        match value {
+            ValidationBits::Alphanumeric => ValidationType::Username,
+            ValidationBits::Password => ValidationType::Password,
+            ValidationBits::Email => ValidationType::Email,
         }
     }
 }
 
-impl Into<Color> for ColorBits {
-    fn into(self) -> Color {
-        Color::new(self.r, self.g, self.b)
+impl From<ColorBits> for Color {
+    fn from(value: ColorBits) -> Self {
+        Color::new(value.r, value.g, value.b)
     }
 }
 
-impl Into<NodeStyle> for UiStyleBits {
-    fn into(self) -> NodeStyle {
+impl From<UiStyleBits> for NodeStyle {
+    fn from(value: UiStyleBits) -> Self {
         NodeStyle {
-            parent_style: self.parent_style.map(|val| StyleId::new(val as u32)),
-            id_str: self.id_str,
+            parent_style: value.parent_style.map(|val| StyleId::new(val as u32)),
+            id_str: value.id_str,
             base: BaseNodeStyle {
-                widget_style: self.widget_style.into(),
-                position_type: self.position_type.map(Into::into),
-                width: self.width.map(Into::into),
-                height: self.height.map(Into::into),
-                width_min: self.width_min.map(Into::into),
-                width_max: self.width_max.map(Into::into),
-                height_min: self.height_min.map(Into::into),
-                height_max: self.height_max.map(Into::into),
-                margin_left: self.margin_left.map(Into::into),
-                margin_right: self.margin_right.map(Into::into),
-                margin_top: self.margin_top.map(Into::into),
-                margin_bottom: self.margin_bottom.map(Into::into),
-                solid_override: self.solid_override.map(Into::into),
-                aspect_ratio: self.aspect_ratio.map(|(w, h)| (w as f32, h as f32)),
-                self_halign: self.self_halign.map(Into::into),
-                self_valign: self.self_valign.map(Into::into),
+                widget_style: value.widget_style.into(),
+                position_type: value.position_type.map(Into::into),
+                width: value.width.map(Into::into),
+                height: value.height.map(Into::into),
+                width_min: value.width_min.map(Into::into),
+                width_max: value.width_max.map(Into::into),
+                height_min: value.height_min.map(Into::into),
+                height_max: value.height_max.map(Into::into),
+                margin_left: value.margin_left.map(Into::into),
+                margin_right: value.margin_right.map(Into::into),
+                margin_top: value.margin_top.map(Into::into),
+                margin_bottom: value.margin_bottom.map(Into::into),
+                solid_override: value.solid_override.map(Into::into),
+                aspect_ratio: value.aspect_ratio.map(|(w, h)| (w as f32, h as f32)),
+                self_halign: value.self_halign.map(Into::into),
+                self_valign: value.self_valign.map(Into::into),
             },
         }
     }
 }
 
-impl Into<WidgetStyle> for WidgetStyleBits {
-    fn into(self) -> WidgetStyle {
-        match self {
+impl From<WidgetStyleBits> for WidgetStyle {
+    fn from(value: WidgetStyleBits) -> Self {
+        match value {
             WidgetStyleBits::Panel(panel_style) => WidgetStyle::Panel(panel_style.into()),
             WidgetStyleBits::Text(text_style) => WidgetStyle::Text(text_style.into()),
             WidgetStyleBits::Button(button_style) => WidgetStyle::Button(button_style.into()),
@@ -598,65 +614,66 @@ impl Into<WidgetStyle> for WidgetStyleBits {
     }
 }
 
-impl Into<PanelStyle> for PanelStyleBits {
-    fn into(self) -> PanelStyle {
+impl From<PanelStyleBits> for PanelStyle {
+    fn from(value: PanelStyleBits) -> Self {
         PanelStyle {
-            is_viewport: self.is_viewport,
-            background_color: self.background_color.map(Into::into),
-            background_alpha: self.background_alpha.map(bits_into_alpha),
-            layout_type: self.layout_type.map(Into::into),
-            padding_left: self.padding_left.map(Into::into),
-            padding_right: self.padding_right.map(Into::into),
-            padding_top: self.padding_top.map(Into::into),
-            padding_bottom: self.padding_bottom.map(Into::into),
-            row_between: self.row_between.map(Into::into),
-            col_between: self.col_between.map(Into::into),
-            children_halign: self.children_halign.map(Into::into),
-            children_valign: self.children_valign.map(Into::into),
+            is_viewport: value.is_viewport,
+            background_color: value.background_color.map(Into::into),
+            background_alpha: value.background_alpha.map(bits_into_alpha),
+            background_fit: value.background_fit.map(Into::into),
+            layout_type: value.layout_type.map(Into::into),
+            padding_left: value.padding_left.map(Into::into),
+            padding_right: value.padding_right.map(Into::into),
+            padding_top: value.padding_top.map(Into::into),
+            padding_bottom: value.padding_bottom.map(Into::into),
+            row_between: value.row_between.map(Into::into),
+            col_between: value.col_between.map(Into::into),
+            children_halign: value.children_halign.map(Into::into),
+            children_valign: value.children_valign.map(Into::into),
         }
     }
 }
 
-impl Into<TextStyle> for TextStyleBits {
-    fn into(self) -> TextStyle {
+impl From<TextStyleBits> for TextStyle {
+    fn from(value: TextStyleBits) -> Self {
         TextStyle {
-            background_color: self.background_color.map(Into::into),
-            background_alpha: self.background_alpha.map(bits_into_alpha),
-            text_color: self.text_color.map(Into::into),
+            background_color: value.background_color.map(Into::into),
+            background_alpha: value.background_alpha.map(bits_into_alpha),
+            text_color: value.text_color.map(Into::into),
         }
     }
 }
 
-impl Into<ButtonStyle> for ButtonStyleBits {
-    fn into(self) -> ButtonStyle {
+impl From<ButtonStyleBits> for ButtonStyle {
+    fn from(value: ButtonStyleBits) -> Self {
         ButtonStyle {
-            panel: self.panel.into(),
-            hover_color: self.hover_color.map(|val| val.into()),
-            down_color: self.down_color.map(|val| val.into()),
-            disabled_color: self.disabled_color.map(|val| val.into()),
+            panel: value.panel.into(),
+            hover_color: value.hover_color.map(|val| val.into()),
+            down_color: value.down_color.map(|val| val.into()),
+            disabled_color: value.disabled_color.map(|val| val.into()),
         }
     }
 }
 
-impl Into<TextboxStyle> for TextboxStyleBits {
-    fn into(self) -> TextboxStyle {
+impl From<TextboxStyleBits> for TextboxStyle {
+    fn from(value: TextboxStyleBits) -> Self {
         TextboxStyle {
-            background_color: self.background_color.map(Into::into),
-            background_alpha: self.background_alpha.map(bits_into_alpha),
-            text_color: self.text_color.map(Into::into),
-            hover_color: self.hover_color.map(|val| val.into()),
-            active_color: self.active_color.map(|val| val.into()),
-            select_color: self.select_color.map(|val| val.into()),
+            background_color: value.background_color.map(Into::into),
+            background_alpha: value.background_alpha.map(bits_into_alpha),
+            text_color: value.text_color.map(Into::into),
+            hover_color: value.hover_color.map(|val| val.into()),
+            active_color: value.active_color.map(|val| val.into()),
+            select_color: value.select_color.map(|val| val.into()),
         }
     }
 }
 
-impl Into<SpinnerStyle> for SpinnerStyleBits {
-    fn into(self) -> SpinnerStyle {
+impl From<SpinnerStyleBits> for SpinnerStyle {
+    fn from(value: SpinnerStyleBits) -> Self {
         SpinnerStyle {
-            background_color: self.background_color.map(Into::into),
-            background_alpha: self.background_alpha.map(bits_into_alpha),
-            spinner_color: self.spinner_color.map(Into::into),
+            background_color: value.background_color.map(Into::into),
+            background_alpha: value.background_alpha.map(bits_into_alpha),
+            spinner_color: value.spinner_color.map(Into::into),
         }
     }
 }
@@ -4,18 +4,18 @@ use naia_serde::{FileBitWriter, SerdeInternal as Serde, UnsignedInteger, Unsigne
 
 use render_api::base::Color;
 use ui_builder_config::{
-    Button, ButtonStyle, Navigation, NodeStyle, Panel, PanelStyle, Spinner, SpinnerStyle, StyleId,
-    Text, TextStyle, Textbox, TextboxStyle, UiConfig, UiContainer, UiNode, ValidationType, Widget,
-    WidgetStyle,
+    Button, ButtonStyle, ImageFit, Navigation, NodeStyle, Panel, PanelStyle, Spinner, SpinnerStyle,
+    StyleId, Text, TextStyle, Textbox, TextboxStyle, UiConfig, UiContainer, UiNode, ValidationType,
+    Widget, WidgetStyle,
 };
 use ui_layout::{Alignment, LayoutType, MarginUnits, PositionType, SizeUnits, Solid};
 
 use crate::bits::{
-    AlignmentBits, ButtonBits, ButtonStyleBits, ColorBits, LayoutTypeBits, MarginUnitsBits,
-    NavigationBits, PanelBits, PanelStyleBits, PositionTypeBits, SizeUnitsBits, SolidBits,
-    SpinnerBits, SpinnerStyleBits, TextBits, TextStyleBits, TextboxBits, TextboxStyleBits,
-    UiAction, UiActionType, UiContainerBits, UiNodeBits, UiStyleBits, ValidationBits, WidgetBits,
-    WidgetStyleBits,
+    AlignmentBits, ButtonBits, ButtonStyleBits, ColorBits, ImageFitBits, LayoutTypeBits,
+    MarginUnitsBits, NavigationBits, PanelBits, PanelStyleBits, PositionTypeBits, SizeUnitsBits,
+    SolidBits, SpinnerBits, SpinnerStyleBits, TextBits, TextStyleBits, TextboxBits,
+    TextboxStyleBits, UiAction, UiActionType, UiContainerBits, UiNodeBits, UiStyleBits,
+    ValidationBits, WidgetBits, WidgetStyleBits, UI_FORMAT_VERSION,
 };
 
 pub fn write_bits(ui_config: &UiConfig) -> Vec<u8> {
@@ -63,6 +63,8 @@ fn convert_ui_to_actions(ui_config: &UiConfig) -> Vec<UiAction> {
 fn actions_to_bytes(actions: Vec<UiAction>) -> Vec<u8> {
     let mut bit_writer = FileBitWriter::new();
 
+    UI_FORMAT_VERSION.ser(&mut bit_writer);
+
     for action in actions {
         match action {
             UiAction::FirstInput(button_id_opt) => {
@@ -167,6 +169,7 @@ impl From<&PanelStyle> for PanelStyleBits {
 
             background_color: style.background_color.map(From::from),
             background_alpha: style.background_alpha().map(bits_from_alpha),
+            background_fit: style.background_fit.map(From::from),
 
             layout_type: style.layout_type.map(From::from),
 
@@ -376,6 +379,16 @@ impl From<LayoutType> for LayoutTypeBits {
     }
 }
 
+impl From<ImageFit> for ImageFitBits {
+    fn from(fit: ImageFit) -> Self {
+        match fit {
+            ImageFit::Stretch => Self::Stretch,
+            ImageFit::Contain => Self::Contain,
+            ImageFit::Cover => Self::Cover,
+        }
+    }
+}
+
 impl From<Color> for ColorBits {
     fn from(color: Color) -> Self {
         Self {
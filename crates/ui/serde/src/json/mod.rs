@@ -69,6 +69,7 @@ pub(crate) struct PanelStyleJson {
 
     background_color: Option<ColorJson>,
     background_alpha: Option<f32>,
+    background_fit: Option<ImageFitJson>,
 
     layout_type: Option<LayoutTypeJson>,
 
@@ -172,6 +173,14 @@ pub(crate) enum LayoutTypeJson {
     Column,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum ImageFitJson {
+    Stretch,
+    Contain,
+    Cover,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub(crate) struct ColorJson {
     r: u8,
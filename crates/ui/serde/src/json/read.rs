@@ -2,16 +2,16 @@ use std::collections::HashMap;
 
 use render_api::base::Color;
 use ui_builder_config::{
-    BaseNodeStyle, Button, ButtonStyle, NodeId, NodeStyle, Panel, PanelStyle, Spinner,
+    BaseNodeStyle, Button, ButtonStyle, ImageFit, NodeId, NodeStyle, Panel, PanelStyle, Spinner,
     SpinnerStyle, StyleId, Text, TextStyle, Textbox, TextboxStyle, UiConfig, UiContainer,
     ValidationType, Widget, WidgetKind, WidgetStyle,
 };
 use ui_layout::{Alignment, LayoutType, MarginUnits, PositionType, SizeUnits, Solid};
 
 use super::{
-    AlignmentJson, ColorJson, LayoutTypeJson, MarginUnitsJson, PanelJson, PositionTypeJson,
-    SizeUnitsJson, SolidJson, SpinnerStyleJson, UiConfigJson, UiNodeJson, UiStyleJson,
-    ValidationJson, WidgetJson, WidgetStyleJson,
+    AlignmentJson, ColorJson, ImageFitJson, LayoutTypeJson, MarginUnitsJson, PanelJson,
+    PositionTypeJson, SizeUnitsJson, SolidJson, SpinnerStyleJson, UiConfigJson, UiNodeJson,
+    UiStyleJson, ValidationJson, WidgetJson, WidgetStyleJson,
 };
 use crate::json::{
     ButtonJson, ButtonStyleJson, PanelStyleJson, TextStyleJson, TextboxJson, TextboxStyleJson,
@@ -461,6 +461,16 @@ impl Into<LayoutType> for LayoutTypeJson {
     }
 }
 
+impl Into<ImageFit> for ImageFitJson {
+    fn into(self) -> ImageFit {
+        match self {
+            Self::Stretch => ImageFit::Stretch,
+            Self::Contain => ImageFit::Contain,
+            Self::Cover => ImageFit::Cover,
+        }
+    }
+}
+
 impl Into<Color> for ColorJson {
     fn into(self) -> Color {
         Color::new(self.r, self.g, self.b)
@@ -513,6 +523,7 @@ impl Into<PanelStyle> for PanelStyleJson {
             is_viewport: self.is_viewport,
             background_color: self.background_color.map(Into::into),
             background_alpha: self.background_alpha,
+            background_fit: self.background_fit.map(Into::into),
             layout_type: self.layout_type.map(Into::into),
             padding_left: self.padding_left.map(Into::into),
             padding_right: self.padding_right.map(Into::into),
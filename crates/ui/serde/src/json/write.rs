@@ -3,17 +3,17 @@ use std::collections::HashMap;
 use render_api::base::Color;
 
 use ui_builder_config::{
-    Button, ButtonStyle, Navigation, NodeStyle, Panel, PanelStyle, Spinner, SpinnerStyle, StyleId,
-    Text, TextStyle, Textbox, TextboxStyle, UiConfig, UiContainer, UiNode, ValidationType, Widget,
-    WidgetStyle,
+    Button, ButtonStyle, ImageFit, Navigation, NodeStyle, Panel, PanelStyle, Spinner, SpinnerStyle,
+    StyleId, Text, TextStyle, Textbox, TextboxStyle, UiConfig, UiContainer, UiNode, ValidationType,
+    Widget, WidgetStyle,
 };
 use ui_layout::{Alignment, LayoutType, MarginUnits, PositionType, SizeUnits, Solid};
 
 use super::{
-    AlignmentJson, ColorJson, LayoutTypeJson, MarginUnitsJson, PanelJson, PanelStyleJson,
-    PositionTypeJson, SizeUnitsJson, SolidJson, SpinnerJson, SpinnerStyleJson, TextJson,
-    TextStyleJson, UiConfigJson, UiContainerJson, UiNodeJson, UiStyleJson, ValidationJson,
-    WidgetJson, WidgetStyleJson,
+    AlignmentJson, ColorJson, ImageFitJson, LayoutTypeJson, MarginUnitsJson, PanelJson,
+    PanelStyleJson, PositionTypeJson, SizeUnitsJson, SolidJson, SpinnerJson, SpinnerStyleJson,
+    TextJson, TextStyleJson, UiConfigJson, UiContainerJson, UiNodeJson, UiStyleJson,
+    ValidationJson, WidgetJson, WidgetStyleJson,
 };
 use crate::json::{ButtonJson, ButtonStyleJson, NavigationJson, TextboxJson, TextboxStyleJson};
 
@@ -98,6 +98,7 @@ impl From<&PanelStyle> for PanelStyleJson {
 
             background_color: style.background_color.map(From::from),
             background_alpha: style.background_alpha(),
+            background_fit: style.background_fit.map(From::from),
 
             layout_type: style.layout_type.map(From::from),
 
@@ -216,6 +217,16 @@ impl From<LayoutType> for LayoutTypeJson {
     }
 }
 
+impl From<ImageFit> for ImageFitJson {
+    fn from(fit: ImageFit) -> Self {
+        match fit {
+            ImageFit::Stretch => Self::Stretch,
+            ImageFit::Contain => Self::Contain,
+            ImageFit::Cover => Self::Cover,
+        }
+    }
+}
+
 impl From<Color> for ColorJson {
     fn from(color: Color) -> Self {
         Self {
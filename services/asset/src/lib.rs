@@ -0,0 +1,66 @@
+#[macro_use]
+extern crate cfg_if;
+
+mod asset_endpoint;
+mod asset_metadata_store;
+mod region_connection;
+mod state;
+
+cfg_if! {
+    if #[cfg(feature = "local")] {
+        pub mod local;
+    } else {}
+}
+
+use std::{net::SocketAddr, time::Duration};
+
+use config::{asset_server_files_path, asset_server_port, self_binding_addr};
+use http_server::{
+    async_dup::Arc,
+    executor::smol::{lock::RwLock, Timer},
+    Server,
+};
+use logging::info;
+
+use crate::{asset_metadata_store::AssetMetadataStore, state::State};
+
+pub fn start() {
+    // setup state
+    let asset_metadata_store = AssetMetadataStore::new(asset_server_files_path());
+
+    let registration_resend_rate = Duration::from_secs(5);
+    let region_server_disconnect_timeout = Duration::from_secs(61);
+    let cache_size_kb = 5000; // 5 MB
+    let state = Arc::new(RwLock::new(State::new(
+        registration_resend_rate,
+        region_server_disconnect_timeout,
+        cache_size_kb,
+        asset_metadata_store,
+    )));
+
+    // setup listening http server
+    info!("Asset Server starting up...");
+    let socket_addr: SocketAddr =
+        SocketAddr::new(self_binding_addr().parse().unwrap(), asset_server_port());
+
+    let mut server = Server::new(socket_addr);
+    let host = "asset";
+
+    region_connection::recv_heartbeat_request(host, &mut server, state.clone());
+    asset_endpoint::handle_asset_request(host, &mut server, state.clone());
+
+    server.start();
+
+    let state_clone = state.clone();
+    Server::spawn(async move {
+        loop {
+            // send registration
+            region_connection::send_register_instance_request(state_clone.clone()).await;
+
+            // handle disconnection
+            region_connection::process_region_server_disconnect(state_clone.clone()).await;
+
+            Timer::after(Duration::from_secs(1)).await;
+        }
+    });
+}
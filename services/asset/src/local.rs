@@ -2,7 +2,7 @@ use automation_lib::{copy_from_repo_to_target_dir, OutputType, TargetEnv};
 
 use logging::info;
 
-pub(crate) fn setup() {
+pub fn setup() {
     info!("Setting up local environment");
 
     let project_path = "/home/connor/Work/cyberlith";
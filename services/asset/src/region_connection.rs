@@ -10,8 +10,8 @@ use asset_server_http_proto::{HeartbeatRequest, HeartbeatResponse};
 use region_server_http_proto::{AssetRegisterInstanceRequest, AssetRegisterInstanceResponse};
 
 use config::{
-    ASSET_SERVER_GLOBAL_SECRET, ASSET_SERVER_PORT, ASSET_SERVER_RECV_ADDR, REGION_SERVER_PORT,
-    REGION_SERVER_RECV_ADDR,
+    asset_server_global_secret, asset_server_port, asset_server_recv_addr, region_server_port,
+    region_server_recv_addr,
 };
 
 use crate::state::State;
@@ -89,30 +89,30 @@ pub async fn send_register_instance_request(state: Arc<RwLock<State>>) {
     }
 
     let request = AssetRegisterInstanceRequest::new(
-        ASSET_SERVER_GLOBAL_SECRET,
-        ASSET_SERVER_RECV_ADDR,
-        ASSET_SERVER_PORT,
+        asset_server_global_secret(),
+        asset_server_recv_addr(),
+        asset_server_port(),
     );
 
     let host = "asset";
     let remote = "region";
     http_server::log_util::send_req(host, remote, AssetRegisterInstanceRequest::name());
-    let response = HttpClient::send(REGION_SERVER_RECV_ADDR, REGION_SERVER_PORT, request).await;
+    let response = HttpClient::send(region_server_recv_addr(), region_server_port(), request).await;
     http_server::log_util::recv_res(host, remote, AssetRegisterInstanceResponse::name());
 
     match response {
         Ok(_) => {
             // info!(
             //     "from {:?}:{} - asset server registration success",
-            //     REGION_SERVER_RECV_ADDR, REGION_SERVER_PORT
+            //     region_server_recv_addr(), region_server_port()
             // );
             state.set_connected();
         }
         Err(err) => {
             warn!(
                 "from {:?}:{} - asset server registration failure: {}",
-                REGION_SERVER_RECV_ADDR,
-                REGION_SERVER_PORT,
+                region_server_recv_addr(),
+                region_server_port(),
                 err.to_string()
             );
         }
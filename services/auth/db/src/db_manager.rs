@@ -17,6 +17,12 @@ impl DatabaseManager {
         Self { wrapper }
     }
 
+    pub fn init_in_memory() -> Self {
+        let mut wrapper = DatabaseWrapper::init_in_memory();
+        wrapper.table_open::<Users>();
+        Self { wrapper }
+    }
+
     // user create
     pub fn create_user(&mut self, user: User) -> Result<UserId, AuthServerDbError> {
         self.wrapper
@@ -69,7 +69,7 @@ impl State {
             .email_catalog
             .user_name_forgot_html(&username, link_url);
 
-        match email::send(
+        match self.mailer.send(
             sending_email,
             &user_email,
             email_subject,
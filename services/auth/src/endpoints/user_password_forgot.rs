@@ -81,7 +81,7 @@ impl State {
             .email_catalog
             .user_password_forgot_html(&user_name, &link_url);
 
-        match email::send(
+        match self.mailer.send(
             sending_email,
             &user_email,
             email_subject,
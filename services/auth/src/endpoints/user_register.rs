@@ -101,7 +101,7 @@ impl State {
             .email_catalog
             .register_verification_html(&username, &link_url);
 
-        match email::send(
+        match self.mailer.send(
             sending_email,
             &user_email,
             email_subject,
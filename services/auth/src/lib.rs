@@ -0,0 +1,52 @@
+mod emails;
+mod endpoints;
+mod error;
+mod expire_manager;
+mod state;
+mod types;
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use auth_server_db::DatabaseManager;
+use config::{auth_server_port, self_binding_addr};
+use email::Mailer;
+use http_server::{async_dup::Arc, executor::smol, executor::smol::lock::RwLock, Server};
+use logging::info;
+
+use crate::state::State;
+
+// returns once listening, so several servers can share one process (see the test harness)
+pub fn start(database_manager: DatabaseManager, mailer: Mailer) {
+    info!("Auth Server starting up...");
+    let socket_addr: SocketAddr =
+        SocketAddr::new(self_binding_addr().parse().unwrap(), auth_server_port());
+
+    let mut server = Server::new(socket_addr);
+    let state = Arc::new(RwLock::new(State::new(database_manager, mailer)));
+    let server_name = "auth_server";
+
+    endpoints::user_get(server_name, &mut server, state.clone());
+
+    endpoints::user_login(server_name, &mut server, state.clone());
+    endpoints::user_register(server_name, &mut server, state.clone());
+    endpoints::user_register_confirm(server_name, &mut server, state.clone());
+    endpoints::user_name_forgot(server_name, &mut server, state.clone());
+    endpoints::user_password_forgot(server_name, &mut server, state.clone());
+    endpoints::user_password_reset(server_name, &mut server, state.clone());
+    endpoints::access_token_validate(server_name, &mut server, state.clone());
+    endpoints::refresh_token_grant(server_name, &mut server, state.clone());
+
+    // expire tokens
+    Server::spawn(async move {
+        let state = state.clone();
+        loop {
+            smol::Timer::after(Duration::from_secs(60 * 15)).await; // 15 minutes
+
+            let mut state = state.write().await;
+            state.clear_expired_tokens();
+        }
+    });
+
+    server.start();
+}
@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use auth_server_db::DatabaseManager;
 use auth_server_http_proto::{AccessToken, RefreshToken, RegisterToken, ResetPasswordToken};
 use auth_server_types::UserId;
+use email::Mailer;
 use logging::info;
 
 use crate::{
@@ -13,6 +14,7 @@ use crate::{
 
 pub struct State {
     pub(crate) email_catalog: EmailCatalog,
+    pub(crate) mailer: Mailer,
 
     pub(crate) database_manager: DatabaseManager,
     pub(crate) username_to_id_map: HashMap<String, Option<UserId>>,
@@ -27,8 +29,7 @@ pub struct State {
 }
 
 impl State {
-    pub fn new() -> Self {
-        let database_manager = DatabaseManager::init();
+    pub fn new(database_manager: DatabaseManager, mailer: Mailer) -> Self {
         let mut username_to_id_map = HashMap::new();
         let mut email_to_id_map = HashMap::new();
         let mut user_data_map = HashMap::new();
@@ -41,6 +42,7 @@ impl State {
 
         Self {
             email_catalog: EmailCatalog::new(),
+            mailer,
 
             database_manager,
             username_to_id_map,
@@ -0,0 +1,65 @@
+#[macro_use]
+extern crate cfg_if;
+
+mod file_endpoint;
+mod file_metadata_store;
+mod state;
+
+cfg_if! {
+    if #[cfg(feature = "local")] {
+        pub mod local;
+    } else {}
+}
+
+use std::net::SocketAddr;
+
+use config::{content_server_files_path, content_server_port, self_binding_addr};
+use http_server::{async_dup::Arc, executor::smol::lock::RwLock, ApiServer, Method, Server};
+use logging::info;
+
+use crate::{
+    file_endpoint::file_endpoint_handler, file_metadata_store::FileMetadataStore, state::State,
+};
+
+pub fn start() {
+    // setup state
+    let file_metadata_store = FileMetadataStore::new(content_server_files_path());
+
+    let cache_size_kb = 5000; // 5 MB
+    let state = Arc::new(RwLock::new(State::new(cache_size_kb, file_metadata_store)));
+
+    // setup listening http server
+    info!("Content Server starting up...");
+    let socket_addr: SocketAddr =
+        SocketAddr::new(self_binding_addr().parse().unwrap(), content_server_port());
+
+    let mut server = Server::new(socket_addr);
+    let content_server = "content_server";
+
+    for file_name in [
+        "launcher.html",
+        "launcher.js",
+        "launcher_bg.wasm",
+        "game.html",
+        "game.js",
+        "game_bg.wasm",
+    ]
+    .iter()
+    {
+        let state = state.clone();
+        server.raw_endpoint(
+            content_server,
+            None,
+            None,
+            Method::Get,
+            file_name,
+            move |addr, incoming_req| {
+                let state = state.clone();
+                let file_name = file_name.to_string();
+                async move { file_endpoint_handler(addr, incoming_req, state, file_name).await }
+            },
+        );
+    }
+
+    server.start();
+}
@@ -1,7 +1,7 @@
 use automation_lib::{copy_from_repo_to_target_dir, TargetEnv};
 use logging::info;
 
-pub(crate) fn setup() {
+pub fn setup() {
     info!("Setting up local environment");
 
     let project_path = "/home/connor/Work/cyberlith";
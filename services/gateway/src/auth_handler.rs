@@ -1,6 +1,6 @@
 use std::net::SocketAddr;
 
-use config::{TargetEnv, AUTH_SERVER_PORT, AUTH_SERVER_RECV_ADDR, PUBLIC_IP_ADDR};
+use config::{auth_server_port, auth_server_recv_addr, public_ip_addr, TargetEnv};
 use http_client::HttpClient;
 use http_server::{ApiRequest, ApiResponse, Request, RequestMiddlewareAction, Response};
 use logging::info;
@@ -31,7 +31,7 @@ pub(crate) async fn require_auth_tokens(
             let mut cookies = Vec::new();
 
             let access_token_value = AccessToken::get_new_cookie_value(
-                PUBLIC_IP_ADDR,
+                public_ip_addr(),
                 TargetEnv::is_prod(),
                 &access_token,
             );
@@ -48,12 +48,12 @@ pub(crate) async fn require_auth_tokens(
             let mut response = Response::unauthenticated(&incoming_request.url);
             if clear_access_token {
                 let access_token_value =
-                    AccessToken::get_expire_cookie_value(PUBLIC_IP_ADDR, TargetEnv::is_prod());
+                    AccessToken::get_expire_cookie_value(public_ip_addr(), TargetEnv::is_prod());
                 response.insert_header("Set-Cookie", &access_token_value);
             }
             if clear_refresh_token {
                 let refresh_token_value =
-                    RefreshToken::get_expire_cookie_value(PUBLIC_IP_ADDR, TargetEnv::is_prod());
+                    RefreshToken::get_expire_cookie_value(public_ip_addr(), TargetEnv::is_prod());
                 response.insert_header("Set-Cookie", &refresh_token_value);
             }
             RequestMiddlewareAction::Stop(response)
@@ -87,7 +87,7 @@ async fn require_auth_tokens_or_redirect(
         AuthResult::ContinueAndNewAccessToken(user_id, access_token) => {
             let mut cookies_set = Vec::new();
             let access_token_value = AccessToken::get_new_cookie_value(
-                PUBLIC_IP_ADDR,
+                public_ip_addr(),
                 TargetEnv::is_prod(),
                 &access_token,
             );
@@ -103,12 +103,12 @@ async fn require_auth_tokens_or_redirect(
             let mut response = Response::redirect(&url, new_url);
             if clear_access_token {
                 let access_token_value =
-                    AccessToken::get_expire_cookie_value(PUBLIC_IP_ADDR, TargetEnv::is_prod());
+                    AccessToken::get_expire_cookie_value(public_ip_addr(), TargetEnv::is_prod());
                 response.insert_header("Set-Cookie", &access_token_value);
             }
             if clear_refresh_token {
                 let refresh_token_value =
-                    RefreshToken::get_expire_cookie_value(PUBLIC_IP_ADDR, TargetEnv::is_prod());
+                    RefreshToken::get_expire_cookie_value(public_ip_addr(), TargetEnv::is_prod());
                 response.insert_header("Set-Cookie", &refresh_token_value);
             }
             RequestMiddlewareAction::Stop(response)
@@ -142,7 +142,7 @@ async fn if_auth_tokens_and_offline_redirect(
             let mut response = Response::redirect(&url, new_url);
 
             let access_token_value = AccessToken::get_new_cookie_value(
-                PUBLIC_IP_ADDR,
+                public_ip_addr(),
                 TargetEnv::is_prod(),
                 &access_token,
             );
@@ -156,12 +156,12 @@ async fn if_auth_tokens_and_offline_redirect(
             let mut set_cookies = Vec::new();
             if clear_access_token {
                 let access_token_value =
-                    AccessToken::get_expire_cookie_value(PUBLIC_IP_ADDR, TargetEnv::is_prod());
+                    AccessToken::get_expire_cookie_value(public_ip_addr(), TargetEnv::is_prod());
                 set_cookies.push(access_token_value);
             }
             if clear_refresh_token {
                 let refresh_token_value =
-                    RefreshToken::get_expire_cookie_value(PUBLIC_IP_ADDR, TargetEnv::is_prod());
+                    RefreshToken::get_expire_cookie_value(public_ip_addr(), TargetEnv::is_prod());
                 set_cookies.push(refresh_token_value);
             }
             // info!("continuing to /");
@@ -191,8 +191,8 @@ async fn auth_impl(_incoming_addr: &SocketAddr, incoming_request: &Request) -> A
     let host_name = "gateway_auth";
     let remote_name = "client";
     let auth_server = "auth_server";
-    let auth_addr = AUTH_SERVER_RECV_ADDR;
-    let auth_port = AUTH_SERVER_PORT;
+    let auth_addr = auth_server_recv_addr();
+    let auth_port = auth_server_port();
 
     let access_token_opt = extract_cookie_value(&incoming_request, "access_token");
     let refresh_token_opt = extract_cookie_value(&incoming_request, "refresh_token");
@@ -1,7 +1,7 @@
 use std::net::SocketAddr;
 
 use auth_server_types::UserId;
-use config::{SOCIAL_SERVER_PORT, SOCIAL_SERVER_RECV_ADDR};
+use config::{social_server_port, social_server_recv_addr};
 use http_client::HttpClient;
 use http_server::{ApiRequest, ApiResponse, Request, RequestMiddlewareAction, Response};
 
@@ -59,8 +59,8 @@ async fn get_user_online_status(
 pub(crate) async fn get_user_online_status_impl(user_id: UserId) -> UserPresenceResult {
     let host_name = "gateway_demultiply";
     let social_server = "social_server";
-    let social_addr = SOCIAL_SERVER_RECV_ADDR;
-    let social_port = SOCIAL_SERVER_PORT;
+    let social_addr = social_server_recv_addr();
+    let social_port = social_server_port();
 
     let request = UserIsOnlineRequest::new(user_id);
 
@@ -4,8 +4,8 @@ use auth_server_types::UserId;
 use naia_serde::BitWriter;
 
 use config::{
-    REGION_SERVER_PORT, REGION_SERVER_RECV_ADDR, SESSION_SERVER_RECV_ADDR,
-    SESSION_SERVER_SIGNAL_PORT,
+    region_server_port, region_server_recv_addr, session_server_recv_addr,
+    session_server_signal_port,
 };
 use http_client::{HttpClient, ResponseError};
 use http_server::{
@@ -29,8 +29,8 @@ pub(crate) async fn handler(
     // call to region server with login request
     let connect_response = {
         let remote = "region";
-        let remote_addr = REGION_SERVER_RECV_ADDR;
-        let remote_port = REGION_SERVER_PORT;
+        let remote_addr = region_server_recv_addr();
+        let remote_port = region_server_port();
         let remote_method = SessionConnectRequest::method();
         let remote_path = SessionConnectRequest::path();
 
@@ -72,8 +72,8 @@ pub(crate) async fn handler(
     // forward original request to session server
     {
         let session_server = "session_server";
-        let remote_addr = SESSION_SERVER_RECV_ADDR;
-        let remote_port = SESSION_SERVER_SIGNAL_PORT.to_string();
+        let remote_addr = session_server_recv_addr();
+        let remote_port = session_server_signal_port().to_string();
         let remote_method = Method::Post;
 
         let protocol = session_protocol.read().await;
@@ -1,6 +1,6 @@
 use std::net::SocketAddr;
 
-use config::{TargetEnv, AUTH_SERVER_PORT, AUTH_SERVER_RECV_ADDR, PUBLIC_IP_ADDR};
+use config::{auth_server_port, auth_server_recv_addr, public_ip_addr, TargetEnv};
 use http_client::HttpClient;
 use http_server::{ApiRequest, ApiResponse, Request, Response, ResponseError};
 
@@ -42,8 +42,8 @@ pub(crate) async fn handler(
 
     // call auth server
     let auth_server = "auth_server";
-    let auth_addr = AUTH_SERVER_RECV_ADDR;
-    let auth_port = AUTH_SERVER_PORT;
+    let auth_addr = auth_server_recv_addr();
+    let auth_port = auth_server_port();
 
     let auth_request =
         AuthUserLoginRequest::new(&gateway_request.handle, &gateway_request.password);
@@ -103,13 +103,13 @@ pub(crate) async fn handler(
 
     let mut gateway_response = gateway_response.to_response();
     let access_token_value = AccessToken::get_new_cookie_value(
-        PUBLIC_IP_ADDR,
+        public_ip_addr(),
         TargetEnv::is_prod(),
         &access_token.to_string(),
     );
     gateway_response.insert_header("Set-Cookie", &access_token_value);
     let refresh_token_value = RefreshToken::get_new_cookie_value(
-        PUBLIC_IP_ADDR,
+        public_ip_addr(),
         TargetEnv::is_prod(),
         &refresh_token.to_string(),
     );
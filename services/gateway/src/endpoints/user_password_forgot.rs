@@ -1,6 +1,6 @@
 use std::net::SocketAddr;
 
-use config::{AUTH_SERVER_PORT, AUTH_SERVER_RECV_ADDR};
+use config::{auth_server_port, auth_server_recv_addr};
 use http_client::HttpClient;
 use http_server::{ApiRequest, ApiResponse, Request, Response, ResponseError};
 
@@ -38,8 +38,8 @@ pub(crate) async fn handler(
 
     // call auth server
     let auth_server = "auth_server";
-    let auth_addr = AUTH_SERVER_RECV_ADDR;
-    let auth_port = AUTH_SERVER_PORT;
+    let auth_addr = auth_server_recv_addr();
+    let auth_port = auth_server_port();
 
     let auth_request = UserPasswordForgotRequest::new(&gateway_request.email);
 
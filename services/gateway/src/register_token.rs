@@ -1,6 +1,6 @@
 use std::net::SocketAddr;
 
-use config::{TargetEnv, AUTH_SERVER_PORT, AUTH_SERVER_RECV_ADDR, PUBLIC_IP_ADDR};
+use config::{auth_server_port, auth_server_recv_addr, public_ip_addr, TargetEnv};
 use http_client::HttpClient;
 use http_server::{
     clear_query_string, extract_query_string, ApiRequest, ApiResponse, Request,
@@ -34,8 +34,8 @@ pub(crate) async fn handle(
     // call auth server to with register token
     let host_name = "gateway";
     let auth_server = "auth_server";
-    let remote_addr = AUTH_SERVER_RECV_ADDR;
-    let remote_port = AUTH_SERVER_PORT;
+    let remote_addr = auth_server_recv_addr();
+    let remote_port = auth_server_port();
 
     http_server::log_util::send_req(host_name, auth_server, UserRegisterConfirmRequest::name());
 
@@ -56,7 +56,7 @@ pub(crate) async fn handle(
 
             // set access token
             let access_token_value = AccessToken::get_new_cookie_value(
-                PUBLIC_IP_ADDR,
+                public_ip_addr(),
                 TargetEnv::is_prod(),
                 &access_token.to_string(),
             );
@@ -64,7 +64,7 @@ pub(crate) async fn handle(
 
             // set refresh token
             let refresh_token_value = RefreshToken::get_new_cookie_value(
-                PUBLIC_IP_ADDR,
+                public_ip_addr(),
                 TargetEnv::is_prod(),
                 &refresh_token.to_string(),
             );
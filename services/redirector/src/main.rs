@@ -3,7 +3,7 @@ mod server;
 use std::{net::SocketAddr, thread};
 
 use config::{
-    REDIRECTOR_PORT, REDIRECTOR_SERVER_CPU_PRIORITY, SELF_BINDING_ADDR, TOTAL_CPU_PRIORITY,
+    redirector_port, redirector_server_cpu_priority, self_binding_addr, total_cpu_priority,
 };
 use logging::info;
 
@@ -11,11 +11,12 @@ use crate::server::RedirectServer;
 
 pub fn main() {
     logging::initialize();
-    executor::setup(REDIRECTOR_SERVER_CPU_PRIORITY, TOTAL_CPU_PRIORITY);
+    config::init_runtime_config(&[&config::REDIRECTOR_CONFIG]);
+    executor::setup(redirector_server_cpu_priority(), total_cpu_priority());
 
     info!("Redirector starting up...");
     let socket_addr: SocketAddr =
-        SocketAddr::new(SELF_BINDING_ADDR.parse().unwrap(), REDIRECTOR_PORT);
+        SocketAddr::new(self_binding_addr().parse().unwrap(), redirector_port());
 
     RedirectServer::start(socket_addr);
 
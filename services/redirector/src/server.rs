@@ -2,7 +2,7 @@ use std::net::SocketAddr;
 
 use executor::smol::{net::TcpListener, stream::StreamExt};
 
-use config::{PUBLIC_IP_ADDR, PUBLIC_PROTOCOL};
+use config::{public_ip_addr, public_protocol};
 use http_common::{Request, Response, ResponseError};
 use http_server::log_util;
 use http_server_shared::{serve_impl, MatchHostResult};
@@ -74,7 +74,7 @@ impl RedirectServer {
         let original_url = request.url.clone();
         let response = Response::redirect(
             &original_url,
-            format!("{}://{}", PUBLIC_PROTOCOL, PUBLIC_IP_ADDR).as_str(),
+            format!("{}://{}", public_protocol(), public_ip_addr()).as_str(),
         );
 
         log_util::send_res(
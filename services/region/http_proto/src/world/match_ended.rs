@@ -0,0 +1,75 @@
+use naia_serde::SerdeInternal as Serde;
+
+use auth_server_types::UserId;
+use http_common::{ApiRequest, ApiResponse, Method};
+use social_server_types::{LobbyId, MatchOutcome, MatchUserResult};
+
+// this is sent by the world server, when a match has ended
+
+// Request
+#[derive(Serde, PartialEq, Clone)]
+pub struct WorldMatchEndedRequest {
+    global_secret: String,
+    lobby_id: LobbyId,
+    outcome: MatchOutcome,
+    results: Vec<(UserId, MatchUserResult)>,
+}
+
+impl WorldMatchEndedRequest {
+    pub fn new(
+        global_secret: &str,
+        lobby_id: LobbyId,
+        outcome: MatchOutcome,
+        results: Vec<(UserId, MatchUserResult)>,
+    ) -> Self {
+        Self {
+            global_secret: global_secret.to_string(),
+            lobby_id,
+            outcome,
+            results,
+        }
+    }
+
+    pub fn global_secret(&self) -> &str {
+        &self.global_secret
+    }
+
+    pub fn lobby_id(&self) -> LobbyId {
+        self.lobby_id
+    }
+
+    pub fn outcome(&self) -> MatchOutcome {
+        self.outcome
+    }
+
+    pub fn results(&self) -> &Vec<(UserId, MatchUserResult)> {
+        &self.results
+    }
+}
+
+// Response
+#[derive(Serde, PartialEq, Clone)]
+pub struct WorldMatchEndedResponse;
+
+// Traits
+impl ApiRequest for WorldMatchEndedRequest {
+    type Response = WorldMatchEndedResponse;
+
+    fn name() -> &'static str {
+        "WorldMatchEndedRequest"
+    }
+
+    fn method() -> Method {
+        Method::Post
+    }
+
+    fn path() -> &'static str {
+        "world/match_ended"
+    }
+}
+
+impl ApiResponse for WorldMatchEndedResponse {
+    fn name() -> &'static str {
+        "WorldMatchEndedResponse"
+    }
+}
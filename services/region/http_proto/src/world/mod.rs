@@ -1,5 +1,14 @@
 mod world_connect;
 pub use world_connect::*;
 
+mod world_spectate;
+pub use world_spectate::*;
+
+mod world_spectate_leave;
+pub use world_spectate_leave::*;
+
 mod register_instance;
 pub use register_instance::*;
+
+mod match_ended;
+pub use match_ended::*;
@@ -0,0 +1,72 @@
+use naia_serde::SerdeInternal as Serde;
+
+use auth_server_types::UserId;
+use http_common::{ApiRequest, ApiResponse, Method};
+use social_server_types::LobbyId;
+
+// this is sent by the social server, to add a spectator to a match already in progress
+
+// Request
+#[derive(Serde, PartialEq, Clone)]
+pub struct WorldSpectateRequest {
+    pub social_server_global_secret: String,
+    pub world_instance_secret: String,
+    pub lobby_id: LobbyId,
+    pub session_instance_secret: String,
+    pub user_id: UserId,
+}
+
+impl WorldSpectateRequest {
+    pub fn new(
+        social_server_global_secret: &str,
+        world_instance_secret: &str,
+        lobby_id: LobbyId,
+        session_instance_secret: &str,
+        user_id: UserId,
+    ) -> Self {
+        Self {
+            social_server_global_secret: social_server_global_secret.to_string(),
+            world_instance_secret: world_instance_secret.to_string(),
+            lobby_id,
+            session_instance_secret: session_instance_secret.to_string(),
+            user_id,
+        }
+    }
+}
+
+// Response
+#[derive(Serde, PartialEq, Clone, Eq, Hash)]
+pub struct WorldSpectateResponse {
+    pub login_token: String,
+}
+
+impl WorldSpectateResponse {
+    pub fn new(login_token: &str) -> Self {
+        Self {
+            login_token: login_token.to_string(),
+        }
+    }
+}
+
+// Traits
+impl ApiRequest for WorldSpectateRequest {
+    type Response = WorldSpectateResponse;
+
+    fn name() -> &'static str {
+        "WorldSpectateRequest"
+    }
+
+    fn method() -> Method {
+        Method::Post
+    }
+
+    fn path() -> &'static str {
+        "world/spectate"
+    }
+}
+
+impl ApiResponse for WorldSpectateResponse {
+    fn name() -> &'static str {
+        "WorldSpectateResponse"
+    }
+}
@@ -0,0 +1,59 @@
+use naia_serde::SerdeInternal as Serde;
+
+use auth_server_types::UserId;
+use http_common::{ApiRequest, ApiResponse, Method};
+use social_server_types::LobbyId;
+
+// this is sent by the social server, when a spectator stops watching a match
+
+// Request
+#[derive(Serde, PartialEq, Clone)]
+pub struct WorldSpectateLeaveRequest {
+    pub social_server_global_secret: String,
+    pub world_instance_secret: String,
+    pub lobby_id: LobbyId,
+    pub user_id: UserId,
+}
+
+impl WorldSpectateLeaveRequest {
+    pub fn new(
+        social_server_global_secret: &str,
+        world_instance_secret: &str,
+        lobby_id: LobbyId,
+        user_id: UserId,
+    ) -> Self {
+        Self {
+            social_server_global_secret: social_server_global_secret.to_string(),
+            world_instance_secret: world_instance_secret.to_string(),
+            lobby_id,
+            user_id,
+        }
+    }
+}
+
+// Response
+#[derive(Serde, PartialEq, Clone, Eq, Hash)]
+pub struct WorldSpectateLeaveResponse;
+
+// Traits
+impl ApiRequest for WorldSpectateLeaveRequest {
+    type Response = WorldSpectateLeaveResponse;
+
+    fn name() -> &'static str {
+        "WorldSpectateLeaveRequest"
+    }
+
+    fn method() -> Method {
+        Method::Post
+    }
+
+    fn path() -> &'static str {
+        "world/spectate_leave"
+    }
+}
+
+impl ApiResponse for WorldSpectateLeaveResponse {
+    fn name() -> &'static str {
+        "WorldSpectateLeaveResponse"
+    }
+}
@@ -5,7 +5,7 @@ use logging::warn;
 use http_client::ResponseError;
 use http_server::{async_dup::Arc, executor::smol::lock::RwLock, ApiServer, Server};
 
-use config::ASSET_SERVER_GLOBAL_SECRET;
+use config::asset_server_global_secret;
 use region_server_http_proto::{AssetRegisterInstanceRequest, AssetRegisterInstanceResponse};
 
 use crate::state::State;
@@ -22,7 +22,7 @@ async fn async_impl(
     state: Arc<RwLock<State>>,
     incoming_request: AssetRegisterInstanceRequest,
 ) -> Result<AssetRegisterInstanceResponse, ResponseError> {
-    if incoming_request.global_secret() != ASSET_SERVER_GLOBAL_SECRET {
+    if incoming_request.global_secret() != asset_server_global_secret() {
         warn!("invalid request secret");
         return Err(ResponseError::Unauthenticated);
     }
@@ -1,4 +1,4 @@
-use config::REGION_SERVER_SECRET;
+use config::region_server_secret;
 use http_client::{HttpClient, ResponseError};
 use http_server::{async_dup::Arc, executor::smol::lock::RwLock, ApiRequest, ApiServer, Server};
 use logging::warn;
@@ -24,13 +24,14 @@ async fn async_impl(
 
     let state = state.read().await;
 
-    // select a session server
-    let Some(session_server) = state.get_available_session_server() else {
+    // select a session server, unless the user turns out to already be on one
+    let Some(available_session_server) = state.get_available_session_server() else {
         warn!("no available session server");
         return Err(ResponseError::InternalServerError(
             "no available session server".to_string(),
         ));
     };
+    let mut session_server = available_session_server;
 
     // send user connection request to social server
     {
@@ -46,9 +47,9 @@ async fn async_impl(
         let request_path = UserConnectedRequest::path();
 
         let request = UserConnectedRequest::new(
-            REGION_SERVER_SECRET,
+            region_server_secret(),
             user_id,
-            session_server.instance_secret(),
+            available_session_server.instance_secret(),
         );
 
         let host = "region";
@@ -73,10 +74,17 @@ async fn async_impl(
         };
 
         if !response.successful() {
-            warn!(
-                "Failed session_connect request to social server, because of pre-existing session"
-            );
-            return Err(ResponseError::Conflict);
+            // a session server holds on to a dropped user for a while, so send them back there
+            let Some(existing_session_server) = response
+                .session_instance_secret()
+                .and_then(|secret| state.get_session_server_from_instance_secret(secret))
+            else {
+                warn!(
+                    "Failed session_connect request to social server, because of pre-existing session"
+                );
+                return Err(ResponseError::Conflict);
+            };
+            session_server = existing_session_server;
         }
     }
 
@@ -88,7 +96,7 @@ async fn async_impl(
         let request_path = IncomingUserRequest::path();
 
         let temp_token = random::generate_random_string(16);
-        let request = IncomingUserRequest::new(REGION_SERVER_SECRET, user_id, &temp_token);
+        let request = IncomingUserRequest::new(region_server_secret(), user_id, &temp_token);
 
         let host = "region";
         let remote = "session";
@@ -1,6 +1,6 @@
 use std::net::SocketAddr;
 
-use config::SOCIAL_SERVER_GLOBAL_SECRET;
+use config::social_server_global_secret;
 use http_client::ResponseError;
 use http_server::{async_dup::Arc, executor::smol::lock::RwLock, ApiServer, Server};
 use logging::warn;
@@ -21,7 +21,7 @@ async fn async_impl(
     state: Arc<RwLock<State>>,
     incoming_request: SocialRegisterInstanceRequest,
 ) -> Result<SocialRegisterInstanceResponse, ResponseError> {
-    if incoming_request.global_secret() != SOCIAL_SERVER_GLOBAL_SECRET {
+    if incoming_request.global_secret() != social_server_global_secret() {
         warn!("invalid request secret");
         return Err(ResponseError::Unauthenticated);
     }
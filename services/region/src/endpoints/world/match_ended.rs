@@ -0,0 +1,73 @@
+use logging::warn;
+
+use config::{region_server_secret, world_server_global_secret};
+use http_client::{HttpClient, ResponseError};
+use http_server::{
+    async_dup::Arc, executor::smol::lock::RwLock, ApiRequest, ApiResponse, ApiServer, Server,
+};
+use region_server_http_proto::{WorldMatchEndedRequest, WorldMatchEndedResponse};
+use social_server_http_proto::{MatchEndedRequest, MatchEndedResponse};
+
+use crate::state::State;
+
+pub fn world_match_ended(host_name: &str, server: &mut Server, state: Arc<RwLock<State>>) {
+    server.api_endpoint(host_name, None, move |_addr, req| {
+        let state = state.clone();
+        async move { async_impl(state, req).await }
+    });
+}
+
+async fn async_impl(
+    state: Arc<RwLock<State>>,
+    incoming_request: WorldMatchEndedRequest,
+) -> Result<WorldMatchEndedResponse, ResponseError> {
+    if incoming_request.global_secret() != world_server_global_secret() {
+        warn!("invalid request secret");
+        return Err(ResponseError::Unauthenticated);
+    }
+
+    let state = state.read().await;
+    let Some(social_server) = state.get_social_server() else {
+        warn!("no available social server");
+        return Err(ResponseError::InternalServerError(
+            "no available social server".to_string(),
+        ));
+    };
+
+    let social_server_http_addr = social_server.http_addr();
+    let social_server_http_port = social_server.http_port();
+
+    let host = "region";
+    let remote = "social";
+
+    let social_server_request = MatchEndedRequest::new(
+        region_server_secret(),
+        incoming_request.lobby_id(),
+        incoming_request.outcome(),
+        incoming_request.results().clone(),
+    );
+
+    http_server::log_util::send_req(&host, &remote, MatchEndedRequest::name());
+    match HttpClient::send(
+        social_server_http_addr,
+        social_server_http_port,
+        social_server_request,
+    )
+    .await
+    {
+        Ok(_social_server_response) => {}
+        // social can never accept this report, so the world server shouldn't retry it
+        Err(error @ (ResponseError::NotFound | ResponseError::Conflict)) => {
+            warn!("social server rejected match end: {}", error.to_string());
+            return Err(error);
+        }
+        Err(_) => {
+            return Err(ResponseError::InternalServerError(
+                "failed match ended request to social server".to_string(),
+            ));
+        }
+    }
+    http_server::log_util::recv_res(&host, &remote, MatchEndedResponse::name());
+
+    Ok(WorldMatchEndedResponse)
+}
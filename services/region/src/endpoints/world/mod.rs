@@ -1,5 +1,11 @@
+mod match_ended;
 mod register_instance;
 mod world_connect;
+mod world_spectate;
+mod world_spectate_leave;
 
+pub use match_ended::*;
 pub use register_instance::*;
 pub use world_connect::*;
+pub use world_spectate::*;
+pub use world_spectate_leave::*;
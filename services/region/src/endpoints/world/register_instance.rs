@@ -5,7 +5,7 @@ use logging::warn;
 use http_client::ResponseError;
 use http_server::{async_dup::Arc, executor::smol::lock::RwLock, ApiServer, Server};
 
-use config::WORLD_SERVER_GLOBAL_SECRET;
+use config::world_server_global_secret;
 use region_server_http_proto::{WorldRegisterInstanceRequest, WorldRegisterInstanceResponse};
 
 use crate::state::State;
@@ -22,7 +22,7 @@ async fn async_impl(
     state: Arc<RwLock<State>>,
     incoming_request: WorldRegisterInstanceRequest,
 ) -> Result<WorldRegisterInstanceResponse, ResponseError> {
-    if incoming_request.global_secret() != WORLD_SERVER_GLOBAL_SECRET {
+    if incoming_request.global_secret() != world_server_global_secret() {
         warn!("invalid request secret");
         return Err(ResponseError::Unauthenticated);
     }
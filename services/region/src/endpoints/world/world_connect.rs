@@ -1,6 +1,6 @@
 use logging::{info, warn};
 
-use config::{REGION_SERVER_SECRET, SOCIAL_SERVER_GLOBAL_SECRET};
+use config::{region_server_secret, social_server_global_secret};
 use http_client::{HttpClient, ResponseError};
 use http_server::{
     async_dup::Arc, executor::smol::lock::RwLock, ApiRequest, ApiResponse, ApiServer, Server,
@@ -27,7 +27,7 @@ async fn async_impl(
     state: Arc<RwLock<State>>,
     incoming_request: RegionWorldConnectRequest,
 ) -> Result<RegionWorldConnectResponse, ResponseError> {
-    if incoming_request.social_server_global_secret != SOCIAL_SERVER_GLOBAL_SECRET {
+    if incoming_request.social_server_global_secret != social_server_global_secret() {
         warn!("invalid request secret");
         return Err(ResponseError::Unauthenticated);
     }
@@ -75,8 +75,11 @@ async fn async_impl(
     let host = "region";
     let remote = "world";
 
-    let world_server_request =
-        WorldWorldConnectRequest::new(REGION_SERVER_SECRET, lobby_id, login_tokens_to_world_server);
+    let world_server_request = WorldWorldConnectRequest::new(
+        region_server_secret(),
+        lobby_id,
+        login_tokens_to_world_server,
+    );
 
     http_server::log_util::send_req(&host, &remote, WorldWorldConnectRequest::name());
     let Ok(_world_server_response) = HttpClient::send(
@@ -0,0 +1,85 @@
+use logging::warn;
+
+use config::{region_server_secret, social_server_global_secret};
+use http_client::{HttpClient, ResponseError};
+use http_server::{
+    async_dup::Arc, executor::smol::lock::RwLock, ApiRequest, ApiResponse, ApiServer, Server,
+};
+use region_server_http_proto::{
+    WorldSpectateRequest as RegionWorldSpectateRequest,
+    WorldSpectateResponse as RegionWorldSpectateResponse,
+};
+use world_server_http_proto::{
+    WorldSpectateRequest as WorldWorldSpectateRequest,
+    WorldSpectateResponse as WorldWorldSpectateResponse,
+};
+
+use crate::state::State;
+
+pub fn world_spectate(host_name: &str, server: &mut Server, state: Arc<RwLock<State>>) {
+    server.api_endpoint(host_name, None, move |_addr, req| {
+        let state = state.clone();
+        async move { async_impl(state, req).await }
+    });
+}
+
+async fn async_impl(
+    state: Arc<RwLock<State>>,
+    incoming_request: RegionWorldSpectateRequest,
+) -> Result<RegionWorldSpectateResponse, ResponseError> {
+    if incoming_request.social_server_global_secret != social_server_global_secret() {
+        warn!("invalid request secret");
+        return Err(ResponseError::Unauthenticated);
+    }
+
+    let state = state.read().await;
+
+    // the spectator must go to the world server already running the match
+    let Some(world_server) =
+        state.get_world_server_from_instance_secret(&incoming_request.world_instance_secret)
+    else {
+        warn!(
+            "world server not found: {}",
+            incoming_request.world_instance_secret
+        );
+        return Err(ResponseError::NotFound);
+    };
+    let Some(session_server) =
+        state.get_session_server_from_instance_secret(&incoming_request.session_instance_secret)
+    else {
+        warn!(
+            "session server not found: {}",
+            incoming_request.session_instance_secret
+        );
+        return Err(ResponseError::NotFound);
+    };
+
+    let login_token = random::generate_random_string(16);
+
+    let world_server_request = WorldWorldSpectateRequest::new(
+        region_server_secret(),
+        incoming_request.lobby_id,
+        session_server.http_addr(),
+        session_server.http_port(),
+        incoming_request.user_id,
+        &login_token,
+    );
+
+    let host = "region";
+    let remote = "world";
+    http_server::log_util::send_req(&host, &remote, WorldWorldSpectateRequest::name());
+    let response_result = HttpClient::send(
+        world_server.http_addr(),
+        world_server.http_port(),
+        world_server_request,
+    )
+    .await;
+    http_server::log_util::recv_res(&host, &remote, WorldWorldSpectateResponse::name());
+
+    if let Err(err) = response_result {
+        warn!("failed world spectate request to world server: {:?}", err);
+        return Err(err);
+    }
+
+    Ok(RegionWorldSpectateResponse::new(&login_token))
+}
@@ -0,0 +1,73 @@
+use logging::warn;
+
+use config::{region_server_secret, social_server_global_secret};
+use http_client::{HttpClient, ResponseError};
+use http_server::{
+    async_dup::Arc, executor::smol::lock::RwLock, ApiRequest, ApiResponse, ApiServer, Server,
+};
+use region_server_http_proto::{
+    WorldSpectateLeaveRequest as RegionWorldSpectateLeaveRequest,
+    WorldSpectateLeaveResponse as RegionWorldSpectateLeaveResponse,
+};
+use world_server_http_proto::{
+    WorldSpectateLeaveRequest as WorldWorldSpectateLeaveRequest,
+    WorldSpectateLeaveResponse as WorldWorldSpectateLeaveResponse,
+};
+
+use crate::state::State;
+
+pub fn world_spectate_leave(host_name: &str, server: &mut Server, state: Arc<RwLock<State>>) {
+    server.api_endpoint(host_name, None, move |_addr, req| {
+        let state = state.clone();
+        async move { async_impl(state, req).await }
+    });
+}
+
+async fn async_impl(
+    state: Arc<RwLock<State>>,
+    incoming_request: RegionWorldSpectateLeaveRequest,
+) -> Result<RegionWorldSpectateLeaveResponse, ResponseError> {
+    if incoming_request.social_server_global_secret != social_server_global_secret() {
+        warn!("invalid request secret");
+        return Err(ResponseError::Unauthenticated);
+    }
+
+    let state = state.read().await;
+
+    let Some(world_server) =
+        state.get_world_server_from_instance_secret(&incoming_request.world_instance_secret)
+    else {
+        warn!(
+            "world server not found: {}",
+            incoming_request.world_instance_secret
+        );
+        return Err(ResponseError::NotFound);
+    };
+
+    let world_server_request = WorldWorldSpectateLeaveRequest::new(
+        region_server_secret(),
+        incoming_request.lobby_id,
+        incoming_request.user_id,
+    );
+
+    let host = "region";
+    let remote = "world";
+    http_server::log_util::send_req(&host, &remote, WorldWorldSpectateLeaveRequest::name());
+    let response_result = HttpClient::send(
+        world_server.http_addr(),
+        world_server.http_port(),
+        world_server_request,
+    )
+    .await;
+    http_server::log_util::recv_res(&host, &remote, WorldWorldSpectateLeaveResponse::name());
+
+    if let Err(err) = response_result {
+        warn!(
+            "failed world spectate leave request to world server: {:?}",
+            err
+        );
+        return Err(err);
+    }
+
+    Ok(RegionWorldSpectateLeaveResponse)
+}
@@ -0,0 +1,63 @@
+mod asset_instance;
+mod endpoints;
+mod requests;
+mod session_instance;
+mod social_instance;
+mod state;
+mod world_instance;
+
+use std::{net::SocketAddr, time::Duration};
+
+use config::{region_server_port, self_binding_addr};
+use http_server::{
+    async_dup::Arc,
+    executor::smol::{lock::RwLock, Timer},
+    Server,
+};
+use logging::info;
+
+use crate::state::State;
+
+pub fn start() {
+    info!("Region Server starting up...");
+    let socket_addr: SocketAddr =
+        SocketAddr::new(self_binding_addr().parse().unwrap(), region_server_port());
+
+    let mut server = Server::new(socket_addr);
+    let state = Arc::new(RwLock::new(State::new(Duration::from_secs(61))));
+    let host = "region";
+
+    endpoints::session_register_instance(host, &mut server, state.clone());
+    endpoints::world_register_instance(host, &mut server, state.clone());
+    endpoints::asset_register_instance(host, &mut server, state.clone());
+    endpoints::social_register_instance(host, &mut server, state.clone());
+
+    endpoints::session_connect(host, &mut server, state.clone());
+    endpoints::world_connect(host, &mut server, state.clone());
+    endpoints::world_spectate(host, &mut server, state.clone());
+    endpoints::world_spectate_leave(host, &mut server, state.clone());
+    endpoints::world_match_ended(host, &mut server, state.clone());
+
+    server.start();
+
+    let state_clone = state.clone();
+    let state_clone2 = state.clone();
+
+    Server::spawn(async move {
+        loop {
+            let mut state = state_clone.write().await;
+            state.send_heartbeats().await;
+            Timer::after(Duration::from_secs(5)).await;
+        }
+    });
+
+    Server::spawn(async move {
+        loop {
+            let mut state = state_clone2.write().await;
+            state.sync_asset_session_instances().await;
+            state.sync_asset_world_instances().await;
+            state.sync_social_session_instances().await;
+            Timer::after(Duration::from_secs(1)).await;
+        }
+    });
+}
@@ -1,6 +1,6 @@
 use std::time::{Duration, Instant};
 
-use config::REGION_SERVER_SECRET;
+use config::region_server_secret;
 use http_client::{HttpClient, RequestOptions};
 use http_server::{ApiRequest, ApiResponse, Server};
 use logging::warn;
@@ -20,6 +20,10 @@ use social_server_http_proto::{
     HeartbeatResponse as SocialHeartbeatResponse,
 };
 use world_server_http_proto::{
+    ConnectAssetServerRequest as WorldConnectAssetServerRequest,
+    ConnectAssetServerResponse as WorldConnectAssetServerResponse,
+    DisconnectAssetServerRequest as WorldDisconnectAssetServerRequest,
+    DisconnectAssetServerResponse as WorldDisconnectAssetServerResponse,
     HeartbeatRequest as WorldHeartbeatRequest, HeartbeatResponse as WorldHeartbeatResponse,
 };
 
@@ -35,7 +39,7 @@ pub(crate) async fn send_session_heartbeat_request(session_instance: &SessionIns
     let session_last_heard = session_instance.last_heard();
 
     Server::spawn(async move {
-        let request = SessionHeartbeatRequest::new(REGION_SERVER_SECRET);
+        let request = SessionHeartbeatRequest::new(region_server_secret());
         let options = RequestOptions {
             timeout_opt: Some(Duration::from_secs(15)),
         };
@@ -70,7 +74,7 @@ pub(crate) async fn send_world_heartbeat_request(world_instance: &WorldInstance)
     let world_last_heard = world_instance.last_heard().clone();
 
     Server::spawn(async move {
-        let request = WorldHeartbeatRequest::new(REGION_SERVER_SECRET);
+        let request = WorldHeartbeatRequest::new(region_server_secret());
         let options = RequestOptions {
             timeout_opt: Some(Duration::from_secs(15)),
         };
@@ -109,7 +113,7 @@ pub(crate) async fn send_asset_heartbeat_request(asset_instance: &AssetInstance)
     let asset_last_port = asset_instance.last_heard();
 
     Server::spawn(async move {
-        let request = AssetHeartbeatRequest::new(REGION_SERVER_SECRET);
+        let request = AssetHeartbeatRequest::new(region_server_secret());
         let options = RequestOptions {
             timeout_opt: Some(Duration::from_secs(15)),
         };
@@ -148,7 +152,7 @@ pub(crate) async fn send_social_heartbeat_request(social_instance: &SocialInstan
     let social_last_heard = social_instance.last_heard();
 
     Server::spawn(async move {
-        let request = SocialHeartbeatRequest::new(REGION_SERVER_SECRET);
+        let request = SocialHeartbeatRequest::new(region_server_secret());
         let options = RequestOptions {
             timeout_opt: Some(Duration::from_secs(15)),
         };
@@ -197,7 +201,7 @@ pub(crate) async fn send_disconnect_session_server_message_to_social_instance(
 
     Server::spawn(async move {
         let request =
-            DisconnectSessionServerRequest::new(REGION_SERVER_SECRET, &session_instance_secret);
+            DisconnectSessionServerRequest::new(region_server_secret(), &session_instance_secret);
 
         let host = "region";
         let remote = "social";
@@ -236,7 +240,7 @@ pub(crate) async fn send_disconnect_social_server_message_to_session_instance(
     let last_heard = session_instance.last_heard();
 
     Server::spawn(async move {
-        let request = DisconnectSocialServerRequest::new(REGION_SERVER_SECRET);
+        let request = DisconnectSocialServerRequest::new(region_server_secret());
 
         let host_name = "region";
         let remote_name = "session";
@@ -285,7 +289,8 @@ pub(crate) async fn send_connect_asset_server_req_to_session_server(
     let asset_port = asset_instance.http_port();
 
     Server::spawn(async move {
-        let request = ConnectAssetServerRequest::new(REGION_SERVER_SECRET, &asset_addr, asset_port);
+        let request =
+            ConnectAssetServerRequest::new(region_server_secret(), &asset_addr, asset_port);
 
         let host = "region";
         let remote = "session";
@@ -314,6 +319,44 @@ pub(crate) async fn send_connect_asset_server_req_to_session_server(
     });
 }
 
+pub(crate) async fn send_connect_asset_server_req_to_world_server(
+    asset_instance: &AssetInstance,
+    world_instance: &WorldInstance,
+) {
+    let world_addr = world_instance.http_addr().to_string();
+    let world_port = world_instance.http_port();
+    let world_last_heard = world_instance.last_heard();
+
+    let asset_addr = asset_instance.http_addr().to_string();
+    let asset_port = asset_instance.http_port();
+
+    Server::spawn(async move {
+        let request =
+            WorldConnectAssetServerRequest::new(region_server_secret(), &asset_addr, asset_port);
+
+        let host = "region";
+        let remote = "world";
+        http_server::log_util::send_req(host, remote, WorldConnectAssetServerRequest::name());
+        let response = HttpClient::send(&world_addr, world_port, request).await;
+        http_server::log_util::recv_res(host, remote, WorldConnectAssetServerResponse::name());
+
+        match response {
+            Ok(_) => {
+                let mut last_heard = world_last_heard.write().await;
+                *last_heard = Instant::now();
+            }
+            Err(err) => {
+                warn!(
+                    "from {:?}:{} - world connect asset server failure: {}",
+                    world_addr,
+                    world_port,
+                    err.to_string()
+                );
+            }
+        }
+    });
+}
+
 pub(crate) async fn send_connect_social_server_req_to_session_server(
     social_instance: &SocialInstance,
     session_instance: &SessionInstance,
@@ -330,7 +373,7 @@ pub(crate) async fn send_connect_social_server_req_to_session_server(
     // session server receives connection to social server
     Server::spawn(async move {
         let request = ConnectSocialServerRequest::new(
-            REGION_SERVER_SECRET,
+            region_server_secret(),
             &social_server_addr_1,
             social_server_port,
         );
@@ -378,7 +421,7 @@ pub(crate) async fn send_connect_session_server_req_to_social_server(
     // session server receives connection to social server
     Server::spawn(async move {
         let request = ConnectSessionServerRequest::new(
-            REGION_SERVER_SECRET,
+            region_server_secret(),
             &session_secret,
             &session_addr,
             session_port,
@@ -429,7 +472,7 @@ pub(crate) async fn send_disconnect_asset_instance_to_session_instance(
     let last_heard = session_instance.last_heard();
 
     Server::spawn(async move {
-        let request = DisconnectAssetServerRequest::new(REGION_SERVER_SECRET);
+        let request = DisconnectAssetServerRequest::new(region_server_secret());
 
         let host = "region";
         let remote = "session";
@@ -457,3 +500,38 @@ pub(crate) async fn send_disconnect_asset_instance_to_session_instance(
         }
     });
 }
+
+pub(crate) async fn send_disconnect_asset_instance_to_world_instance(
+    world_instance: &mut WorldInstance,
+) {
+    // send disconnect asset server message to world instance
+
+    let world_addr = world_instance.http_addr().to_string();
+    let world_port = world_instance.http_port();
+    let last_heard = world_instance.last_heard();
+
+    Server::spawn(async move {
+        let request = WorldDisconnectAssetServerRequest::new(region_server_secret());
+
+        let host = "region";
+        let remote = "world";
+        http_server::log_util::send_req(host, remote, WorldDisconnectAssetServerRequest::name());
+        let response = HttpClient::send(&world_addr, world_port, request).await;
+        http_server::log_util::recv_res(host, remote, WorldDisconnectAssetServerResponse::name());
+
+        match response {
+            Ok(_) => {
+                let mut last_heard = last_heard.write().await;
+                *last_heard = Instant::now();
+            }
+            Err(err) => {
+                warn!(
+                    "from {:?}:{} - world disconnect asset server failure: {}",
+                    world_addr,
+                    world_port,
+                    err.to_string()
+                );
+            }
+        }
+    });
+}
@@ -14,9 +14,11 @@ use crate::{
     },
     requests::{
         send_connect_asset_server_req_to_session_server,
+        send_connect_asset_server_req_to_world_server,
         send_connect_session_server_req_to_social_server,
         send_connect_social_server_req_to_session_server,
-        send_disconnect_asset_instance_to_session_instance, send_social_heartbeat_request,
+        send_disconnect_asset_instance_to_session_instance,
+        send_disconnect_asset_instance_to_world_instance, send_social_heartbeat_request,
     },
     session_instance::SessionInstance,
     social_instance::SocialInstance,
@@ -166,6 +168,14 @@ impl State {
                 session_instance.clear_asset_server();
             }
         }
+
+        for (_, world_instance) in self.world_instances.iter_mut() {
+            if world_instance.has_asset_server() {
+                send_disconnect_asset_instance_to_world_instance(world_instance).await;
+
+                world_instance.clear_asset_server();
+            }
+        }
     }
 
     pub async fn deregister_social_instance(&mut self) {
@@ -202,6 +212,15 @@ impl State {
         self.world_instances.values().next()
     }
 
+    pub fn get_world_server_from_instance_secret(
+        &self,
+        instance_secret: &str,
+    ) -> Option<&WorldInstance> {
+        self.world_instances
+            .values()
+            .find(|world_instance| world_instance.instance_secret() == instance_secret)
+    }
+
     pub async fn send_heartbeats(&mut self) {
         let now = Instant::now();
 
@@ -242,6 +261,22 @@ impl State {
         }
     }
 
+    pub async fn sync_asset_world_instances(&mut self) {
+        let Some(asset_instance) = self.asset_instance.as_ref() else {
+            return;
+        };
+
+        for (_, world_instance) in self.world_instances.iter_mut() {
+            if world_instance.has_asset_server() {
+                continue;
+            }
+
+            send_connect_asset_server_req_to_world_server(&asset_instance, world_instance).await;
+
+            world_instance.set_has_asset_server();
+        }
+    }
+
     pub async fn sync_social_session_instances(&mut self) {
         let Some(social_instance) = self.social_instance.as_mut() else {
             return;
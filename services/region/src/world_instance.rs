@@ -7,6 +7,7 @@ pub struct WorldInstance {
     http_addr: String,
     http_port: u16,
     last_heard: Arc<RwLock<Instant>>,
+    has_asset_server: bool,
 }
 
 impl WorldInstance {
@@ -16,6 +17,7 @@ impl WorldInstance {
             http_addr: http_addr.to_string(),
             http_port,
             last_heard: Arc::new(RwLock::new(Instant::now())),
+            has_asset_server: false,
         }
     }
 
@@ -38,4 +40,16 @@ impl WorldInstance {
     pub fn key(&self) -> (String, u16) {
         (self.http_addr.clone(), self.http_port)
     }
+
+    pub fn has_asset_server(&self) -> bool {
+        self.has_asset_server
+    }
+
+    pub(crate) fn set_has_asset_server(&mut self) {
+        self.has_asset_server = true;
+    }
+
+    pub fn clear_asset_server(&mut self) {
+        self.has_asset_server = false;
+    }
 }
@@ -3,8 +3,9 @@ use bevy_http_shared::Protocol;
 use crate::{
     ConnectAssetServerRequest, ConnectSocialServerRequest, DisconnectAssetServerRequest,
     DisconnectSocialServerRequest, HeartbeatRequest, IncomingUserRequest,
+    SocialPatchDirectMessagesRequest, SocialPatchFriendsRequest,
     SocialPatchGlobalChatMessagesRequest, SocialPatchMatchLobbiesRequest, SocialPatchUsersRequest,
-    SocialWorldConnectRequest, UserAssetIdRequest,
+    SocialWorldConnectRequest, SocialWorldSpectateRequest, UserAssetIdRequest,
 };
 
 pub fn protocol() -> Protocol {
@@ -22,7 +23,10 @@ pub fn protocol() -> Protocol {
     protocol.add_request::<SocialPatchUsersRequest>();
     protocol.add_request::<SocialPatchGlobalChatMessagesRequest>();
     protocol.add_request::<SocialPatchMatchLobbiesRequest>();
+    protocol.add_request::<SocialPatchFriendsRequest>();
+    protocol.add_request::<SocialPatchDirectMessagesRequest>();
     protocol.add_request::<SocialWorldConnectRequest>();
+    protocol.add_request::<SocialWorldSpectateRequest>();
 
     protocol
 }
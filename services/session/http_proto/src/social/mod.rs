@@ -1,9 +1,15 @@
+mod patch_direct_messages;
+mod patch_friends;
 mod patch_global_chat_messages;
 mod patch_match_lobbies;
 mod patch_users;
 mod world_connect;
+mod world_spectate;
 
+pub use patch_direct_messages::*;
+pub use patch_friends::*;
 pub use patch_global_chat_messages::*;
 pub use patch_match_lobbies::*;
 pub use patch_users::*;
 pub use world_connect::*;
+pub use world_spectate::*;
@@ -0,0 +1,66 @@
+use naia_serde::SerdeInternal as Serde;
+
+use bevy_http_shared::{ApiRequest, ApiResponse, Method};
+
+use auth_server_types::UserId;
+use social_server_types::Timestamp;
+
+// the first UserId in each patch is the user receiving it, who must be connected to the session server
+#[derive(Serde, PartialEq, Clone)]
+pub enum SocialDirectMessagePatch {
+    // recipient, sender, timestamp, message
+    Message(UserId, UserId, Timestamp, String),
+    // user, other user, blocked
+    Block(UserId, UserId, bool),
+}
+
+// Request
+#[derive(Serde, PartialEq, Clone)]
+pub struct SocialPatchDirectMessagesRequest {
+    social_secret: String,
+    patches: Vec<SocialDirectMessagePatch>,
+}
+
+impl SocialPatchDirectMessagesRequest {
+    pub fn new(social_secret: &str, patches: Vec<SocialDirectMessagePatch>) -> Self {
+        Self {
+            social_secret: social_secret.to_string(),
+            patches,
+        }
+    }
+
+    pub fn social_secret(&self) -> &str {
+        &self.social_secret
+    }
+
+    pub fn patches(&self) -> &Vec<SocialDirectMessagePatch> {
+        &self.patches
+    }
+}
+
+// Response
+#[derive(Serde, PartialEq, Clone)]
+pub struct SocialPatchDirectMessagesResponse;
+
+// Traits
+impl ApiRequest for SocialPatchDirectMessagesRequest {
+    type Response = SocialPatchDirectMessagesResponse;
+
+    fn name() -> &'static str {
+        "SocialPatchDirectMessagesRequest"
+    }
+
+    fn method() -> Method {
+        Method::Post
+    }
+
+    fn path() -> &'static str {
+        "social_patch_direct_messages"
+    }
+}
+
+impl ApiResponse for SocialPatchDirectMessagesResponse {
+    fn name() -> &'static str {
+        "SocialPatchDirectMessagesResponse"
+    }
+}
@@ -0,0 +1,67 @@
+use naia_serde::SerdeInternal as Serde;
+
+use bevy_http_shared::{ApiRequest, ApiResponse, Method};
+
+use auth_server_types::UserId;
+use social_server_types::UserPresence;
+
+// the first UserId in each patch is the user receiving it, who must be connected to the session server
+#[derive(Serde, PartialEq, Clone)]
+pub enum SocialFriendPatch {
+    // sent both when a friendship is made and when a friend's presence changes
+    Update(UserId, UserId, UserPresence),
+    Remove(UserId, UserId),
+    RequestReceived(UserId, UserId),
+    RequestRemoved(UserId, UserId),
+}
+
+// Request
+#[derive(Serde, PartialEq, Clone)]
+pub struct SocialPatchFriendsRequest {
+    social_secret: String,
+    patches: Vec<SocialFriendPatch>,
+}
+
+impl SocialPatchFriendsRequest {
+    pub fn new(social_secret: &str, patches: Vec<SocialFriendPatch>) -> Self {
+        Self {
+            social_secret: social_secret.to_string(),
+            patches,
+        }
+    }
+
+    pub fn social_secret(&self) -> &str {
+        &self.social_secret
+    }
+
+    pub fn patches(&self) -> &Vec<SocialFriendPatch> {
+        &self.patches
+    }
+}
+
+// Response
+#[derive(Serde, PartialEq, Clone)]
+pub struct SocialPatchFriendsResponse;
+
+// Traits
+impl ApiRequest for SocialPatchFriendsRequest {
+    type Response = SocialPatchFriendsResponse;
+
+    fn name() -> &'static str {
+        "SocialPatchFriendsRequest"
+    }
+
+    fn method() -> Method {
+        Method::Post
+    }
+
+    fn path() -> &'static str {
+        "social_patch_friends"
+    }
+}
+
+impl ApiResponse for SocialPatchFriendsResponse {
+    fn name() -> &'static str {
+        "SocialPatchFriendsResponse"
+    }
+}
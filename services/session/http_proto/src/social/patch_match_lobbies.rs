@@ -2,20 +2,30 @@ use naia_serde::SerdeInternal as Serde;
 
 use auth_server_types::UserId;
 use bevy_http_shared::{ApiRequest, ApiResponse, Method};
-use social_server_types::{LobbyId, MessageId, Timestamp};
+use social_server_types::{
+    LobbyId, LobbySettings, MatchOutcome, MatchUserResult, MessageId, Timestamp,
+};
 
 #[derive(Serde, PartialEq, Clone)]
 pub enum SocialLobbyPatch {
-    // lobby id, lobby name, owner user id
-    Create(LobbyId, String, UserId),
+    // lobby id, lobby name, owner user id, settings
+    Create(LobbyId, String, UserId, LobbySettings),
     // lobby id, joining user id
     Join(LobbyId, UserId),
     // leaving user id
     Leave(UserId),
+    // kicked user id
+    Kick(UserId),
+    // lobby id, invited user id
+    Invite(LobbyId, UserId),
+    // lobby id, new owner user id
+    OwnerChange(LobbyId, UserId),
     // message id, timestamp, user id, message
     Message(MessageId, Timestamp, UserId, String),
     // lobby id
     Start(LobbyId),
+    // lobby id, outcome, per-user results (empty when syncing a newly connected session server)
+    End(LobbyId, MatchOutcome, Vec<(UserId, MatchUserResult)>),
 }
 
 // Request
@@ -0,0 +1,80 @@
+use naia_serde::SerdeInternal as Serde;
+
+use auth_server_types::UserId;
+use bevy_http_shared::{ApiRequest, ApiResponse, Method};
+use social_server_types::LobbyId;
+
+// Request
+#[derive(Serde, PartialEq, Clone)]
+pub struct SocialWorldSpectateRequest {
+    social_secret: String,
+    world_instance_secret: String,
+    lobby_id: LobbyId,
+    user_id: UserId,
+    login_token: String,
+}
+
+impl SocialWorldSpectateRequest {
+    pub fn new(
+        social_secret: &str,
+        world_instance_secret: &str,
+        lobby_id: LobbyId,
+        user_id: UserId,
+        login_token: &str,
+    ) -> Self {
+        Self {
+            social_secret: social_secret.to_string(),
+            world_instance_secret: world_instance_secret.to_string(),
+            lobby_id,
+            user_id,
+            login_token: login_token.to_string(),
+        }
+    }
+
+    pub fn social_secret(&self) -> &str {
+        &self.social_secret
+    }
+
+    pub fn world_instance_secret(&self) -> &str {
+        &self.world_instance_secret
+    }
+
+    pub fn lobby_id(&self) -> LobbyId {
+        self.lobby_id
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn login_token(&self) -> &str {
+        &self.login_token
+    }
+}
+
+// Response
+#[derive(Serde, PartialEq, Clone)]
+pub struct SocialWorldSpectateResponse;
+
+// Traits
+impl ApiRequest for SocialWorldSpectateRequest {
+    type Response = SocialWorldSpectateResponse;
+
+    fn name() -> &'static str {
+        "SocialWorldSpectateRequest"
+    }
+
+    fn method() -> Method {
+        Method::Post
+    }
+
+    fn path() -> &'static str {
+        "social_world_spectate"
+    }
+}
+
+impl ApiResponse for SocialWorldSpectateResponse {
+    fn name() -> &'static str {
+        "SocialWorldSpectateResponse"
+    }
+}
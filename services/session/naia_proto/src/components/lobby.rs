@@ -2,13 +2,14 @@ use bevy_ecs::prelude::Component;
 
 use naia_bevy_shared::{EntityProperty, Property, Replicate, Serde};
 
-use social_server_types::LobbyId;
+use social_server_types::{LobbyAccess, LobbyId, LobbySettings, MatchOutcome};
 
 #[derive(Serde, Copy, Clone, PartialEq, Eq)]
 pub enum LobbyState {
     WaitingToStart,
     InProgress,
     Finished,
+    Abandoned,
 }
 
 #[derive(Component, Replicate)]
@@ -16,12 +17,40 @@ pub struct Lobby {
     pub id: Property<LobbyId>,
     pub owner_user_entity: EntityProperty,
     pub name: Property<String>,
+    pub settings: Property<LobbySettings>,
+    member_count: Property<u8>,
     state: Property<LobbyState>,
 }
 
 impl Lobby {
-    pub fn new(id: LobbyId, name: &str) -> Self {
-        Self::new_complete(id, name.to_string(), LobbyState::WaitingToStart)
+    pub fn new(id: LobbyId, name: &str, settings: LobbySettings) -> Self {
+        Self::new_complete(
+            id,
+            name.to_string(),
+            settings,
+            0,
+            LobbyState::WaitingToStart,
+        )
+    }
+
+    pub fn member_count(&self) -> u8 {
+        *self.member_count
+    }
+
+    pub fn set_member_count(&mut self, member_count: u8) {
+        *self.member_count = member_count;
+    }
+
+    pub fn is_full(&self) -> bool {
+        *self.member_count >= self.settings.max_players()
+    }
+
+    pub fn requires_password(&self) -> bool {
+        self.settings.access() == LobbyAccess::Password
+    }
+
+    pub fn is_invite_only(&self) -> bool {
+        self.settings.access() == LobbyAccess::InviteOnly
     }
 
     pub fn is_waiting_to_start(&self) -> bool {
@@ -36,10 +65,25 @@ impl Lobby {
         *self.state == LobbyState::Finished
     }
 
+    pub fn is_abandoned(&self) -> bool {
+        *self.state == LobbyState::Abandoned
+    }
+
+    pub fn can_start(&self) -> bool {
+        self.is_waiting_to_start() || self.is_finished()
+    }
+
     pub fn start(&mut self) {
-        if !self.is_waiting_to_start() {
+        if !self.can_start() {
             panic!("Lobby is not waiting to start");
         }
         *self.state = LobbyState::InProgress;
     }
+
+    pub fn end(&mut self, outcome: MatchOutcome) {
+        *self.state = match outcome {
+            MatchOutcome::Finished => LobbyState::Finished,
+            MatchOutcome::Abandoned => LobbyState::Abandoned,
+        };
+    }
 }
@@ -12,11 +12,11 @@ cfg_if! {
     }
 }
 
-pub use naia_bevy_shared::Protocol;
+pub use naia_bevy_shared::{LinkConditionerConfig, Protocol};
 
 pub mod channels;
 pub mod components;
 pub mod messages;
 
 mod protocol;
-pub use protocol::protocol;
+pub use protocol::{conditioned_protocol, protocol};
@@ -17,8 +17,12 @@ pub use load_asset_with_data::LoadAssetWithData;
 
 mod social;
 pub use social::{
-    GlobalChatSendMessage, MatchLobbyCreate, MatchLobbyGameStart, MatchLobbyJoin, MatchLobbyLeave,
-    MatchLobbySendMessage,
+    ChatHistoryEntry, ChatHistoryRequest, DirectMessageReceived, DirectMessageSend,
+    FriendActionRequest, FriendRemoved, FriendRequestReceived, FriendRequestRemoved, FriendUpdated,
+    GlobalChatSendMessage, MatchLobbyCreate, MatchLobbyGameEnded, MatchLobbyGameStart,
+    MatchLobbyInvite, MatchLobbyInvited, MatchLobbyJoin, MatchLobbyKick, MatchLobbyKicked,
+    MatchLobbyLeave, MatchLobbyLeaveSpectate, MatchLobbySendMessage, MatchLobbySpectate, UserBlock,
+    UserBlocked, UserSetAway,
 };
 
 // Plugin
@@ -0,0 +1,35 @@
+use naia_bevy_shared::{EntityProperty, Message};
+
+use social_server_types::{LobbyId, MessageId, Timestamp};
+
+// one older chat message, sent only to the user who requested the history
+#[derive(Message)]
+pub struct ChatHistoryEntry {
+    // None for global chat
+    pub lobby_id: Option<LobbyId>,
+    pub message_id: MessageId,
+    pub timestamp: Timestamp,
+    pub owner_user_entity: EntityProperty,
+    pub message: String,
+    // whether there are even older messages to request
+    pub has_more: bool,
+}
+
+impl ChatHistoryEntry {
+    pub fn new(
+        lobby_id: Option<LobbyId>,
+        message_id: MessageId,
+        timestamp: Timestamp,
+        message: &str,
+        has_more: bool,
+    ) -> Self {
+        Self {
+            lobby_id,
+            message_id,
+            timestamp,
+            owner_user_entity: EntityProperty::new(),
+            message: message.to_string(),
+            has_more,
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use naia_bevy_shared::Message;
+
+use social_server_types::MessageId;
+
+// asks for a page of older chat messages, in the user's current lobby or in global chat
+#[derive(Message)]
+pub struct ChatHistoryRequest {
+    pub lobby_chat: bool,
+    // None for the most recent page
+    pub before_message_id: Option<MessageId>,
+}
+
+impl ChatHistoryRequest {
+    pub fn new(lobby_chat: bool, before_message_id: Option<MessageId>) -> Self {
+        Self {
+            lobby_chat,
+            before_message_id,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use naia_bevy_shared::{EntityProperty, Message};
+
+use social_server_types::Timestamp;
+
+// one message of the conversation with the user behind user_entity,
+// also echoed back to the sender once the social server has accepted it
+#[derive(Message)]
+pub struct DirectMessageReceived {
+    pub user_entity: EntityProperty,
+    // whether the receiving user wrote this message
+    pub from_self: bool,
+    pub timestamp: Timestamp,
+    pub message: String,
+}
+
+impl DirectMessageReceived {
+    pub fn new(from_self: bool, timestamp: Timestamp, message: &str) -> Self {
+        Self {
+            user_entity: EntityProperty::new(),
+            from_self,
+            timestamp,
+            message: message.to_string(),
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use naia_bevy_shared::{EntityProperty, Message};
+
+// a whisper to the user behind user_entity
+#[derive(Message)]
+pub struct DirectMessageSend {
+    pub user_entity: EntityProperty,
+    pub message: String,
+}
+
+impl DirectMessageSend {
+    pub fn new(message: &str) -> Self {
+        Self {
+            user_entity: EntityProperty::new(),
+            message: message.to_string(),
+        }
+    }
+}
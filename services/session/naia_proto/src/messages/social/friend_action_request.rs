@@ -0,0 +1,19 @@
+use naia_bevy_shared::{EntityProperty, Message};
+
+use social_server_types::FriendAction;
+
+// sends, answers or removes a friendship with the user behind user_entity
+#[derive(Message)]
+pub struct FriendActionRequest {
+    pub user_entity: EntityProperty,
+    pub action: FriendAction,
+}
+
+impl FriendActionRequest {
+    pub fn new(action: FriendAction) -> Self {
+        Self {
+            user_entity: EntityProperty::new(),
+            action,
+        }
+    }
+}
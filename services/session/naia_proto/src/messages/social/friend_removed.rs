@@ -0,0 +1,14 @@
+use naia_bevy_shared::{EntityProperty, Message};
+
+#[derive(Message)]
+pub struct FriendRemoved {
+    pub user_entity: EntityProperty,
+}
+
+impl FriendRemoved {
+    pub fn new() -> Self {
+        Self {
+            user_entity: EntityProperty::new(),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use naia_bevy_shared::{EntityProperty, Message};
+
+#[derive(Message)]
+pub struct FriendRequestReceived {
+    pub user_entity: EntityProperty,
+}
+
+impl FriendRequestReceived {
+    pub fn new() -> Self {
+        Self {
+            user_entity: EntityProperty::new(),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use naia_bevy_shared::{EntityProperty, Message};
+
+use social_server_types::UserPresence;
+
+// sent only to the friends of a user, when the friendship is made or the user's presence changes
+#[derive(Message)]
+pub struct FriendUpdated {
+    pub user_entity: EntityProperty,
+    pub presence: UserPresence,
+}
+
+impl FriendUpdated {
+    pub fn new(presence: UserPresence) -> Self {
+        Self {
+            user_entity: EntityProperty::new(),
+            presence,
+        }
+    }
+}
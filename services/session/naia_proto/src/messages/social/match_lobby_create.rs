@@ -1,14 +1,20 @@
 use naia_bevy_shared::Message;
 
+use social_server_types::LobbySettings;
+
 #[derive(Message)]
 pub struct MatchLobbyCreate {
     pub match_name: String,
+    pub settings: LobbySettings,
+    pub password: Option<String>,
 }
 
 impl MatchLobbyCreate {
-    pub fn new(match_name: &str) -> Self {
+    pub fn new(match_name: &str, settings: LobbySettings, password: Option<&str>) -> Self {
         Self {
             match_name: match_name.to_string(),
+            settings,
+            password: password.map(|password| password.to_string()),
         }
     }
 }
@@ -0,0 +1,17 @@
+use naia_bevy_shared::Message;
+
+use social_server_types::{MatchOutcome, MatchUserResult};
+
+// sent to each player of a match once it has ended, returning them to the lobby
+#[derive(Message)]
+pub struct MatchLobbyGameEnded {
+    pub outcome: MatchOutcome,
+    // None if the world server had no record of this player
+    pub result: Option<MatchUserResult>,
+}
+
+impl MatchLobbyGameEnded {
+    pub fn new(outcome: MatchOutcome, result: Option<MatchUserResult>) -> Self {
+        Self { outcome, result }
+    }
+}
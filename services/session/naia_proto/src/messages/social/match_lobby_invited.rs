@@ -0,0 +1,15 @@
+use naia_bevy_shared::Message;
+
+use social_server_types::LobbyId;
+
+// sent to a user who has been invited to a lobby by its owner
+#[derive(Message)]
+pub struct MatchLobbyInvited {
+    pub lobby_id: LobbyId,
+}
+
+impl MatchLobbyInvited {
+    pub fn new(lobby_id: LobbyId) -> Self {
+        Self { lobby_id }
+    }
+}
@@ -5,10 +5,14 @@ use social_server_types::LobbyId;
 #[derive(Message)]
 pub struct MatchLobbyJoin {
     pub match_id: LobbyId,
+    pub password: Option<String>,
 }
 
 impl MatchLobbyJoin {
-    pub fn new(match_id: LobbyId) -> Self {
-        Self { match_id }
+    pub fn new(match_id: LobbyId, password: Option<&str>) -> Self {
+        Self {
+            match_id,
+            password: password.map(|password| password.to_string()),
+        }
     }
 }
@@ -0,0 +1,14 @@
+use naia_bevy_shared::{EntityProperty, Message};
+
+#[derive(Message)]
+pub struct MatchLobbyKick {
+    pub user_entity: EntityProperty,
+}
+
+impl MatchLobbyKick {
+    pub fn new() -> Self {
+        Self {
+            user_entity: EntityProperty::new(),
+        }
+    }
+}
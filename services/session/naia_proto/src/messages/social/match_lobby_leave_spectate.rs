@@ -0,0 +1,5 @@
+use naia_bevy_shared::Message;
+
+// stops watching the match being spectated
+#[derive(Message)]
+pub struct MatchLobbyLeaveSpectate;
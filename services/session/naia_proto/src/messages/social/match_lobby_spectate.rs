@@ -0,0 +1,15 @@
+use naia_bevy_shared::Message;
+
+use social_server_types::LobbyId;
+
+// asks to watch a match that is already in progress
+#[derive(Message)]
+pub struct MatchLobbySpectate {
+    pub match_id: LobbyId,
+}
+
+impl MatchLobbySpectate {
+    pub fn new(match_id: LobbyId) -> Self {
+        Self { match_id }
+    }
+}
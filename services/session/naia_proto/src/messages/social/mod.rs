@@ -1,18 +1,56 @@
 use naia_bevy_shared::{Protocol, ProtocolPlugin};
 
+mod chat_history_entry;
+mod chat_history_request;
+mod direct_message_received;
+mod direct_message_send;
+mod friend_action_request;
+mod friend_removed;
+mod friend_request_received;
+mod friend_request_removed;
+mod friend_updated;
 mod global_chat_send_message;
 mod match_lobby_create;
+mod match_lobby_game_ended;
 mod match_lobby_game_start;
+mod match_lobby_invite;
+mod match_lobby_invited;
 mod match_lobby_join;
+mod match_lobby_kick;
+mod match_lobby_kicked;
 mod match_lobby_leave;
+mod match_lobby_leave_spectate;
 mod match_lobby_send_message;
+mod match_lobby_spectate;
+mod user_block;
+mod user_blocked;
+mod user_set_away;
 
+pub use chat_history_entry::ChatHistoryEntry;
+pub use chat_history_request::ChatHistoryRequest;
+pub use direct_message_received::DirectMessageReceived;
+pub use direct_message_send::DirectMessageSend;
+pub use friend_action_request::FriendActionRequest;
+pub use friend_removed::FriendRemoved;
+pub use friend_request_received::FriendRequestReceived;
+pub use friend_request_removed::FriendRequestRemoved;
+pub use friend_updated::FriendUpdated;
 pub use global_chat_send_message::GlobalChatSendMessage;
 pub use match_lobby_create::MatchLobbyCreate;
+pub use match_lobby_game_ended::MatchLobbyGameEnded;
 pub use match_lobby_game_start::MatchLobbyGameStart;
+pub use match_lobby_invite::MatchLobbyInvite;
+pub use match_lobby_invited::MatchLobbyInvited;
 pub use match_lobby_join::MatchLobbyJoin;
+pub use match_lobby_kick::MatchLobbyKick;
+pub use match_lobby_kicked::MatchLobbyKicked;
 pub use match_lobby_leave::MatchLobbyLeave;
+pub use match_lobby_leave_spectate::MatchLobbyLeaveSpectate;
 pub use match_lobby_send_message::MatchLobbySendMessage;
+pub use match_lobby_spectate::MatchLobbySpectate;
+pub use user_block::UserBlock;
+pub use user_blocked::UserBlocked;
+pub use user_set_away::UserSetAway;
 
 // Plugin
 pub struct SocialMessagesPlugin;
@@ -25,6 +63,25 @@ impl ProtocolPlugin for SocialMessagesPlugin {
             .add_message::<MatchLobbyJoin>()
             .add_message::<MatchLobbyLeave>()
             .add_message::<MatchLobbySendMessage>()
-            .add_message::<MatchLobbyGameStart>();
+            .add_message::<MatchLobbyGameStart>()
+            .add_message::<MatchLobbyGameEnded>()
+            .add_message::<MatchLobbyKick>()
+            .add_message::<MatchLobbyKicked>()
+            .add_message::<MatchLobbyInvite>()
+            .add_message::<MatchLobbyInvited>()
+            .add_message::<MatchLobbySpectate>()
+            .add_message::<MatchLobbyLeaveSpectate>()
+            .add_message::<ChatHistoryRequest>()
+            .add_message::<ChatHistoryEntry>()
+            .add_message::<FriendActionRequest>()
+            .add_message::<UserSetAway>()
+            .add_message::<FriendUpdated>()
+            .add_message::<FriendRemoved>()
+            .add_message::<FriendRequestReceived>()
+            .add_message::<FriendRequestRemoved>()
+            .add_message::<DirectMessageSend>()
+            .add_message::<UserBlock>()
+            .add_message::<DirectMessageReceived>()
+            .add_message::<UserBlocked>();
     }
 }
@@ -0,0 +1,17 @@
+use naia_bevy_shared::{EntityProperty, Message};
+
+// blocks or unblocks the user behind user_entity
+#[derive(Message)]
+pub struct UserBlock {
+    pub user_entity: EntityProperty,
+    pub blocked: bool,
+}
+
+impl UserBlock {
+    pub fn new(blocked: bool) -> Self {
+        Self {
+            user_entity: EntityProperty::new(),
+            blocked,
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use naia_bevy_shared::{EntityProperty, Message};
+
+// the receiving user's block list changed, sent for every blocked user on connect
+#[derive(Message)]
+pub struct UserBlocked {
+    pub user_entity: EntityProperty,
+    pub blocked: bool,
+}
+
+impl UserBlocked {
+    pub fn new(blocked: bool) -> Self {
+        Self {
+            user_entity: EntityProperty::new(),
+            blocked,
+        }
+    }
+}
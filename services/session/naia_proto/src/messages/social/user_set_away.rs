@@ -0,0 +1,12 @@
+use naia_bevy_shared::Message;
+
+#[derive(Message)]
+pub struct UserSetAway {
+    pub away: bool,
+}
+
+impl UserSetAway {
+    pub fn new(away: bool) -> Self {
+        Self { away }
+    }
+}
@@ -1,11 +1,17 @@
 use std::time::Duration;
 
-use naia_bevy_shared::Protocol;
+use naia_bevy_shared::{LinkConditionerConfig, Protocol};
 
 use crate::{channels::ChannelsPlugin, components::ComponentsPlugin, messages::MessagesPlugin};
 
 // Protocol Build
 pub fn protocol() -> Protocol {
+    conditioned_protocol(None)
+}
+
+// `link_conditioner` simulates latency, jitter & loss on incoming packets, overriding the
+// build's default conditioning
+pub fn conditioned_protocol(link_conditioner: Option<LinkConditionerConfig>) -> Protocol {
     let mut builder = Protocol::builder();
 
     // Config
@@ -20,10 +26,13 @@ pub fn protocol() -> Protocol {
         // Components
         .add_plugin(ComponentsPlugin);
 
+    if let Some(link_conditioner) = link_conditioner {
+        builder.link_condition(link_conditioner);
+        return builder.build();
+    }
+
     cfg_if::cfg_if! {
         if #[cfg(feature = "local")]{
-            use naia_bevy_shared::LinkConditionerConfig;
-
             builder.link_condition(LinkConditionerConfig::very_good_condition());
         }
         else {}
@@ -24,6 +24,10 @@ impl UiAssetCatalog {
         AssetId::from_str("pup52m").unwrap()
     }
 
+    pub fn game_match_results_ui() -> AssetId {
+        AssetId::from_str("r7m2xk").unwrap()
+    }
+
     pub fn game_global_chat_ui() -> AssetId {
         AssetId::from_str("ngffab").unwrap()
     }
@@ -44,6 +48,18 @@ impl UiAssetCatalog {
         AssetId::from_str("8ywqfp").unwrap()
     }
 
+    pub fn game_friends_ui() -> AssetId {
+        AssetId::from_str("f7kw3d").unwrap()
+    }
+
+    pub fn game_friend_list_item_ui() -> AssetId {
+        AssetId::from_str("h2nv8q").unwrap()
+    }
+
+    pub fn game_conversation_ui() -> AssetId {
+        AssetId::from_str("p4dm7c").unwrap()
+    }
+
     pub fn text_icon() -> AssetId {
         AssetId::from_str("34mvvk").unwrap()
     }
@@ -66,11 +82,15 @@ pub(crate) fn user_load_default_assets(
         UiAssetCatalog::game_host_match_ui(),
         UiAssetCatalog::game_join_match_ui(),
         UiAssetCatalog::game_join_match_lobby_list_item_ui(),
+        UiAssetCatalog::game_match_results_ui(),
         UiAssetCatalog::game_global_chat_ui(),
         UiAssetCatalog::game_global_chat_day_divider_item_ui(),
         UiAssetCatalog::game_global_chat_username_and_message_item_ui(),
         UiAssetCatalog::game_global_chat_message_item_ui(),
         UiAssetCatalog::game_user_list_item_ui(),
+        UiAssetCatalog::game_friends_ui(),
+        UiAssetCatalog::game_friend_list_item_ui(),
+        UiAssetCatalog::game_conversation_ui(),
     ]
     .iter()
     {
@@ -5,11 +5,14 @@ use logging::info;
 
 use bevy_http_server::HttpServer;
 
-use config::{SELF_BINDING_ADDR, SESSION_SERVER_HTTP_PORT};
+use config::{self_binding_addr, session_server_http_port};
 
 pub fn system(mut server: ResMut<HttpServer>) {
     info!("Session HTTP Server starting up");
 
-    let socket_addr = SocketAddr::new(SELF_BINDING_ADDR.parse().unwrap(), SESSION_SERVER_HTTP_PORT);
+    let socket_addr = SocketAddr::new(
+        self_binding_addr().parse().unwrap(),
+        session_server_http_port(),
+    );
     server.listen(socket_addr);
 }
@@ -0,0 +1,57 @@
+mod asset;
+mod http;
+mod region;
+mod session_instance;
+mod social;
+mod user;
+mod world;
+
+cfg_if::cfg_if!(
+    if #[cfg(feature = "odst")] {
+        mod odst;
+    }
+);
+
+//
+
+use std::time::Duration;
+
+use bevy_app::{App, ScheduleRunnerPlugin};
+
+use crate::{
+    asset::AssetPlugin, http::HttpPlugin, region::RegionPlugin, session_instance::SessionInstance,
+    social::SocialPlugin, user::UserPlugin, world::WorldPlugin,
+};
+
+// builds the app without running it, `App::run()` blocks the calling thread for good
+pub fn build_app() -> App {
+    let instance_secret = random::generate_random_string(16);
+    let registration_resend_rate = Duration::from_secs(5);
+    let region_server_disconnect_timeout = Duration::from_secs(61);
+
+    // Build App
+    let mut app = App::default();
+
+    cfg_if::cfg_if!(
+        if #[cfg(feature = "odst")] {
+            app.add_plugins(odst::OdstPlugin);
+        }
+    );
+
+    app
+        // Plugins
+        .add_plugins(ScheduleRunnerPlugin::run_loop(Duration::from_millis(5)))
+        .add_plugins(RegionPlugin::new(
+            registration_resend_rate,
+            region_server_disconnect_timeout,
+        ))
+        .add_plugins(HttpPlugin)
+        .add_plugins(SocialPlugin)
+        .add_plugins(WorldPlugin)
+        .add_plugins(AssetPlugin)
+        .add_plugins(UserPlugin)
+        // Resources
+        .insert_resource(SessionInstance::new(&instance_secret));
+
+    app
+}
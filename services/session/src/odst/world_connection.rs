@@ -5,7 +5,7 @@ use naia_bevy_server::{Server, UserKey};
 use auth_server_types::UserId;
 use bevy_http_client::HttpClient;
 use session_server_naia_proto::{channels::PrimaryChannel, messages::WorldConnectToken};
-use social_server_types::LobbyId;
+use social_server_types::{LobbyId, LobbySettings};
 
 use crate::{social::SocialManager, user::UserManager, world::WorldManager};
 
@@ -35,9 +35,12 @@ pub(crate) fn handle_world_connection(
         &user_id,
     );
 
-    world_manager.world_set_user_connected(&user_id, user_key, world_instance_secret);
-    // store world instance secret with user key
-    user_manager.user_set_world_connected(user_key, world_instance_secret);
+    let user_id_u64: u64 = user_id.into();
+    let token = format!("odst:{}", user_id_u64);
+
+    world_manager.world_set_user_connected(&user_id, world_instance_secret);
+    // store world instance secret with user
+    user_manager.user_set_world_connected(&user_id, world_instance_secret, &token);
 
     let user_entity = user_manager.get_user_entity(&user_id).unwrap();
     let lobby_room_key = social_manager
@@ -48,8 +51,6 @@ pub(crate) fn handle_world_connection(
         .room_mut(&lobby_room_key)
         .add_entity(&user_entity);
 
-    let user_id: u64 = user_id.into();
-    let token = format!("odst:{}", user_id);
     let token = WorldConnectToken::new(&token);
     naia_server.send_message::<PrimaryChannel, WorldConnectToken>(&user_key, &token);
 }
@@ -88,5 +89,6 @@ fn setup_lobby(
         lobby_id,
         match_name,
         user_id,
+        LobbySettings::default(),
     );
 }
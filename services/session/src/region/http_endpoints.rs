@@ -2,7 +2,7 @@ use bevy_ecs::change_detection::ResMut;
 
 use bevy_http_client::{log_util, ApiRequest, ApiResponse, HttpClient, ResponseError};
 use bevy_http_server::HttpServer;
-use config::REGION_SERVER_SECRET;
+use config::region_server_secret;
 use logging::warn;
 
 use region_server_http_proto::SessionRegisterInstanceResponse;
@@ -43,7 +43,7 @@ pub fn recv_register_instance_response(
 
 pub fn recv_heartbeat_request(mut region: ResMut<RegionManager>, mut server: ResMut<HttpServer>) {
     while let Some((_addr, request, response_key)) = server.receive::<HeartbeatRequest>() {
-        if request.region_secret() != REGION_SERVER_SECRET {
+        if request.region_secret() != region_server_secret() {
             warn!("invalid request secret");
             server.respond(response_key, Err(ResponseError::Unauthenticated));
             continue;
@@ -67,7 +67,7 @@ pub fn recv_heartbeat_request(mut region: ResMut<RegionManager>, mut server: Res
 
 pub fn recv_login_request(mut user_manager: ResMut<UserManager>, mut server: ResMut<HttpServer>) {
     while let Some((_addr, request, response_key)) = server.receive::<IncomingUserRequest>() {
-        if request.region_secret() != REGION_SERVER_SECRET {
+        if request.region_secret() != region_server_secret() {
             warn!("invalid request secret");
             server.respond(response_key, Err(ResponseError::Unauthenticated));
             continue;
@@ -95,7 +95,7 @@ pub fn recv_connect_asset_server_request(
     mut server: ResMut<HttpServer>,
 ) {
     while let Some((_addr, request, response_key)) = server.receive::<ConnectAssetServerRequest>() {
-        if request.region_secret() != REGION_SERVER_SECRET {
+        if request.region_secret() != region_server_secret() {
             warn!("invalid request secret");
             server.respond(response_key, Err(ResponseError::Unauthenticated));
             continue;
@@ -128,7 +128,7 @@ pub fn recv_disconnect_asset_server_request(
     while let Some((_addr, request, response_key)) =
         server.receive::<DisconnectAssetServerRequest>()
     {
-        if request.region_secret() != REGION_SERVER_SECRET {
+        if request.region_secret() != region_server_secret() {
             warn!("invalid request secret");
             server.respond(response_key, Err(ResponseError::Unauthenticated));
             continue;
@@ -160,7 +160,7 @@ pub fn recv_connect_social_server_request(
 ) {
     while let Some((_addr, request, response_key)) = server.receive::<ConnectSocialServerRequest>()
     {
-        if request.region_secret() != REGION_SERVER_SECRET {
+        if request.region_secret() != region_server_secret() {
             warn!("invalid request secret");
             server.respond(response_key, Err(ResponseError::Unauthenticated));
             continue;
@@ -193,7 +193,7 @@ pub fn recv_disconnect_social_server_request(
     while let Some((_addr, request, response_key)) =
         server.receive::<DisconnectSocialServerRequest>()
     {
-        if request.region_secret() != REGION_SERVER_SECRET {
+        if request.region_secret() != region_server_secret() {
             warn!("invalid request secret");
             server.respond(response_key, Err(ResponseError::Unauthenticated));
             continue;
@@ -2,8 +2,8 @@ use bevy_ecs::{change_detection::ResMut, system::Res};
 
 use bevy_http_client::{log_util, ApiRequest, HttpClient};
 use config::{
-    REGION_SERVER_PORT, REGION_SERVER_RECV_ADDR, SESSION_SERVER_GLOBAL_SECRET,
-    SESSION_SERVER_HTTP_PORT, SESSION_SERVER_RECV_ADDR,
+    region_server_port, region_server_recv_addr, session_server_global_secret,
+    session_server_http_port, session_server_recv_addr,
 };
 use logging::info;
 
@@ -31,16 +31,16 @@ pub fn send_register_instance_request(
 
     //info!("Sending request to register instance with region server ..");
     let request = SessionRegisterInstanceRequest::new(
-        SESSION_SERVER_GLOBAL_SECRET,
+        session_server_global_secret(),
         session_instance.instance_secret(),
-        SESSION_SERVER_RECV_ADDR,
-        SESSION_SERVER_HTTP_PORT,
+        session_server_recv_addr(),
+        session_server_http_port(),
     );
 
     let host = "session";
     let remote = "region";
     log_util::send_req(host, remote, SessionRegisterInstanceRequest::name());
-    let key = http_client.send(REGION_SERVER_RECV_ADDR, REGION_SERVER_PORT, request);
+    let key = http_client.send(region_server_recv_addr(), region_server_port(), request);
 
     region.set_register_instance_response_key(key);
     region.sent_to_region_server();
@@ -7,10 +7,14 @@ use naia_bevy_server::{CommandsExt, RoomKey, Server, UserKey};
 use auth_server_types::UserId;
 use bevy_http_client::{ApiRequest, ApiResponse, HttpClient, ResponseKey};
 use logging::warn;
-use session_server_naia_proto::components::{ChatMessage, ChatMessageGlobal, ChatMessageLocal};
+use session_server_naia_proto::{
+    channels::PrimaryChannel,
+    components::{ChatMessage, ChatMessageGlobal, ChatMessageLocal},
+    messages::ChatHistoryEntry,
+};
 use social_server_http_proto::{
-    GlobalChatSendMessageRequest, GlobalChatSendMessageResponse, MatchLobbySendMessageRequest,
-    MatchLobbySendMessageResponse,
+    ChatHistoryRequest, ChatHistoryResponse, GlobalChatSendMessageRequest,
+    GlobalChatSendMessageResponse, MatchLobbySendMessageRequest, MatchLobbySendMessageResponse,
 };
 use social_server_types::{LobbyId, MessageId, Timestamp};
 
@@ -18,14 +22,19 @@ use crate::{
     session_instance::SessionInstance, social::lobby_manager::LobbyManager, user::UserManager,
 };
 
+// how many older messages are sent per history request
+const CHAT_HISTORY_PAGE_SIZE: u8 = 25;
+
 enum ChatMessageReqQueued {
     GlobalChatSendMessage(UserKey, String),
     LobbyChatSendMessage(UserKey, String),
+    ChatHistory(UserKey, bool, Option<MessageId>),
 }
 
 enum ChatMessageReqInFlight {
     GlobalChatSendMessage(UserId, String, ResponseKey<GlobalChatSendMessageResponse>),
     LobbyChatSendMessage(UserId, String, ResponseKey<MatchLobbySendMessageResponse>),
+    ChatHistory(UserKey, Option<LobbyId>, ResponseKey<ChatHistoryResponse>),
 }
 
 pub(crate) struct ChatMessageManager {
@@ -113,6 +122,17 @@ impl ChatMessageManager {
                         &message,
                     );
                 }
+                ChatMessageReqQueued::ChatHistory(user_key, lobby_chat, before_message_id) => {
+                    self.send_chat_history_request(
+                        http_client,
+                        user_manager,
+                        social_server_url.as_ref(),
+                        session_instance,
+                        &user_key,
+                        lobby_chat,
+                        before_message_id,
+                    );
+                }
             }
         }
     }
@@ -219,6 +239,40 @@ impl ChatMessageManager {
                         continuing_requests.push(req);
                     }
                 }
+                ChatMessageReqInFlight::ChatHistory(user_key, lobby_id, response_key) => {
+                    if let Some(response_result) = http_client.recv(&response_key) {
+                        let host = "session";
+                        let remote = "social";
+                        bevy_http_client::log_util::recv_res(
+                            host,
+                            remote,
+                            ChatHistoryResponse::name(),
+                        );
+
+                        match response_result {
+                            Ok(response) => {
+                                self.send_chat_history_entries(
+                                    commands,
+                                    naia_server,
+                                    http_client,
+                                    user_manager,
+                                    main_menu_room_key,
+                                    user_key,
+                                    *lobby_id,
+                                    &response,
+                                );
+                            }
+                            Err(e) => {
+                                warn!(
+                                    "error receiving chat history response from social server: {:?}",
+                                    e.to_string()
+                                );
+                            }
+                        }
+                    } else {
+                        continuing_requests.push(req);
+                    }
+                }
             }
         }
 
@@ -321,6 +375,106 @@ impl ChatMessageManager {
         return;
     }
 
+    pub(crate) fn send_chat_history_request(
+        &mut self,
+        http_client: &mut HttpClient,
+        user_manager: &UserManager,
+        social_server_url: Option<&(String, u16)>,
+        session_instance: &SessionInstance,
+        requesting_user_key: &UserKey,
+        lobby_chat: bool,
+        before_message_id: Option<MessageId>,
+    ) {
+        let Some(requesting_user_id) = user_manager.user_key_to_id(requesting_user_key) else {
+            warn!("User not found: {:?}", requesting_user_key);
+            return;
+        };
+
+        let lobby_id = if lobby_chat {
+            let Some(lobby_id) = user_manager.get_user_lobby_id(&requesting_user_id) else {
+                warn!(
+                    "lobby chat history requested by user not in a lobby: {:?}",
+                    requesting_user_id
+                );
+                return;
+            };
+            Some(lobby_id)
+        } else {
+            None
+        };
+
+        let Some((social_server_addr, social_server_port)) = social_server_url else {
+            warn!("received chat history request but no social server is available!");
+
+            self.queued_requests.push(ChatMessageReqQueued::ChatHistory(
+                *requesting_user_key,
+                lobby_chat,
+                before_message_id,
+            ));
+
+            return;
+        };
+
+        let request = ChatHistoryRequest::new(
+            session_instance.instance_secret(),
+            requesting_user_id,
+            lobby_id,
+            before_message_id,
+            CHAT_HISTORY_PAGE_SIZE,
+        );
+
+        let host = "session";
+        let remote = "social";
+        bevy_http_client::log_util::send_req(host, remote, ChatHistoryRequest::name());
+        let response_key = http_client.send(social_server_addr, *social_server_port, request);
+
+        self.in_flight_requests
+            .push(ChatMessageReqInFlight::ChatHistory(
+                *requesting_user_key,
+                lobby_id,
+                response_key,
+            ));
+    }
+
+    // history is sent only to the requesting user, as messages rather than replicated entities
+    fn send_chat_history_entries(
+        &mut self,
+        commands: &mut Commands,
+        naia_server: &mut Server,
+        http_client: &mut HttpClient,
+        user_manager: &mut UserManager,
+        main_menu_room_key: &RoomKey,
+        requesting_user_key: &UserKey,
+        lobby_id: Option<LobbyId>,
+        response: &ChatHistoryResponse,
+    ) {
+        if user_manager.user_key_to_id(requesting_user_key).is_none() {
+            // user disconnected while the request was in flight
+            return;
+        }
+
+        for (message_id, timestamp, sending_user_id, message) in response.messages() {
+            let user_entity = user_manager.get_or_init_user_entity(
+                commands,
+                naia_server,
+                http_client,
+                main_menu_room_key,
+                sending_user_id,
+            );
+
+            let mut entry = ChatHistoryEntry::new(
+                lobby_id,
+                *message_id,
+                *timestamp,
+                message,
+                response.has_more(),
+            );
+            entry.owner_user_entity.set(naia_server, &user_entity);
+
+            naia_server.send_message::<PrimaryChannel, _>(requesting_user_key, &entry);
+        }
+    }
+
     fn log_global_chat_message(
         &mut self,
         commands: &mut Commands,
@@ -0,0 +1,325 @@
+use bevy_ecs::system::Commands;
+
+use naia_bevy_server::{RoomKey, Server, UserKey};
+
+use auth_server_types::UserId;
+use bevy_http_client::{ApiRequest, ApiResponse, HttpClient, ResponseKey};
+use logging::{info, warn};
+use session_server_http_proto::SocialDirectMessagePatch;
+use session_server_naia_proto::{
+    channels::PrimaryChannel,
+    messages::{DirectMessageReceived, UserBlocked},
+};
+use social_server_http_proto::{
+    DirectMessageSendRequest, DirectMessageSendResponse, UserBlockRequest, UserBlockResponse,
+};
+
+use crate::{session_instance::SessionInstance, user::UserManager};
+
+enum DirectMessageReqQueued {
+    // sending user, recipient, message
+    Send(UserKey, UserId, String),
+    // acting user, other user, blocked
+    Block(UserKey, UserId, bool),
+}
+
+enum DirectMessageReqInFlight {
+    // sending user, recipient
+    Send(UserKey, UserId, ResponseKey<DirectMessageSendResponse>),
+    Block(ResponseKey<UserBlockResponse>),
+}
+
+pub struct DirectMessageManager {
+    queued_requests: Vec<DirectMessageReqQueued>,
+    in_flight_requests: Vec<DirectMessageReqInFlight>,
+}
+
+impl DirectMessageManager {
+    pub fn new() -> Self {
+        Self {
+            queued_requests: Vec::new(),
+            in_flight_requests: Vec::new(),
+        }
+    }
+
+    pub(crate) fn update(
+        &mut self,
+        commands: &mut Commands,
+        naia_server: &mut Server,
+        http_client: &mut HttpClient,
+        user_manager: &mut UserManager,
+        social_server_url: &Option<(String, u16)>,
+        session_instance: &SessionInstance,
+        main_menu_room_key: &RoomKey,
+    ) {
+        self.process_in_flight_requests(
+            commands,
+            naia_server,
+            http_client,
+            user_manager,
+            main_menu_room_key,
+        );
+        self.process_queued_requests(
+            http_client,
+            user_manager,
+            social_server_url,
+            session_instance,
+        );
+    }
+
+    // patches are addressed to a single user
+    pub(crate) fn patch_direct_messages(
+        &mut self,
+        commands: &mut Commands,
+        naia_server: &mut Server,
+        http_client: &mut HttpClient,
+        user_manager: &mut UserManager,
+        main_menu_room_key: &RoomKey,
+        patches: &Vec<SocialDirectMessagePatch>,
+    ) {
+        for patch in patches {
+            let (SocialDirectMessagePatch::Message(receiving_user_id, other_user_id, _, _)
+            | SocialDirectMessagePatch::Block(receiving_user_id, other_user_id, _)) = patch;
+
+            let Some(receiving_user_key) = user_manager.user_id_to_key(receiving_user_id) else {
+                info!(
+                    "dropping direct message patch for user not on this session - [userid {:?}]",
+                    receiving_user_id
+                );
+                continue;
+            };
+
+            let other_user_entity = user_manager.get_or_init_user_entity(
+                commands,
+                naia_server,
+                http_client,
+                main_menu_room_key,
+                other_user_id,
+            );
+
+            match patch {
+                SocialDirectMessagePatch::Message(_, _, timestamp, message) => {
+                    let mut message = DirectMessageReceived::new(false, *timestamp, message);
+                    message.user_entity.set(naia_server, &other_user_entity);
+                    naia_server.send_message::<PrimaryChannel, _>(&receiving_user_key, &message);
+                }
+                SocialDirectMessagePatch::Block(_, _, blocked) => {
+                    let mut message = UserBlocked::new(*blocked);
+                    message.user_entity.set(naia_server, &other_user_entity);
+                    naia_server.send_message::<PrimaryChannel, _>(&receiving_user_key, &message);
+                }
+            }
+        }
+    }
+
+    pub(crate) fn send_direct_message(
+        &mut self,
+        http_client: &mut HttpClient,
+        user_manager: &UserManager,
+        social_server_url: Option<&(String, u16)>,
+        session_instance: &SessionInstance,
+        user_key: &UserKey,
+        recipient_user_id: &UserId,
+        message: &str,
+    ) {
+        let Some(user_id) = user_manager.user_key_to_id(user_key) else {
+            warn!("User not found: {:?}", user_key);
+            return;
+        };
+
+        let Some((social_server_addr, social_server_port)) = social_server_url else {
+            warn!("received direct message but no social server is available!");
+
+            self.queued_requests.push(DirectMessageReqQueued::Send(
+                *user_key,
+                *recipient_user_id,
+                message.to_string(),
+            ));
+
+            return;
+        };
+
+        let request = DirectMessageSendRequest::new(
+            session_instance.instance_secret(),
+            user_id,
+            *recipient_user_id,
+            message,
+        );
+
+        let host = "session";
+        let remote = "social";
+        bevy_http_client::log_util::send_req(host, remote, DirectMessageSendRequest::name());
+        let response_key = http_client.send(social_server_addr, *social_server_port, request);
+
+        self.in_flight_requests.push(DirectMessageReqInFlight::Send(
+            *user_key,
+            *recipient_user_id,
+            response_key,
+        ));
+    }
+
+    pub(crate) fn send_user_block(
+        &mut self,
+        http_client: &mut HttpClient,
+        user_manager: &UserManager,
+        social_server_url: Option<&(String, u16)>,
+        session_instance: &SessionInstance,
+        user_key: &UserKey,
+        other_user_id: &UserId,
+        blocked: bool,
+    ) {
+        let Some(user_id) = user_manager.user_key_to_id(user_key) else {
+            warn!("User not found: {:?}", user_key);
+            return;
+        };
+
+        let Some((social_server_addr, social_server_port)) = social_server_url else {
+            warn!("received user block request but no social server is available!");
+
+            self.queued_requests.push(DirectMessageReqQueued::Block(
+                *user_key,
+                *other_user_id,
+                blocked,
+            ));
+
+            return;
+        };
+
+        let request = UserBlockRequest::new(
+            session_instance.instance_secret(),
+            user_id,
+            *other_user_id,
+            blocked,
+        );
+
+        let host = "session";
+        let remote = "social";
+        bevy_http_client::log_util::send_req(host, remote, UserBlockRequest::name());
+        let response_key = http_client.send(social_server_addr, *social_server_port, request);
+
+        self.in_flight_requests
+            .push(DirectMessageReqInFlight::Block(response_key));
+    }
+
+    fn process_queued_requests(
+        &mut self,
+        http_client: &mut HttpClient,
+        user_manager: &UserManager,
+        social_server_url: &Option<(String, u16)>,
+        session_instance: &SessionInstance,
+    ) {
+        if self.queued_requests.is_empty() {
+            // no queued requests
+            return;
+        }
+        if social_server_url.is_none() {
+            // it's okay to wait until the social server is available
+            return;
+        };
+
+        let queued_requests = std::mem::take(&mut self.queued_requests);
+        for request in queued_requests {
+            match request {
+                DirectMessageReqQueued::Send(user_key, recipient_user_id, message) => {
+                    self.send_direct_message(
+                        http_client,
+                        user_manager,
+                        social_server_url.as_ref(),
+                        session_instance,
+                        &user_key,
+                        &recipient_user_id,
+                        &message,
+                    );
+                }
+                DirectMessageReqQueued::Block(user_key, other_user_id, blocked) => {
+                    self.send_user_block(
+                        http_client,
+                        user_manager,
+                        social_server_url.as_ref(),
+                        session_instance,
+                        &user_key,
+                        &other_user_id,
+                        blocked,
+                    );
+                }
+            }
+        }
+    }
+
+    fn process_in_flight_requests(
+        &mut self,
+        commands: &mut Commands,
+        naia_server: &mut Server,
+        http_client: &mut HttpClient,
+        user_manager: &mut UserManager,
+        main_menu_room_key: &RoomKey,
+    ) {
+        if self.in_flight_requests.is_empty() {
+            // no in-flight requests
+            return;
+        }
+
+        let mut continuing_requests = Vec::new();
+        let in_flight_requests = std::mem::take(&mut self.in_flight_requests);
+
+        for req in in_flight_requests {
+            let host = "session";
+            let remote = "social";
+            match &req {
+                DirectMessageReqInFlight::Send(user_key, recipient_user_id, response_key) => {
+                    let Some(response_result) = http_client.recv(response_key) else {
+                        continuing_requests.push(req);
+                        continue;
+                    };
+                    bevy_http_client::log_util::recv_res(
+                        host,
+                        remote,
+                        DirectMessageSendResponse::name(),
+                    );
+                    let response = match response_result {
+                        Ok(response) => response,
+                        Err(e) => {
+                            warn!(
+                                "error receiving direct message response from social server: {:?}",
+                                e.to_string()
+                            );
+                            continue;
+                        }
+                    };
+                    if user_manager.user_key_to_id(user_key).is_none() {
+                        // sender disconnected while the message was in flight
+                        continue;
+                    }
+
+                    // echo the accepted message back into the sender's side of the conversation
+                    let recipient_user_entity = user_manager.get_or_init_user_entity(
+                        commands,
+                        naia_server,
+                        http_client,
+                        main_menu_room_key,
+                        recipient_user_id,
+                    );
+                    let mut message =
+                        DirectMessageReceived::new(true, response.timestamp(), response.message());
+                    message.user_entity.set(naia_server, &recipient_user_entity);
+                    naia_server.send_message::<PrimaryChannel, _>(user_key, &message);
+                }
+                DirectMessageReqInFlight::Block(response_key) => {
+                    let Some(response_result) = http_client.recv(response_key) else {
+                        continuing_requests.push(req);
+                        continue;
+                    };
+                    bevy_http_client::log_util::recv_res(host, remote, UserBlockResponse::name());
+                    if let Err(e) = response_result {
+                        warn!(
+                            "error receiving user block response from social server: {:?}",
+                            e.to_string()
+                        );
+                    }
+                }
+            }
+        }
+
+        self.in_flight_requests = continuing_requests;
+    }
+}
@@ -0,0 +1,285 @@
+use bevy_ecs::system::Commands;
+
+use naia_bevy_server::{RoomKey, Server, UserKey};
+
+use auth_server_types::UserId;
+use bevy_http_client::{ApiRequest, ApiResponse, HttpClient, ResponseKey};
+use logging::{info, warn};
+use session_server_http_proto::SocialFriendPatch;
+use session_server_naia_proto::{
+    channels::PrimaryChannel,
+    messages::{FriendRemoved, FriendRequestReceived, FriendRequestRemoved, FriendUpdated},
+};
+use social_server_http_proto::{
+    FriendActionRequest, FriendActionResponse, UserSetAwayRequest, UserSetAwayResponse,
+};
+use social_server_types::FriendAction;
+
+use crate::{session_instance::SessionInstance, user::UserManager};
+
+enum FriendReqQueued {
+    // acting user, other user
+    Action(UserKey, UserId, FriendAction),
+    SetAway(UserKey, bool),
+}
+
+enum FriendReqInFlight {
+    Action(FriendAction, ResponseKey<FriendActionResponse>),
+    SetAway(ResponseKey<UserSetAwayResponse>),
+}
+
+pub struct FriendManager {
+    queued_requests: Vec<FriendReqQueued>,
+    in_flight_requests: Vec<FriendReqInFlight>,
+}
+
+impl FriendManager {
+    pub fn new() -> Self {
+        Self {
+            queued_requests: Vec::new(),
+            in_flight_requests: Vec::new(),
+        }
+    }
+
+    pub(crate) fn update(
+        &mut self,
+        http_client: &mut HttpClient,
+        user_manager: &UserManager,
+        social_server_url: &Option<(String, u16)>,
+        session_instance: &SessionInstance,
+    ) {
+        self.process_in_flight_requests(http_client);
+        self.process_queued_requests(
+            http_client,
+            user_manager,
+            social_server_url,
+            session_instance,
+        );
+    }
+
+    // patches are addressed to a single user, and their presence info only ever goes to friends
+    pub(crate) fn patch_friends(
+        &mut self,
+        commands: &mut Commands,
+        naia_server: &mut Server,
+        http_client: &mut HttpClient,
+        user_manager: &mut UserManager,
+        main_menu_room_key: &RoomKey,
+        patches: &Vec<SocialFriendPatch>,
+    ) {
+        for patch in patches {
+            let (SocialFriendPatch::Update(receiving_user_id, other_user_id, _)
+            | SocialFriendPatch::Remove(receiving_user_id, other_user_id)
+            | SocialFriendPatch::RequestReceived(receiving_user_id, other_user_id)
+            | SocialFriendPatch::RequestRemoved(receiving_user_id, other_user_id)) = patch;
+
+            let Some(receiving_user_key) = user_manager.user_id_to_key(receiving_user_id) else {
+                info!(
+                    "dropping friend patch for user not on this session - [userid {:?}]",
+                    receiving_user_id
+                );
+                continue;
+            };
+
+            let other_user_entity = user_manager.get_or_init_user_entity(
+                commands,
+                naia_server,
+                http_client,
+                main_menu_room_key,
+                other_user_id,
+            );
+
+            match patch {
+                SocialFriendPatch::Update(_, _, presence) => {
+                    let mut message = FriendUpdated::new(*presence);
+                    message.user_entity.set(naia_server, &other_user_entity);
+                    naia_server.send_message::<PrimaryChannel, _>(&receiving_user_key, &message);
+                }
+                SocialFriendPatch::Remove(_, _) => {
+                    let mut message = FriendRemoved::new();
+                    message.user_entity.set(naia_server, &other_user_entity);
+                    naia_server.send_message::<PrimaryChannel, _>(&receiving_user_key, &message);
+                }
+                SocialFriendPatch::RequestReceived(_, _) => {
+                    let mut message = FriendRequestReceived::new();
+                    message.user_entity.set(naia_server, &other_user_entity);
+                    naia_server.send_message::<PrimaryChannel, _>(&receiving_user_key, &message);
+                }
+                SocialFriendPatch::RequestRemoved(_, _) => {
+                    let mut message = FriendRequestRemoved::new();
+                    message.user_entity.set(naia_server, &other_user_entity);
+                    naia_server.send_message::<PrimaryChannel, _>(&receiving_user_key, &message);
+                }
+            }
+        }
+    }
+
+    pub(crate) fn send_friend_action(
+        &mut self,
+        http_client: &mut HttpClient,
+        user_manager: &UserManager,
+        social_server_url: Option<&(String, u16)>,
+        session_instance: &SessionInstance,
+        user_key: &UserKey,
+        other_user_id: &UserId,
+        action: FriendAction,
+    ) {
+        let Some(user_id) = user_manager.user_key_to_id(user_key) else {
+            warn!("User not found: {:?}", user_key);
+            return;
+        };
+
+        let Some((social_server_addr, social_server_port)) = social_server_url else {
+            warn!("received friend action request but no social server is available!");
+
+            self.queued_requests
+                .push(FriendReqQueued::Action(*user_key, *other_user_id, action));
+
+            return;
+        };
+
+        let request = FriendActionRequest::new(
+            session_instance.instance_secret(),
+            user_id,
+            *other_user_id,
+            action,
+        );
+
+        let host = "session";
+        let remote = "social";
+        bevy_http_client::log_util::send_req(host, remote, FriendActionRequest::name());
+        let response_key = http_client.send(social_server_addr, *social_server_port, request);
+
+        self.in_flight_requests
+            .push(FriendReqInFlight::Action(action, response_key));
+    }
+
+    pub(crate) fn send_user_set_away(
+        &mut self,
+        http_client: &mut HttpClient,
+        user_manager: &UserManager,
+        social_server_url: Option<&(String, u16)>,
+        session_instance: &SessionInstance,
+        user_key: &UserKey,
+        away: bool,
+    ) {
+        let Some(user_id) = user_manager.user_key_to_id(user_key) else {
+            warn!("User not found: {:?}", user_key);
+            return;
+        };
+
+        let Some((social_server_addr, social_server_port)) = social_server_url else {
+            warn!("received set away request but no social server is available!");
+
+            self.queued_requests
+                .push(FriendReqQueued::SetAway(*user_key, away));
+
+            return;
+        };
+
+        let request = UserSetAwayRequest::new(session_instance.instance_secret(), user_id, away);
+
+        let host = "session";
+        let remote = "social";
+        bevy_http_client::log_util::send_req(host, remote, UserSetAwayRequest::name());
+        let response_key = http_client.send(social_server_addr, *social_server_port, request);
+
+        self.in_flight_requests
+            .push(FriendReqInFlight::SetAway(response_key));
+    }
+
+    fn process_queued_requests(
+        &mut self,
+        http_client: &mut HttpClient,
+        user_manager: &UserManager,
+        social_server_url: &Option<(String, u16)>,
+        session_instance: &SessionInstance,
+    ) {
+        if self.queued_requests.is_empty() {
+            // no queued requests
+            return;
+        }
+        if social_server_url.is_none() {
+            // it's okay to wait until the social server is available
+            return;
+        };
+
+        let queued_requests = std::mem::take(&mut self.queued_requests);
+        for request in queued_requests {
+            match request {
+                FriendReqQueued::Action(user_key, other_user_id, action) => {
+                    self.send_friend_action(
+                        http_client,
+                        user_manager,
+                        social_server_url.as_ref(),
+                        session_instance,
+                        &user_key,
+                        &other_user_id,
+                        action,
+                    );
+                }
+                FriendReqQueued::SetAway(user_key, away) => {
+                    self.send_user_set_away(
+                        http_client,
+                        user_manager,
+                        social_server_url.as_ref(),
+                        session_instance,
+                        &user_key,
+                        away,
+                    );
+                }
+            }
+        }
+    }
+
+    // the results arrive as friend patches, so responses are only checked for errors
+    fn process_in_flight_requests(&mut self, http_client: &mut HttpClient) {
+        if self.in_flight_requests.is_empty() {
+            // no in-flight requests
+            return;
+        }
+
+        let mut continuing_requests = Vec::new();
+        let in_flight_requests = std::mem::take(&mut self.in_flight_requests);
+
+        for req in in_flight_requests {
+            let host = "session";
+            let remote = "social";
+            match &req {
+                FriendReqInFlight::Action(action, response_key) => {
+                    let Some(response_result) = http_client.recv(response_key) else {
+                        continuing_requests.push(req);
+                        continue;
+                    };
+                    bevy_http_client::log_util::recv_res(
+                        host,
+                        remote,
+                        FriendActionResponse::name(),
+                    );
+                    if let Err(e) = response_result {
+                        warn!(
+                            "error receiving friend action {:?} response from social server: {:?}",
+                            action,
+                            e.to_string()
+                        );
+                    }
+                }
+                FriendReqInFlight::SetAway(response_key) => {
+                    let Some(response_result) = http_client.recv(response_key) else {
+                        continuing_requests.push(req);
+                        continue;
+                    };
+                    bevy_http_client::log_util::recv_res(host, remote, UserSetAwayResponse::name());
+                    if let Err(e) = response_result {
+                        warn!(
+                            "error receiving set away response from social server: {:?}",
+                            e.to_string()
+                        );
+                    }
+                }
+            }
+        }
+
+        self.in_flight_requests = continuing_requests;
+    }
+}
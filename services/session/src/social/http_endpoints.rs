@@ -9,12 +9,15 @@ use naia_bevy_server::Server;
 use auth_server_types::UserId;
 use bevy_http_client::{HttpClient, ResponseError};
 use bevy_http_server::HttpServer;
-use config::SOCIAL_SERVER_GLOBAL_SECRET;
+use config::social_server_global_secret;
 use logging::{info, warn};
 use session_server_http_proto::{
-    SocialPatchGlobalChatMessagesRequest, SocialPatchGlobalChatMessagesResponse,
-    SocialPatchMatchLobbiesRequest, SocialPatchMatchLobbiesResponse, SocialPatchUsersRequest,
-    SocialPatchUsersResponse, SocialWorldConnectRequest, SocialWorldConnectResponse,
+    SocialPatchDirectMessagesRequest, SocialPatchDirectMessagesResponse, SocialPatchFriendsRequest,
+    SocialPatchFriendsResponse, SocialPatchGlobalChatMessagesRequest,
+    SocialPatchGlobalChatMessagesResponse, SocialPatchMatchLobbiesRequest,
+    SocialPatchMatchLobbiesResponse, SocialPatchUsersRequest, SocialPatchUsersResponse,
+    SocialWorldConnectRequest, SocialWorldConnectResponse, SocialWorldSpectateRequest,
+    SocialWorldSpectateResponse,
 };
 use session_server_naia_proto::{
     channels::PrimaryChannel,
@@ -37,7 +40,7 @@ pub fn recv_patch_users_request(
     while let Some((_addr, request, response_key)) =
         http_server.receive::<SocialPatchUsersRequest>()
     {
-        if request.social_secret() != SOCIAL_SERVER_GLOBAL_SECRET {
+        if request.social_secret() != social_server_global_secret() {
             warn!("invalid request secret");
             http_server.respond(response_key, Err(ResponseError::Unauthenticated));
             continue;
@@ -60,6 +63,72 @@ pub fn recv_patch_users_request(
     }
 }
 
+pub fn recv_patch_friends_request(
+    mut commands: Commands,
+    mut social_manager: ResMut<SocialManager>,
+    mut http_server: ResMut<HttpServer>,
+    mut http_client: ResMut<HttpClient>,
+    mut user_manager: ResMut<UserManager>,
+    mut naia_server: Server,
+) {
+    while let Some((_addr, request, response_key)) =
+        http_server.receive::<SocialPatchFriendsRequest>()
+    {
+        if request.social_secret() != social_server_global_secret() {
+            warn!("invalid request secret");
+            http_server.respond(response_key, Err(ResponseError::Unauthenticated));
+            continue;
+        }
+
+        info!("received patch friends request");
+        let main_menu_room_key = social_manager.global_room_key().unwrap();
+        social_manager.friend_manager.patch_friends(
+            &mut commands,
+            &mut naia_server,
+            &mut http_client,
+            &mut user_manager,
+            &main_menu_room_key,
+            request.patches(),
+        );
+
+        // responding
+        http_server.respond(response_key, Ok(SocialPatchFriendsResponse));
+    }
+}
+
+pub fn recv_patch_direct_messages_request(
+    mut commands: Commands,
+    mut social_manager: ResMut<SocialManager>,
+    mut http_server: ResMut<HttpServer>,
+    mut http_client: ResMut<HttpClient>,
+    mut user_manager: ResMut<UserManager>,
+    mut naia_server: Server,
+) {
+    while let Some((_addr, request, response_key)) =
+        http_server.receive::<SocialPatchDirectMessagesRequest>()
+    {
+        if request.social_secret() != social_server_global_secret() {
+            warn!("invalid request secret");
+            http_server.respond(response_key, Err(ResponseError::Unauthenticated));
+            continue;
+        }
+
+        info!("received patch direct messages request");
+        let main_menu_room_key = social_manager.global_room_key().unwrap();
+        social_manager.direct_message_manager.patch_direct_messages(
+            &mut commands,
+            &mut naia_server,
+            &mut http_client,
+            &mut user_manager,
+            &main_menu_room_key,
+            request.patches(),
+        );
+
+        // responding
+        http_server.respond(response_key, Ok(SocialPatchDirectMessagesResponse));
+    }
+}
+
 pub fn recv_patch_global_chat_messages_request(
     mut commands: Commands,
     mut social_manager: ResMut<SocialManager>,
@@ -71,7 +140,7 @@ pub fn recv_patch_global_chat_messages_request(
     while let Some((_addr, request, response_key)) =
         http_server.receive::<SocialPatchGlobalChatMessagesRequest>()
     {
-        if request.social_secret() != SOCIAL_SERVER_GLOBAL_SECRET {
+        if request.social_secret() != social_server_global_secret() {
             warn!("invalid request secret");
             http_server.respond(response_key, Err(ResponseError::Unauthenticated));
             continue;
@@ -102,13 +171,14 @@ pub fn recv_patch_match_lobby_request(
     mut http_server: ResMut<HttpServer>,
     mut http_client: ResMut<HttpClient>,
     mut user_manager: ResMut<UserManager>,
+    mut world_manager: ResMut<WorldManager>,
     mut naia_server: Server,
     mut lobby_q: Query<&mut Lobby>,
 ) {
     while let Some((_addr, request, response_key)) =
         http_server.receive::<SocialPatchMatchLobbiesRequest>()
     {
-        if request.social_secret() != SOCIAL_SERVER_GLOBAL_SECRET {
+        if request.social_secret() != social_server_global_secret() {
             warn!("invalid request secret");
             http_server.respond(response_key, Err(ResponseError::Unauthenticated));
             continue;
@@ -122,6 +192,7 @@ pub fn recv_patch_match_lobby_request(
             &mut naia_server,
             &mut http_client,
             &mut user_manager,
+            &mut world_manager,
             &mut lobby_q,
             &main_menu_room_key,
             request.patches(),
@@ -142,7 +213,7 @@ pub fn recv_world_connect(
     while let Some((_addr, request, response_key)) =
         http_server.receive::<SocialWorldConnectRequest>()
     {
-        if request.social_secret() != SOCIAL_SERVER_GLOBAL_SECRET {
+        if request.social_secret() != social_server_global_secret() {
             warn!("invalid request secret");
             http_server.respond(response_key, Err(ResponseError::Unauthenticated));
             continue;
@@ -169,6 +240,58 @@ pub fn recv_world_connect(
     }
 }
 
+pub fn recv_world_spectate(
+    mut naia_server: Server,
+    mut http_server: ResMut<HttpServer>,
+    mut user_manager: ResMut<UserManager>,
+    mut world_manager: ResMut<WorldManager>,
+    mut social_manager: ResMut<SocialManager>,
+) {
+    while let Some((_addr, request, response_key)) =
+        http_server.receive::<SocialWorldSpectateRequest>()
+    {
+        if request.social_secret() != social_server_global_secret() {
+            warn!("invalid request secret");
+            http_server.respond(response_key, Err(ResponseError::Unauthenticated));
+            continue;
+        }
+
+        info!("received world spectate request");
+
+        let user_id = request.user_id();
+        let lobby_id = request.lobby_id();
+        let world_instance_secret = request.world_instance_secret();
+        let login_token = request.login_token();
+
+        if !user_manager.has_user_data(&user_id) {
+            warn!("spectating user has left - [userid {:?}]", user_id);
+            http_server.respond(response_key, Err(ResponseError::NotFound));
+            continue;
+        }
+
+        // tracked like a player's world connection, so the spectator can reconnect to it
+        user_manager.user_set_world_connected(&user_id, world_instance_secret, login_token);
+        world_manager.world_set_user_connected(&user_id, world_instance_secret);
+        social_manager
+            .lobby_manager
+            .add_spectator(&lobby_id, &user_id);
+
+        // a spectator within their reconnect grace period gets the token when they're back
+        if let Some(user_key) = user_manager.user_id_to_key(&user_id) {
+            let global_room_key = social_manager.global_room_key().unwrap();
+            naia_server
+                .room_mut(&global_room_key)
+                .remove_user(&user_key);
+
+            let token = WorldConnectToken::new(login_token);
+            naia_server.send_message::<PrimaryChannel, WorldConnectToken>(&user_key, &token);
+        }
+
+        // responding
+        http_server.respond(response_key, Ok(SocialWorldSpectateResponse));
+    }
+}
+
 fn process_world_connect(
     naia_server: &mut Server,
     user_manager: &mut UserManager,
@@ -198,25 +321,31 @@ fn process_world_connect(
 
     // handle user login tokens
     for (user_id, login_token) in login_tokens {
-        let user_key = user_manager.user_id_to_key(&user_id).unwrap();
-
-        // store world instance secret with user key
-        user_manager.user_set_world_connected(&user_key, &world_instance_secret);
-        world_manager.world_set_user_connected(&user_id, &user_key, &world_instance_secret);
+        // store world instance secret with user, so they can be sent back if they reconnect
+        user_manager.user_set_world_connected(&user_id, &world_instance_secret, &login_token);
+        world_manager.world_set_user_connected(&user_id, &world_instance_secret);
 
         let user_entity = user_manager.get_user_entity(&user_id).unwrap();
 
         naia_server
             .room_mut(&global_room_key)
             // remove user entity from global room
-            .remove_entity(&user_entity)
-            // remove user from global room
-            .remove_user(&user_key);
+            .remove_entity(&user_entity);
 
         naia_server
             .room_mut(&lobby_room_key)
             .add_entity(&user_entity);
 
+        // a user within their reconnect grace period gets the token when they're back
+        let Some(user_key) = user_manager.user_id_to_key(&user_id) else {
+            continue;
+        };
+
+        // remove user from global room
+        naia_server
+            .room_mut(&global_room_key)
+            .remove_user(&user_key);
+
         // send world connect token to user
         // info!("sending world connect token to user");
         let token = WorldConnectToken::new(&login_token);
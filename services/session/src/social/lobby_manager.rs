@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use bevy_ecs::{
     entity::Entity,
@@ -11,37 +11,62 @@ use auth_server_types::UserId;
 use bevy_http_client::{ApiRequest, ApiResponse, HttpClient, ResponseKey};
 use logging::{info, warn};
 use session_server_http_proto::SocialLobbyPatch;
-use session_server_naia_proto::components::{Lobby, LobbyMember};
+use session_server_naia_proto::{
+    channels::PrimaryChannel,
+    components::{Lobby, LobbyMember},
+    messages::{MatchLobbyGameEnded, MatchLobbyInvited, MatchLobbyKicked},
+};
 use social_server_http_proto::{
-    MatchLobbyCreateRequest, MatchLobbyCreateResponse, MatchLobbyJoinRequest,
-    MatchLobbyJoinResponse, MatchLobbyLeaveRequest, MatchLobbyLeaveResponse,
-    MatchLobbyStartRequest, MatchLobbyStartResponse,
+    MatchLobbyCreateRequest, MatchLobbyCreateResponse, MatchLobbyInviteRequest,
+    MatchLobbyInviteResponse, MatchLobbyJoinRequest, MatchLobbyJoinResponse, MatchLobbyKickRequest,
+    MatchLobbyKickResponse, MatchLobbyLeaveRequest, MatchLobbyLeaveResponse,
+    MatchLobbyLeaveSpectateRequest, MatchLobbyLeaveSpectateResponse, MatchLobbySpectateRequest,
+    MatchLobbySpectateResponse, MatchLobbyStartRequest, MatchLobbyStartResponse,
 };
-use social_server_types::LobbyId;
+use social_server_types::{LobbyId, LobbySettings, MatchOutcome, MatchUserResult};
 
 use crate::{
     session_instance::SessionInstance, social::chat_message_manager::ChatMessageManager,
-    user::UserManager,
+    user::UserManager, world::WorldManager,
 };
 
 #[derive(PartialEq, Eq, Copy, Clone)]
 enum LobbyState {
     WaitingToStart,
     InProgress,
+    Finished,
+    Abandoned,
 }
 
 enum LobbyReqQueued {
-    MatchCreate(UserKey, String),
-    MatchJoin(UserKey, LobbyId),
+    MatchCreate(UserKey, String, LobbySettings, Option<String>),
+    MatchJoin(UserKey, LobbyId, Option<String>),
     MatchLeave(UserKey),
     MatchStart(UserKey),
+    MatchSpectate(UserKey, LobbyId),
+    MatchLeaveSpectate(UserKey),
+    // kicking user, kicked user
+    MatchKick(UserKey, UserId),
+    // inviting user, invited user
+    MatchInvite(UserKey, UserId),
 }
 
 enum LobbyReqInFlight {
-    MatchCreate(UserId, String, ResponseKey<MatchLobbyCreateResponse>),
+    MatchCreate(
+        UserId,
+        String,
+        LobbySettings,
+        ResponseKey<MatchLobbyCreateResponse>,
+    ),
     MatchJoin(UserId, LobbyId, ResponseKey<MatchLobbyJoinResponse>),
     MatchLeave(UserId, ResponseKey<MatchLobbyLeaveResponse>),
     MatchStart(UserId, ResponseKey<MatchLobbyStartResponse>),
+    MatchSpectate(UserId, LobbyId, ResponseKey<MatchLobbySpectateResponse>),
+    MatchLeaveSpectate(UserId, ResponseKey<MatchLobbyLeaveSpectateResponse>),
+    // kicked user
+    MatchKick(UserId, ResponseKey<MatchLobbyKickResponse>),
+    // invited user
+    MatchInvite(UserId, ResponseKey<MatchLobbyInviteResponse>),
 }
 
 struct LobbyData {
@@ -49,6 +74,8 @@ struct LobbyData {
     lobby_entity: Entity,
     room_key: RoomKey,
     lobby_member_entities: HashMap<Entity, UserId>,
+    // this session server's users watching the running match
+    spectators: HashSet<UserId>,
     state: LobbyState,
 }
 
@@ -59,6 +86,7 @@ impl LobbyData {
             lobby_entity,
             room_key,
             lobby_member_entities: HashMap::new(),
+            spectators: HashSet::new(),
             state: LobbyState::WaitingToStart,
         }
     }
@@ -73,11 +101,27 @@ impl LobbyData {
     }
 
     pub(crate) fn start(&mut self) {
-        if self.state != LobbyState::WaitingToStart {
-            panic!("Lobby is not waiting to start");
+        match self.state {
+            LobbyState::WaitingToStart | LobbyState::Finished => {}
+            _ => panic!("Lobby is not waiting to start"),
         }
         self.state = LobbyState::InProgress;
     }
+
+    pub(crate) fn end(&mut self, outcome: MatchOutcome) {
+        self.state = match outcome {
+            MatchOutcome::Finished => LobbyState::Finished,
+            MatchOutcome::Abandoned => LobbyState::Abandoned,
+        };
+    }
+
+    pub(crate) fn set_owner(&mut self, owner_user_id: UserId) {
+        self.lobby_owner_user_id = owner_user_id;
+    }
+
+    pub(crate) fn member_count(&self) -> u8 {
+        self.lobby_member_entities.len() as u8
+    }
 }
 
 pub struct LobbyManager {
@@ -109,12 +153,21 @@ impl LobbyManager {
             .map(|lobby_data| lobby_data.room_key)
     }
 
+    pub(crate) fn add_spectator(&mut self, lobby_id: &LobbyId, user_id: &UserId) {
+        let Some(lobby_data) = self.lobbies.get_mut(lobby_id) else {
+            warn!("spectating non-existent lobby - [lobbyid {:?}]", lobby_id);
+            return;
+        };
+        lobby_data.spectators.insert(*user_id);
+    }
+
     pub(crate) fn update(
         &mut self,
         commands: &mut Commands,
         naia_server: &mut Server,
         http_client: &mut HttpClient,
         user_manager: &mut UserManager,
+        world_manager: &mut WorldManager,
         lobby_q: &mut Query<&mut Lobby>,
         social_server_url: &Option<(String, u16)>,
         session_instance: &SessionInstance,
@@ -125,6 +178,7 @@ impl LobbyManager {
             naia_server,
             http_client,
             user_manager,
+            world_manager,
             lobby_q,
             global_room_key,
         );
@@ -155,7 +209,7 @@ impl LobbyManager {
         let queued_requests = std::mem::take(&mut self.queued_requests);
         for request in queued_requests {
             match request {
-                LobbyReqQueued::MatchCreate(owner_user_key, match_name) => {
+                LobbyReqQueued::MatchCreate(owner_user_key, match_name, settings, password) => {
                     self.send_match_lobby_create(
                         http_client,
                         user_manager,
@@ -163,9 +217,11 @@ impl LobbyManager {
                         session_instance,
                         &owner_user_key,
                         &match_name,
+                        settings,
+                        password.as_deref(),
                     );
                 }
-                LobbyReqQueued::MatchJoin(user_key, lobby_id) => {
+                LobbyReqQueued::MatchJoin(user_key, lobby_id, password) => {
                     self.send_match_lobby_join(
                         http_client,
                         user_manager,
@@ -173,6 +229,7 @@ impl LobbyManager {
                         session_instance,
                         &user_key,
                         &lobby_id,
+                        password.as_deref(),
                     );
                 }
                 LobbyReqQueued::MatchLeave(user_key) => {
@@ -193,6 +250,45 @@ impl LobbyManager {
                         &user_key,
                     );
                 }
+                LobbyReqQueued::MatchSpectate(user_key, lobby_id) => {
+                    self.send_match_lobby_spectate(
+                        http_client,
+                        user_manager,
+                        social_server_url.as_ref(),
+                        session_instance,
+                        &user_key,
+                        &lobby_id,
+                    );
+                }
+                LobbyReqQueued::MatchLeaveSpectate(user_key) => {
+                    self.send_match_lobby_leave_spectate(
+                        http_client,
+                        user_manager,
+                        social_server_url.as_ref(),
+                        session_instance,
+                        &user_key,
+                    );
+                }
+                LobbyReqQueued::MatchKick(user_key, kicked_user_id) => {
+                    self.send_match_lobby_kick(
+                        http_client,
+                        user_manager,
+                        social_server_url.as_ref(),
+                        session_instance,
+                        &user_key,
+                        &kicked_user_id,
+                    );
+                }
+                LobbyReqQueued::MatchInvite(user_key, invited_user_id) => {
+                    self.send_match_lobby_invite(
+                        http_client,
+                        user_manager,
+                        social_server_url.as_ref(),
+                        session_instance,
+                        &user_key,
+                        &invited_user_id,
+                    );
+                }
             }
         }
     }
@@ -203,6 +299,7 @@ impl LobbyManager {
         naia_server: &mut Server,
         http_client: &mut HttpClient,
         user_manager: &mut UserManager,
+        world_manager: &mut WorldManager,
         lobby_q: &mut Query<&mut Lobby>,
         main_menu_room_key: &RoomKey,
     ) {
@@ -216,7 +313,12 @@ impl LobbyManager {
 
         for req in in_flight_requests {
             match &req {
-                LobbyReqInFlight::MatchCreate(owner_user_id, match_name, response_key) => {
+                LobbyReqInFlight::MatchCreate(
+                    owner_user_id,
+                    match_name,
+                    settings,
+                    response_key,
+                ) => {
                     if let Some(response_result) = http_client.recv(response_key) {
                         let host = "session";
                         let remote = "social";
@@ -240,6 +342,7 @@ impl LobbyManager {
                                     &lobby_id,
                                     match_name,
                                     owner_user_id,
+                                    *settings,
                                 );
                             }
                             Err(e) => {
@@ -271,6 +374,7 @@ impl LobbyManager {
                                     commands,
                                     naia_server,
                                     user_manager,
+                                    lobby_q,
                                     &lobby_id,
                                     user_id,
                                 );
@@ -300,7 +404,13 @@ impl LobbyManager {
                             Ok(_response) => {
                                 // info!("received leave match lobby message response from social server");
 
-                                self.leave_lobby(commands, naia_server, user_manager, user_id);
+                                self.leave_lobby(
+                                    commands,
+                                    naia_server,
+                                    user_manager,
+                                    lobby_q,
+                                    user_id,
+                                );
                             }
                             Err(e) => {
                                 warn!(
@@ -341,6 +451,132 @@ impl LobbyManager {
                         continuing_requests.push(req);
                     }
                 }
+                LobbyReqInFlight::MatchSpectate(user_id, lobby_id, response_key) => {
+                    if let Some(response_result) = http_client.recv(response_key) {
+                        let host = "session";
+                        let remote = "social";
+                        bevy_http_client::log_util::recv_res(
+                            host,
+                            remote,
+                            MatchLobbySpectateResponse::name(),
+                        );
+
+                        match response_result {
+                            Ok(_response) => {
+                                // the world connect token follows once the world server is ready
+                                info!(
+                                    "user will spectate match - [userid {:?}, lobbyid {:?}]",
+                                    user_id, lobby_id
+                                );
+                            }
+                            Err(e) => {
+                                warn!(
+                                    "error receiving spectate match lobby response from social server: {:?}",
+                                    e.to_string()
+                                );
+                            }
+                        }
+                    } else {
+                        continuing_requests.push(req);
+                    }
+                }
+                LobbyReqInFlight::MatchLeaveSpectate(user_id, response_key) => {
+                    if let Some(response_result) = http_client.recv(response_key) {
+                        let host = "session";
+                        let remote = "social";
+                        bevy_http_client::log_util::recv_res(
+                            host,
+                            remote,
+                            MatchLobbyLeaveSpectateResponse::name(),
+                        );
+
+                        match response_result {
+                            Ok(_response) => {
+                                self.remove_spectator(
+                                    naia_server,
+                                    user_manager,
+                                    world_manager,
+                                    main_menu_room_key,
+                                    user_id,
+                                );
+                            }
+                            Err(e) => {
+                                warn!(
+                                    "error receiving leave spectate response from social server: {:?}",
+                                    e.to_string()
+                                );
+                            }
+                        }
+                    } else {
+                        continuing_requests.push(req);
+                    }
+                }
+                LobbyReqInFlight::MatchKick(kicked_user_id, response_key) => {
+                    if let Some(response_result) = http_client.recv(response_key) {
+                        let host = "session";
+                        let remote = "social";
+                        bevy_http_client::log_util::recv_res(
+                            host,
+                            remote,
+                            MatchLobbyKickResponse::name(),
+                        );
+
+                        match response_result {
+                            Ok(_response) => {
+                                // info!("received kick match lobby message response from social server");
+
+                                self.kick_from_lobby(
+                                    commands,
+                                    naia_server,
+                                    user_manager,
+                                    lobby_q,
+                                    kicked_user_id,
+                                );
+                            }
+                            Err(e) => {
+                                warn!(
+                                    "error receiving kick match lobby response from social server: {:?}",
+                                    e.to_string()
+                                );
+                            }
+                        }
+                    } else {
+                        continuing_requests.push(req);
+                    }
+                }
+                LobbyReqInFlight::MatchInvite(invited_user_id, response_key) => {
+                    if let Some(response_result) = http_client.recv(response_key) {
+                        let host = "session";
+                        let remote = "social";
+                        bevy_http_client::log_util::recv_res(
+                            host,
+                            remote,
+                            MatchLobbyInviteResponse::name(),
+                        );
+
+                        match response_result {
+                            Ok(response) => {
+                                // info!("received invite match lobby message response from social server");
+                                let lobby_id = response.lobby_id();
+
+                                self.invite_to_lobby(
+                                    naia_server,
+                                    user_manager,
+                                    &lobby_id,
+                                    invited_user_id,
+                                );
+                            }
+                            Err(e) => {
+                                warn!(
+                                    "error receiving invite match lobby response from social server: {:?}",
+                                    e.to_string()
+                                );
+                            }
+                        }
+                    } else {
+                        continuing_requests.push(req);
+                    }
+                }
             }
         }
 
@@ -355,6 +591,8 @@ impl LobbyManager {
         session_instance: &SessionInstance,
         owner_user_key: &UserKey,
         match_name: &str,
+        settings: LobbySettings,
+        password: Option<&str>,
     ) {
         let Some(owner_user_id) = user_manager.user_key_to_id(owner_user_key) else {
             warn!("User not found: {:?}", owner_user_key);
@@ -367,6 +605,8 @@ impl LobbyManager {
             self.queued_requests.push(LobbyReqQueued::MatchCreate(
                 *owner_user_key,
                 match_name.to_string(),
+                settings,
+                password.map(|password| password.to_string()),
             ));
 
             return;
@@ -377,6 +617,8 @@ impl LobbyManager {
             session_instance.instance_secret(),
             owner_user_id,
             match_name,
+            settings,
+            password,
         );
 
         let host = "session";
@@ -387,6 +629,7 @@ impl LobbyManager {
         self.in_flight_requests.push(LobbyReqInFlight::MatchCreate(
             owner_user_id,
             match_name.to_string(),
+            settings,
             response_key,
         ));
 
@@ -401,6 +644,7 @@ impl LobbyManager {
         session_instance: &SessionInstance,
         user_key: &UserKey,
         lobby_id: &LobbyId,
+        password: Option<&str>,
     ) {
         let Some(user_id) = user_manager.user_key_to_id(user_key) else {
             warn!("User not found: {:?}", user_key);
@@ -410,15 +654,22 @@ impl LobbyManager {
         let Some((social_server_addr, social_server_port)) = social_server_url else {
             warn!("received join match lobby request but no social server is available!");
 
-            self.queued_requests
-                .push(LobbyReqQueued::MatchJoin(*user_key, *lobby_id));
+            self.queued_requests.push(LobbyReqQueued::MatchJoin(
+                *user_key,
+                *lobby_id,
+                password.map(|password| password.to_string()),
+            ));
 
             return;
         };
 
         // info!("sending match lobby join request to social server - [userid {:?}]:(`{:?}`)", sending_user_id, message);
-        let request =
-            MatchLobbyJoinRequest::new(session_instance.instance_secret(), *lobby_id, user_id);
+        let request = MatchLobbyJoinRequest::new(
+            session_instance.instance_secret(),
+            *lobby_id,
+            user_id,
+            password,
+        );
 
         let host = "session";
         let remote = "social";
@@ -470,6 +721,83 @@ impl LobbyManager {
         return;
     }
 
+    pub(crate) fn send_match_lobby_spectate(
+        &mut self,
+        http_client: &mut HttpClient,
+        user_manager: &UserManager,
+        social_server_url: Option<&(String, u16)>,
+        session_instance: &SessionInstance,
+        user_key: &UserKey,
+        lobby_id: &LobbyId,
+    ) {
+        let Some(user_id) = user_manager.user_key_to_id(user_key) else {
+            warn!("User not found: {:?}", user_key);
+            return;
+        };
+
+        let Some((social_server_addr, social_server_port)) = social_server_url else {
+            warn!("received spectate match lobby request but no social server is available!");
+
+            self.queued_requests
+                .push(LobbyReqQueued::MatchSpectate(*user_key, *lobby_id));
+
+            return;
+        };
+
+        let request =
+            MatchLobbySpectateRequest::new(session_instance.instance_secret(), *lobby_id, user_id);
+
+        let host = "session";
+        let remote = "social";
+        bevy_http_client::log_util::send_req(host, remote, MatchLobbySpectateRequest::name());
+        let response_key = http_client.send(social_server_addr, *social_server_port, request);
+
+        self.in_flight_requests
+            .push(LobbyReqInFlight::MatchSpectate(
+                user_id,
+                *lobby_id,
+                response_key,
+            ));
+
+        return;
+    }
+
+    pub(crate) fn send_match_lobby_leave_spectate(
+        &mut self,
+        http_client: &mut HttpClient,
+        user_manager: &UserManager,
+        social_server_url: Option<&(String, u16)>,
+        session_instance: &SessionInstance,
+        user_key: &UserKey,
+    ) {
+        let Some(user_id) = user_manager.user_key_to_id(user_key) else {
+            warn!("User not found: {:?}", user_key);
+            return;
+        };
+
+        let Some((social_server_addr, social_server_port)) = social_server_url else {
+            warn!("received leave spectate request but no social server is available!");
+
+            self.queued_requests
+                .push(LobbyReqQueued::MatchLeaveSpectate(*user_key));
+
+            return;
+        };
+
+        let request =
+            MatchLobbyLeaveSpectateRequest::new(session_instance.instance_secret(), user_id);
+
+        let host = "session";
+        let remote = "social";
+        bevy_http_client::log_util::send_req(host, remote, MatchLobbyLeaveSpectateRequest::name());
+        let response_key = http_client.send(social_server_addr, *social_server_port, request);
+
+        self.in_flight_requests
+            .push(LobbyReqInFlight::MatchLeaveSpectate(user_id, response_key));
+
+        return;
+    }
+
     pub(crate) fn send_match_lobby_leave(
         &mut self,
         http_client: &mut HttpClient,
@@ -506,12 +834,97 @@ impl LobbyManager {
         return;
     }
 
+    pub(crate) fn send_match_lobby_kick(
+        &mut self,
+        http_client: &mut HttpClient,
+        user_manager: &UserManager,
+        social_server_url: Option<&(String, u16)>,
+        session_instance: &SessionInstance,
+        user_key: &UserKey,
+        kicked_user_id: &UserId,
+    ) {
+        let Some(user_id) = user_manager.user_key_to_id(user_key) else {
+            warn!("User not found: {:?}", user_key);
+            return;
+        };
+
+        let Some((social_server_addr, social_server_port)) = social_server_url else {
+            warn!("received kick match lobby request but no social server is available!");
+
+            self.queued_requests
+                .push(LobbyReqQueued::MatchKick(*user_key, *kicked_user_id));
+
+            return;
+        };
+
+        // info!("sending match lobby kick request to social server - [userid {:?}]:(`{:?}`)", sending_user_id, kicked_user_id);
+        let request = MatchLobbyKickRequest::new(
+            session_instance.instance_secret(),
+            user_id,
+            *kicked_user_id,
+        );
+
+        let host = "session";
+        let remote = "social";
+        bevy_http_client::log_util::send_req(host, remote, MatchLobbyKickRequest::name());
+        let response_key = http_client.send(social_server_addr, *social_server_port, request);
+
+        self.in_flight_requests
+            .push(LobbyReqInFlight::MatchKick(*kicked_user_id, response_key));
+
+        return;
+    }
+
+    pub(crate) fn send_match_lobby_invite(
+        &mut self,
+        http_client: &mut HttpClient,
+        user_manager: &UserManager,
+        social_server_url: Option<&(String, u16)>,
+        session_instance: &SessionInstance,
+        user_key: &UserKey,
+        invited_user_id: &UserId,
+    ) {
+        let Some(user_id) = user_manager.user_key_to_id(user_key) else {
+            warn!("User not found: {:?}", user_key);
+            return;
+        };
+
+        let Some((social_server_addr, social_server_port)) = social_server_url else {
+            warn!("received invite match lobby request but no social server is available!");
+
+            self.queued_requests
+                .push(LobbyReqQueued::MatchInvite(*user_key, *invited_user_id));
+
+            return;
+        };
+
+        // info!("sending match lobby invite request to social server - [userid {:?}]:(`{:?}`)", sending_user_id, invited_user_id);
+        let request = MatchLobbyInviteRequest::new(
+            session_instance.instance_secret(),
+            user_id,
+            *invited_user_id,
+        );
+
+        let host = "session";
+        let remote = "social";
+        bevy_http_client::log_util::send_req(host, remote, MatchLobbyInviteRequest::name());
+        let response_key = http_client.send(social_server_addr, *social_server_port, request);
+
+        self.in_flight_requests.push(LobbyReqInFlight::MatchInvite(
+            *invited_user_id,
+            response_key,
+        ));
+
+        return;
+    }
+
     pub(crate) fn patch_match_lobbies(
         &mut self,
         commands: &mut Commands,
         naia_server: &mut Server,
         http_client: &mut HttpClient,
         user_manager: &mut UserManager,
+        world_manager: &mut WorldManager,
         chat_message_manager: &mut ChatMessageManager,
         lobby_q: &mut Query<&mut Lobby>,
         main_menu_room_key: &RoomKey,
@@ -519,7 +932,7 @@ impl LobbyManager {
     ) {
         for patch in patches {
             match patch {
-                SocialLobbyPatch::Create(lobby_id, match_name, owner_id) => {
+                SocialLobbyPatch::Create(lobby_id, match_name, owner_id, settings) => {
                     info!(
                         "creating lobby - [lobbyid {:?}]:(`{:?}`), [ownerid {:?}]",
                         lobby_id, match_name, owner_id
@@ -534,6 +947,7 @@ impl LobbyManager {
                         lobby_id,
                         match_name,
                         owner_id,
+                        *settings,
                     );
                 }
                 // SocialLobbyPatch::Delete(lobby_id) => {
@@ -547,12 +961,40 @@ impl LobbyManager {
                         lobby_id, user_id
                     );
 
-                    self.join_lobby(commands, naia_server, user_manager, lobby_id, user_id);
+                    self.join_lobby(
+                        commands,
+                        naia_server,
+                        user_manager,
+                        lobby_q,
+                        lobby_id,
+                        user_id,
+                    );
                 }
                 SocialLobbyPatch::Leave(user_id) => {
                     info!("leaving lobby - [userid {:?}]", user_id);
 
-                    self.leave_lobby(commands, naia_server, user_manager, user_id);
+                    self.leave_lobby(commands, naia_server, user_manager, lobby_q, user_id);
+                }
+                SocialLobbyPatch::Kick(user_id) => {
+                    info!("kicking from lobby - [userid {:?}]", user_id);
+
+                    self.kick_from_lobby(commands, naia_server, user_manager, lobby_q, user_id);
+                }
+                SocialLobbyPatch::Invite(lobby_id, user_id) => {
+                    info!(
+                        "inviting to lobby - [lobbyid {:?}], [userid {:?}]",
+                        lobby_id, user_id
+                    );
+
+                    self.invite_to_lobby(naia_server, user_manager, lobby_id, user_id);
+                }
+                SocialLobbyPatch::OwnerChange(lobby_id, user_id) => {
+                    info!(
+                        "changing lobby owner - [lobbyid {:?}], [userid {:?}]",
+                        lobby_id, user_id
+                    );
+
+                    self.set_lobby_owner(naia_server, user_manager, lobby_q, lobby_id, user_id);
                 }
                 SocialLobbyPatch::Message(message_id, timestamp, user_id, message) => {
                     info!(
@@ -578,6 +1020,23 @@ impl LobbyManager {
 
                     self.start_lobby(lobby_q, lobby_id);
                 }
+                SocialLobbyPatch::End(lobby_id, outcome, results) => {
+                    info!(
+                        "ending lobby match - [lobbyid {:?}], [outcome {:?}]",
+                        lobby_id, outcome
+                    );
+
+                    self.end_lobby(
+                        naia_server,
+                        user_manager,
+                        world_manager,
+                        lobby_q,
+                        main_menu_room_key,
+                        lobby_id,
+                        *outcome,
+                        results,
+                    );
+                }
             }
         }
     }
@@ -596,10 +1055,11 @@ impl LobbyManager {
         lobby_id: &LobbyId,
         lobby_name: &str,
         owner_user_id: &UserId,
+        settings: LobbySettings,
     ) {
         // spawn lobby entity
         let lobby_entity = commands.spawn_empty().enable_replication(naia_server).id();
-        let mut lobby = Lobby::new(*lobby_id, lobby_name);
+        let mut lobby = Lobby::new(*lobby_id, lobby_name, settings);
 
         // add to main menu room
         naia_server
@@ -624,10 +1084,13 @@ impl LobbyManager {
 
         // set lobby owner
         lobby.owner_user_entity.set(naia_server, &owner_user_entity);
-        commands.entity(lobby_entity).insert(lobby);
 
         // join lobby room
-        self.join_lobby(commands, naia_server, user_manager, lobby_id, owner_user_id);
+        self.join_lobby_impl(commands, naia_server, user_manager, lobby_id, owner_user_id);
+
+        let member_count = self.lobbies.get(lobby_id).unwrap().member_count();
+        lobby.set_member_count(member_count);
+        commands.entity(lobby_entity).insert(lobby);
     }
 
     fn remove_lobby(
@@ -667,6 +1130,25 @@ impl LobbyManager {
     }
 
     fn join_lobby(
+        &mut self,
+        commands: &mut Commands,
+        naia_server: &mut Server,
+        user_manager: &mut UserManager,
+        lobby_q: &mut Query<&mut Lobby>,
+        lobby_id: &LobbyId,
+        joining_user_id: &UserId,
+    ) {
+        self.join_lobby_impl(
+            commands,
+            naia_server,
+            user_manager,
+            lobby_id,
+            joining_user_id,
+        );
+        self.sync_member_count(lobby_q, lobby_id);
+    }
+
+    fn join_lobby_impl(
         &mut self,
         commands: &mut Commands,
         naia_server: &mut Server,
@@ -712,6 +1194,7 @@ impl LobbyManager {
         commands: &mut Commands,
         naia_server: &mut Server,
         user_manager: &mut UserManager,
+        lobby_q: &mut Query<&mut Lobby>,
         leaving_user_id: &UserId,
     ) {
         // get user key & entity & lobby_id
@@ -719,20 +1202,132 @@ impl LobbyManager {
 
         // despawn lobby_member entity
         commands.entity(lobby_member_entity).despawn();
-        {
-            let lobby_data = self.lobbies.get_mut(&lobby_id).unwrap();
-            lobby_data.remove_lobby_member_entity(&lobby_member_entity);
-        }
+        let lobby_data = self.lobbies.get_mut(&lobby_id).unwrap();
+        lobby_data.remove_lobby_member_entity(&lobby_member_entity);
 
-        // determine if this is the owner
-        let lobby_data = self.lobbies.get(&lobby_id).unwrap();
-        let lobby_owner_user_id = lobby_data.lobby_owner_user_id;
-        if lobby_owner_user_id == *leaving_user_id {
-            // delete the lobby
+        if lobby_data.lobby_member_entities.is_empty() {
+            // nobody is left, delete the lobby
             self.remove_lobby(commands, naia_server, user_manager, &lobby_id);
+        } else {
+            // if the owner left, the new owner arrives in an OwnerChange patch
+            self.sync_member_count(lobby_q, &lobby_id);
+        }
+    }
+
+    fn kick_from_lobby(
+        &mut self,
+        commands: &mut Commands,
+        naia_server: &mut Server,
+        user_manager: &mut UserManager,
+        lobby_q: &mut Query<&mut Lobby>,
+        kicked_user_id: &UserId,
+    ) {
+        let Some(lobby_id) = user_manager.get_user_lobby_id(kicked_user_id) else {
+            warn!(
+                "attempted to kick user who is not in a lobby - [userid {:?}]",
+                kicked_user_id
+            );
+            return;
+        };
+
+        self.leave_lobby(commands, naia_server, user_manager, lobby_q, kicked_user_id);
+
+        // let the kicked user know, if they are connected to this session server
+        if let Some(kicked_user_key) = user_manager.user_id_to_key(kicked_user_id) {
+            let message = MatchLobbyKicked::new(lobby_id);
+            naia_server
+                .send_message::<PrimaryChannel, MatchLobbyKicked>(&kicked_user_key, &message);
+        }
+    }
+
+    fn invite_to_lobby(
+        &mut self,
+        naia_server: &mut Server,
+        user_manager: &UserManager,
+        lobby_id: &LobbyId,
+        invited_user_id: &UserId,
+    ) {
+        // let the invited user know, if they are connected to this session server
+        let Some(invited_user_key) = user_manager.user_id_to_key(invited_user_id) else {
+            return;
+        };
+        let message = MatchLobbyInvited::new(*lobby_id);
+        naia_server.send_message::<PrimaryChannel, MatchLobbyInvited>(&invited_user_key, &message);
+    }
+
+    fn set_lobby_owner(
+        &mut self,
+        naia_server: &mut Server,
+        user_manager: &UserManager,
+        lobby_q: &mut Query<&mut Lobby>,
+        lobby_id: &LobbyId,
+        owner_user_id: &UserId,
+    ) {
+        let Some(lobby_data) = self.lobbies.get_mut(lobby_id) else {
+            warn!(
+                "attempted to change owner of non-existent lobby - [lobbyid {:?}]",
+                lobby_id
+            );
+            return;
+        };
+        let Some(owner_user_entity) = user_manager.get_user_entity(owner_user_id) else {
+            warn!(
+                "attempted to change lobby owner to unknown user - [userid {:?}]",
+                owner_user_id
+            );
+            return;
+        };
+        if lobby_data.lobby_owner_user_id == *owner_user_id {
+            return;
+        }
+        lobby_data.set_owner(*owner_user_id);
+
+        if let Ok(mut lobby) = lobby_q.get_mut(lobby_data.lobby_entity) {
+            lobby.owner_user_entity.set(naia_server, &owner_user_entity);
+        }
+    }
+
+    fn sync_member_count(&self, lobby_q: &mut Query<&mut Lobby>, lobby_id: &LobbyId) {
+        let Some(lobby_data) = self.lobbies.get(lobby_id) else {
+            return;
+        };
+        if let Ok(mut lobby) = lobby_q.get_mut(lobby_data.lobby_entity) {
+            lobby.set_member_count(lobby_data.member_count());
         }
     }
 
+    // the world server disconnects the spectator, which returns their client to the main menu
+    fn remove_spectator(
+        &mut self,
+        naia_server: &mut Server,
+        user_manager: &mut UserManager,
+        world_manager: &mut WorldManager,
+        main_menu_room_key: &RoomKey,
+        user_id: &UserId,
+    ) {
+        let Some(lobby_data) = self
+            .lobbies
+            .values_mut()
+            .find(|lobby_data| lobby_data.spectators.contains(user_id))
+        else {
+            warn!("user is not spectating - [userid {:?}]", user_id);
+            return;
+        };
+        lobby_data.spectators.remove(user_id);
+
+        if let Some(world_instance_secret) = user_manager.user_set_world_disconnected(user_id) {
+            world_manager.world_set_user_disconnected(user_id, &world_instance_secret);
+        }
+
+        let Some(user_key) = user_manager.user_id_to_key(user_id) else {
+            return;
+        };
+        naia_server
+            .room_mut(main_menu_room_key)
+            // add user to global room
+            .add_user(&user_key);
+    }
+
     fn start_lobby(&mut self, lobby_q: &mut Query<&mut Lobby>, lobby_id: &LobbyId) {
         let lobby_data = self.lobbies.get_mut(&lobby_id).unwrap();
         lobby_data.start();
@@ -740,4 +1335,107 @@ impl LobbyManager {
         let mut lobby = lobby_q.get_mut(lobby_entity).unwrap();
         lobby.start();
     }
+
+    fn end_lobby(
+        &mut self,
+        naia_server: &mut Server,
+        user_manager: &mut UserManager,
+        world_manager: &mut WorldManager,
+        lobby_q: &mut Query<&mut Lobby>,
+        main_menu_room_key: &RoomKey,
+        lobby_id: &LobbyId,
+        outcome: MatchOutcome,
+        results: &Vec<(UserId, MatchUserResult)>,
+    ) {
+        let Some(lobby_data) = self.lobbies.get_mut(lobby_id) else {
+            warn!(
+                "attempted to end match of non-existent lobby - [lobbyid {:?}]",
+                lobby_id
+            );
+            return;
+        };
+        let was_in_progress = lobby_data.state == LobbyState::InProgress;
+        lobby_data.end(outcome);
+
+        let lobby_entity = lobby_data.lobby_entity;
+        let lobby_room_key = lobby_data.room_key;
+        let member_user_ids: Vec<UserId> =
+            lobby_data.lobby_member_entities.values().copied().collect();
+        let spectator_user_ids = std::mem::take(&mut lobby_data.spectators);
+
+        if let Ok(mut lobby) = lobby_q.get_mut(lobby_entity) {
+            lobby.end(outcome);
+        }
+
+        if !was_in_progress {
+            // this lobby's match never ran on this session server (we are syncing), nothing to undo
+            return;
+        }
+
+        // move lobby entity from lobby room back to global room
+        naia_server
+            .room_mut(&lobby_room_key)
+            .remove_entity(&lobby_entity);
+        naia_server
+            .room_mut(main_menu_room_key)
+            .add_entity(&lobby_entity);
+
+        // return this session server's players to the main menu
+        for user_id in member_user_ids {
+            let Some(world_instance_secret) = user_manager.user_set_world_disconnected(&user_id)
+            else {
+                continue;
+            };
+            world_manager.world_set_user_disconnected(&user_id, &world_instance_secret);
+
+            let user_entity = user_manager.get_user_entity(&user_id).unwrap();
+
+            naia_server
+                .room_mut(&lobby_room_key)
+                .remove_entity(&user_entity);
+            naia_server
+                .room_mut(main_menu_room_key)
+                // add user entity to global room
+                .add_entity(&user_entity);
+
+            // a reconnecting user is put in the global room when they're back
+            let Some(user_key) = user_manager.user_id_to_key(&user_id) else {
+                continue;
+            };
+
+            naia_server
+                .room_mut(main_menu_room_key)
+                // add user to global room
+                .add_user(&user_key);
+
+            // send results to user
+            let result = results
+                .iter()
+                .find(|(result_user_id, _)| *result_user_id == user_id)
+                .map(|(_, result)| *result);
+            let message = MatchLobbyGameEnded::new(outcome, result);
+            naia_server.send_message::<PrimaryChannel, MatchLobbyGameEnded>(&user_key, &message);
+        }
+
+        // and its spectators, who have no entity in the lobby room and no result
+        for user_id in spectator_user_ids {
+            let Some(world_instance_secret) = user_manager.user_set_world_disconnected(&user_id)
+            else {
+                continue;
+            };
+            world_manager.world_set_user_disconnected(&user_id, &world_instance_secret);
+
+            let Some(user_key) = user_manager.user_id_to_key(&user_id) else {
+                continue;
+            };
+
+            naia_server
+                .room_mut(main_menu_room_key)
+                // add user to global room
+                .add_user(&user_key);
+
+            let message = MatchLobbyGameEnded::new(outcome, None);
+            naia_server.send_message::<PrimaryChannel, MatchLobbyGameEnded>(&user_key, &message);
+        }
+    }
 }
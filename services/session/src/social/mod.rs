@@ -4,6 +4,8 @@ mod social_manager;
 pub use social_manager::*;
 
 mod chat_message_manager;
+mod direct_message_manager;
+mod friend_manager;
 mod lobby_manager;
 mod user_presence_manager;
 
@@ -17,9 +17,12 @@ impl Plugin for SocialPlugin {
                 (
                     SocialManager::update,
                     http_endpoints::recv_patch_users_request,
+                    http_endpoints::recv_patch_friends_request,
+                    http_endpoints::recv_patch_direct_messages_request,
                     http_endpoints::recv_patch_global_chat_messages_request,
                     http_endpoints::recv_patch_match_lobby_request,
                     http_endpoints::recv_world_connect,
+                    http_endpoints::recv_world_spectate,
                 )
                     .in_set(ReceiveEvents),
             );
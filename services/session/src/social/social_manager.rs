@@ -12,10 +12,12 @@ use session_server_naia_proto::components::{Lobby, User};
 use crate::{
     session_instance::SessionInstance,
     social::{
-        chat_message_manager::ChatMessageManager, lobby_manager::LobbyManager,
+        chat_message_manager::ChatMessageManager, direct_message_manager::DirectMessageManager,
+        friend_manager::FriendManager, lobby_manager::LobbyManager,
         user_presence_manager::UserPresenceManager,
     },
     user::UserManager,
+    world::WorldManager,
 };
 
 #[derive(Resource)]
@@ -27,6 +29,8 @@ pub struct SocialManager {
     pub(crate) chat_message_manager: ChatMessageManager,
     pub(crate) lobby_manager: LobbyManager,
     pub(crate) user_presence_manager: UserPresenceManager,
+    pub(crate) friend_manager: FriendManager,
+    pub(crate) direct_message_manager: DirectMessageManager,
 }
 
 impl SocialManager {
@@ -38,6 +42,8 @@ impl SocialManager {
             chat_message_manager: ChatMessageManager::new(),
             lobby_manager: LobbyManager::new(),
             user_presence_manager: UserPresenceManager::new(),
+            friend_manager: FriendManager::new(),
+            direct_message_manager: DirectMessageManager::new(),
         }
     }
 
@@ -47,6 +53,7 @@ impl SocialManager {
         naia_server: &mut Server,
         http_client: &mut HttpClient,
         user_manager: &mut UserManager,
+        world_manager: &mut WorldManager,
         lobby_q: &mut Query<&mut Lobby>,
         main_menu_room_key: &RoomKey,
         patches: &Vec<SocialLobbyPatch>,
@@ -56,6 +63,7 @@ impl SocialManager {
             naia_server,
             http_client,
             user_manager,
+            world_manager,
             &mut self.chat_message_manager,
             lobby_q,
             main_menu_room_key,
@@ -97,6 +105,7 @@ impl SocialManager {
         mut http_client: ResMut<HttpClient>,
         session_instance: Res<SessionInstance>,
         mut user_manager: ResMut<UserManager>,
+        mut world_manager: ResMut<WorldManager>,
         mut users_q: Query<&mut User>,
         mut lobby_q: Query<&mut Lobby>,
     ) {
@@ -107,6 +116,7 @@ impl SocialManager {
             &mut naia_server,
             &mut http_client,
             &mut user_manager,
+            &mut world_manager,
             &mut lobby_q,
             &social_server_url,
             &session_instance,
@@ -121,6 +131,7 @@ impl SocialManager {
         naia_server: &mut Server,
         http_client: &mut HttpClient,
         user_manager: &mut UserManager,
+        world_manager: &mut WorldManager,
         lobby_q: &mut Query<&mut Lobby>,
         social_server_url: &Option<(String, u16)>,
         session_instance: &SessionInstance,
@@ -142,6 +153,7 @@ impl SocialManager {
             naia_server,
             http_client,
             user_manager,
+            world_manager,
             lobby_q,
             social_server_url,
             session_instance,
@@ -154,5 +166,20 @@ impl SocialManager {
             session_instance,
             users_q,
         );
+        self.friend_manager.update(
+            http_client,
+            user_manager,
+            social_server_url,
+            session_instance,
+        );
+        self.direct_message_manager.update(
+            commands,
+            naia_server,
+            http_client,
+            user_manager,
+            social_server_url,
+            session_instance,
+            global_room_key,
+        );
     }
 }
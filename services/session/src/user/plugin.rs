@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use bevy_app::{App, Plugin, Startup, Update};
 use bevy_ecs::prelude::IntoSystemConfigs;
 
@@ -5,7 +7,9 @@ use naia_bevy_server::{
     Plugin as NaiaServerPlugin, ReceiveEvents, ServerConfig as NaiaServerConfig,
 };
 
-use session_server_naia_proto::protocol as naia_protocol;
+use config::{link_conditions, reconnect_grace_period_secs};
+use logging::info;
+use session_server_naia_proto::{conditioned_protocol, LinkConditionerConfig};
 
 use super::{systems, user_manager::UserManager};
 
@@ -13,11 +17,19 @@ pub struct UserPlugin;
 
 impl Plugin for UserPlugin {
     fn build(&self, app: &mut App) {
+        // how long a dropped user stays online, with their lobby, before social hears they left
+        let reconnect_grace_period = Duration::from_secs(reconnect_grace_period_secs().into());
+
+        let link_conditioner = link_conditions().map(|conditions| {
+            info!("simulating link conditions: {}", conditions);
+            LinkConditionerConfig::new(conditions.latency_ms, conditions.jitter_ms, conditions.loss)
+        });
+
         app.add_plugins(NaiaServerPlugin::new(
             NaiaServerConfig::default(),
-            naia_protocol(),
+            conditioned_protocol(link_conditioner),
         ))
-        .init_resource::<UserManager>()
+        .insert_resource(UserManager::new(reconnect_grace_period))
         .add_systems(Startup, systems::startup::server)
         .add_systems(
             Update,
@@ -26,6 +38,7 @@ impl Plugin for UserPlugin {
                 systems::auth_events,
                 systems::connect_events,
                 systems::disconnect_events,
+                systems::expire_disconnected_users,
                 systems::error_events,
                 systems::message_events,
                 systems::scope_checks,
@@ -8,7 +8,10 @@ use naia_bevy_server::{
 use bevy_http_client::HttpClient;
 use logging::{info, warn};
 
-use session_server_naia_proto::messages::Auth;
+use session_server_naia_proto::{
+    channels::PrimaryChannel,
+    messages::{Auth, WorldConnectToken},
+};
 
 use crate::{
     asset::asset_manager::AssetManager, session_instance::SessionInstance, social::SocialManager,
@@ -25,7 +28,10 @@ pub fn auth_events(
             if let Some(user_id) = user_manager.spend_login_token(&auth.token()) {
                 info!("Accepted connection. Token: {}", auth.token());
 
-                user_manager.accept_user(user_key, user_id);
+                if let Some(old_user_key) = user_manager.accept_user(user_key, user_id) {
+                    // the user has reconnected before their old connection timed out
+                    server.user_mut(&old_user_key).disconnect();
+                }
 
                 // Accept incoming connection
                 server.accept_connection(&user_key);
@@ -64,9 +70,24 @@ pub fn connect_events(
             &user_key,
             &main_menu_room_key,
         );
+        let user_id = user_manager.user_key_to_id(user_key).unwrap();
+
+        // a user coming back within their grace period picks up where they left off
+        if let Some(lobby_id) = user_manager.get_user_lobby_id(&user_id) {
+            if let Some(lobby_room_key) = social_manager.lobby_manager.get_lobby_room_key(&lobby_id)
+            {
+                server.room_mut(&lobby_room_key).add_user(user_key);
+            }
+        }
 
-        // add to main menu room
-        server.room_mut(&main_menu_room_key).add_user(user_key);
+        if let Some(world_login_token) = user_manager.user_world_login_token(&user_id) {
+            // still in a match, so send them back to it
+            let token = WorldConnectToken::new(&world_login_token);
+            server.send_message::<PrimaryChannel, WorldConnectToken>(user_key, &token);
+        } else {
+            // add to main menu room
+            server.room_mut(&main_menu_room_key).add_user(user_key);
+        }
 
         // Assets
         asset_manager.register_user(user_key);
@@ -74,15 +95,17 @@ pub fn connect_events(
         cfg_if::cfg_if!(
             if #[cfg(feature = "odst")] {
                 // setup world connection
-                crate::odst::handle_world_connection(
-                    &mut commands,
-                    &mut server,
-                    &mut http_client,
-                    &mut world_manager,
-                    &mut user_manager,
-                    &mut social_manager,
-                    user_key
-                );
+                if !user_manager.user_has_world_connection(user_key) {
+                    crate::odst::handle_world_connection(
+                        &mut commands,
+                        &mut server,
+                        &mut http_client,
+                        &mut world_manager,
+                        &mut user_manager,
+                        &mut social_manager,
+                        user_key
+                    );
+                }
             } else {
                 // load "default" assets
                 crate::asset::user_load_default_assets(&mut server, &mut http_client, &mut asset_manager, user_key);
@@ -94,11 +117,8 @@ pub fn connect_events(
 pub fn disconnect_events(
     mut commands: Commands,
     mut naia_server: Server,
-    mut http_client: ResMut<HttpClient>,
     mut user_manager: ResMut<UserManager>,
-    mut social_manager: ResMut<SocialManager>,
     mut asset_manager: ResMut<AssetManager>,
-    session_instance: Res<SessionInstance>,
     mut event_reader: EventReader<DisconnectEvent>,
 ) {
     for DisconnectEvent(user_key, user) in event_reader.read() {
@@ -106,14 +126,28 @@ pub fn disconnect_events(
 
         // TODO: probably need to deregister user from global too?
 
-        // remove from user manager
-        let user_id = user_manager
-            .disconnect_user(&mut commands, &mut naia_server, user_key)
-            .unwrap();
-
         // remove from asset manager
         asset_manager.deregister_user(user_key);
 
+        // remove from user manager, social hears about it once the grace period is up
+        if user_manager
+            .disconnect_user(&mut commands, &mut naia_server, user_key)
+            .is_none()
+        {
+            info!("Replaced connection closed: {:?}", user_key);
+        }
+    }
+}
+
+pub fn expire_disconnected_users(
+    mut http_client: ResMut<HttpClient>,
+    mut user_manager: ResMut<UserManager>,
+    mut social_manager: ResMut<SocialManager>,
+    session_instance: Res<SessionInstance>,
+) {
+    for user_id in user_manager.take_expired_users() {
+        info!("User did not reconnect in time: {:?}", user_id);
+
         let social_server_url = social_manager.get_social_server_url();
 
         // send user disconnect to social server
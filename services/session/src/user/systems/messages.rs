@@ -1,20 +1,24 @@
 use bevy_ecs::{change_detection::ResMut, event::EventReader, system::Res};
 
-use naia_bevy_server::events::MessageEvents;
+use naia_bevy_server::{events::MessageEvents, Server};
 
 use bevy_http_client::HttpClient;
+use logging::warn;
 
 use session_server_naia_proto::{
     channels::ClientActionsChannel,
     messages::{
-        GlobalChatSendMessage, MatchLobbyCreate, MatchLobbyGameStart, MatchLobbyJoin,
-        MatchLobbyLeave, MatchLobbySendMessage,
+        ChatHistoryRequest, DirectMessageSend, FriendActionRequest, GlobalChatSendMessage,
+        MatchLobbyCreate, MatchLobbyGameStart, MatchLobbyInvite, MatchLobbyJoin, MatchLobbyKick,
+        MatchLobbyLeave, MatchLobbyLeaveSpectate, MatchLobbySendMessage, MatchLobbySpectate,
+        UserBlock, UserSetAway,
     },
 };
 
 use crate::{session_instance::SessionInstance, social::SocialManager, user::UserManager};
 
 pub fn message_events(
+    naia_server: Server,
     mut http_client: ResMut<HttpClient>,
     user_manager: Res<UserManager>,
     mut social_manager: ResMut<SocialManager>,
@@ -50,6 +54,21 @@ pub fn message_events(
             );
         }
 
+        // Chat History
+        for (user_key, req) in events.read::<ClientActionsChannel, ChatHistoryRequest>() {
+            social_manager
+                .chat_message_manager
+                .send_chat_history_request(
+                    &mut http_client,
+                    &user_manager,
+                    social_server_url.as_ref(),
+                    &session_instance,
+                    &user_key,
+                    req.lobby_chat,
+                    req.before_message_id,
+                );
+        }
+
         // Create Match Lobby
         for (user_key, req) in events.read::<ClientActionsChannel, MatchLobbyCreate>() {
             social_manager.lobby_manager.send_match_lobby_create(
@@ -59,6 +78,8 @@ pub fn message_events(
                 &session_instance,
                 &user_key,
                 &req.match_name,
+                req.settings,
+                req.password.as_deref(),
             );
         }
 
@@ -71,6 +92,7 @@ pub fn message_events(
                 &session_instance,
                 &user_key,
                 &req.match_id,
+                req.password.as_deref(),
             );
         }
 
@@ -85,6 +107,31 @@ pub fn message_events(
             );
         }
 
+        // Spectate Match
+        for (user_key, req) in events.read::<ClientActionsChannel, MatchLobbySpectate>() {
+            social_manager.lobby_manager.send_match_lobby_spectate(
+                &mut http_client,
+                &user_manager,
+                social_server_url.as_ref(),
+                &session_instance,
+                &user_key,
+                &req.match_id,
+            );
+        }
+
+        // Stop Spectating Match
+        for (user_key, _req) in events.read::<ClientActionsChannel, MatchLobbyLeaveSpectate>() {
+            social_manager
+                .lobby_manager
+                .send_match_lobby_leave_spectate(
+                    &mut http_client,
+                    &user_manager,
+                    social_server_url.as_ref(),
+                    &session_instance,
+                    &user_key,
+                );
+        }
+
         // Leave Match Lobby
         for (user_key, _req) in events.read::<ClientActionsChannel, MatchLobbyLeave>() {
             social_manager.lobby_manager.send_match_lobby_leave(
@@ -95,5 +142,120 @@ pub fn message_events(
                 &user_key,
             );
         }
+
+        // Kick from Match Lobby
+        for (user_key, req) in events.read::<ClientActionsChannel, MatchLobbyKick>() {
+            let Some(kicked_user_id) = req
+                .user_entity
+                .get(&naia_server)
+                .and_then(|user_entity| user_manager.user_entity_to_id(&user_entity))
+            else {
+                warn!("kick request for unknown user entity");
+                continue;
+            };
+            social_manager.lobby_manager.send_match_lobby_kick(
+                &mut http_client,
+                &user_manager,
+                social_server_url.as_ref(),
+                &session_instance,
+                &user_key,
+                &kicked_user_id,
+            );
+        }
+
+        // Invite to Match Lobby
+        for (user_key, req) in events.read::<ClientActionsChannel, MatchLobbyInvite>() {
+            let Some(invited_user_id) = req
+                .user_entity
+                .get(&naia_server)
+                .and_then(|user_entity| user_manager.user_entity_to_id(&user_entity))
+            else {
+                warn!("invite request for unknown user entity");
+                continue;
+            };
+            social_manager.lobby_manager.send_match_lobby_invite(
+                &mut http_client,
+                &user_manager,
+                social_server_url.as_ref(),
+                &session_instance,
+                &user_key,
+                &invited_user_id,
+            );
+        }
+
+        // Friend Actions
+        for (user_key, req) in events.read::<ClientActionsChannel, FriendActionRequest>() {
+            let Some(other_user_id) = req
+                .user_entity
+                .get(&naia_server)
+                .and_then(|user_entity| user_manager.user_entity_to_id(&user_entity))
+            else {
+                warn!("friend action request for unknown user entity");
+                continue;
+            };
+            social_manager.friend_manager.send_friend_action(
+                &mut http_client,
+                &user_manager,
+                social_server_url.as_ref(),
+                &session_instance,
+                &user_key,
+                &other_user_id,
+                req.action,
+            );
+        }
+
+        // Set Away
+        for (user_key, req) in events.read::<ClientActionsChannel, UserSetAway>() {
+            social_manager.friend_manager.send_user_set_away(
+                &mut http_client,
+                &user_manager,
+                social_server_url.as_ref(),
+                &session_instance,
+                &user_key,
+                req.away,
+            );
+        }
+
+        // Direct Messages
+        for (user_key, req) in events.read::<ClientActionsChannel, DirectMessageSend>() {
+            let Some(recipient_user_id) = req
+                .user_entity
+                .get(&naia_server)
+                .and_then(|user_entity| user_manager.user_entity_to_id(&user_entity))
+            else {
+                warn!("direct message for unknown user entity");
+                continue;
+            };
+            social_manager.direct_message_manager.send_direct_message(
+                &mut http_client,
+                &user_manager,
+                social_server_url.as_ref(),
+                &session_instance,
+                &user_key,
+                &recipient_user_id,
+                &req.message,
+            );
+        }
+
+        // Block / Unblock User
+        for (user_key, req) in events.read::<ClientActionsChannel, UserBlock>() {
+            let Some(other_user_id) = req
+                .user_entity
+                .get(&naia_server)
+                .and_then(|user_entity| user_manager.user_entity_to_id(&user_entity))
+            else {
+                warn!("user block request for unknown user entity");
+                continue;
+            };
+            social_manager.direct_message_manager.send_user_block(
+                &mut http_client,
+                &user_manager,
+                social_server_url.as_ref(),
+                &session_instance,
+                &user_key,
+                &other_user_id,
+                req.blocked,
+            );
+        }
     }
 }
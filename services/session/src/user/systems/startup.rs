@@ -3,8 +3,8 @@ use std::net::SocketAddr;
 use naia_bevy_server::{transport::webrtc, Server};
 
 use config::{
-    PUBLIC_IP_ADDR, PUBLIC_PROTOCOL, SELF_BINDING_ADDR, SESSION_SERVER_SIGNAL_PORT,
-    SESSION_SERVER_WEBRTC_PORT,
+    public_ip_addr, public_protocol, self_binding_addr, session_server_signal_port,
+    session_server_webrtc_port,
 };
 use logging::info;
 
@@ -14,18 +14,20 @@ pub fn server(mut server: Server) {
     let server_addresses = webrtc::ServerAddrs::new(
         // IP Address to listen on for WebRTC signaling
         SocketAddr::new(
-            SELF_BINDING_ADDR.parse().unwrap(),
-            SESSION_SERVER_SIGNAL_PORT,
+            self_binding_addr().parse().unwrap(),
+            session_server_signal_port(),
         ),
         // IP Address to listen on for UDP WebRTC data channels
         SocketAddr::new(
-            SELF_BINDING_ADDR.parse().unwrap(),
-            SESSION_SERVER_WEBRTC_PORT,
+            self_binding_addr().parse().unwrap(),
+            session_server_webrtc_port(),
         ),
         // The public WebRTC IP address to advertise
         format!(
             "{}://{}:{}",
-            PUBLIC_PROTOCOL, PUBLIC_IP_ADDR, SESSION_SERVER_WEBRTC_PORT
+            public_protocol(),
+            public_ip_addr(),
+            session_server_webrtc_port()
         )
         .as_str(),
     );
@@ -15,7 +15,8 @@ pub(crate) struct UserData {
     lobby_id: Option<(LobbyId, Entity)>,
 
     // LATER this may be used to send meaningful data about a user back to the given world server instance..
-    world_instance_secret: Option<String>,
+    // world instance secret, world login token
+    world_connection: Option<(String, String)>,
 
     requesting_info: bool,
     make_online_after_info: Option<bool>,
@@ -31,7 +32,7 @@ impl UserData {
 
             lobby_id: None,
 
-            world_instance_secret: None, // tells us whether user is connected
+            world_connection: None, // tells us whether user is connected
 
             requesting_info: true,
             make_online_after_info: None,
@@ -88,11 +89,27 @@ impl UserData {
     }
 
     pub fn get_world_connected(&self) -> bool {
-        self.world_instance_secret.is_some()
+        self.world_connection.is_some()
     }
 
-    pub fn set_world_connected(&mut self, world_instance_secret: &str) {
-        self.world_instance_secret = Some(world_instance_secret.to_string());
+    pub fn set_world_connected(&mut self, world_instance_secret: &str, world_login_token: &str) {
+        self.world_connection = Some((
+            world_instance_secret.to_string(),
+            world_login_token.to_string(),
+        ));
+    }
+
+    // returns the world instance secret the user was connected to
+    pub fn set_world_disconnected(&mut self) -> Option<String> {
+        self.world_connection
+            .take()
+            .map(|(world_instance_secret, _)| world_instance_secret)
+    }
+
+    pub fn world_login_token(&self) -> Option<String> {
+        self.world_connection
+            .as_ref()
+            .map(|(_, world_login_token)| world_login_token.clone())
     }
 
     pub fn user_entity(&self) -> Entity {
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use auth_server_http_proto::{UserGetRequest, UserGetResponse};
 use auth_server_types::UserId;
 use bevy_http_client::{ApiRequest, ApiResponse, HttpClient, ResponseKey};
-use config::{AUTH_SERVER_PORT, AUTH_SERVER_RECV_ADDR};
+use config::{auth_server_port, auth_server_recv_addr};
 use logging::{info, warn};
 
 pub(crate) struct UserInfoService {
@@ -66,7 +66,7 @@ impl UserInfoService {
         let host = "session";
         let remote = "auth";
         bevy_http_client::log_util::send_req(host, remote, UserGetRequest::name());
-        let response_key = http_client.send(AUTH_SERVER_RECV_ADDR, AUTH_SERVER_PORT, request);
+        let response_key = http_client.send(auth_server_recv_addr(), auth_server_port(), request);
 
         self.inflight_user_info_requests
             .insert(*user_id, response_key);
@@ -1,4 +1,7 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
 
 use bevy_ecs::{
     change_detection::ResMut,
@@ -26,20 +29,26 @@ pub struct UserManager {
     user_key_to_id: HashMap<UserKey, UserId>,
     user_data: HashMap<UserId, UserData>,
     user_info_service: UserInfoService,
+
+    // users who dropped, and when social should be told they went offline,
+    // unless they reconnect first
+    disconnected_users: HashMap<UserId, Instant>,
+    reconnect_grace_period: Duration,
 }
 
-impl Default for UserManager {
-    fn default() -> Self {
+impl UserManager {
+    pub fn new(reconnect_grace_period: Duration) -> Self {
         Self {
             login_token_store: UserLoginTokenStore::new(),
             user_key_to_id: HashMap::new(),
             user_data: HashMap::new(),
             user_info_service: UserInfoService::new(),
+
+            disconnected_users: HashMap::new(),
+            reconnect_grace_period,
         }
     }
-}
 
-impl UserManager {
     // used as a system
     pub fn update(
         mut commands: Commands,
@@ -79,10 +88,14 @@ impl UserManager {
         self.login_token_store.spend_login_token(token)
     }
 
-    pub fn accept_user(&mut self, user_key: UserKey, user_id: UserId) {
+    // returns the user's previous connection, if it hasn't been dropped yet
+    pub fn accept_user(&mut self, user_key: UserKey, user_id: UserId) -> Option<UserKey> {
+        let old_user_key = self.user_id_to_key(&user_id);
         self.user_key_to_id.insert(user_key, user_id);
+        old_user_key
     }
 
+    // returns None for a connection that has already been replaced by a newer one
     pub fn disconnect_user(
         &mut self,
         commands: &mut Commands,
@@ -90,13 +103,33 @@ impl UserManager {
         user_key: &UserKey,
     ) -> Option<UserId> {
         let user_id = self.user_key_to_id.remove(user_key)?;
-        self.user_data
-            .get_mut(&user_id)
-            .unwrap()
-            .disconnect(commands, naia_server);
+        let user_data = self.user_data.get_mut(&user_id).unwrap();
+        if user_data.user_key() != Some(*user_key) {
+            return None;
+        }
+        user_data.disconnect(commands, naia_server);
+
+        let expires_at = Instant::now() + self.reconnect_grace_period;
+        self.disconnected_users.insert(user_id, expires_at);
+
         Some(user_id)
     }
 
+    // users whose grace period has run out without them reconnecting
+    pub fn take_expired_users(&mut self) -> Vec<UserId> {
+        let now = Instant::now();
+        let expired_users: Vec<UserId> = self
+            .disconnected_users
+            .iter()
+            .filter(|(_, expires_at)| **expires_at <= now)
+            .map(|(user_id, _)| *user_id)
+            .collect();
+        for user_id in &expired_users {
+            self.disconnected_users.remove(user_id);
+        }
+        expired_users
+    }
+
     pub fn connect_user(
         &mut self,
         commands: &mut Commands,
@@ -106,6 +139,8 @@ impl UserManager {
         main_menu_room_key: &RoomKey,
     ) {
         let user_id = self.user_key_to_id(user_key).unwrap();
+        self.disconnected_users.remove(&user_id);
+
         if !self.has_user_data(&user_id) {
             self.add_user_data(
                 commands,
@@ -117,6 +152,11 @@ impl UserManager {
         }
 
         let user_data = self.user_data.get_mut(&user_id).unwrap();
+        if let Some(old_user_key) = user_data.user_key() {
+            // the old connection hasn't been dropped yet, so its disconnect event is ignored
+            self.user_key_to_id.remove(&old_user_key);
+            user_data.disconnect(commands, naia_server);
+        }
         user_data.connect(commands, naia_server, &user_key);
     }
 
@@ -136,10 +176,26 @@ impl UserManager {
         user_data.get_world_connected()
     }
 
-    pub fn user_set_world_connected(&mut self, user_key: &UserKey, world_instance_secret: &str) {
-        let user_id = self.user_key_to_id(user_key).unwrap();
-        let user_data = self.user_data.get_mut(&user_id).unwrap();
-        user_data.set_world_connected(world_instance_secret);
+    pub fn user_set_world_connected(
+        &mut self,
+        user_id: &UserId,
+        world_instance_secret: &str,
+        world_login_token: &str,
+    ) {
+        let user_data = self.user_data.get_mut(user_id).unwrap();
+        user_data.set_world_connected(world_instance_secret, world_login_token);
+    }
+
+    // returns the world instance secret the user was connected to
+    pub fn user_set_world_disconnected(&mut self, user_id: &UserId) -> Option<String> {
+        let user_data = self.user_data.get_mut(user_id)?;
+        user_data.set_world_disconnected()
+    }
+
+    // a reconnecting user is sent this again, to rejoin their match
+    pub fn user_world_login_token(&self, user_id: &UserId) -> Option<String> {
+        let user_data = self.user_data.get(user_id)?;
+        user_data.world_login_token()
     }
 
     // user entities
@@ -152,6 +208,13 @@ impl UserManager {
         self.user_data.get(user_id).map(|data| data.user_entity())
     }
 
+    pub(crate) fn user_entity_to_id(&self, user_entity: &Entity) -> Option<UserId> {
+        self.user_data
+            .iter()
+            .find(|(_, data)| data.user_entity() == *user_entity)
+            .map(|(user_id, _)| *user_id)
+    }
+
     pub(crate) fn add_user_data(
         &mut self,
         commands: &mut Commands,
@@ -8,10 +8,11 @@ use logging::{info, warn};
 
 use session_server_http_proto::{UserAssetIdRequest, UserAssetIdResponse};
 
-use crate::{asset::asset_manager::AssetManager, world::WorldManager};
+use crate::{asset::asset_manager::AssetManager, user::UserManager, world::WorldManager};
 
 pub fn recv_added_asset_id_request(
     world_connections: Res<WorldManager>,
+    user_manager: Res<UserManager>,
     mut http_server: ResMut<HttpServer>,
     mut naia_server: Server,
     mut http_client: ResMut<HttpClient>,
@@ -35,9 +36,17 @@ pub fn recv_added_asset_id_request(
             user_id, asset_id
         );
 
-        let user_key = world_connections
-            .get_user_key_from_world_instance(world_instance_secret, &user_id)
-            .unwrap();
+        if !world_connections.world_instance_has_user(world_instance_secret, &user_id) {
+            warn!("user not connected to world instance: {:?}", user_id);
+            http_server.respond(response_key, Err(ResponseError::NotFound));
+            continue;
+        }
+
+        let Some(user_key) = user_manager.user_id_to_key(&user_id) else {
+            // the user is reconnecting, the world server registers their assets again once they're back
+            http_server.respond(response_key, Ok(UserAssetIdResponse));
+            continue;
+        };
 
         if added {
             asset_manager.load_user_asset(&mut naia_server, &mut http_client, user_key, asset_id);
@@ -1,9 +1,7 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use bevy_ecs::system::Resource;
 
-use naia_bevy_server::UserKey;
-
 use auth_server_types::UserId;
 
 #[derive(Resource)]
@@ -26,42 +24,54 @@ impl WorldManager {
         self.world_instances.contains_key(world_instance_secret)
     }
 
-    pub fn get_user_key_from_world_instance(
-        &self,
-        world_instance_secret: &str,
-        user_id: &UserId,
-    ) -> Option<UserKey> {
-        let world_instance = self.world_instances.get(world_instance_secret)?;
-        world_instance.user_id_to_key_map.get(user_id).copied()
+    pub fn world_instance_has_user(&self, world_instance_secret: &str, user_id: &UserId) -> bool {
+        let Some(world_instance) = self.world_instances.get(world_instance_secret) else {
+            return false;
+        };
+        world_instance.user_ids.contains(user_id)
     }
 
-    pub fn world_set_user_connected(
-        &mut self,
-        user_id: &UserId,
-        user_key: &UserKey,
-        world_instance_secret: &str,
-    ) {
+    pub fn world_set_user_connected(&mut self, user_id: &UserId, world_instance_secret: &str) {
         if !self.world_instances.contains_key(world_instance_secret) {
             self.world_instances
                 .insert(world_instance_secret.to_string(), WorldInstanceData::new());
         }
         let world_instance = self.world_instances.get_mut(world_instance_secret).unwrap();
-        world_instance.add_user(*user_key, *user_id);
+        world_instance.add_user(*user_id);
+    }
+
+    pub fn world_set_user_disconnected(&mut self, user_id: &UserId, world_instance_secret: &str) {
+        let Some(world_instance) = self.world_instances.get_mut(world_instance_secret) else {
+            return;
+        };
+        world_instance.remove_user(user_id);
+        if world_instance.is_empty() {
+            self.world_instances.remove(world_instance_secret);
+        }
     }
 }
 
+// users stay here while reconnecting, so this is keyed by id rather than connection
 struct WorldInstanceData {
-    user_id_to_key_map: HashMap<UserId, UserKey>,
+    user_ids: HashSet<UserId>,
 }
 
 impl WorldInstanceData {
     pub fn new() -> Self {
         Self {
-            user_id_to_key_map: HashMap::new(),
+            user_ids: HashSet::new(),
         }
     }
 
-    pub(crate) fn add_user(&mut self, user_key: UserKey, user_id: UserId) {
-        self.user_id_to_key_map.insert(user_id, user_key);
+    pub(crate) fn add_user(&mut self, user_id: UserId) {
+        self.user_ids.insert(user_id);
+    }
+
+    pub(crate) fn remove_user(&mut self, user_id: &UserId) {
+        self.user_ids.remove(user_id);
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.user_ids.is_empty()
     }
 }
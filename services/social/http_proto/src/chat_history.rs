@@ -0,0 +1,99 @@
+use auth_server_types::UserId;
+use http_common::{ApiRequest, ApiResponse, Method};
+use naia_serde::SerdeInternal as Serde;
+use social_server_types::{LobbyId, MessageId, Timestamp};
+
+// Request
+#[derive(Serde, PartialEq, Clone)]
+pub struct ChatHistoryRequest {
+    session_instance_secret: String,
+    user_id: UserId,
+    // None for global chat
+    lobby_id: Option<LobbyId>,
+    // None for the most recent page
+    before_message_id: Option<MessageId>,
+    count: u8,
+}
+
+impl ChatHistoryRequest {
+    pub fn new(
+        session_instance_secret: &str,
+        user_id: UserId,
+        lobby_id: Option<LobbyId>,
+        before_message_id: Option<MessageId>,
+        count: u8,
+    ) -> Self {
+        Self {
+            session_instance_secret: session_instance_secret.to_string(),
+            user_id,
+            lobby_id,
+            before_message_id,
+            count,
+        }
+    }
+
+    pub fn session_instance_secret(&self) -> &str {
+        &self.session_instance_secret
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn lobby_id(&self) -> Option<LobbyId> {
+        self.lobby_id
+    }
+
+    pub fn before_message_id(&self) -> Option<MessageId> {
+        self.before_message_id
+    }
+
+    pub fn count(&self) -> u8 {
+        self.count
+    }
+}
+
+// Response
+#[derive(Serde, PartialEq, Clone)]
+pub struct ChatHistoryResponse {
+    // oldest first
+    messages: Vec<(MessageId, Timestamp, UserId, String)>,
+    has_more: bool,
+}
+
+impl ChatHistoryResponse {
+    pub fn new(messages: Vec<(MessageId, Timestamp, UserId, String)>, has_more: bool) -> Self {
+        Self { messages, has_more }
+    }
+
+    pub fn messages(&self) -> &Vec<(MessageId, Timestamp, UserId, String)> {
+        &self.messages
+    }
+
+    pub fn has_more(&self) -> bool {
+        self.has_more
+    }
+}
+
+// Traits
+impl ApiRequest for ChatHistoryRequest {
+    type Response = ChatHistoryResponse;
+
+    fn name() -> &'static str {
+        "ChatHistoryRequest"
+    }
+
+    fn method() -> Method {
+        Method::Post
+    }
+
+    fn path() -> &'static str {
+        "chat_history"
+    }
+}
+
+impl ApiResponse for ChatHistoryResponse {
+    fn name() -> &'static str {
+        "ChatHistoryResponse"
+    }
+}
@@ -0,0 +1,93 @@
+use auth_server_types::UserId;
+use http_common::{ApiRequest, ApiResponse, Method};
+use naia_serde::SerdeInternal as Serde;
+use social_server_types::Timestamp;
+
+// Request
+#[derive(Serde, PartialEq, Clone)]
+pub struct DirectMessageSendRequest {
+    session_instance_secret: String,
+    sender_user_id: UserId,
+    recipient_user_id: UserId,
+    message: String,
+}
+
+impl DirectMessageSendRequest {
+    pub fn new(
+        session_instance_secret: &str,
+        sender_user_id: UserId,
+        recipient_user_id: UserId,
+        message: &str,
+    ) -> Self {
+        Self {
+            session_instance_secret: session_instance_secret.to_string(),
+            sender_user_id,
+            recipient_user_id,
+            message: message.to_string(),
+        }
+    }
+
+    pub fn session_instance_secret(&self) -> &str {
+        &self.session_instance_secret
+    }
+
+    pub fn sender_user_id(&self) -> UserId {
+        self.sender_user_id
+    }
+
+    pub fn recipient_user_id(&self) -> UserId {
+        self.recipient_user_id
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+// Response
+#[derive(Serde, PartialEq, Clone)]
+pub struct DirectMessageSendResponse {
+    timestamp: Timestamp,
+    // the message after moderation, as the recipient sees it
+    message: String,
+}
+
+impl DirectMessageSendResponse {
+    pub fn new(timestamp: Timestamp, message: &str) -> Self {
+        Self {
+            timestamp,
+            message: message.to_string(),
+        }
+    }
+
+    pub fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+// Traits
+impl ApiRequest for DirectMessageSendRequest {
+    type Response = DirectMessageSendResponse;
+
+    fn name() -> &'static str {
+        "DirectMessageSendRequest"
+    }
+
+    fn method() -> Method {
+        Method::Post
+    }
+
+    fn path() -> &'static str {
+        "direct_message_send"
+    }
+}
+
+impl ApiResponse for DirectMessageSendResponse {
+    fn name() -> &'static str {
+        "DirectMessageSendResponse"
+    }
+}
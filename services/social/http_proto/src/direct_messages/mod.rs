@@ -0,0 +1,5 @@
+mod direct_message_send;
+mod user_block;
+
+pub use direct_message_send::*;
+pub use user_block::*;
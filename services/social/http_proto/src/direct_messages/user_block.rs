@@ -0,0 +1,71 @@
+use auth_server_types::UserId;
+use http_common::{ApiRequest, ApiResponse, Method};
+use naia_serde::SerdeInternal as Serde;
+
+// Request
+#[derive(Serde, PartialEq, Clone)]
+pub struct UserBlockRequest {
+    session_instance_secret: String,
+    user_id: UserId,
+    other_user_id: UserId,
+    blocked: bool,
+}
+
+impl UserBlockRequest {
+    pub fn new(
+        session_instance_secret: &str,
+        user_id: UserId,
+        other_user_id: UserId,
+        blocked: bool,
+    ) -> Self {
+        Self {
+            session_instance_secret: session_instance_secret.to_string(),
+            user_id,
+            other_user_id,
+            blocked,
+        }
+    }
+
+    pub fn session_instance_secret(&self) -> &str {
+        &self.session_instance_secret
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn other_user_id(&self) -> UserId {
+        self.other_user_id
+    }
+
+    pub fn blocked(&self) -> bool {
+        self.blocked
+    }
+}
+
+// Response
+#[derive(Serde, PartialEq, Clone)]
+pub struct UserBlockResponse;
+
+// Traits
+impl ApiRequest for UserBlockRequest {
+    type Response = UserBlockResponse;
+
+    fn name() -> &'static str {
+        "UserBlockRequest"
+    }
+
+    fn method() -> Method {
+        Method::Post
+    }
+
+    fn path() -> &'static str {
+        "user_block"
+    }
+}
+
+impl ApiResponse for UserBlockResponse {
+    fn name() -> &'static str {
+        "UserBlockResponse"
+    }
+}
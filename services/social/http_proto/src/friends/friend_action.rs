@@ -0,0 +1,72 @@
+use auth_server_types::UserId;
+use http_common::{ApiRequest, ApiResponse, Method};
+use naia_serde::SerdeInternal as Serde;
+use social_server_types::FriendAction;
+
+// Request
+#[derive(Serde, PartialEq, Clone)]
+pub struct FriendActionRequest {
+    session_instance_secret: String,
+    user_id: UserId,
+    other_user_id: UserId,
+    action: FriendAction,
+}
+
+impl FriendActionRequest {
+    pub fn new(
+        session_instance_secret: &str,
+        user_id: UserId,
+        other_user_id: UserId,
+        action: FriendAction,
+    ) -> Self {
+        Self {
+            session_instance_secret: session_instance_secret.to_string(),
+            user_id,
+            other_user_id,
+            action,
+        }
+    }
+
+    pub fn session_instance_secret(&self) -> &str {
+        &self.session_instance_secret
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn other_user_id(&self) -> UserId {
+        self.other_user_id
+    }
+
+    pub fn action(&self) -> FriendAction {
+        self.action
+    }
+}
+
+// Response
+#[derive(Serde, PartialEq, Clone)]
+pub struct FriendActionResponse;
+
+// Traits
+impl ApiRequest for FriendActionRequest {
+    type Response = FriendActionResponse;
+
+    fn name() -> &'static str {
+        "FriendActionRequest"
+    }
+
+    fn method() -> Method {
+        Method::Post
+    }
+
+    fn path() -> &'static str {
+        "friend_action"
+    }
+}
+
+impl ApiResponse for FriendActionResponse {
+    fn name() -> &'static str {
+        "FriendActionResponse"
+    }
+}
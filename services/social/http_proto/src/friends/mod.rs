@@ -0,0 +1,5 @@
+mod friend_action;
+mod user_set_away;
+
+pub use friend_action::*;
+pub use user_set_away::*;
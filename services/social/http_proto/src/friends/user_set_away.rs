@@ -0,0 +1,60 @@
+use auth_server_types::UserId;
+use http_common::{ApiRequest, ApiResponse, Method};
+use naia_serde::SerdeInternal as Serde;
+
+// Request
+#[derive(Serde, PartialEq, Clone)]
+pub struct UserSetAwayRequest {
+    session_instance_secret: String,
+    user_id: UserId,
+    away: bool,
+}
+
+impl UserSetAwayRequest {
+    pub fn new(session_instance_secret: &str, user_id: UserId, away: bool) -> Self {
+        Self {
+            session_instance_secret: session_instance_secret.to_string(),
+            user_id,
+            away,
+        }
+    }
+
+    pub fn session_instance_secret(&self) -> &str {
+        &self.session_instance_secret
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn away(&self) -> bool {
+        self.away
+    }
+}
+
+// Response
+#[derive(Serde, PartialEq, Clone)]
+pub struct UserSetAwayResponse;
+
+// Traits
+impl ApiRequest for UserSetAwayRequest {
+    type Response = UserSetAwayResponse;
+
+    fn name() -> &'static str {
+        "UserSetAwayRequest"
+    }
+
+    fn method() -> Method {
+        Method::Post
+    }
+
+    fn path() -> &'static str {
+        "user_set_away"
+    }
+}
+
+impl ApiResponse for UserSetAwayResponse {
+    fn name() -> &'static str {
+        "UserSetAwayResponse"
+    }
+}
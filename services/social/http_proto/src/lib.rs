@@ -4,11 +4,23 @@ pub use heartbeat::*;
 mod global_chat_send_message;
 pub use global_chat_send_message::*;
 
+mod chat_history;
+pub use chat_history::*;
+
+mod moderation_mute_user;
+pub use moderation_mute_user::*;
+
 mod match_lobbies;
 pub use match_lobbies::*;
 
 mod users;
 pub use users::*;
 
+mod friends;
+pub use friends::*;
+
+mod direct_messages;
+pub use direct_messages::*;
+
 mod session_servers;
 pub use session_servers::*;
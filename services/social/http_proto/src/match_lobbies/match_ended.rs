@@ -0,0 +1,74 @@
+use auth_server_types::UserId;
+use http_common::{ApiRequest, ApiResponse, Method};
+use naia_serde::SerdeInternal as Serde;
+use social_server_types::{LobbyId, MatchOutcome, MatchUserResult};
+
+// this is forwarded by the region server, from the world server the match ran on
+
+// Request
+#[derive(Serde, PartialEq, Clone)]
+pub struct MatchEndedRequest {
+    region_secret: String,
+    lobby_id: LobbyId,
+    outcome: MatchOutcome,
+    results: Vec<(UserId, MatchUserResult)>,
+}
+
+impl MatchEndedRequest {
+    pub fn new(
+        region_secret: &str,
+        lobby_id: LobbyId,
+        outcome: MatchOutcome,
+        results: Vec<(UserId, MatchUserResult)>,
+    ) -> Self {
+        Self {
+            region_secret: region_secret.to_string(),
+            lobby_id,
+            outcome,
+            results,
+        }
+    }
+
+    pub fn region_secret(&self) -> &str {
+        &self.region_secret
+    }
+
+    pub fn lobby_id(&self) -> LobbyId {
+        self.lobby_id
+    }
+
+    pub fn outcome(&self) -> MatchOutcome {
+        self.outcome
+    }
+
+    pub fn results(&self) -> &Vec<(UserId, MatchUserResult)> {
+        &self.results
+    }
+}
+
+// Response
+#[derive(Serde, PartialEq, Clone)]
+pub struct MatchEndedResponse;
+
+// Traits
+impl ApiRequest for MatchEndedRequest {
+    type Response = MatchEndedResponse;
+
+    fn name() -> &'static str {
+        "MatchEndedRequest"
+    }
+
+    fn method() -> Method {
+        Method::Post
+    }
+
+    fn path() -> &'static str {
+        "match_ended"
+    }
+}
+
+impl ApiResponse for MatchEndedResponse {
+    fn name() -> &'static str {
+        "MatchEndedResponse"
+    }
+}
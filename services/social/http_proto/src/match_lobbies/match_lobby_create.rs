@@ -2,7 +2,7 @@ use naia_serde::SerdeInternal as Serde;
 
 use auth_server_types::UserId;
 use http_common::{ApiRequest, ApiResponse, Method};
-use social_server_types::LobbyId;
+use social_server_types::{LobbyId, LobbySettings};
 
 // Request
 #[derive(Serde, PartialEq, Clone)]
@@ -10,14 +10,24 @@ pub struct MatchLobbyCreateRequest {
     session_instance_secret: String,
     creator_user_id: UserId,
     match_name: String,
+    settings: LobbySettings,
+    password: Option<String>,
 }
 
 impl MatchLobbyCreateRequest {
-    pub fn new(session_secret: &str, creator_user_id: UserId, match_name: &str) -> Self {
+    pub fn new(
+        session_secret: &str,
+        creator_user_id: UserId,
+        match_name: &str,
+        settings: LobbySettings,
+        password: Option<&str>,
+    ) -> Self {
         Self {
             session_instance_secret: session_secret.to_string(),
             creator_user_id,
             match_name: match_name.to_string(),
+            settings,
+            password: password.map(|password| password.to_string()),
         }
     }
 
@@ -32,6 +42,14 @@ impl MatchLobbyCreateRequest {
     pub fn match_name(&self) -> &str {
         &self.match_name
     }
+
+    pub fn settings(&self) -> LobbySettings {
+        self.settings
+    }
+
+    pub fn password(&self) -> Option<&str> {
+        self.password.as_deref()
+    }
 }
 
 // Response
@@ -0,0 +1,73 @@
+use auth_server_types::UserId;
+use http_common::{ApiRequest, ApiResponse, Method};
+use naia_serde::SerdeInternal as Serde;
+use social_server_types::LobbyId;
+
+// Request
+#[derive(Serde, PartialEq, Clone)]
+pub struct MatchLobbyInviteRequest {
+    session_instance_secret: String,
+    user_id: UserId,
+    invited_user_id: UserId,
+}
+
+impl MatchLobbyInviteRequest {
+    pub fn new(session_instance_secret: &str, user_id: UserId, invited_user_id: UserId) -> Self {
+        Self {
+            session_instance_secret: session_instance_secret.to_string(),
+            user_id,
+            invited_user_id,
+        }
+    }
+
+    pub fn session_instance_secret(&self) -> &str {
+        &self.session_instance_secret
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn invited_user_id(&self) -> UserId {
+        self.invited_user_id
+    }
+}
+
+// Response
+#[derive(Serde, PartialEq, Clone)]
+pub struct MatchLobbyInviteResponse {
+    lobby_id: LobbyId,
+}
+
+impl MatchLobbyInviteResponse {
+    pub fn new(lobby_id: LobbyId) -> Self {
+        Self { lobby_id }
+    }
+
+    pub fn lobby_id(&self) -> LobbyId {
+        self.lobby_id
+    }
+}
+
+// Traits
+impl ApiRequest for MatchLobbyInviteRequest {
+    type Response = MatchLobbyInviteResponse;
+
+    fn name() -> &'static str {
+        "MatchLobbyInviteRequest"
+    }
+
+    fn method() -> Method {
+        Method::Post
+    }
+
+    fn path() -> &'static str {
+        "match_lobby_invite"
+    }
+}
+
+impl ApiResponse for MatchLobbyInviteResponse {
+    fn name() -> &'static str {
+        "MatchLobbyInviteResponse"
+    }
+}
@@ -9,14 +9,21 @@ pub struct MatchLobbyJoinRequest {
     session_instance_secret: String,
     lobby_id: LobbyId,
     user_id: UserId,
+    password: Option<String>,
 }
 
 impl MatchLobbyJoinRequest {
-    pub fn new(session_secret: &str, lobby_id: LobbyId, user_id: UserId) -> Self {
+    pub fn new(
+        session_secret: &str,
+        lobby_id: LobbyId,
+        user_id: UserId,
+        password: Option<&str>,
+    ) -> Self {
         Self {
             session_instance_secret: session_secret.to_string(),
             lobby_id,
             user_id,
+            password: password.map(|password| password.to_string()),
         }
     }
 
@@ -31,6 +38,10 @@ impl MatchLobbyJoinRequest {
     pub fn user_id(&self) -> UserId {
         self.user_id
     }
+
+    pub fn password(&self) -> Option<&str> {
+        self.password.as_deref()
+    }
 }
 
 // Response
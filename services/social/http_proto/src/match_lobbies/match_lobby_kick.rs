@@ -0,0 +1,60 @@
+use auth_server_types::UserId;
+use http_common::{ApiRequest, ApiResponse, Method};
+use naia_serde::SerdeInternal as Serde;
+
+// Request
+#[derive(Serde, PartialEq, Clone)]
+pub struct MatchLobbyKickRequest {
+    session_instance_secret: String,
+    user_id: UserId,
+    kicked_user_id: UserId,
+}
+
+impl MatchLobbyKickRequest {
+    pub fn new(session_instance_secret: &str, user_id: UserId, kicked_user_id: UserId) -> Self {
+        Self {
+            session_instance_secret: session_instance_secret.to_string(),
+            user_id,
+            kicked_user_id,
+        }
+    }
+
+    pub fn session_instance_secret(&self) -> &str {
+        &self.session_instance_secret
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn kicked_user_id(&self) -> UserId {
+        self.kicked_user_id
+    }
+}
+
+// Response
+#[derive(Serde, PartialEq, Clone)]
+pub struct MatchLobbyKickResponse;
+
+// Traits
+impl ApiRequest for MatchLobbyKickRequest {
+    type Response = MatchLobbyKickResponse;
+
+    fn name() -> &'static str {
+        "MatchLobbyKickRequest"
+    }
+
+    fn method() -> Method {
+        Method::Post
+    }
+
+    fn path() -> &'static str {
+        "match_lobby_kick"
+    }
+}
+
+impl ApiResponse for MatchLobbyKickResponse {
+    fn name() -> &'static str {
+        "MatchLobbyKickResponse"
+    }
+}
@@ -0,0 +1,54 @@
+use auth_server_types::UserId;
+use http_common::{ApiRequest, ApiResponse, Method};
+use naia_serde::SerdeInternal as Serde;
+
+// Request
+#[derive(Serde, PartialEq, Clone)]
+pub struct MatchLobbyLeaveSpectateRequest {
+    session_instance_secret: String,
+    user_id: UserId,
+}
+
+impl MatchLobbyLeaveSpectateRequest {
+    pub fn new(session_instance_secret: &str, user_id: UserId) -> Self {
+        Self {
+            session_instance_secret: session_instance_secret.to_string(),
+            user_id,
+        }
+    }
+
+    pub fn session_instance_secret(&self) -> &str {
+        &self.session_instance_secret
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+}
+
+// Response
+#[derive(Serde, PartialEq, Clone)]
+pub struct MatchLobbyLeaveSpectateResponse;
+
+// Traits
+impl ApiRequest for MatchLobbyLeaveSpectateRequest {
+    type Response = MatchLobbyLeaveSpectateResponse;
+
+    fn name() -> &'static str {
+        "MatchLobbyLeaveSpectateRequest"
+    }
+
+    fn method() -> Method {
+        Method::Post
+    }
+
+    fn path() -> &'static str {
+        "match_lobby_leave_spectate"
+    }
+}
+
+impl ApiResponse for MatchLobbyLeaveSpectateResponse {
+    fn name() -> &'static str {
+        "MatchLobbyLeaveSpectateResponse"
+    }
+}
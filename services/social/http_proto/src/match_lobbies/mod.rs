@@ -1,11 +1,21 @@
+mod match_ended;
 mod match_lobby_create;
+mod match_lobby_invite;
 mod match_lobby_join;
+mod match_lobby_kick;
 mod match_lobby_leave;
+mod match_lobby_leave_spectate;
 mod match_lobby_send_message;
+mod match_lobby_spectate;
 mod match_lobby_start;
 
+pub use match_ended::*;
 pub use match_lobby_create::*;
+pub use match_lobby_invite::*;
 pub use match_lobby_join::*;
+pub use match_lobby_kick::*;
 pub use match_lobby_leave::*;
+pub use match_lobby_leave_spectate::*;
 pub use match_lobby_send_message::*;
+pub use match_lobby_spectate::*;
 pub use match_lobby_start::*;
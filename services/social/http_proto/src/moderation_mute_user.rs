@@ -0,0 +1,60 @@
+use auth_server_types::UserId;
+use http_common::{ApiRequest, ApiResponse, Method};
+use naia_serde::SerdeInternal as Serde;
+
+// Request
+#[derive(Serde, PartialEq, Clone)]
+pub struct ModerationMuteUserRequest {
+    admin_secret: String,
+    user_id: UserId,
+    muted: bool,
+}
+
+impl ModerationMuteUserRequest {
+    pub fn new(admin_secret: &str, user_id: UserId, muted: bool) -> Self {
+        Self {
+            admin_secret: admin_secret.to_string(),
+            user_id,
+            muted,
+        }
+    }
+
+    pub fn admin_secret(&self) -> &str {
+        &self.admin_secret
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn muted(&self) -> bool {
+        self.muted
+    }
+}
+
+// Response
+#[derive(Serde, PartialEq, Clone)]
+pub struct ModerationMuteUserResponse;
+
+// Traits
+impl ApiRequest for ModerationMuteUserRequest {
+    type Response = ModerationMuteUserResponse;
+
+    fn name() -> &'static str {
+        "ModerationMuteUserRequest"
+    }
+
+    fn method() -> Method {
+        Method::Post
+    }
+
+    fn path() -> &'static str {
+        "moderation_mute_user"
+    }
+}
+
+impl ApiResponse for ModerationMuteUserResponse {
+    fn name() -> &'static str {
+        "ModerationMuteUserResponse"
+    }
+}
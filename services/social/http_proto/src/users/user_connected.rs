@@ -36,24 +36,32 @@ impl UserConnectedRequest {
 #[derive(Serde, PartialEq, Clone)]
 pub struct UserConnectedResponse {
     already_connected: bool,
+    // the session server still holding an already connected user
+    session_instance_secret: Option<String>,
 }
 
 impl UserConnectedResponse {
     pub fn success() -> Self {
         Self {
             already_connected: false,
+            session_instance_secret: None,
         }
     }
 
-    pub fn already_connected() -> Self {
+    pub fn already_connected(session_instance_secret: Option<&str>) -> Self {
         Self {
             already_connected: true,
+            session_instance_secret: session_instance_secret.map(|secret| secret.to_string()),
         }
     }
 
     pub fn successful(&self) -> bool {
         !self.already_connected
     }
+
+    pub fn session_instance_secret(&self) -> Option<&str> {
+        self.session_instance_secret.as_deref()
+    }
 }
 
 // Traits
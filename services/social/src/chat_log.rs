@@ -0,0 +1,233 @@
+use std::{
+    collections::VecDeque,
+    fs::{self, File, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use auth_server_types::UserId;
+use http_server::executor::smol::unblock;
+use logging::{info, warn};
+use social_server_types::{MessageId, Timestamp};
+
+// where all chat logs are written, relative to the working directory
+pub const CHAT_LOG_DIR: &str = "chat_logs";
+
+// how many messages are kept in memory per log, older messages stay on disk
+const MAX_LOADED_MESSAGES: usize = 10_000;
+
+pub type ChatLogEntry = (MessageId, Timestamp, UserId, String);
+
+// an append-only chat log, persisted as one line per message
+pub struct ChatLog {
+    path: PathBuf,
+    file: Option<File>,
+    messages: VecDeque<ChatLogEntry>,
+    next_message_id: MessageId,
+}
+
+impl ChatLog {
+    // opens an existing log (or starts a new one), loading previous messages
+    pub fn open(path: &Path) -> Self {
+        let mut messages = VecDeque::new();
+        let mut next_message_id = MessageId::new(0);
+
+        if let Ok(contents) = fs::read_to_string(path) {
+            for line in contents.lines() {
+                let Some(entry) = deserialize_entry(line) else {
+                    warn!("skipping malformed chat log line in {:?}", path);
+                    continue;
+                };
+                next_message_id = entry.0.next();
+                messages.push_back(entry);
+                if messages.len() > MAX_LOADED_MESSAGES {
+                    messages.pop_front();
+                }
+            }
+            info!("loaded {} chat messages from {:?}", messages.len(), path);
+        }
+
+        Self {
+            path: path.to_path_buf(),
+            file: open_append(path, false),
+            messages,
+            next_message_id,
+        }
+    }
+
+    // starts a fresh log, discarding anything previously written at the path
+    pub fn create(path: &Path) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            file: open_append(path, true),
+            messages: VecDeque::new(),
+            next_message_id: MessageId::new(0),
+        }
+    }
+
+    // writes a single message to the end of the log at `path`, without loading or holding it open
+    // message ids aren't tracked, so this is only for logs which are read back whole
+    pub fn append_to_file(path: &Path, user_id: &UserId, timestamp: Timestamp, message: &str) {
+        let Some(mut file) = open_append(path, false) else {
+            return;
+        };
+        let entry = (MessageId::new(0), timestamp, *user_id, message.to_string());
+        if let Err(e) = writeln!(file, "{}", serialize_entry(&entry)) {
+            warn!("failed to write to chat log {:?}: {:?}", path, e);
+        }
+    }
+
+    // closes the log and removes it from disk, off the calling thread
+    pub fn delete(self) {
+        let Self { path, file, .. } = self;
+        drop(file);
+
+        unblock(move || {
+            if let Err(e) = fs::remove_file(&path) {
+                warn!("failed to delete chat log {:?}: {:?}", path, e);
+            }
+        })
+        .detach();
+    }
+
+    pub fn append(&mut self, user_id: &UserId, message: &str) -> (MessageId, Timestamp) {
+        // get next message id
+        let message_id = self.next_message_id;
+        self.next_message_id = self.next_message_id.next();
+
+        // get timestamp
+        let timestamp = Timestamp::now();
+
+        let entry = (message_id, timestamp, *user_id, message.to_string());
+
+        // persist
+        if let Some(file) = self.file.as_mut() {
+            let line = serialize_entry(&entry);
+            if let Err(e) = writeln!(file, "{}", line) {
+                warn!("failed to write to chat log {:?}: {:?}", self.path, e);
+            }
+        }
+
+        // add to in-memory log
+        self.messages.push_back(entry);
+        if self.messages.len() > MAX_LOADED_MESSAGES {
+            self.messages.pop_front();
+        }
+
+        (message_id, timestamp)
+    }
+
+    // the most recent messages, oldest first
+    pub fn recent(&self, count: usize) -> Vec<ChatLogEntry> {
+        let skip = self.messages.len().saturating_sub(count);
+        self.messages.iter().skip(skip).cloned().collect()
+    }
+
+    // up to `count` messages older than `before_id` (or the newest, if None), oldest first
+    // also returns whether there are even older messages
+    pub fn page(&self, before_id: Option<MessageId>, count: usize) -> (Vec<ChatLogEntry>, bool) {
+        let end = match before_id {
+            Some(before_id) => self
+                .messages
+                .partition_point(|(id, _, _, _)| *id < before_id),
+            None => self.messages.len(),
+        };
+        let start = end.saturating_sub(count);
+        let page = self.messages.range(start..end).cloned().collect();
+        (page, start > 0)
+    }
+}
+
+fn open_append(path: &Path, truncate: bool) -> Option<File> {
+    if let Some(parent) = path.parent() {
+        if let Err(e) = fs::create_dir_all(parent) {
+            warn!("failed to create chat log directory {:?}: {:?}", parent, e);
+            return None;
+        }
+    }
+    let mut options = OpenOptions::new();
+    options.create(true);
+    if truncate {
+        options.write(true).truncate(true);
+    } else {
+        options.append(true);
+    }
+    match options.open(path) {
+        Ok(file) => Some(file),
+        Err(e) => {
+            warn!(
+                "failed to open chat log {:?}, messages will not be persisted: {:?}",
+                path, e
+            );
+            None
+        }
+    }
+}
+
+// line format: id, day, month, year, hour, minute, pm, user id, message (tab separated)
+fn serialize_entry((message_id, timestamp, user_id, message): &ChatLogEntry) -> String {
+    let user_id: u64 = (*user_id).into();
+    format!(
+        "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+        message_id.to_u32(),
+        timestamp.day(),
+        timestamp.month(),
+        timestamp.year(),
+        timestamp.hour(),
+        timestamp.minute(),
+        timestamp.pm() as u8,
+        user_id,
+        escape(message),
+    )
+}
+
+fn deserialize_entry(line: &str) -> Option<ChatLogEntry> {
+    let mut parts = line.splitn(9, '\t');
+    let message_id = MessageId::new(parts.next()?.parse().ok()?);
+    let day: u8 = parts.next()?.parse().ok()?;
+    let month: u8 = parts.next()?.parse().ok()?;
+    let year: u16 = parts.next()?.parse().ok()?;
+    let hour: u8 = parts.next()?.parse().ok()?;
+    let minute: u8 = parts.next()?.parse().ok()?;
+    let pm: u8 = parts.next()?.parse().ok()?;
+    let user_id = UserId::new(parts.next()?.parse().ok()?);
+    let message = unescape(parts.next()?);
+
+    // Timestamp stores years past 2024
+    let timestamp = Timestamp::new(day, month, year.checked_sub(2024)?, hour, minute, pm != 0);
+
+    Some((message_id, timestamp, user_id, message))
+}
+
+fn escape(message: &str) -> String {
+    let mut output = String::with_capacity(message.len());
+    for c in message.chars() {
+        match c {
+            '\\' => output.push_str("\\\\"),
+            '\t' => output.push_str("\\t"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            _ => output.push(c),
+        }
+    }
+    output
+}
+
+fn unescape(message: &str) -> String {
+    let mut output = String::with_capacity(message.len());
+    let mut chars = message.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            output.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => output.push('\t'),
+            Some('n') => output.push('\n'),
+            Some('r') => output.push('\r'),
+            Some(other) => output.push(other),
+            None => {}
+        }
+    }
+    output
+}
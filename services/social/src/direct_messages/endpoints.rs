@@ -0,0 +1,114 @@
+use http_client::ResponseError;
+use http_server::{async_dup::Arc, executor::smol::lock::RwLock, ApiServer, Server};
+use logging::warn;
+
+use social_server_http_proto::{
+    DirectMessageSendRequest, DirectMessageSendResponse, UserBlockRequest, UserBlockResponse,
+};
+
+use crate::state::State;
+
+pub fn recv_direct_message_send_request(
+    host_name: &str,
+    server: &mut Server,
+    state: Arc<RwLock<State>>,
+) {
+    server.api_endpoint(host_name, None, move |_addr, req| {
+        let state = state.clone();
+        async move { async_recv_direct_message_send_request_impl(state, req).await }
+    });
+}
+
+async fn async_recv_direct_message_send_request_impl(
+    state: Arc<RwLock<State>>,
+    request: DirectMessageSendRequest,
+) -> Result<DirectMessageSendResponse, ResponseError> {
+    let mut state = state.write().await;
+
+    if state
+        .session_servers
+        .get_session_server_id(&request.session_instance_secret())
+        .is_none()
+    {
+        warn!("invalid request instance secret");
+        return Err(ResponseError::Unauthenticated);
+    }
+
+    let sender_id = request.sender_user_id();
+    let recipient_id = request.recipient_user_id();
+    if sender_id == recipient_id {
+        warn!("user {:?} tried to message themselves", sender_id);
+        return Err(ResponseError::BadRequest);
+    }
+    // every message to an offline user gets an inbox file, so only real users get one
+    if !state.users.is_known_user(&recipient_id) {
+        warn!(
+            "user {:?} tried to message unknown user {:?}",
+            sender_id, recipient_id
+        );
+        return Err(ResponseError::NotFound);
+    }
+
+    let message = match state
+        .moderation
+        .moderate_message(&sender_id, request.message())
+    {
+        Ok(message) => message,
+        Err(rejection) => {
+            warn!(
+                "direct message from user {:?} rejected: {:?}",
+                sender_id, rejection
+            );
+            return Err(ResponseError::BadRequest);
+        }
+    };
+
+    let recipient_online = state.users.is_user_online(&recipient_id);
+    let timestamp =
+        state
+            .direct_messages
+            .send_message(&sender_id, &recipient_id, recipient_online, &message);
+
+    // responding
+    return Ok(DirectMessageSendResponse::new(timestamp, &message));
+}
+
+pub fn recv_user_block_request(host_name: &str, server: &mut Server, state: Arc<RwLock<State>>) {
+    server.api_endpoint(host_name, None, move |_addr, req| {
+        let state = state.clone();
+        async move { async_recv_user_block_request_impl(state, req).await }
+    });
+}
+
+async fn async_recv_user_block_request_impl(
+    state: Arc<RwLock<State>>,
+    request: UserBlockRequest,
+) -> Result<UserBlockResponse, ResponseError> {
+    let mut state = state.write().await;
+
+    if state
+        .session_servers
+        .get_session_server_id(&request.session_instance_secret())
+        .is_none()
+    {
+        warn!("invalid request instance secret");
+        return Err(ResponseError::Unauthenticated);
+    }
+
+    if request.user_id() == request.other_user_id() {
+        warn!("user {:?} tried to block themselves", request.user_id());
+        return Err(ResponseError::BadRequest);
+    }
+
+    if state.direct_messages.set_blocked(
+        &request.user_id(),
+        &request.other_user_id(),
+        request.blocked(),
+    ) {
+        // saved before the lock is released, so that concurrent saves land in order
+        state.direct_messages.save_block_lists().await;
+    }
+
+    // responding
+    return Ok(UserBlockResponse);
+}
@@ -0,0 +1,5 @@
+mod endpoints;
+mod state;
+
+pub use endpoints::*;
+pub use state::*;
@@ -0,0 +1,338 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use auth_server_types::UserId;
+use http_server::executor::smol::unblock;
+use logging::{info, warn};
+use social_server_types::Timestamp;
+
+use crate::{chat_log::ChatLog, friends::SOCIAL_DATA_DIR};
+
+// the receiving user id is stored alongside the patch
+pub(crate) enum DirectMessagePatch {
+    // sender, timestamp, message
+    Message(UserId, Timestamp, String),
+    // other user, blocked
+    Block(UserId, bool),
+}
+
+pub struct DirectMessagesState {
+    // blocking user id -> blocked user ids
+    block_lists: HashMap<UserId, HashSet<UserId>>,
+    block_list_path: PathBuf,
+
+    // receiving user id, patch
+    outgoing_patches: Vec<(UserId, DirectMessagePatch)>,
+}
+
+impl DirectMessagesState {
+    pub fn new() -> Self {
+        let block_list_path = Path::new(SOCIAL_DATA_DIR).join("blocked_users.txt");
+        let block_lists = load_block_lists(&block_list_path);
+
+        Self {
+            block_lists,
+            block_list_path,
+            outgoing_patches: Vec::new(),
+        }
+    }
+
+    pub fn is_blocked(&self, user_id: &UserId, other_user_id: &UserId) -> bool {
+        self.block_lists
+            .get(user_id)
+            .map(|blocked| blocked.contains(other_user_id))
+            .unwrap_or(false)
+    }
+
+    // messages to an offline recipient are kept until they next connect
+    pub fn send_message(
+        &mut self,
+        sender_id: &UserId,
+        recipient_id: &UserId,
+        recipient_online: bool,
+        message: &str,
+    ) -> Timestamp {
+        // a blocked sender isn't told, the message just never arrives
+        if self.is_blocked(recipient_id, sender_id) {
+            return Timestamp::now();
+        }
+
+        if recipient_online {
+            let timestamp = Timestamp::now();
+            self.outgoing_patches.push((
+                *recipient_id,
+                DirectMessagePatch::Message(*sender_id, timestamp, message.to_string()),
+            ));
+            return timestamp;
+        }
+
+        let timestamp = Timestamp::now();
+        self.store_pending_message(recipient_id, sender_id, timestamp, message);
+        timestamp
+    }
+
+    // also used when a recipient's messages couldn't be patched through to them
+    pub(crate) fn store_pending_message(
+        &self,
+        recipient_id: &UserId,
+        sender_id: &UserId,
+        timestamp: Timestamp,
+        message: &str,
+    ) {
+        ChatLog::append_to_file(
+            &pending_inbox_path(recipient_id),
+            sender_id,
+            timestamp,
+            message,
+        );
+    }
+
+    // returns true if the block list changed, and needs saving
+    pub fn set_blocked(&mut self, user_id: &UserId, other_user_id: &UserId, blocked: bool) -> bool {
+        let changed = if blocked {
+            self.block_lists
+                .entry(*user_id)
+                .or_insert(HashSet::new())
+                .insert(*other_user_id)
+        } else {
+            self.block_lists
+                .get_mut(user_id)
+                .map(|blocked_users| blocked_users.remove(other_user_id))
+                .unwrap_or(false)
+        };
+        if !changed {
+            return false;
+        }
+
+        info!(
+            "user {:?} blocked user {:?}: {}",
+            user_id, other_user_id, blocked
+        );
+        self.outgoing_patches
+            .push((*user_id, DirectMessagePatch::Block(*other_user_id, blocked)));
+        true
+    }
+
+    // queues the user's block list and any messages they received while offline
+    pub fn send_snapshot(&mut self, user_id: &UserId) {
+        if let Some(blocked_users) = self.block_lists.get(user_id) {
+            for blocked_user_id in blocked_users {
+                self.outgoing_patches
+                    .push((*user_id, DirectMessagePatch::Block(*blocked_user_id, true)));
+            }
+        }
+
+        let path = pending_inbox_path(user_id);
+        if !path.exists() {
+            return;
+        }
+        let inbox = ChatLog::open(&path);
+        for (_, timestamp, sender_id, message) in inbox.recent(usize::MAX) {
+            self.outgoing_patches.push((
+                *user_id,
+                DirectMessagePatch::Message(sender_id, timestamp, message),
+            ));
+        }
+        drop(inbox);
+        if let Err(e) = fs::remove_file(&path) {
+            warn!("failed to remove delivered inbox {:?}: {:?}", path, e);
+        }
+    }
+
+    pub(crate) fn take_patches(&mut self) -> Vec<(UserId, DirectMessagePatch)> {
+        std::mem::take(&mut self.outgoing_patches)
+    }
+
+    pub async fn save_block_lists(&self) {
+        let path = self.block_list_path.clone();
+        let mut contents = String::new();
+        for (user_id, blocked_users) in &self.block_lists {
+            let user_id: u64 = (*user_id).into();
+            for blocked_user_id in blocked_users {
+                let blocked_user_id: u64 = (*blocked_user_id).into();
+                contents.push_str(&format!("{}\t{}\n", user_id, blocked_user_id));
+            }
+        }
+
+        let result = unblock({
+            let path = path.clone();
+            move || {
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(&path, contents)
+            }
+        })
+        .await;
+        if let Err(e) = result {
+            warn!("failed to save block lists {:?}: {:?}", path, e);
+        }
+    }
+}
+
+fn pending_inbox_path(user_id: &UserId) -> PathBuf {
+    let user_id: u64 = (*user_id).into();
+    Path::new(SOCIAL_DATA_DIR)
+        .join("direct_messages")
+        .join(format!("inbox_{}.log", user_id))
+}
+
+// one "<blocking user>\t<blocked user>" pair per line
+fn load_block_lists(path: &Path) -> HashMap<UserId, HashSet<UserId>> {
+    let mut block_lists: HashMap<UserId, HashSet<UserId>> = HashMap::new();
+
+    let Ok(contents) = fs::read_to_string(path) else {
+        return block_lists;
+    };
+    for line in contents.lines() {
+        let mut parts = line.trim().split('\t');
+        let (Some(Ok(a)), Some(Ok(b))) = (
+            parts.next().map(|s| s.parse::<u64>()),
+            parts.next().map(|s| s.parse::<u64>()),
+        ) else {
+            warn!("skipping malformed block list line: {:?}", line);
+            continue;
+        };
+        block_lists
+            .entry(UserId::new(a))
+            .or_insert(HashSet::new())
+            .insert(UserId::new(b));
+    }
+
+    block_lists
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn alice() -> UserId {
+        UserId::new(1)
+    }
+
+    fn bob() -> UserId {
+        UserId::new(2)
+    }
+
+    // `new()` would load the block lists saved by a local server
+    fn empty_direct_messages() -> DirectMessagesState {
+        DirectMessagesState {
+            block_lists: HashMap::new(),
+            block_list_path: PathBuf::new(),
+            outgoing_patches: Vec::new(),
+        }
+    }
+
+    // offline messages go to an inbox file under the working directory, so each test which queues
+    // them uses its own recipient and starts with that inbox cleared
+    fn offline_recipient(user_id: u64) -> UserId {
+        let user_id = UserId::new(user_id);
+        let _ = fs::remove_file(pending_inbox_path(&user_id));
+        user_id
+    }
+
+    fn messages(patches: &[(UserId, DirectMessagePatch)]) -> Vec<(UserId, UserId, String)> {
+        patches
+            .iter()
+            .filter_map(|(receiver, patch)| match patch {
+                DirectMessagePatch::Message(sender, _, message) => {
+                    Some((*receiver, *sender, message.clone()))
+                }
+                DirectMessagePatch::Block(..) => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn block_and_unblock() {
+        let mut direct_messages = empty_direct_messages();
+
+        assert!(direct_messages.set_blocked(&alice(), &bob(), true));
+        assert!(direct_messages.is_blocked(&alice(), &bob()));
+        // blocking only goes one way
+        assert!(!direct_messages.is_blocked(&bob(), &alice()));
+        assert!(!direct_messages.set_blocked(&alice(), &bob(), true));
+
+        assert!(direct_messages.set_blocked(&alice(), &bob(), false));
+        assert!(!direct_messages.is_blocked(&alice(), &bob()));
+        assert!(!direct_messages.set_blocked(&alice(), &bob(), false));
+
+        let patches = direct_messages.take_patches();
+        assert!(matches!(
+            patches.as_slice(),
+            [
+                (_, DirectMessagePatch::Block(_, true)),
+                (_, DirectMessagePatch::Block(_, false)),
+            ]
+        ));
+    }
+
+    #[test]
+    fn block_list_is_in_the_snapshot() {
+        let mut direct_messages = empty_direct_messages();
+        direct_messages.set_blocked(&alice(), &bob(), true);
+        direct_messages.take_patches();
+
+        direct_messages.send_snapshot(&alice());
+        assert!(matches!(
+            direct_messages.take_patches().as_slice(),
+            [(receiver, DirectMessagePatch::Block(blocked, true))]
+                if *receiver == alice() && *blocked == bob()
+        ));
+    }
+
+    #[test]
+    fn online_recipient_gets_message() {
+        let mut direct_messages = empty_direct_messages();
+
+        direct_messages.send_message(&alice(), &bob(), true, "hello");
+        assert_eq!(
+            messages(&direct_messages.take_patches()),
+            vec![(bob(), alice(), "hello".to_string())]
+        );
+    }
+
+    #[test]
+    fn blocked_sender_is_dropped() {
+        let mut direct_messages = empty_direct_messages();
+        let recipient = offline_recipient(9_000_001);
+        direct_messages.set_blocked(&recipient, &alice(), true);
+        direct_messages.take_patches();
+
+        direct_messages.send_message(&alice(), &recipient, true, "hello");
+        direct_messages.send_message(&alice(), &recipient, false, "hello?");
+        assert!(direct_messages.take_patches().is_empty());
+        assert!(!pending_inbox_path(&recipient).exists());
+
+        // the block is one way
+        direct_messages.send_message(&recipient, &alice(), true, "go away");
+        assert_eq!(direct_messages.take_patches().len(), 1);
+    }
+
+    #[test]
+    fn offline_messages_are_delivered_on_connect() {
+        let mut direct_messages = empty_direct_messages();
+        let recipient = offline_recipient(9_000_002);
+
+        direct_messages.send_message(&alice(), &recipient, false, "first");
+        direct_messages.send_message(&bob(), &recipient, false, "second");
+        assert!(direct_messages.take_patches().is_empty());
+
+        direct_messages.send_snapshot(&recipient);
+        assert_eq!(
+            messages(&direct_messages.take_patches()),
+            vec![
+                (recipient, alice(), "first".to_string()),
+                (recipient, bob(), "second".to_string()),
+            ]
+        );
+
+        // delivered once only
+        assert!(!pending_inbox_path(&recipient).exists());
+        direct_messages.send_snapshot(&recipient);
+        assert!(direct_messages.take_patches().is_empty());
+    }
+}
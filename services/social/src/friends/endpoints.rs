@@ -0,0 +1,55 @@
+use http_client::ResponseError;
+use http_server::{async_dup::Arc, executor::smol::lock::RwLock, ApiServer, Server};
+use logging::warn;
+
+use social_server_http_proto::{FriendActionRequest, FriendActionResponse};
+use social_server_types::FriendAction;
+
+use crate::state::State;
+
+pub fn recv_friend_action_request(host_name: &str, server: &mut Server, state: Arc<RwLock<State>>) {
+    server.api_endpoint(host_name, None, move |_addr, req| {
+        let state = state.clone();
+        async move { async_recv_friend_action_request_impl(state, req).await }
+    });
+}
+
+async fn async_recv_friend_action_request_impl(
+    state: Arc<RwLock<State>>,
+    request: FriendActionRequest,
+) -> Result<FriendActionResponse, ResponseError> {
+    let mut state = state.write().await;
+    let state = &mut *state;
+
+    if state
+        .session_servers
+        .get_session_server_id(&request.session_instance_secret())
+        .is_none()
+    {
+        warn!("invalid request instance secret");
+        return Err(ResponseError::Unauthenticated);
+    }
+
+    let user_id = request.user_id();
+    let other_user_id = request.other_user_id();
+
+    let result = match request.action() {
+        FriendAction::SendRequest => {
+            state
+                .friends
+                .send_request(&state.users, &user_id, &other_user_id)
+        }
+        FriendAction::AcceptRequest => state.friends.respond(&user_id, &other_user_id, true),
+        FriendAction::DeclineRequest => state.friends.respond(&user_id, &other_user_id, false),
+        FriendAction::Remove => state.friends.remove(&user_id, &other_user_id),
+    };
+    if let Err(err) = result {
+        warn!("friend action {:?} failed: {}", request.action(), err);
+        return Err(ResponseError::InternalServerError(err));
+    }
+    // every successful action changes the graph
+    state.friends.save().await;
+
+    // responding
+    return Ok(FriendActionResponse);
+}
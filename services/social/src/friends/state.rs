@@ -0,0 +1,385 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use auth_server_types::UserId;
+use http_server::executor::smol::unblock;
+use logging::{info, warn};
+
+use crate::users::UsersState;
+
+pub const SOCIAL_DATA_DIR: &str = "social_data";
+
+// the UserId inside each patch is the OTHER user, the receiver is stored alongside the patch
+pub(crate) enum FriendPatch {
+    Added(UserId),
+    Removed(UserId),
+    RequestReceived(UserId),
+    RequestRemoved(UserId),
+}
+
+pub struct FriendsState {
+    friends: HashMap<UserId, HashSet<UserId>>,
+    // receiving user id -> requesting user ids
+    incoming_requests: HashMap<UserId, HashSet<UserId>>,
+    save_path: PathBuf,
+
+    // receiving user id, patch
+    outgoing_patches: Vec<(UserId, FriendPatch)>,
+}
+
+impl FriendsState {
+    pub fn new() -> Self {
+        let save_path = Path::new(SOCIAL_DATA_DIR).join("friends.txt");
+        let (friends, incoming_requests) = load_friend_graph(&save_path);
+
+        Self {
+            friends,
+            incoming_requests,
+            save_path,
+            outgoing_patches: Vec::new(),
+        }
+    }
+
+    pub fn get_friends(&self, user_id: &UserId) -> Vec<UserId> {
+        let Some(friends) = self.friends.get(user_id) else {
+            return Vec::new();
+        };
+        friends.iter().copied().collect()
+    }
+
+    pub fn are_friends(&self, user_id: &UserId, other_user_id: &UserId) -> bool {
+        self.friends
+            .get(user_id)
+            .map(|friends| friends.contains(other_user_id))
+            .unwrap_or(false)
+    }
+
+    // queues the user's whole friend list and pending requests, used when they come online
+    pub fn send_snapshot(&mut self, user_id: &UserId) {
+        for friend_id in self.get_friends(user_id) {
+            self.outgoing_patches
+                .push((*user_id, FriendPatch::Added(friend_id)));
+        }
+        if let Some(requests) = self.incoming_requests.get(user_id) {
+            for requester_id in requests {
+                self.outgoing_patches
+                    .push((*user_id, FriendPatch::RequestReceived(*requester_id)));
+            }
+        }
+    }
+
+    pub fn send_request(
+        &mut self,
+        users: &UsersState,
+        sender_id: &UserId,
+        receiver_id: &UserId,
+    ) -> Result<(), String> {
+        if sender_id == receiver_id {
+            return Err("cannot send a friend request to yourself".to_string());
+        }
+        if !users.is_known_user(receiver_id) {
+            return Err("no such user".to_string());
+        }
+        if self.are_friends(sender_id, receiver_id) {
+            return Err("users are already friends".to_string());
+        }
+
+        // both users asked, treat it as accepted
+        if self.has_request(sender_id, receiver_id) {
+            return self.respond(sender_id, receiver_id, true);
+        }
+
+        let inserted = self
+            .incoming_requests
+            .entry(*receiver_id)
+            .or_insert(HashSet::new())
+            .insert(*sender_id);
+        if !inserted {
+            return Err("friend request already sent".to_string());
+        }
+
+        self.outgoing_patches
+            .push((*receiver_id, FriendPatch::RequestReceived(*sender_id)));
+        Ok(())
+    }
+
+    pub fn respond(
+        &mut self,
+        receiver_id: &UserId,
+        sender_id: &UserId,
+        accept: bool,
+    ) -> Result<(), String> {
+        let removed = self
+            .incoming_requests
+            .get_mut(receiver_id)
+            .map(|requests| requests.remove(sender_id))
+            .unwrap_or(false);
+        if !removed {
+            return Err("no pending friend request".to_string());
+        }
+        self.outgoing_patches
+            .push((*receiver_id, FriendPatch::RequestRemoved(*sender_id)));
+
+        if accept {
+            self.add_friendship(receiver_id, sender_id);
+        }
+        Ok(())
+    }
+
+    pub fn remove(&mut self, user_id: &UserId, friend_id: &UserId) -> Result<(), String> {
+        if !self.are_friends(user_id, friend_id) {
+            return Err("users are not friends".to_string());
+        }
+
+        for (a, b) in [(user_id, friend_id), (friend_id, user_id)] {
+            if let Some(friends) = self.friends.get_mut(a) {
+                friends.remove(b);
+            }
+            self.outgoing_patches.push((*a, FriendPatch::Removed(*b)));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn take_patches(&mut self) -> Vec<(UserId, FriendPatch)> {
+        std::mem::take(&mut self.outgoing_patches)
+    }
+
+    // patches which couldn't be delivered, they go out again with the next batch
+    pub(crate) fn requeue_patches(&mut self, patches: Vec<(UserId, FriendPatch)>) {
+        self.outgoing_patches.extend(patches);
+    }
+
+    fn has_request(&self, receiver_id: &UserId, sender_id: &UserId) -> bool {
+        self.incoming_requests
+            .get(receiver_id)
+            .map(|requests| requests.contains(sender_id))
+            .unwrap_or(false)
+    }
+
+    fn add_friendship(&mut self, user_id: &UserId, friend_id: &UserId) {
+        info!("users {:?} and {:?} are now friends", user_id, friend_id);
+
+        for (a, b) in [(user_id, friend_id), (friend_id, user_id)] {
+            self.friends.entry(*a).or_insert(HashSet::new()).insert(*b);
+            self.outgoing_patches.push((*a, FriendPatch::Added(*b)));
+        }
+
+        // a request the other way round is no longer needed
+        if let Some(requests) = self.incoming_requests.get_mut(friend_id) {
+            if requests.remove(user_id) {
+                self.outgoing_patches
+                    .push((*friend_id, FriendPatch::RequestRemoved(*user_id)));
+            }
+        }
+    }
+
+    // the whole graph is small enough to rewrite on every change
+    pub async fn save(&self) {
+        let path = self.save_path.clone();
+        let mut contents = String::new();
+        for (user_id, friends) in &self.friends {
+            let user_id: u64 = (*user_id).into();
+            for friend_id in friends {
+                let friend_id: u64 = (*friend_id).into();
+                // each friendship is stored in both directions, only write it once
+                if user_id < friend_id {
+                    contents.push_str(&format!("friend\t{}\t{}\n", user_id, friend_id));
+                }
+            }
+        }
+        for (receiver_id, requests) in &self.incoming_requests {
+            let receiver_id: u64 = (*receiver_id).into();
+            for sender_id in requests {
+                let sender_id: u64 = (*sender_id).into();
+                contents.push_str(&format!("request\t{}\t{}\n", sender_id, receiver_id));
+            }
+        }
+
+        let result = unblock({
+            let path = path.clone();
+            move || {
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(&path, contents)
+            }
+        })
+        .await;
+        if let Err(e) = result {
+            warn!("failed to save friend graph {:?}: {:?}", path, e);
+        }
+    }
+}
+
+// one relation per line, either "friend\t<user>\t<user>" or "request\t<sender>\t<receiver>"
+fn load_friend_graph(
+    path: &Path,
+) -> (
+    HashMap<UserId, HashSet<UserId>>,
+    HashMap<UserId, HashSet<UserId>>,
+) {
+    let mut friends: HashMap<UserId, HashSet<UserId>> = HashMap::new();
+    let mut incoming_requests: HashMap<UserId, HashSet<UserId>> = HashMap::new();
+
+    let Ok(contents) = fs::read_to_string(path) else {
+        return (friends, incoming_requests);
+    };
+    for line in contents.lines() {
+        let mut parts = line.trim().split('\t');
+        let (Some(kind), Some(a), Some(b)) = (parts.next(), parts.next(), parts.next()) else {
+            continue;
+        };
+        let (Ok(a), Ok(b)) = (a.parse::<u64>(), b.parse::<u64>()) else {
+            warn!("skipping malformed friend graph line: {:?}", line);
+            continue;
+        };
+        let (a, b) = (UserId::new(a), UserId::new(b));
+        match kind {
+            "friend" => {
+                friends.entry(a).or_insert(HashSet::new()).insert(b);
+                friends.entry(b).or_insert(HashSet::new()).insert(a);
+            }
+            "request" => {
+                incoming_requests
+                    .entry(b)
+                    .or_insert(HashSet::new())
+                    .insert(a);
+            }
+            _ => {
+                warn!("skipping malformed friend graph line: {:?}", line);
+            }
+        }
+    }
+
+    (friends, incoming_requests)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn alice() -> UserId {
+        UserId::new(1)
+    }
+
+    fn bob() -> UserId {
+        UserId::new(2)
+    }
+
+    // `new()` would load the graph saved by a local server
+    fn empty_friends() -> FriendsState {
+        FriendsState {
+            friends: HashMap::new(),
+            incoming_requests: HashMap::new(),
+            save_path: PathBuf::new(),
+            outgoing_patches: Vec::new(),
+        }
+    }
+
+    fn known_users() -> UsersState {
+        let mut users = UsersState::new();
+        users.add_known_user(&alice());
+        users.add_known_user(&bob());
+        users
+    }
+
+    #[test]
+    fn request_and_accept() {
+        let users = known_users();
+        let mut friends = empty_friends();
+
+        friends.send_request(&users, &alice(), &bob()).unwrap();
+        assert!(matches!(
+            friends.take_patches().as_slice(),
+            [(receiver, FriendPatch::RequestReceived(sender))]
+                if *receiver == bob() && *sender == alice()
+        ));
+        assert!(!friends.are_friends(&alice(), &bob()));
+
+        friends.respond(&bob(), &alice(), true).unwrap();
+        assert!(friends.are_friends(&alice(), &bob()));
+        assert!(friends.are_friends(&bob(), &alice()));
+        assert_eq!(friends.get_friends(&alice()), vec![bob()]);
+
+        let patches = friends.take_patches();
+        assert!(matches!(
+            patches.as_slice(),
+            [
+                (_, FriendPatch::RequestRemoved(_)),
+                (_, FriendPatch::Added(_)),
+                (_, FriendPatch::Added(_)),
+            ]
+        ));
+
+        // nothing left to respond to
+        assert!(friends.respond(&bob(), &alice(), true).is_err());
+        assert!(friends.send_request(&users, &alice(), &bob()).is_err());
+    }
+
+    #[test]
+    fn declined_request_is_dropped() {
+        let users = known_users();
+        let mut friends = empty_friends();
+
+        friends.send_request(&users, &alice(), &bob()).unwrap();
+        friends.respond(&bob(), &alice(), false).unwrap();
+        assert!(!friends.are_friends(&alice(), &bob()));
+
+        // and may be sent again
+        assert!(friends.send_request(&users, &alice(), &bob()).is_ok());
+    }
+
+    #[test]
+    fn invalid_requests_are_rejected() {
+        let users = known_users();
+        let mut friends = empty_friends();
+
+        assert!(friends.send_request(&users, &alice(), &alice()).is_err());
+        assert!(friends
+            .send_request(&users, &alice(), &UserId::new(3))
+            .is_err());
+
+        friends.send_request(&users, &alice(), &bob()).unwrap();
+        assert!(friends.send_request(&users, &alice(), &bob()).is_err());
+        assert!(friends.respond(&alice(), &bob(), true).is_err());
+        assert_eq!(friends.take_patches().len(), 1);
+    }
+
+    #[test]
+    fn crossed_requests_become_friends() {
+        let users = known_users();
+        let mut friends = empty_friends();
+
+        friends.send_request(&users, &alice(), &bob()).unwrap();
+        friends.send_request(&users, &bob(), &alice()).unwrap();
+        assert!(friends.are_friends(&alice(), &bob()));
+        assert!(!friends.has_request(&bob(), &alice()));
+        assert!(!friends.has_request(&alice(), &bob()));
+    }
+
+    #[test]
+    fn remove_friend() {
+        let users = known_users();
+        let mut friends = empty_friends();
+        friends.send_request(&users, &alice(), &bob()).unwrap();
+        friends.respond(&bob(), &alice(), true).unwrap();
+        friends.take_patches();
+
+        friends.remove(&bob(), &alice()).unwrap();
+        assert!(!friends.are_friends(&alice(), &bob()));
+        assert!(!friends.are_friends(&bob(), &alice()));
+        assert!(friends.get_friends(&alice()).is_empty());
+
+        let patches = friends.take_patches();
+        for user_id in [alice(), bob()] {
+            assert!(patches.iter().any(|(receiver, patch)| {
+                *receiver == user_id && matches!(patch, FriendPatch::Removed(_))
+            }));
+        }
+
+        assert!(friends.remove(&bob(), &alice()).is_err());
+    }
+}
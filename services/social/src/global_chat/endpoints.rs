@@ -2,7 +2,10 @@ use http_client::ResponseError;
 use http_server::{async_dup::Arc, executor::smol::lock::RwLock, ApiServer, Server};
 use logging::warn;
 
-use social_server_http_proto::{GlobalChatSendMessageRequest, GlobalChatSendMessageResponse};
+use social_server_http_proto::{
+    ChatHistoryRequest, ChatHistoryResponse, GlobalChatSendMessageRequest,
+    GlobalChatSendMessageResponse,
+};
 
 use crate::state::State;
 
@@ -31,12 +34,78 @@ async fn async_recv_global_chat_send_message_request_impl(
         return Err(ResponseError::Unauthenticated);
     };
 
+    let message = match state
+        .moderation
+        .moderate_message(&request.user_id(), request.message())
+    {
+        Ok(message) => message,
+        Err(rejection) => {
+            warn!(
+                "global chat message from user {:?} rejected: {:?}",
+                request.user_id(),
+                rejection
+            );
+            return Err(ResponseError::BadRequest);
+        }
+    };
+
     let (msg_id, timestamp) =
         state
             .global_chat
-            .send_message(session_server_id, request.user_id(), request.message());
+            .send_message(session_server_id, request.user_id(), &message);
 
     // responding
     let response = GlobalChatSendMessageResponse::new(msg_id, timestamp);
     return Ok(response);
 }
+
+pub fn recv_chat_history_request(host_name: &str, server: &mut Server, state: Arc<RwLock<State>>) {
+    server.api_endpoint(host_name, None, move |_addr, req| {
+        let state = state.clone();
+        async move { async_recv_chat_history_request_impl(state, req).await }
+    });
+}
+
+async fn async_recv_chat_history_request_impl(
+    state: Arc<RwLock<State>>,
+    request: ChatHistoryRequest,
+) -> Result<ChatHistoryResponse, ResponseError> {
+    let state = state.read().await;
+
+    if state
+        .session_servers
+        .get_session_server_id(&request.session_instance_secret())
+        .is_none()
+    {
+        warn!("invalid request instance secret");
+        return Err(ResponseError::Unauthenticated);
+    }
+
+    let before_message_id = request.before_message_id();
+    let count = request.count() as usize;
+
+    let (messages, has_more) = match request.lobby_id() {
+        None => state.global_chat.get_history(before_message_id, count),
+        Some(lobby_id) => {
+            // only members may read a lobby's history
+            if state.users.get_user_lobby_id(&request.user_id()) != Some(lobby_id) {
+                warn!(
+                    "user {:?} is not in lobby {:?}",
+                    request.user_id(),
+                    lobby_id
+                );
+                return Err(ResponseError::Unauthenticated);
+            }
+            let Some(history) =
+                state
+                    .match_lobbies
+                    .get_message_history(&lobby_id, before_message_id, count)
+            else {
+                return Err(ResponseError::NotFound);
+            };
+            history
+        }
+    };
+
+    return Ok(ChatHistoryResponse::new(messages, has_more));
+}
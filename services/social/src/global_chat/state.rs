@@ -1,23 +1,29 @@
-use std::collections::{HashMap, VecDeque};
+use std::{collections::HashMap, path::Path};
 
 use auth_server_types::UserId;
 use social_server_types::{MessageId, Timestamp};
 
-use crate::session_servers::SessionServerId;
+use crate::{
+    chat_log::{ChatLog, ChatLogEntry, CHAT_LOG_DIR},
+    session_servers::SessionServerId,
+};
+
+// how many recent messages a newly connected session server receives
+const RECENT_MESSAGE_COUNT: usize = 100;
 
 pub struct GlobalChatState {
-    chat_log: VecDeque<(MessageId, Timestamp, UserId, String)>,
-    next_global_chat_id: MessageId,
+    chat_log: ChatLog,
 
     // the session server id here is the SENDER not the RECEIVER
-    outgoing_patches: HashMap<SessionServerId, Vec<(MessageId, Timestamp, UserId, String)>>,
+    outgoing_patches: HashMap<SessionServerId, Vec<ChatLogEntry>>,
 }
 
 impl GlobalChatState {
     pub fn new() -> Self {
+        let chat_log_path = Path::new(CHAT_LOG_DIR).join("global.log");
+
         Self {
-            chat_log: VecDeque::new(),
-            next_global_chat_id: MessageId::new(0),
+            chat_log: ChatLog::open(&chat_log_path),
 
             outgoing_patches: HashMap::new(),
         }
@@ -29,19 +35,8 @@ impl GlobalChatState {
         user_id: UserId,
         message: &str,
     ) -> (MessageId, Timestamp) {
-        // get next global chat id
-        let next_global_chat_id = self.next_global_chat_id;
-        self.next_global_chat_id = self.next_global_chat_id.next();
-
-        // get timestamp
-        let timestamp = Timestamp::now();
-
         // add to global log
-        self.chat_log
-            .push_back((next_global_chat_id, timestamp, user_id, message.to_string()));
-        if self.chat_log.len() > 100 {
-            self.chat_log.pop_front();
-        }
+        let (message_id, timestamp) = self.chat_log.append(&user_id, message);
 
         // add to outgoing patches
         if !self
@@ -55,18 +50,24 @@ impl GlobalChatState {
             .outgoing_patches
             .get_mut(&sending_session_server_id)
             .unwrap();
-        session_server_patches.push((next_global_chat_id, timestamp, user_id, message.to_string()));
+        session_server_patches.push((message_id, timestamp, user_id, message.to_string()));
 
-        (next_global_chat_id, timestamp)
+        (message_id, timestamp)
     }
 
-    pub fn get_full_log(&self) -> &VecDeque<(MessageId, Timestamp, UserId, String)> {
-        &self.chat_log
+    pub fn get_recent_log(&self) -> Vec<ChatLogEntry> {
+        self.chat_log.recent(RECENT_MESSAGE_COUNT)
     }
 
-    pub fn take_patches(
-        &mut self,
-    ) -> HashMap<SessionServerId, Vec<(MessageId, Timestamp, UserId, String)>> {
+    pub fn get_history(
+        &self,
+        before_message_id: Option<MessageId>,
+        count: usize,
+    ) -> (Vec<ChatLogEntry>, bool) {
+        self.chat_log.page(before_message_id, count)
+    }
+
+    pub fn take_patches(&mut self) -> HashMap<SessionServerId, Vec<ChatLogEntry>> {
         std::mem::take(&mut self.outgoing_patches)
     }
 }
@@ -0,0 +1,78 @@
+mod chat_log;
+mod direct_messages;
+mod friends;
+mod global_chat;
+mod match_history;
+mod match_lobbies;
+mod moderation;
+mod region;
+mod session_servers;
+mod state;
+mod users;
+
+use std::{net::SocketAddr, time::Duration};
+
+use config::{chat_word_filter, self_binding_addr, social_server_port};
+use http_server::{async_dup::Arc, executor::smol::lock::RwLock, Server};
+use logging::info;
+
+use crate::{moderation::ModerationConfig, state::State};
+
+pub fn start() {
+    // setup state
+    let registration_resend_rate = Duration::from_secs(5);
+    let region_server_disconnect_timeout = Duration::from_secs(61);
+    let moderation_config = ModerationConfig {
+        word_filter: chat_word_filter().to_vec(),
+        ..Default::default()
+    };
+    let state = Arc::new(RwLock::new(State::new(
+        registration_resend_rate,
+        region_server_disconnect_timeout,
+        moderation_config,
+    )));
+
+    // setup listening http server
+    info!("Social Server starting up...");
+    let socket_addr: SocketAddr =
+        SocketAddr::new(self_binding_addr().parse().unwrap(), social_server_port());
+
+    let mut server = Server::new(socket_addr);
+    let host = "social";
+
+    region::recv_heartbeat_request(host, &mut server, state.clone());
+
+    session_servers::recv_connect_session_server_request(host, &mut server, state.clone());
+    session_servers::recv_disconnect_session_server_request(host, &mut server, state.clone());
+
+    users::recv_user_connected_request(host, &mut server, state.clone());
+    users::recv_user_disconnected_request(host, &mut server, state.clone());
+    users::recv_user_is_online_request(host, &mut server, state.clone());
+    users::recv_user_set_away_request(host, &mut server, state.clone());
+
+    friends::recv_friend_action_request(host, &mut server, state.clone());
+
+    direct_messages::recv_direct_message_send_request(host, &mut server, state.clone());
+    direct_messages::recv_user_block_request(host, &mut server, state.clone());
+
+    match_lobbies::recv_match_lobby_create_request(host, &mut server, state.clone());
+    match_lobbies::recv_match_lobby_join_request(host, &mut server, state.clone());
+    match_lobbies::recv_match_lobby_leave_request(host, &mut server, state.clone());
+    match_lobbies::recv_match_lobby_send_message_request(host, &mut server, state.clone());
+    match_lobbies::recv_match_lobby_start_request(host, &mut server, state.clone());
+    match_lobbies::recv_match_lobby_spectate_request(host, &mut server, state.clone());
+    match_lobbies::recv_match_lobby_leave_spectate_request(host, &mut server, state.clone());
+    match_lobbies::recv_match_lobby_kick_request(host, &mut server, state.clone());
+    match_lobbies::recv_match_lobby_invite_request(host, &mut server, state.clone());
+    match_lobbies::recv_match_ended_request(host, &mut server, state.clone());
+
+    global_chat::recv_global_chat_send_message_request(host, &mut server, state.clone());
+    global_chat::recv_chat_history_request(host, &mut server, state.clone());
+
+    moderation::recv_moderation_mute_user_request(host, &mut server, state.clone());
+
+    server.start();
+
+    region::start_processes(state.clone());
+    session_servers::start_processes(state.clone());
+}
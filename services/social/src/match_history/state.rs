@@ -0,0 +1,91 @@
+use std::collections::{HashMap, VecDeque};
+
+use auth_server_types::UserId;
+use social_server_types::{LobbyId, MatchOutcome, MatchUserResult, Timestamp};
+
+// how many past matches are kept per user
+const MAX_HISTORY_PER_USER: usize = 50;
+
+pub struct MatchRecord {
+    lobby_id: LobbyId,
+    match_name: String,
+    timestamp: Timestamp,
+    outcome: MatchOutcome,
+    player_count: usize,
+    result: MatchUserResult,
+}
+
+impl MatchRecord {
+    pub fn lobby_id(&self) -> LobbyId {
+        self.lobby_id
+    }
+
+    pub fn match_name(&self) -> &str {
+        &self.match_name
+    }
+
+    pub fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+
+    pub fn outcome(&self) -> MatchOutcome {
+        self.outcome
+    }
+
+    pub fn player_count(&self) -> usize {
+        self.player_count
+    }
+
+    pub fn result(&self) -> &MatchUserResult {
+        &self.result
+    }
+}
+
+pub struct MatchHistoryState {
+    // most recent match is at the back
+    user_histories: HashMap<UserId, VecDeque<MatchRecord>>,
+}
+
+impl MatchHistoryState {
+    pub fn new() -> Self {
+        Self {
+            user_histories: HashMap::new(),
+        }
+    }
+
+    pub fn record(
+        &mut self,
+        lobby_id: &LobbyId,
+        match_name: &str,
+        outcome: MatchOutcome,
+        results: &Vec<(UserId, MatchUserResult)>,
+    ) {
+        let timestamp = Timestamp::now();
+        let player_count = results.len();
+
+        for (user_id, result) in results {
+            let history = self
+                .user_histories
+                .entry(*user_id)
+                .or_insert(VecDeque::new());
+            history.push_back(MatchRecord {
+                lobby_id: *lobby_id,
+                match_name: match_name.to_string(),
+                timestamp,
+                outcome,
+                player_count,
+                result: *result,
+            });
+            if history.len() > MAX_HISTORY_PER_USER {
+                history.pop_front();
+            }
+        }
+    }
+
+    pub fn get_user_history(&self, user_id: &UserId) -> Vec<&MatchRecord> {
+        let Some(history) = self.user_histories.get(user_id) else {
+            return Vec::new();
+        };
+        history.iter().rev().collect()
+    }
+}
@@ -1,12 +1,15 @@
+use config::region_server_secret;
 use http_client::ResponseError;
 use http_server::{async_dup::Arc, executor::smol::lock::RwLock, ApiServer, Server};
 use logging::warn;
 
 use social_server_http_proto::{
-    MatchLobbyCreateRequest, MatchLobbyCreateResponse, MatchLobbyJoinRequest,
-    MatchLobbyJoinResponse, MatchLobbyLeaveRequest, MatchLobbyLeaveResponse,
-    MatchLobbySendMessageRequest, MatchLobbySendMessageResponse, MatchLobbyStartRequest,
-    MatchLobbyStartResponse,
+    MatchEndedRequest, MatchEndedResponse, MatchLobbyCreateRequest, MatchLobbyCreateResponse,
+    MatchLobbyInviteRequest, MatchLobbyInviteResponse, MatchLobbyJoinRequest,
+    MatchLobbyJoinResponse, MatchLobbyKickRequest, MatchLobbyKickResponse, MatchLobbyLeaveRequest,
+    MatchLobbyLeaveResponse, MatchLobbyLeaveSpectateRequest, MatchLobbyLeaveSpectateResponse,
+    MatchLobbySendMessageRequest, MatchLobbySendMessageResponse, MatchLobbySpectateRequest,
+    MatchLobbySpectateResponse, MatchLobbyStartRequest, MatchLobbyStartResponse,
 };
 
 use crate::state::State;
@@ -37,11 +40,19 @@ async fn async_recv_match_lobby_create_request_impl(
     };
 
     // create new match lobby
-    let new_match_lobby_id = state.match_lobbies.create(
+    let new_match_lobby_id = match state.match_lobbies.create(
         &session_server_id,
         request.match_name(),
         &request.creator_user_id(),
-    );
+        request.settings(),
+        request.password(),
+    ) {
+        Ok(lobby_id) => lobby_id,
+        Err(err) => {
+            warn!("failed to create lobby: {}", err);
+            return Err(ResponseError::InternalServerError(err));
+        }
+    };
 
     // owner joins the lobby
     state
@@ -77,14 +88,20 @@ async fn async_recv_match_lobby_join_request_impl(
         return Err(ResponseError::Unauthenticated);
     };
 
+    if let Err(err) = state.match_lobbies.join(
+        &session_server_id,
+        &request.lobby_id(),
+        &request.user_id(),
+        request.password(),
+    ) {
+        warn!("failed to join lobby: {}", err);
+        return Err(ResponseError::InternalServerError(err));
+    }
+
     state
         .users
         .user_joins_lobby(&request.user_id(), &request.lobby_id());
 
-    state
-        .match_lobbies
-        .join(&session_server_id, &request.lobby_id(), &request.user_id());
-
     // responding
     return Ok(MatchLobbyJoinResponse);
 }
@@ -124,6 +141,100 @@ async fn async_recv_match_lobby_leave_request_impl(
     return Ok(MatchLobbyLeaveResponse);
 }
 
+pub fn recv_match_lobby_kick_request(
+    host_name: &str,
+    server: &mut Server,
+    state: Arc<RwLock<State>>,
+) {
+    server.api_endpoint(host_name, None, move |_addr, req| {
+        let state = state.clone();
+        async move { async_recv_match_lobby_kick_request_impl(state, req).await }
+    });
+}
+
+async fn async_recv_match_lobby_kick_request_impl(
+    state: Arc<RwLock<State>>,
+    request: MatchLobbyKickRequest,
+) -> Result<MatchLobbyKickResponse, ResponseError> {
+    let mut state = state.write().await;
+
+    let Some(session_server_id) = state
+        .session_servers
+        .get_session_server_id(&request.session_instance_secret())
+    else {
+        warn!("invalid request instance secret");
+        return Err(ResponseError::Unauthenticated);
+    };
+
+    let Some(lobby_id) = state.users.get_user_lobby_id(&request.user_id()) else {
+        warn!("user is not in a lobby");
+        return Err(ResponseError::InternalServerError(
+            "user is not in a lobby".to_string(),
+        ));
+    };
+
+    if let Err(err) = state.match_lobbies.kick(
+        &session_server_id,
+        &lobby_id,
+        &request.user_id(),
+        &request.kicked_user_id(),
+    ) {
+        warn!("failed to kick user from lobby: {}", err);
+        return Err(ResponseError::InternalServerError(err));
+    }
+
+    state.users.user_leaves_lobby(&request.kicked_user_id());
+
+    // responding
+    return Ok(MatchLobbyKickResponse);
+}
+
+pub fn recv_match_lobby_invite_request(
+    host_name: &str,
+    server: &mut Server,
+    state: Arc<RwLock<State>>,
+) {
+    server.api_endpoint(host_name, None, move |_addr, req| {
+        let state = state.clone();
+        async move { async_recv_match_lobby_invite_request_impl(state, req).await }
+    });
+}
+
+async fn async_recv_match_lobby_invite_request_impl(
+    state: Arc<RwLock<State>>,
+    request: MatchLobbyInviteRequest,
+) -> Result<MatchLobbyInviteResponse, ResponseError> {
+    let mut state = state.write().await;
+
+    let Some(session_server_id) = state
+        .session_servers
+        .get_session_server_id(&request.session_instance_secret())
+    else {
+        warn!("invalid request instance secret");
+        return Err(ResponseError::Unauthenticated);
+    };
+
+    let Some(lobby_id) = state.users.get_user_lobby_id(&request.user_id()) else {
+        warn!("user is not in a lobby");
+        return Err(ResponseError::InternalServerError(
+            "user is not in a lobby".to_string(),
+        ));
+    };
+
+    if let Err(err) = state.match_lobbies.invite(
+        &session_server_id,
+        &lobby_id,
+        &request.user_id(),
+        &request.invited_user_id(),
+    ) {
+        warn!("failed to invite user to lobby: {}", err);
+        return Err(ResponseError::InternalServerError(err));
+    }
+
+    // responding
+    return Ok(MatchLobbyInviteResponse::new(lobby_id));
+}
+
 pub fn recv_match_lobby_start_request(
     host_name: &str,
     server: &mut Server,
@@ -164,10 +275,93 @@ async fn async_recv_match_lobby_start_request_impl(
         return Err(ResponseError::InternalServerError(err));
     }
 
+    let lobby_user_ids = state.match_lobbies.get_lobby_users(&lobby_id);
+    state.users.users_set_in_match(&lobby_user_ids, true);
+
     // responding
     return Ok(MatchLobbyStartResponse::new(lobby_id));
 }
 
+pub fn recv_match_lobby_spectate_request(
+    host_name: &str,
+    server: &mut Server,
+    state: Arc<RwLock<State>>,
+) {
+    server.api_endpoint(host_name, None, move |_addr, req| {
+        let state = state.clone();
+        async move { async_recv_match_lobby_spectate_request_impl(state, req).await }
+    });
+}
+
+async fn async_recv_match_lobby_spectate_request_impl(
+    state: Arc<RwLock<State>>,
+    request: MatchLobbySpectateRequest,
+) -> Result<MatchLobbySpectateResponse, ResponseError> {
+    let mut state = state.write().await;
+
+    if state
+        .session_servers
+        .get_session_server_id(&request.session_instance_secret())
+        .is_none()
+    {
+        warn!("invalid request instance secret");
+        return Err(ResponseError::Unauthenticated);
+    }
+
+    if state.users.get_user_lobby_id(&request.user_id()).is_some() {
+        warn!("user in a lobby cannot spectate");
+        return Err(ResponseError::InternalServerError(
+            "user is in a lobby".to_string(),
+        ));
+    }
+
+    if let Err(err) = state
+        .match_lobbies
+        .spectate(&request.lobby_id(), &request.user_id())
+    {
+        warn!("failed to spectate lobby: {}", err);
+        return Err(ResponseError::InternalServerError(err));
+    }
+
+    // responding
+    return Ok(MatchLobbySpectateResponse);
+}
+
+pub fn recv_match_lobby_leave_spectate_request(
+    host_name: &str,
+    server: &mut Server,
+    state: Arc<RwLock<State>>,
+) {
+    server.api_endpoint(host_name, None, move |_addr, req| {
+        let state = state.clone();
+        async move { async_recv_match_lobby_leave_spectate_request_impl(state, req).await }
+    });
+}
+
+async fn async_recv_match_lobby_leave_spectate_request_impl(
+    state: Arc<RwLock<State>>,
+    request: MatchLobbyLeaveSpectateRequest,
+) -> Result<MatchLobbyLeaveSpectateResponse, ResponseError> {
+    let mut state = state.write().await;
+
+    if state
+        .session_servers
+        .get_session_server_id(&request.session_instance_secret())
+        .is_none()
+    {
+        warn!("invalid request instance secret");
+        return Err(ResponseError::Unauthenticated);
+    }
+
+    if let Err(err) = state.match_lobbies.leave_spectate(&request.user_id()) {
+        warn!("failed to leave spectating: {}", err);
+        return Err(ResponseError::InternalServerError(err));
+    }
+
+    // responding
+    return Ok(MatchLobbyLeaveSpectateResponse);
+}
+
 pub fn recv_match_lobby_send_message_request(
     host_name: &str,
     server: &mut Server,
@@ -195,13 +389,84 @@ async fn async_recv_match_lobby_send_message_request_impl(
 
     let lobby_id = state.users.get_user_lobby_id(&request.user_id()).unwrap();
 
+    let message = match state
+        .moderation
+        .moderate_message(&request.user_id(), request.message())
+    {
+        Ok(message) => message,
+        Err(rejection) => {
+            warn!(
+                "lobby chat message from user {:?} rejected: {:?}",
+                request.user_id(),
+                rejection
+            );
+            return Err(ResponseError::BadRequest);
+        }
+    };
+
     let (msg_id, timestamp) = state.match_lobbies.send_message(
         &session_server_id,
         &lobby_id,
         &request.user_id(),
-        request.message(),
+        &message,
     );
 
     // responding
     return Ok(MatchLobbySendMessageResponse::new(msg_id, timestamp));
 }
+
+pub fn recv_match_ended_request(host_name: &str, server: &mut Server, state: Arc<RwLock<State>>) {
+    server.api_endpoint(host_name, None, move |_addr, req| {
+        let state = state.clone();
+        async move { async_recv_match_ended_request_impl(state, req).await }
+    });
+}
+
+async fn async_recv_match_ended_request_impl(
+    state: Arc<RwLock<State>>,
+    request: MatchEndedRequest,
+) -> Result<MatchEndedResponse, ResponseError> {
+    if request.region_secret() != region_server_secret() {
+        warn!("invalid request secret");
+        return Err(ResponseError::Unauthenticated);
+    }
+
+    let mut state = state.write().await;
+
+    // setting last heard
+    state.region_server.heard_from_region_server();
+
+    let lobby_id = request.lobby_id();
+    let outcome = request.outcome();
+    let results = request.results();
+
+    // the lobby is gone if social restarted mid-match, there's nothing to end and nothing for the
+    // world server to retry
+    if !state.match_lobbies.has_lobby(&lobby_id) {
+        warn!("match ended for unknown lobby: {:?}", lobby_id);
+        return Err(ResponseError::NotFound);
+    }
+
+    let match_name = match state.match_lobbies.end(&lobby_id, outcome, results) {
+        Ok(Some(match_name)) => match_name,
+        Ok(None) => {
+            // a retry, after our response to the first report was lost
+            return Ok(MatchEndedResponse);
+        }
+        Err(err) => {
+            warn!("failed to end lobby match: {}", err);
+            return Err(ResponseError::Conflict);
+        }
+    };
+
+    let lobby_user_ids = state.match_lobbies.get_lobby_users(&lobby_id);
+    state.users.users_set_in_match(&lobby_user_ids, false);
+
+    // record match history
+    state
+        .match_history
+        .record(&lobby_id, &match_name, outcome, results);
+
+    // responding
+    return Ok(MatchEndedResponse);
+}
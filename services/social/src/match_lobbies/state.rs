@@ -1,63 +1,142 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 use auth_server_types::UserId;
-use social_server_types::{LobbyId, MessageId, Timestamp};
+use social_server_types::{
+    LobbyAccess, LobbyId, LobbySettings, MatchOutcome, MatchUserResult, MessageId, Timestamp,
+};
 
-use crate::session_servers::SessionServerId;
+use crate::{
+    chat_log::{ChatLog, ChatLogEntry, CHAT_LOG_DIR},
+    session_servers::SessionServerId,
+};
 
 #[derive(Eq, PartialEq, Copy, Clone)]
 pub(crate) enum LobbyState {
     WaitingToStart,
     InProgress,
+    // the last match ran to completion, lobby may start another
+    Finished,
+    // every player left the match. no more joins or starts
+    Abandoned,
 }
 
 pub(crate) enum LobbyPatch {
-    Create(LobbyId, UserId, String),
+    Create(LobbyId, UserId, String, LobbySettings),
     Join(LobbyId, UserId),
     Leave(UserId),
+    Kick(UserId),
+    Invite(LobbyId, UserId),
+    OwnerChange(LobbyId, UserId),
     Message(MessageId, Timestamp, UserId, String),
     Start(LobbyId),
+    End(LobbyId, MatchOutcome, Vec<(UserId, MatchUserResult)>),
 }
 
 struct LobbyData {
     owner_user_id: UserId,
     match_name: String,
-    users: HashSet<UserId>,
-    message_log: VecDeque<(MessageId, Timestamp, UserId, String)>,
-    next_message_id: MessageId,
+    settings: LobbySettings,
+    password: Option<String>,
+    // in order of joining, so the longest-present member is first
+    users: Vec<UserId>,
+    invited_users: HashSet<UserId>,
+    chat_log: ChatLog,
     state: LobbyState,
+    // set once the region server has placed the running match on a world server
+    world_instance_secret: Option<String>,
+    // watching the running match, without being members of the lobby
+    spectators: HashSet<UserId>,
 }
 
 impl LobbyData {
-    pub fn new(owner_user_id: UserId, match_name: String) -> Self {
-        let mut users = HashSet::new();
-        users.insert(owner_user_id);
+    pub fn new(
+        lobby_id: LobbyId,
+        owner_user_id: UserId,
+        match_name: String,
+        settings: LobbySettings,
+        password: Option<String>,
+    ) -> Self {
+        // lobby ids are reused across restarts, so each lobby's log is named by creation time too
+        let created_secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0);
+        let chat_log_path = Path::new(CHAT_LOG_DIR).join("lobbies").join(format!(
+            "lobby_{}_{}.log",
+            lobby_id.to_u16(),
+            created_secs
+        ));
+
         Self {
             owner_user_id,
             match_name,
-            users,
-            message_log: VecDeque::new(),
-            next_message_id: MessageId::new(0),
+            settings,
+            password,
+            users: vec![owner_user_id],
+            invited_users: HashSet::new(),
+            chat_log: ChatLog::create(&chat_log_path),
             state: LobbyState::WaitingToStart,
+            world_instance_secret: None,
+            spectators: HashSet::new(),
         }
     }
 
-    pub fn send_message(&mut self, user_id: &UserId, message: &str) -> (MessageId, Timestamp) {
-        // get next lobby chat id
-        let next_message_id = self.next_message_id;
-        self.next_message_id = self.next_message_id.next();
+    fn has_user(&self, user_id: &UserId) -> bool {
+        self.users.contains(user_id)
+    }
 
-        // get timestamp
-        let timestamp = Timestamp::now();
+    fn is_full(&self) -> bool {
+        self.users.len() >= self.settings.max_players() as usize
+    }
 
-        // add to message log
-        self.message_log
-            .push_back((next_message_id, timestamp, *user_id, message.to_string()));
-        if self.message_log.len() > 100 {
-            self.message_log.pop_front();
+    fn can_join(&self, user_id: &UserId, password: Option<&str>) -> Result<(), String> {
+        match self.state {
+            LobbyState::WaitingToStart | LobbyState::Finished => {}
+            LobbyState::InProgress => {
+                return Err("lobby match is in progress".to_string());
+            }
+            LobbyState::Abandoned => {
+                return Err("lobby has been abandoned".to_string());
+            }
+        }
+        if self.has_user(user_id) {
+            return Err("user is already in the lobby".to_string());
         }
+        if self.is_full() {
+            return Err("lobby is full".to_string());
+        }
+        if self.invited_users.contains(user_id) {
+            // an invite gets past both the password and invite-only checks
+            return Ok(());
+        }
+        match self.settings.access() {
+            LobbyAccess::Public => Ok(()),
+            LobbyAccess::Password => {
+                if password.is_some() && password == self.password.as_deref() {
+                    Ok(())
+                } else {
+                    Err("incorrect lobby password".to_string())
+                }
+            }
+            LobbyAccess::InviteOnly => Err("lobby is invite-only".to_string()),
+        }
+    }
+
+    // returns true if the user was in the lobby
+    fn remove_user(&mut self, user_id: &UserId) -> bool {
+        let Some(index) = self.users.iter().position(|id| id == user_id) else {
+            return false;
+        };
+        self.users.remove(index);
+        true
+    }
 
-        (next_message_id, timestamp)
+    pub fn send_message(&mut self, user_id: &UserId, message: &str) -> (MessageId, Timestamp) {
+        self.chat_log.append(user_id, message)
     }
 }
 
@@ -66,9 +145,12 @@ pub struct MatchLobbiesState {
     next_lobby_id: LobbyId,
 
     starting_lobbies: Vec<LobbyId>,
+    starting_spectators: Vec<(LobbyId, UserId)>,
+    leaving_spectators: Vec<(LobbyId, UserId)>,
 
     // the session server id here is the SENDER not the RECEIVER
-    outgoing_patches: HashMap<SessionServerId, Vec<LobbyPatch>>,
+    // (None when the patch originates from the region server, and goes to every session server)
+    outgoing_patches: HashMap<Option<SessionServerId>, Vec<LobbyPatch>>,
 }
 
 impl MatchLobbiesState {
@@ -78,6 +160,8 @@ impl MatchLobbiesState {
             next_lobby_id: LobbyId::new(0),
 
             starting_lobbies: Vec::new(),
+            starting_spectators: Vec::new(),
+            leaving_spectators: Vec::new(),
 
             outgoing_patches: HashMap::new(),
         }
@@ -88,28 +172,43 @@ impl MatchLobbiesState {
         session_instance_id: &SessionServerId,
         match_name: &str,
         creator_user_id: &UserId,
-    ) -> LobbyId {
+        settings: LobbySettings,
+        password: Option<&str>,
+    ) -> Result<LobbyId, String> {
+        let password = match settings.access() {
+            LobbyAccess::Password => match password {
+                Some(password) if !password.is_empty() => Some(password.to_string()),
+                _ => return Err("password lobby requires a password".to_string()),
+            },
+            LobbyAccess::Public | LobbyAccess::InviteOnly => None,
+        };
+
         let new_lobby_id = self.next_lobby_id;
         self.next_lobby_id = self.next_lobby_id.next();
 
         self.lobbies.insert(
             new_lobby_id,
-            LobbyData::new(*creator_user_id, match_name.to_string()),
+            LobbyData::new(
+                new_lobby_id,
+                *creator_user_id,
+                match_name.to_string(),
+                settings,
+                password,
+            ),
         );
 
         // add to outgoing patches
-        if !self.outgoing_patches.contains_key(&session_instance_id) {
-            self.outgoing_patches
-                .insert(*session_instance_id, Vec::new());
-        }
-        let session_server_patches = self.outgoing_patches.get_mut(session_instance_id).unwrap();
-        session_server_patches.push(LobbyPatch::Create(
-            new_lobby_id,
-            *creator_user_id,
-            match_name.to_string(),
-        ));
+        self.push_patch(
+            Some(session_instance_id),
+            LobbyPatch::Create(
+                new_lobby_id,
+                *creator_user_id,
+                match_name.to_string(),
+                settings,
+            ),
+        );
 
-        new_lobby_id
+        Ok(new_lobby_id)
     }
 
     pub fn join(
@@ -117,16 +216,22 @@ impl MatchLobbiesState {
         session_server_id: &SessionServerId,
         lobby_id: &LobbyId,
         joining_user_id: &UserId,
-    ) {
-        let lobby_data = self.lobbies.get_mut(lobby_id).unwrap();
-        lobby_data.users.insert(*joining_user_id);
+        password: Option<&str>,
+    ) -> Result<(), String> {
+        let Some(lobby_data) = self.lobbies.get_mut(lobby_id) else {
+            return Err("lobby does not exist".to_string());
+        };
+        lobby_data.can_join(joining_user_id, password)?;
+        lobby_data.invited_users.remove(joining_user_id);
+        lobby_data.users.push(*joining_user_id);
 
         // add to outgoing patches
-        if !self.outgoing_patches.contains_key(&session_server_id) {
-            self.outgoing_patches.insert(*session_server_id, Vec::new());
-        }
-        let session_server_patches = self.outgoing_patches.get_mut(&session_server_id).unwrap();
-        session_server_patches.push(LobbyPatch::Join(*lobby_id, *joining_user_id));
+        self.push_patch(
+            Some(session_server_id),
+            LobbyPatch::Join(*lobby_id, *joining_user_id),
+        );
+
+        Ok(())
     }
 
     pub fn leave(
@@ -136,21 +241,86 @@ impl MatchLobbiesState {
         leaving_user_id: &UserId,
     ) {
         let lobby_data = self.lobbies.get_mut(lobby_id).unwrap();
-        lobby_data.users.remove(leaving_user_id);
+        lobby_data.remove_user(leaving_user_id);
+
+        let new_owner_user_id = if lobby_data.users.is_empty() {
+            // nobody is left, delete the lobby along with its chat. a running match still has to
+            // report its end, so that lobby is deleted once it does
+            if lobby_data.state != LobbyState::InProgress {
+                self.delete_lobby(lobby_id);
+            }
+            None
+        } else if lobby_data.owner_user_id == *leaving_user_id {
+            // ownership goes to the longest-present member
+            let new_owner_user_id = lobby_data.users[0];
+            lobby_data.owner_user_id = new_owner_user_id;
+            Some(new_owner_user_id)
+        } else {
+            None
+        };
 
-        let owner_user_id = lobby_data.owner_user_id;
-        if owner_user_id == *leaving_user_id {
-            // delete the lobby
-            self.lobbies.remove(lobby_id);
+        // add to outgoing patches
+        self.push_patch(Some(session_server_id), LobbyPatch::Leave(*leaving_user_id));
+        if let Some(new_owner_user_id) = new_owner_user_id {
+            // this goes to every session server, including the sender
+            self.push_patch(None, LobbyPatch::OwnerChange(*lobby_id, new_owner_user_id));
+        }
+    }
+
+    pub fn kick(
+        &mut self,
+        session_server_id: &SessionServerId,
+        lobby_id: &LobbyId,
+        kicking_user_id: &UserId,
+        kicked_user_id: &UserId,
+    ) -> Result<(), String> {
+        let Some(lobby_data) = self.lobbies.get_mut(lobby_id) else {
+            return Err("lobby does not exist".to_string());
+        };
+        if lobby_data.owner_user_id != *kicking_user_id {
+            return Err("user is not the owner of the lobby".to_string());
+        }
+        if kicking_user_id == kicked_user_id {
+            return Err("owner cannot kick themselves".to_string());
+        }
+        if lobby_data.state == LobbyState::InProgress {
+            return Err("lobby match is in progress".to_string());
+        }
+        if !lobby_data.remove_user(kicked_user_id) {
+            return Err("kicked user is not in the lobby".to_string());
         }
 
         // add to outgoing patches
-        if !self.outgoing_patches.contains_key(&session_server_id) {
-            self.outgoing_patches.insert(*session_server_id, Vec::new());
+        self.push_patch(Some(session_server_id), LobbyPatch::Kick(*kicked_user_id));
+
+        Ok(())
+    }
+
+    pub fn invite(
+        &mut self,
+        session_server_id: &SessionServerId,
+        lobby_id: &LobbyId,
+        inviting_user_id: &UserId,
+        invited_user_id: &UserId,
+    ) -> Result<(), String> {
+        let Some(lobby_data) = self.lobbies.get_mut(lobby_id) else {
+            return Err("lobby does not exist".to_string());
+        };
+        if lobby_data.owner_user_id != *inviting_user_id {
+            return Err("user is not the owner of the lobby".to_string());
+        }
+        if lobby_data.has_user(invited_user_id) {
+            return Err("invited user is already in the lobby".to_string());
         }
+        lobby_data.invited_users.insert(*invited_user_id);
+
+        // add to outgoing patches
+        self.push_patch(
+            Some(session_server_id),
+            LobbyPatch::Invite(*lobby_id, *invited_user_id),
+        );
 
-        let session_server_patches = self.outgoing_patches.get_mut(&session_server_id).unwrap();
-        session_server_patches.push(LobbyPatch::Leave(*leaving_user_id));
+        Ok(())
     }
 
     pub fn send_message(
@@ -164,27 +334,24 @@ impl MatchLobbiesState {
         let (msg_id, timestamp) = lobby_data.send_message(user_id, message);
 
         // add to outgoing patches
-        if !self
-            .outgoing_patches
-            .contains_key(sending_session_server_id)
-        {
-            self.outgoing_patches
-                .insert(*sending_session_server_id, Vec::new());
-        }
-        let session_server_patches = self
-            .outgoing_patches
-            .get_mut(sending_session_server_id)
-            .unwrap();
-        session_server_patches.push(LobbyPatch::Message(
-            msg_id,
-            timestamp,
-            *user_id,
-            message.to_string(),
-        ));
+        self.push_patch(
+            Some(sending_session_server_id),
+            LobbyPatch::Message(msg_id, timestamp, *user_id, message.to_string()),
+        );
 
         (msg_id, timestamp)
     }
 
+    pub fn get_message_history(
+        &self,
+        lobby_id: &LobbyId,
+        before_message_id: Option<MessageId>,
+        count: usize,
+    ) -> Option<(Vec<ChatLogEntry>, bool)> {
+        let lobby_data = self.lobbies.get(lobby_id)?;
+        Some(lobby_data.chat_log.page(before_message_id, count))
+    }
+
     pub fn start(
         &mut self,
         session_server_id: &SessionServerId,
@@ -195,33 +362,156 @@ impl MatchLobbiesState {
         if lobby_data.owner_user_id != *starting_user_id {
             return Err("user is not the owner of the lobby".to_string());
         }
-        if lobby_data.state != LobbyState::WaitingToStart {
-            return Err("lobby is not waiting to start".to_string());
+        match lobby_data.state {
+            LobbyState::WaitingToStart | LobbyState::Finished => {}
+            LobbyState::InProgress => {
+                return Err("lobby match is already in progress".to_string());
+            }
+            LobbyState::Abandoned => {
+                return Err("lobby has been abandoned".to_string());
+            }
         }
         lobby_data.state = LobbyState::InProgress;
 
         self.starting_lobbies.push(*lobby_id);
 
         // add to outgoing patches
-        if !self.outgoing_patches.contains_key(&session_server_id) {
-            self.outgoing_patches.insert(*session_server_id, Vec::new());
+        self.push_patch(Some(session_server_id), LobbyPatch::Start(*lobby_id));
+
+        return Ok(());
+    }
+
+    pub fn spectate(&mut self, lobby_id: &LobbyId, user_id: &UserId) -> Result<(), String> {
+        let Some(lobby_data) = self.lobbies.get_mut(lobby_id) else {
+            return Err("lobby does not exist".to_string());
+        };
+        if lobby_data.state != LobbyState::InProgress {
+            return Err("lobby match is not in progress".to_string());
+        }
+        if lobby_data.world_instance_secret.is_none() {
+            return Err("lobby match has not reached a world server yet".to_string());
+        }
+        if lobby_data.has_user(user_id) {
+            return Err("user is playing in the lobby match".to_string());
+        }
+        if !lobby_data.spectators.insert(*user_id) {
+            return Err("user is already spectating the lobby match".to_string());
         }
 
-        let session_server_patches = self.outgoing_patches.get_mut(&session_server_id).unwrap();
-        session_server_patches.push(LobbyPatch::Start(*lobby_id));
+        self.starting_spectators.push((*lobby_id, *user_id));
 
-        return Ok(());
+        Ok(())
+    }
+
+    // the world server is told to let the spectator go, see `take_leaving_spectators()`
+    pub fn leave_spectate(&mut self, user_id: &UserId) -> Result<LobbyId, String> {
+        let Some((lobby_id, lobby_data)) = self
+            .lobbies
+            .iter_mut()
+            .find(|(_, lobby_data)| lobby_data.spectators.contains(user_id))
+        else {
+            return Err("user is not spectating a lobby match".to_string());
+        };
+        lobby_data.spectators.remove(user_id);
+        let lobby_id = *lobby_id;
+
+        self.leaving_spectators.push((lobby_id, *user_id));
+
+        Ok(lobby_id)
+    }
+
+    pub fn is_spectating(&self, lobby_id: &LobbyId, user_id: &UserId) -> bool {
+        self.lobbies
+            .get(lobby_id)
+            .map(|lobby_data| lobby_data.spectators.contains(user_id))
+            .unwrap_or(false)
     }
 
-    pub fn get_lobbies(&self) -> Vec<(LobbyId, UserId, String, Vec<UserId>, LobbyState)> {
+    pub fn remove_spectator(&mut self, user_id: &UserId) {
+        for lobby_data in self.lobbies.values_mut() {
+            lobby_data.spectators.remove(user_id);
+        }
+    }
+
+    pub fn set_world_instance_secret(&mut self, lobby_id: &LobbyId, world_instance_secret: &str) {
+        let Some(lobby_data) = self.lobbies.get_mut(lobby_id) else {
+            return;
+        };
+        lobby_data.world_instance_secret = Some(world_instance_secret.to_string());
+    }
+
+    pub fn get_world_instance_secret(&self, lobby_id: &LobbyId) -> Option<String> {
+        self.lobbies.get(lobby_id)?.world_instance_secret.clone()
+    }
+
+    pub fn has_lobby(&self, lobby_id: &LobbyId) -> bool {
+        self.lobbies.contains_key(lobby_id)
+    }
+
+    // returns the match name, for the match history. the world server retries until it hears
+    // back, so a match which has already ended returns None rather than an error
+    pub fn end(
+        &mut self,
+        lobby_id: &LobbyId,
+        outcome: MatchOutcome,
+        results: &Vec<(UserId, MatchUserResult)>,
+    ) -> Result<Option<String>, String> {
+        let Some(lobby_data) = self.lobbies.get_mut(lobby_id) else {
+            return Err("lobby does not exist".to_string());
+        };
+        match lobby_data.state {
+            LobbyState::InProgress => {}
+            LobbyState::Finished | LobbyState::Abandoned => return Ok(None),
+            LobbyState::WaitingToStart => {
+                return Err("lobby match has not started".to_string());
+            }
+        }
+        lobby_data.state = match outcome {
+            MatchOutcome::Finished => LobbyState::Finished,
+            MatchOutcome::Abandoned => LobbyState::Abandoned,
+        };
+        lobby_data.world_instance_secret = None;
+        // session servers send their spectators home along with the players
+        lobby_data.spectators.clear();
+        let match_name = lobby_data.match_name.clone();
+        let is_empty = lobby_data.users.is_empty();
+
+        // add to outgoing patches, this goes to every session server
+        self.push_patch(None, LobbyPatch::End(*lobby_id, outcome, results.clone()));
+
+        // everyone left during the match, `leave()` kept the lobby around until now
+        if is_empty {
+            self.delete_lobby(lobby_id);
+        }
+
+        return Ok(Some(match_name));
+    }
+
+    fn delete_lobby(&mut self, lobby_id: &LobbyId) {
+        if let Some(lobby_data) = self.lobbies.remove(lobby_id) {
+            lobby_data.chat_log.delete();
+        }
+    }
+
+    pub fn get_lobbies(
+        &self,
+    ) -> Vec<(
+        LobbyId,
+        UserId,
+        String,
+        LobbySettings,
+        Vec<UserId>,
+        LobbyState,
+    )> {
         let mut output = Vec::new();
 
         for (lobby_id, lobby_data) in self.lobbies.iter() {
             let owner_user_id = lobby_data.owner_user_id;
             let match_name = lobby_data.match_name.clone();
-            let users = lobby_data.users.iter().map(|x| *x).collect();
+            let settings = lobby_data.settings;
+            let users = lobby_data.users.clone();
             let state = lobby_data.state.clone();
-            output.push((*lobby_id, owner_user_id, match_name, users, state));
+            output.push((*lobby_id, owner_user_id, match_name, settings, users, state));
         }
 
         output
@@ -232,11 +522,324 @@ impl MatchLobbiesState {
         lobby_data.users.iter().map(|x| *x).collect()
     }
 
-    pub fn take_patches(&mut self) -> HashMap<SessionServerId, Vec<LobbyPatch>> {
+    pub fn take_patches(&mut self) -> HashMap<Option<SessionServerId>, Vec<LobbyPatch>> {
         std::mem::take(&mut self.outgoing_patches)
     }
 
+    fn push_patch(
+        &mut self,
+        sending_session_server_id: Option<&SessionServerId>,
+        patch: LobbyPatch,
+    ) {
+        self.outgoing_patches
+            .entry(sending_session_server_id.copied())
+            .or_insert(Vec::new())
+            .push(patch);
+    }
+
     pub fn take_starting_lobbies(&mut self) -> Vec<LobbyId> {
         std::mem::take(&mut self.starting_lobbies)
     }
+
+    pub fn take_starting_spectators(&mut self) -> Vec<(LobbyId, UserId)> {
+        std::mem::take(&mut self.starting_spectators)
+    }
+
+    pub fn take_leaving_spectators(&mut self) -> Vec<(LobbyId, UserId)> {
+        std::mem::take(&mut self.leaving_spectators)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn owner() -> UserId {
+        UserId::new(1)
+    }
+
+    fn viewer() -> UserId {
+        UserId::new(2)
+    }
+
+    fn player() -> UserId {
+        UserId::new(3)
+    }
+
+    fn session_server() -> SessionServerId {
+        SessionServerId::new(0)
+    }
+
+    fn create_lobby(lobbies: &mut MatchLobbiesState) -> LobbyId {
+        let settings = LobbySettings::new(4, LobbyAccess::Public);
+        lobbies
+            .create(&session_server(), "match", &owner(), settings, None)
+            .unwrap()
+    }
+
+    // a lobby whose match is running on a world server
+    fn running_lobby(lobbies: &mut MatchLobbiesState) -> LobbyId {
+        let lobby_id = create_lobby(lobbies);
+        lobbies
+            .start(&session_server(), &lobby_id, &owner())
+            .unwrap();
+        lobbies.set_world_instance_secret(&lobby_id, "world");
+        lobby_id
+    }
+
+    fn lobby_state(lobbies: &MatchLobbiesState, lobby_id: &LobbyId) -> LobbyState {
+        lobbies.lobbies.get(lobby_id).unwrap().state
+    }
+
+    #[test]
+    fn create_and_join() {
+        let mut lobbies = MatchLobbiesState::new();
+        let lobby_id = create_lobby(&mut lobbies);
+        assert!(lobbies.has_lobby(&lobby_id));
+        assert_eq!(lobbies.get_lobby_users(&lobby_id), vec![owner()]);
+
+        lobbies
+            .join(&session_server(), &lobby_id, &player(), None)
+            .unwrap();
+        assert_eq!(lobbies.get_lobby_users(&lobby_id), vec![owner(), player()]);
+        assert!(lobbies
+            .join(&session_server(), &lobby_id, &player(), None)
+            .is_err());
+        assert!(lobbies
+            .join(&session_server(), &lobby_id.next(), &viewer(), None)
+            .is_err());
+
+        let patches = lobbies
+            .take_patches()
+            .remove(&Some(session_server()))
+            .unwrap();
+        assert!(matches!(
+            patches.as_slice(),
+            [LobbyPatch::Create(..), LobbyPatch::Join(..)]
+        ));
+    }
+
+    #[test]
+    fn join_checks_password_and_capacity() {
+        let mut lobbies = MatchLobbiesState::new();
+        let settings = LobbySettings::new(2, LobbyAccess::Password);
+        assert!(lobbies
+            .create(&session_server(), "match", &owner(), settings, None)
+            .is_err());
+        let lobby_id = lobbies
+            .create(
+                &session_server(),
+                "match",
+                &owner(),
+                settings,
+                Some("secret"),
+            )
+            .unwrap();
+
+        assert!(lobbies
+            .join(&session_server(), &lobby_id, &player(), None)
+            .is_err());
+        assert!(lobbies
+            .join(&session_server(), &lobby_id, &player(), Some("guess"))
+            .is_err());
+        lobbies
+            .join(&session_server(), &lobby_id, &player(), Some("secret"))
+            .unwrap();
+
+        assert!(lobbies
+            .join(&session_server(), &lobby_id, &viewer(), Some("secret"))
+            .is_err());
+    }
+
+    #[test]
+    fn start_and_end() {
+        let mut lobbies = MatchLobbiesState::new();
+        let lobby_id = create_lobby(&mut lobbies);
+        lobbies
+            .join(&session_server(), &lobby_id, &player(), None)
+            .unwrap();
+        assert!(lobbies
+            .end(&lobby_id, MatchOutcome::Finished, &Vec::new())
+            .is_err());
+
+        assert!(lobbies
+            .start(&session_server(), &lobby_id, &player())
+            .is_err());
+        lobbies
+            .start(&session_server(), &lobby_id, &owner())
+            .unwrap();
+        assert_eq!(lobbies.take_starting_lobbies(), vec![lobby_id]);
+        assert!(lobby_state(&lobbies, &lobby_id) == LobbyState::InProgress);
+        assert!(lobbies
+            .start(&session_server(), &lobby_id, &owner())
+            .is_err());
+        assert!(lobbies
+            .join(&session_server(), &lobby_id, &viewer(), None)
+            .is_err());
+
+        assert_eq!(
+            lobbies.end(&lobby_id, MatchOutcome::Finished, &Vec::new()),
+            Ok(Some("match".to_string()))
+        );
+        // the world server's retry of the same report
+        assert_eq!(
+            lobbies.end(&lobby_id, MatchOutcome::Finished, &Vec::new()),
+            Ok(None)
+        );
+        assert!(lobby_state(&lobbies, &lobby_id) == LobbyState::Finished);
+
+        // a finished lobby takes new players and plays again
+        lobbies
+            .join(&session_server(), &lobby_id, &viewer(), None)
+            .unwrap();
+        lobbies
+            .start(&session_server(), &lobby_id, &owner())
+            .unwrap();
+    }
+
+    #[test]
+    fn abandoned_lobby_is_closed() {
+        let mut lobbies = MatchLobbiesState::new();
+        let lobby_id = create_lobby(&mut lobbies);
+        lobbies
+            .start(&session_server(), &lobby_id, &owner())
+            .unwrap();
+        lobbies
+            .end(&lobby_id, MatchOutcome::Abandoned, &Vec::new())
+            .unwrap();
+
+        assert!(lobby_state(&lobbies, &lobby_id) == LobbyState::Abandoned);
+        assert!(lobbies
+            .join(&session_server(), &lobby_id, &player(), None)
+            .is_err());
+        assert!(lobbies
+            .start(&session_server(), &lobby_id, &owner())
+            .is_err());
+    }
+
+    #[test]
+    fn owner_leaving_passes_ownership() {
+        let mut lobbies = MatchLobbiesState::new();
+        let lobby_id = create_lobby(&mut lobbies);
+        lobbies
+            .join(&session_server(), &lobby_id, &player(), None)
+            .unwrap();
+        lobbies
+            .join(&session_server(), &lobby_id, &viewer(), None)
+            .unwrap();
+        lobbies.take_patches();
+
+        lobbies.leave(&session_server(), &lobby_id, &owner());
+        assert_eq!(lobbies.get_lobby_users(&lobby_id), vec![player(), viewer()]);
+
+        let mut patches = lobbies.take_patches();
+        let owner_changes = patches.remove(&None).unwrap();
+        assert!(matches!(
+            owner_changes.as_slice(),
+            [LobbyPatch::OwnerChange(_, user_id)] if *user_id == player()
+        ));
+        assert!(lobbies
+            .kick(&session_server(), &lobby_id, &player(), &viewer())
+            .is_ok());
+    }
+
+    #[test]
+    fn last_leave_deletes_lobby() {
+        let mut lobbies = MatchLobbiesState::new();
+        let lobby_id = create_lobby(&mut lobbies);
+
+        lobbies.leave(&session_server(), &lobby_id, &owner());
+        assert!(!lobbies.has_lobby(&lobby_id));
+        assert!(!lobbies.take_patches().contains_key(&None));
+    }
+
+    #[test]
+    fn running_lobby_is_deleted_once_its_match_ends() {
+        let mut lobbies = MatchLobbiesState::new();
+        let lobby_id = running_lobby(&mut lobbies);
+
+        lobbies.leave(&session_server(), &lobby_id, &owner());
+        assert!(lobbies.has_lobby(&lobby_id));
+
+        lobbies
+            .end(&lobby_id, MatchOutcome::Finished, &Vec::new())
+            .unwrap();
+        assert!(!lobbies.has_lobby(&lobby_id));
+    }
+
+    #[test]
+    fn spectate_running_match() {
+        let mut lobbies = MatchLobbiesState::new();
+        let lobby_id = running_lobby(&mut lobbies);
+
+        assert!(lobbies.spectate(&lobby_id, &viewer()).is_ok());
+        assert!(lobbies.is_spectating(&lobby_id, &viewer()));
+        assert_eq!(
+            lobbies.take_starting_spectators(),
+            vec![(lobby_id, viewer())]
+        );
+    }
+
+    #[test]
+    fn spectate_requires_a_running_match() {
+        let mut lobbies = MatchLobbiesState::new();
+        let lobby_id = create_lobby(&mut lobbies);
+        assert!(lobbies.spectate(&lobby_id, &viewer()).is_err());
+
+        // started, but not yet placed on a world server
+        lobbies
+            .start(&session_server(), &lobby_id, &owner())
+            .unwrap();
+        assert!(lobbies.spectate(&lobby_id, &viewer()).is_err());
+
+        lobbies.set_world_instance_secret(&lobby_id, "world");
+        assert!(lobbies.spectate(&lobby_id, &owner()).is_err());
+        assert!(lobbies.take_starting_spectators().is_empty());
+    }
+
+    #[test]
+    fn duplicate_spectate_is_rejected() {
+        let mut lobbies = MatchLobbiesState::new();
+        let lobby_id = running_lobby(&mut lobbies);
+
+        lobbies.spectate(&lobby_id, &viewer()).unwrap();
+        assert!(lobbies.spectate(&lobby_id, &viewer()).is_err());
+        assert_eq!(
+            lobbies.take_starting_spectators(),
+            vec![(lobby_id, viewer())]
+        );
+    }
+
+    #[test]
+    fn leave_spectate() {
+        let mut lobbies = MatchLobbiesState::new();
+        let lobby_id = running_lobby(&mut lobbies);
+        lobbies.spectate(&lobby_id, &viewer()).unwrap();
+
+        assert_eq!(lobbies.leave_spectate(&viewer()), Ok(lobby_id));
+        assert!(!lobbies.is_spectating(&lobby_id, &viewer()));
+        assert_eq!(
+            lobbies.take_leaving_spectators(),
+            vec![(lobby_id, viewer())]
+        );
+
+        assert!(lobbies.leave_spectate(&viewer()).is_err());
+        assert!(lobbies.take_leaving_spectators().is_empty());
+
+        // and may watch again
+        assert!(lobbies.spectate(&lobby_id, &viewer()).is_ok());
+    }
+
+    #[test]
+    fn match_end_clears_spectators() {
+        let mut lobbies = MatchLobbiesState::new();
+        let lobby_id = running_lobby(&mut lobbies);
+        lobbies.spectate(&lobby_id, &viewer()).unwrap();
+
+        lobbies
+            .end(&lobby_id, MatchOutcome::Finished, &Vec::new())
+            .unwrap();
+        assert!(!lobbies.is_spectating(&lobby_id, &viewer()));
+        assert!(lobbies.leave_spectate(&viewer()).is_err());
+    }
 }
@@ -0,0 +1,42 @@
+use config::social_server_admin_secret;
+use http_client::ResponseError;
+use http_server::{async_dup::Arc, executor::smol::lock::RwLock, ApiServer, Server};
+use logging::warn;
+
+use social_server_http_proto::{ModerationMuteUserRequest, ModerationMuteUserResponse};
+
+use crate::state::State;
+
+// admin tooling uses this to add or remove users from the mute list
+pub fn recv_moderation_mute_user_request(
+    host_name: &str,
+    server: &mut Server,
+    state: Arc<RwLock<State>>,
+) {
+    server.api_endpoint(host_name, None, move |_addr, req| {
+        let state = state.clone();
+        async move { async_recv_moderation_mute_user_request_impl(state, req).await }
+    });
+}
+
+async fn async_recv_moderation_mute_user_request_impl(
+    state: Arc<RwLock<State>>,
+    request: ModerationMuteUserRequest,
+) -> Result<ModerationMuteUserResponse, ResponseError> {
+    if request.admin_secret() != social_server_admin_secret() {
+        warn!("invalid request secret");
+        return Err(ResponseError::Unauthenticated);
+    }
+
+    let mut state = state.write().await;
+
+    if state
+        .moderation
+        .set_muted(&request.user_id(), request.muted())
+    {
+        // still under the lock, so that concurrent saves land in order
+        state.moderation.save_mute_list().await;
+    }
+
+    return Ok(ModerationMuteUserResponse);
+}
@@ -0,0 +1,190 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use auth_server_types::UserId;
+use http_server::executor::smol::unblock;
+use logging::{info, warn};
+
+use crate::friends::SOCIAL_DATA_DIR;
+
+pub struct ModerationConfig {
+    // words that are masked out of chat messages, matched case-insensitively
+    pub word_filter: Vec<String>,
+    // how many messages a single user may send within the rate limit window
+    pub rate_limit_count: usize,
+    pub rate_limit_window: Duration,
+    pub max_message_length: usize,
+}
+
+impl Default for ModerationConfig {
+    fn default() -> Self {
+        Self {
+            word_filter: Vec::new(),
+            rate_limit_count: 5,
+            rate_limit_window: Duration::from_secs(5),
+            max_message_length: 256,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ModerationRejection {
+    Empty,
+    Muted,
+    RateLimited,
+}
+
+pub struct ModerationState {
+    word_filter: HashSet<String>,
+    rate_limit_count: usize,
+    rate_limit_window: Duration,
+    max_message_length: usize,
+
+    recent_sends: HashMap<UserId, VecDeque<Instant>>,
+    muted_users: HashSet<UserId>,
+    mute_list_path: PathBuf,
+}
+
+impl ModerationState {
+    pub fn new(config: ModerationConfig) -> Self {
+        let mute_list_path = Path::new(SOCIAL_DATA_DIR).join("muted_users.txt");
+        let muted_users = load_mute_list(&mute_list_path);
+
+        Self {
+            word_filter: config
+                .word_filter
+                .iter()
+                .map(|word| word.to_lowercase())
+                .collect(),
+            rate_limit_count: config.rate_limit_count,
+            rate_limit_window: config.rate_limit_window,
+            max_message_length: config.max_message_length,
+
+            recent_sends: HashMap::new(),
+            muted_users,
+            mute_list_path,
+        }
+    }
+
+    // runs before a chat message is accepted, returns the message as it should be logged
+    pub fn moderate_message(
+        &mut self,
+        user_id: &UserId,
+        message: &str,
+    ) -> Result<String, ModerationRejection> {
+        let message = message.trim();
+        if message.is_empty() {
+            return Err(ModerationRejection::Empty);
+        }
+        if self.muted_users.contains(user_id) {
+            return Err(ModerationRejection::Muted);
+        }
+        if !self.record_send(user_id) {
+            return Err(ModerationRejection::RateLimited);
+        }
+
+        let message: String = message.chars().take(self.max_message_length).collect();
+        Ok(self.filter_words(&message))
+    }
+
+    // returns true if the mute list changed, and so should be saved
+    pub fn set_muted(&mut self, user_id: &UserId, muted: bool) -> bool {
+        let changed = if muted {
+            self.muted_users.insert(*user_id)
+        } else {
+            self.muted_users.remove(user_id)
+        };
+        if changed {
+            info!("user {:?} muted: {}", user_id, muted);
+        }
+        changed
+    }
+
+    // written off the executor's threads, as it is called from request handlers
+    pub async fn save_mute_list(&self) {
+        let path = self.mute_list_path.clone();
+        let contents: String = self
+            .muted_users
+            .iter()
+            .map(|user_id| {
+                let user_id: u64 = (*user_id).into();
+                format!("{}\n", user_id)
+            })
+            .collect();
+
+        let result = unblock({
+            let path = path.clone();
+            move || {
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(&path, contents)
+            }
+        })
+        .await;
+        if let Err(e) = result {
+            warn!("failed to save mute list {:?}: {:?}", path, e);
+        }
+    }
+
+    // returns false if the user is over the rate limit
+    fn record_send(&mut self, user_id: &UserId) -> bool {
+        let now = Instant::now();
+        let sends = self.recent_sends.entry(*user_id).or_insert(VecDeque::new());
+        while let Some(oldest) = sends.front() {
+            if now.duration_since(*oldest) < self.rate_limit_window {
+                break;
+            }
+            sends.pop_front();
+        }
+        if sends.len() >= self.rate_limit_count {
+            return false;
+        }
+        sends.push_back(now);
+        true
+    }
+
+    fn filter_words(&self, message: &str) -> String {
+        if self.word_filter.is_empty() {
+            return message.to_string();
+        }
+
+        let mut output = String::with_capacity(message.len());
+        let mut word = String::new();
+        for c in message.chars() {
+            if c.is_alphanumeric() {
+                word.push(c);
+            } else {
+                self.push_filtered_word(&mut output, &word);
+                word.clear();
+                output.push(c);
+            }
+        }
+        self.push_filtered_word(&mut output, &word);
+        output
+    }
+
+    fn push_filtered_word(&self, output: &mut String, word: &str) {
+        if self.word_filter.contains(&word.to_lowercase()) {
+            output.extend(word.chars().map(|_| '*'));
+        } else {
+            output.push_str(word);
+        }
+    }
+}
+
+// one user id per line
+fn load_mute_list(path: &Path) -> HashSet<UserId> {
+    let Ok(contents) = fs::read_to_string(path) else {
+        return HashSet::new();
+    };
+    contents
+        .lines()
+        .filter_map(|line| line.trim().parse::<u64>().ok())
+        .map(UserId::new)
+        .collect()
+}
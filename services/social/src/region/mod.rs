@@ -2,8 +2,10 @@ mod endpoints;
 mod processes;
 mod state;
 mod world_connect;
+mod world_spectate;
 
 pub use endpoints::*;
 pub use processes::*;
 pub use state::*;
 pub use world_connect::*;
+pub use world_spectate::*;
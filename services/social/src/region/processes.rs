@@ -1,8 +1,8 @@
 use std::{collections::HashMap, time::Duration};
 
 use config::{
-    REGION_SERVER_PORT, REGION_SERVER_RECV_ADDR, SOCIAL_SERVER_GLOBAL_SECRET, SOCIAL_SERVER_PORT,
-    SOCIAL_SERVER_RECV_ADDR,
+    region_server_port, region_server_recv_addr, social_server_global_secret, social_server_port,
+    social_server_recv_addr,
 };
 use http_client::HttpClient;
 use http_server::{
@@ -13,9 +13,14 @@ use http_server::{
 use logging::{info, warn};
 
 use region_server_http_proto::{SocialRegisterInstanceRequest, SocialRegisterInstanceResponse};
-use session_server_http_proto::SocialWorldConnectRequest;
+use session_server_http_proto::{SocialWorldConnectRequest, SocialWorldSpectateRequest};
 
-use crate::{region::send_world_connect_request, state::State};
+use crate::{
+    region::{
+        send_world_connect_request, send_world_spectate_leave_request, send_world_spectate_request,
+    },
+    state::State,
+};
 
 pub fn start_processes(state: Arc<RwLock<State>>) {
     let state_clone = state.clone();
@@ -24,6 +29,8 @@ pub fn start_processes(state: Arc<RwLock<State>>) {
             send_register_instance_request(state_clone.clone()).await;
             process_region_server_disconnect(state_clone.clone()).await;
             handle_world_connect(state_clone.clone()).await;
+            handle_world_spectate(state_clone.clone()).await;
+            handle_world_spectate_leave(state_clone.clone()).await;
             Timer::after(Duration::from_secs(1)).await;
         }
     });
@@ -40,30 +47,30 @@ async fn send_register_instance_request(state: Arc<RwLock<State>>) {
     }
 
     let request = SocialRegisterInstanceRequest::new(
-        SOCIAL_SERVER_GLOBAL_SECRET,
-        SOCIAL_SERVER_RECV_ADDR,
-        SOCIAL_SERVER_PORT,
+        social_server_global_secret(),
+        social_server_recv_addr(),
+        social_server_port(),
     );
 
     let host = "social";
     let remote = "region";
     http_server::log_util::send_req(host, remote, SocialRegisterInstanceRequest::name());
-    let response = HttpClient::send(REGION_SERVER_RECV_ADDR, REGION_SERVER_PORT, request).await;
+    let response = HttpClient::send(region_server_recv_addr(), region_server_port(), request).await;
     http_server::log_util::recv_res(host, remote, SocialRegisterInstanceResponse::name());
 
     match response {
         Ok(_) => {
             // info!(
             //     "from {:?}:{} - social server registration success",
-            //     REGION_SERVER_RECV_ADDR, REGION_SERVER_PORT
+            //     region_server_recv_addr(), region_server_port()
             // );
             state.set_connected();
         }
         Err(err) => {
             warn!(
                 "from {:?}:{} - social server registration failure: {}",
-                REGION_SERVER_RECV_ADDR,
-                REGION_SERVER_PORT,
+                region_server_recv_addr(),
+                region_server_port(),
                 err.to_string()
             );
         }
@@ -131,6 +138,9 @@ async fn handle_world_connect(state: Arc<RwLock<State>>) {
                 return;
             }
         };
+        state
+            .match_lobbies
+            .set_world_instance_secret(&starting_lobby_id, &world_server_instance_secret);
 
         let mut session_servers = HashMap::new();
         for (user_id, login_token) in login_tokens {
@@ -157,7 +167,7 @@ async fn handle_world_connect(state: Arc<RwLock<State>>) {
                 .unwrap();
 
             let request = SocialWorldConnectRequest::new(
-                SOCIAL_SERVER_GLOBAL_SECRET,
+                social_server_global_secret(),
                 &world_server_instance_secret,
                 starting_lobby_id,
                 outgoing_message,
@@ -179,3 +189,104 @@ async fn handle_world_connect(state: Arc<RwLock<State>>) {
         }
     }
 }
+
+async fn handle_world_spectate(state: Arc<RwLock<State>>) {
+    let state = &mut state.write().await;
+
+    let starting_spectators = state.match_lobbies.take_starting_spectators();
+    for (lobby_id, user_id) in starting_spectators {
+        if !state.match_lobbies.is_spectating(&lobby_id, &user_id) {
+            // the user stopped spectating in the meantime
+            continue;
+        }
+        let Some(world_instance_secret) = state.match_lobbies.get_world_instance_secret(&lobby_id)
+        else {
+            // the match ended in the meantime
+            continue;
+        };
+        let Some(session_server_id) = state.users.find_user_session_server_id(&user_id) else {
+            // the user went offline in the meantime
+            continue;
+        };
+        let session_instance_secret = state
+            .session_servers
+            .get_session_instance_secret(&session_server_id)
+            .unwrap()
+            .to_string();
+
+        // get a token from the world server running the match, via region server
+        let login_token = match send_world_spectate_request(
+            &mut state.region_server,
+            &world_instance_secret,
+            lobby_id,
+            &session_instance_secret,
+            user_id,
+        )
+        .await
+        {
+            Ok(login_token) => login_token,
+            Err(err) => {
+                warn!(
+                    "failed to send world spectate request: {:?}",
+                    err.to_string()
+                );
+                state.match_lobbies.remove_spectator(&user_id);
+                continue;
+            }
+        };
+
+        let (recv_addr, recv_port) = state
+            .session_servers
+            .get_recv_addr(session_server_id)
+            .unwrap();
+
+        let request = SocialWorldSpectateRequest::new(
+            social_server_global_secret(),
+            &world_instance_secret,
+            lobby_id,
+            user_id,
+            &login_token,
+        );
+        let response = HttpClient::send(recv_addr, recv_port, request).await;
+        match response {
+            Ok(_) => {
+                info!("from {:?}:{} - world spectate sent", recv_addr, recv_port);
+            }
+            Err(e) => {
+                warn!(
+                    "from {:?}:{} - world spectate send failed: {:?}",
+                    recv_addr,
+                    recv_port,
+                    e.to_string()
+                );
+            }
+        }
+    }
+}
+
+async fn handle_world_spectate_leave(state: Arc<RwLock<State>>) {
+    let state = &mut state.write().await;
+
+    let leaving_spectators = state.match_lobbies.take_leaving_spectators();
+    for (lobby_id, user_id) in leaving_spectators {
+        let Some(world_instance_secret) = state.match_lobbies.get_world_instance_secret(&lobby_id)
+        else {
+            // the match ended in the meantime, which sent the spectator home already
+            continue;
+        };
+
+        if let Err(err) = send_world_spectate_leave_request(
+            &mut state.region_server,
+            &world_instance_secret,
+            lobby_id,
+            user_id,
+        )
+        .await
+        {
+            warn!(
+                "failed to send world spectate leave request: {:?}",
+                err.to_string()
+            );
+        }
+    }
+}
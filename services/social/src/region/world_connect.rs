@@ -1,5 +1,5 @@
 use auth_server_types::UserId;
-use config::{REGION_SERVER_PORT, REGION_SERVER_RECV_ADDR, SOCIAL_SERVER_GLOBAL_SECRET};
+use config::{region_server_port, region_server_recv_addr, social_server_global_secret};
 use http_client::{HttpClient, ResponseError};
 use http_server::{ApiRequest, ApiResponse};
 use region_server_http_proto::{WorldConnectRequest, WorldConnectResponse};
@@ -19,13 +19,13 @@ pub async fn send_world_connect_request(
         ));
     }
 
-    let request = WorldConnectRequest::new(SOCIAL_SERVER_GLOBAL_SECRET, lobby_id, user_ids);
+    let request = WorldConnectRequest::new(social_server_global_secret(), lobby_id, user_ids);
 
     let host = "social";
     let remote = "region";
     http_server::log_util::send_req(host, remote, WorldConnectRequest::name());
     let response_result =
-        HttpClient::send(REGION_SERVER_RECV_ADDR, REGION_SERVER_PORT, request).await;
+        HttpClient::send(region_server_recv_addr(), region_server_port(), request).await;
     http_server::log_util::recv_res(host, remote, WorldConnectResponse::name());
     region_server_state.sent_to_region_server();
 
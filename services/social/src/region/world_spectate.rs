@@ -0,0 +1,80 @@
+use auth_server_types::UserId;
+use config::{region_server_port, region_server_recv_addr, social_server_global_secret};
+use http_client::{HttpClient, ResponseError};
+use http_server::{ApiRequest, ApiResponse};
+use region_server_http_proto::{
+    WorldSpectateLeaveRequest, WorldSpectateLeaveResponse, WorldSpectateRequest,
+    WorldSpectateResponse,
+};
+use social_server_types::LobbyId;
+
+use crate::region::RegionServerState;
+
+// returns the spectator's login token for the world server
+pub async fn send_world_spectate_request(
+    region_server_state: &mut RegionServerState,
+    world_instance_secret: &str,
+    lobby_id: LobbyId,
+    session_instance_secret: &str,
+    user_id: UserId,
+) -> Result<String, ResponseError> {
+    if !region_server_state.connected() {
+        return Err(ResponseError::NetworkError(
+            "region server not connected".to_string(),
+        ));
+    }
+
+    let request = WorldSpectateRequest::new(
+        social_server_global_secret(),
+        world_instance_secret,
+        lobby_id,
+        session_instance_secret,
+        user_id,
+    );
+
+    let host = "social";
+    let remote = "region";
+    http_server::log_util::send_req(host, remote, WorldSpectateRequest::name());
+    let response_result =
+        HttpClient::send(region_server_recv_addr(), region_server_port(), request).await;
+    http_server::log_util::recv_res(host, remote, WorldSpectateResponse::name());
+    region_server_state.sent_to_region_server();
+
+    let response = response_result?;
+    region_server_state.heard_from_region_server();
+
+    Ok(response.login_token)
+}
+
+pub async fn send_world_spectate_leave_request(
+    region_server_state: &mut RegionServerState,
+    world_instance_secret: &str,
+    lobby_id: LobbyId,
+    user_id: UserId,
+) -> Result<(), ResponseError> {
+    if !region_server_state.connected() {
+        return Err(ResponseError::NetworkError(
+            "region server not connected".to_string(),
+        ));
+    }
+
+    let request = WorldSpectateLeaveRequest::new(
+        social_server_global_secret(),
+        world_instance_secret,
+        lobby_id,
+        user_id,
+    );
+
+    let host = "social";
+    let remote = "region";
+    http_server::log_util::send_req(host, remote, WorldSpectateLeaveRequest::name());
+    let response_result =
+        HttpClient::send(region_server_recv_addr(), region_server_port(), request).await;
+    http_server::log_util::recv_res(host, remote, WorldSpectateLeaveResponse::name());
+    region_server_state.sent_to_region_server();
+
+    response_result?;
+    region_server_state.heard_from_region_server();
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use auth_server_types::UserId;
-use config::REGION_SERVER_SECRET;
+use config::region_server_secret;
 use http_client::ResponseError;
 use http_server::{async_dup::Arc, executor::smol::lock::RwLock, ApiServer, Server};
 use logging::{info, warn};
@@ -8,7 +8,6 @@ use social_server_http_proto::{
     ConnectSessionServerRequest, ConnectSessionServerResponse, DisconnectSessionServerRequest,
     DisconnectSessionServerResponse,
 };
-use social_server_types::{MessageId, Timestamp};
 
 use crate::state::State;
 
@@ -27,7 +26,7 @@ async fn async_recv_connect_session_server_request_impl(
     state: Arc<RwLock<State>>,
     request: ConnectSessionServerRequest,
 ) -> Result<ConnectSessionServerResponse, ResponseError> {
-    if request.region_secret() != REGION_SERVER_SECRET {
+    if request.region_secret() != region_server_secret() {
         warn!("invalid request secret");
         return Err(ResponseError::Unauthenticated);
     }
@@ -47,13 +46,8 @@ async fn async_recv_connect_session_server_request_impl(
         .map(|u| u.clone())
         .collect();
 
-    // get full chat log
-    let messages: Vec<(MessageId, Timestamp, UserId, String)> = state
-        .global_chat
-        .get_full_log()
-        .iter()
-        .map(|m| m.clone())
-        .collect();
+    // get recent chat log
+    let messages = state.global_chat.get_recent_log();
 
     // get match lobbies
     let match_lobbies = state.match_lobbies.get_lobbies();
@@ -91,7 +85,7 @@ async fn async_recv_disconnect_session_server_request_impl(
     state: Arc<RwLock<State>>,
     request: DisconnectSessionServerRequest,
 ) -> Result<DisconnectSessionServerResponse, ResponseError> {
-    if request.region_secret() != REGION_SERVER_SECRET {
+    if request.region_secret() != region_server_secret() {
         warn!("invalid request secret");
         return Err(ResponseError::Unauthenticated);
     }
@@ -1,6 +1,7 @@
 use std::{collections::HashMap, time::Duration};
 
-use config::SOCIAL_SERVER_GLOBAL_SECRET;
+use auth_server_types::UserId;
+use config::social_server_global_secret;
 use http_client::HttpClient;
 use http_server::{
     async_dup::Arc,
@@ -9,12 +10,15 @@ use http_server::{
 };
 use logging::{info, warn};
 use session_server_http_proto::{
-    SocialLobbyPatch, SocialPatchGlobalChatMessagesRequest, SocialPatchMatchLobbiesRequest,
-    SocialPatchUsersRequest, SocialUserPatch,
+    SocialDirectMessagePatch, SocialFriendPatch, SocialLobbyPatch,
+    SocialPatchDirectMessagesRequest, SocialPatchFriendsRequest,
+    SocialPatchGlobalChatMessagesRequest, SocialPatchMatchLobbiesRequest, SocialPatchUsersRequest,
+    SocialUserPatch,
 };
 
 use crate::{
-    match_lobbies::LobbyPatch, session_servers::SessionServerId, state::State, users::UserPatch,
+    direct_messages::DirectMessagePatch, friends::FriendPatch, match_lobbies::LobbyPatch,
+    session_servers::SessionServerId, state::State, users::UserPatch,
 };
 
 pub fn start_processes(state: Arc<RwLock<State>>) {
@@ -25,6 +29,8 @@ pub fn start_processes(state: Arc<RwLock<State>>) {
         loop {
             let state = &mut state_clone_1.write().await;
             handle_user_patches(state).await;
+            handle_friend_patches(state).await;
+            handle_direct_message_patches(state).await;
             handle_global_chat_patches(state).await;
             handle_match_lobby_patches(state).await;
             Timer::after(Duration::from_secs(1)).await;
@@ -71,7 +77,7 @@ async fn handle_user_patches(state: &mut State) {
             .get_recv_addr(receiving_session_server_id)
             .unwrap();
 
-        let request = SocialPatchUsersRequest::new(SOCIAL_SERVER_GLOBAL_SECRET, user_patches);
+        let request = SocialPatchUsersRequest::new(social_server_global_secret(), user_patches);
         let response = HttpClient::send(recv_addr, recv_port, request).await;
         match response {
             Ok(_) => {
@@ -89,6 +95,189 @@ async fn handle_user_patches(state: &mut State) {
     }
 }
 
+// the originals are kept so that a failed send can be re-queued
+#[derive(Default)]
+struct QueuedFriendPatches {
+    social_patches: Vec<SocialFriendPatch>,
+    presence_changes: Vec<UserId>,
+    friend_patches: Vec<(UserId, FriendPatch)>,
+}
+
+async fn handle_friend_patches(state: &mut State) {
+    let mut queued_friend_patches: HashMap<SessionServerId, QueuedFriendPatches> = HashMap::new();
+
+    // presence changes only go out to the session servers of the user's online friends
+    let mut changed_user_ids = state.users.take_presence_changes();
+    changed_user_ids.sort();
+    changed_user_ids.dedup();
+    for changed_user_id in changed_user_ids {
+        let presence = state.users.get_presence(&changed_user_id);
+        for friend_id in state.friends.get_friends(&changed_user_id) {
+            let Some(receiving_session_server_id) =
+                state.users.find_user_session_server_id(&friend_id)
+            else {
+                continue;
+            };
+            let queued = queued_friend_patches
+                .entry(receiving_session_server_id)
+                .or_default();
+            queued.social_patches.push(SocialFriendPatch::Update(
+                friend_id,
+                changed_user_id,
+                presence,
+            ));
+            if !queued.presence_changes.contains(&changed_user_id) {
+                queued.presence_changes.push(changed_user_id);
+            }
+        }
+    }
+
+    for (receiving_user_id, friend_patch) in state.friends.take_patches() {
+        let Some(receiving_session_server_id) =
+            state.users.find_user_session_server_id(&receiving_user_id)
+        else {
+            // offline users get their friend list when they next connect
+            continue;
+        };
+        let social_friend_patch = match &friend_patch {
+            FriendPatch::Added(friend_id) => SocialFriendPatch::Update(
+                receiving_user_id,
+                *friend_id,
+                state.users.get_presence(friend_id),
+            ),
+            FriendPatch::Removed(friend_id) => {
+                SocialFriendPatch::Remove(receiving_user_id, *friend_id)
+            }
+            FriendPatch::RequestReceived(sender_id) => {
+                SocialFriendPatch::RequestReceived(receiving_user_id, *sender_id)
+            }
+            FriendPatch::RequestRemoved(sender_id) => {
+                SocialFriendPatch::RequestRemoved(receiving_user_id, *sender_id)
+            }
+        };
+        let queued = queued_friend_patches
+            .entry(receiving_session_server_id)
+            .or_default();
+        queued.social_patches.push(social_friend_patch);
+        queued
+            .friend_patches
+            .push((receiving_user_id, friend_patch));
+    }
+
+    for (receiving_session_server_id, queued) in queued_friend_patches {
+        let (recv_addr, recv_port) = state
+            .session_servers
+            .get_recv_addr(receiving_session_server_id)
+            .unwrap();
+
+        let request =
+            SocialPatchFriendsRequest::new(social_server_global_secret(), queued.social_patches);
+        let response = HttpClient::send(recv_addr, recv_port, request).await;
+        match response {
+            Ok(_) => {
+                info!("from {:?}:{} - friend patches sent", recv_addr, recv_port);
+            }
+            Err(e) => {
+                warn!(
+                    "from {:?}:{} - friend patches send failed: {:?}",
+                    recv_addr,
+                    recv_port,
+                    e.to_string()
+                );
+
+                // try again on the next pass, receivers who have gone offline by then are
+                // dropped and get a fresh friend list when they reconnect
+                state
+                    .users
+                    .requeue_presence_changes(queued.presence_changes);
+                state.friends.requeue_patches(queued.friend_patches);
+            }
+        }
+    }
+}
+
+async fn handle_direct_message_patches(state: &mut State) {
+    let mut queued_social_dm_patches: HashMap<SessionServerId, Vec<SocialDirectMessagePatch>> =
+        HashMap::new();
+
+    for (receiving_user_id, dm_patch) in state.direct_messages.take_patches() {
+        let Some(receiving_session_server_id) =
+            state.users.find_user_session_server_id(&receiving_user_id)
+        else {
+            // the recipient went offline since the message was sent, keep it for their next login
+            if let DirectMessagePatch::Message(sender_id, timestamp, message) = dm_patch {
+                state.direct_messages.store_pending_message(
+                    &receiving_user_id,
+                    &sender_id,
+                    timestamp,
+                    &message,
+                );
+            }
+            continue;
+        };
+        let social_dm_patch = match dm_patch {
+            DirectMessagePatch::Message(sender_id, timestamp, message) => {
+                SocialDirectMessagePatch::Message(receiving_user_id, sender_id, timestamp, message)
+            }
+            DirectMessagePatch::Block(other_user_id, blocked) => {
+                SocialDirectMessagePatch::Block(receiving_user_id, other_user_id, blocked)
+            }
+        };
+        queued_social_dm_patches
+            .entry(receiving_session_server_id)
+            .or_insert(Vec::new())
+            .push(social_dm_patch);
+    }
+
+    for (receiving_session_server_id, dm_patches) in queued_social_dm_patches {
+        let (recv_addr, recv_port) = state
+            .session_servers
+            .get_recv_addr(receiving_session_server_id)
+            .unwrap();
+
+        let request = SocialPatchDirectMessagesRequest::new(
+            social_server_global_secret(),
+            dm_patches.clone(),
+        );
+        let response = HttpClient::send(recv_addr, recv_port, request).await;
+        match response {
+            Ok(_) => {
+                info!(
+                    "from {:?}:{} - direct message patches sent",
+                    recv_addr, recv_port
+                );
+            }
+            Err(e) => {
+                warn!(
+                    "from {:?}:{} - direct message patches send failed: {:?}",
+                    recv_addr,
+                    recv_port,
+                    e.to_string()
+                );
+
+                // undelivered messages wait in the recipients' inboxes for their next login,
+                // block lists are resent with the snapshot then too
+                for dm_patch in dm_patches {
+                    if let SocialDirectMessagePatch::Message(
+                        receiving_user_id,
+                        sender_id,
+                        timestamp,
+                        message,
+                    ) = dm_patch
+                    {
+                        state.direct_messages.store_pending_message(
+                            &receiving_user_id,
+                            &sender_id,
+                            timestamp,
+                            &message,
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
 async fn handle_global_chat_patches(state: &mut State) {
     let global_chat_patches = state.global_chat.take_patches();
     for (sending_session_server_id, messages) in global_chat_patches {
@@ -103,7 +292,7 @@ async fn handle_global_chat_patches(state: &mut State) {
                 .unwrap();
 
             let request = SocialPatchGlobalChatMessagesRequest::new(
-                SOCIAL_SERVER_GLOBAL_SECRET,
+                social_server_global_secret(),
                 messages.clone(),
             );
             let response = HttpClient::send(recv_addr, recv_port, request).await;
@@ -131,7 +320,7 @@ async fn handle_match_lobby_patches(state: &mut State) {
     let match_lobby_patches = state.match_lobbies.take_patches();
     for (sending_session_server_id, patches) in match_lobby_patches {
         for receiving_session_server_id in state.session_servers.all_session_ids() {
-            if sending_session_server_id == receiving_session_server_id {
+            if sending_session_server_id == Some(receiving_session_server_id) {
                 continue;
             }
 
@@ -143,17 +332,25 @@ async fn handle_match_lobby_patches(state: &mut State) {
             let patches = patches
                 .iter()
                 .map(|patch| match patch {
-                    LobbyPatch::Create(lobby_id, creator_user_id, match_name) => {
+                    LobbyPatch::Create(lobby_id, creator_user_id, match_name, settings) => {
                         SocialLobbyPatch::Create(
                             lobby_id.clone(),
                             match_name.clone(),
                             creator_user_id.clone(),
+                            settings.clone(),
                         )
                     }
                     LobbyPatch::Join(lobby_id, user_id) => {
                         SocialLobbyPatch::Join(lobby_id.clone(), user_id.clone())
                     }
                     LobbyPatch::Leave(user_id) => SocialLobbyPatch::Leave(user_id.clone()),
+                    LobbyPatch::Kick(user_id) => SocialLobbyPatch::Kick(user_id.clone()),
+                    LobbyPatch::Invite(lobby_id, user_id) => {
+                        SocialLobbyPatch::Invite(lobby_id.clone(), user_id.clone())
+                    }
+                    LobbyPatch::OwnerChange(lobby_id, user_id) => {
+                        SocialLobbyPatch::OwnerChange(lobby_id.clone(), user_id.clone())
+                    }
                     LobbyPatch::Message(message_id, timestamp, user_id, message) => {
                         SocialLobbyPatch::Message(
                             message_id.clone(),
@@ -163,10 +360,14 @@ async fn handle_match_lobby_patches(state: &mut State) {
                         )
                     }
                     LobbyPatch::Start(lobby_id) => SocialLobbyPatch::Start(lobby_id.clone()),
+                    LobbyPatch::End(lobby_id, outcome, results) => {
+                        SocialLobbyPatch::End(lobby_id.clone(), *outcome, results.clone())
+                    }
                 })
                 .collect();
 
-            let request = SocialPatchMatchLobbiesRequest::new(SOCIAL_SERVER_GLOBAL_SECRET, patches);
+            let request =
+                SocialPatchMatchLobbiesRequest::new(social_server_global_secret(), patches);
             let response = HttpClient::send(recv_addr, recv_port, request).await;
             match response {
                 Ok(_) => {
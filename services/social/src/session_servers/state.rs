@@ -1,14 +1,14 @@
 use std::collections::{HashMap, HashSet};
 
 use auth_server_types::UserId;
-use config::SOCIAL_SERVER_GLOBAL_SECRET;
+use config::social_server_global_secret;
 use http_client::HttpClient;
 use logging::{info, warn};
 use session_server_http_proto::{
     SocialLobbyPatch, SocialPatchGlobalChatMessagesRequest, SocialPatchMatchLobbiesRequest,
     SocialPatchUsersRequest, SocialUserPatch,
 };
-use social_server_types::{LobbyId, MessageId, Timestamp};
+use social_server_types::{LobbyId, LobbySettings, MatchOutcome, MessageId, Timestamp};
 
 use crate::match_lobbies::LobbyState;
 
@@ -18,7 +18,7 @@ pub struct SessionServerId {
 }
 
 impl SessionServerId {
-    fn new(val: u64) -> Self {
+    pub(crate) fn new(val: u64) -> Self {
         Self { val }
     }
 }
@@ -81,7 +81,14 @@ impl SessionServersState {
         recv_port: u16,
         present_users: Vec<UserId>,
         global_chat_full_log: Vec<(MessageId, Timestamp, UserId, String)>,
-        match_lobbies: Vec<(LobbyId, UserId, String, Vec<UserId>, LobbyState)>,
+        match_lobbies: Vec<(
+            LobbyId,
+            UserId,
+            String,
+            LobbySettings,
+            Vec<UserId>,
+            LobbyState,
+        )>,
     ) {
         let id = self.next_session_id();
         self.instances.insert(
@@ -99,7 +106,7 @@ impl SessionServersState {
                 .iter()
                 .map(|user_id| SocialUserPatch::Add(*user_id))
                 .collect();
-            let request = SocialPatchUsersRequest::new(SOCIAL_SERVER_GLOBAL_SECRET, user_patches);
+            let request = SocialPatchUsersRequest::new(social_server_global_secret(), user_patches);
             let response = HttpClient::send(recv_addr, recv_port, request).await;
             match response {
                 Ok(_) => {
@@ -122,7 +129,7 @@ impl SessionServersState {
         // sync global chat
         {
             let request = SocialPatchGlobalChatMessagesRequest::new(
-                SOCIAL_SERVER_GLOBAL_SECRET,
+                social_server_global_secret(),
                 global_chat_full_log,
             );
             let response = HttpClient::send(recv_addr, recv_port, request).await;
@@ -148,21 +155,41 @@ impl SessionServersState {
         {
             let mut patches = Vec::new();
 
-            for (lobby_id, owner_user_id, match_name, member_ids, state) in &match_lobbies {
+            for (lobby_id, owner_user_id, match_name, settings, member_ids, state) in &match_lobbies
+            {
                 patches.push(SocialLobbyPatch::Create(
                     *lobby_id,
                     match_name.clone(),
                     *owner_user_id,
+                    *settings,
                 ));
                 for member_id in member_ids {
                     patches.push(SocialLobbyPatch::Join(*lobby_id, *member_id));
                 }
-                if let LobbyState::InProgress = state {
-                    patches.push(SocialLobbyPatch::Start(*lobby_id));
+                match state {
+                    LobbyState::WaitingToStart => {}
+                    LobbyState::InProgress => {
+                        patches.push(SocialLobbyPatch::Start(*lobby_id));
+                    }
+                    LobbyState::Finished => {
+                        patches.push(SocialLobbyPatch::End(
+                            *lobby_id,
+                            MatchOutcome::Finished,
+                            Vec::new(),
+                        ));
+                    }
+                    LobbyState::Abandoned => {
+                        patches.push(SocialLobbyPatch::End(
+                            *lobby_id,
+                            MatchOutcome::Abandoned,
+                            Vec::new(),
+                        ));
+                    }
                 }
             }
 
-            let request = SocialPatchMatchLobbiesRequest::new(SOCIAL_SERVER_GLOBAL_SECRET, patches);
+            let request =
+                SocialPatchMatchLobbiesRequest::new(social_server_global_secret(), patches);
             let response = HttpClient::send(recv_addr, recv_port, request).await;
             match response {
                 Ok(_) => {
@@ -1,22 +1,34 @@
 use std::time::Duration;
 
 use crate::{
-    global_chat::GlobalChatState, match_lobbies::MatchLobbiesState, region::RegionServerState,
-    session_servers::SessionServersState, users::UsersState,
+    direct_messages::DirectMessagesState,
+    friends::FriendsState,
+    global_chat::GlobalChatState,
+    match_history::MatchHistoryState,
+    match_lobbies::MatchLobbiesState,
+    moderation::{ModerationConfig, ModerationState},
+    region::RegionServerState,
+    session_servers::SessionServersState,
+    users::UsersState,
 };
 
 pub struct State {
     pub region_server: RegionServerState,
     pub session_servers: SessionServersState,
     pub match_lobbies: MatchLobbiesState,
+    pub match_history: MatchHistoryState,
     pub users: UsersState,
+    pub friends: FriendsState,
+    pub direct_messages: DirectMessagesState,
     pub global_chat: GlobalChatState,
+    pub moderation: ModerationState,
 }
 
 impl State {
     pub fn new(
         registration_resend_rate: Duration,
         region_server_disconnect_timeout: Duration,
+        moderation_config: ModerationConfig,
     ) -> Self {
         Self {
             region_server: RegionServerState::new(
@@ -25,8 +37,12 @@ impl State {
             ),
             session_servers: SessionServersState::new(),
             match_lobbies: MatchLobbiesState::new(),
+            match_history: MatchHistoryState::new(),
             users: UsersState::new(),
+            friends: FriendsState::new(),
+            direct_messages: DirectMessagesState::new(),
             global_chat: GlobalChatState::new(),
+            moderation: ModerationState::new(moderation_config),
         }
     }
 }
@@ -1,11 +1,11 @@
-use config::REGION_SERVER_SECRET;
+use config::region_server_secret;
 use http_client::ResponseError;
 use http_server::{async_dup::Arc, executor::smol::lock::RwLock, ApiServer, Server};
 use logging::warn;
 
 use social_server_http_proto::{
     UserConnectedRequest, UserConnectedResponse, UserDisconnectedRequest, UserDisconnectedResponse,
-    UserIsOnlineRequest, UserIsOnlineResponse,
+    UserIsOnlineRequest, UserIsOnlineResponse, UserSetAwayRequest, UserSetAwayResponse,
 };
 
 use crate::state::State;
@@ -27,7 +27,7 @@ async fn async_recv_user_connected_request_impl(
     state: Arc<RwLock<State>>,
     request: UserConnectedRequest,
 ) -> Result<UserConnectedResponse, ResponseError> {
-    if request.region_secret() != REGION_SERVER_SECRET {
+    if request.region_secret() != region_server_secret() {
         warn!("invalid request secret");
         return Err(ResponseError::Unauthenticated);
     }
@@ -40,8 +40,18 @@ async fn async_recv_user_connected_request_impl(
     // setting last heard
     state.region_server.heard_from_region_server();
 
-    if state.users.is_user_online(&user_id) {
-        return Ok(UserConnectedResponse::already_connected());
+    if let Some(session_server_id) = state.users.find_user_session_server_id(&user_id) {
+        // the user may be reconnecting to a session server still holding them, which
+        // dropped any patches for them in the meantime
+        state.friends.send_snapshot(&user_id);
+        state.direct_messages.send_snapshot(&user_id);
+
+        let session_instance_secret = state
+            .session_servers
+            .get_session_instance_secret(&session_server_id);
+        return Ok(UserConnectedResponse::already_connected(
+            session_instance_secret,
+        ));
     }
 
     let Some(session_server_id) = state
@@ -52,10 +62,19 @@ async fn async_recv_user_connected_request_impl(
         return Err(ResponseError::Unauthenticated);
     };
     state.users.connect_user(&user_id, &session_server_id);
+    // friend requests may only be sent to users that have connected before
+    if state.users.add_known_user(&user_id) {
+        state.users.save_known_users().await;
+    }
     state
         .session_servers
         .session_server_user_connect(&session_server_id, &user_id);
 
+    // the user's session server needs their friend list & pending requests
+    state.friends.send_snapshot(&user_id);
+    // ... and their block list & any direct messages received while offline
+    state.direct_messages.send_snapshot(&user_id);
+
     // responding
     return Ok(UserConnectedResponse::success());
 }
@@ -90,6 +109,7 @@ async fn async_recv_user_disconnected_request_impl(
     state
         .users
         .disconnect_user(session_server_id, request.user_id());
+    state.match_lobbies.remove_spectator(&request.user_id());
     state
         .session_servers
         .session_server_user_disconnect(&session_server_id, &request.user_id());
@@ -126,3 +146,35 @@ async fn async_recv_user_is_online_request_impl(
         return Ok(UserIsOnlineResponse::offline());
     }
 }
+
+// User Set Away
+
+pub fn recv_user_set_away_request(host_name: &str, server: &mut Server, state: Arc<RwLock<State>>) {
+    server.api_endpoint(host_name, None, move |_addr, req| {
+        let state = state.clone();
+        async move { async_recv_user_set_away_request_impl(state, req).await }
+    });
+}
+
+async fn async_recv_user_set_away_request_impl(
+    state: Arc<RwLock<State>>,
+    request: UserSetAwayRequest,
+) -> Result<UserSetAwayResponse, ResponseError> {
+    let mut state = state.write().await;
+
+    if state
+        .session_servers
+        .get_session_server_id(&request.session_instance_secret())
+        .is_none()
+    {
+        warn!("invalid request instance secret");
+        return Err(ResponseError::Unauthenticated);
+    }
+
+    state
+        .users
+        .user_set_away(&request.user_id(), request.away());
+
+    // responding
+    return Ok(UserSetAwayResponse);
+}
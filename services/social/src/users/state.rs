@@ -1,9 +1,15 @@
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+};
 
 use auth_server_types::UserId;
-use social_server_types::LobbyId;
+use http_server::executor::smol::unblock;
+use logging::warn;
+use social_server_types::{LobbyId, PresenceStatus, UserPresence};
 
-use crate::session_servers::SessionServerId;
+use crate::{friends::SOCIAL_DATA_DIR, session_servers::SessionServerId};
 
 pub(crate) enum UserPatch {
     Add(UserId),
@@ -13,6 +19,8 @@ pub(crate) enum UserPatch {
 struct UserData {
     session_server_id: SessionServerId,
     lobby_id: Option<LobbyId>,
+    in_match: bool,
+    away: bool,
 }
 
 impl UserData {
@@ -20,22 +28,48 @@ impl UserData {
         Self {
             session_server_id: *session_server_id,
             lobby_id: None,
+            in_match: false,
+            away: false,
         }
     }
+
+    pub fn presence(&self) -> UserPresence {
+        if self.in_match {
+            return UserPresence::new(PresenceStatus::InMatch, self.lobby_id);
+        }
+        if self.lobby_id.is_some() {
+            return UserPresence::new(PresenceStatus::InLobby, self.lobby_id);
+        }
+        if self.away {
+            return UserPresence::new(PresenceStatus::Away, None);
+        }
+        UserPresence::new(PresenceStatus::Online, None)
+    }
 }
 
 pub struct UsersState {
     users: HashMap<UserId, UserData>,
+    // every user that has ever connected, online or not
+    known_users: HashSet<UserId>,
+    known_users_path: PathBuf,
 
     // the session server id here is the SENDER not the RECEIVER
     outgoing_patches: Vec<UserPatch>,
+    // users whose presence changed since the last patch, these only go out to friends
+    presence_changes: Vec<UserId>,
 }
 
 impl UsersState {
     pub fn new() -> Self {
+        let known_users_path = Path::new(SOCIAL_DATA_DIR).join("known_users.txt");
+        let known_users = load_known_users(&known_users_path);
+
         Self {
             users: HashMap::new(),
+            known_users,
+            known_users_path,
             outgoing_patches: Vec::new(),
+            presence_changes: Vec::new(),
         }
     }
 
@@ -43,11 +77,48 @@ impl UsersState {
         self.users.contains_key(user_id)
     }
 
+    pub fn is_known_user(&self, user_id: &UserId) -> bool {
+        self.known_users.contains(user_id)
+    }
+
+    // returns true if the user hasn't been seen before
+    pub fn add_known_user(&mut self, user_id: &UserId) -> bool {
+        self.known_users.insert(*user_id)
+    }
+
+    // awaited under the state lock, so the file write runs on the blocking pool
+    pub async fn save_known_users(&self) {
+        let path = self.known_users_path.clone();
+        let contents: String = self
+            .known_users
+            .iter()
+            .map(|user_id| {
+                let user_id: u64 = (*user_id).into();
+                format!("{}\n", user_id)
+            })
+            .collect();
+
+        let result = unblock({
+            let path = path.clone();
+            move || {
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(&path, contents)
+            }
+        })
+        .await;
+        if let Err(e) = result {
+            warn!("failed to save known users {:?}: {:?}", path, e);
+        }
+    }
+
     pub fn connect_user(&mut self, user_id: &UserId, session_server_id: &SessionServerId) {
         self.users
             .insert(*user_id, UserData::new(session_server_id));
 
         self.outgoing_patches.push(UserPatch::Add(*user_id));
+        self.presence_changes.push(*user_id);
     }
 
     pub fn disconnect_user(&mut self, sending_session_server_id: SessionServerId, user_id: UserId) {
@@ -55,6 +126,7 @@ impl UsersState {
 
         self.outgoing_patches
             .push(UserPatch::Remove(sending_session_server_id, user_id));
+        self.presence_changes.push(user_id);
     }
 
     pub fn get_online_users(&self) -> Vec<UserId> {
@@ -65,6 +137,21 @@ impl UsersState {
         std::mem::take(&mut self.outgoing_patches)
     }
 
+    pub fn take_presence_changes(&mut self) -> Vec<UserId> {
+        std::mem::take(&mut self.presence_changes)
+    }
+
+    pub(crate) fn requeue_presence_changes(&mut self, user_ids: Vec<UserId>) {
+        self.presence_changes.extend(user_ids);
+    }
+
+    pub fn get_presence(&self, user_id: &UserId) -> UserPresence {
+        match self.users.get(user_id) {
+            Some(user_data) => user_data.presence(),
+            None => UserPresence::offline(),
+        }
+    }
+
     pub fn get_user_lobby_id(&self, user_id: &UserId) -> Option<LobbyId> {
         let user_data = self.users.get(user_id)?;
         user_data.lobby_id
@@ -74,15 +161,60 @@ impl UsersState {
         self.users.get(user_id).unwrap().session_server_id
     }
 
+    // returns None if the user is offline
+    pub fn find_user_session_server_id(&self, user_id: &UserId) -> Option<SessionServerId> {
+        self.users
+            .get(user_id)
+            .map(|user_data| user_data.session_server_id)
+    }
+
     pub fn user_joins_lobby(&mut self, user_id: &UserId, lobby_id: &LobbyId) {
         let user_data = self.users.get_mut(user_id).unwrap();
         user_data.lobby_id = Some(*lobby_id);
+        self.presence_changes.push(*user_id);
     }
 
     pub fn user_leaves_lobby(&mut self, user_id: &UserId) -> LobbyId {
         let user_data = self.users.get_mut(user_id).unwrap();
         let output = user_data.lobby_id.unwrap();
         user_data.lobby_id = None;
+        user_data.in_match = false;
+        self.presence_changes.push(*user_id);
         output
     }
+
+    pub fn user_set_away(&mut self, user_id: &UserId, away: bool) {
+        let Some(user_data) = self.users.get_mut(user_id) else {
+            return;
+        };
+        if user_data.away != away {
+            user_data.away = away;
+            self.presence_changes.push(*user_id);
+        }
+    }
+
+    // users can go offline mid-match, those are skipped
+    pub fn users_set_in_match(&mut self, user_ids: &Vec<UserId>, in_match: bool) {
+        for user_id in user_ids {
+            let Some(user_data) = self.users.get_mut(user_id) else {
+                continue;
+            };
+            if user_data.in_match != in_match {
+                user_data.in_match = in_match;
+                self.presence_changes.push(*user_id);
+            }
+        }
+    }
+}
+
+// one user id per line
+fn load_known_users(path: &Path) -> HashSet<UserId> {
+    let Ok(contents) = fs::read_to_string(path) else {
+        return HashSet::new();
+    };
+    contents
+        .lines()
+        .filter_map(|line| line.trim().parse::<u64>().ok())
+        .map(UserId::new)
+        .collect()
 }
@@ -1,60 +1,37 @@
 use std::fmt::Debug;
 
-use naia_bevy_shared::sequence_greater_than;
-
-use naia_serde::{SerdeInternal as Serde, UnsignedInteger};
+use naia_serde::{SerdeInternal as Serde, UnsignedVariableInteger};
 
+// message ids never wrap, so that persisted chat history can be paginated by id
 #[derive(Serde, PartialEq, Clone, Eq, Copy, Hash, Debug)]
 pub struct MessageId {
-    id: UnsignedInteger<9>, // TODO: there should only ever be ... 100 message in chat at a time? in that case, u9 would be definitely be enough
+    id: UnsignedVariableInteger<7>,
 }
 
 impl MessageId {
-    pub fn new(id: u16) -> Self {
+    pub fn new(id: u32) -> Self {
         Self {
-            id: UnsignedInteger::new(id),
+            id: UnsignedVariableInteger::new(id),
         }
     }
 
     pub fn next(&self) -> Self {
-        let id: u16 = self.id.to();
-        let mut id = id + 1;
-        let max_value: u16 = 2_u16.pow(9);
-        if id == max_value {
-            id = 0;
-        }
-        Self::new(id)
+        Self::new(self.to_u32() + 1)
+    }
+
+    pub fn to_u32(&self) -> u32 {
+        self.id.to()
     }
 }
 
 impl PartialOrd for MessageId {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        if self.id == other.id {
-            return Some(std::cmp::Ordering::Equal);
-        }
-
-        let a: u16 = self.id.to();
-        let b: u16 = other.id.to();
-        if sequence_greater_than(a, b) {
-            return Some(std::cmp::Ordering::Greater);
-        } else {
-            return Some(std::cmp::Ordering::Less);
-        }
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for MessageId {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        if self.id == other.id {
-            return std::cmp::Ordering::Equal;
-        }
-
-        let a: u16 = self.id.to();
-        let b: u16 = other.id.to();
-        if sequence_greater_than(a, b) {
-            return std::cmp::Ordering::Greater;
-        } else {
-            return std::cmp::Ordering::Less;
-        }
+        self.to_u32().cmp(&other.to_u32())
     }
 }
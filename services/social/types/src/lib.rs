@@ -1,8 +1,17 @@
 mod match_lobby_id;
 pub use match_lobby_id::*;
 
+mod match_lobby_settings;
+pub use match_lobby_settings::*;
+
 mod global_chat_message_id;
 pub use global_chat_message_id::*;
 
 mod timestamp;
 pub use timestamp::*;
+
+mod match_result;
+pub use match_result::*;
+
+mod presence;
+pub use presence::*;
@@ -0,0 +1,45 @@
+use naia_serde::{SerdeInternal as Serde, UnsignedInteger};
+
+#[derive(Serde, Copy, Clone, PartialEq, Eq, Debug)]
+pub enum LobbyAccess {
+    // anyone may join
+    Public,
+    // joining requires the lobby's password (or an invite)
+    Password,
+    // joining requires an invite from the lobby owner
+    InviteOnly,
+}
+
+#[derive(Serde, Copy, Clone, PartialEq, Eq, Debug)]
+pub struct LobbySettings {
+    max_players: UnsignedInteger<5>,
+    access: LobbyAccess,
+}
+
+impl LobbySettings {
+    pub const MIN_PLAYERS: u8 = 2;
+    pub const MAX_PLAYERS: u8 = 16;
+    pub const DEFAULT_MAX_PLAYERS: u8 = 8;
+
+    pub fn new(max_players: u8, access: LobbyAccess) -> Self {
+        let max_players = max_players.clamp(Self::MIN_PLAYERS, Self::MAX_PLAYERS);
+        Self {
+            max_players: UnsignedInteger::new(max_players),
+            access,
+        }
+    }
+
+    pub fn max_players(&self) -> u8 {
+        self.max_players.to()
+    }
+
+    pub fn access(&self) -> LobbyAccess {
+        self.access
+    }
+}
+
+impl Default for LobbySettings {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_MAX_PLAYERS, LobbyAccess::Public)
+    }
+}
@@ -0,0 +1,32 @@
+use naia_serde::{SerdeInternal as Serde, UnsignedVariableInteger};
+
+#[derive(Serde, Copy, Clone, PartialEq, Eq, Debug)]
+pub enum MatchOutcome {
+    // the match ran until its end condition was met
+    Finished,
+    // every player left before the match could finish
+    Abandoned,
+}
+
+#[derive(Serde, Copy, Clone, PartialEq, Eq)]
+pub struct MatchUserResult {
+    seconds_played: UnsignedVariableInteger<7>,
+    present_at_end: bool,
+}
+
+impl MatchUserResult {
+    pub fn new(seconds_played: u32, present_at_end: bool) -> Self {
+        Self {
+            seconds_played: seconds_played.into(),
+            present_at_end,
+        }
+    }
+
+    pub fn seconds_played(&self) -> u32 {
+        self.seconds_played.to::<u32>()
+    }
+
+    pub fn present_at_end(&self) -> bool {
+        self.present_at_end
+    }
+}
@@ -0,0 +1,45 @@
+use naia_serde::SerdeInternal as Serde;
+
+use crate::LobbyId;
+
+#[derive(Serde, Copy, Clone, PartialEq, Eq, Debug)]
+pub enum PresenceStatus {
+    Offline,
+    Online,
+    InLobby,
+    InMatch,
+    Away,
+}
+
+#[derive(Serde, Copy, Clone, PartialEq, Eq, Debug)]
+pub struct UserPresence {
+    status: PresenceStatus,
+    // set while the user is InLobby or InMatch
+    lobby_id: Option<LobbyId>,
+}
+
+impl UserPresence {
+    pub fn offline() -> Self {
+        Self::new(PresenceStatus::Offline, None)
+    }
+
+    pub fn new(status: PresenceStatus, lobby_id: Option<LobbyId>) -> Self {
+        Self { status, lobby_id }
+    }
+
+    pub fn status(&self) -> PresenceStatus {
+        self.status
+    }
+
+    pub fn lobby_id(&self) -> Option<LobbyId> {
+        self.lobby_id
+    }
+}
+
+#[derive(Serde, Copy, Clone, PartialEq, Eq, Debug)]
+pub enum FriendAction {
+    SendRequest,
+    AcceptRequest,
+    DeclineRequest,
+    Remove,
+}
@@ -50,7 +50,7 @@ impl Timestamp {
     }
 
     pub fn minute(&self) -> u8 {
-        self.hour.to::<u8>()
+        self.minute.to::<u8>()
     }
 
     pub fn hour(&self) -> u8 {
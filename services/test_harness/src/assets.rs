@@ -0,0 +1,78 @@
+use std::{fs, path::Path};
+
+use asset_id::{AssetId, ETag};
+use asset_serde::json::ProcessedAssetMeta;
+use spec::Unit;
+use spec_serde::bits::{AnimatedModelBits, MovementConfigBits, UnitBits};
+
+use world_server_naia_proto::constants::default_movement_config;
+
+// the avatar the world server spawns for every player, see `AssetCatalog::AvatarUnit`
+const AVATAR_UNIT_ID: &str = "h1g2dt";
+const AVATAR_ANIMATED_MODEL_ID: &str = "h1g2am";
+const AVATAR_MODEL_ID: &str = "h1g2md";
+const AVATAR_MOVEMENT_CONFIG_ID: &str = "h1g2mc";
+
+// The real avatar lives in the assets repo, which isn't checked out for tests. The world server
+// won't simulate a unit until it has the unit's movement config, so the asset server is given a
+// stand-in avatar: the default movement values, and an animated model with no animations (the
+// headless client never draws it).
+pub(crate) fn write_avatar_assets(assets_dir: &Path) {
+    let movement_config_id = asset_id(AVATAR_MOVEMENT_CONFIG_ID);
+    let movement_config: Vec<u8> = MovementConfigBits::from(&default_movement_config()).into();
+    write_asset(
+        assets_dir,
+        "avatar.movement_config",
+        movement_config_id,
+        MovementConfigBits::FORMAT_VERSION,
+        Vec::new(),
+        movement_config,
+    );
+
+    let animated_model_id = asset_id(AVATAR_ANIMATED_MODEL_ID);
+    let animated_model: Vec<u8> = AnimatedModelBits::new(asset_id(AVATAR_MODEL_ID)).into();
+    write_asset(
+        assets_dir,
+        "avatar.animated_model",
+        animated_model_id,
+        0,
+        Vec::new(),
+        animated_model,
+    );
+
+    let unit = Unit::new(animated_model_id, movement_config_id);
+    let unit: Vec<u8> = UnitBits::from(&unit).into();
+    write_asset(
+        assets_dir,
+        "avatar.unit",
+        asset_id(AVATAR_UNIT_ID),
+        0,
+        vec![animated_model_id, movement_config_id],
+        unit,
+    );
+}
+
+// the asset server finds assets by their `<name>.<type>.meta` files
+fn write_asset(
+    assets_dir: &Path,
+    file_name: &str,
+    asset_id: AssetId,
+    format_version: u8,
+    dependencies: Vec<AssetId>,
+    data: Vec<u8>,
+) {
+    let meta = ProcessedAssetMeta::new(
+        asset_id,
+        ETag::gen_random(),
+        0,
+        format_version,
+        dependencies,
+        Vec::new(),
+    );
+    fs::write(assets_dir.join(file_name), data).unwrap();
+    fs::write(assets_dir.join(format!("{}.meta", file_name)), meta.write()).unwrap();
+}
+
+fn asset_id(asset_id_str: &str) -> AssetId {
+    AssetId::from_str(asset_id_str).unwrap()
+}
@@ -0,0 +1,385 @@
+use std::{
+    collections::BTreeMap,
+    thread,
+    time::{Duration, Instant},
+};
+
+use bevy_app::{App, Update};
+use bevy_ecs::{
+    change_detection::Mut,
+    entity::Entity,
+    event::EventReader,
+    system::{Res, ResMut, Resource, SystemState},
+};
+
+use executor::smol;
+use http_common::{ApiRequest, ApiResponse, Method, Request, Response, ResponseError};
+use logging::info;
+use naia_bevy_client::{
+    events::{ClientTickEvent, ConnectEvent, MessageEvents},
+    transport::webrtc::Socket as WebrtcSocket,
+    Client, ClientConfig as NaiaClientConfig, Plugin as NaiaClientPlugin,
+};
+
+use gateway_http_proto::{UserLoginRequest, UserRegisterRequest};
+use session_server_naia_proto::{
+    channels::{ClientActionsChannel, PrimaryChannel},
+    components::Lobby,
+    messages::{MatchLobbyCreate, MatchLobbyGameStart, WorldConnectToken},
+};
+use social_server_types::{LobbyId, LobbySettings};
+use world_server_naia_proto::{
+    channels::{EntityAssignmentChannel, PlayerCommandChannel},
+    components::{NetworkedLastCommand, NetworkedTileTarget},
+    messages::{Auth as WorldAuth, EntityAssignment, PlayerCommands},
+    types::Direction,
+};
+
+use crate::Cluster;
+
+const PASSWORD: &str = "Password123!";
+const STEP_TIMEOUT: Duration = Duration::from_secs(30);
+const FRAME_DURATION: Duration = Duration::from_millis(5);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Session;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct World;
+
+type SessionClient<'w> = Client<'w, Session>;
+type WorldClient<'w> = Client<'w, World>;
+
+// A game client without the game: it drives the same http & naia traffic as a player would, one
+// blocking step at a time, so tests can script a flow and assert on what comes back.
+// Each step panics if it doesn't complete in time, naming what it was waiting for.
+pub struct HeadlessClient {
+    app: App,
+    username: String,
+    email: String,
+    cookies: BTreeMap<String, String>,
+}
+
+impl HeadlessClient {
+    pub fn new(username: &str) -> Self {
+        let mut app = App::default();
+        app.add_plugins(NaiaClientPlugin::<Session>::new(
+            NaiaClientConfig::default(),
+            session_server_naia_proto::protocol(),
+        ))
+        .add_plugins(NaiaClientPlugin::<World>::new(
+            NaiaClientConfig::default(),
+            world_server_naia_proto::protocol(),
+        ))
+        .init_resource::<HeadlessState>()
+        .add_systems(Update, session_connect_events)
+        .add_systems(Update, session_message_events)
+        .add_systems(Update, world_connect_events)
+        .add_systems(Update, world_message_events)
+        .add_systems(Update, world_tick_events);
+
+        Self {
+            app,
+            username: username.to_string(),
+            email: format!("{}@cyberlith.test", username),
+            cookies: BTreeMap::new(),
+        }
+    }
+
+    pub fn username(&self) -> &str {
+        &self.username
+    }
+
+    pub fn email(&self) -> &str {
+        &self.email
+    }
+
+    // http
+
+    pub fn register(&mut self, cluster: &Cluster) -> Result<(), ResponseError> {
+        let request = UserRegisterRequest::new(&self.username, &self.email, PASSWORD);
+        self.send(cluster, request).map(|_| ())
+    }
+
+    // follows the link from the verification email, which logs the new user in
+    pub fn confirm_registration(&mut self, cluster: &Cluster) -> Result<(), ResponseError> {
+        let Some(register_token) = cluster.register_token(&self.email) else {
+            panic!("no verification email was sent to {}", self.email);
+        };
+        let url = format!(
+            "http://{}:{}/?register_token={}",
+            cluster.gateway_addr(),
+            cluster.gateway_port(),
+            register_token
+        );
+        let response = self.fetch(Request::new(Method::Get, &url, Vec::new()))?;
+        // a confirmed registration redirects into the game
+        if response.status != 302 {
+            return Err(ResponseError::from_response(&response));
+        }
+        Ok(())
+    }
+
+    pub fn login(&mut self, cluster: &Cluster) -> Result<(), ResponseError> {
+        let request = UserLoginRequest::new(&self.username, PASSWORD);
+        let response = self.send(cluster, request)?;
+        if response.is_simultaneous_login_detected() {
+            panic!("{} is already logged in elsewhere", self.username);
+        }
+        Ok(())
+    }
+
+    pub fn is_logged_in(&self) -> bool {
+        !self.cookies.is_empty()
+    }
+
+    fn send<Q: ApiRequest>(
+        &mut self,
+        cluster: &Cluster,
+        request: Q,
+    ) -> Result<Q::Response, ResponseError> {
+        let request = request.to_request(cluster.gateway_addr(), cluster.gateway_port());
+        let response = self.fetch(request)?.to_result()?;
+        Q::Response::from_response(response)
+    }
+
+    fn fetch(&mut self, mut request: Request) -> Result<Response, ResponseError> {
+        if let Some(cookie_header) = self.cookie_header() {
+            request.insert_header("Cookie", &cookie_header);
+        }
+        let response = smol::block_on(http_client_shared::fetch_async(request))?;
+        if let Some(set_cookie_headers) = response.get_headers("Set-Cookie") {
+            for header_value in set_cookie_headers {
+                let cookie = header_value.split(';').next().unwrap_or_default();
+                if let Some((name, value)) = cookie.split_once('=') {
+                    self.cookies
+                        .insert(name.trim().to_string(), value.trim().to_string());
+                }
+            }
+        }
+        Ok(response)
+    }
+
+    fn cookie_header(&self) -> Option<String> {
+        if self.cookies.is_empty() {
+            return None;
+        }
+        let cookies: Vec<String> = self
+            .cookies
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect();
+        Some(cookies.join("; "))
+    }
+
+    // session
+
+    pub fn connect_to_session(&mut self, cluster: &Cluster) {
+        let Some(cookie_header) = self.cookie_header() else {
+            panic!("{} must log in before connecting", self.username);
+        };
+        let url = format!(
+            "http://{}:{}",
+            cluster.gateway_addr(),
+            cluster.gateway_port()
+        );
+        self.state_mut().world_url_and_cookies = Some((url.clone(), cookie_header.clone()));
+
+        info!("{} connecting to session server: {}", self.username, url);
+        self.with_session_client(|client| {
+            client.auth_headers(vec![("Cookie".to_string(), cookie_header)]);
+            let socket = WebrtcSocket::new(&url, client.socket_config());
+            client.connect(socket);
+        });
+
+        self.update_until("session connection", |client| {
+            client.state().session_connected
+        });
+    }
+
+    pub fn create_lobby(&mut self, name: &str) -> LobbyId {
+        let message = MatchLobbyCreate::new(name, LobbySettings::default(), None);
+        self.with_session_client(|client| {
+            client.send_message::<ClientActionsChannel, _>(&message);
+        });
+
+        let mut lobby_id = None;
+        self.update_until("lobby to be created", |client| {
+            lobby_id = client.find_lobby(|lobby| *lobby.name == name);
+            lobby_id.is_some()
+        });
+        lobby_id.unwrap()
+    }
+
+    // starts the match in the lobby this user owns, and waits to be given a unit in the world
+    pub fn start_match(&mut self, lobby_id: LobbyId) {
+        self.with_session_client(|client| {
+            client.send_message::<ClientActionsChannel, _>(&MatchLobbyGameStart);
+        });
+
+        self.update_until("lobby to be in progress", |client| {
+            client
+                .find_lobby(|lobby| *lobby.id == lobby_id && lobby.is_in_progress())
+                .is_some()
+        });
+        self.update_until("world connection", |client| client.state().world_connected);
+        self.update_until("entity assignment", |client| {
+            client.state().owned_entity.is_some()
+        });
+    }
+
+    fn with_session_client(&mut self, func: impl FnOnce(&mut SessionClient)) {
+        let world = self.app.world_mut();
+        let mut system_state: SystemState<SessionClient> = SystemState::new(world);
+        let mut client = system_state.get_mut(world);
+        func(&mut client);
+        system_state.apply(world);
+    }
+
+    // world
+
+    // the commands are sent every tick until released, like a held key
+    pub fn hold_commands(&mut self, commands: PlayerCommands) {
+        self.state_mut().held_commands = Some(commands);
+    }
+
+    pub fn release_commands(&mut self) {
+        self.state_mut().held_commands = None;
+    }
+
+    pub fn owned_entity(&self) -> Option<Entity> {
+        self.state().owned_entity
+    }
+
+    // the last move the server processed for this user's unit
+    pub fn last_command(&self) -> Option<Direction> {
+        let entity = self.owned_entity()?;
+        self.app.world().get::<NetworkedLastCommand>(entity)?.get()
+    }
+
+    pub fn tile_position(&self) -> Option<(i16, i16)> {
+        let entity = self.owned_entity()?;
+        let tile_target = self.app.world().get::<NetworkedTileTarget>(entity)?;
+        Some((tile_target.x(), tile_target.y()))
+    }
+
+    // frames
+
+    // runs frames until the condition holds, panicking if it doesn't within the step timeout
+    pub fn update_until(
+        &mut self,
+        waiting_for: &str,
+        mut condition: impl FnMut(&mut Self) -> bool,
+    ) {
+        let started = Instant::now();
+        loop {
+            self.app.update();
+            if condition(self) {
+                return;
+            }
+            if started.elapsed() > STEP_TIMEOUT {
+                panic!(
+                    "{} timed out after {:?} waiting for {}",
+                    self.username, STEP_TIMEOUT, waiting_for
+                );
+            }
+            thread::sleep(FRAME_DURATION);
+        }
+    }
+
+    fn state(&self) -> &HeadlessState {
+        self.app.world().resource::<HeadlessState>()
+    }
+
+    fn state_mut(&mut self) -> Mut<HeadlessState> {
+        self.app.world_mut().resource_mut::<HeadlessState>()
+    }
+
+    fn find_lobby(&mut self, predicate: impl Fn(&Lobby) -> bool) -> Option<LobbyId> {
+        let world = self.app.world_mut();
+        world
+            .query::<&Lobby>()
+            .iter(world)
+            .find(|lobby| predicate(lobby))
+            .map(|lobby| *lobby.id)
+    }
+}
+
+#[derive(Resource, Default)]
+struct HeadlessState {
+    session_connected: bool,
+    world_connected: bool,
+    world_url_and_cookies: Option<(String, String)>,
+    owned_entity: Option<Entity>,
+    held_commands: Option<PlayerCommands>,
+}
+
+fn session_connect_events(
+    mut state: ResMut<HeadlessState>,
+    mut event_reader: EventReader<ConnectEvent<Session>>,
+) {
+    for _ in event_reader.read() {
+        state.session_connected = true;
+    }
+}
+
+// the session server hands out a token once a match starts, which gets us into the world
+fn session_message_events(
+    mut world_client: WorldClient,
+    state: Res<HeadlessState>,
+    mut event_reader: EventReader<MessageEvents<Session>>,
+) {
+    for events in event_reader.read() {
+        for token in events.read::<PrimaryChannel, WorldConnectToken>() {
+            let Some((url, cookie_header)) = state.world_url_and_cookies.clone() else {
+                panic!("received a world connect token before connecting to the session");
+            };
+            info!("connecting to world server: {}", url);
+            world_client.auth(WorldAuth::new(&token.login_token));
+            world_client.auth_headers(vec![("Cookie".to_string(), cookie_header)]);
+            let socket = WebrtcSocket::new(&url, world_client.socket_config());
+            world_client.connect(socket);
+        }
+    }
+}
+
+fn world_connect_events(
+    mut state: ResMut<HeadlessState>,
+    mut event_reader: EventReader<ConnectEvent<World>>,
+) {
+    for _ in event_reader.read() {
+        state.world_connected = true;
+    }
+}
+
+fn world_message_events(
+    client: WorldClient,
+    mut state: ResMut<HeadlessState>,
+    mut event_reader: EventReader<MessageEvents<World>>,
+) {
+    for events in event_reader.read() {
+        for message in events.read::<EntityAssignmentChannel, EntityAssignment>() {
+            let entity = message.entity.get(&client);
+            if message.assign {
+                state.owned_entity = entity;
+            } else if state.owned_entity == entity {
+                state.owned_entity = None;
+            }
+        }
+    }
+}
+
+fn world_tick_events(
+    mut client: WorldClient,
+    state: Res<HeadlessState>,
+    mut event_reader: EventReader<ClientTickEvent<World>>,
+) {
+    for event in event_reader.read() {
+        if let Some(commands) = &state.held_commands {
+            client.send_tick_buffer_message::<PlayerCommandChannel, PlayerCommands>(
+                &event.tick,
+                commands,
+            );
+        }
+    }
+}
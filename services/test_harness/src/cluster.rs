@@ -0,0 +1,200 @@
+use std::{
+    env, fs,
+    net::{SocketAddr, TcpStream},
+    sync::OnceLock,
+    thread,
+    time::{Duration, Instant},
+};
+
+use auth_server_db::DatabaseManager;
+use config::total_cpu_priority;
+use email::{Mailer, Outbox};
+use logging::info;
+
+use crate::{
+    assets::write_avatar_assets,
+    ports::{pick_tcp_port, pick_udp_port},
+};
+
+const LOCALHOST: &str = "127.0.0.1";
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(30);
+
+static CLUSTER: OnceLock<Cluster> = OnceLock::new();
+
+// Every service, running on its own ephemeral ports inside the test process.
+// Logging, the runtime config & the executor are all process-wide, so there can only be one
+// cluster per process: tests share it, and should register their own users to stay independent.
+pub struct Cluster {
+    outbox: Outbox,
+}
+
+impl Cluster {
+    // boots the cluster on first call, and returns the running one after that
+    pub fn get() -> &'static Self {
+        CLUSTER.get_or_init(Self::start)
+    }
+
+    fn start() -> Self {
+        // servers read & write files relative to the working directory (social data, content &
+        // asset files), so give this run a directory of its own
+        let data_dir = env::temp_dir().join(format!("cyberlith_cluster_{}", std::process::id()));
+        for dir in ["files", "assets"] {
+            fs::create_dir_all(data_dir.join(dir)).unwrap();
+        }
+        write_avatar_assets(&data_dir.join("assets"));
+        env::set_current_dir(&data_dir).unwrap();
+
+        set_config_env_vars();
+
+        logging::initialize();
+        config::init_runtime_config(&[
+            &config::AUTH_SERVER_CONFIG,
+            &config::REGION_SERVER_CONFIG,
+            &config::SOCIAL_SERVER_CONFIG,
+            &config::ASSET_SERVER_CONFIG,
+            &config::CONTENT_SERVER_CONFIG,
+            &config::GATEWAY_CONFIG,
+            &config::SESSION_SERVER_CONFIG,
+            &config::WORLD_SERVER_CONFIG,
+        ]);
+        // all servers share the one executor, so it gets every cpu
+        executor::setup(total_cpu_priority(), total_cpu_priority());
+
+        info!("Local cluster starting up in {:?}", data_dir);
+
+        let outbox = Outbox::new();
+
+        auth_server::start(
+            DatabaseManager::init_in_memory(),
+            Mailer::InMemory(outbox.clone()),
+        );
+        region_server::start();
+        social_server::start();
+        asset_server::start();
+        content_server::start();
+        gateway::start();
+
+        thread::Builder::new()
+            .name("session_server".to_string())
+            .spawn(|| session_server::build_app().run())
+            .unwrap();
+        thread::Builder::new()
+            .name("world_server".to_string())
+            .spawn(|| world_server::build_app().run())
+            .unwrap();
+
+        for port in [
+            config::auth_server_port(),
+            config::region_server_port(),
+            config::social_server_port(),
+            config::asset_server_port(),
+            config::content_server_port(),
+            config::gateway_port(),
+            config::session_server_http_port(),
+            config::world_server_http_port(),
+        ] {
+            wait_for_port(port);
+        }
+
+        info!("Local cluster is up");
+
+        Self { outbox }
+    }
+
+    pub fn outbox(&self) -> &Outbox {
+        &self.outbox
+    }
+
+    pub fn gateway_addr(&self) -> &'static str {
+        LOCALHOST
+    }
+
+    pub fn gateway_port(&self) -> u16 {
+        config::gateway_port()
+    }
+
+    // the register token from the latest verification email sent to the address, if any
+    pub fn register_token(&self, email: &str) -> Option<String> {
+        self.outbox.sent_to(email).iter().rev().find_map(|email| {
+            query_param(&email.text_content, "register_token")
+                .or_else(|| query_param(&email.html_content, "register_token"))
+        })
+    }
+}
+
+// every server binds & connects on localhost, on ports nothing else is using
+fn set_config_env_vars() {
+    let strings = [
+        ("SELF_BINDING_ADDR", LOCALHOST),
+        ("PUBLIC_PROTOCOL", "http"),
+        ("PUBLIC_IP_ADDR", LOCALHOST),
+        ("CONTENT_SERVER_RECV_ADDR", LOCALHOST),
+        ("REGION_SERVER_RECV_ADDR", LOCALHOST),
+        ("SESSION_SERVER_RECV_ADDR", LOCALHOST),
+        ("WORLD_SERVER_RECV_ADDR", LOCALHOST),
+        ("ASSET_SERVER_RECV_ADDR", LOCALHOST),
+        ("SOCIAL_SERVER_RECV_ADDR", LOCALHOST),
+        ("AUTH_SERVER_RECV_ADDR", LOCALHOST),
+        ("CONTENT_SERVER_FILES_PATH", "./files"),
+        ("ASSET_SERVER_FILES_PATH", "./assets"),
+        ("WORLD_SERVER_RECORDINGS_PATH", "./recordings"),
+    ];
+    for (name, value) in strings {
+        env::set_var(format!("CYBERLITH_{}", name), value);
+    }
+
+    let tcp_ports = [
+        "REDIRECTOR_PORT",
+        "GATEWAY_PORT",
+        "CONTENT_SERVER_PORT",
+        "REGION_SERVER_PORT",
+        "SESSION_SERVER_HTTP_PORT",
+        "SESSION_SERVER_SIGNAL_PORT",
+        "WORLD_SERVER_HTTP_PORT",
+        "WORLD_SERVER_SIGNAL_PORT",
+        "ASSET_SERVER_PORT",
+        "AUTH_SERVER_PORT",
+        "SOCIAL_SERVER_PORT",
+    ];
+    let udp_ports = ["SESSION_SERVER_WEBRTC_PORT", "WORLD_SERVER_WEBRTC_PORT"];
+
+    let mut taken = Vec::new();
+    for name in tcp_ports {
+        let port = pick_tcp_port(&taken);
+        taken.push(port);
+        env::set_var(format!("CYBERLITH_{}", name), port.to_string());
+    }
+    for name in udp_ports {
+        let port = pick_udp_port(&taken);
+        taken.push(port);
+        env::set_var(format!("CYBERLITH_{}", name), port.to_string());
+    }
+}
+
+fn wait_for_port(port: u16) {
+    let addr = SocketAddr::new(LOCALHOST.parse().unwrap(), port);
+    let started = Instant::now();
+    while TcpStream::connect_timeout(&addr, Duration::from_millis(100)).is_err() {
+        if started.elapsed() > STARTUP_TIMEOUT {
+            panic!(
+                "nothing is listening on port {} after {:?}",
+                port, STARTUP_TIMEOUT
+            );
+        }
+        thread::sleep(Duration::from_millis(50));
+    }
+}
+
+fn query_param(text: &str, name: &str) -> Option<String> {
+    let prefix = format!("{}=", name);
+    let start = text.find(&prefix)? + prefix.len();
+    let value: String = text[start..]
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+        .collect();
+    if value.is_empty() {
+        None
+    } else {
+        Some(value)
+    }
+}
@@ -0,0 +1,12 @@
+// Boots every service in this one process, for end-to-end tests that need no network.
+// `Cluster::get()` starts the services on first use; `HeadlessClient` plays the part of a player.
+
+mod assets;
+mod client;
+mod cluster;
+mod ports;
+
+pub use client::HeadlessClient;
+pub use cluster::Cluster;
+
+pub use world_server_naia_proto::{messages::PlayerCommands, types::Direction};
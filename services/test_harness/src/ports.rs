@@ -0,0 +1,23 @@
+use std::net::{TcpListener, UdpSocket};
+
+// binding to port 0 has the OS hand out a free port, which is released again for the service to
+// take. `taken` guards against the OS handing the same port out twice before anything binds it
+pub(crate) fn pick_tcp_port(taken: &[u16]) -> u16 {
+    loop {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        if !taken.contains(&port) {
+            return port;
+        }
+    }
+}
+
+pub(crate) fn pick_udp_port(taken: &[u16]) -> u16 {
+    loop {
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let port = socket.local_addr().unwrap().port();
+        if !taken.contains(&port) {
+            return port;
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use test_harness::{Cluster, Direction, HeadlessClient, PlayerCommands};
+
+#[test]
+fn register_login_and_play_a_match() {
+    let cluster = Cluster::get();
+    let mut client = HeadlessClient::new("matchplayer");
+
+    client.register(cluster).expect("register");
+    client
+        .confirm_registration(cluster)
+        .expect("confirm registration");
+    assert!(client.is_logged_in());
+
+    client.login(cluster).expect("login");
+    client.connect_to_session(cluster);
+
+    let lobby_id = client.create_lobby("harness match");
+    client.start_match(lobby_id);
+    assert!(client.owned_entity().is_some());
+
+    client.update_until("unit position", |client| client.tile_position().is_some());
+    let start_position = client.tile_position().unwrap();
+
+    let mut commands = PlayerCommands::new();
+    commands.set_move(Direction::East);
+    client.hold_commands(commands);
+
+    client.update_until("server to process the move", |client| {
+        client.last_command() == Some(Direction::East)
+    });
+    client.update_until("unit to leave its starting tile", |client| {
+        client.tile_position() != Some(start_position)
+    });
+
+    client.release_commands();
+    client.update_until("server to process the release", |client| {
+        client.last_command().is_none()
+    });
+}
+
+#[test]
+fn unconfirmed_user_cannot_log_in() {
+    let cluster = Cluster::get();
+    let mut client = HeadlessClient::new("unconfirmed");
+
+    client.register(cluster).expect("register");
+    assert!(cluster.register_token(client.email()).is_some());
+
+    assert!(client.login(cluster).is_err());
+    assert!(!client.is_logged_in());
+}
@@ -1,7 +1,15 @@
+mod connect_asset_server;
+mod disconnect_asset_server;
 mod heartbeat;
 mod protocol;
 mod world_connect;
+mod world_spectate;
+mod world_spectate_leave;
 
+pub use connect_asset_server::*;
+pub use disconnect_asset_server::*;
 pub use heartbeat::*;
 pub use protocol::protocol;
 pub use world_connect::*;
+pub use world_spectate::*;
+pub use world_spectate_leave::*;
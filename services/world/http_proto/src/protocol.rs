@@ -1,10 +1,19 @@
 use bevy_http_shared::Protocol;
 
-use crate::{HeartbeatRequest, WorldConnectRequest};
+use crate::{
+    ConnectAssetServerRequest, DisconnectAssetServerRequest, HeartbeatRequest, WorldConnectRequest,
+    WorldSpectateLeaveRequest, WorldSpectateRequest,
+};
 
 pub fn protocol() -> Protocol {
     let mut protocol = Protocol::new();
     protocol.add_request::<WorldConnectRequest>();
+    protocol.add_request::<WorldSpectateRequest>();
+    protocol.add_request::<WorldSpectateLeaveRequest>();
     protocol.add_request::<HeartbeatRequest>();
+
+    protocol.add_request::<ConnectAssetServerRequest>();
+    protocol.add_request::<DisconnectAssetServerRequest>();
+
     protocol
 }
@@ -0,0 +1,91 @@
+use naia_serde::SerdeInternal as Serde;
+
+use auth_server_types::UserId;
+use bevy_http_shared::{ApiRequest, ApiResponse, Method};
+use social_server_types::LobbyId;
+
+// this is sent by the region server, for a match already running on this world server
+
+// Request
+#[derive(Serde, PartialEq, Clone)]
+pub struct WorldSpectateRequest {
+    region_secret: String,
+    lobby_id: LobbyId,
+    session_server_addr: String,
+    session_server_port: u16,
+    user_id: UserId,
+    login_token: String,
+}
+
+impl WorldSpectateRequest {
+    pub fn new(
+        region_secret: &str,
+        lobby_id: LobbyId,
+        session_server_addr: &str,
+        session_server_port: u16,
+        user_id: UserId,
+        login_token: &str,
+    ) -> Self {
+        Self {
+            region_secret: region_secret.to_string(),
+            lobby_id,
+            session_server_addr: session_server_addr.to_string(),
+            session_server_port,
+            user_id,
+            login_token: login_token.to_string(),
+        }
+    }
+
+    pub fn region_secret(&self) -> &str {
+        &self.region_secret
+    }
+
+    pub fn lobby_id(&self) -> LobbyId {
+        self.lobby_id
+    }
+
+    pub fn session_server(&self) -> (&str, u16) {
+        (&self.session_server_addr, self.session_server_port)
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn login_token(&self) -> &str {
+        &self.login_token
+    }
+}
+
+// Response
+#[derive(Serde, PartialEq, Clone)]
+pub struct WorldSpectateResponse;
+
+impl WorldSpectateResponse {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+// Traits
+impl ApiRequest for WorldSpectateRequest {
+    type Response = WorldSpectateResponse;
+
+    fn name() -> &'static str {
+        "WorldSpectateRequest"
+    }
+
+    fn method() -> Method {
+        Method::Post
+    }
+
+    fn path() -> &'static str {
+        "world/spectate"
+    }
+}
+
+impl ApiResponse for WorldSpectateResponse {
+    fn name() -> &'static str {
+        "WorldSpectateResponse"
+    }
+}
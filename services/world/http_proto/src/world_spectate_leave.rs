@@ -0,0 +1,70 @@
+use naia_serde::SerdeInternal as Serde;
+
+use auth_server_types::UserId;
+use bevy_http_shared::{ApiRequest, ApiResponse, Method};
+use social_server_types::LobbyId;
+
+// this is sent by the region server, when a spectator stops watching a match running here
+
+// Request
+#[derive(Serde, PartialEq, Clone)]
+pub struct WorldSpectateLeaveRequest {
+    region_secret: String,
+    lobby_id: LobbyId,
+    user_id: UserId,
+}
+
+impl WorldSpectateLeaveRequest {
+    pub fn new(region_secret: &str, lobby_id: LobbyId, user_id: UserId) -> Self {
+        Self {
+            region_secret: region_secret.to_string(),
+            lobby_id,
+            user_id,
+        }
+    }
+
+    pub fn region_secret(&self) -> &str {
+        &self.region_secret
+    }
+
+    pub fn lobby_id(&self) -> LobbyId {
+        self.lobby_id
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+}
+
+// Response
+#[derive(Serde, PartialEq, Clone)]
+pub struct WorldSpectateLeaveResponse;
+
+impl WorldSpectateLeaveResponse {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+// Traits
+impl ApiRequest for WorldSpectateLeaveRequest {
+    type Response = WorldSpectateLeaveResponse;
+
+    fn name() -> &'static str {
+        "WorldSpectateLeaveRequest"
+    }
+
+    fn method() -> Method {
+        Method::Post
+    }
+
+    fn path() -> &'static str {
+        "world/spectate_leave"
+    }
+}
+
+impl ApiResponse for WorldSpectateLeaveResponse {
+    fn name() -> &'static str {
+        "WorldSpectateLeaveResponse"
+    }
+}
@@ -3,3 +3,8 @@ pub use process_tick::process_tick;
 
 mod tick_output;
 pub use tick_output::TickOutput;
+
+mod movement_state;
+pub use movement_state::{
+    is_movement_checksum_tick, MovementState, MOVEMENT_CHECKSUM_INTERVAL_TICKS,
+};
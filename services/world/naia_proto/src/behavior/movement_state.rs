@@ -0,0 +1,300 @@
+use naia_bevy_shared::Tick;
+
+use crate::components::{PhysicsController, TileMovement};
+
+// the server sends a checksum for every unit once per second
+pub const MOVEMENT_CHECKSUM_INTERVAL_TICKS: Tick = 25;
+
+pub fn is_movement_checksum_tick(tick: Tick) -> bool {
+    tick % MOVEMENT_CHECKSUM_INTERVAL_TICKS == 0
+}
+
+// A unit's simulated movement, quantized to hundredths of a world unit so that the server's
+// and the client's snapshots can be compared exactly.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MovementState {
+    tile: (i16, i16),
+    position: (i32, i32),
+    velocity: (i32, i32),
+}
+
+impl MovementState {
+    pub fn capture(tile_movement: &TileMovement, physics: &PhysicsController) -> Self {
+        let position = physics.position();
+        let velocity = physics.velocity();
+        Self {
+            tile: tile_movement.tile_position(),
+            position: (quantize(position.x), quantize(position.y)),
+            velocity: (quantize(velocity.x), quantize(velocity.y)),
+        }
+    }
+
+    pub fn tile(&self) -> (i16, i16) {
+        self.tile
+    }
+
+    pub fn position(&self) -> (f32, f32) {
+        (
+            self.position.0 as f32 / 100.0,
+            self.position.1 as f32 / 100.0,
+        )
+    }
+
+    pub fn velocity(&self) -> (f32, f32) {
+        (
+            self.velocity.0 as f32 / 100.0,
+            self.velocity.1 as f32 / 100.0,
+        )
+    }
+
+    // 32-bit FNV-1a, which unlike std's hashers is the same on every platform and every run
+    pub fn checksum(&self) -> u32 {
+        let mut hash: u32 = 0x811c9dc5;
+        let mut write = |bytes: &[u8]| {
+            for byte in bytes {
+                hash ^= *byte as u32;
+                hash = hash.wrapping_mul(0x01000193);
+            }
+        };
+        write(&self.tile.0.to_le_bytes());
+        write(&self.tile.1.to_le_bytes());
+        write(&self.position.0.to_le_bytes());
+        write(&self.position.1.to_le_bytes());
+        write(&self.velocity.0.to_le_bytes());
+        write(&self.velocity.1.to_le_bytes());
+        hash
+    }
+}
+
+fn quantize(value: f32) -> i32 {
+    (value * 100.0).round() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use random::gen_range_u32;
+
+    use crate::{
+        behavior::{process_tick, MovementState},
+        components::{
+            MoveBuffer, NetworkedTileTarget, PhysicsController, TileMovement, TileMovementType,
+        },
+        messages::PlayerCommands,
+        types::Direction,
+    };
+
+    struct Simulation {
+        tile_movement: TileMovement,
+        physics: PhysicsController,
+        move_buffer: MoveBuffer,
+    }
+
+    impl Simulation {
+        fn new(tile_x: i16, tile_y: i16) -> Self {
+            let net_tile_target = NetworkedTileTarget::new(tile_x, tile_y);
+            Self {
+                tile_movement: TileMovement::new_stopped(&net_tile_target),
+                physics: PhysicsController::new(&net_tile_target),
+                move_buffer: MoveBuffer::new(),
+            }
+        }
+
+        fn step(
+            &mut self,
+            tile_movement_type: TileMovementType,
+            tick: u16,
+            command: Option<PlayerCommands>,
+        ) {
+            process_tick(
+                tile_movement_type,
+                tick,
+                command,
+                &mut self.tile_movement,
+                &mut self.physics,
+                &mut self.move_buffer,
+                None,
+                None,
+            );
+        }
+
+        fn state(&self) -> MovementState {
+            MovementState::capture(&self.tile_movement, &self.physics)
+        }
+    }
+
+    const DIRECTIONS: [Direction; 8] = [
+        Direction::North,
+        Direction::Northeast,
+        Direction::East,
+        Direction::Southeast,
+        Direction::South,
+        Direction::Southwest,
+        Direction::West,
+        Direction::Northwest,
+    ];
+
+    // xorshift, so that a failing run can be replayed from its seed alone
+    struct SeededRandom {
+        state: u32,
+    }
+
+    impl SeededRandom {
+        fn new(seed: u32) -> Self {
+            Self { state: seed.max(1) }
+        }
+
+        fn next_u32(&mut self) -> u32 {
+            let mut x = self.state;
+            x ^= x << 13;
+            x ^= x >> 17;
+            x ^= x << 5;
+            self.state = x;
+            x
+        }
+
+        fn gen_range_u32(&mut self, lower: u32, upper: u32) -> u32 {
+            lower + self.next_u32() % (upper - lower)
+        }
+
+        fn gen_bool(&mut self) -> bool {
+            self.next_u32() & 1 == 1
+        }
+
+        fn direction(&mut self) -> Direction {
+            DIRECTIONS[self.gen_range_u32(0, 8) as usize]
+        }
+    }
+
+    // set MOVEMENT_TEST_SEED to replay a single failing run
+    fn run_seeds(runs: usize) -> Vec<u32> {
+        if let Ok(seed) = std::env::var("MOVEMENT_TEST_SEED") {
+            return vec![seed.parse().expect("MOVEMENT_TEST_SEED must be a u32")];
+        }
+        (0..runs).map(|_| gen_range_u32(1, u32::MAX)).collect()
+    }
+
+    // long runs of the same direction exercise buffered moves, short ones exercise reversals
+    fn random_command_stream(
+        random: &mut SeededRandom,
+        length: usize,
+    ) -> Vec<Option<(Option<Direction>, Option<Direction>)>> {
+        let mut stream = Vec::with_capacity(length);
+        let mut held: Option<Direction> = None;
+        while stream.len() < length {
+            let run = random.gen_range_u32(1, 30) as usize;
+            if random.gen_range_u32(0, 4) == 0 {
+                held = None;
+            } else {
+                held = Some(random.direction());
+            }
+            for _ in 0..run {
+                let look = if random.gen_bool() {
+                    Some(random.direction())
+                } else {
+                    None
+                };
+                if held.is_none() && look.is_none() {
+                    stream.push(None);
+                } else {
+                    stream.push(Some((look, held)));
+                }
+            }
+        }
+        stream.truncate(length);
+        stream
+    }
+
+    fn to_command(
+        entry: &Option<(Option<Direction>, Option<Direction>)>,
+    ) -> Option<PlayerCommands> {
+        let (look_opt, move_opt) = (*entry)?;
+        let mut command = PlayerCommands::new();
+        if let Some(look) = look_opt {
+            command.set_look(look);
+        }
+        if let Some(direction) = move_opt {
+            command.set_move(direction);
+        }
+        Some(command)
+    }
+
+    #[test]
+    fn server_and_prediction_trajectories_match() {
+        for seed in run_seeds(200) {
+            let mut random = SeededRandom::new(seed);
+            let stream = random_command_stream(&mut random, 500);
+            let start_x = random.gen_range_u32(0, 20) as i16 - 10;
+            let start_y = random.gen_range_u32(0, 20) as i16 - 10;
+
+            let mut server = Simulation::new(start_x, start_y);
+            let mut predicted = Simulation::new(start_x, start_y);
+
+            for (index, entry) in stream.iter().enumerate() {
+                // start past the wrap-around point so tick sequencing is covered too
+                let tick = (u16::MAX - 100).wrapping_add(index as u16);
+                server.step(TileMovementType::Server, tick, to_command(entry));
+                predicted.step(TileMovementType::ClientPredicted, tick, to_command(entry));
+
+                let server_state = server.state();
+                let predicted_state = predicted.state();
+                assert_eq!(
+                    server_state, predicted_state,
+                    "diverged at step {} (tick {}), replay with MOVEMENT_TEST_SEED={}",
+                    index, tick, seed,
+                );
+                assert_eq!(
+                    server_state.checksum(),
+                    predicted_state.checksum(),
+                    "checksums differ at step {}, replay with MOVEMENT_TEST_SEED={}",
+                    index,
+                    seed,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn replaying_a_stream_is_deterministic() {
+        for seed in run_seeds(50) {
+            let stream = random_command_stream(&mut SeededRandom::new(seed), 300);
+
+            let mut first = Simulation::new(0, 0);
+            let mut second = Simulation::new(0, 0);
+            let mut first_trajectory = Vec::new();
+            let mut second_trajectory = Vec::new();
+
+            for (index, entry) in stream.iter().enumerate() {
+                first.step(TileMovementType::Server, index as u16, to_command(entry));
+                first_trajectory.push(first.state().checksum());
+            }
+            for (index, entry) in stream.iter().enumerate() {
+                second.step(TileMovementType::Server, index as u16, to_command(entry));
+                second_trajectory.push(second.state().checksum());
+            }
+
+            assert_eq!(
+                first_trajectory, second_trajectory,
+                "replay with MOVEMENT_TEST_SEED={}",
+                seed
+            );
+        }
+    }
+
+    #[test]
+    fn checksum_covers_every_field() {
+        let mut simulation = Simulation::new(0, 0);
+        let stopped = simulation.state();
+
+        simulation.physics.set_velocity(1.0, 0.0, false);
+        let with_velocity = simulation.state();
+        assert_ne!(stopped.checksum(), with_velocity.checksum());
+
+        simulation.physics.set_position(0.5, 0.0, false);
+        let with_position = simulation.state();
+        assert_ne!(with_velocity.checksum(), with_position.checksum());
+
+        simulation.tile_movement.set_tile_position(1, 0);
+        let with_tile = simulation.state();
+        assert_ne!(with_position.checksum(), with_tile.checksum());
+    }
+}
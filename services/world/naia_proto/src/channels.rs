@@ -9,6 +9,9 @@ pub struct PlayerCommandChannel;
 #[derive(Channel)]
 pub struct EntityAssignmentChannel;
 
+#[derive(Channel)]
+pub struct MovementChecksumChannel;
+
 // Plugin
 pub(crate) struct ChannelsPlugin;
 
@@ -22,6 +25,10 @@ impl ProtocolPlugin for ChannelsPlugin {
             .add_channel::<EntityAssignmentChannel>(
                 ChannelDirection::ServerToClient,
                 ChannelMode::UnorderedReliable(ReliableSettings::default()),
+            )
+            .add_channel::<MovementChecksumChannel>(
+                ChannelDirection::ServerToClient,
+                ChannelMode::UnorderedUnreliable,
             );
     }
 }
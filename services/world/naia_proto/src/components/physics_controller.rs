@@ -3,13 +3,11 @@ use bevy_ecs::component::Component;
 use logging::warn;
 use math::Vec2;
 use naia_bevy_shared::Tick;
+use spec::MovementConfig;
 
 use crate::{
     components::{velocity::Velocity, NetworkedTileTarget},
-    constants::{
-        MOVEMENT_ACCELERATION, MOVEMENT_ARRIVAL_DISTANCE, MOVEMENT_FRICTION,
-        MOVEMENT_STEERING_DEADZONE, MOVEMENT_VELOCITY_MAX, MOVEMENT_VELOCITY_MIN, TILE_SIZE,
-    },
+    constants::{default_movement_config, TILE_SIZE},
     types::Direction,
 };
 
@@ -18,6 +16,7 @@ pub struct PhysicsController {
     position: Vec2,
     velocity: Velocity,
     last_acceleration: Vec2,
+    movement_config: MovementConfig,
 }
 
 impl PhysicsController {
@@ -28,9 +27,18 @@ impl PhysicsController {
             position,
             velocity: Velocity::new(0.0, 0.0),
             last_acceleration: Vec2::ZERO,
+            movement_config: default_movement_config(),
         }
     }
 
+    pub fn movement_config(&self) -> &MovementConfig {
+        &self.movement_config
+    }
+
+    pub fn set_movement_config(&mut self, movement_config: &MovementConfig) {
+        self.movement_config = *movement_config;
+    }
+
     pub fn position(&self) -> Vec2 {
         self.position
     }
@@ -121,7 +129,7 @@ impl PhysicsController {
         let current_position = self.position();
         let target_distance = current_position.distance(current_target_position);
 
-        if target_distance <= MOVEMENT_ARRIVAL_DISTANCE {
+        if target_distance <= self.movement_config.arrival_distance() {
             // arrived!
             return None;
         }
@@ -153,6 +161,7 @@ impl PhysicsController {
         let current_velocity = self.velocity.get_vec2();
 
         let new_velocity = update(
+            &self.movement_config,
             current_position,
             current_velocity,
             current_direction,
@@ -172,6 +181,7 @@ impl PhysicsController {
 }
 
 fn update(
+    config: &MovementConfig,
     current_position: Vec2,
     current_velocity: Vec2,
     current_direction: Direction,
@@ -184,6 +194,7 @@ fn update(
     let mut output_velocity = current_velocity;
 
     let control_signal = find_steering(
+        config,
         current_position,
         current_velocity,
         current_direction,
@@ -192,13 +203,19 @@ fn update(
         axis_ray,
         axis_ray_nearest_point,
     );
-    apply_locomotion(control_signal, &mut output_velocity, last_acceleration);
-    apply_limitations(&mut output_velocity);
+    apply_locomotion(
+        config,
+        control_signal,
+        &mut output_velocity,
+        last_acceleration,
+    );
+    apply_limitations(config, &mut output_velocity);
 
     output_velocity
 }
 
 fn find_steering(
+    config: &MovementConfig,
     current_position: Vec2,
     current_velocity: Vec2,
     current_direction: Direction,
@@ -209,13 +226,13 @@ fn find_steering(
 ) -> Option<Direction> {
     let axis_distance_to_target = axis_ray_nearest_point.distance(target_position);
     if future_direction.is_none() {
-        // real_distance_to_target IS > MOVEMENT_ARRIVAL_DISTANCE, otherwise wouldn't be here
-        if axis_distance_to_target <= MOVEMENT_ARRIVAL_DISTANCE {
+        // real_distance_to_target IS > arrival distance, otherwise wouldn't be here
+        if axis_distance_to_target <= config.arrival_distance() {
             // we have overshot
             let target_offset = target_position - current_position;
             // let target_distance = target_offset.length();
             let target_direction = target_offset.normalize_or_zero();
-            let desired_velocity = target_direction * MOVEMENT_VELOCITY_MIN;
+            let desired_velocity = target_direction * config.min_velocity();
             let desired_acceleration = desired_velocity - current_velocity;
             return Some(Direction::from_coords(
                 desired_acceleration.x,
@@ -238,7 +255,7 @@ fn find_steering(
 
     // check if we should accelerate to axis
     let accelerate_to_axis = {
-        if distance_to_axis < MOVEMENT_STEERING_DEADZONE {
+        if distance_to_axis < config.steering_deadzone() {
             false
         } else {
             if left_speed_to_axis < 0.0 {
@@ -248,7 +265,7 @@ fn find_steering(
                 // currently moving towards axis
                 let left_speed_abs = left_speed.abs();
                 let ticks_to_target = distance_to_axis / left_speed_abs;
-                let tick_to_deacc = left_speed_abs / MOVEMENT_FRICTION;
+                let tick_to_deacc = left_speed_abs / config.friction();
                 if ticks_to_target > tick_to_deacc {
                     true
                 } else {
@@ -280,8 +297,8 @@ fn find_steering(
                 // currently moving towards target
                 let forward_speed_abs = forward_speed.abs();
                 let ticks_to_target = axis_distance_to_target / forward_speed_abs;
-                let tick_to_deacc = (forward_speed_abs - MOVEMENT_VELOCITY_MIN) / MOVEMENT_FRICTION;
-                // let tick_to_deacc = (forward_speed_abs) / MOVEMENT_FRICTION;
+                let tick_to_deacc = (forward_speed_abs - config.min_velocity()) / config.friction();
+                // let tick_to_deacc = (forward_speed_abs) / config.friction();
                 if ticks_to_target > tick_to_deacc {
                     true
                 } else {
@@ -376,6 +393,7 @@ fn find_steering(
 }
 
 fn apply_locomotion(
+    config: &MovementConfig,
     control_signal: Option<Direction>,
     velocity: &mut Vec2,
     last_acceleration: &mut Vec2,
@@ -384,7 +402,7 @@ fn apply_locomotion(
         // control signal exists, apply acceleration
         let (dx, dy) = control_signal.to_delta();
         let acceleration =
-            Vec2::new(dx as f32, dy as f32).normalize_or_zero() * MOVEMENT_ACCELERATION;
+            Vec2::new(dx as f32, dy as f32).normalize_or_zero() * config.acceleration();
 
         *velocity += acceleration;
 
@@ -395,7 +413,7 @@ fn apply_locomotion(
         let forward_speed = velocity.dot(forward_direction);
         if forward_speed < 0.0 {
             // currently moving backwards, apply friction
-            let friction = forward_direction * MOVEMENT_FRICTION;
+            let friction = forward_direction * config.friction();
             *velocity += friction;
         }
 
@@ -404,16 +422,16 @@ fn apply_locomotion(
         let left_speed = velocity.dot(left_direction);
         let friction = if left_speed < 0.0 {
             // currently moving left
-            left_direction * MOVEMENT_FRICTION
+            left_direction * config.friction()
         } else {
             // currently moving right
-            left_direction * MOVEMENT_FRICTION * -1.0
+            left_direction * config.friction() * -1.0
         };
         *velocity += friction;
     } else {
         // no control signal, apply friction
-        if velocity.length() > MOVEMENT_FRICTION {
-            let friction = velocity.normalize_or_zero() * MOVEMENT_FRICTION * -1.0;
+        if velocity.length() > config.friction() {
+            let friction = velocity.normalize_or_zero() * config.friction() * -1.0;
             *velocity += friction;
         } else {
             *velocity = Vec2::ZERO;
@@ -421,10 +439,11 @@ fn apply_locomotion(
     }
 }
 
-fn apply_limitations(velocity: &mut Vec2) {
+fn apply_limitations(config: &MovementConfig, velocity: &mut Vec2) {
     // limit max speed
-    if velocity.length() > MOVEMENT_VELOCITY_MAX {
-        *velocity = velocity.normalize_or_zero() * MOVEMENT_VELOCITY_MAX;
+    let max_velocity = config.max_velocity();
+    if velocity.length() > max_velocity {
+        *velocity = velocity.normalize_or_zero() * max_velocity;
     }
 }
 
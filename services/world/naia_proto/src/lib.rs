@@ -12,15 +12,16 @@ cfg_if! {
     }
 }
 
-pub use naia_bevy_shared::Protocol;
+pub use naia_bevy_shared::{LinkConditionerConfig, Protocol};
 
 pub mod behavior;
 pub mod channels;
 pub mod components;
 pub mod constants;
 pub mod messages;
+pub mod recording;
 pub mod resources;
 pub mod types;
 
 mod protocol;
-pub use protocol::protocol;
+pub use protocol::{conditioned_protocol, protocol};
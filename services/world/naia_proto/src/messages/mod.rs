@@ -1,9 +1,11 @@
 mod auth;
 mod entity_assignment;
+mod movement_checksum;
 mod player_command;
 
 pub use auth::Auth;
 pub use entity_assignment::EntityAssignment;
+pub use movement_checksum::MovementChecksum;
 pub use player_command::PlayerCommands;
 
 use naia_bevy_shared::{Protocol, ProtocolPlugin};
@@ -18,6 +20,7 @@ impl ProtocolPlugin for MessagesPlugin {
         protocol
             .add_message::<Auth>()
             .add_message::<EntityAssignment>()
+            .add_message::<MovementChecksum>()
             .add_message::<PlayerCommands>();
     }
 }
@@ -0,0 +1,19 @@
+use naia_bevy_shared::{EntityProperty, Message, Tick};
+
+// Sent periodically by the server so clients can check their confirmed simulation against it
+#[derive(Message)]
+pub struct MovementChecksum {
+    pub entity: EntityProperty,
+    pub tick: Tick,
+    pub checksum: u32,
+}
+
+impl MovementChecksum {
+    pub fn new(tick: Tick, checksum: u32) -> Self {
+        Self {
+            entity: EntityProperty::new(),
+            tick,
+            checksum,
+        }
+    }
+}
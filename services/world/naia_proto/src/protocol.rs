@@ -1,17 +1,26 @@
 use std::time::Duration;
 
-use naia_bevy_shared::Protocol;
+use naia_bevy_shared::{LinkConditionerConfig, Protocol};
 
-use crate::{channels::ChannelsPlugin, components::ComponentsPlugin, messages::MessagesPlugin};
+use crate::{
+    channels::ChannelsPlugin, components::ComponentsPlugin, constants::TICK_INTERVAL_MS,
+    messages::MessagesPlugin,
+};
 
 // Protocol Build
 pub fn protocol() -> Protocol {
+    conditioned_protocol(None)
+}
+
+// `link_conditioner` simulates latency, jitter & loss on incoming packets, overriding the
+// build's default conditioning
+pub fn conditioned_protocol(link_conditioner: Option<LinkConditionerConfig>) -> Protocol {
     let mut builder = Protocol::builder();
 
     // Config
     builder
         .rtc_endpoint("api/world_connect".to_string())
-        .tick_interval(Duration::from_millis(40))
+        .tick_interval(Duration::from_millis(TICK_INTERVAL_MS))
         // .enable_client_authoritative_entities()
         // Channels
         .add_plugin(ChannelsPlugin)
@@ -20,10 +29,13 @@ pub fn protocol() -> Protocol {
         // Components
         .add_plugin(ComponentsPlugin);
 
+    if let Some(link_conditioner) = link_conditioner {
+        builder.link_condition(link_conditioner);
+        return builder.build();
+    }
+
     cfg_if::cfg_if! {
         if #[cfg(feature = "local")]{
-            use naia_bevy_shared::LinkConditionerConfig;
-
             builder.link_condition(LinkConditionerConfig::new(50, 0, 0.0));
 
             //builder.link_condition(LinkConditionerConfig::very_good_condition());
@@ -0,0 +1,210 @@
+use naia_serde::{BitReader, FileBitWriter, SerdeErr, SerdeInternal as Serde};
+
+use asset_id::AssetId;
+
+use crate::{messages::PlayerCommands, types::Direction};
+
+// bumped whenever the layout below changes, old recordings are then rejected
+const RECORDING_VERSION: u8 = 2;
+
+// A unit's id is only meaningful within its recording
+pub type RecordedUnitId = u16;
+
+#[derive(Serde, Clone, PartialEq)]
+pub enum RecordedEvent {
+    // unit, asset, tile x, tile y, look direction
+    Spawn(RecordedUnitId, AssetId, i16, i16, Direction),
+    Despawn(RecordedUnitId),
+    // the server stops simulating a unit while its user is reconnecting
+    Hold(RecordedUnitId),
+    Resume(RecordedUnitId),
+    // unit, look direction, move direction
+    Command(RecordedUnitId, Option<Direction>, Option<Direction>),
+}
+
+impl RecordedEvent {
+    pub fn command(unit_id: RecordedUnitId, command: &PlayerCommands) -> Self {
+        Self::Command(unit_id, command.get_look(), command.get_move())
+    }
+}
+
+pub fn to_player_commands(
+    look_opt: Option<Direction>,
+    move_opt: Option<Direction>,
+) -> PlayerCommands {
+    let mut command = PlayerCommands::new();
+    if let Some(look_dir) = look_opt {
+        command.set_look(look_dir);
+    }
+    if let Some(move_dir) = move_opt {
+        command.set_move(move_dir);
+    }
+    command
+}
+
+#[derive(Serde, Clone, PartialEq)]
+pub struct RecordedTick {
+    // ticks elapsed since the start of the recording
+    pub offset: u32,
+    pub events: Vec<RecordedEvent>,
+}
+
+// A recording is its version byte followed by a run of chunks, each prefixed with its length in
+// bytes (u32, little endian). This lets the server append ticks to the file as the match runs.
+#[derive(Serde, Clone, PartialEq)]
+pub enum RecordingChunk {
+    Tick(RecordedTick),
+    // total length of the recording in ticks, written once the match ends
+    End(u32),
+}
+
+impl RecordingChunk {
+    // written once, before any chunk
+    pub fn header_bytes() -> Vec<u8> {
+        vec![RECORDING_VERSION]
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bit_writer = FileBitWriter::new();
+        self.ser(&mut bit_writer);
+        let chunk_bytes = bit_writer.to_vec();
+
+        let mut bytes = (chunk_bytes.len() as u32).to_le_bytes().to_vec();
+        bytes.extend(chunk_bytes);
+        bytes
+    }
+}
+
+// Everything needed to re-simulate a match: which units existed when, and which commands the
+// server accepted for them on each tick. Ticks on which nothing happened are left out.
+#[derive(Clone, PartialEq)]
+pub struct MatchRecording {
+    tick_count: u32,
+    ticks: Vec<RecordedTick>,
+}
+
+impl MatchRecording {
+    pub fn new() -> Self {
+        Self {
+            tick_count: 0,
+            ticks: Vec::new(),
+        }
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SerdeErr> {
+        let Some((version, mut bytes)) = bytes.split_first() else {
+            return Err(SerdeErr);
+        };
+        if *version != RECORDING_VERSION {
+            return Err(SerdeErr);
+        }
+
+        let mut me = Self::new();
+        // a recording cut off mid-chunk (the server stopped before the match ended) still plays
+        // back up to its last whole chunk
+        while bytes.len() >= 4 {
+            let (length_bytes, rest) = bytes.split_at(4);
+            let length = u32::from_le_bytes(length_bytes.try_into().unwrap()) as usize;
+            if rest.len() < length {
+                break;
+            }
+            let (chunk_bytes, rest) = rest.split_at(length);
+            bytes = rest;
+
+            let mut bit_reader = BitReader::new(chunk_bytes);
+            match RecordingChunk::de(&mut bit_reader)? {
+                RecordingChunk::Tick(tick) => {
+                    me.tick_count = tick.offset + 1;
+                    me.ticks.push(tick);
+                }
+                RecordingChunk::End(tick_count) => {
+                    me.tick_count = tick_count;
+                    break;
+                }
+            }
+        }
+        Ok(me)
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = RecordingChunk::header_bytes();
+        for tick in &self.ticks {
+            bytes.extend(RecordingChunk::Tick(tick.clone()).to_bytes());
+        }
+        bytes.extend(RecordingChunk::End(self.tick_count).to_bytes());
+        bytes
+    }
+
+    // total length of the recording, in ticks
+    pub fn tick_count(&self) -> u32 {
+        self.tick_count
+    }
+
+    pub fn ticks(&self) -> &Vec<RecordedTick> {
+        &self.ticks
+    }
+
+    // advances the recording by one tick, events recorded afterwards land on the new tick
+    pub fn next_tick(&mut self) {
+        self.tick_count += 1;
+    }
+
+    pub fn record(&mut self, event: RecordedEvent) {
+        let offset = self.tick_count;
+        if let Some(last_tick) = self.ticks.last_mut() {
+            if last_tick.offset == offset {
+                last_tick.events.push(event);
+                return;
+            }
+        }
+        self.ticks.push(RecordedTick {
+            offset,
+            events: vec![event],
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recording() -> MatchRecording {
+        let mut recording = MatchRecording::new();
+        recording.record(RecordedEvent::Hold(0));
+        recording.next_tick();
+        recording.next_tick();
+        recording.record(RecordedEvent::Resume(0));
+        recording.record(RecordedEvent::Despawn(1));
+        recording.next_tick();
+        recording.next_tick();
+        recording
+    }
+
+    #[test]
+    fn roundtrip() {
+        let recording = recording();
+        let read = MatchRecording::from_bytes(&recording.to_bytes()).unwrap();
+        assert!(read == recording);
+        assert_eq!(read.tick_count(), 4);
+        assert_eq!(read.ticks().len(), 2);
+    }
+
+    #[test]
+    fn cut_off_recording_keeps_its_whole_ticks() {
+        let mut bytes = recording().to_bytes();
+        // drop the end chunk, and part of the last tick
+        let end_length = RecordingChunk::End(4).to_bytes().len();
+        bytes.truncate(bytes.len() - end_length - 1);
+
+        let read = MatchRecording::from_bytes(&bytes).unwrap();
+        assert_eq!(read.ticks().len(), 1);
+        assert_eq!(read.tick_count(), 1);
+    }
+
+    #[test]
+    fn other_versions_are_rejected() {
+        let mut bytes = recording().to_bytes();
+        bytes[0] = RECORDING_VERSION + 1;
+        assert!(MatchRecording::from_bytes(&bytes).is_err());
+    }
+}
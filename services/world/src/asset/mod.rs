@@ -6,3 +6,6 @@ pub use asset_manager::*;
 
 mod asset_commands;
 pub use asset_commands::*;
+
+mod spec_store;
+pub use spec_store::*;
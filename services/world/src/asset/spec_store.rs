@@ -0,0 +1,188 @@
+use std::collections::{HashMap, HashSet};
+
+use bevy_ecs::{
+    prelude::{Query, Resource, With},
+    system::ResMut,
+};
+
+use naia_bevy_server::Server;
+
+use asset_id::{AssetId, AssetType};
+use asset_server_http_proto::{AssetRequest, AssetResponse, AssetResponseValue};
+use bevy_http_client::{HttpClient, ResponseKey};
+use logging::{info, warn};
+use spec::MovementConfig;
+use spec_serde::bits::{MovementConfigBits, UnitBits};
+
+use world_server_naia_proto::components::{AssetEntry, AssetRef, Main, PhysicsController};
+
+// SpecStore
+// fetches the gameplay specs (units & their movement configs) that the simulation depends on
+#[derive(Resource)]
+pub struct SpecStore {
+    asset_server_opt: Option<(String, u16)>,
+    // assets which still need to be fetched from the asset server
+    wanted_assets: HashSet<AssetId>,
+    in_flight_requests: HashMap<AssetId, ResponseKey<AssetResponse>>,
+    unit_to_movement_config_map: HashMap<AssetId, AssetId>,
+    movement_configs: HashMap<AssetId, MovementConfig>,
+}
+
+impl Default for SpecStore {
+    fn default() -> Self {
+        Self {
+            asset_server_opt: None,
+            wanted_assets: HashSet::new(),
+            in_flight_requests: HashMap::new(),
+            unit_to_movement_config_map: HashMap::new(),
+            movement_configs: HashMap::new(),
+        }
+    }
+}
+
+impl SpecStore {
+    // Asset Server
+
+    pub fn set_asset_server(&mut self, addr: &str, port: u16) {
+        self.asset_server_opt = Some((addr.to_string(), port));
+    }
+
+    pub fn clear_asset_server(&mut self) {
+        self.asset_server_opt = None;
+
+        // responses will never arrive, so re-request once a new asset server is available
+        for (asset_id, _) in std::mem::take(&mut self.in_flight_requests) {
+            self.wanted_assets.insert(asset_id);
+        }
+    }
+
+    // Units
+
+    pub fn load_unit(&mut self, unit_asset_id: &AssetId) {
+        if self.unit_to_movement_config_map.contains_key(unit_asset_id) {
+            return;
+        }
+        if self.in_flight_requests.contains_key(unit_asset_id) {
+            return;
+        }
+        self.wanted_assets.insert(*unit_asset_id);
+    }
+
+    pub fn get_unit_movement_config(&self, unit_asset_id: &AssetId) -> Option<&MovementConfig> {
+        let movement_config_asset_id = self.unit_to_movement_config_map.get(unit_asset_id)?;
+        self.movement_configs.get(movement_config_asset_id)
+    }
+
+    fn update(&mut self, http_client: &mut HttpClient) {
+        let Some((asset_server_addr, asset_server_port)) = self.asset_server_opt.clone() else {
+            // it's okay to wait until the asset server is available
+            return;
+        };
+
+        // send requests
+        for asset_id in std::mem::take(&mut self.wanted_assets) {
+            info!("requesting spec asset from asset server: {:?}", asset_id);
+            let request = AssetRequest::new(asset_id, None);
+            let key = http_client.send(&asset_server_addr, asset_server_port, request);
+            self.in_flight_requests.insert(asset_id, key);
+        }
+
+        // receive responses
+        let mut received = Vec::new();
+        for (asset_id, response_key) in self.in_flight_requests.iter() {
+            if let Some(result) = http_client.recv(response_key) {
+                received.push((*asset_id, result));
+            }
+        }
+        for (asset_id, result) in received {
+            self.in_flight_requests.remove(&asset_id);
+
+            match result {
+                Ok(response) => {
+                    let AssetResponseValue::Modified(_etag, asset_type, _dependencies, data) =
+                        response.value
+                    else {
+                        // we never send an etag, so this shouldn't happen
+                        warn!("unexpected NotModified response for asset: {:?}", asset_id);
+                        continue;
+                    };
+                    self.recv_asset_data(asset_id, asset_type, &data);
+                }
+                Err(error) => {
+                    warn!(
+                        "failed to fetch spec asset: {:?}, error: {}",
+                        asset_id,
+                        error.to_string()
+                    );
+                    self.wanted_assets.insert(asset_id);
+                }
+            }
+        }
+    }
+
+    fn recv_asset_data(&mut self, asset_id: AssetId, asset_type: AssetType, data: &[u8]) {
+        match asset_type {
+            AssetType::Unit => {
+                let Ok(unit) = UnitBits::from_bytes(data) else {
+                    warn!("unable to parse unit: {:?}", asset_id);
+                    return;
+                };
+                let movement_config_asset_id = unit.get_movement_config_asset_id();
+                self.unit_to_movement_config_map
+                    .insert(asset_id, movement_config_asset_id);
+                if !self
+                    .movement_configs
+                    .contains_key(&movement_config_asset_id)
+                    && !self
+                        .in_flight_requests
+                        .contains_key(&movement_config_asset_id)
+                {
+                    self.wanted_assets.insert(movement_config_asset_id);
+                }
+            }
+            AssetType::MovementConfig => {
+                let Ok(movement_config) = MovementConfigBits::from_bytes(data) else {
+                    warn!("unable to parse movement config: {:?}", asset_id);
+                    return;
+                };
+                info!("loaded movement config: {:?}", asset_id);
+                self.movement_configs
+                    .insert(asset_id, movement_config.into());
+            }
+            _ => {
+                warn!("unexpected spec asset type: {:?}", asset_type);
+            }
+        }
+    }
+}
+
+// Systems
+
+pub fn update(mut spec_store: ResMut<SpecStore>, mut http_client: ResMut<HttpClient>) {
+    spec_store.update(&mut http_client);
+}
+
+// requests the specs of every spawned unit, which the tick waits on before simulating it
+pub fn request_unit_specs(
+    server: Server,
+    mut spec_store: ResMut<SpecStore>,
+    asset_entry_q: Query<&AssetEntry>,
+    unit_q: Query<&AssetRef<Main>, With<PhysicsController>>,
+) {
+    for asset_ref in unit_q.iter() {
+        let Some(asset_entry_entity) = asset_ref.asset_id_entity.get(&server) else {
+            continue;
+        };
+        let Ok(asset_entry) = asset_entry_q.get(asset_entry_entity) else {
+            continue;
+        };
+        let unit_asset_id = *asset_entry.asset_id;
+
+        if spec_store
+            .get_unit_movement_config(&unit_asset_id)
+            .is_none()
+        {
+            spec_store.load_unit(&unit_asset_id);
+        }
+    }
+}
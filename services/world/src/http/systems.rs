@@ -1,21 +1,27 @@
 use std::net::SocketAddr;
 
-use bevy_ecs::change_detection::ResMut;
+use bevy_ecs::{change_detection::ResMut, system::Res};
 
 use naia_bevy_server::Server;
 
 use bevy_http_client::ResponseError;
 use bevy_http_server::HttpServer;
-use config::{REGION_SERVER_SECRET, SELF_BINDING_ADDR, WORLD_SERVER_HTTP_PORT};
+use config::{region_server_secret, self_binding_addr, world_server_http_port};
 use logging::{info, warn};
-use world_server_http_proto::{WorldConnectRequest, WorldConnectResponse};
+use world_server_http_proto::{
+    WorldConnectRequest, WorldConnectResponse, WorldSpectateLeaveRequest,
+    WorldSpectateLeaveResponse, WorldSpectateRequest, WorldSpectateResponse,
+};
 
 use crate::{social::LobbyManager, user::UserManager};
 
 pub fn init(mut server: ResMut<HttpServer>) {
     info!("World HTTP Server starting up");
 
-    let socket_addr = SocketAddr::new(SELF_BINDING_ADDR.parse().unwrap(), WORLD_SERVER_HTTP_PORT);
+    let socket_addr = SocketAddr::new(
+        self_binding_addr().parse().unwrap(),
+        world_server_http_port(),
+    );
     server.listen(socket_addr);
 }
 
@@ -26,7 +32,7 @@ pub fn recv_world_connect_request(
     mut naia_server: Server,
 ) {
     while let Some((_addr, request, response_key)) = http_server.receive::<WorldConnectRequest>() {
-        if request.region_secret() != REGION_SERVER_SECRET {
+        if request.region_secret() != region_server_secret() {
             warn!("invalid request secret");
             http_server.respond(response_key, Err(ResponseError::Unauthenticated));
             continue;
@@ -40,10 +46,85 @@ pub fn recv_world_connect_request(
 
         user_manager.recv_login_token(&request.lobby_id(), request.login_tokens());
         let lobby_room_key = naia_server.make_room().key();
-        lobby_manager.insert_lobby_room_key(request.lobby_id(), lobby_room_key);
+        lobby_manager.start_match(request.lobby_id(), lobby_room_key);
 
         info!("Sending login response to region server ..");
 
         http_server.respond(response_key, Ok(WorldConnectResponse::new()));
     }
 }
+
+pub fn recv_world_spectate_request(
+    lobby_manager: Res<LobbyManager>,
+    mut user_manager: ResMut<UserManager>,
+    mut http_server: ResMut<HttpServer>,
+) {
+    while let Some((_addr, request, response_key)) = http_server.receive::<WorldSpectateRequest>() {
+        if request.region_secret() != region_server_secret() {
+            warn!("invalid request secret");
+            http_server.respond(response_key, Err(ResponseError::Unauthenticated));
+            continue;
+        }
+
+        let lobby_id = request.lobby_id();
+        if lobby_manager.lobby_room_key(&lobby_id).is_none() {
+            warn!(
+                "spectate request for match not running here: {:?}",
+                lobby_id
+            );
+            http_server.respond(response_key, Err(ResponseError::NotFound));
+            continue;
+        }
+
+        info!(
+            "Spectate request received from region server: (lobby_id: {:?}, user_id: {:?})",
+            lobby_id,
+            request.user_id()
+        );
+
+        user_manager.recv_spectator_login_token(
+            &lobby_id,
+            request.session_server(),
+            &request.user_id(),
+            request.login_token(),
+        );
+
+        http_server.respond(response_key, Ok(WorldSpectateResponse::new()));
+    }
+}
+
+pub fn recv_world_spectate_leave_request(
+    lobby_manager: Res<LobbyManager>,
+    mut user_manager: ResMut<UserManager>,
+    mut http_server: ResMut<HttpServer>,
+    mut naia_server: Server,
+) {
+    while let Some((_addr, request, response_key)) =
+        http_server.receive::<WorldSpectateLeaveRequest>()
+    {
+        if request.region_secret() != region_server_secret() {
+            warn!("invalid request secret");
+            http_server.respond(response_key, Err(ResponseError::Unauthenticated));
+            continue;
+        }
+
+        let lobby_id = request.lobby_id();
+
+        info!(
+            "Spectate leave request received from region server: (lobby_id: {:?}, user_id: {:?})",
+            lobby_id,
+            request.user_id()
+        );
+
+        // a spectator who hasn't connected yet, or is within their reconnect grace, is just forgotten
+        if let Some(user_key) = user_manager.remove_spectator(&lobby_id, &request.user_id()) {
+            if let Some(lobby_room_key) = lobby_manager.lobby_room_key(&lobby_id) {
+                naia_server.room_mut(&lobby_room_key).remove_user(&user_key);
+            }
+            // the client returns to the main menu when its world connection drops
+            naia_server.user_mut(&user_key).disconnect();
+        }
+
+        http_server.respond(response_key, Ok(WorldSpectateLeaveResponse::new()));
+    }
+}
@@ -0,0 +1,116 @@
+use bevy_ecs::system::Resource;
+
+// InterestManager
+// decides which positioned entities fall within a user's area of interest
+#[derive(Resource)]
+pub struct InterestManager {
+    enter_radius: i16,
+    exit_radius: i16,
+    chunk_size: i16,
+}
+
+impl InterestManager {
+    pub fn new(enter_radius: i16, exit_hysteresis: i16, chunk_size: i16) -> Self {
+        if enter_radius < 0 || exit_hysteresis < 0 {
+            panic!("interest radius & hysteresis must not be negative");
+        }
+        if chunk_size <= 0 {
+            panic!("interest chunk size must be positive");
+        }
+
+        Self {
+            enter_radius,
+            exit_radius: enter_radius + exit_hysteresis,
+            chunk_size,
+        }
+    }
+
+    // `viewer_tile` is the tile of the user's own entity, `target_tile` is the tile of the
+    // entity being checked. Entities without a tile position are always in scope.
+    pub fn should_be_in_scope(
+        &self,
+        viewer_tile_opt: Option<(i16, i16)>,
+        target_tile_opt: Option<(i16, i16)>,
+        currently_in_scope: bool,
+    ) -> bool {
+        let Some(target_tile) = target_tile_opt else {
+            // not a positioned entity (asset entries, etc.)
+            return true;
+        };
+        let Some(viewer_tile) = viewer_tile_opt else {
+            // user doesn't have a position yet, so nothing positioned is relevant
+            return false;
+        };
+
+        if self.chunk(viewer_tile) == self.chunk(target_tile) {
+            return true;
+        }
+
+        let distance = tile_distance(viewer_tile, target_tile);
+        if currently_in_scope {
+            distance <= self.exit_radius
+        } else {
+            distance <= self.enter_radius
+        }
+    }
+
+    fn chunk(&self, (tile_x, tile_y): (i16, i16)) -> (i16, i16) {
+        (
+            tile_x.div_euclid(self.chunk_size),
+            tile_y.div_euclid(self.chunk_size),
+        )
+    }
+}
+
+// chebyshev distance, since diagonal movement costs the same as orthogonal
+fn tile_distance((ax, ay): (i16, i16), (bx, by): (i16, i16)) -> i16 {
+    let dx = (ax as i32 - bx as i32).abs();
+    let dy = (ay as i32 - by as i32).abs();
+    dx.max(dy).min(i16::MAX as i32) as i16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIEWER: (i16, i16) = (4, 4);
+
+    fn interest_manager() -> InterestManager {
+        InterestManager::new(8, 2, 8)
+    }
+
+    #[test]
+    fn enters_at_the_enter_radius() {
+        let interest = interest_manager();
+        assert!(interest.should_be_in_scope(Some(VIEWER), Some((12, 4)), false));
+        assert!(interest.should_be_in_scope(Some(VIEWER), Some((-4, -4)), false));
+        assert!(!interest.should_be_in_scope(Some(VIEWER), Some((13, 4)), false));
+        assert!(!interest.should_be_in_scope(Some(VIEWER), Some((4, -5)), false));
+    }
+
+    #[test]
+    fn exits_past_the_enter_radius_plus_hysteresis() {
+        let interest = interest_manager();
+        assert!(interest.should_be_in_scope(Some(VIEWER), Some((13, 4)), true));
+        assert!(interest.should_be_in_scope(Some(VIEWER), Some((14, 14)), true));
+        assert!(!interest.should_be_in_scope(Some(VIEWER), Some((15, 4)), true));
+        assert!(!interest.should_be_in_scope(Some(VIEWER), Some((4, -7)), true));
+    }
+
+    #[test]
+    fn same_chunk_is_always_in_scope() {
+        let interest = InterestManager::new(2, 1, 16);
+        assert!(interest.should_be_in_scope(Some((0, 0)), Some((15, 15)), false));
+        assert!(!interest.should_be_in_scope(Some((0, 0)), Some((16, 0)), true));
+        // chunks don't straddle the origin
+        assert!(!interest.should_be_in_scope(Some((0, 0)), Some((-4, 0)), false));
+    }
+
+    #[test]
+    fn unpositioned_entities() {
+        let interest = interest_manager();
+        assert!(interest.should_be_in_scope(None, None, false));
+        assert!(interest.should_be_in_scope(Some(VIEWER), None, false));
+        assert!(!interest.should_be_in_scope(None, Some(VIEWER), true));
+    }
+}
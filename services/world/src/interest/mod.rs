@@ -0,0 +1,5 @@
+mod plugin;
+pub use plugin::*;
+
+mod interest_manager;
+pub use interest_manager::*;
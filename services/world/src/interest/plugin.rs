@@ -0,0 +1,20 @@
+use bevy_app::{App, Plugin};
+
+use config::{interest_chunk_size, interest_enter_radius, interest_exit_hysteresis};
+
+use crate::interest::InterestManager;
+
+pub struct InterestPlugin;
+
+impl Plugin for InterestPlugin {
+    fn build(&self, app: &mut App) {
+        // the config validates that these fit in a tile coordinate on load
+        let interest_manager = InterestManager::new(
+            interest_enter_radius() as i16,
+            interest_exit_hysteresis() as i16,
+            interest_chunk_size() as i16,
+        );
+
+        app.insert_resource(interest_manager);
+    }
+}
@@ -11,5 +11,5 @@ pub(crate) fn startup(mut naia_server: Server, mut lobby_manager: ResMut<LobbyMa
     let lobby_id = LobbyId::new(1);
     let lobby_room_key = naia_server.make_room().key();
 
-    lobby_manager.insert_lobby_room_key(lobby_id, lobby_room_key)
+    lobby_manager.start_endless_match(lobby_id, lobby_room_key)
 }
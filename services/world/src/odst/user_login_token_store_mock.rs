@@ -22,6 +22,20 @@ impl UserLoginTokenStore {
         panic!("ODST mode does not support this method");
     }
 
+    pub fn recv_spectator_login_token(
+        &mut self,
+        _lobby_id: &LobbyId,
+        _session_server: (&str, u16),
+        _user_id: &UserId,
+        _token: &str,
+    ) {
+        panic!("ODST mode does not support this method");
+    }
+
+    pub fn remove_spectator_login_token(&mut self, _lobby_id: &LobbyId, _user_id: &UserId) {
+        //
+    }
+
     pub fn spend_login_token(&mut self, token: &str) -> Option<UserData> {
         let tokens: Vec<String> = token.split(":").map(|s| s.to_string()).collect();
         if tokens[0].eq_ignore_ascii_case("odst") {
@@ -29,10 +43,11 @@ impl UserLoginTokenStore {
             // info!("ODST mode spend_login_token user_id_u64: {}", user_id_u64);
             let user_id = UserId::new(user_id_u64);
             let lobby_id = LobbyId::new(1);
-            let session_addr = config::SESSION_SERVER_RECV_ADDR.to_string();
-            let session_port = config::SESSION_SERVER_HTTP_PORT;
+            let session_addr = config::session_server_recv_addr().to_string();
+            let session_port = config::session_server_http_port();
 
             Some(UserData::new(
+                token,
                 &session_addr,
                 session_port,
                 user_id,
@@ -0,0 +1,260 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs::{self, File},
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::mpsc::{self, Receiver, Sender},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use bevy_ecs::{entity::Entity, system::Resource};
+
+use asset_id::AssetId;
+use logging::{info, warn};
+use social_server_types::LobbyId;
+
+use world_server_naia_proto::{
+    messages::PlayerCommands,
+    recording::{RecordedEvent, RecordedTick, RecordedUnitId, RecordingChunk},
+    types::Direction,
+};
+
+struct RecordedUnit {
+    lobby_id: LobbyId,
+    unit_id: RecordedUnitId,
+    held: bool,
+}
+
+struct RecordingData {
+    tick_count: u32,
+    // events recorded on the current tick, handed to the writer once the tick finishes
+    events: Vec<RecordedEvent>,
+    next_unit_id: RecordedUnitId,
+}
+
+impl RecordingData {
+    fn new() -> Self {
+        Self {
+            tick_count: 0,
+            events: Vec::new(),
+            next_unit_id: 0,
+        }
+    }
+
+    fn record(&mut self, event: RecordedEvent) {
+        self.events.push(event);
+    }
+}
+
+enum WriterMessage {
+    Start(LobbyId, PathBuf),
+    Chunk(LobbyId, RecordingChunk),
+    Finish(LobbyId),
+}
+
+// MatchRecorder
+// logs every running match tick by tick, so it can be played back on the client later
+#[derive(Resource)]
+pub struct MatchRecorder {
+    output_dir: PathBuf,
+    recordings: HashMap<LobbyId, RecordingData>,
+    units: HashMap<Entity, RecordedUnit>,
+    writer: Sender<WriterMessage>,
+}
+
+impl MatchRecorder {
+    pub fn new(output_dir: PathBuf) -> Self {
+        // recordings are appended to on every tick, so the file IO is kept off the main loop
+        let (writer, receiver) = mpsc::channel();
+        std::thread::Builder::new()
+            .name("match_recorder".to_owned())
+            .spawn(move || write_recordings(receiver))
+            .expect("Failed to spawn match_recorder thread");
+
+        Self {
+            output_dir,
+            recordings: HashMap::new(),
+            units: HashMap::new(),
+            writer,
+        }
+    }
+
+    pub fn is_recording_unit(&self, entity: &Entity) -> bool {
+        self.units.contains_key(entity)
+    }
+
+    // called for each unit the server simulates this tick, before it is simulated
+    pub fn record_unit_tick(
+        &mut self,
+        lobby_id: &LobbyId,
+        entity: &Entity,
+        spawn_state: Option<(AssetId, (i16, i16), Direction)>,
+        command_opt: Option<&PlayerCommands>,
+    ) {
+        let recording_data = self.recordings.entry(*lobby_id).or_insert_with(|| {
+            let started_secs = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_secs())
+                .unwrap_or_default();
+            let file_name = format!("match_{}_{}.rec", lobby_id.to_u16(), started_secs);
+            let file_path = self.output_dir.join(file_name);
+            let _ = self.writer.send(WriterMessage::Start(*lobby_id, file_path));
+
+            RecordingData::new()
+        });
+
+        let unit_id = if let Some(unit) = self.units.get_mut(entity) {
+            if unit.held {
+                unit.held = false;
+                recording_data.record(RecordedEvent::Resume(unit.unit_id));
+            }
+            unit.unit_id
+        } else {
+            let Some((asset_id, (tile_x, tile_y), look_dir)) = spawn_state else {
+                // can't be replayed until its asset is known
+                return;
+            };
+            let unit_id = recording_data.next_unit_id;
+            recording_data.next_unit_id = recording_data.next_unit_id.wrapping_add(1);
+            recording_data.record(RecordedEvent::Spawn(
+                unit_id, asset_id, tile_x, tile_y, look_dir,
+            ));
+            self.units.insert(
+                *entity,
+                RecordedUnit {
+                    lobby_id: *lobby_id,
+                    unit_id,
+                    held: false,
+                },
+            );
+            unit_id
+        };
+
+        if let Some(command) = command_opt {
+            recording_data.record(RecordedEvent::command(unit_id, command));
+        }
+    }
+
+    // called once all units have been simulated for the tick
+    pub fn finish_tick(
+        &mut self,
+        simulated_units: &HashSet<Entity>,
+        unit_exists: impl Fn(&Entity) -> bool,
+    ) {
+        let mut despawned_units = Vec::new();
+
+        for (entity, unit) in self.units.iter_mut() {
+            if simulated_units.contains(entity) {
+                continue;
+            }
+            let Some(recording_data) = self.recordings.get_mut(&unit.lobby_id) else {
+                continue;
+            };
+            if !unit_exists(entity) {
+                recording_data.record(RecordedEvent::Despawn(unit.unit_id));
+                despawned_units.push(*entity);
+            } else if !unit.held {
+                unit.held = true;
+                recording_data.record(RecordedEvent::Hold(unit.unit_id));
+            }
+        }
+
+        for entity in despawned_units {
+            self.units.remove(&entity);
+        }
+
+        for (lobby_id, recording_data) in self.recordings.iter_mut() {
+            if !recording_data.events.is_empty() {
+                let tick = RecordedTick {
+                    offset: recording_data.tick_count,
+                    events: std::mem::take(&mut recording_data.events),
+                };
+                let _ = self
+                    .writer
+                    .send(WriterMessage::Chunk(*lobby_id, RecordingChunk::Tick(tick)));
+            }
+            recording_data.tick_count += 1;
+        }
+    }
+
+    // closes out the match's recording, called when the match ends
+    pub fn finish_recording(&mut self, lobby_id: &LobbyId) {
+        self.units.retain(|_, unit| unit.lobby_id != *lobby_id);
+
+        let Some(recording_data) = self.recordings.remove(lobby_id) else {
+            return;
+        };
+
+        let end = RecordingChunk::End(recording_data.tick_count);
+        let _ = self.writer.send(WriterMessage::Chunk(*lobby_id, end));
+        let _ = self.writer.send(WriterMessage::Finish(*lobby_id));
+    }
+}
+
+struct RecordingFile {
+    path: PathBuf,
+    // None once a write has failed, the rest of the recording is then dropped
+    writer: Option<BufWriter<File>>,
+}
+
+// runs on the match_recorder thread until the MatchRecorder is dropped
+fn write_recordings(receiver: Receiver<WriterMessage>) {
+    let mut files: HashMap<LobbyId, RecordingFile> = HashMap::new();
+
+    while let Ok(message) = receiver.recv() {
+        match message {
+            WriterMessage::Start(lobby_id, path) => {
+                let writer = start_file(&path);
+                files.insert(lobby_id, RecordingFile { path, writer });
+            }
+            WriterMessage::Chunk(lobby_id, chunk) => {
+                let Some(file) = files.get_mut(&lobby_id) else {
+                    continue;
+                };
+                let Some(writer) = file.writer.as_mut() else {
+                    continue;
+                };
+                if let Err(e) = writer.write_all(&chunk.to_bytes()) {
+                    warn!("failed to write match recording {:?}: {:?}", file.path, e);
+                    file.writer = None;
+                }
+            }
+            WriterMessage::Finish(lobby_id) => {
+                let Some(file) = files.remove(&lobby_id) else {
+                    continue;
+                };
+                let Some(mut writer) = file.writer else {
+                    continue;
+                };
+                match writer.flush() {
+                    Ok(()) => info!(
+                        "match recording saved - [lobbyid {:?}, path {:?}]",
+                        lobby_id, file.path
+                    ),
+                    Err(e) => warn!("failed to save match recording {:?}: {:?}", file.path, e),
+                }
+            }
+        }
+    }
+}
+
+fn start_file(path: &Path) -> Option<BufWriter<File>> {
+    if let Some(output_dir) = path.parent() {
+        if let Err(e) = fs::create_dir_all(output_dir) {
+            warn!("failed to create recordings directory: {:?}", e);
+            return None;
+        }
+    }
+    let mut writer = match File::create(path) {
+        Ok(file) => BufWriter::new(file),
+        Err(e) => {
+            warn!("failed to create match recording {:?}: {:?}", path, e);
+            return None;
+        }
+    };
+    if let Err(e) = writer.write_all(&RecordingChunk::header_bytes()) {
+        warn!("failed to write match recording {:?}: {:?}", path, e);
+        return None;
+    }
+    Some(writer)
+}
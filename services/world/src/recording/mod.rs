@@ -0,0 +1,5 @@
+mod plugin;
+pub use plugin::*;
+
+mod match_recorder;
+pub use match_recorder::*;
@@ -0,0 +1,18 @@
+use std::path::PathBuf;
+
+use bevy_app::{App, Plugin};
+
+use config::world_server_recordings_path;
+
+use crate::recording::MatchRecorder;
+
+pub struct RecordingPlugin;
+
+impl Plugin for RecordingPlugin {
+    fn build(&self, app: &mut App) {
+        // where finished matches are written, one file per match
+        let output_dir = PathBuf::from(world_server_recordings_path());
+
+        app.insert_resource(MatchRecorder::new(output_dir));
+    }
+}
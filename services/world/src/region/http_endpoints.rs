@@ -2,19 +2,22 @@ use bevy_ecs::change_detection::ResMut;
 
 use bevy_http_client::{ApiRequest, ApiResponse, HttpClient, ResponseError};
 use bevy_http_server::HttpServer;
-use config::REGION_SERVER_SECRET;
+use config::region_server_secret;
 use logging::warn;
 use region_server_http_proto::WorldRegisterInstanceResponse;
-use world_server_http_proto::{HeartbeatRequest, HeartbeatResponse};
+use world_server_http_proto::{
+    ConnectAssetServerRequest, ConnectAssetServerResponse, DisconnectAssetServerRequest,
+    DisconnectAssetServerResponse, HeartbeatRequest, HeartbeatResponse,
+};
 
-use crate::region::RegionManager;
+use crate::{asset::SpecStore, region::RegionManager};
 
 pub fn recv_heartbeat_request(
     mut region_manager: ResMut<RegionManager>,
     mut server: ResMut<HttpServer>,
 ) {
     while let Some((_addr, request, response_key)) = server.receive::<HeartbeatRequest>() {
-        if request.region_secret() != REGION_SERVER_SECRET {
+        if request.region_secret() != region_server_secret() {
             warn!("invalid request secret");
             server.respond(response_key, Err(ResponseError::Unauthenticated));
             continue;
@@ -63,3 +66,61 @@ pub fn recv_register_instance_response(
         }
     }
 }
+
+pub fn recv_connect_asset_server_request(
+    mut spec_store: ResMut<SpecStore>,
+    mut region_manager: ResMut<RegionManager>,
+    mut server: ResMut<HttpServer>,
+) {
+    while let Some((_addr, request, response_key)) = server.receive::<ConnectAssetServerRequest>() {
+        if request.region_secret() != region_server_secret() {
+            warn!("invalid request secret");
+            server.respond(response_key, Err(ResponseError::Unauthenticated));
+            continue;
+        }
+
+        let host = "world";
+        let remote = "region";
+        bevy_http_client::log_util::recv_req(host, remote, ConnectAssetServerRequest::name());
+
+        // setting last heard
+        region_manager.heard_from_region_server();
+
+        // store asset server details
+        spec_store.set_asset_server(request.http_addr(), request.http_port());
+
+        // responding
+        bevy_http_client::log_util::send_res(host, ConnectAssetServerResponse::name());
+        server.respond(response_key, Ok(ConnectAssetServerResponse));
+    }
+}
+
+pub fn recv_disconnect_asset_server_request(
+    mut spec_store: ResMut<SpecStore>,
+    mut region_manager: ResMut<RegionManager>,
+    mut server: ResMut<HttpServer>,
+) {
+    while let Some((_addr, request, response_key)) =
+        server.receive::<DisconnectAssetServerRequest>()
+    {
+        if request.region_secret() != region_server_secret() {
+            warn!("invalid request secret");
+            server.respond(response_key, Err(ResponseError::Unauthenticated));
+            continue;
+        }
+
+        let host = "world";
+        let remote = "region";
+        bevy_http_client::log_util::recv_req(host, remote, DisconnectAssetServerRequest::name());
+
+        // setting last heard
+        region_manager.heard_from_region_server();
+
+        // erase asset server details
+        spec_store.clear_asset_server();
+
+        // responding
+        bevy_http_client::log_util::send_res(host, DisconnectAssetServerResponse::name());
+        server.respond(response_key, Ok(DisconnectAssetServerResponse));
+    }
+}
@@ -18,6 +18,8 @@ impl Plugin for RegionPlugin {
             (
                 http_endpoints::recv_heartbeat_request,
                 http_endpoints::recv_register_instance_response,
+                http_endpoints::recv_connect_asset_server_request,
+                http_endpoints::recv_disconnect_asset_server_request,
                 systems::send_register_instance_request,
                 systems::process_region_server_disconnect,
             ),
@@ -2,8 +2,8 @@ use bevy_ecs::change_detection::{Res, ResMut};
 
 use bevy_http_client::HttpClient;
 use config::{
-    REGION_SERVER_PORT, REGION_SERVER_RECV_ADDR, WORLD_SERVER_GLOBAL_SECRET,
-    WORLD_SERVER_HTTP_PORT, WORLD_SERVER_RECV_ADDR,
+    region_server_port, region_server_recv_addr, world_server_global_secret,
+    world_server_http_port, world_server_recv_addr,
 };
 use logging::info;
 use region_server_http_proto::WorldRegisterInstanceRequest;
@@ -27,12 +27,12 @@ pub fn send_register_instance_request(
 
     //info!("Sending request to register instance with region server ..");
     let request = WorldRegisterInstanceRequest::new(
-        WORLD_SERVER_GLOBAL_SECRET,
+        world_server_global_secret(),
         world_instance.instance_secret(),
-        WORLD_SERVER_RECV_ADDR,
-        WORLD_SERVER_HTTP_PORT,
+        world_server_recv_addr(),
+        world_server_http_port(),
     );
-    let key = http_client.send(REGION_SERVER_RECV_ADDR, REGION_SERVER_PORT, request);
+    let key = http_client.send(region_server_recv_addr(), region_server_port(), request);
 
     region_manager.set_register_instance_response_key(key);
     region_manager.sent_to_region_server();
@@ -1,30 +1,247 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
 
 use bevy_ecs::system::Resource;
 
 use naia_bevy_server::RoomKey;
 
-use social_server_types::LobbyId;
+use auth_server_types::UserId;
+use bevy_http_client::ResponseKey;
+use region_server_http_proto::WorldMatchEndedResponse;
+use social_server_types::{LobbyId, MatchOutcome, MatchUserResult};
 
-#[derive(Resource)]
-pub struct LobbyManager {
-    lobby_room_keys: HashMap<LobbyId, RoomKey>,
+struct PlayerData {
+    joined_at: Instant,
+    left_at: Option<Instant>,
 }
 
-impl Default for LobbyManager {
-    fn default() -> Self {
+impl PlayerData {
+    fn new() -> Self {
         Self {
-            lobby_room_keys: HashMap::new(),
+            joined_at: Instant::now(),
+            left_at: None,
+        }
+    }
+
+    fn result(&self, now: Instant) -> MatchUserResult {
+        let until = self.left_at.unwrap_or(now);
+        let seconds_played = until.duration_since(self.joined_at).as_secs() as u32;
+        MatchUserResult::new(seconds_played, self.left_at.is_none())
+    }
+}
+
+struct MatchData {
+    room_key: RoomKey,
+    started_at: Instant,
+    players: HashMap<UserId, PlayerData>,
+    endless: bool,
+}
+
+impl MatchData {
+    fn new(room_key: RoomKey, endless: bool) -> Self {
+        Self {
+            room_key,
+            started_at: Instant::now(),
+            players: HashMap::new(),
+            endless,
+        }
+    }
+
+    fn end_condition(
+        &self,
+        now: Instant,
+        match_duration: Duration,
+        join_timeout: Duration,
+    ) -> Option<MatchOutcome> {
+        if self.endless {
+            return None;
+        }
+
+        let elapsed = now.duration_since(self.started_at);
+
+        if self.players.is_empty() {
+            // nobody showed up
+            if elapsed >= join_timeout {
+                return Some(MatchOutcome::Abandoned);
+            }
+            return None;
         }
+
+        if self.players.values().all(|player| player.left_at.is_some()) {
+            // everybody left
+            return Some(MatchOutcome::Abandoned);
+        }
+
+        if elapsed >= match_duration {
+            return Some(MatchOutcome::Finished);
+        }
+
+        None
+    }
+
+    fn results(&self, now: Instant) -> Vec<(UserId, MatchUserResult)> {
+        self.players
+            .iter()
+            .map(|(user_id, player)| (*user_id, player.result(now)))
+            .collect()
+    }
+}
+
+// retries of a failed match end report back off from this, doubling each time up to the max
+const MATCH_END_RETRY_INTERVAL: Duration = Duration::from_secs(1);
+const MAX_MATCH_END_RETRY_INTERVAL: Duration = Duration::from_secs(60);
+
+// a match end report, kept until the region server acknowledges it
+pub(crate) struct MatchEndReport {
+    pub(crate) lobby_id: LobbyId,
+    pub(crate) outcome: MatchOutcome,
+    pub(crate) results: Vec<(UserId, MatchUserResult)>,
+    retries: u32,
+    send_at: Instant,
+}
+
+impl MatchEndReport {
+    fn new(
+        lobby_id: LobbyId,
+        outcome: MatchOutcome,
+        results: Vec<(UserId, MatchUserResult)>,
+    ) -> Self {
+        Self {
+            lobby_id,
+            outcome,
+            results,
+            retries: 0,
+            send_at: Instant::now(),
+        }
+    }
+
+    pub(crate) fn retries(&self) -> u32 {
+        self.retries
     }
 }
 
+#[derive(Resource)]
+pub struct LobbyManager {
+    match_duration: Duration,
+    join_timeout: Duration,
+
+    matches: HashMap<LobbyId, MatchData>,
+
+    // matches which have ended, but have not yet been reported to the region server
+    outgoing_match_ends: Vec<MatchEndReport>,
+    in_flight_match_ends: Vec<(MatchEndReport, ResponseKey<WorldMatchEndedResponse>)>,
+}
+
 impl LobbyManager {
+    pub fn new(match_duration: Duration, join_timeout: Duration) -> Self {
+        Self {
+            match_duration,
+            join_timeout,
+
+            matches: HashMap::new(),
+
+            outgoing_match_ends: Vec::new(),
+            in_flight_match_ends: Vec::new(),
+        }
+    }
+
     pub fn lobby_room_key(&self, lobby_id: &LobbyId) -> Option<RoomKey> {
-        self.lobby_room_keys.get(lobby_id).cloned()
+        self.matches
+            .get(lobby_id)
+            .map(|match_data| match_data.room_key)
+    }
+
+    pub fn start_match(&mut self, lobby_id: LobbyId, room_key: RoomKey) {
+        self.matches
+            .insert(lobby_id, MatchData::new(room_key, false));
+    }
+
+    // a match with no end conditions, used when there is no region server to report to
+    #[allow(unused)]
+    pub fn start_endless_match(&mut self, lobby_id: LobbyId, room_key: RoomKey) {
+        self.matches
+            .insert(lobby_id, MatchData::new(room_key, true));
+    }
+
+    pub fn player_joined(&mut self, lobby_id: &LobbyId, user_id: &UserId) {
+        let Some(match_data) = self.matches.get_mut(lobby_id) else {
+            return;
+        };
+        match_data.players.insert(*user_id, PlayerData::new());
+    }
+
+    pub fn player_left(&mut self, lobby_id: &LobbyId, user_id: &UserId) {
+        let Some(match_data) = self.matches.get_mut(lobby_id) else {
+            // match has already ended
+            return;
+        };
+        let Some(player) = match_data.players.get_mut(user_id) else {
+            return;
+        };
+        player.left_at = Some(Instant::now());
+    }
+
+    // removes any matches which have met their end condition, returning their (lobby id, room key)
+    pub(crate) fn end_matches(&mut self) -> Vec<(LobbyId, RoomKey)> {
+        let now = Instant::now();
+
+        let mut ended_lobby_ids = Vec::new();
+        for (lobby_id, match_data) in self.matches.iter() {
+            if let Some(outcome) =
+                match_data.end_condition(now, self.match_duration, self.join_timeout)
+            {
+                ended_lobby_ids.push((*lobby_id, outcome));
+            }
+        }
+
+        let mut output = Vec::new();
+        for (lobby_id, outcome) in ended_lobby_ids {
+            let match_data = self.matches.remove(&lobby_id).unwrap();
+            self.outgoing_match_ends.push(MatchEndReport::new(
+                lobby_id,
+                outcome,
+                match_data.results(now),
+            ));
+            output.push((lobby_id, match_data.room_key));
+        }
+        output
+    }
+
+    // the reports which are due to be sent, leaving those still backing off from a failure
+    pub(crate) fn take_outgoing_match_ends(&mut self) -> Vec<MatchEndReport> {
+        let now = Instant::now();
+        let (due, waiting) = std::mem::take(&mut self.outgoing_match_ends)
+            .into_iter()
+            .partition(|report| report.send_at <= now);
+        self.outgoing_match_ends = waiting;
+        due
+    }
+
+    pub(crate) fn add_in_flight_match_end(
+        &mut self,
+        report: MatchEndReport,
+        response_key: ResponseKey<WorldMatchEndedResponse>,
+    ) {
+        self.in_flight_match_ends.push((report, response_key));
+    }
+
+    pub(crate) fn take_in_flight_match_ends(
+        &mut self,
+    ) -> Vec<(MatchEndReport, ResponseKey<WorldMatchEndedResponse>)> {
+        std::mem::take(&mut self.in_flight_match_ends)
     }
 
-    pub fn insert_lobby_room_key(&mut self, lobby_id: LobbyId, room_key: RoomKey) {
-        self.lobby_room_keys.insert(lobby_id, room_key);
+    // queues a report the region server didn't acknowledge, to be sent again after a backoff
+    pub(crate) fn retry_match_end(&mut self, mut report: MatchEndReport) -> Duration {
+        report.retries += 1;
+        let multiplier = 2u32.saturating_pow(report.retries - 1);
+        let retry_interval = MATCH_END_RETRY_INTERVAL
+            .saturating_mul(multiplier)
+            .min(MAX_MATCH_END_RETRY_INTERVAL);
+        report.send_at = Instant::now() + retry_interval;
+        self.outgoing_match_ends.push(report);
+        retry_interval
     }
 }
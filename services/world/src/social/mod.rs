@@ -3,3 +3,5 @@ pub use lobby_manager::*;
 
 mod plugin;
 pub use plugin::*;
+
+mod systems;
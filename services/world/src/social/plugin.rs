@@ -1,13 +1,31 @@
-use bevy_app::{App, Plugin};
+use std::time::Duration;
 
-use crate::social::LobbyManager;
+use bevy_app::{App, Plugin, Update};
+
+use config::match_duration_secs;
+
+use crate::social::{systems, LobbyManager};
 
 pub struct SocialPlugin;
 
 impl Plugin for SocialPlugin {
     fn build(&self, app: &mut App) {
+        // how long a match runs before it is finished
+        let match_duration = Duration::from_secs(match_duration_secs().into());
+        // how long to wait for the first player to connect, before the match is abandoned
+        let join_timeout = Duration::from_secs(60);
+
         app
             // Resource
-            .init_resource::<LobbyManager>();
+            .insert_resource(LobbyManager::new(match_duration, join_timeout))
+            // Systems
+            .add_systems(
+                Update,
+                (
+                    systems::end_matches,
+                    systems::send_match_ended_requests,
+                    systems::recv_match_ended_responses,
+                ),
+            );
     }
 }
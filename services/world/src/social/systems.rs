@@ -0,0 +1,118 @@
+use bevy_ecs::{
+    change_detection::{Res, ResMut},
+    system::Commands,
+};
+
+use naia_bevy_server::Server;
+
+use bevy_http_client::{ApiRequest, ApiResponse, HttpClient, ResponseError};
+use config::{region_server_port, region_server_recv_addr, world_server_global_secret};
+use logging::{info, warn};
+use region_server_http_proto::{WorldMatchEndedRequest, WorldMatchEndedResponse};
+
+use crate::{
+    recording::MatchRecorder, region::RegionManager, social::LobbyManager, user::UserManager,
+};
+
+pub fn end_matches(
+    mut commands: Commands,
+    mut naia_server: Server,
+    mut lobby_manager: ResMut<LobbyManager>,
+    mut user_manager: ResMut<UserManager>,
+    mut match_recorder: ResMut<MatchRecorder>,
+) {
+    for (lobby_id, room_key) in lobby_manager.end_matches() {
+        info!("match ended - [lobbyid {:?}]", lobby_id);
+
+        match_recorder.finish_recording(&lobby_id);
+
+        // players return to the session server's main menu, so disconnect them here
+        for user_key in user_manager.user_keys_in_lobby(&lobby_id) {
+            naia_server.user_mut(&user_key).disconnect();
+        }
+
+        // nobody is coming back for the units of players still within their reconnect grace
+        for user_data in user_manager.take_disconnected_users_in_lobby(&lobby_id) {
+            if let Some(user_entity) = user_data.user_entity() {
+                commands.entity(user_entity).despawn();
+            }
+        }
+
+        naia_server.room_mut(&room_key).destroy();
+    }
+}
+
+pub fn send_match_ended_requests(
+    mut http_client: ResMut<HttpClient>,
+    mut lobby_manager: ResMut<LobbyManager>,
+    region_manager: Res<RegionManager>,
+) {
+    if !region_manager.connected() {
+        // it's okay to wait until the region server is available
+        return;
+    }
+
+    for report in lobby_manager.take_outgoing_match_ends() {
+        let request = WorldMatchEndedRequest::new(
+            world_server_global_secret(),
+            report.lobby_id,
+            report.outcome,
+            report.results.clone(),
+        );
+
+        let host = "world";
+        let remote = "region";
+        bevy_http_client::log_util::send_req(host, remote, WorldMatchEndedRequest::name());
+        let response_key =
+            http_client.send(region_server_recv_addr(), region_server_port(), request);
+
+        lobby_manager.add_in_flight_match_end(report, response_key);
+    }
+}
+
+pub fn recv_match_ended_responses(
+    mut http_client: ResMut<HttpClient>,
+    mut lobby_manager: ResMut<LobbyManager>,
+) {
+    let mut continuing_requests = Vec::new();
+
+    for (report, response_key) in lobby_manager.take_in_flight_match_ends() {
+        let Some(response_result) = http_client.recv(&response_key) else {
+            continuing_requests.push((report, response_key));
+            continue;
+        };
+
+        let host = "world";
+        let remote = "region";
+        bevy_http_client::log_util::recv_res(host, remote, WorldMatchEndedResponse::name());
+
+        match response_result {
+            Ok(_) => {}
+            Err(e @ (ResponseError::NotFound | ResponseError::Conflict)) => {
+                // social no longer has a running match for this lobby, retrying can't change that
+                warn!(
+                    "match end rejected by social server, dropping it - [lobbyid {:?}]: {:?}",
+                    report.lobby_id,
+                    e.to_string()
+                );
+            }
+            Err(e) => {
+                // the region server needs every result, so keep trying until it acknowledges
+                let lobby_id = report.lobby_id;
+                let retries = report.retries();
+                let retry_interval = lobby_manager.retry_match_end(report);
+                warn!(
+                    "error reporting match end to region server - [lobbyid {:?}] (attempt {}), retrying in {:?}: {:?}",
+                    lobby_id,
+                    retries + 1,
+                    retry_interval,
+                    e.to_string()
+                );
+            }
+        }
+    }
+
+    for (report, response_key) in continuing_requests {
+        lobby_manager.add_in_flight_match_end(report, response_key);
+    }
+}
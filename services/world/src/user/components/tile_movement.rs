@@ -27,6 +27,10 @@ impl ServerTileMovement {
         me
     }
 
+    pub fn tile_position(&self) -> (i16, i16) {
+        self.tile_movement.tile_position()
+    }
+
     pub fn decompose(&mut self) -> (&mut TileMovement, &mut MoveBuffer) {
         (&mut self.tile_movement, &mut self.move_buffer)
     }
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use bevy_app::{App, Plugin, Startup, Update};
 use bevy_ecs::schedule::IntoSystemConfigs;
 
@@ -5,7 +7,9 @@ use naia_bevy_server::{
     Plugin as NaiaServerPlugin, ReceiveEvents, ServerConfig as NaiaServerConfig,
 };
 
-use world_server_naia_proto::protocol as naia_protocol;
+use config::{link_conditions, reconnect_grace_period_secs};
+use logging::info;
+use world_server_naia_proto::{conditioned_protocol, LinkConditionerConfig};
 
 use super::systems;
 use crate::user::UserManager;
@@ -14,14 +18,22 @@ pub struct UserPlugin;
 
 impl Plugin for UserPlugin {
     fn build(&self, app: &mut App) {
+        // how long a dropped user's unit stays in the match, waiting for them to reconnect
+        let reconnect_grace_period = Duration::from_secs(reconnect_grace_period_secs().into());
+
+        let link_conditioner = link_conditions().map(|conditions| {
+            info!("simulating link conditions: {}", conditions);
+            LinkConditionerConfig::new(conditions.latency_ms, conditions.jitter_ms, conditions.loss)
+        });
+
         app
             // Plugins
             .add_plugins(NaiaServerPlugin::new(
                 NaiaServerConfig::default(),
-                naia_protocol(),
+                conditioned_protocol(link_conditioner),
             ))
             // Resources
-            .init_resource::<UserManager>()
+            .insert_resource(UserManager::new(reconnect_grace_period))
             // Startup Systems
             .add_systems(
                 Startup,
@@ -34,6 +46,7 @@ impl Plugin for UserPlugin {
                     systems::connection::auth_events,
                     systems::connection::connect_events,
                     systems::connection::disconnect_events,
+                    systems::connection::expire_disconnected_users,
                     systems::error::error_events,
                     systems::tick::tick_events,
                 )
@@ -1,12 +1,13 @@
 use bevy_ecs::{
-    change_detection::ResMut,
+    change_detection::{Res, ResMut},
+    entity::Entity,
     event::EventReader,
-    system::{Commands, Res},
+    system::Commands,
 };
 
 use naia_bevy_server::{
     events::{AuthEvents, ConnectEvent, DisconnectEvent},
-    CommandsExt, Random, Server,
+    CommandsExt, Random, RoomKey, Server,
 };
 
 use logging::{info, warn};
@@ -28,29 +29,49 @@ use crate::{
 };
 
 pub fn auth_events(
+    mut commands: Commands,
     mut user_manager: ResMut<UserManager>,
+    lobby_manager: Res<LobbyManager>,
     mut server: Server,
     mut event_reader: EventReader<AuthEvents>,
 ) {
     for events in event_reader.read() {
         for (user_key, auth) in events.read::<Auth>() {
-            if let Some(user_data) = user_manager.spend_login_token(&auth.login_token) {
-                info!(
-                    "Accepted connection. User Id: {:?}, Token: {}",
-                    user_data.user_id(),
-                    auth.login_token
-                );
-
-                user_manager.add_user(&user_key, user_data);
-
-                // Accept incoming connection
-                server.accept_connection(&user_key);
-            } else {
+            let Some(user_data) = user_manager.spend_login_token(&auth.login_token) else {
                 warn!("Rejected connection. Token: {}", auth.login_token);
 
                 // Reject incoming connection
                 server.reject_connection(&user_key);
+                continue;
+            };
+
+            if lobby_manager
+                .lobby_room_key(&user_data.lobby_id())
+                .is_none()
+            {
+                warn!(
+                    "Rejected connection, match has ended. User Id: {:?}",
+                    user_data.user_id()
+                );
+
+                // the match ended while this user was away
+                if let Some(user_entity) = user_data.user_entity() {
+                    commands.entity(user_entity).despawn();
+                }
+                server.reject_connection(&user_key);
+                continue;
             }
+
+            info!(
+                "Accepted connection. User Id: {:?}, Token: {}",
+                user_data.user_id(),
+                auth.login_token
+            );
+
+            user_manager.add_user(&user_key, user_data);
+
+            // Accept incoming connection
+            server.accept_connection(&user_key);
         }
     }
 }
@@ -58,7 +79,7 @@ pub fn auth_events(
 pub fn connect_events(
     mut commands: Commands,
     mut server: Server,
-    lobby_manager: Res<LobbyManager>,
+    mut lobby_manager: ResMut<LobbyManager>,
     mut user_manager: ResMut<UserManager>,
     mut asset_manager: ResMut<AssetManager>,
     mut event_reader: EventReader<ConnectEvent>,
@@ -76,44 +97,33 @@ pub fn connect_events(
         let lobby_room_key = lobby_manager.lobby_room_key(&lobby_id).unwrap();
         server.room_mut(&lobby_room_key).add_user(&user_key);
 
-        let tile_position_x = Random::gen_range_i32(-TILE_COUNT, TILE_COUNT) as i16;
-        let tile_position_y = Random::gen_range_i32(-TILE_COUNT, TILE_COUNT) as i16;
-
-        let net_tile_target = NetworkedTileTarget::new(tile_position_x, tile_position_y);
-        let net_move_buffer = NetworkedMoveBuffer::new();
-        let net_look_dir = NetworkedLookDir::new(Direction::random());
-        let net_last_command = NetworkedLastCommand::new(None);
-        let tile_movement = ServerTileMovement::new_stopped(&net_tile_target);
-        let physics = PhysicsController::new(&net_tile_target);
-
-        // give user an entity
-        let user_entity = commands
-            // spawn new entity
-            .spawn_empty()
-            // MUST call this to begin replication
-            .enable_replication(&mut server)
-            // insert asset ref
-            .insert_asset::<Main>(
-                &mut asset_manager,
-                &mut server,
-                AssetCatalog::AvatarUnit.into(),
-            )
-            // insert position components
-            .insert(net_tile_target)
-            .insert(net_move_buffer)
-            .insert(net_look_dir)
-            .insert(net_last_command)
-            .insert(tile_movement)
-            .insert(physics)
-            // return Entity id
-            .id();
-
-        // add entity to lobby room
-        server.room_mut(&lobby_room_key).add_entity(&user_entity);
-
-        user_manager.set_user_entity(user_key, &user_entity);
-
-        // TODO: need to clean up this entity on disconnect
+        if user_manager.is_spectator(user_key) {
+            // sees the whole match, but has nothing to control
+            info!("User joined as spectator: {:?}", user_key);
+            continue;
+        }
+
+        let user_entity = match user_manager.get_user_entity(user_key) {
+            Some(user_entity) => {
+                // reconnected within the grace period, their unit is where they left it
+                info!("User resumed control of entity: {:?}", user_entity);
+                user_entity
+            }
+            None => {
+                // track player for match results
+                let user_id = user_manager.get_user_id(user_key).unwrap();
+                lobby_manager.player_joined(&lobby_id, &user_id);
+
+                let user_entity = spawn_user_entity(
+                    &mut commands,
+                    &mut server,
+                    &mut asset_manager,
+                    &lobby_room_key,
+                );
+                user_manager.set_user_entity(user_key, &user_entity);
+                user_entity
+            }
+        };
 
         // Send an Entity Assignment message to User
         let mut assignment_message = EntityAssignment::new(true);
@@ -130,16 +140,102 @@ pub fn disconnect_events(
     mut commands: Commands,
     mut asset_manager: ResMut<AssetManager>,
     mut user_manager: ResMut<UserManager>,
+    mut lobby_manager: ResMut<LobbyManager>,
     mut event_reader: EventReader<DisconnectEvent>,
 ) {
     for DisconnectEvent(user_key, user) in event_reader.read() {
         info!("Server disconnected from: {:?}", user.address());
 
         asset_manager.deregister_user(user_key);
+
+        if user_manager.get_user_id(user_key).is_none() {
+            // a spectator who stopped watching, already forgotten
+            continue;
+        }
+
+        if let Some(lobby_id) = user_manager.get_user_lobby_id(user_key) {
+            if lobby_manager.lobby_room_key(&lobby_id).is_some() {
+                // the match is still on, so give the user a chance to come back to it
+                user_manager.hold_user(user_key);
+                continue;
+            }
+        }
+
+        let is_spectator = user_manager.is_spectator(user_key);
+        if let (Some(user_id), Some(lobby_id)) = (
+            user_manager.get_user_id(user_key),
+            user_manager.get_user_lobby_id(user_key),
+        ) {
+            if !is_spectator {
+                lobby_manager.player_left(&lobby_id, &user_id);
+            }
+        }
+
         if let Some(user_entity) = user_manager.remove_user(user_key) {
             commands.entity(user_entity).despawn();
-        } else {
+        } else if !is_spectator {
             warn!("User entity not found for user key: {:?}", user_key);
         }
     }
 }
+
+pub fn expire_disconnected_users(
+    mut commands: Commands,
+    mut user_manager: ResMut<UserManager>,
+    mut lobby_manager: ResMut<LobbyManager>,
+) {
+    for user_data in user_manager.take_expired_users() {
+        info!(
+            "User did not reconnect in time, removing from match: {:?}",
+            user_data.user_id()
+        );
+
+        if !user_data.is_spectator() {
+            lobby_manager.player_left(&user_data.lobby_id(), &user_data.user_id());
+        }
+
+        if let Some(user_entity) = user_data.user_entity() {
+            commands.entity(user_entity).despawn();
+        }
+    }
+}
+
+fn spawn_user_entity(
+    commands: &mut Commands,
+    server: &mut Server,
+    asset_manager: &mut AssetManager,
+    lobby_room_key: &RoomKey,
+) -> Entity {
+    let tile_position_x = Random::gen_range_i32(-TILE_COUNT, TILE_COUNT) as i16;
+    let tile_position_y = Random::gen_range_i32(-TILE_COUNT, TILE_COUNT) as i16;
+
+    let net_tile_target = NetworkedTileTarget::new(tile_position_x, tile_position_y);
+    let net_move_buffer = NetworkedMoveBuffer::new();
+    let net_look_dir = NetworkedLookDir::new(Direction::random());
+    let net_last_command = NetworkedLastCommand::new(None);
+    let tile_movement = ServerTileMovement::new_stopped(&net_tile_target);
+    let physics = PhysicsController::new(&net_tile_target);
+
+    // give user an entity
+    let user_entity = commands
+        // spawn new entity
+        .spawn_empty()
+        // MUST call this to begin replication
+        .enable_replication(server)
+        // insert asset ref
+        .insert_asset::<Main>(asset_manager, server, AssetCatalog::AvatarUnit.into())
+        // insert position components
+        .insert(net_tile_target)
+        .insert(net_move_buffer)
+        .insert(net_look_dir)
+        .insert(net_last_command)
+        .insert(tile_movement)
+        .insert(physics)
+        // return Entity id
+        .id();
+
+    // add entity to lobby room
+    server.room_mut(lobby_room_key).add_entity(&user_entity);
+
+    user_entity
+}
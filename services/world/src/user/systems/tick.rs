@@ -1,30 +1,33 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use bevy_ecs::{
     change_detection::Mut,
     entity::Entity,
     event::EventReader,
     prelude::{Query, Resource, World},
-    system::{Res, SystemState},
+    system::{Res, ResMut, SystemState},
 };
 
-use naia_bevy_server::{events::TickEvent, Server, UserKey};
+use naia_bevy_server::{events::TickEvent, Server, Tick, UserKey};
 
+use asset_id::AssetId;
 use logging::info;
 
 use world_server_naia_proto::{
     behavior as shared_behavior,
-    behavior::TickOutput,
-    channels::PlayerCommandChannel,
+    behavior::{is_movement_checksum_tick, MovementState, TickOutput},
+    channels::{MovementChecksumChannel, PlayerCommandChannel},
     components::{
-        NetworkedLastCommand, NetworkedLookDir, NetworkedMoveBuffer, NetworkedTileTarget,
-        PhysicsController, TileMovementType,
+        AssetEntry, AssetRef, Main, NetworkedLastCommand, NetworkedLookDir, NetworkedMoveBuffer,
+        NetworkedTileTarget, PhysicsController, TileMovementType,
     },
-    messages::PlayerCommands,
+    messages::{MovementChecksum, PlayerCommands},
 };
 
 use crate::{
-    asset::AssetManager,
+    asset::{AssetManager, SpecStore},
+    interest::InterestManager,
+    recording::MatchRecorder,
     user::{components::ServerTileMovement, UserManager},
 };
 
@@ -63,6 +66,10 @@ pub fn tick_events(world: &mut World) {
             Query<&mut NetworkedMoveBuffer>,
             Query<&mut NetworkedLookDir>,
             Query<&mut NetworkedLastCommand>,
+            ResMut<MatchRecorder>,
+            Query<&AssetRef<Main>>,
+            Query<&AssetEntry>,
+            Res<SpecStore>,
         )> = SystemState::new(world);
         let (
             mut server,
@@ -72,6 +79,10 @@ pub fn tick_events(world: &mut World) {
             mut net_move_buffer_q,
             mut net_look_dir_q,
             mut net_last_command_q,
+            mut match_recorder,
+            asset_ref_q,
+            asset_entry_q,
+            spec_store,
         ) = system_state.get_mut(world);
 
         for server_tick in tick_events.iter() {
@@ -83,6 +94,9 @@ pub fn tick_events(world: &mut World) {
             for (user_key, incoming_command) in
                 messages.read::<PlayerCommandChannel, PlayerCommands>()
             {
+                if user_manager.is_spectator(&user_key) {
+                    continue;
+                }
                 users_without_command.remove(&user_key);
 
                 if let Some(prev_command) = user_commands.get_mut(&user_key) {
@@ -108,6 +122,8 @@ pub fn tick_events(world: &mut World) {
 
             // All game logic should happen here, on a tick event
 
+            let mut simulated_units = HashSet::new();
+
             // process movement
             for (entity, mut tile_movement, mut physics) in tile_movement_q.iter_mut() {
                 let Some(user_key) = user_manager.get_user_key_from_entity(&entity) else {
@@ -122,6 +138,36 @@ pub fn tick_events(world: &mut World) {
                     panic!("NetworkedLookDir not found for entity: {:?}", entity);
                 };
 
+                // a unit stands still until its movement config has loaded, as the client's
+                // prediction waits for its own copy, rather than moving with a default one
+                let Some(movement_config) =
+                    unit_asset_id(&server, &asset_ref_q, &asset_entry_q, &entity)
+                        .and_then(|asset_id| spec_store.get_unit_movement_config(&asset_id))
+                else {
+                    continue;
+                };
+                if physics.movement_config() != movement_config {
+                    physics.set_movement_config(movement_config);
+                }
+
+                // record the unit's input before simulating it, as replay will
+                if let Some(lobby_id) = user_manager.get_user_lobby_id(&user_key) {
+                    let spawn_state = if match_recorder.is_recording_unit(&entity) {
+                        None
+                    } else {
+                        unit_asset_id(&server, &asset_ref_q, &asset_entry_q, &entity).map(
+                            |asset_id| (asset_id, tile_movement.tile_position(), look_dir.get()),
+                        )
+                    };
+                    match_recorder.record_unit_tick(
+                        &lobby_id,
+                        &entity,
+                        spawn_state,
+                        player_command.as_ref(),
+                    );
+                    simulated_units.insert(entity);
+                }
+
                 let mut tick_output = TickOutput::new();
                 let (inner_tile_movement, inner_move_buffer) = tile_movement.decompose();
                 shared_behavior::process_tick(
@@ -135,6 +181,11 @@ pub fn tick_events(world: &mut World) {
                     Some(&mut tick_output),
                 );
 
+                if is_movement_checksum_tick(*server_tick) {
+                    let checksum = MovementState::capture(inner_tile_movement, &physics).checksum();
+                    send_movement_checksum(&mut server, *server_tick, &entity, checksum);
+                }
+
                 if let Some((outbound_tile_x, outbound_tile_y)) =
                     tick_output.take_outbound_net_tile_target()
                 {
@@ -171,6 +222,9 @@ pub fn tick_events(world: &mut World) {
                 };
                 net_last_command.recv_command(player_command.clone());
             }
+
+            match_recorder
+                .finish_tick(&simulated_units, |entity| tile_movement_q.contains(*entity));
         }
     }
 
@@ -179,8 +233,31 @@ pub fn tick_events(world: &mut World) {
     }
 }
 
+fn send_movement_checksum(server: &mut Server, tick: Tick, entity: &Entity, checksum: u32) {
+    for user_key in server.user_keys() {
+        if !server.user_scope(&user_key).has(entity) {
+            continue;
+        }
+        let mut message = MovementChecksum::new(tick, checksum);
+        message.entity.set(server, entity);
+        server.send_message::<MovementChecksumChannel, MovementChecksum>(&user_key, &message);
+    }
+}
+
+fn unit_asset_id(
+    server: &Server,
+    asset_ref_q: &Query<&AssetRef<Main>>,
+    asset_entry_q: &Query<&AssetEntry>,
+    entity: &Entity,
+) -> Option<AssetId> {
+    let asset_ref = asset_ref_q.get(*entity).ok()?;
+    let asset_entry_entity = asset_ref.asset_id_entity.get(server)?;
+    let asset_entry = asset_entry_q.get(asset_entry_entity).ok()?;
+    Some(*asset_entry.asset_id)
+}
+
 fn handle_scope_checks(world: &mut World) {
-    // Asset scope checks
+    // Area-of-interest & asset scope checks
     // TODO: this does not belong here... see notes
 
     // 1. get all scope checks from server
@@ -204,17 +281,42 @@ fn handle_scope_checks(world: &mut World) {
     // 2. calculate all updates to scope needed
     let mut scope_actions: HashMap<(UserKey, Entity), bool> = HashMap::new();
 
-    for (_room_key, user_key, entity, in_scope) in scope_checks {
-        // TODO: assess scope logic here ..
-        // right now, everything not in scope is added to user
-        // however this will change later
-
-        if !in_scope {
-            info!(
-                "Entity out of scope: {:?}, should be added to user.",
-                entity
-            );
-            scope_actions.insert((user_key, entity), true);
+    {
+        let mut system_state: SystemState<(
+            Res<InterestManager>,
+            Res<UserManager>,
+            Query<&ServerTileMovement>,
+        )> = SystemState::new(world);
+        let (interest_manager, user_manager, tile_movement_q) = system_state.get(world);
+
+        for (_room_key, user_key, entity, in_scope) in scope_checks {
+            if user_manager.is_spectator(&user_key) {
+                // spectators watch the whole match
+                if !in_scope {
+                    scope_actions.insert((user_key, entity), true);
+                }
+                continue;
+            }
+
+            let viewer_tile_opt = user_manager
+                .get_user_entity(&user_key)
+                .and_then(|user_entity| tile_movement_q.get(user_entity).ok())
+                .map(|tile_movement| tile_movement.tile_position());
+            let target_tile_opt = if Some(entity) == user_manager.get_user_entity(&user_key) {
+                // a user's own entity is always in view
+                None
+            } else {
+                tile_movement_q
+                    .get(entity)
+                    .ok()
+                    .map(|tile_movement| tile_movement.tile_position())
+            };
+
+            let should_be_in_scope =
+                interest_manager.should_be_in_scope(viewer_tile_opt, target_tile_opt, in_scope);
+            if should_be_in_scope != in_scope {
+                scope_actions.insert((user_key, entity), should_be_in_scope);
+            }
         }
     }
 
@@ -4,29 +4,62 @@ use auth_server_types::UserId;
 use social_server_types::LobbyId;
 
 pub struct UserData {
+    login_token: String,
     session_server_addr: String,
     session_server_port: u16,
     user_id: UserId,
     lobby_id: LobbyId,
     user_entity_opt: Option<Entity>,
+    // watches the match without an avatar, and its commands are ignored
+    spectator: bool,
 }
 
 impl UserData {
     pub(crate) fn new(
+        login_token: &str,
         session_server_addr: &str,
         session_server_port: u16,
         user_id: UserId,
         lobby_id: LobbyId,
     ) -> Self {
         Self {
+            login_token: login_token.to_string(),
             session_server_addr: session_server_addr.to_string(),
             session_server_port,
             user_id,
             lobby_id,
             user_entity_opt: None,
+            spectator: false,
         }
     }
 
+    pub(crate) fn new_spectator(
+        login_token: &str,
+        session_server_addr: &str,
+        session_server_port: u16,
+        user_id: UserId,
+        lobby_id: LobbyId,
+    ) -> Self {
+        let mut user_data = Self::new(
+            login_token,
+            session_server_addr,
+            session_server_port,
+            user_id,
+            lobby_id,
+        );
+        user_data.spectator = true;
+        user_data
+    }
+
+    pub(crate) fn is_spectator(&self) -> bool {
+        self.spectator
+    }
+
+    // stays valid for the rest of the match, so a dropped user can reconnect with it
+    pub(crate) fn login_token(&self) -> &str {
+        &self.login_token
+    }
+
     pub(crate) fn session_server_addr(&self) -> (&str, u16) {
         (&self.session_server_addr, self.session_server_port)
     }
@@ -30,6 +30,7 @@ impl UserLoginTokenStore {
                 self.login_tokens.insert(
                     token.to_string(),
                     UserData::new(
+                        token,
                         session_server_addr,
                         *session_server_port,
                         *user_id,
@@ -40,6 +41,33 @@ impl UserLoginTokenStore {
         }
     }
 
+    pub fn recv_spectator_login_token(
+        &mut self,
+        lobby_id: &LobbyId,
+        (session_server_addr, session_server_port): (&str, u16),
+        user_id: &UserId,
+        token: &str,
+    ) {
+        self.login_tokens.insert(
+            token.to_string(),
+            UserData::new_spectator(
+                token,
+                session_server_addr,
+                session_server_port,
+                *user_id,
+                *lobby_id,
+            ),
+        );
+    }
+
+    pub fn remove_spectator_login_token(&mut self, lobby_id: &LobbyId, user_id: &UserId) {
+        self.login_tokens.retain(|_, user_data| {
+            !(user_data.is_spectator()
+                && user_data.lobby_id() == *lobby_id
+                && user_data.user_id() == *user_id)
+        });
+    }
+
     pub fn spend_login_token(&mut self, token: &str) -> Option<UserData> {
         self.login_tokens.remove(token)
     }
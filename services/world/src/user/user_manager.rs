@@ -1,4 +1,7 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    time::{Duration, Instant},
+};
 
 use bevy_ecs::{entity::Entity, system::Resource};
 
@@ -14,19 +17,25 @@ pub struct UserManager {
     login_token_store: UserLoginTokenStore,
     users: HashMap<UserKey, UserData>,
     user_entity_to_key: HashMap<Entity, UserKey>,
+
+    // users who dropped mid-match, keyed by login token, whose units are kept in the match until
+    // they reconnect or their grace period runs out
+    disconnected_users: HashMap<String, (UserData, Instant)>,
+    reconnect_grace_period: Duration,
 }
 
-impl Default for UserManager {
-    fn default() -> Self {
+impl UserManager {
+    pub fn new(reconnect_grace_period: Duration) -> Self {
         Self {
             login_token_store: UserLoginTokenStore::new(),
             users: HashMap::new(),
             user_entity_to_key: HashMap::new(),
+
+            disconnected_users: HashMap::new(),
+            reconnect_grace_period,
         }
     }
-}
 
-impl UserManager {
     pub fn get_user_id(&self, user_key: &UserKey) -> Option<UserId> {
         let user_data = self.users.get(user_key)?;
         Some(user_data.user_id())
@@ -44,6 +53,10 @@ impl UserManager {
     }
 
     pub fn add_user(&mut self, user_key: &UserKey, user_data: UserData) {
+        // a reconnecting user already has a unit
+        if let Some(user_entity) = user_data.user_entity() {
+            self.user_entity_to_key.insert(user_entity, *user_key);
+        }
         self.users.insert(user_key.clone(), user_data);
     }
 
@@ -63,16 +76,100 @@ impl UserManager {
             .recv_login_token(lobby_id, login_tokens);
     }
 
+    pub fn recv_spectator_login_token(
+        &mut self,
+        lobby_id: &LobbyId,
+        session_server: (&str, u16),
+        user_id: &UserId,
+        login_token: &str,
+    ) {
+        self.login_token_store.recv_spectator_login_token(
+            lobby_id,
+            session_server,
+            user_id,
+            login_token,
+        );
+    }
+
+    // forgets a spectator who stopped watching, returning their connection if they're still on
+    pub fn remove_spectator(&mut self, lobby_id: &LobbyId, user_id: &UserId) -> Option<UserKey> {
+        let is_leaving_spectator = |user_data: &UserData| {
+            user_data.is_spectator()
+                && user_data.lobby_id() == *lobby_id
+                && user_data.user_id() == *user_id
+        };
+
+        self.login_token_store
+            .remove_spectator_login_token(lobby_id, user_id);
+        self.disconnected_users
+            .retain(|_, (user_data, _)| !is_leaving_spectator(user_data));
+
+        let user_key = self
+            .users
+            .iter()
+            .find(|(_, user_data)| is_leaving_spectator(user_data))
+            .map(|(user_key, _)| *user_key)?;
+        self.users.remove(&user_key);
+        Some(user_key)
+    }
+
+    // a disconnected user's token is spent again when they reconnect within the grace period
     pub fn spend_login_token(&mut self, token: &str) -> Option<UserData> {
+        if let Some((user_data, _)) = self.disconnected_users.remove(token) {
+            return Some(user_data);
+        }
         self.login_token_store.spend_login_token(token)
     }
 
+    // keeps the user's unit in the match until they reconnect, or `take_expired_users()`
+    pub fn hold_user(&mut self, user_key: &UserKey) {
+        let Some(user_data) = self.users.remove(user_key) else {
+            return;
+        };
+        if let Some(user_entity) = user_data.user_entity() {
+            self.user_entity_to_key.remove(&user_entity);
+        }
+        let expires_at = Instant::now() + self.reconnect_grace_period;
+        self.disconnected_users
+            .insert(user_data.login_token().to_string(), (user_data, expires_at));
+    }
+
+    pub fn take_expired_users(&mut self) -> Vec<UserData> {
+        let now = Instant::now();
+        let expired_tokens: Vec<String> = self
+            .disconnected_users
+            .iter()
+            .filter(|(_, (_, expires_at))| *expires_at <= now)
+            .map(|(token, _)| token.clone())
+            .collect();
+        expired_tokens
+            .iter()
+            .filter_map(|token| self.disconnected_users.remove(token))
+            .map(|(user_data, _)| user_data)
+            .collect()
+    }
+
+    pub fn take_disconnected_users_in_lobby(&mut self, lobby_id: &LobbyId) -> Vec<UserData> {
+        let tokens: Vec<String> = self
+            .disconnected_users
+            .iter()
+            .filter(|(_, (user_data, _))| user_data.lobby_id() == *lobby_id)
+            .map(|(token, _)| token.clone())
+            .collect();
+        tokens
+            .iter()
+            .filter_map(|token| self.disconnected_users.remove(token))
+            .map(|(user_data, _)| user_data)
+            .collect()
+    }
+
     pub fn reset(&mut self) {
         // clear login tokens
         self.login_token_store.clear();
 
         // clear users
         self.users.clear();
+        self.disconnected_users.clear();
     }
 
     pub(crate) fn set_user_entity(&mut self, user_key: &UserKey, user_entity: &Entity) {
@@ -86,10 +183,30 @@ impl UserManager {
             .insert(*user_entity, user_key.clone());
     }
 
+    pub(crate) fn get_user_entity(&self, user_key: &UserKey) -> Option<Entity> {
+        let user_data = self.users.get(user_key)?;
+        user_data.user_entity()
+    }
+
     pub(crate) fn get_user_key_from_entity(&self, user_entity: &Entity) -> Option<UserKey> {
         self.user_entity_to_key.get(user_entity).cloned()
     }
 
+    pub(crate) fn user_keys_in_lobby(&self, lobby_id: &LobbyId) -> Vec<UserKey> {
+        self.users
+            .iter()
+            .filter(|(_, user_data)| user_data.lobby_id() == *lobby_id)
+            .map(|(user_key, _)| *user_key)
+            .collect()
+    }
+
+    pub(crate) fn is_spectator(&self, user_key: &UserKey) -> bool {
+        self.users
+            .get(user_key)
+            .map(|user_data| user_data.is_spectator())
+            .unwrap_or(false)
+    }
+
     pub(crate) fn user_key_set(&self) -> HashSet<UserKey> {
         self.users.keys().cloned().collect()
     }